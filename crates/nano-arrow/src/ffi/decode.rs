@@ -0,0 +1,172 @@
+//! Imports of arrays whose layout has no equivalent [`DataType`] in this crate, i.e. the
+//! view types and run-end encoded arrays. They are converted to arrays of the closest
+//! supported [`DataType`] while they are imported.
+use super::schema::to_field;
+use super::{import_array_from_c, ArrowArray, ArrowSchema};
+use crate::array::growable::make_growable;
+use crate::array::{Array, BinaryArray, MutableBinaryArray, PrimitiveArray, Utf8Array};
+use crate::bitmap::utils::get_bit;
+use crate::datatypes::{DataType, Field};
+use crate::error::{Error, Result};
+use crate::types::NativeType;
+
+/// Imports a [`Field`] and its [`Array`] from the C data interface.
+///
+/// In addition to [`import_field_from_c`](super::import_field_from_c) and
+/// [`import_array_from_c`] this accepts the layouts `Utf8View` and `BinaryView`, which are
+/// converted to [`DataType::LargeUtf8`] and [`DataType::LargeBinary`], and run-end encoded
+/// arrays, which are decoded to an array of their values' [`DataType`]. Views nested in other
+/// types are not supported.
+/// # Safety
+/// This function is intrinsically `unsafe` and relies on a [`ArrowSchema`] and [`ArrowArray`]
+/// being valid according to the [C data interface](https://arrow.apache.org/docs/format/CDataInterface.html) (FFI).
+pub unsafe fn import_field_and_array_from_c(
+    schema: &ArrowSchema,
+    array: ArrowArray,
+) -> Result<(Field, Box<dyn Array>)> {
+    let (name, is_nullable) = (schema.name(), schema.nullable());
+    match schema.format() {
+        "vu" => {
+            let array = import_view(array)?;
+            let array = Utf8Array::<i64>::try_new(
+                DataType::LargeUtf8,
+                array.offsets().clone(),
+                array.values().clone(),
+                array.validity().cloned(),
+            )?;
+            let field = Field::new(name, DataType::LargeUtf8, is_nullable);
+            Ok((field, array.boxed()))
+        },
+        "vz" => {
+            let array = import_view(array)?;
+            let field = Field::new(name, DataType::LargeBinary, is_nullable);
+            Ok((field, array.boxed()))
+        },
+        "+r" => {
+            let (values_field, array) = import_run_end_encoded(schema, array)?;
+            let field = Field::new(name, values_field.data_type, is_nullable);
+            Ok((field, array))
+        },
+        _ => {
+            let field = to_field(schema)?;
+            let array = import_array_from_c(array, field.data_type.clone())?;
+            Ok((field, array))
+        },
+    }
+}
+
+/// # Safety
+/// `i` must be smaller than the number of buffers of `array`.
+unsafe fn buffer(array: &ArrowArray, i: usize) -> *const u8 {
+    *array.buffers.add(i) as *const u8
+}
+
+/// Copies the values of a view array to a [`BinaryArray`].
+///
+/// The buffers are the validity, the views, the variadic data buffers and the sizes of the
+/// data buffers. A view of a value of at most 12 bytes contains the value itself, a view of
+/// a longer value contains the index of its data buffer and its offset in that buffer.
+unsafe fn import_view(array: ArrowArray) -> Result<BinaryArray<i64>> {
+    if array.n_buffers < 3 {
+        return Err(Error::oos(
+            "An array of a view type must have at least 3 buffers",
+        ));
+    }
+    let n_buffers = array.n_buffers as usize;
+    let (offset, len) = (array.offset(), array.len());
+    let end = offset + len;
+
+    let validity = buffer(&array, 0);
+    let validity =
+        (!validity.is_null()).then(|| std::slice::from_raw_parts(validity, (end + 7) / 8));
+    let views = std::slice::from_raw_parts(buffer(&array, 1), end * 16);
+    let sizes = buffer(&array, n_buffers - 1) as *const i64;
+    let data_buffers = (0..n_buffers - 3)
+        .map(|i| {
+            let size = sizes.add(i).read_unaligned() as usize;
+            let data = buffer(&array, 2 + i);
+            if size == 0 || data.is_null() {
+                &[][..]
+            } else {
+                std::slice::from_raw_parts(data, size)
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let read_u32 = |bytes: &[u8]| u32::from_ne_bytes(bytes.try_into().unwrap()) as usize;
+    let mut out = MutableBinaryArray::<i64>::with_capacity(len);
+    for i in offset..end {
+        if validity.map_or(false, |validity| !get_bit(validity, i)) {
+            out.push::<&[u8]>(None);
+            continue;
+        }
+        let view = &views[i * 16..(i + 1) * 16];
+        let length = read_u32(&view[..4]);
+        let value = if length <= 12 {
+            &view[4..4 + length]
+        } else {
+            let (index, start) = (read_u32(&view[8..12]), read_u32(&view[12..16]));
+            data_buffers
+                .get(index)
+                .and_then(|data| data.get(start..start + length))
+                .ok_or_else(|| Error::oos("A view points outside of the data buffers"))?
+        };
+        out.push(Some(value));
+    }
+    // the values are copied, the buffers can be released
+    drop(array);
+    Ok(out.into())
+}
+
+fn values_as_i64<T: NativeType + Into<i64>>(array: &dyn Array) -> Vec<i64> {
+    let array = array.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    array.values().iter().map(|v| (*v).into()).collect()
+}
+
+/// Decodes a run-end encoded array to an array of the type of its values.
+unsafe fn import_run_end_encoded(
+    schema: &ArrowSchema,
+    array: ArrowArray,
+) -> Result<(Field, Box<dyn Array>)> {
+    if array.n_children != 2 || schema.n_children != 2 {
+        return Err(Error::oos("A run-end encoded array must have 2 children"));
+    }
+    // move the children out of the parent, they are released on their own
+    let children = std::slice::from_raw_parts_mut(array.children, 2);
+    let [run_ends, values] = [0, 1].map(|i| {
+        let child = &mut *children[i];
+        let moved = std::ptr::read(child);
+        child.release = None;
+        moved
+    });
+    let (offset, len) = (array.offset(), array.len());
+    drop(array);
+
+    let (_, run_ends) = import_field_and_array_from_c(schema.child(0), run_ends)?;
+    let (values_field, values) = import_field_and_array_from_c(schema.child(1), values)?;
+    let run_ends = match run_ends.data_type() {
+        DataType::Int16 => values_as_i64::<i16>(run_ends.as_ref()),
+        DataType::Int32 => values_as_i64::<i32>(run_ends.as_ref()),
+        DataType::Int64 => values_as_i64::<i64>(run_ends.as_ref()),
+        dt => {
+            return Err(Error::oos(format!(
+                "The run ends of a run-end encoded array must be Int16, Int32 or Int64, got {dt:?}"
+            )))
+        },
+    };
+
+    let mut growable = make_growable(&[values.as_ref()], values.null_count() > 0, len);
+    let mut run = 0;
+    for i in offset..offset + len {
+        while run < run_ends.len() && run_ends[run] <= i as i64 {
+            run += 1;
+        }
+        if run >= run_ends.len() || run >= values.len() {
+            return Err(Error::oos(
+                "The run ends of a run-end encoded array are shorter than the array",
+            ));
+        }
+        growable.extend(0, run, 1);
+    }
+    Ok((values_field, growable.as_box()))
+}
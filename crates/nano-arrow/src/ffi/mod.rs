@@ -2,12 +2,14 @@
 //! Arrow's [C Data Interface](https://arrow.apache.org/docs/format/CDataInterface.html)
 mod array;
 mod bridge;
+mod decode;
 mod generated;
 pub mod mmap;
 mod schema;
 mod stream;
 
 pub(crate) use array::{try_from, ArrowArrayRef, InternalArrowArray};
+pub use decode::import_field_and_array_from_c;
 pub use generated::{ArrowArray, ArrowArrayStream, ArrowSchema};
 pub use stream::{export_iterator, ArrowArrayStreamReader};
 
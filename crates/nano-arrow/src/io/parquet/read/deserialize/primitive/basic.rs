@@ -83,6 +83,49 @@ where
     }
 }
 
+#[derive(Debug)]
+pub(super) struct ByteStreamSplitValues<T>
+where
+    T: NativeType,
+{
+    pub values: std::vec::IntoIter<T>,
+}
+
+impl<T> ByteStreamSplitValues<T>
+where
+    T: NativeType,
+{
+    pub fn try_new<P, F>(page: &DataPage, op: F) -> Result<Self>
+    where
+        P: ParquetNativeType,
+        F: Copy + Fn(P) -> T,
+    {
+        let (_, _, values) = split_buffer(page)?;
+        let size = std::mem::size_of::<P>();
+        assert_eq!(values.len() % size, 0);
+        let n = values.len() / size;
+
+        // byte `k` of value `i` is stored in stream `k` at position `i`
+        let mut plain = vec![0u8; values.len()];
+        if n > 0 {
+            for (k, stream) in values.chunks_exact(n).enumerate() {
+                for (i, byte) in stream.iter().enumerate() {
+                    plain[i * size + k] = *byte;
+                }
+            }
+        }
+
+        Ok(Self {
+            values: deserialize_plain(&plain, op).into_iter(),
+        })
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+}
+
 // The state of a `DataPage` of `Primitive` parquet primitive type
 #[derive(Debug)]
 pub(super) enum State<'a, T>
@@ -95,6 +138,8 @@ where
     OptionalDictionary(OptionalPageValidity<'a>, ValuesDictionary<'a, T>),
     FilteredRequired(FilteredRequiredValues<'a>),
     FilteredOptional(FilteredOptionalPageValidity<'a>, Values<'a>),
+    RequiredByteStreamSplit(ByteStreamSplitValues<T>),
+    OptionalByteStreamSplit(OptionalPageValidity<'a>, ByteStreamSplitValues<T>),
 }
 
 impl<'a, T> utils::PageState<'a> for State<'a, T>
@@ -109,6 +154,8 @@ where
             State::OptionalDictionary(optional, _) => optional.len(),
             State::FilteredRequired(values) => values.len(),
             State::FilteredOptional(optional, _) => optional.len(),
+            State::RequiredByteStreamSplit(values) => values.len(),
+            State::OptionalByteStreamSplit(optional, _) => optional.len(),
         }
     }
 }
@@ -186,6 +233,13 @@ where
                 FilteredOptionalPageValidity::try_new(page)?,
                 Values::try_new::<P>(page)?,
             )),
+            (Encoding::ByteStreamSplit, _, false, false) => {
+                ByteStreamSplitValues::try_new(page, self.op).map(State::RequiredByteStreamSplit)
+            },
+            (Encoding::ByteStreamSplit, _, true, false) => Ok(State::OptionalByteStreamSplit(
+                OptionalPageValidity::try_new(page)?,
+                ByteStreamSplitValues::try_new(page, self.op)?,
+            )),
             _ => Err(utils::not_implemented(page)),
         }
     }
@@ -259,6 +313,18 @@ where
                     page_values.values.by_ref().map(decode).map(self.op),
                 );
             },
+            State::RequiredByteStreamSplit(page) => {
+                values.extend(page.values.by_ref().take(remaining));
+            },
+            State::OptionalByteStreamSplit(page_validity, page_values) => {
+                utils::extend_from_decoder(
+                    validity,
+                    page_validity,
+                    Some(remaining),
+                    values,
+                    page_values.values.by_ref(),
+                );
+            },
         }
     }
 
@@ -1,6 +1,12 @@
 use std::io::Write;
+use std::sync::{Arc, Mutex};
 
 use parquet2::metadata::{KeyValue, SchemaDescriptor};
+use parquet2::thrift_format::thrift::protocol::{TCompactInputProtocol, TCompactOutputProtocol};
+use parquet2::thrift_format::{
+    BloomFilterAlgorithm, BloomFilterCompression, BloomFilterHash, BloomFilterHeader,
+    SplitBlockAlgorithm, Uncompressed, XxHash,
+};
 use parquet2::write::{RowGroupIter, WriteOptions as FileWriteOptions};
 
 use super::schema::schema_to_metadata_key;
@@ -21,11 +27,70 @@ pub fn add_arrow_schema(
         .or_else(|| Some(vec![schema_to_metadata_key(schema)]))
 }
 
+struct SharedState<W> {
+    writer: W,
+    /// If set, the written bytes are kept here instead of being written to `writer`.
+    held: Option<Vec<u8>>,
+}
+
+/// A handle to a [`Write`] that can hold back the bytes written through it. This allows
+/// inserting data, such as bloom filters, before the footer that [`parquet2`] writes.
+struct SharedWriter<W>(Arc<Mutex<SharedState<W>>>);
+
+impl<W> SharedWriter<W> {
+    fn new(writer: W) -> Self {
+        Self(Arc::new(Mutex::new(SharedState { writer, held: None })))
+    }
+
+    fn handle(&self) -> Self {
+        Self(self.0.clone())
+    }
+
+    fn hold(&self) {
+        self.0.lock().unwrap().held = Some(vec![]);
+    }
+
+    fn take_held(&self) -> Vec<u8> {
+        self.0.lock().unwrap().held.take().unwrap_or_default()
+    }
+
+    /// Returns the inner writer. Must only be called on the last handle.
+    fn into_inner(self) -> W {
+        match Arc::try_unwrap(self.0) {
+            Ok(state) => state.into_inner().unwrap().writer,
+            Err(_) => panic!("the writer is still shared"),
+        }
+    }
+}
+
+impl<W: Write> Write for SharedWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let mut state = self.0.lock().unwrap();
+        match &mut state.held {
+            Some(held) => {
+                held.extend_from_slice(buf);
+                Ok(buf.len())
+            },
+            None => state.writer.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.0.lock().unwrap().writer.flush()
+    }
+}
+
 /// An interface to write a parquet to a [`Write`]
 pub struct FileWriter<W: Write> {
-    writer: parquet2::write::FileWriter<W>,
+    writer: parquet2::write::FileWriter<SharedWriter<W>>,
+    /// A second handle to the writer of `writer`.
+    shared: SharedWriter<W>,
     schema: Schema,
     options: WriteOptions,
+    /// The number of row groups written so far.
+    num_row_groups: usize,
+    /// The bloom filters to write, as row group, column and bitset.
+    bloom_filters: Vec<(usize, usize, Vec<u8>)>,
 }
 
 // Accessors
@@ -54,10 +119,11 @@ impl<W: Write> FileWriter<W> {
         let parquet_schema = to_parquet_schema(&schema)?;
 
         let created_by = Some("Arrow2 - Native Rust implementation of Arrow".to_string());
+        let shared = SharedWriter::new(writer);
 
         Ok(Self {
             writer: parquet2::write::FileWriter::new(
-                writer,
+                shared.handle(),
                 parquet_schema,
                 FileWriteOptions {
                     version: options.version,
@@ -65,31 +131,108 @@ impl<W: Write> FileWriter<W> {
                 },
                 created_by,
             ),
+            shared,
             schema,
             options,
+            num_row_groups: 0,
+            bloom_filters: vec![],
         })
     }
 
     /// Writes a row group to the file.
     pub fn write(&mut self, row_group: RowGroupIter<'_, Error>) -> Result<()> {
-        Ok(self.writer.write(row_group)?)
+        self.writer.write(row_group)?;
+        self.num_row_groups += 1;
+        Ok(())
+    }
+
+    /// Adds a split block bloom filter of the (leaf) `column` of the row group that was written
+    /// last. The bloom filters are written before the footer by [`Self::end`].
+    pub fn add_bloom_filter(&mut self, column: usize, bitset: Vec<u8>) -> Result<()> {
+        if self.num_row_groups == 0 {
+            return Err(Error::InvalidArgumentError(
+                "a bloom filter must follow the row group it belongs to".to_string(),
+            ));
+        }
+        self.bloom_filters
+            .push((self.num_row_groups - 1, column, bitset));
+        Ok(())
     }
 
     /// Writes the footer of the parquet file. Returns the total size of the file.
     pub fn end(&mut self, key_value_metadata: Option<Vec<KeyValue>>) -> Result<u64> {
         let key_value_metadata = add_arrow_schema(&self.schema, key_value_metadata);
-        Ok(self.writer.end(key_value_metadata)?)
+        if self.bloom_filters.is_empty() {
+            return Ok(self.writer.end(key_value_metadata)?);
+        }
+
+        // The footer is held back, so that the bloom filters can be written in front of it
+        // and their offsets can be recorded in the metadata.
+        self.shared.hold();
+        let size = self.writer.end(key_value_metadata)?;
+        let tail = self.shared.take_held();
+        let footer_len = tail.len() - 8;
+        let metadata_len =
+            u32::from_le_bytes(tail[footer_len..footer_len + 4].try_into().unwrap()) as usize;
+        let metadata_start = footer_len - metadata_len;
+        let mut metadata = ThriftFileMetaData::read_from_in_protocol(
+            &mut TCompactInputProtocol::new(&tail[metadata_start..footer_len], metadata_len),
+        )
+        .map_err(parquet2::error::Error::from)?;
+
+        // the tail starts with the page indexes, which stay where they are
+        let mut out = tail[..metadata_start].to_vec();
+        let mut offset = size - tail.len() as u64 + metadata_start as u64;
+        for (row_group, column, bitset) in std::mem::take(&mut self.bloom_filters) {
+            let header = BloomFilterHeader {
+                num_bytes: bitset.len() as i32,
+                algorithm: BloomFilterAlgorithm::BLOCK(SplitBlockAlgorithm {}),
+                hash: BloomFilterHash::XXHASH(XxHash {}),
+                compression: BloomFilterCompression::UNCOMPRESSED(Uncompressed {}),
+            };
+            let start = out.len();
+            header
+                .write_to_out_protocol(&mut TCompactOutputProtocol::new(&mut out))
+                .map_err(parquet2::error::Error::from)?;
+            out.extend_from_slice(&bitset);
+
+            if let Some(meta_data) = metadata
+                .row_groups
+                .get_mut(row_group)
+                .and_then(|rg| rg.columns.get_mut(column))
+                .and_then(|c| c.meta_data.as_mut())
+            {
+                meta_data.bloom_filter_offset = Some(offset as i64);
+            }
+            offset += (out.len() - start) as u64;
+        }
+
+        let start = out.len();
+        metadata
+            .write_to_out_protocol(&mut TCompactOutputProtocol::new(&mut out))
+            .map_err(parquet2::error::Error::from)?;
+        let metadata_len = (out.len() - start) as u32;
+        out.extend_from_slice(&metadata_len.to_le_bytes());
+        out.extend_from_slice(&tail[footer_len + 4..]);
+
+        self.shared.write_all(&out)?;
+        Ok(size - tail.len() as u64 + out.len() as u64)
     }
 
     /// Consumes this writer and returns the inner writer
     pub fn into_inner(self) -> W {
-        self.writer.into_inner()
+        drop(self.writer.into_inner());
+        self.shared.into_inner()
     }
 
     /// Returns the underlying writer and [`ThriftFileMetaData`]
+    ///
+    /// The metadata doesn't include the offsets of the bloom filters.
     /// # Panics
     /// This function panics if [`Self::end`] has not yet been called
     pub fn into_inner_and_metadata(self) -> (W, ThriftFileMetaData) {
-        self.writer.into_inner_and_metadata()
+        let (writer, metadata) = self.writer.into_inner_and_metadata();
+        drop(writer);
+        (self.shared.into_inner(), metadata)
     }
 }
@@ -131,6 +131,10 @@ pub fn can_encode(data_type: &DataType, encoding: Encoding) -> bool {
                 Encoding::DeltaLengthByteArray,
                 DataType::Binary | DataType::LargeBinary | DataType::Utf8 | DataType::LargeUtf8,
             )
+            | (
+                Encoding::ByteStreamSplit,
+                DataType::Float32 | DataType::Float64
+            )
             | (Encoding::RleDictionary, DataType::Dictionary(_, _, _))
             | (Encoding::PlainDictionary, DataType::Dictionary(_, _, _))
             | (
@@ -363,15 +367,17 @@ pub fn array_to_page_simple(
             type_,
             encoding,
         ),
-        DataType::Float32 => primitive::array_to_page_plain::<f32, f32>(
+        DataType::Float32 => primitive::array_to_page_float::<f32, f32>(
             array.as_any().downcast_ref().unwrap(),
             options,
             type_,
+            encoding,
         ),
-        DataType::Float64 => primitive::array_to_page_plain::<f64, f64>(
+        DataType::Float64 => primitive::array_to_page_float::<f64, f64>(
             array.as_any().downcast_ref().unwrap(),
             options,
             type_,
+            encoding,
         ),
         DataType::Utf8 => utf8::array_to_page::<i32>(
             array.as_any().downcast_ref().unwrap(),
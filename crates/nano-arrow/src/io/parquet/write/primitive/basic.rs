@@ -74,6 +74,36 @@ where
     buffer
 }
 
+pub(crate) fn encode_byte_stream_split<T, P>(
+    array: &PrimitiveArray<T>,
+    is_optional: bool,
+    mut buffer: Vec<u8>,
+) -> Vec<u8>
+where
+    T: NativeType,
+    P: ParquetNativeType,
+    T: num_traits::AsPrimitive<P>,
+{
+    let values = if is_optional {
+        array.iter().flatten().map(|x| x.as_()).collect::<Vec<P>>()
+    } else {
+        array.values().iter().map(|x| x.as_()).collect::<Vec<P>>()
+    };
+    let size = std::mem::size_of::<P>();
+    let n = values.len();
+    let start = buffer.len();
+    buffer.resize(start + size * n, 0);
+
+    // byte `k` of value `i` is written to stream `k` at position `i`
+    let out = &mut buffer[start..];
+    for (i, value) in values.iter().enumerate() {
+        for (k, byte) in value.to_le_bytes().as_ref().iter().enumerate() {
+            out[k * n + i] = *byte;
+        }
+    }
+    buffer
+}
+
 pub fn array_to_page_plain<T, P>(
     array: &PrimitiveArray<T>,
     options: WriteOptions,
@@ -106,6 +136,26 @@ where
     }
 }
 
+pub fn array_to_page_float<T, P>(
+    array: &PrimitiveArray<T>,
+    options: WriteOptions,
+    type_: PrimitiveType,
+    encoding: Encoding,
+) -> Result<DataPage, Error>
+where
+    T: NativeType,
+    P: ParquetNativeType,
+    T: num_traits::AsPrimitive<P>,
+{
+    match encoding {
+        Encoding::ByteStreamSplit => {
+            array_to_page(array, options, type_, encoding, encode_byte_stream_split)
+        },
+        Encoding::Plain => array_to_page(array, options, type_, encoding, encode_plain),
+        other => Err(Error::nyi(format!("Encoding float as {other:?}"))),
+    }
+}
+
 pub fn array_to_page<T, P, F: Fn(&PrimitiveArray<T>, bool, Vec<u8>) -> Vec<u8>>(
     array: &PrimitiveArray<T>,
     options: WriteOptions,
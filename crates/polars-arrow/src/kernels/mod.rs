@@ -18,6 +18,7 @@ pub mod set;
 pub mod sort_partition;
 #[cfg(feature = "performant")]
 pub mod sorted_join;
+pub mod stable;
 #[cfg(feature = "strings")]
 pub mod string;
 pub mod take_agg;
@@ -79,6 +79,14 @@ fn det_offsets_center(i: Idx, window_size: WindowSize, len: Len) -> (usize, usiz
     )
 }
 
+/// The weights of the values `start..end` in the window of `idx`. The windows at the edges
+/// hold fewer values than there are weights, so the weights are aligned with the full window.
+fn truncated_weights<W>(weights: &[W], idx: Idx, start: Start, end: End, center: bool) -> &[W] {
+    let right_window = if center { (weights.len() + 1) / 2 } else { 1 };
+    let offset = start + weights.len() - (idx + right_window);
+    &weights[offset..offset + end - start]
+}
+
 fn create_validity<Fo>(
     min_periods: usize,
     len: usize,
@@ -150,3 +158,169 @@ pub struct RollingQuantileParams {
     pub prob: f64,
     pub interpol: QuantileInterpolOptions,
 }
+
+/// Defaults to the biased skewness.
+#[derive(Clone, Copy, Debug)]
+pub struct RollingSkewParams {
+    pub bias: bool,
+}
+
+impl Default for RollingSkewParams {
+    fn default() -> Self {
+        Self { bias: true }
+    }
+}
+
+/// Defaults to the biased kurtosis of Fisher.
+#[derive(Clone, Copy, Debug)]
+pub struct RollingKurtosisParams {
+    pub fisher: bool,
+    pub bias: bool,
+}
+
+impl Default for RollingKurtosisParams {
+    fn default() -> Self {
+        Self {
+            fisher: true,
+            bias: true,
+        }
+    }
+}
+
+/// The running power sums of the values in a window, from which the central moments follow.
+///
+/// The values are shifted by the mean of the window they are first computed for. Without the
+/// shift, the power sums of values far from zero lose too much precision for the higher moments.
+#[derive(Clone, Copy)]
+struct MomentSums<T> {
+    shift: T,
+    n: usize,
+    s1: T,
+    s2: T,
+    s3: T,
+    s4: T,
+}
+
+impl<T: Float> MomentSums<T> {
+    fn compute<I>(values: I) -> Self
+    where
+        I: Iterator<Item = T> + Clone,
+    {
+        let (n, sum) = values
+            .clone()
+            .fold((0usize, T::zero()), |(n, sum), v| (n + 1, sum + v));
+        let shift = if n == 0 {
+            T::zero()
+        } else {
+            sum / NumCast::from(n).unwrap()
+        };
+        let mut out = Self {
+            shift,
+            n: 0,
+            s1: T::zero(),
+            s2: T::zero(),
+            s3: T::zero(),
+            s4: T::zero(),
+        };
+        values.for_each(|v| out.add(v));
+        out
+    }
+
+    #[inline]
+    fn add(&mut self, v: T) {
+        let d = v - self.shift;
+        let d2 = d * d;
+        self.n += 1;
+        self.s1 = self.s1 + d;
+        self.s2 = self.s2 + d2;
+        self.s3 = self.s3 + d2 * d;
+        self.s4 = self.s4 + d2 * d2;
+    }
+
+    #[inline]
+    fn remove(&mut self, v: T) {
+        let d = v - self.shift;
+        let d2 = d * d;
+        self.n -= 1;
+        self.s1 = self.s1 - d;
+        self.s2 = self.s2 - d2;
+        self.s3 = self.s3 - d2 * d;
+        self.s4 = self.s4 - d2 * d2;
+    }
+
+    /// The number of values and their biased central moments `(m2, m3, m4)`.
+    fn central_moments(&self) -> Option<(T, T, T, T)> {
+        if self.n == 0 {
+            return None;
+        }
+        let n: T = NumCast::from(self.n).unwrap();
+        let [two, three, four, six]: [T; 4] = [2, 3, 4, 6].map(|c| NumCast::from(c).unwrap());
+        // the mean and raw moments of the shifted values
+        let mean = self.s1 / n;
+        let mean2 = mean * mean;
+        let (r2, r3, r4) = (self.s2 / n, self.s3 / n, self.s4 / n);
+        let m2 = r2 - mean2;
+        let m3 = r3 - three * mean * r2 + two * mean2 * mean;
+        let m4 = r4 - four * mean * r3 + six * mean2 * r2 - three * mean2 * mean2;
+        Some((n, m2, m3, m4))
+    }
+}
+
+// see `Series::skew`
+fn skew_from_moments<T: Float>((n, m2, m3, _): (T, T, T, T), bias: bool) -> T {
+    let one = T::one();
+    let two = one + one;
+    let out = m3 / m2.powf(NumCast::from(1.5).unwrap());
+    if bias {
+        out
+    } else {
+        ((n - one) * n).sqrt() / (n - two) * out
+    }
+}
+
+// see `Series::kurtosis`
+fn kurtosis_from_moments<T: Float>((n, m2, _, m4): (T, T, T, T), fisher: bool, bias: bool) -> T {
+    let one = T::one();
+    let two = one + one;
+    let three = two + one;
+    let out = if bias {
+        m4 / (m2 * m2)
+    } else {
+        three
+            + one / (n - two) / (n - three)
+                * ((n * n - one) * m4 / (m2 * m2) - three * (n - one) * (n - one))
+    };
+    if fisher {
+        out - three
+    } else {
+        out
+    }
+}
+
+/// The options of a window of a fixed number of values, shared by all rolling kernels.
+#[derive(Clone)]
+pub struct RollingOptionsFixedWindow {
+    /// The length of the window.
+    pub window_size: usize,
+    /// Amount of elements in the window that should be filled before computing a result.
+    pub min_periods: usize,
+    /// An optional slice with the same length as the window that will be multiplied
+    ///              elementwise with the values in the window.
+    pub weights: Option<Vec<f64>>,
+    /// Set the labels at the center of the window.
+    pub center: bool,
+    /// Optional parameters for the rolling function, e.g. [`RollingVarParams`].
+    pub fn_params: DynArgs,
+}
+
+impl Default for RollingOptionsFixedWindow {
+    fn default() -> Self {
+        RollingOptionsFixedWindow {
+            window_size: 3,
+            min_periods: 1,
+            weights: None,
+            center: false,
+            fn_params: None,
+        }
+    }
+}
@@ -25,17 +25,17 @@ impl<
     }
 }
 
-pub fn rolling_mean<T>(
-    values: &[T],
-    window_size: usize,
-    min_periods: usize,
-    center: bool,
-    weights: Option<&[f64]>,
-    _params: DynArgs,
-) -> PolarsResult<ArrayRef>
+pub fn rolling_mean<T>(values: &[T], options: &RollingOptionsFixedWindow) -> PolarsResult<ArrayRef>
 where
     T: NativeType + Float + std::iter::Sum<T> + SubAssign + AddAssign + IsFloat,
 {
+    let RollingOptionsFixedWindow {
+        window_size,
+        min_periods,
+        center,
+        ..
+    } = *options;
+    let weights = options.weights.as_deref();
     let offset_fn = match center {
         true => det_offsets_center,
         false => det_offsets,
@@ -1,5 +1,4 @@
-use no_nulls;
-use no_nulls::{rolling_apply_agg_window, RollingAggWindowNoNulls};
+use no_nulls::{self, rolling_apply_agg_window, RollingAggWindowNoNulls};
 
 use super::*;
 
@@ -237,15 +236,18 @@ macro_rules! rolling_minmax_func {
     ($rolling_m:ident, $window:tt, $wtd_f:ident) => {
         pub fn $rolling_m<T>(
             values: &[T],
-            window_size: usize,
-            min_periods: usize,
-            center: bool,
-            weights: Option<&[f64]>,
-            _params: DynArgs,
+            options: &RollingOptionsFixedWindow,
         ) -> PolarsResult<ArrayRef>
         where
             T: NativeType + PartialOrd + IsFloat + Bounded + NumCast + Mul<Output = T>,
         {
+            let RollingOptionsFixedWindow {
+                window_size,
+                min_periods,
+                center,
+                ..
+            } = *options;
+            let weights = options.weights.as_deref();
             let offset_fn = match center {
                 true => det_offsets_center,
                 false => det_offsets,
@@ -292,32 +294,80 @@ mod test {
     fn test_rolling_min_max() {
         let values = &[1.0f64, 5.0, 3.0, 4.0];
 
-        let out = rolling_min(values, 2, 2, false, None, None).unwrap();
+        let out = rolling_min(
+            values,
+            &RollingOptionsFixedWindow {
+                window_size: 2,
+                min_periods: 2,
+                ..Default::default()
+            },
+        )
+        .unwrap();
         let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
         let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
         assert_eq!(out, &[None, Some(1.0), Some(3.0), Some(3.0)]);
-        let out = rolling_max(values, 2, 2, false, None, None).unwrap();
+        let out = rolling_max(
+            values,
+            &RollingOptionsFixedWindow {
+                window_size: 2,
+                min_periods: 2,
+                ..Default::default()
+            },
+        )
+        .unwrap();
         let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
         let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
         assert_eq!(out, &[None, Some(5.0), Some(5.0), Some(4.0)]);
 
-        let out = rolling_min(values, 2, 1, false, None, None).unwrap();
+        let out = rolling_min(
+            values,
+            &RollingOptionsFixedWindow {
+                window_size: 2,
+                min_periods: 1,
+                ..Default::default()
+            },
+        )
+        .unwrap();
         let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
         let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
         assert_eq!(out, &[Some(1.0), Some(1.0), Some(3.0), Some(3.0)]);
-        let out = rolling_max(values, 2, 1, false, None, None).unwrap();
+        let out = rolling_max(
+            values,
+            &RollingOptionsFixedWindow {
+                window_size: 2,
+                min_periods: 1,
+                ..Default::default()
+            },
+        )
+        .unwrap();
         let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
         let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
         assert_eq!(out, &[Some(1.0), Some(5.0), Some(5.0), Some(4.0)]);
 
-        let out = rolling_max(values, 3, 1, false, None, None).unwrap();
+        let out = rolling_max(
+            values,
+            &RollingOptionsFixedWindow {
+                window_size: 3,
+                min_periods: 1,
+                ..Default::default()
+            },
+        )
+        .unwrap();
         let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
         let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
         assert_eq!(out, &[Some(1.0), Some(5.0), Some(5.0), Some(5.0)]);
 
         // test nan handling.
         let values = &[1.0, 2.0, 3.0, f64::nan(), 5.0, 6.0, 7.0];
-        let out = rolling_min(values, 3, 3, false, None, None).unwrap();
+        let out = rolling_min(
+            values,
+            &RollingOptionsFixedWindow {
+                window_size: 3,
+                min_periods: 3,
+                ..Default::default()
+            },
+        )
+        .unwrap();
         let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
         let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
         // we cannot compare nans, so we compare the string values
@@ -337,7 +387,15 @@ mod test {
             )
         );
 
-        let out = rolling_max(values, 3, 3, false, None, None).unwrap();
+        let out = rolling_max(
+            values,
+            &RollingOptionsFixedWindow {
+                window_size: 3,
+                min_periods: 3,
+                ..Default::default()
+            },
+        )
+        .unwrap();
         let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
         let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
         assert_eq!(
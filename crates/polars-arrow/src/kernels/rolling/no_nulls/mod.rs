@@ -1,5 +1,6 @@
 mod mean;
 mod min_max;
+mod moment;
 mod quantile;
 mod sum;
 mod variance;
@@ -11,6 +12,7 @@ use arrow::datatypes::DataType;
 use arrow::types::NativeType;
 pub use mean::*;
 pub use min_max::*;
+pub use moment::*;
 use num_traits::{Float, NumCast};
 pub use quantile::*;
 #[cfg(feature = "serde")]
@@ -65,6 +67,38 @@ where
     )))
 }
 
+/// Apply the rolling aggregation `Agg` over windows given by precomputed offsets, e.g. the
+/// windows of a time column. The window of the `i`-th output value is given by the
+/// `(start, len)` pair `offsets[i]`; a window with fewer than `min_periods` values gives a null.
+pub fn rolling_apply_agg_window_by_offsets<'a, Agg, T>(
+    values: &'a [T],
+    offsets: &[(IdxSize, IdxSize)],
+    min_periods: usize,
+    params: DynArgs,
+) -> ArrayRef
+where
+    Agg: RollingAggWindowNoNulls<'a, T>,
+    T: IsFloat + NativeType,
+{
+    let min_periods = std::cmp::max(min_periods, 1);
+    let mut agg_window: Option<Agg> = None;
+    let out = offsets
+        .iter()
+        .map(|&(start, len)| {
+            let (start, end) = (start as usize, (start + len) as usize);
+            assert!(end <= values.len(), "window out of bounds");
+            if end - start < min_periods {
+                return None;
+            }
+            let agg_window =
+                agg_window.get_or_insert_with(|| Agg::new(values, start, end, params.clone()));
+            // safety: we are in bounds
+            Some(unsafe { agg_window.update(start, end) })
+        })
+        .collect::<PrimitiveArray<T>>();
+    Box::new(out)
+}
+
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum QuantileInterpolOptions {
@@ -0,0 +1,219 @@
+use no_nulls::{rolling_apply_agg_window, RollingAggWindowNoNulls};
+use polars_error::polars_ensure;
+
+use super::*;
+
+struct MomentWindow<'a, T> {
+    slice: &'a [T],
+    sums: MomentSums<T>,
+    last_start: usize,
+    last_end: usize,
+    // if we don't recompute every 'n' iterations
+    // we get a accumulated error/drift
+    last_recompute: u8,
+}
+
+impl<'a, T: NativeType + Float> MomentWindow<'a, T> {
+    fn new(slice: &'a [T], start: usize, end: usize) -> Self {
+        Self {
+            slice,
+            sums: MomentSums::compute(slice[start..end].iter().copied()),
+            last_start: start,
+            last_end: end,
+            last_recompute: 0,
+        }
+    }
+
+    /// # Safety
+    /// `start` and `end` must be in bounds of `slice`
+    unsafe fn update(&mut self, start: usize, end: usize) -> (T, T, T, T) {
+        // if we exceed the end, we have a completely new window
+        // so we recompute
+        let recompute = if start >= self.last_end || self.last_recompute > 128 {
+            self.last_recompute = 0;
+            true
+        } else {
+            self.last_recompute += 1;
+            // remove elements that should leave the window
+            let mut recompute = false;
+            for idx in self.last_start..start {
+                let leaving_value = *self.slice.get_unchecked(idx);
+                // a nan or infinity can't be subtracted from the sums again
+                if !leaving_value.is_finite() {
+                    recompute = true;
+                    break;
+                }
+                self.sums.remove(leaving_value);
+            }
+            recompute
+        };
+        self.last_start = start;
+
+        if recompute {
+            self.sums = MomentSums::compute(self.slice.get_unchecked(start..end).iter().copied());
+        } else {
+            for idx in self.last_end..end {
+                self.sums.add(*self.slice.get_unchecked(idx));
+            }
+        }
+        self.last_end = end;
+        // the window is never empty
+        self.sums.central_moments().unwrap()
+    }
+}
+
+pub struct SkewWindow<'a, T> {
+    moments: MomentWindow<'a, T>,
+    bias: bool,
+}
+
+impl<'a, T: NativeType + Float> RollingAggWindowNoNulls<'a, T> for SkewWindow<'a, T> {
+    fn new(slice: &'a [T], start: usize, end: usize, params: DynArgs) -> Self {
+        let params = params.map_or_else(Default::default, |pars| {
+            *pars.downcast_ref::<RollingSkewParams>().unwrap()
+        });
+        Self {
+            moments: MomentWindow::new(slice, start, end),
+            bias: params.bias,
+        }
+    }
+
+    unsafe fn update(&mut self, start: usize, end: usize) -> T {
+        skew_from_moments(self.moments.update(start, end), self.bias)
+    }
+}
+
+pub struct KurtosisWindow<'a, T> {
+    moments: MomentWindow<'a, T>,
+    params: RollingKurtosisParams,
+}
+
+impl<'a, T: NativeType + Float> RollingAggWindowNoNulls<'a, T> for KurtosisWindow<'a, T> {
+    fn new(slice: &'a [T], start: usize, end: usize, params: DynArgs) -> Self {
+        Self {
+            moments: MomentWindow::new(slice, start, end),
+            params: params.map_or_else(Default::default, |pars| {
+                *pars.downcast_ref::<RollingKurtosisParams>().unwrap()
+            }),
+        }
+    }
+
+    unsafe fn update(&mut self, start: usize, end: usize) -> T {
+        let RollingKurtosisParams { fisher, bias } = self.params;
+        kurtosis_from_moments(self.moments.update(start, end), fisher, bias)
+    }
+}
+
+fn rolling_moment<'a, Agg, T>(
+    values: &'a [T],
+    options: &RollingOptionsFixedWindow,
+    name: &str,
+) -> PolarsResult<ArrayRef>
+where
+    Agg: RollingAggWindowNoNulls<'a, T>,
+    T: NativeType + IsFloat + Debug,
+{
+    polars_ensure!(
+        options.weights.is_none(),
+        InvalidOperation: "weights are not supported by the rolling {}", name
+    );
+    let offset_fn = match options.center {
+        true => det_offsets_center,
+        false => det_offsets,
+    };
+    rolling_apply_agg_window::<Agg, _, _>(
+        values,
+        options.window_size,
+        options.min_periods,
+        offset_fn,
+        options.fn_params.clone(),
+    )
+}
+
+/// The skewness of the values in the window, which takes optional [`RollingSkewParams`].
+pub fn rolling_skew<T>(values: &[T], options: &RollingOptionsFixedWindow) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + Float + IsFloat + Debug,
+{
+    rolling_moment::<SkewWindow<_>, _>(values, options, "skew")
+}
+
+/// The kurtosis of the values in the window, which takes optional [`RollingKurtosisParams`].
+pub fn rolling_kurtosis<T>(
+    values: &[T],
+    options: &RollingOptionsFixedWindow,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + Float + IsFloat + Debug,
+{
+    rolling_moment::<KurtosisWindow<_>, _>(values, options, "kurtosis")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn to_vec(out: ArrayRef) -> Vec<Option<f64>> {
+        let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+        out.into_iter().map(|v| v.copied()).collect()
+    }
+
+    #[test]
+    fn test_rolling_skew_kurtosis() {
+        let values = &[1.0f64, 2.0, 4.0, 8.0, 16.0];
+        let options = RollingOptionsFixedWindow {
+            window_size: 4,
+            min_periods: 4,
+            ..Default::default()
+        };
+
+        // the values of scipy.stats.skew and scipy.stats.kurtosis
+        let out = to_vec(rolling_skew(values, &options).unwrap());
+        assert_eq!(out[..3], [None, None, None]);
+        assert!((out[3].unwrap() - 0.6568077344996993).abs() < 1e-9);
+        assert!((out[4].unwrap() - 0.6568077344996993).abs() < 1e-9);
+
+        let out = to_vec(rolling_kurtosis(values, &options).unwrap());
+        assert!((out[3].unwrap() + 1.0989792060491494).abs() < 1e-9);
+
+        let options = RollingOptionsFixedWindow {
+            fn_params: Some(Arc::new(RollingKurtosisParams {
+                fisher: false,
+                bias: true,
+            })),
+            ..options
+        };
+        let out = to_vec(rolling_kurtosis(values, &options).unwrap());
+        assert!((out[4].unwrap() - 1.9010207939508506).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_rolling_skew_incremental() {
+        // large values with a small spread, and a nan that leaves the window again
+        let mut values = (0..500)
+            .map(|i| 1e6 + ((i * 7919) % 101) as f64 / 7.0)
+            .collect::<Vec<_>>();
+        values[200] = f64::NAN;
+        let options = RollingOptionsFixedWindow {
+            window_size: 20,
+            min_periods: 20,
+            ..Default::default()
+        };
+        let out = to_vec(rolling_skew(&values, &options).unwrap());
+
+        for (i, v) in out.iter().enumerate().skip(19) {
+            let window = &values[i + 1 - 20..i + 1];
+            let expected = skew_from_moments(
+                MomentSums::compute(window.iter().copied())
+                    .central_moments()
+                    .unwrap(),
+                true,
+            );
+            if expected.is_nan() {
+                assert!(v.unwrap().is_nan());
+            } else {
+                assert!((v.unwrap() - expected).abs() < 1e-6, "window ending at {i}");
+            }
+        }
+    }
+}
@@ -94,11 +94,7 @@ impl<
 
 pub fn rolling_quantile<T>(
     values: &[T],
-    window_size: usize,
-    min_periods: usize,
-    center: bool,
-    weights: Option<&[f64]>,
-    params: DynArgs,
+    options: &RollingOptionsFixedWindow,
 ) -> PolarsResult<ArrayRef>
 where
     T: NativeType
@@ -114,6 +110,14 @@ where
         + PartialOrd
         + Sub<Output = T>,
 {
+    let RollingOptionsFixedWindow {
+        window_size,
+        min_periods,
+        center,
+        ..
+    } = *options;
+    let weights = options.weights.as_deref();
+    let params = options.fn_params.clone();
     let offset_fn = match center {
         true => det_offsets_center,
         false => det_offsets,
@@ -127,22 +131,18 @@ where
             params,
         ),
         Some(weights) => {
-            let wsum = weights.iter().sum();
             polars_ensure!(
-                wsum != 0.0,
+                weights.iter().sum::<f64>() != 0.0,
                 ComputeError: "Weighted quantile is undefined if weights sum to 0"
             );
-            let params = params.unwrap();
-            let params = params.downcast_ref::<RollingQuantileParams>().unwrap();
             Ok(rolling_apply_weighted_quantile(
                 values,
-                params.prob,
-                params.interpol,
+                None,
                 window_size,
                 min_periods,
-                offset_fn,
+                center,
                 weights,
-                wsum,
+                params,
             ))
         },
     }
@@ -195,19 +195,19 @@ where
     }
 }
 
-#[allow(clippy::too_many_arguments)]
-fn rolling_apply_weighted_quantile<T, Fo>(
+/// Apply a rolling weighted quantile, where `weights` holds the weight of every position in
+/// the window. The null values (if `validity` is given) and the values with a zero weight are
+/// skipped, and a window without any weight left is null.
+pub(crate) fn rolling_apply_weighted_quantile<T>(
     values: &[T],
-    p: f64,
-    interpolation: QuantileInterpolOptions,
+    validity: Option<&Bitmap>,
     window_size: usize,
     min_periods: usize,
-    det_offsets_fn: Fo,
+    center: bool,
     weights: &[f64],
-    wsum: f64,
+    params: DynArgs,
 ) -> ArrayRef
 where
-    Fo: Fn(Idx, WindowSize, Len) -> (Start, End),
     T: Debug
         + NativeType
         + Mul<Output = T>
@@ -219,32 +219,42 @@ where
         + PartialOrd,
 {
     assert_eq!(weights.len(), window_size);
-    // Keep nonzero weights and their indices to know which values we need each iteration.
-    let nz_idx_wts: Vec<_> = weights.iter().enumerate().filter(|x| x.1 != &0.0).collect();
-    let mut buf = vec![(T::zero(), 0.0); nz_idx_wts.len()];
+    let params = params.unwrap();
+    let params = params.downcast_ref::<RollingQuantileParams>().unwrap();
+    let offset_fn = match center {
+        true => det_offsets_center,
+        false => det_offsets,
+    };
+    let is_valid = |i: usize| validity.map_or(true, |validity| validity.get_bit(i));
+
+    let mut buf = Vec::with_capacity(window_size);
     let len = values.len();
-    let out = (0..len)
+    let out: PrimitiveArray<T> = (0..len)
         .map(|idx| {
-            // Don't need end. Window size is constant and we computed offsets from start above.
-            let (start, _) = det_offsets_fn(idx, window_size, len);
+            let (start, end) = offset_fn(idx, window_size, len);
+            let weights = truncated_weights(weights, idx, start, end, center);
 
-            // Sorting is not ideal, see https://github.com/tobiasschoch/wquantile for something faster
-            unsafe {
-                buf.iter_mut()
-                    .zip(nz_idx_wts.iter())
-                    .for_each(|(b, (i, w))| *b = (*values.get_unchecked(i + start), **w));
+            buf.clear();
+            let mut n_valid = 0;
+            for (i, &w) in (start..end).zip(weights) {
+                if is_valid(i) {
+                    n_valid += 1;
+                    if w != 0.0 {
+                        // safety: we are in bounds
+                        buf.push((unsafe { *values.get_unchecked(i) }, w));
+                    }
+                }
+            }
+            let wsum: f64 = buf.iter().map(|(_, w)| w).sum();
+            if n_valid < min_periods || wsum == 0.0 {
+                return None;
             }
-            buf.sort_unstable_by(|&a, &b| compare_fn_nan_max(&a.0, &b.0));
-            compute_wq(&buf, p, wsum, interpolation)
+            // Sorting is not ideal, see https://github.com/tobiasschoch/wquantile for something faster
+            buf.sort_unstable_by(|a, b| compare_fn_nan_max(&a.0, &b.0));
+            Some(compute_wq(&buf, params.prob, wsum, params.interpol))
         })
-        .collect_trusted::<Vec<T>>();
-
-    let validity = create_validity(min_periods, len, window_size, det_offsets_fn);
-    Box::new(PrimitiveArray::new(
-        T::PRIMITIVE.into(),
-        out.into(),
-        validity.map(|b| b.into()),
-    ))
+        .collect_trusted();
+    Box::new(out)
 }
 
 #[cfg(test)]
@@ -259,32 +269,106 @@ mod test {
             prob: 0.5,
             interpol: Linear,
         }) as Arc<dyn Any + Send + Sync>);
-        let out = rolling_quantile(values, 2, 2, false, None, med_pars.clone()).unwrap();
+        let out = rolling_quantile(
+            values,
+            &RollingOptionsFixedWindow {
+                window_size: 2,
+                min_periods: 2,
+                fn_params: med_pars.clone(),
+                ..Default::default()
+            },
+        )
+        .unwrap();
         let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
         let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
         assert_eq!(out, &[None, Some(1.5), Some(2.5), Some(3.5)]);
 
-        let out = rolling_quantile(values, 2, 1, false, None, med_pars.clone()).unwrap();
+        let out = rolling_quantile(
+            values,
+            &RollingOptionsFixedWindow {
+                window_size: 2,
+                min_periods: 1,
+                fn_params: med_pars.clone(),
+                ..Default::default()
+            },
+        )
+        .unwrap();
         let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
         let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
         assert_eq!(out, &[Some(1.0), Some(1.5), Some(2.5), Some(3.5)]);
 
-        let out = rolling_quantile(values, 4, 1, false, None, med_pars.clone()).unwrap();
+        let out = rolling_quantile(
+            values,
+            &RollingOptionsFixedWindow {
+                window_size: 4,
+                min_periods: 1,
+                fn_params: med_pars.clone(),
+                ..Default::default()
+            },
+        )
+        .unwrap();
         let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
         let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
         assert_eq!(out, &[Some(1.0), Some(1.5), Some(2.0), Some(2.5)]);
 
-        let out = rolling_quantile(values, 4, 1, true, None, med_pars.clone()).unwrap();
+        let out = rolling_quantile(
+            values,
+            &RollingOptionsFixedWindow {
+                window_size: 4,
+                min_periods: 1,
+                center: true,
+                fn_params: med_pars.clone(),
+                ..Default::default()
+            },
+        )
+        .unwrap();
         let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
         let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
         assert_eq!(out, &[Some(1.5), Some(2.0), Some(2.5), Some(3.0)]);
 
-        let out = rolling_quantile(values, 4, 4, true, None, med_pars.clone()).unwrap();
+        let out = rolling_quantile(
+            values,
+            &RollingOptionsFixedWindow {
+                window_size: 4,
+                min_periods: 4,
+                center: true,
+                fn_params: med_pars.clone(),
+                ..Default::default()
+            },
+        )
+        .unwrap();
         let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
         let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
         assert_eq!(out, &[None, None, Some(2.5), None]);
     }
 
+    #[test]
+    fn test_rolling_weighted_median_edges() {
+        let values = &[1.0, 2.0, 3.0, 4.0];
+        let med_pars = Some(Arc::new(RollingQuantileParams {
+            prob: 0.5,
+            interpol: Linear,
+        }) as Arc<dyn Any + Send + Sync>);
+
+        // the weights are aligned with the full window, so only the value at the label counts
+        for (center, weights) in [(false, vec![0.0, 0.0, 1.0]), (true, vec![0.0, 1.0, 0.0])] {
+            let out = rolling_quantile(
+                values,
+                &RollingOptionsFixedWindow {
+                    window_size: 3,
+                    min_periods: 1,
+                    weights: Some(weights),
+                    center,
+                    fn_params: med_pars.clone(),
+                },
+            )
+            .unwrap();
+            let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+            let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+            assert_eq!(out, &[Some(1.0), Some(2.0), Some(3.0), Some(4.0)]);
+        }
+    }
+
     #[test]
     fn test_rolling_quantile_limits() {
         let values = &[1.0f64, 2.0, 3.0, 4.0];
@@ -302,10 +386,27 @@ mod test {
                 prob: 0.0,
                 interpol,
             }) as Arc<dyn Any + Send + Sync>);
-            let out1 = rolling_min(values, 2, 2, false, None, None).unwrap();
+            let out1 = rolling_min(
+                values,
+                &RollingOptionsFixedWindow {
+                    window_size: 2,
+                    min_periods: 2,
+                    ..Default::default()
+                },
+            )
+            .unwrap();
             let out1 = out1.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
             let out1 = out1.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
-            let out2 = rolling_quantile(values, 2, 2, false, None, min_pars).unwrap();
+            let out2 = rolling_quantile(
+                values,
+                &RollingOptionsFixedWindow {
+                    window_size: 2,
+                    min_periods: 2,
+                    fn_params: min_pars,
+                    ..Default::default()
+                },
+            )
+            .unwrap();
             let out2 = out2.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
             let out2 = out2.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
             assert_eq!(out1, out2);
@@ -314,10 +415,27 @@ mod test {
                 prob: 1.0,
                 interpol,
             }) as Arc<dyn Any + Send + Sync>);
-            let out1 = rolling_max(values, 2, 2, false, None, None).unwrap();
+            let out1 = rolling_max(
+                values,
+                &RollingOptionsFixedWindow {
+                    window_size: 2,
+                    min_periods: 2,
+                    ..Default::default()
+                },
+            )
+            .unwrap();
             let out1 = out1.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
             let out1 = out1.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
-            let out2 = rolling_quantile(values, 2, 2, false, None, max_pars).unwrap();
+            let out2 = rolling_quantile(
+                values,
+                &RollingOptionsFixedWindow {
+                    window_size: 2,
+                    min_periods: 2,
+                    fn_params: max_pars,
+                    ..Default::default()
+                },
+            )
+            .unwrap();
             let out2 = out2.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
             let out2 = out2.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
             assert_eq!(out1, out2);
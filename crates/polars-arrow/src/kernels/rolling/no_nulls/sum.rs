@@ -1,5 +1,4 @@
-use no_nulls;
-use no_nulls::{rolling_apply_agg_window, RollingAggWindowNoNulls};
+use no_nulls::{self, rolling_apply_agg_window, RollingAggWindowNoNulls};
 
 use super::*;
 
@@ -67,17 +66,17 @@ impl<'a, T: NativeType + IsFloat + std::iter::Sum + AddAssign + SubAssign>
     }
 }
 
-pub fn rolling_sum<T>(
-    values: &[T],
-    window_size: usize,
-    min_periods: usize,
-    center: bool,
-    weights: Option<&[f64]>,
-    _params: DynArgs,
-) -> PolarsResult<ArrayRef>
+pub fn rolling_sum<T>(values: &[T], options: &RollingOptionsFixedWindow) -> PolarsResult<ArrayRef>
 where
     T: NativeType + std::iter::Sum + NumCast + Mul<Output = T> + AddAssign + SubAssign + IsFloat,
 {
+    let RollingOptionsFixedWindow {
+        window_size,
+        min_periods,
+        center,
+        ..
+    } = *options;
+    let weights = options.weights.as_deref();
     match (center, weights) {
         (true, None) => rolling_apply_agg_window::<SumWindow<_>, _, _>(
             values,
@@ -125,34 +124,84 @@ mod test {
     fn test_rolling_sum() {
         let values = &[1.0f64, 2.0, 3.0, 4.0];
 
-        let out = rolling_sum(values, 2, 2, false, None, None).unwrap();
+        let out = rolling_sum(
+            values,
+            &RollingOptionsFixedWindow {
+                window_size: 2,
+                min_periods: 2,
+                ..Default::default()
+            },
+        )
+        .unwrap();
         let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
         let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
         assert_eq!(out, &[None, Some(3.0), Some(5.0), Some(7.0)]);
 
-        let out = rolling_sum(values, 2, 1, false, None, None).unwrap();
+        let out = rolling_sum(
+            values,
+            &RollingOptionsFixedWindow {
+                window_size: 2,
+                min_periods: 1,
+                ..Default::default()
+            },
+        )
+        .unwrap();
         let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
         let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
         assert_eq!(out, &[Some(1.0), Some(3.0), Some(5.0), Some(7.0)]);
 
-        let out = rolling_sum(values, 4, 1, false, None, None).unwrap();
+        let out = rolling_sum(
+            values,
+            &RollingOptionsFixedWindow {
+                window_size: 4,
+                min_periods: 1,
+                ..Default::default()
+            },
+        )
+        .unwrap();
         let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
         let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
         assert_eq!(out, &[Some(1.0), Some(3.0), Some(6.0), Some(10.0)]);
 
-        let out = rolling_sum(values, 4, 1, true, None, None).unwrap();
+        let out = rolling_sum(
+            values,
+            &RollingOptionsFixedWindow {
+                window_size: 4,
+                min_periods: 1,
+                center: true,
+                ..Default::default()
+            },
+        )
+        .unwrap();
         let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
         let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
         assert_eq!(out, &[Some(3.0), Some(6.0), Some(10.0), Some(9.0)]);
 
-        let out = rolling_sum(values, 4, 4, true, None, None).unwrap();
+        let out = rolling_sum(
+            values,
+            &RollingOptionsFixedWindow {
+                window_size: 4,
+                min_periods: 4,
+                center: true,
+                ..Default::default()
+            },
+        )
+        .unwrap();
         let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
         let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
         assert_eq!(out, &[None, None, Some(10.0), None]);
 
         // test nan handling.
         let values = &[1.0, 2.0, 3.0, f64::nan(), 5.0, 6.0, 7.0];
-        let out = rolling_sum(values, 3, 3, false, None, None).unwrap();
+        let out = rolling_sum(
+            values,
+            &RollingOptionsFixedWindow {
+                window_size: 3,
+                min_periods: 3,
+                ..Default::default()
+            },
+        )
+        .unwrap();
         let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
         let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
 
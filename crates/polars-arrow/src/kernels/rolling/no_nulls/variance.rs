@@ -134,14 +134,7 @@ impl<
     }
 }
 
-pub fn rolling_var<T>(
-    values: &[T],
-    window_size: usize,
-    min_periods: usize,
-    center: bool,
-    weights: Option<&[f64]>,
-    params: DynArgs,
-) -> PolarsResult<ArrayRef>
+pub fn rolling_var<T>(values: &[T], options: &RollingOptionsFixedWindow) -> PolarsResult<ArrayRef>
 where
     T: NativeType
         + Float
@@ -155,6 +148,14 @@ where
         + Zero
         + Sub<Output = T>,
 {
+    let RollingOptionsFixedWindow {
+        window_size,
+        min_periods,
+        center,
+        ..
+    } = *options;
+    let weights = options.weights.as_deref();
+    let params = options.fn_params.clone();
     let offset_fn = match center {
         true => det_offsets_center,
         false => det_offsets,
@@ -197,18 +198,43 @@ mod test {
     fn test_rolling_var() {
         let values = &[1.0f64, 5.0, 3.0, 4.0];
 
-        let out = rolling_var(values, 2, 2, false, None, None).unwrap();
+        let out = rolling_var(
+            values,
+            &RollingOptionsFixedWindow {
+                window_size: 2,
+                min_periods: 2,
+                ..Default::default()
+            },
+        )
+        .unwrap();
         let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
         let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
         assert_eq!(out, &[None, Some(8.0), Some(2.0), Some(0.5)]);
 
         let testpars = Some(Arc::new(RollingVarParams { ddof: 0 }) as Arc<dyn Any + Send + Sync>);
-        let out = rolling_var(values, 2, 2, false, None, testpars).unwrap();
+        let out = rolling_var(
+            values,
+            &RollingOptionsFixedWindow {
+                window_size: 2,
+                min_periods: 2,
+                fn_params: testpars,
+                ..Default::default()
+            },
+        )
+        .unwrap();
         let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
         let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
         assert_eq!(out, &[None, Some(4.0), Some(1.0), Some(0.25)]);
 
-        let out = rolling_var(values, 2, 1, false, None, None).unwrap();
+        let out = rolling_var(
+            values,
+            &RollingOptionsFixedWindow {
+                window_size: 2,
+                min_periods: 1,
+                ..Default::default()
+            },
+        )
+        .unwrap();
         let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
         let out = out
             .into_iter()
@@ -221,7 +247,15 @@ mod test {
         );
         // test nan handling.
         let values = &[-10.0, 2.0, 3.0, f64::nan(), 5.0, 6.0, 7.0];
-        let out = rolling_var(values, 3, 3, false, None, None).unwrap();
+        let out = rolling_var(
+            values,
+            &RollingOptionsFixedWindow {
+                window_size: 3,
+                min_periods: 3,
+                ..Default::default()
+            },
+        )
+        .unwrap();
         let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
         let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
         // we cannot compare nans, so we compare the string values
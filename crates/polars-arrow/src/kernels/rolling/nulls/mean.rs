@@ -31,14 +31,7 @@ impl<
     }
 }
 
-pub fn rolling_mean<T>(
-    arr: &PrimitiveArray<T>,
-    window_size: usize,
-    min_periods: usize,
-    center: bool,
-    weights: Option<&[f64]>,
-    _params: DynArgs,
-) -> ArrayRef
+pub fn rolling_mean<T>(arr: &PrimitiveArray<T>, options: &RollingOptionsFixedWindow) -> ArrayRef
 where
     T: NativeType
         + IsFloat
@@ -48,7 +41,13 @@ where
         + NumCast
         + Div<Output = T>,
 {
-    if weights.is_some() {
+    let RollingOptionsFixedWindow {
+        window_size,
+        min_periods,
+        center,
+        ..
+    } = *options;
+    if options.weights.is_some() {
         panic!("weights not yet supported on array with null values")
     }
     if center {
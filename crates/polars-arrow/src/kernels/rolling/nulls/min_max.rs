@@ -1,6 +1,5 @@
 use arrow::bitmap::utils::{count_zeros, ZipValidityIter};
-use nulls;
-use nulls::{rolling_apply_agg_window, RollingAggWindowNulls};
+use nulls::{self, rolling_apply_agg_window, RollingAggWindowNulls};
 
 use super::*;
 
@@ -330,18 +329,17 @@ impl<'a, T: NativeType + IsFloat + PartialOrd> RollingAggWindowNulls<'a, T> for
     }
 }
 
-pub fn rolling_min<T>(
-    arr: &PrimitiveArray<T>,
-    window_size: usize,
-    min_periods: usize,
-    center: bool,
-    weights: Option<&[f64]>,
-    _params: DynArgs,
-) -> ArrayRef
+pub fn rolling_min<T>(arr: &PrimitiveArray<T>, options: &RollingOptionsFixedWindow) -> ArrayRef
 where
     T: NativeType + std::iter::Sum + Zero + AddAssign + Copy + PartialOrd + Bounded + IsFloat,
 {
-    if weights.is_some() {
+    let RollingOptionsFixedWindow {
+        window_size,
+        min_periods,
+        center,
+        ..
+    } = *options;
+    if options.weights.is_some() {
         panic!("weights not yet supported on array with null values")
     }
     if center {
@@ -403,18 +401,17 @@ impl<'a, T: NativeType + IsFloat + PartialOrd> RollingAggWindowNulls<'a, T> for
     }
 }
 
-pub fn rolling_max<T>(
-    arr: &PrimitiveArray<T>,
-    window_size: usize,
-    min_periods: usize,
-    center: bool,
-    weights: Option<&[f64]>,
-    _params: DynArgs,
-) -> ArrayRef
+pub fn rolling_max<T>(arr: &PrimitiveArray<T>, options: &RollingOptionsFixedWindow) -> ArrayRef
 where
     T: NativeType + std::iter::Sum + Zero + AddAssign + Copy + PartialOrd + Bounded + IsFloat,
 {
-    if weights.is_some() {
+    let RollingOptionsFixedWindow {
+        window_size,
+        min_periods,
+        center,
+        ..
+    } = *options;
+    if options.weights.is_some() {
         panic!("weights not yet supported on array with null values")
     }
     if center {
@@ -1,11 +1,13 @@
 mod mean;
 mod min_max;
+mod moment;
 mod quantile;
 mod sum;
 mod variance;
 
 pub use mean::*;
 pub use min_max::*;
+pub use moment::*;
 pub use quantile::*;
 pub use sum::*;
 pub use variance::*;
@@ -90,6 +92,47 @@ where
     ))
 }
 
+/// Apply the rolling aggregation `Agg` over windows given by precomputed offsets, e.g. the
+/// windows of a time column. The window of the `i`-th output value is given by the
+/// `(start, len)` pair `offsets[i]`; a window with fewer than `min_periods` non-null values
+/// gives a null.
+///
+/// # Panics
+/// Panics if `arr` has no validity.
+pub fn rolling_apply_agg_window_by_offsets<'a, Agg, T>(
+    arr: &'a PrimitiveArray<T>,
+    offsets: &[(IdxSize, IdxSize)],
+    min_periods: usize,
+    params: DynArgs,
+) -> ArrayRef
+where
+    Agg: RollingAggWindowNulls<'a, T>,
+    T: IsFloat + NativeType,
+{
+    let values = arr.values().as_slice();
+    let validity = arr.validity().as_ref().unwrap();
+    let min_periods = std::cmp::max(min_periods, 1);
+    let mut agg_window: Option<Agg> = None;
+    let out = offsets
+        .iter()
+        .map(|&(start, len)| {
+            let (start, end) = (start as usize, (start + len) as usize);
+            assert!(end <= values.len(), "window out of bounds");
+            if end - start < min_periods {
+                return None;
+            }
+            // safety: we are in bounds
+            let agg_window = agg_window.get_or_insert_with(|| unsafe {
+                Agg::new(values, validity, start, end, params.clone())
+            });
+            // safety: we are in bounds
+            let agg = unsafe { agg_window.update(start, end) };
+            agg.filter(|_| agg_window.is_valid(min_periods))
+        })
+        .collect::<PrimitiveArray<T>>();
+    Box::new(out)
+}
+
 #[cfg(test)]
 mod test {
     use arrow::array::{Array, Int32Array};
@@ -118,27 +161,64 @@ mod test {
             Some(Bitmap::from(&[true, false, true, true])),
         );
 
-        let out = rolling_sum(arr, 2, 2, false, None, None);
+        let out = rolling_sum(
+            arr,
+            &RollingOptionsFixedWindow {
+                window_size: 2,
+                min_periods: 2,
+                ..Default::default()
+            },
+        );
         let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
         let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
         assert_eq!(out, &[None, None, None, Some(7.0)]);
 
-        let out = rolling_sum(arr, 2, 1, false, None, None);
+        let out = rolling_sum(
+            arr,
+            &RollingOptionsFixedWindow {
+                window_size: 2,
+                min_periods: 1,
+                ..Default::default()
+            },
+        );
         let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
         let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
         assert_eq!(out, &[Some(1.0), Some(1.0), Some(3.0), Some(7.0)]);
 
-        let out = rolling_sum(arr, 4, 1, false, None, None);
+        let out = rolling_sum(
+            arr,
+            &RollingOptionsFixedWindow {
+                window_size: 4,
+                min_periods: 1,
+                ..Default::default()
+            },
+        );
         let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
         let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
         assert_eq!(out, &[Some(1.0), Some(1.0), Some(4.0), Some(8.0)]);
 
-        let out = rolling_sum(arr, 4, 1, true, None, None);
+        let out = rolling_sum(
+            arr,
+            &RollingOptionsFixedWindow {
+                window_size: 4,
+                min_periods: 1,
+                center: true,
+                ..Default::default()
+            },
+        );
         let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
         let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
         assert_eq!(out, &[Some(1.0), Some(4.0), Some(8.0), Some(7.0)]);
 
-        let out = rolling_sum(arr, 4, 4, true, None, None);
+        let out = rolling_sum(
+            arr,
+            &RollingOptionsFixedWindow {
+                window_size: 4,
+                min_periods: 4,
+                center: true,
+                ..Default::default()
+            },
+        );
         let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
         let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
         assert_eq!(out, &[None, None, None, None]);
@@ -149,17 +229,38 @@ mod test {
         let arr = get_null_arr();
         let arr = &arr;
 
-        let out = rolling_mean(arr, 2, 2, false, None, None);
+        let out = rolling_mean(
+            arr,
+            &RollingOptionsFixedWindow {
+                window_size: 2,
+                min_periods: 2,
+                ..Default::default()
+            },
+        );
         let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
         let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
         assert_eq!(out, &[None, None, None, Some(1.5)]);
 
-        let out = rolling_mean(arr, 2, 1, false, None, None);
+        let out = rolling_mean(
+            arr,
+            &RollingOptionsFixedWindow {
+                window_size: 2,
+                min_periods: 1,
+                ..Default::default()
+            },
+        );
         let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
         let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
         assert_eq!(out, &[Some(1.0), Some(1.0), Some(-1.0), Some(1.5)]);
 
-        let out = rolling_mean(arr, 4, 1, false, None, None);
+        let out = rolling_mean(
+            arr,
+            &RollingOptionsFixedWindow {
+                window_size: 4,
+                min_periods: 1,
+                ..Default::default()
+            },
+        );
         let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
         let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
         assert_eq!(out, &[Some(1.0), Some(1.0), Some(0.0), Some(4.0 / 3.0)]);
@@ -170,7 +271,14 @@ mod test {
         let arr = get_null_arr();
         let arr = &arr;
 
-        let out = rolling_var(arr, 3, 1, false, None, None);
+        let out = rolling_var(
+            arr,
+            &RollingOptionsFixedWindow {
+                window_size: 3,
+                min_periods: 1,
+                ..Default::default()
+            },
+        );
         let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
         let out = out
             .into_iter()
@@ -180,7 +288,15 @@ mod test {
         assert_eq!(out, &[0.0, 0.0, 2.0, 12.5]);
 
         let testpars = Some(Arc::new(RollingVarParams { ddof: 0 }) as Arc<dyn Any + Send + Sync>);
-        let out = rolling_var(arr, 3, 1, false, None, testpars.clone());
+        let out = rolling_var(
+            arr,
+            &RollingOptionsFixedWindow {
+                window_size: 3,
+                min_periods: 1,
+                fn_params: testpars.clone(),
+                ..Default::default()
+            },
+        );
         let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
         let out = out
             .into_iter()
@@ -189,7 +305,14 @@ mod test {
 
         assert_eq!(out, &[0.0, 0.0, 1.0, 6.25]);
 
-        let out = rolling_var(arr, 4, 1, false, None, None);
+        let out = rolling_var(
+            arr,
+            &RollingOptionsFixedWindow {
+                window_size: 4,
+                min_periods: 1,
+                ..Default::default()
+            },
+        );
         let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
         let out = out
             .into_iter()
@@ -197,7 +320,15 @@ mod test {
             .collect::<Vec<_>>();
         assert_eq!(out, &[0.0, 0.0, 2.0, 6.333333333333334]);
 
-        let out = rolling_var(arr, 4, 1, false, None, testpars.clone());
+        let out = rolling_var(
+            arr,
+            &RollingOptionsFixedWindow {
+                window_size: 4,
+                min_periods: 1,
+                fn_params: testpars.clone(),
+                ..Default::default()
+            },
+        );
         let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
         let out = out
             .into_iter()
@@ -214,17 +345,38 @@ mod test {
             buf,
             Some(Bitmap::from(&[true, true, true, true])),
         );
-        let out = rolling_max(arr, 4, 1, false, None, None);
+        let out = rolling_max(
+            arr,
+            &RollingOptionsFixedWindow {
+                window_size: 4,
+                min_periods: 1,
+                ..Default::default()
+            },
+        );
         let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
         let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
         assert_eq!(out, &[Some(1.0), Some(2.0), Some(3.0), Some(4.0)]);
 
-        let out = rolling_max(arr, 2, 2, false, None, None);
+        let out = rolling_max(
+            arr,
+            &RollingOptionsFixedWindow {
+                window_size: 2,
+                min_periods: 2,
+                ..Default::default()
+            },
+        );
         let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
         let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
         assert_eq!(out, &[None, Some(2.0), Some(3.0), Some(4.0)]);
 
-        let out = rolling_max(arr, 4, 4, false, None, None);
+        let out = rolling_max(
+            arr,
+            &RollingOptionsFixedWindow {
+                window_size: 4,
+                min_periods: 4,
+                ..Default::default()
+            },
+        );
         let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
         let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
         assert_eq!(out, &[None, None, None, Some(4.0)]);
@@ -235,13 +387,27 @@ mod test {
             buf,
             Some(Bitmap::from(&[true, true, true, true])),
         );
-        let out = rolling_max(arr, 2, 1, false, None, None);
+        let out = rolling_max(
+            arr,
+            &RollingOptionsFixedWindow {
+                window_size: 2,
+                min_periods: 1,
+                ..Default::default()
+            },
+        );
         let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
         let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
         assert_eq!(out, &[Some(4.0), Some(4.0), Some(3.0), Some(2.0)]);
 
-        let out =
-            super::no_nulls::rolling_max(arr.values().as_slice(), 2, 1, false, None, None).unwrap();
+        let out = super::no_nulls::rolling_max(
+            arr.values().as_slice(),
+            &RollingOptionsFixedWindow {
+                window_size: 2,
+                min_periods: 1,
+                ..Default::default()
+            },
+        )
+        .unwrap();
         let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
         let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
         assert_eq!(out, &[Some(4.0), Some(4.0), Some(3.0), Some(2.0)]);
@@ -273,4 +439,30 @@ mod test {
             &[3, 10, 10, 10, 10, 10, 9, 8, 7, 6, 5, 4, 3]
         );
     }
+
+    #[test]
+    fn test_rolling_apply_agg_window_by_offsets() {
+        let arr = get_null_arr();
+        let offsets = [(0, 1), (0, 2), (1, 2), (1, 3)];
+
+        let out = rolling_apply_agg_window_by_offsets::<SumWindow<_>, _>(&arr, &offsets, 1, None);
+        let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(out, &[Some(1.0), Some(1.0), Some(-1.0), Some(3.0)]);
+
+        let out = rolling_apply_agg_window_by_offsets::<SumWindow<_>, _>(&arr, &offsets, 2, None);
+        let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(out, &[None, None, None, Some(3.0)]);
+
+        // an empty window gives a null
+        let offsets = [(0, 1), (1, 0), (2, 2)];
+        let out = super::no_nulls::rolling_apply_agg_window_by_offsets::<
+            super::no_nulls::SumWindow<_>,
+            _,
+        >(&[1.0, 2.0, 3.0, 4.0], &offsets, 1, None);
+        let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(out, &[Some(1.0), None, Some(7.0)]);
+    }
 }
@@ -0,0 +1,229 @@
+use nulls::{rolling_apply_agg_window, RollingAggWindowNulls};
+
+use super::*;
+
+struct MomentWindow<'a, T> {
+    slice: &'a [T],
+    validity: &'a Bitmap,
+    sums: MomentSums<T>,
+    last_start: usize,
+    last_end: usize,
+    // if we don't recompute every 'n' iterations
+    // we get a accumulated error/drift
+    last_recompute: u8,
+}
+
+impl<'a, T: NativeType + Float> MomentWindow<'a, T> {
+    /// # Safety
+    /// `start` and `end` must be in bounds of `slice` and `validity`
+    unsafe fn new(slice: &'a [T], validity: &'a Bitmap, start: usize, end: usize) -> Self {
+        let mut out = Self {
+            slice,
+            validity,
+            sums: MomentSums::compute(std::iter::empty()),
+            last_start: start,
+            last_end: end,
+            last_recompute: 0,
+        };
+        out.compute_sums(start, end);
+        out
+    }
+
+    /// # Safety
+    /// `start` and `end` must be in bounds of `slice` and `validity`
+    unsafe fn compute_sums(&mut self, start: usize, end: usize) {
+        let values = (start..end)
+            .filter(|idx| self.validity.get_bit_unchecked(*idx))
+            .map(|idx| *self.slice.get_unchecked(idx));
+        self.sums = MomentSums::compute(values);
+    }
+
+    /// # Safety
+    /// `start` and `end` must be in bounds of `slice` and `validity`
+    unsafe fn update(&mut self, start: usize, end: usize) -> Option<(T, T, T, T)> {
+        // if we exceed the end, we have a completely new window
+        // so we recompute
+        let recompute = if start >= self.last_end || self.last_recompute > 128 {
+            self.last_recompute = 0;
+            true
+        } else {
+            self.last_recompute += 1;
+            // remove elements that should leave the window
+            let mut recompute = false;
+            for idx in self.last_start..start {
+                if self.validity.get_bit_unchecked(idx) {
+                    let leaving_value = *self.slice.get_unchecked(idx);
+                    // a nan or infinity can't be subtracted from the sums again
+                    if !leaving_value.is_finite() {
+                        recompute = true;
+                        break;
+                    }
+                    self.sums.remove(leaving_value);
+                }
+            }
+            recompute
+        };
+        self.last_start = start;
+
+        if recompute {
+            self.compute_sums(start, end);
+        } else {
+            for idx in self.last_end..end {
+                if self.validity.get_bit_unchecked(idx) {
+                    self.sums.add(*self.slice.get_unchecked(idx));
+                }
+            }
+        }
+        self.last_end = end;
+        self.sums.central_moments()
+    }
+
+    fn is_valid(&self, min_periods: usize) -> bool {
+        self.sums.n >= min_periods
+    }
+}
+
+pub struct SkewWindow<'a, T> {
+    moments: MomentWindow<'a, T>,
+    bias: bool,
+}
+
+impl<'a, T: NativeType + Float> RollingAggWindowNulls<'a, T> for SkewWindow<'a, T> {
+    unsafe fn new(
+        slice: &'a [T],
+        validity: &'a Bitmap,
+        start: usize,
+        end: usize,
+        params: DynArgs,
+    ) -> Self {
+        let params = params.map_or_else(Default::default, |pars| {
+            *pars.downcast_ref::<RollingSkewParams>().unwrap()
+        });
+        Self {
+            moments: MomentWindow::new(slice, validity, start, end),
+            bias: params.bias,
+        }
+    }
+
+    unsafe fn update(&mut self, start: usize, end: usize) -> Option<T> {
+        let moments = self.moments.update(start, end)?;
+        Some(skew_from_moments(moments, self.bias))
+    }
+
+    fn is_valid(&self, min_periods: usize) -> bool {
+        self.moments.is_valid(min_periods)
+    }
+}
+
+pub struct KurtosisWindow<'a, T> {
+    moments: MomentWindow<'a, T>,
+    params: RollingKurtosisParams,
+}
+
+impl<'a, T: NativeType + Float> RollingAggWindowNulls<'a, T> for KurtosisWindow<'a, T> {
+    unsafe fn new(
+        slice: &'a [T],
+        validity: &'a Bitmap,
+        start: usize,
+        end: usize,
+        params: DynArgs,
+    ) -> Self {
+        Self {
+            moments: MomentWindow::new(slice, validity, start, end),
+            params: params.map_or_else(Default::default, |pars| {
+                *pars.downcast_ref::<RollingKurtosisParams>().unwrap()
+            }),
+        }
+    }
+
+    unsafe fn update(&mut self, start: usize, end: usize) -> Option<T> {
+        let moments = self.moments.update(start, end)?;
+        let RollingKurtosisParams { fisher, bias } = self.params;
+        Some(kurtosis_from_moments(moments, fisher, bias))
+    }
+
+    fn is_valid(&self, min_periods: usize) -> bool {
+        self.moments.is_valid(min_periods)
+    }
+}
+
+fn rolling_moment<'a, Agg, T>(
+    arr: &'a PrimitiveArray<T>,
+    options: &RollingOptionsFixedWindow,
+) -> ArrayRef
+where
+    Agg: RollingAggWindowNulls<'a, T>,
+    T: NativeType + IsFloat,
+{
+    if options.weights.is_some() {
+        panic!("weights not yet supported on array with null values")
+    }
+    let offsets_fn = if options.center {
+        det_offsets_center
+    } else {
+        det_offsets
+    };
+    rolling_apply_agg_window::<Agg, _, _>(
+        arr.values().as_slice(),
+        arr.validity().as_ref().unwrap(),
+        options.window_size,
+        options.min_periods,
+        offsets_fn,
+        options.fn_params.clone(),
+    )
+}
+
+/// The skewness of the non-null values in the window, which takes optional
+/// [`RollingSkewParams`].
+pub fn rolling_skew<T>(arr: &PrimitiveArray<T>, options: &RollingOptionsFixedWindow) -> ArrayRef
+where
+    T: NativeType + Float + IsFloat,
+{
+    rolling_moment::<SkewWindow<_>, _>(arr, options)
+}
+
+/// The kurtosis of the non-null values in the window, which takes optional
+/// [`RollingKurtosisParams`].
+pub fn rolling_kurtosis<T>(arr: &PrimitiveArray<T>, options: &RollingOptionsFixedWindow) -> ArrayRef
+where
+    T: NativeType + Float + IsFloat,
+{
+    rolling_moment::<KurtosisWindow<_>, _>(arr, options)
+}
+
+#[cfg(test)]
+mod test {
+    use arrow::buffer::Buffer;
+    use arrow::datatypes::DataType;
+
+    use super::*;
+
+    #[test]
+    fn test_rolling_skew_nulls() {
+        let buf = Buffer::from(vec![1.0, 2.0, 4.0, 0.0, 16.0]);
+        let arr = &PrimitiveArray::new(
+            DataType::Float64,
+            buf,
+            Some(Bitmap::from(&[true, true, true, false, true])),
+        );
+        let options = RollingOptionsFixedWindow {
+            window_size: 5,
+            min_periods: 3,
+            ..Default::default()
+        };
+        let out = rolling_skew(arr, &options);
+        let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(out[..2], [None, None]);
+        // the null is skipped
+        assert_eq!(out[3], out[2]);
+        assert!((out[4].unwrap() - 1.0469317329009034).abs() < 1e-9);
+
+        let options = RollingOptionsFixedWindow {
+            min_periods: 5,
+            ..options
+        };
+        let out = rolling_kurtosis(arr, &options);
+        assert_eq!(out.null_count(), 5);
+    }
+}
@@ -92,14 +92,7 @@ impl<
     }
 }
 
-pub fn rolling_quantile<T>(
-    arr: &PrimitiveArray<T>,
-    window_size: usize,
-    min_periods: usize,
-    center: bool,
-    weights: Option<&[f64]>,
-    params: DynArgs,
-) -> ArrayRef
+pub fn rolling_quantile<T>(arr: &PrimitiveArray<T>, options: &RollingOptionsFixedWindow) -> ArrayRef
 where
     T: NativeType
         + IsFloat
@@ -114,8 +107,23 @@ where
         + PartialOrd
         + Sub<Output = T>,
 {
-    if weights.is_some() {
-        panic!("weights not yet supported on array with null values")
+    let RollingOptionsFixedWindow {
+        window_size,
+        min_periods,
+        center,
+        ..
+    } = *options;
+    let params = options.fn_params.clone();
+    if let Some(weights) = options.weights.as_deref() {
+        return no_nulls::rolling_apply_weighted_quantile(
+            arr.values().as_slice(),
+            arr.validity(),
+            window_size,
+            min_periods,
+            center,
+            weights,
+            params,
+        );
     }
     let offset_fn = match center {
         true => det_offsets_center,
@@ -152,27 +160,69 @@ mod test {
             interpol: QuantileInterpolOptions::Linear,
         }) as Arc<dyn Any + Send + Sync>);
 
-        let out = rolling_quantile(arr, 2, 2, false, None, med_pars.clone());
+        let out = rolling_quantile(
+            arr,
+            &RollingOptionsFixedWindow {
+                window_size: 2,
+                min_periods: 2,
+                fn_params: med_pars.clone(),
+                ..Default::default()
+            },
+        );
         let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
         let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
         assert_eq!(out, &[None, None, None, Some(3.5)]);
 
-        let out = rolling_quantile(arr, 2, 1, false, None, med_pars.clone());
+        let out = rolling_quantile(
+            arr,
+            &RollingOptionsFixedWindow {
+                window_size: 2,
+                min_periods: 1,
+                fn_params: med_pars.clone(),
+                ..Default::default()
+            },
+        );
         let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
         let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
         assert_eq!(out, &[Some(1.0), Some(1.0), Some(3.0), Some(3.5)]);
 
-        let out = rolling_quantile(arr, 4, 1, false, None, med_pars.clone());
+        let out = rolling_quantile(
+            arr,
+            &RollingOptionsFixedWindow {
+                window_size: 4,
+                min_periods: 1,
+                fn_params: med_pars.clone(),
+                ..Default::default()
+            },
+        );
         let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
         let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
         assert_eq!(out, &[Some(1.0), Some(1.0), Some(2.0), Some(3.0)]);
 
-        let out = rolling_quantile(arr, 4, 1, true, None, med_pars.clone());
+        let out = rolling_quantile(
+            arr,
+            &RollingOptionsFixedWindow {
+                window_size: 4,
+                min_periods: 1,
+                center: true,
+                fn_params: med_pars.clone(),
+                ..Default::default()
+            },
+        );
         let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
         let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
         assert_eq!(out, &[Some(1.0), Some(2.0), Some(3.0), Some(3.5)]);
 
-        let out = rolling_quantile(arr, 4, 4, true, None, med_pars.clone());
+        let out = rolling_quantile(
+            arr,
+            &RollingOptionsFixedWindow {
+                window_size: 4,
+                min_periods: 4,
+                center: true,
+                fn_params: med_pars.clone(),
+                ..Default::default()
+            },
+        );
         let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
         let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
         assert_eq!(out, &[None, None, None, None]);
@@ -201,10 +251,25 @@ mod test {
                 prob: 0.0,
                 interpol,
             }) as Arc<dyn Any + Send + Sync>);
-            let out1 = rolling_min(values, 2, 1, false, None, None);
+            let out1 = rolling_min(
+                values,
+                &RollingOptionsFixedWindow {
+                    window_size: 2,
+                    min_periods: 1,
+                    ..Default::default()
+                },
+            );
             let out1 = out1.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
             let out1 = out1.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
-            let out2 = rolling_quantile(values, 2, 1, false, None, min_pars);
+            let out2 = rolling_quantile(
+                values,
+                &RollingOptionsFixedWindow {
+                    window_size: 2,
+                    min_periods: 1,
+                    fn_params: min_pars,
+                    ..Default::default()
+                },
+            );
             let out2 = out2.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
             let out2 = out2.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
             assert_eq!(out1, out2);
@@ -213,13 +278,61 @@ mod test {
                 prob: 1.0,
                 interpol,
             }) as Arc<dyn Any + Send + Sync>);
-            let out1 = rolling_max(values, 2, 1, false, None, None);
+            let out1 = rolling_max(
+                values,
+                &RollingOptionsFixedWindow {
+                    window_size: 2,
+                    min_periods: 1,
+                    ..Default::default()
+                },
+            );
             let out1 = out1.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
             let out1 = out1.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
-            let out2 = rolling_quantile(values, 2, 1, false, None, max_pars);
+            let out2 = rolling_quantile(
+                values,
+                &RollingOptionsFixedWindow {
+                    window_size: 2,
+                    min_periods: 1,
+                    fn_params: max_pars,
+                    ..Default::default()
+                },
+            );
             let out2 = out2.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
             let out2 = out2.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
             assert_eq!(out1, out2);
         }
     }
+
+    #[test]
+    fn test_rolling_weighted_median_nulls() {
+        let buf = Buffer::from(vec![1.0, 2.0, 3.0, 4.0]);
+        let arr = &PrimitiveArray::new(
+            DataType::Float64,
+            buf,
+            Some(Bitmap::from(&[true, false, true, true])),
+        );
+        let med_pars = Some(Arc::new(RollingQuantileParams {
+            prob: 0.5,
+            interpol: QuantileInterpolOptions::Linear,
+        }) as Arc<dyn Any + Send + Sync>);
+
+        for (min_periods, expected) in [
+            (1, [Some(1.0), Some(1.0), Some(3.0), Some(3.5)]),
+            (2, [None, None, None, Some(3.5)]),
+        ] {
+            let out = rolling_quantile(
+                arr,
+                &RollingOptionsFixedWindow {
+                    window_size: 2,
+                    min_periods,
+                    weights: Some(vec![1.0, 2.0]),
+                    fn_params: med_pars.clone(),
+                    ..Default::default()
+                },
+            );
+            let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+            let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+            assert_eq!(out, expected);
+        }
+    }
 }
@@ -1,5 +1,4 @@
-use nulls;
-use nulls::{rolling_apply_agg_window, RollingAggWindowNulls};
+use nulls::{self, rolling_apply_agg_window, RollingAggWindowNulls};
 
 use super::*;
 
@@ -123,18 +122,17 @@ impl<'a, T: NativeType + IsFloat + Add<Output = T> + Sub<Output = T>> RollingAgg
     }
 }
 
-pub fn rolling_sum<T>(
-    arr: &PrimitiveArray<T>,
-    window_size: usize,
-    min_periods: usize,
-    center: bool,
-    weights: Option<&[f64]>,
-    _params: DynArgs,
-) -> ArrayRef
+pub fn rolling_sum<T>(arr: &PrimitiveArray<T>, options: &RollingOptionsFixedWindow) -> ArrayRef
 where
     T: NativeType + IsFloat + PartialOrd + Add<Output = T> + Sub<Output = T>,
 {
-    if weights.is_some() {
+    let RollingOptionsFixedWindow {
+        window_size,
+        min_periods,
+        center,
+        ..
+    } = *options;
+    if options.weights.is_some() {
         panic!("weights not yet supported on array with null values")
     }
     if center {
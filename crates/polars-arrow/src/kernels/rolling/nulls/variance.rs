@@ -1,6 +1,5 @@
 use mean::MeanWindow;
-use nulls;
-use nulls::{rolling_apply_agg_window, RollingAggWindowNulls};
+use nulls::{self, rolling_apply_agg_window, RollingAggWindowNulls};
 
 use super::*;
 
@@ -195,18 +194,18 @@ impl<
     }
 }
 
-pub fn rolling_var<T>(
-    arr: &PrimitiveArray<T>,
-    window_size: usize,
-    min_periods: usize,
-    center: bool,
-    weights: Option<&[f64]>,
-    params: DynArgs,
-) -> ArrayRef
+pub fn rolling_var<T>(arr: &PrimitiveArray<T>, options: &RollingOptionsFixedWindow) -> ArrayRef
 where
     T: NativeType + std::iter::Sum<T> + Zero + AddAssign + SubAssign + IsFloat + Float,
 {
-    if weights.is_some() {
+    let RollingOptionsFixedWindow {
+        window_size,
+        min_periods,
+        center,
+        ..
+    } = *options;
+    let params = options.fn_params.clone();
+    if options.weights.is_some() {
         panic!("weights not yet supported on array with null values")
     }
     let offsets_fn = if center {
@@ -0,0 +1,341 @@
+//! The kernels that are supported for use outside of polars.
+//!
+//! The other modules of [`kernels`](crate::kernels) are laid out for the internal use of polars
+//! and their paths and signatures change between releases. The items in this module keep
+//! their path and signature until the next major release of this crate. New kernels may be
+//! added in a minor release.
+//!
+//! All kernels operate on a single array, slice or iterator of values; chunking and the
+//! logical types are left to the caller.
+
+pub mod rolling {
+    //! Rolling window aggregations over a window of a fixed number of values.
+    //!
+    //! # Contract
+    //! * The output has the length of the input and the data type of the input, except for the
+    //!   mean, variance, skewness, kurtosis and quantile, which require float input.
+    //! * `window_size` must be at least `min_periods`. A window with fewer than `min_periods`
+    //!   (non-null) values gives a null.
+    //! * If `center` is set, the label is at the center of the window, otherwise at its end.
+    //! * `weights`, if given, must have length `window_size`. The [`nulls`] kernels only support
+    //!   weights for the quantile.
+    //! * The [`no_nulls`] kernels ignore the validity of the input, the [`nulls`] kernels require
+    //!   the input to have a validity.
+    //! * The variance takes an optional [`VarParams`], the skewness an optional [`SkewParams`],
+    //!   the kurtosis an optional [`KurtosisParams`] and the quantile requires
+    //!   [`QuantileParams`], see [`RollingOptions::with_params`].
+    //!
+    //! # Example
+    //!
+    //! ```
+    //! use polars_arrow::kernels::stable::rolling::{no_nulls, RollingOptions, VarParams};
+    //!
+    //! let options = RollingOptions::new(2);
+    //! let out = no_nulls::rolling_sum(&[1i32, 2, 3], &options).unwrap();
+    //! assert_eq!(out.len(), 3);
+    //! assert_eq!(out.null_count(), 1);
+    //!
+    //! let options = options.with_min_periods(1).with_params(VarParams::new(0));
+    //! let out = no_nulls::rolling_var(&[1.0f64, 2.0, 3.0], &options).unwrap();
+    //! assert_eq!(out.null_count(), 0);
+    //! ```
+    use std::sync::Arc;
+
+    pub use crate::kernels::rolling::no_nulls::QuantileInterpolOptions;
+    use crate::kernels::rolling::{
+        DynArgs, RollingKurtosisParams, RollingOptionsFixedWindow, RollingQuantileParams,
+        RollingSkewParams, RollingVarParams,
+    };
+
+    /// The options of a rolling window of a fixed number of values.
+    #[derive(Clone)]
+    pub struct RollingOptions(RollingOptionsFixedWindow);
+
+    impl RollingOptions {
+        /// A window of `window_size` values that gives a value once it is full and is labeled
+        /// at its end.
+        pub fn new(window_size: usize) -> Self {
+            Self(RollingOptionsFixedWindow {
+                window_size,
+                min_periods: window_size,
+                ..Default::default()
+            })
+        }
+
+        /// Give a value once the window has `min_periods` (non-null) values.
+        pub fn with_min_periods(mut self, min_periods: usize) -> Self {
+            self.0.min_periods = min_periods;
+            self
+        }
+
+        /// Multiply the values in the window elementwise with `weights`.
+        pub fn with_weights(mut self, weights: Vec<f64>) -> Self {
+            self.0.weights = Some(weights);
+            self
+        }
+
+        /// Label the window at its center instead of at its end.
+        pub fn with_center(mut self, center: bool) -> Self {
+            self.0.center = center;
+            self
+        }
+
+        /// Set the parameters of the aggregation.
+        pub fn with_params(mut self, params: impl RollingParams) -> Self {
+            self.0.fn_params = Some(params.into_dyn_args());
+            self
+        }
+
+        pub fn window_size(&self) -> usize {
+            self.0.window_size
+        }
+
+        pub fn min_periods(&self) -> usize {
+            self.0.min_periods
+        }
+
+        pub fn weights(&self) -> Option<&[f64]> {
+            self.0.weights.as_deref()
+        }
+
+        pub fn center(&self) -> bool {
+            self.0.center
+        }
+    }
+
+    mod private {
+        pub trait Sealed {
+            fn into_dyn_args(self) -> super::DynArgs;
+        }
+    }
+
+    /// The parameters of a rolling aggregation, see [`RollingOptions::with_params`]. This trait
+    /// is sealed.
+    pub trait RollingParams: private::Sealed {}
+
+    macro_rules! impl_params {
+        ($($params:ident => $inner:ident { $($field:ident),* }),* $(,)?) => {$(
+            impl private::Sealed for $params {
+                fn into_dyn_args(self) -> DynArgs {
+                    let $params { $($field),* } = self;
+                    Some(Arc::new($inner { $($field),* }))
+                }
+            }
+
+            impl RollingParams for $params {}
+        )*};
+    }
+
+    /// The parameters of the variance.
+    #[derive(Clone, Copy, Debug)]
+    pub struct VarParams {
+        ddof: u8,
+    }
+
+    impl VarParams {
+        pub fn new(ddof: u8) -> Self {
+            Self { ddof }
+        }
+
+        pub fn ddof(&self) -> u8 {
+            self.ddof
+        }
+    }
+
+    /// The parameters of the quantile.
+    #[derive(Clone, Copy, Debug)]
+    pub struct QuantileParams {
+        prob: f64,
+        interpol: QuantileInterpolOptions,
+    }
+
+    impl QuantileParams {
+        pub fn new(prob: f64, interpol: QuantileInterpolOptions) -> Self {
+            Self { prob, interpol }
+        }
+
+        pub fn prob(&self) -> f64 {
+            self.prob
+        }
+
+        pub fn interpol(&self) -> QuantileInterpolOptions {
+            self.interpol
+        }
+    }
+
+    /// The parameters of the skewness.
+    #[derive(Clone, Copy, Debug)]
+    pub struct SkewParams {
+        bias: bool,
+    }
+
+    impl SkewParams {
+        pub fn new(bias: bool) -> Self {
+            Self { bias }
+        }
+
+        pub fn bias(&self) -> bool {
+            self.bias
+        }
+    }
+
+    /// The parameters of the kurtosis.
+    #[derive(Clone, Copy, Debug)]
+    pub struct KurtosisParams {
+        fisher: bool,
+        bias: bool,
+    }
+
+    impl KurtosisParams {
+        pub fn new(fisher: bool, bias: bool) -> Self {
+            Self { fisher, bias }
+        }
+
+        pub fn fisher(&self) -> bool {
+            self.fisher
+        }
+
+        pub fn bias(&self) -> bool {
+            self.bias
+        }
+    }
+
+    impl_params!(
+        VarParams => RollingVarParams { ddof },
+        QuantileParams => RollingQuantileParams { prob, interpol },
+        SkewParams => RollingSkewParams { bias },
+        KurtosisParams => RollingKurtosisParams { fisher, bias },
+    );
+
+    macro_rules! forward_kernels {
+        ($($name:ident($input:ty) -> $output:ty where T: { $($bound:tt)+ })*) => {$(
+            pub fn $name<T>(values: $input, options: &RollingOptions) -> $output
+            where
+                T: $($bound)+
+            {
+                kernels::$name(values, &options.0)
+            }
+        )*};
+    }
+
+    pub mod no_nulls {
+        use std::fmt::Debug;
+        use std::ops::{AddAssign, Div, Mul, Sub, SubAssign};
+
+        use arrow::types::NativeType;
+        use num_traits::{Bounded, Float, NumCast, One, Zero};
+        use polars_error::PolarsResult;
+
+        use super::RollingOptions;
+        use crate::data_types::IsFloat;
+        use crate::kernels::rolling::no_nulls as kernels;
+        use crate::prelude::ArrayRef;
+
+        forward_kernels! {
+            rolling_sum(&[T]) -> PolarsResult<ArrayRef> where T: {
+                NativeType + std::iter::Sum + NumCast + Mul<Output = T> + AddAssign + SubAssign + IsFloat
+            }
+            rolling_min(&[T]) -> PolarsResult<ArrayRef> where T: {
+                NativeType + PartialOrd + IsFloat + Bounded + NumCast + Mul<Output = T>
+            }
+            rolling_max(&[T]) -> PolarsResult<ArrayRef> where T: {
+                NativeType + PartialOrd + IsFloat + Bounded + NumCast + Mul<Output = T>
+            }
+            rolling_mean(&[T]) -> PolarsResult<ArrayRef> where T: {
+                NativeType + Float + std::iter::Sum<T> + SubAssign + AddAssign + IsFloat
+            }
+            rolling_var(&[T]) -> PolarsResult<ArrayRef> where T: {
+                NativeType + Float + IsFloat + std::iter::Sum + AddAssign + SubAssign
+                    + Div<Output = T> + NumCast + One + Zero + Sub<Output = T>
+            }
+            rolling_quantile(&[T]) -> PolarsResult<ArrayRef> where T: {
+                NativeType + IsFloat + Float + std::iter::Sum + AddAssign + SubAssign
+                    + Div<Output = T> + NumCast + One + Zero + PartialOrd + Sub<Output = T>
+            }
+            rolling_skew(&[T]) -> PolarsResult<ArrayRef> where T: {
+                NativeType + Float + IsFloat + Debug
+            }
+            rolling_kurtosis(&[T]) -> PolarsResult<ArrayRef> where T: {
+                NativeType + Float + IsFloat + Debug
+            }
+        }
+    }
+
+    pub mod nulls {
+        use std::ops::{Add, AddAssign, Div, Sub, SubAssign};
+
+        use arrow::array::PrimitiveArray;
+        use arrow::types::NativeType;
+        use num_traits::{Bounded, Float, NumCast, One, Zero};
+
+        use super::RollingOptions;
+        use crate::data_types::IsFloat;
+        use crate::kernels::rolling::nulls as kernels;
+        use crate::prelude::ArrayRef;
+
+        forward_kernels! {
+            rolling_sum(&PrimitiveArray<T>) -> ArrayRef where T: {
+                NativeType + IsFloat + PartialOrd + Add<Output = T> + Sub<Output = T>
+            }
+            rolling_min(&PrimitiveArray<T>) -> ArrayRef where T: {
+                NativeType + std::iter::Sum + Zero + AddAssign + Copy + PartialOrd + Bounded + IsFloat
+            }
+            rolling_max(&PrimitiveArray<T>) -> ArrayRef where T: {
+                NativeType + std::iter::Sum + Zero + AddAssign + Copy + PartialOrd + Bounded + IsFloat
+            }
+            rolling_mean(&PrimitiveArray<T>) -> ArrayRef where T: {
+                NativeType + IsFloat + PartialOrd + Add<Output = T> + Sub<Output = T> + NumCast
+                    + Div<Output = T>
+            }
+            rolling_var(&PrimitiveArray<T>) -> ArrayRef where T: {
+                NativeType + std::iter::Sum<T> + Zero + AddAssign + SubAssign + IsFloat + Float
+            }
+            rolling_quantile(&PrimitiveArray<T>) -> ArrayRef where T: {
+                NativeType + IsFloat + Float + std::iter::Sum + AddAssign + SubAssign
+                    + Div<Output = T> + NumCast + One + Zero + PartialOrd + Sub<Output = T>
+            }
+            rolling_skew(&PrimitiveArray<T>) -> ArrayRef where T: {
+                NativeType + Float + IsFloat
+            }
+            rolling_kurtosis(&PrimitiveArray<T>) -> ArrayRef where T: {
+                NativeType + Float + IsFloat
+            }
+        }
+    }
+}
+
+pub mod ewm {
+    //! Exponentially weighted moving aggregations.
+    //!
+    //! # Contract
+    //! * The output has the length of the input. A value with fewer than `min_periods` non-null
+    //!   values up to and including it gives a null.
+    //! * `alpha` must be in `(0, 1]`.
+
+    pub use crate::kernels::ewm::{ewm_cov, ewm_mean, ewm_std, ewm_var};
+}
+
+pub mod sort_partition {
+    //! Grouping of sorted data.
+    //!
+    //! # Contract
+    //! * The input must be sorted in the given order and must not contain nulls; the nulls are
+    //!   accounted for with `first_group_offset`.
+    //! * The groups are `[first, len]` pairs, offset by `offset`.
+    //! * [`create_clean_partitions`] splits the values in at most `n + 1` partitions that don't
+    //!   split a group.
+
+    pub use crate::kernels::sort_partition::{
+        create_clean_partitions, partition_to_groups, partition_to_groups_amortized,
+    };
+}
+
+pub mod list {
+    //! Iteration over the lists of a list array.
+    //!
+    //! # Contract
+    //! * [`numeric_list_bytes_iter`] yields the bytes of the values of every list, or `None` for
+    //!   a null list. It errors if the child array is not numeric or contains nulls.
+
+    pub use crate::kernels::list_bytes_iter::numeric_list_bytes_iter;
+}
@@ -6,7 +6,10 @@ pub use crate::bitmap::mutable::MutableBitmapExtension;
 pub use crate::data_types::*;
 pub use crate::index::*;
 pub use crate::kernels::rolling::no_nulls::QuantileInterpolOptions;
-pub use crate::kernels::rolling::{DynArgs, RollingQuantileParams, RollingVarParams};
+pub use crate::kernels::rolling::{
+    DynArgs, RollingKurtosisParams, RollingOptionsFixedWindow, RollingQuantileParams,
+    RollingSkewParams, RollingVarParams,
+};
 
 pub type LargeStringArray = Utf8Array<i64>;
 pub type LargeBinaryArray = BinaryArray<i64>;
@@ -0,0 +1,92 @@
+/*
+ * C API of the polars query engine, see the documentation of the `polars-capi` crate.
+ *
+ * Functions returning an int return 0 on success and -1 on failure; the message of the last
+ * error on the calling thread is returned by `polars_last_error`.
+ *
+ * Ownership: every object written to an out pointer or returned by a `*_new` function is owned
+ * by the caller and must be released exactly once with the matching `*_destroy` function.
+ * Arguments are borrowed for the duration of the call, except for the arrays passed to
+ * `polars_dataframe_from_arrow`, which are moved. Out pointers are only written on success.
+ * A NULL argument is reported as an error and never dereferenced; `*_destroy` accepts NULL.
+ */
+#ifndef POLARS_H
+#define POLARS_H
+
+#include <stddef.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* The Arrow C data interface, see https://arrow.apache.org/docs/format/CDataInterface.html */
+#ifndef ARROW_C_DATA_INTERFACE
+#define ARROW_C_DATA_INTERFACE
+
+#include <stdint.h>
+
+#define ARROW_FLAG_DICTIONARY_ORDERED 1
+#define ARROW_FLAG_NULLABLE 2
+#define ARROW_FLAG_MAP_KEYS_SORTED 4
+
+struct ArrowSchema {
+  const char* format;
+  const char* name;
+  const char* metadata;
+  int64_t flags;
+  int64_t n_children;
+  struct ArrowSchema** children;
+  struct ArrowSchema* dictionary;
+  void (*release)(struct ArrowSchema*);
+  void* private_data;
+};
+
+struct ArrowArray {
+  int64_t length;
+  int64_t null_count;
+  int64_t offset;
+  int64_t n_buffers;
+  int64_t n_children;
+  const void** buffers;
+  struct ArrowArray** children;
+  struct ArrowArray* dictionary;
+  void (*release)(struct ArrowArray*);
+  void* private_data;
+};
+
+#endif /* ARROW_C_DATA_INTERFACE */
+
+typedef struct PolarsDataFrame PolarsDataFrame;
+typedef struct PolarsSqlContext PolarsSqlContext;
+
+/* The message of the last error on this thread, or NULL. Valid until the next error. */
+const char* polars_last_error(void);
+
+/* Create a DataFrame from `n_columns` arrays. The arrays are moved, also on failure; the schemas
+ * are not. */
+int polars_dataframe_from_arrow(const struct ArrowSchema* schemas, struct ArrowArray* arrays,
+                                size_t n_columns, PolarsDataFrame** out);
+/* Export a column as a single array. The caller releases the schema and array. */
+int polars_dataframe_column_to_arrow(const PolarsDataFrame* df, size_t index,
+                                     struct ArrowSchema* schema, struct ArrowArray* array);
+/* The number of rows and columns, 0 if `df` is NULL. */
+size_t polars_dataframe_height(const PolarsDataFrame* df);
+size_t polars_dataframe_width(const PolarsDataFrame* df);
+void polars_dataframe_destroy(PolarsDataFrame* df);
+
+PolarsSqlContext* polars_sql_context_new(void);
+/* The context keeps its own reference to the data, `df` remains owned by the caller. */
+int polars_sql_context_register(PolarsSqlContext* ctx, const char* name,
+                                const PolarsDataFrame* df);
+int polars_sql_context_execute(PolarsSqlContext* ctx, const char* query,
+                               PolarsDataFrame** out);
+void polars_sql_context_destroy(PolarsSqlContext* ctx);
+
+/* Run a logical plan serialized as JSON. */
+int polars_execute_json_plan(const char* plan, size_t len, PolarsDataFrame** out);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* POLARS_H */
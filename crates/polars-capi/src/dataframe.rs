@@ -0,0 +1,125 @@
+use std::ffi::c_int;
+
+use arrow::ffi::{self, ArrowArray, ArrowSchema};
+use polars_core::prelude::*;
+
+use crate::{check_not_null, handle_result};
+
+/// An opaque handle to a [`DataFrame`].
+pub struct PolarsDataFrame(pub(crate) DataFrame);
+
+impl PolarsDataFrame {
+    pub(crate) fn into_raw(df: DataFrame) -> *mut PolarsDataFrame {
+        Box::into_raw(Box::new(PolarsDataFrame(df)))
+    }
+
+    /// # Safety
+    /// `ptr` must be null or a valid pointer returned by this crate.
+    pub(crate) unsafe fn from_ptr<'a>(ptr: *const PolarsDataFrame) -> PolarsResult<&'a DataFrame> {
+        polars_ensure!(!ptr.is_null(), ComputeError: "the DataFrame is a null pointer");
+        Ok(&(*ptr).0)
+    }
+}
+
+/// Create a DataFrame of `n_columns` columns from Arrow arrays. The name of a column is the
+/// name of its schema.
+///
+/// Unless `arrays` is null, the arrays are always moved, also when the call fails: they are
+/// marked as released and must not be released by the caller. The schemas remain owned by the
+/// caller. On success the DataFrame is written to `out` and must be released with
+/// [`polars_dataframe_destroy`].
+///
+/// # Safety
+/// `schemas` and `arrays` must point to `n_columns` valid structs and `out` must be valid for
+/// writes.
+#[no_mangle]
+pub unsafe extern "C" fn polars_dataframe_from_arrow(
+    schemas: *const ArrowSchema,
+    arrays: *mut ArrowArray,
+    n_columns: usize,
+    out: *mut *mut PolarsDataFrame,
+) -> c_int {
+    handle_result(|| {
+        if n_columns > 0 {
+            check_not_null(arrays, "arrays")?;
+        }
+        // Take ownership of all arrays first, so that they are released when the call fails.
+        let arrays = (0..n_columns)
+            .map(|i| std::ptr::replace(arrays.add(i), ArrowArray::empty()))
+            .collect::<Vec<_>>();
+        if n_columns > 0 {
+            check_not_null(schemas, "schemas")?;
+        }
+        check_not_null(out, "out")?;
+        let columns = arrays
+            .into_iter()
+            .enumerate()
+            .map(|(i, array)| {
+                let (field, array) = ffi::import_field_and_array_from_c(&*schemas.add(i), array)?;
+                Series::try_from((field.name.as_str(), array))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let df = DataFrame::new(columns)?;
+        *out = PolarsDataFrame::into_raw(df);
+        Ok(())
+    })
+}
+
+/// Export the column at `index` of the DataFrame as a single Arrow array.
+///
+/// On success the caller owns the written schema and array and must release them with their
+/// `release` callbacks. The exported data stays valid after the DataFrame is destroyed.
+///
+/// # Safety
+/// `df` must be a valid DataFrame, `schema` and `array` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn polars_dataframe_column_to_arrow(
+    df: *const PolarsDataFrame,
+    index: usize,
+    schema: *mut ArrowSchema,
+    array: *mut ArrowArray,
+) -> c_int {
+    handle_result(|| {
+        let df = PolarsDataFrame::from_ptr(df)?;
+        check_not_null(schema, "schema")?;
+        check_not_null(array, "array")?;
+        let s = df.select_at_idx(index).ok_or_else(|| {
+            polars_err!(ComputeError: "column index {} is out of bounds for a DataFrame of width {}", index, df.width())
+        })?;
+        let s = s.rechunk();
+        let arr = s.to_arrow(0);
+        let field = ArrowField::new(s.name(), arr.data_type().clone(), true);
+        std::ptr::write(schema, ffi::export_field_to_c(&field));
+        std::ptr::write(array, ffi::export_array_to_c(arr));
+        Ok(())
+    })
+}
+
+/// The number of rows of the DataFrame, or `0` if `df` is null.
+///
+/// # Safety
+/// `df` must be null or a valid DataFrame.
+#[no_mangle]
+pub unsafe extern "C" fn polars_dataframe_height(df: *const PolarsDataFrame) -> usize {
+    PolarsDataFrame::from_ptr(df).map_or(0, |df| df.height())
+}
+
+/// The number of columns of the DataFrame, or `0` if `df` is null.
+///
+/// # Safety
+/// `df` must be null or a valid DataFrame.
+#[no_mangle]
+pub unsafe extern "C" fn polars_dataframe_width(df: *const PolarsDataFrame) -> usize {
+    PolarsDataFrame::from_ptr(df).map_or(0, |df| df.width())
+}
+
+/// Release a DataFrame. Does nothing if `df` is null.
+///
+/// # Safety
+/// `df` must be null or a valid DataFrame that is not used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn polars_dataframe_destroy(df: *mut PolarsDataFrame) {
+    if !df.is_null() {
+        drop(Box::from_raw(df));
+    }
+}
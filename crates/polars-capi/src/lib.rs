@@ -0,0 +1,94 @@
+//! A C API to embed the polars query engine in applications that are not written in Rust.
+//!
+//! Data is exchanged with the [Arrow C data interface](https://arrow.apache.org/docs/format/CDataInterface.html).
+//! A [`PolarsDataFrame`] is created from Arrow arrays, queried with SQL or a serialized
+//! logical plan, and the columns of the result are exported as Arrow arrays again. The
+//! declarations of the API are in `include/polars.h`.
+//!
+//! # Errors
+//! Functions that can fail return `0` on success and `-1` on failure. The message of the last
+//! error on the calling thread is returned by [`polars_last_error`]. Panics are caught and
+//! reported as errors, they never unwind into the caller.
+//!
+//! # Ownership
+//! Every `*_new` and every function that writes a `PolarsDataFrame**` hands out an object that
+//! is owned by the caller and must be released exactly once with the matching `*_destroy`
+//! function. An object is never released by another function: a DataFrame that is passed to
+//! [`polars_sql_context_register`] or queried remains owned by the caller, and a DataFrame
+//! written by a query is independent of its inputs.
+//!
+//! Pointers that are passed in are borrowed for the duration of the call, except for the Arrow
+//! arrays passed to [`polars_dataframe_from_arrow`], which are moved. Out pointers are only
+//! written on success; on failure they are left untouched and nothing needs to be released.
+//!
+//! A null pointer where an object is expected is reported as an error, it is never
+//! dereferenced. The `*_destroy` functions accept null and do nothing.
+mod dataframe;
+mod query;
+
+use std::cell::RefCell;
+use std::ffi::{c_char, c_int, CStr, CString};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+pub use dataframe::*;
+use polars_core::prelude::*;
+pub use query::*;
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = RefCell::new(None);
+}
+
+fn set_last_error(msg: String) {
+    // a message can't contain a nul byte in C
+    let msg = CString::new(msg.replace('\0', " ")).unwrap();
+    LAST_ERROR.with(|e| *e.borrow_mut() = Some(msg));
+}
+
+/// Run `f`, storing its error or panic as the last error of this thread.
+fn handle_result<F>(f: F) -> c_int
+where
+    F: FnOnce() -> PolarsResult<()>,
+{
+    match catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(())) => 0,
+        Ok(Err(e)) => {
+            set_last_error(e.to_string());
+            -1
+        },
+        Err(panic) => {
+            let msg = panic
+                .downcast_ref::<&str>()
+                .map(|s| s.to_string())
+                .or_else(|| panic.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic".to_string());
+            set_last_error(format!("panic in polars: {msg}"));
+            -1
+        },
+    }
+}
+
+fn check_not_null<T>(ptr: *const T, name: &str) -> PolarsResult<()> {
+    polars_ensure!(!ptr.is_null(), ComputeError: "'{}' is a null pointer", name);
+    Ok(())
+}
+
+/// # Safety
+/// `ptr` must be null or point to a nul terminated string.
+unsafe fn str_from_c<'a>(ptr: *const c_char, name: &str) -> PolarsResult<&'a str> {
+    check_not_null(ptr, name)?;
+    CStr::from_ptr(ptr)
+        .to_str()
+        .map_err(|_| polars_err!(ComputeError: "'{}' is not valid utf-8", name))
+}
+
+/// The message of the last error on the calling thread, or null if no error occurred.
+///
+/// The string is owned by polars and is valid until the next failing call on this thread.
+#[no_mangle]
+pub extern "C" fn polars_last_error() -> *const c_char {
+    LAST_ERROR.with(|e| {
+        e.borrow()
+            .as_ref()
+            .map_or(std::ptr::null(), |msg| msg.as_ptr())
+    })
+}
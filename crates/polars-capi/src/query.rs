@@ -0,0 +1,206 @@
+use std::ffi::{c_char, c_int};
+
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::SQLContext;
+
+use crate::{check_not_null, handle_result, str_from_c, PolarsDataFrame};
+
+/// An opaque handle to a [`SQLContext`].
+pub struct PolarsSqlContext(SQLContext);
+
+/// Create a SQL context without tables. It must be released with
+/// [`polars_sql_context_destroy`].
+#[no_mangle]
+pub extern "C" fn polars_sql_context_new() -> *mut PolarsSqlContext {
+    Box::into_raw(Box::new(PolarsSqlContext(SQLContext::new())))
+}
+
+/// Register a DataFrame as the table `name`, replacing a table of the same name.
+///
+/// The context keeps its own reference to the data; `df` remains owned by the caller and may be
+/// destroyed afterwards.
+///
+/// # Safety
+/// `ctx` and `df` must be valid and `name` a nul terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn polars_sql_context_register(
+    ctx: *mut PolarsSqlContext,
+    name: *const c_char,
+    df: *const PolarsDataFrame,
+) -> c_int {
+    handle_result(|| {
+        check_not_null(ctx, "ctx")?;
+        let name = str_from_c(name, "name")?;
+        let df = PolarsDataFrame::from_ptr(df)?;
+        (*ctx).0.register(name, df.clone().lazy());
+        Ok(())
+    })
+}
+
+/// Run a SQL query against the registered tables and write the result to `out`. The result
+/// must be released with [`polars_dataframe_destroy`].
+///
+/// # Safety
+/// `ctx` must be valid, `query` a nul terminated string and `out` valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn polars_sql_context_execute(
+    ctx: *mut PolarsSqlContext,
+    query: *const c_char,
+    out: *mut *mut PolarsDataFrame,
+) -> c_int {
+    handle_result(|| {
+        check_not_null(ctx, "ctx")?;
+        check_not_null(out, "out")?;
+        let query = str_from_c(query, "query")?;
+        let df = (*ctx).0.execute(query)?.collect()?;
+        *out = PolarsDataFrame::into_raw(df);
+        Ok(())
+    })
+}
+
+/// Release a SQL context. Does nothing if `ctx` is null.
+///
+/// # Safety
+/// `ctx` must be null or a valid context that is not used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn polars_sql_context_destroy(ctx: *mut PolarsSqlContext) {
+    if !ctx.is_null() {
+        drop(Box::from_raw(ctx));
+    }
+}
+
+/// Run a logical plan serialized as JSON, e.g. by `LazyFrame.serialize` in Python, and write
+/// the result to `out`. The result must be released with [`polars_dataframe_destroy`].
+///
+/// # Safety
+/// `plan` must point to `len` bytes and `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn polars_execute_json_plan(
+    plan: *const c_char,
+    len: usize,
+    out: *mut *mut PolarsDataFrame,
+) -> c_int {
+    handle_result(|| {
+        check_not_null(plan, "plan")?;
+        check_not_null(out, "out")?;
+        let json = std::slice::from_raw_parts(plan as *const u8, len);
+        let json = std::str::from_utf8(json)
+            .map_err(|_| polars_err!(ComputeError: "'plan' is not valid utf-8"))?;
+        let lp = serde_json::from_str::<LogicalPlan>(json)
+            .map_err(|e| polars_err!(ComputeError: "cannot deserialize the plan: {}", e))?;
+        let df = LazyFrame::from(lp).collect()?;
+        *out = PolarsDataFrame::into_raw(df);
+        Ok(())
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use std::ffi::{CStr, CString};
+
+    use arrow::ffi::{self, ArrowArray, ArrowSchema};
+    use polars_core::df;
+
+    use super::*;
+    use crate::*;
+
+    fn import_column(df: *const PolarsDataFrame, index: usize) -> Series {
+        let mut schema = ArrowSchema::empty();
+        let mut array = ArrowArray::empty();
+        unsafe {
+            assert_eq!(
+                polars_dataframe_column_to_arrow(df, index, &mut schema, &mut array),
+                0
+            );
+            let field = ffi::import_field_from_c(&schema).unwrap();
+            let array = ffi::import_array_from_c(array, field.data_type).unwrap();
+            Series::try_from((field.name.as_str(), array)).unwrap()
+        }
+    }
+
+    #[test]
+    fn test_c_api() -> PolarsResult<()> {
+        let input = df![
+            "a" => [1i64, 2, 3],
+            "b" => ["x", "y", "z"]
+        ]?;
+        let schemas = input
+            .get_columns()
+            .iter()
+            .map(|s| {
+                let field = ArrowField::new(s.name(), s.dtype().to_arrow(), true);
+                ffi::export_field_to_c(&field)
+            })
+            .collect::<Vec<_>>();
+        let mut arrays = input
+            .get_columns()
+            .iter()
+            .map(|s| ffi::export_array_to_c(s.to_arrow(0)))
+            .collect::<Vec<_>>();
+
+        unsafe {
+            let mut df = std::ptr::null_mut();
+            assert_eq!(
+                polars_dataframe_from_arrow(schemas.as_ptr(), arrays.as_mut_ptr(), 2, &mut df),
+                0
+            );
+            assert_eq!(polars_dataframe_height(df), 3);
+            assert_eq!(polars_dataframe_width(df), 2);
+
+            let ctx = polars_sql_context_new();
+            let name = CString::new("t").unwrap();
+            assert_eq!(polars_sql_context_register(ctx, name.as_ptr(), df), 0);
+            polars_dataframe_destroy(df);
+
+            let query = CString::new("SELECT a * 2 AS a2 FROM t WHERE b != 'y'").unwrap();
+            let mut out = std::ptr::null_mut();
+            assert_eq!(polars_sql_context_execute(ctx, query.as_ptr(), &mut out), 0);
+            assert_eq!(import_column(out, 0), Series::new("a2", [2i64, 6]));
+            polars_dataframe_destroy(out);
+
+            let query = CString::new("SELECT * FROM unknown").unwrap();
+            assert_eq!(
+                polars_sql_context_execute(ctx, query.as_ptr(), &mut out),
+                -1
+            );
+            assert!(!CStr::from_ptr(polars_last_error()).to_bytes().is_empty());
+
+            // null pointers are errors, they are never dereferenced
+            let query = CString::new("SELECT * FROM t").unwrap();
+            assert_eq!(
+                polars_sql_context_execute(ctx, query.as_ptr(), std::ptr::null_mut()),
+                -1
+            );
+            assert_eq!(
+                CStr::from_ptr(polars_last_error()).to_str().unwrap(),
+                "'out' is a null pointer"
+            );
+            assert_eq!(
+                polars_sql_context_execute(std::ptr::null_mut(), query.as_ptr(), &mut out),
+                -1
+            );
+            assert_eq!(
+                polars_dataframe_column_to_arrow(
+                    std::ptr::null(),
+                    0,
+                    &mut ArrowSchema::empty(),
+                    &mut ArrowArray::empty()
+                ),
+                -1
+            );
+            assert_eq!(polars_dataframe_height(std::ptr::null()), 0);
+            polars_sql_context_destroy(ctx);
+
+            let lf = input.lazy().filter(col("a").gt(lit(1)));
+            let plan = serde_json::to_string(&lf.logical_plan).unwrap();
+            assert_eq!(
+                polars_execute_json_plan(plan.as_ptr() as *const c_char, plan.len(), &mut out),
+                0
+            );
+            assert_eq!(import_column(out, 1), Series::new("b", ["y", "z"]));
+            polars_dataframe_destroy(out);
+        }
+        Ok(())
+    }
+}
@@ -4,14 +4,13 @@ use arrow::array::{
 };
 use arrow::types::NativeType;
 use polars_utils::unwrap::UnwrapUncheckedRelease;
-use smartstring::alias::String as SmartString;
 
 use crate::prelude::*;
 
 pub(crate) struct FixedSizeListNumericBuilder<T: NativeType> {
     inner: Option<MutableFixedSizeListArray<MutablePrimitiveArray<T>>>,
     width: usize,
-    name: SmartString,
+    name: PlSmallStr,
 }
 
 impl<T: NativeType> FixedSizeListNumericBuilder<T> {
@@ -74,7 +73,7 @@ impl<T: NativeType> FixedSizeListBuilder for FixedSizeListNumericBuilder<T> {
 
 pub(crate) struct AnonymousOwnedFixedSizeListBuilder {
     inner: fixed_size_list::AnonymousBuilder,
-    name: SmartString,
+    name: PlSmallStr,
     inner_dtype: Option<DataType>,
 }
 
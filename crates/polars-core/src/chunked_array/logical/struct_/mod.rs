@@ -7,10 +7,9 @@ use std::ops::BitOr;
 use arrow::bitmap::MutableBitmap;
 use arrow::offset::OffsetsBuffer;
 use polars_arrow::trusted_len::TrustedLenPush;
-use smartstring::alias::String as SmartString;
 
 use super::*;
-use crate::datatypes::*;
+use crate::datatypes::{PlSmallStr, *};
 use crate::utils::index_to_chunked_index;
 
 /// This is logical type [`StructChunked`] that
@@ -254,7 +253,7 @@ impl StructChunked {
         &self.field
     }
 
-    pub fn name(&self) -> &SmartString {
+    pub fn name(&self) -> &PlSmallStr {
         self.field.name()
     }
 
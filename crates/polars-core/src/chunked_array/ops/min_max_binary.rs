@@ -2,46 +2,56 @@ use crate::datatypes::PolarsNumericType;
 use crate::prelude::*;
 use crate::series::arithmetic::coerce_lhs_rhs;
 
-fn min_binary<T>(left: &ChunkedArray<T>, right: &ChunkedArray<T>) -> ChunkedArray<T>
-where
-    T: PolarsNumericType,
-    T::Native: PartialOrd,
-{
-    let op = |l: T::Native, r: T::Native| {
-        if l < r {
-            l
-        } else {
-            r
-        }
-    };
-    arity::binary_elementwise_values(left, right, op)
+#[inline]
+fn min_value<T: PartialOrd>(l: T, r: T) -> T {
+    if l < r {
+        l
+    } else {
+        r
+    }
 }
 
-fn max_binary<T>(left: &ChunkedArray<T>, right: &ChunkedArray<T>) -> ChunkedArray<T>
+#[inline]
+fn max_value<T: PartialOrd>(l: T, r: T) -> T {
+    if l > r {
+        l
+    } else {
+        r
+    }
+}
+
+fn min_max_binary<T, F>(
+    left: &ChunkedArray<T>,
+    right: &ChunkedArray<T>,
+    null_strategy: NullStrategy,
+    op: F,
+) -> ChunkedArray<T>
 where
     T: PolarsNumericType,
-    T::Native: PartialOrd,
+    F: Fn(T::Native, T::Native) -> T::Native,
 {
-    let op = |l: T::Native, r: T::Native| {
-        if l > r {
-            l
-        } else {
-            r
-        }
-    };
-    arity::binary_elementwise_values(left, right, op)
+    match null_strategy {
+        NullStrategy::Propagate => arity::binary_elementwise_values(left, right, op),
+        NullStrategy::Ignore => {
+            if left.null_count() == 0 && right.null_count() == 0 {
+                return arity::binary_elementwise_values(left, right, op);
+            }
+            arity::binary_elementwise(left, right, |l, r| match (l, r) {
+                (Some(l), Some(r)) => Some(op(l, r)),
+                (l, None) => l,
+                (None, r) => r,
+            })
+        },
+    }
 }
 
 pub(crate) fn min_max_binary_series(
     left: &Series,
     right: &Series,
     min: bool,
+    null_strategy: NullStrategy,
 ) -> PolarsResult<Series> {
-    if left.dtype().to_physical().is_numeric()
-        && left.null_count() == 0
-        && right.null_count() == 0
-        && left.len() == right.len()
-    {
+    if left.dtype().to_physical().is_numeric() && left.len() == right.len() {
         let (lhs, rhs) = coerce_lhs_rhs(left, right)?;
         let logical = lhs.dtype();
         let lhs = lhs.to_physical_repr();
@@ -52,9 +62,9 @@ pub(crate) fn min_max_binary_series(
         let b: &ChunkedArray<$T> = rhs.as_ref().as_ref().as_ref();
 
         if min {
-            min_binary(a, b).into_series().cast(logical)
+            min_max_binary(a, b, null_strategy, min_value).into_series().cast(logical)
         } else {
-            max_binary(a, b).into_series().cast(logical)
+            min_max_binary(a, b, null_strategy, max_value).into_series().cast(logical)
             }
         })
     } else {
@@ -63,6 +73,61 @@ pub(crate) fn min_max_binary_series(
         } else {
             left.gt(right)? & left.is_not_null() | right.is_null()
         };
-        left.zip_with(&mask, right)
+        let out = left.zip_with(&mask, right)?;
+        match null_strategy {
+            NullStrategy::Ignore => Ok(out),
+            NullStrategy::Propagate => {
+                let valid = left.is_not_null() & right.is_not_null();
+                out.zip_with(&valid, &Series::full_null(out.name(), 1, out.dtype()))
+            },
+        }
+    }
+}
+
+impl Series {
+    /// Get the element-wise minimum of `self` and `other`.
+    ///
+    /// With [`NullStrategy::Ignore`] a null is only returned if both values are null,
+    /// with [`NullStrategy::Propagate`] a null is returned if any of the values is null.
+    pub fn zip_min(&self, other: &Series, null_strategy: NullStrategy) -> PolarsResult<Series> {
+        min_max_binary_series(self, other, true, null_strategy)
+    }
+
+    /// Get the element-wise maximum of `self` and `other`.
+    ///
+    /// See [`Series::zip_min`] for the null semantics.
+    pub fn zip_max(&self, other: &Series, null_strategy: NullStrategy) -> PolarsResult<Series> {
+        min_max_binary_series(self, other, false, null_strategy)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_zip_min_max() -> PolarsResult<()> {
+        let a = Series::new("a", &[Some(1), None, Some(3), None]);
+        let b = Series::new("b", &[Some(2), Some(2), Some(1), None]);
+
+        let out = a.zip_min(&b, NullStrategy::Ignore)?;
+        assert_eq!(out.name(), "a");
+        assert_eq!(Vec::from(out.i32()?), &[Some(1), Some(2), Some(1), None]);
+        let out = a.zip_max(&b, NullStrategy::Ignore)?;
+        assert_eq!(Vec::from(out.i32()?), &[Some(2), Some(2), Some(3), None]);
+        let out = a.zip_min(&b, NullStrategy::Propagate)?;
+        assert_eq!(Vec::from(out.i32()?), &[Some(1), None, Some(1), None]);
+
+        // supertypes and non-numeric data
+        let c = Series::new("c", &[1.5, 1.5, 1.5, 1.5]);
+        let out = a.zip_max(&c, NullStrategy::Propagate)?;
+        assert_eq!(Vec::from(out.f64()?), &[Some(1.5), None, Some(3.0), None]);
+        let s = Series::new("s", &[Some("a"), None, Some("c")]);
+        let t = Series::new("t", &[Some("b"), Some("b"), None]);
+        let out = s.zip_max(&t, NullStrategy::Ignore)?;
+        assert_eq!(Vec::from(out.utf8()?), &[Some("b"), Some("b"), Some("c")]);
+        let out = s.zip_max(&t, NullStrategy::Propagate)?;
+        assert_eq!(Vec::from(out.utf8()?), &[Some("b"), None, None]);
+        Ok(())
     }
 }
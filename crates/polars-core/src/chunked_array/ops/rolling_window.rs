@@ -1,30 +1,4 @@
-use polars_arrow::prelude::DynArgs;
-
-#[derive(Clone)]
-pub struct RollingOptionsFixedWindow {
-    /// The length of the window.
-    pub window_size: usize,
-    /// Amount of elements in the window that should be filled before computing a result.
-    pub min_periods: usize,
-    /// An optional slice with the same length as the window that will be multiplied
-    ///              elementwise with the values in the window.
-    pub weights: Option<Vec<f64>>,
-    /// Set the labels at the center of the window.
-    pub center: bool,
-    pub fn_params: DynArgs,
-}
-
-impl Default for RollingOptionsFixedWindow {
-    fn default() -> Self {
-        RollingOptionsFixedWindow {
-            window_size: 3,
-            min_periods: 1,
-            weights: None,
-            center: false,
-            fn_params: None,
-        }
-    }
-}
+pub use polars_arrow::kernels::rolling::RollingOptionsFixedWindow;
 
 #[cfg(feature = "rolling_window")]
 mod inner_mod {
@@ -32,9 +32,24 @@ impl DateChunked {
 
     /// Convert from Date into Utf8 with the given format.
     /// See [chrono strftime/strptime](https://docs.rs/chrono/0.4.19/chrono/format/strftime/index.html).
+    ///
+    /// # Panics
+    /// Panics if the format is invalid, see [`DateChunked::try_to_string`].
     pub fn to_string(&self, format: &str) -> Utf8Chunked {
+        self.try_to_string(format).unwrap()
+    }
+
+    /// Convert from Date into Utf8 with the given format, or return an error if the format is
+    /// invalid.
+    ///
+    /// The format is parsed once and applied to every value.
+    pub fn try_to_string(&self, format: &str) -> PolarsResult<Utf8Chunked> {
+        let items = compile_format(format)?;
         let date = NaiveDate::from_ymd_opt(2001, 1, 1).unwrap();
-        let fmted = format!("{}", date.format(format));
+        let mut fmted = String::new();
+        write!(fmted, "{}", date.format_with_items(items.iter())).map_err(
+            |_| polars_err!(ComputeError: "cannot format Date with format '{}'", format),
+        )?;
 
         let mut ca: Utf8Chunked = self.apply_kernel_cast(&|arr| {
             let mut buf = String::new();
@@ -46,7 +61,7 @@ impl DateChunked {
                     None => mutarr.push_null(),
                     Some(v) => {
                         buf.clear();
-                        let datefmt = date32_to_date(*v).format(format);
+                        let datefmt = date32_to_date(*v).format_with_items(items.iter());
                         write!(buf, "{datefmt}").unwrap();
                         mutarr.push(Some(&buf))
                     },
@@ -57,7 +72,7 @@ impl DateChunked {
             Box::new(arr)
         });
         ca.rename(self.name());
-        ca
+        Ok(ca)
     }
 
     /// Convert from Date into Utf8 with the given format.
@@ -1,9 +1,9 @@
-use std::fmt::Write;
+use std::fmt::{Display, Write};
 
 use arrow::temporal_conversions::{
     timestamp_ms_to_datetime, timestamp_ns_to_datetime, timestamp_us_to_datetime,
 };
-use chrono::format::{DelayedFormat, StrftimeItems};
+use chrono::format::Item;
 use chrono::NaiveDate;
 #[cfg(feature = "timezones")]
 use chrono::TimeZone as TimeZoneTrait;
@@ -17,11 +17,11 @@ use crate::chunked_array::temporal::validate_time_zone;
 use crate::prelude::DataType::Datetime;
 use crate::prelude::*;
 
-fn apply_datefmt_f<'a>(
+fn apply_datefmt_f<D: Display>(
     arr: &PrimitiveArray<i64>,
-    fmted: &'a str,
+    fmted: &str,
     conversion_f: fn(i64) -> NaiveDateTime,
-    datefmt_f: impl Fn(NaiveDateTime) -> DelayedFormat<StrftimeItems<'a>>,
+    datefmt_f: impl Fn(NaiveDateTime) -> D,
 ) -> ArrayRef {
     let mut buf = String::new();
     let mut mutarr = MutableUtf8Array::with_capacities(arr.len(), arr.len() * fmted.len() + 1);
@@ -45,20 +45,20 @@ fn apply_datefmt_f<'a>(
 fn format_tz(
     tz: Tz,
     arr: &PrimitiveArray<i64>,
-    fmt: &str,
+    items: &[Item],
     fmted: &str,
     conversion_f: fn(i64) -> NaiveDateTime,
 ) -> ArrayRef {
-    let datefmt_f = |ndt| tz.from_utc_datetime(&ndt).format(fmt);
+    let datefmt_f = |ndt| tz.from_utc_datetime(&ndt).format_with_items(items.iter());
     apply_datefmt_f(arr, fmted, conversion_f, datefmt_f)
 }
 fn format_naive(
     arr: &PrimitiveArray<i64>,
-    fmt: &str,
+    items: &[Item],
     fmted: &str,
     conversion_f: fn(i64) -> NaiveDateTime,
 ) -> ArrayRef {
-    let datefmt_f = |ndt: NaiveDateTime| ndt.format(fmt);
+    let datefmt_f = |ndt: NaiveDateTime| ndt.format_with_items(items.iter());
     apply_datefmt_f(arr, fmted, conversion_f, datefmt_f)
 }
 
@@ -95,9 +95,13 @@ impl DatetimeChunked {
 
     /// Convert from Datetime into Utf8 with the given format.
     /// See [chrono strftime/strptime](https://docs.rs/chrono/0.4.19/chrono/format/strftime/index.html).
+    ///
+    /// The format is parsed once and applied to every value. Values with a time zone are
+    /// formatted in their local time.
     pub fn to_string(&self, format: &str) -> PolarsResult<Utf8Chunked> {
         #[cfg(feature = "timezones")]
         use chrono::Utc;
+        let items = compile_format(format)?;
         let conversion_f = match self.time_unit() {
             TimeUnit::Nanoseconds => timestamp_ns_to_datetime,
             TimeUnit::Microseconds => timestamp_us_to_datetime,
@@ -114,12 +118,15 @@ impl DatetimeChunked {
             Some(_) => write!(
                 fmted,
                 "{}",
-                Utc.from_local_datetime(&dt).earliest().unwrap().format(format)
+                Utc.from_local_datetime(&dt)
+                    .earliest()
+                    .unwrap()
+                    .format_with_items(items.iter())
             )
             .map_err(
                 |_| polars_err!(ComputeError: "cannot format DateTime with format '{}'", format),
             )?,
-            _ => write!(fmted, "{}", dt.format(format)).map_err(
+            _ => write!(fmted, "{}", dt.format_with_items(items.iter())).map_err(
                 |_| polars_err!(ComputeError: "cannot format NaiveDateTime with format '{}'", format),
             )?,
         };
@@ -131,12 +138,12 @@ impl DatetimeChunked {
                 format_tz(
                     time_zone.parse::<Tz>().unwrap(),
                     arr,
-                    format,
+                    &items,
                     &fmted,
                     conversion_f,
                 )
             }),
-            _ => self.apply_kernel_cast(&|arr| format_naive(arr, format, &fmted, conversion_f)),
+            _ => self.apply_kernel_cast(&|arr| format_naive(arr, &items, &fmted, conversion_f)),
         };
         ca.rename(self.name());
         Ok(ca)
@@ -1,7 +1,54 @@
+use std::fmt::Write;
+
 use crate::export::chrono::Duration as ChronoDuration;
+use crate::fmt::DurationFmt;
 use crate::prelude::DataType::Duration;
 use crate::prelude::*;
 
+/// Write `v` in time unit `tu` as an ISO 8601 duration, e.g. `P1DT2H3M4.5S`.
+fn write_iso_duration(buf: &mut String, v: i64, tu: TimeUnit) {
+    let (units_per_second, precision) = match tu {
+        TimeUnit::Nanoseconds => (1_000_000_000, 9),
+        TimeUnit::Microseconds => (1_000_000, 6),
+        TimeUnit::Milliseconds => (1_000, 3),
+    };
+    if v == 0 {
+        buf.push_str("PT0S");
+        return;
+    }
+    if v < 0 {
+        buf.push('-');
+    }
+    let v = v.unsigned_abs();
+    let fraction = v % units_per_second;
+    let seconds = v / units_per_second;
+    let (days, hours) = (seconds / 86_400, seconds % 86_400 / 3_600);
+    let (minutes, seconds) = (seconds % 3_600 / 60, seconds % 60);
+
+    buf.push('P');
+    if days > 0 {
+        write!(buf, "{days}D").unwrap();
+    }
+    if hours == 0 && minutes == 0 && seconds == 0 && fraction == 0 {
+        return;
+    }
+    buf.push('T');
+    if hours > 0 {
+        write!(buf, "{hours}H").unwrap();
+    }
+    if minutes > 0 {
+        write!(buf, "{minutes}M").unwrap();
+    }
+    if seconds > 0 || fraction > 0 {
+        write!(buf, "{seconds}").unwrap();
+        if fraction > 0 {
+            let fraction = format!("{fraction:0precision$}");
+            write!(buf, ".{}", fraction.trim_end_matches('0')).unwrap();
+        }
+        buf.push('S');
+    }
+}
+
 impl DurationChunked {
     pub fn time_unit(&self) -> TimeUnit {
         match self.2.as_ref().unwrap() {
@@ -55,6 +102,47 @@ impl DurationChunked {
         }
     }
 
+    /// Convert from Duration into Utf8 with the given format.
+    ///
+    /// The supported formats are `"iso"`, for ISO 8601 durations like `P1DT2H3M4.5S`, and
+    /// `"polars"`, for the format in which durations are displayed, like `1d 2h 3m 4s 500ms`.
+    pub fn to_string(&self, format: &str) -> PolarsResult<Utf8Chunked> {
+        let iso = match format {
+            "iso" => true,
+            "polars" => false,
+            _ => polars_bail!(
+                InvalidOperation: "format '{}' is not supported for Duration, expected 'iso' or 'polars'",
+                format
+            ),
+        };
+        let tu = self.time_unit();
+
+        let mut ca: Utf8Chunked = self.apply_kernel_cast(&|arr| {
+            let mut buf = String::new();
+            let mut mutarr = MutableUtf8Array::with_capacities(arr.len(), arr.len() * 8);
+
+            for opt in arr.into_iter() {
+                match opt {
+                    None => mutarr.push_null(),
+                    Some(v) => {
+                        buf.clear();
+                        if iso {
+                            write_iso_duration(&mut buf, *v, tu)
+                        } else {
+                            write!(buf, "{}", DurationFmt(*v, tu)).unwrap()
+                        }
+                        mutarr.push(Some(&buf))
+                    },
+                }
+            }
+
+            let arr: Utf8Array<i64> = mutarr.into();
+            Box::new(arr)
+        });
+        ca.rename(self.name());
+        Ok(ca)
+    }
+
     /// Change the underlying [`TimeUnit`]. This does not modify the data.
     pub fn set_time_unit(&mut self, tu: TimeUnit) {
         self.2 = Some(Duration(tu))
@@ -90,3 +178,39 @@ impl DurationChunked {
         Int64Chunked::from_iter_options(name, vals).into_duration(tu)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_duration_to_string() -> PolarsResult<()> {
+        let ca = Int64Chunked::new(
+            "d",
+            &[
+                Some(93_784_500),
+                Some(0),
+                Some(-60_000),
+                Some(86_400_000),
+                None,
+            ],
+        )
+        .into_duration(TimeUnit::Milliseconds);
+
+        let iso = ca.to_string("iso")?;
+        assert_eq!(
+            Vec::from(&iso),
+            &[
+                Some("P1DT2H3M4.5S"),
+                Some("PT0S"),
+                Some("-PT1M"),
+                Some("P1D"),
+                None
+            ]
+        );
+        let polars = ca.to_string("polars")?;
+        assert_eq!(polars.get(0), Some("1d 2h 3m 4s 500ms"));
+        assert!(ca.to_string("%H").is_err());
+        Ok(())
+    }
+}
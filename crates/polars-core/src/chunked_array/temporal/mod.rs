@@ -8,6 +8,12 @@ mod datetime;
 mod duration;
 #[cfg(feature = "dtype-time")]
 mod time;
+#[cfg(any(
+    feature = "dtype-date",
+    feature = "dtype-datetime",
+    feature = "dtype-time"
+))]
+use chrono::format::{Item, StrftimeItems};
 #[cfg(feature = "dtype-date")]
 use chrono::NaiveDate;
 use chrono::NaiveDateTime;
@@ -20,12 +26,40 @@ pub use time::time_to_time64ns;
 
 pub use self::conversion::*;
 #[cfg(feature = "timezones")]
-use crate::prelude::{polars_bail, PolarsResult};
+use crate::prelude::polars_bail;
+#[cfg(any(
+    feature = "dtype-date",
+    feature = "dtype-datetime",
+    feature = "dtype-time"
+))]
+use crate::prelude::polars_ensure;
+#[cfg(any(
+    feature = "timezones",
+    feature = "dtype-date",
+    feature = "dtype-datetime",
+    feature = "dtype-time"
+))]
+use crate::prelude::PolarsResult;
 
 pub fn unix_time() -> NaiveDateTime {
     NaiveDateTime::from_timestamp_opt(0, 0).unwrap()
 }
 
+/// Parse a strftime `format` once, so that the formatting kernels don't parse it for every value.
+#[cfg(any(
+    feature = "dtype-date",
+    feature = "dtype-datetime",
+    feature = "dtype-time"
+))]
+pub(crate) fn compile_format(format: &str) -> PolarsResult<Vec<Item<'_>>> {
+    let items = StrftimeItems::new(format).collect::<Vec<_>>();
+    polars_ensure!(
+        !items.contains(&Item::Error),
+        ComputeError: "invalid format string '{}'", format
+    );
+    Ok(items)
+}
+
 #[cfg(feature = "timezones")]
 pub(crate) fn validate_time_zone(tz: &str) -> PolarsResult<()> {
     match tz.parse::<Tz>() {
@@ -20,9 +20,24 @@ pub fn time_to_time64ns(time: &NaiveTime) -> i64 {
 impl TimeChunked {
     /// Convert from Time into Utf8 with the given format.
     /// See [chrono strftime/strptime](https://docs.rs/chrono/0.4.19/chrono/format/strftime/index.html).
+    ///
+    /// # Panics
+    /// Panics if the format is invalid, see [`TimeChunked::try_to_string`].
     pub fn to_string(&self, format: &str) -> Utf8Chunked {
+        self.try_to_string(format).unwrap()
+    }
+
+    /// Convert from Time into Utf8 with the given format, or return an error if the format is
+    /// invalid.
+    ///
+    /// The format is parsed once and applied to every value.
+    pub fn try_to_string(&self, format: &str) -> PolarsResult<Utf8Chunked> {
+        let items = compile_format(format)?;
         let time = NaiveTime::from_hms_opt(0, 0, 0).unwrap();
-        let fmted = format!("{}", time.format(format));
+        let mut fmted = String::new();
+        write!(fmted, "{}", time.format_with_items(items.iter())).map_err(
+            |_| polars_err!(ComputeError: "cannot format Time with format '{}'", format),
+        )?;
 
         let mut ca: Utf8Chunked = self.apply_kernel_cast(&|arr| {
             let mut buf = String::new();
@@ -34,7 +49,7 @@ impl TimeChunked {
                     None => mutarr.push_null(),
                     Some(v) => {
                         buf.clear();
-                        let timefmt = time64ns_to_time(*v).format(format);
+                        let timefmt = time64ns_to_time(*v).format_with_items(items.iter());
                         write!(buf, "{timefmt}").unwrap();
                         mutarr.push(Some(&buf))
                     },
@@ -46,7 +61,7 @@ impl TimeChunked {
         });
 
         ca.rename(self.name());
-        ca
+        Ok(ca)
     }
 
     /// Convert from Time into Utf8 with the given format.
@@ -1,4 +1,5 @@
 pub use polars_arrow::index::{IdxArr, IdxSize};
+pub use polars_utils::aliases::PlSmallStr;
 
 use super::*;
 use crate::hashing::IdBuildHasher;
@@ -1,5 +1,3 @@
-use smartstring::alias::String as SmartString;
-
 use super::*;
 
 /// Characterizes the name and the [`DataType`] of a column.
@@ -9,7 +7,7 @@ use super::*;
     derive(Serialize, Deserialize)
 )]
 pub struct Field {
-    pub name: SmartString,
+    pub name: PlSmallStr,
     pub dtype: DataType,
 }
 
@@ -32,7 +30,7 @@ impl Field {
         }
     }
 
-    pub fn from_owned(name: SmartString, dtype: DataType) -> Self {
+    pub fn from_owned(name: PlSmallStr, dtype: DataType) -> Self {
         Field { name, dtype }
     }
 
@@ -47,7 +45,7 @@ impl Field {
     /// assert_eq!(f.name(), "Year");
     /// ```
     #[inline]
-    pub fn name(&self) -> &SmartString {
+    pub fn name(&self) -> &PlSmallStr {
         &self.name
     }
 
@@ -92,7 +90,7 @@ impl Field {
     ///
     /// assert_eq!(f, Field::new("Proton", DataType::UInt32));
     /// ```
-    pub fn set_name(&mut self, name: SmartString) {
+    pub fn set_name(&mut self, name: PlSmallStr) {
         self.name = name;
     }
 
@@ -818,6 +818,21 @@ fn format_duration(f: &mut Formatter, v: i64, sizes: &[i64], names: &[&str]) ->
     Ok(())
 }
 
+/// Displays a duration `value` in time unit `tu` like an [`AnyValue::Duration`].
+#[cfg(feature = "dtype-duration")]
+pub(crate) struct DurationFmt(pub i64, pub TimeUnit);
+
+#[cfg(feature = "dtype-duration")]
+impl Display for DurationFmt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.1 {
+            TimeUnit::Nanoseconds => fmt_duration_ns(f, self.0),
+            TimeUnit::Microseconds => fmt_duration_us(f, self.0),
+            TimeUnit::Milliseconds => fmt_duration_ms(f, self.0),
+        }
+    }
+}
+
 impl Display for AnyValue<'_> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         let width = 0;
@@ -854,11 +869,7 @@ impl Display for AnyValue<'_> {
                 }
             },
             #[cfg(feature = "dtype-duration")]
-            AnyValue::Duration(v, tu) => match tu {
-                TimeUnit::Nanoseconds => fmt_duration_ns(f, *v),
-                TimeUnit::Microseconds => fmt_duration_us(f, *v),
-                TimeUnit::Milliseconds => fmt_duration_ms(f, *v),
-            },
+            AnyValue::Duration(v, tu) => write!(f, "{}", DurationFmt(*v, *tu)),
             #[cfg(feature = "dtype-time")]
             AnyValue::Time(_) => {
                 let nt: chrono::NaiveTime = self.into();
@@ -3,7 +3,6 @@ use polars_arrow::kernels::concatenate::concatenate_owned_unchecked;
 use rayon::prelude::*;
 #[cfg(feature = "serde-lazy")]
 use serde::{Deserialize, Serialize};
-use smartstring::alias::String as SmartString;
 
 use crate::chunked_array::ops::explode::offsets_to_indexes;
 use crate::prelude::*;
@@ -25,10 +24,10 @@ fn get_exploded(series: &Series) -> PolarsResult<(Series, OffsetsBuffer<i64>)> {
 #[derive(Clone, Default, Debug, PartialEq)]
 #[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
 pub struct MeltArgs {
-    pub id_vars: Vec<SmartString>,
-    pub value_vars: Vec<SmartString>,
-    pub variable_name: Option<SmartString>,
-    pub value_name: Option<SmartString>,
+    pub id_vars: Vec<PlSmallStr>,
+    pub value_vars: Vec<PlSmallStr>,
+    pub variable_name: Option<PlSmallStr>,
+    pub value_name: Option<PlSmallStr>,
     /// Whether the melt may be done
     /// in the streaming engine
     /// This will not have a stable ordering
@@ -242,8 +241,8 @@ impl DataFrame {
     /// ```
     pub fn melt<I, J>(&self, id_vars: I, value_vars: J) -> PolarsResult<Self>
     where
-        I: IntoVec<SmartString>,
-        J: IntoVec<SmartString>,
+        I: IntoVec<PlSmallStr>,
+        J: IntoVec<PlSmallStr>,
     {
         let id_vars = id_vars.into_vec();
         let value_vars = value_vars.into_vec();
@@ -1,6 +1,7 @@
 mod agg_list;
 mod boolean;
 mod dispatch;
+mod n_unique;
 mod utf8;
 
 use std::cmp::Ordering;
@@ -9,6 +10,7 @@ pub use agg_list::*;
 use arrow::bitmap::{Bitmap, MutableBitmap};
 use arrow::types::simd::Simd;
 use arrow::types::NativeType;
+pub use n_unique::agg_n_unique_many;
 use num_traits::pow::Pow;
 use num_traits::{Bounded, Float, Num, NumCast, ToPrimitive, Zero};
 use polars_arrow::data_types::IsFloat;
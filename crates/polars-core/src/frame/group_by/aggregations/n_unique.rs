@@ -0,0 +1,151 @@
+use super::*;
+use crate::utils::_split_offsets;
+
+/// Map every value of `s` to the id of its unique value. Nulls get their own id.
+fn dense_ids(s: &Series) -> PolarsResult<Vec<IdxSize>> {
+    let mut ids = vec![0 as IdxSize; s.len()];
+    match s.group_tuples(false, false)? {
+        GroupsProxy::Idx(groups) => {
+            for (id, idx) in groups.all().iter().enumerate() {
+                for &i in idx {
+                    ids[i as usize] = id as IdxSize;
+                }
+            }
+        },
+        GroupsProxy::Slice { groups, .. } => {
+            for (id, [first, len]) in groups.iter().enumerate() {
+                let first = *first as usize;
+                ids[first..first + *len as usize].fill(id as IdxSize);
+            }
+        },
+    }
+    Ok(ids)
+}
+
+#[inline]
+fn count_unique(set: &mut PlHashSet<IdxSize>, ids: &[IdxSize], rows: &[IdxSize]) -> IdxSize {
+    set.clear();
+    // Safety: the group indices are in bounds of the column.
+    set.extend(
+        rows.iter()
+            .map(|&i| unsafe { *ids.get_unchecked(i as usize) }),
+    );
+    set.len() as IdxSize
+}
+
+#[inline]
+fn count_unique_slice(
+    set: &mut PlHashSet<IdxSize>,
+    ids: &[IdxSize],
+    first: usize,
+    len: usize,
+) -> IdxSize {
+    set.clear();
+    set.extend(ids[first..first + len].iter().copied());
+    set.len() as IdxSize
+}
+
+/// Count the unique values per group of all `columns` at once.
+///
+/// This gives the same result as calling `agg_n_unique` on every column, but the columns are
+/// first mapped to dense ids so that the groups are traversed only once and the hash set that
+/// tracks the distinct ids is shared by all columns.
+pub fn agg_n_unique_many(columns: &[Series], groups: &GroupsProxy) -> PolarsResult<Vec<Series>> {
+    let ids = POOL.install(|| {
+        columns
+            .par_iter()
+            .map(dense_ids)
+            .collect::<PolarsResult<Vec<_>>>()
+    })?;
+
+    let n_threads = POOL.current_num_threads();
+    let offsets = _split_offsets(groups.len(), n_threads);
+
+    // Every thread produces the counts of its groups, one `Vec` per column.
+    let partitions = POOL.install(|| {
+        offsets
+            .into_par_iter()
+            .map(|(offset, len)| {
+                let mut set = PlHashSet::new();
+                let mut out = (0..columns.len())
+                    .map(|_| Vec::with_capacity(len))
+                    .collect::<Vec<_>>();
+                for g in offset..offset + len {
+                    match groups {
+                        GroupsProxy::Idx(groups) => {
+                            let rows = &groups.all()[g];
+                            for (ids, out) in ids.iter().zip(out.iter_mut()) {
+                                out.push(
+                                    (!rows.is_empty()).then(|| count_unique(&mut set, ids, rows)),
+                                );
+                            }
+                        },
+                        GroupsProxy::Slice { groups, .. } => {
+                            let [first, len] = groups[g];
+                            let (first, len) = (first as usize, len as usize);
+                            for (ids, out) in ids.iter().zip(out.iter_mut()) {
+                                out.push(
+                                    (len > 0)
+                                        .then(|| count_unique_slice(&mut set, ids, first, len)),
+                                );
+                            }
+                        },
+                    }
+                }
+                out
+            })
+            .collect::<Vec<_>>()
+    });
+
+    Ok(columns
+        .iter()
+        .enumerate()
+        .map(|(i, s)| {
+            let ca: IdxCa = partitions
+                .iter()
+                .flat_map(|part| part[i].iter().copied())
+                .collect();
+            ca.with_name(s.name()).into_series()
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_agg_n_unique_many() -> PolarsResult<()> {
+        let df = df![
+            "g" => [1, 2, 1, 2, 3, 1],
+            "a" => [Some(1), None, Some(1), Some(2), None, None],
+            "b" => ["x", "y", "z", "y", "x", "x"]
+        ]?;
+        let columns = [df.column("a")?.clone(), df.column("b")?.clone()];
+
+        for sorted in [false, true] {
+            let groups = df.column("g")?.group_tuples(false, sorted)?;
+            let out = agg_n_unique_many(&columns, &groups)?;
+            for (s, out) in columns.iter().zip(&out) {
+                let expected = unsafe { s.agg_n_unique(&groups) };
+                assert!(out.series_equal_missing(&expected));
+                assert_eq!(out.name(), s.name());
+            }
+        }
+
+        let groups = GroupsProxy::Slice {
+            groups: vec![[0, 3], [3, 3], [6, 0]],
+            rolling: false,
+        };
+        let out = agg_n_unique_many(&columns, &groups)?;
+        assert_eq!(
+            Vec::from(out[0].idx()?),
+            &[Some(2), Some(2), None] as &[Option<IdxSize>]
+        );
+        assert_eq!(
+            Vec::from(out[1].idx()?),
+            &[Some(3), Some(2), None] as &[Option<IdxSize>]
+        );
+        Ok(())
+    }
+}
@@ -29,12 +29,13 @@ mod upstream_traits;
 pub use chunks::*;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
-use smartstring::alias::String as SmartString;
 
 #[cfg(feature = "algorithm_group_by")]
 use crate::frame::group_by::GroupsIndicator;
 #[cfg(feature = "row_hash")]
 use crate::hashing::_df_rows_to_hashes_threaded_vertical;
+#[cfg(feature = "row_hash")]
+use crate::hashing::stable::stable_hash_rows;
 #[cfg(feature = "zip_with")]
 use crate::prelude::min_max_binary::min_max_binary_series;
 use crate::prelude::sort::{argsort_multiple_row_fmt, prepare_arg_sort};
@@ -567,7 +568,7 @@ impl DataFrame {
     }
 
     /// Get the [`Vec<String>`] representing the column names.
-    pub fn get_column_names_owned(&self) -> Vec<SmartString> {
+    pub fn get_column_names_owned(&self) -> Vec<PlSmallStr> {
         self.columns.iter().map(|s| s.name().into()).collect()
     }
 
@@ -632,6 +633,31 @@ impl DataFrame {
         }
     }
 
+    /// The lengths of the chunks of every column, in column order.
+    ///
+    /// Columns whose chunk lengths differ are not aligned; see [`DataFrame::should_rechunk`]
+    /// and [`DataFrame::align_chunks`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// let mut s = Series::new("a", [1, 2]);
+    /// s.append(&Series::new("a", [3]))?;
+    /// let mut df = DataFrame::new(vec![s, Series::new("b", [1, 2, 3])])?;
+    /// assert_eq!(df.chunk_lengths(), vec![vec![2, 1], vec![3]]);
+    ///
+    /// df.align_chunks();
+    /// assert_eq!(df.chunk_lengths(), vec![vec![3], vec![3]]);
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    pub fn chunk_lengths(&self) -> Vec<Vec<usize>> {
+        self.columns
+            .iter()
+            .map(|s| s.chunk_lengths().collect())
+            .collect()
+    }
+
     /// Get a reference to the schema fields of the [`DataFrame`].
     ///
     /// # Example
@@ -1424,17 +1450,17 @@ impl DataFrame {
     {
         let cols = selection
             .into_iter()
-            .map(|s| SmartString::from(s.as_ref()))
+            .map(|s| PlSmallStr::from(s.as_ref()))
             .collect::<Vec<_>>();
         self._select_impl(&cols)
     }
 
-    pub fn _select_impl(&self, cols: &[SmartString]) -> PolarsResult<Self> {
+    pub fn _select_impl(&self, cols: &[PlSmallStr]) -> PolarsResult<Self> {
         self.select_check_duplicates(cols)?;
         self._select_impl_unchecked(cols)
     }
 
-    pub fn _select_impl_unchecked(&self, cols: &[SmartString]) -> PolarsResult<Self> {
+    pub fn _select_impl_unchecked(&self, cols: &[PlSmallStr]) -> PolarsResult<Self> {
         let selected = self.select_series_impl(cols)?;
         Ok(DataFrame::new_no_checks(selected))
     }
@@ -1447,7 +1473,7 @@ impl DataFrame {
     {
         let cols = selection
             .into_iter()
-            .map(|s| SmartString::from(s.as_ref()))
+            .map(|s| PlSmallStr::from(s.as_ref()))
             .collect::<Vec<_>>();
         self.select_with_schema_impl(&cols, schema, true)
     }
@@ -1464,14 +1490,14 @@ impl DataFrame {
     {
         let cols = selection
             .into_iter()
-            .map(|s| SmartString::from(s.as_ref()))
+            .map(|s| PlSmallStr::from(s.as_ref()))
             .collect::<Vec<_>>();
         self.select_with_schema_impl(&cols, schema, false)
     }
 
     fn select_with_schema_impl(
         &self,
-        cols: &[SmartString],
+        cols: &[PlSmallStr],
         schema: &Schema,
         check_duplicates: bool,
     ) -> PolarsResult<Self> {
@@ -1485,7 +1511,7 @@ impl DataFrame {
     /// A non generic implementation to reduce compiler bloat.
     fn select_series_impl_with_schema(
         &self,
-        cols: &[SmartString],
+        cols: &[PlSmallStr],
         schema: &Schema,
     ) -> PolarsResult<Vec<Series>> {
         cols.iter()
@@ -1503,18 +1529,18 @@ impl DataFrame {
     {
         let cols = selection
             .into_iter()
-            .map(|s| SmartString::from(s.as_ref()))
+            .map(|s| PlSmallStr::from(s.as_ref()))
             .collect::<Vec<_>>();
         self.select_physical_impl(&cols)
     }
 
-    fn select_physical_impl(&self, cols: &[SmartString]) -> PolarsResult<Self> {
+    fn select_physical_impl(&self, cols: &[PlSmallStr]) -> PolarsResult<Self> {
         self.select_check_duplicates(cols)?;
         let selected = self.select_series_physical_impl(cols)?;
         Ok(DataFrame::new_no_checks(selected))
     }
 
-    fn select_check_duplicates(&self, cols: &[SmartString]) -> PolarsResult<()> {
+    fn select_check_duplicates(&self, cols: &[PlSmallStr]) -> PolarsResult<()> {
         let mut names = PlHashSet::with_capacity(cols.len());
         for name in cols {
             if !names.insert(name.as_str()) {
@@ -1539,7 +1565,7 @@ impl DataFrame {
     /// assert_eq!(df["Hydrogen"], sv[1]);
     /// # Ok::<(), PolarsError>(())
     /// ```
-    pub fn select_series(&self, selection: impl IntoVec<SmartString>) -> PolarsResult<Vec<Series>> {
+    pub fn select_series(&self, selection: impl IntoVec<PlSmallStr>) -> PolarsResult<Vec<Series>> {
         let cols = selection.into_vec();
         self.select_series_impl(&cols)
     }
@@ -1553,7 +1579,7 @@ impl DataFrame {
     }
 
     /// A non generic implementation to reduce compiler bloat.
-    fn select_series_physical_impl(&self, cols: &[SmartString]) -> PolarsResult<Vec<Series>> {
+    fn select_series_physical_impl(&self, cols: &[PlSmallStr]) -> PolarsResult<Vec<Series>> {
         let selected = if cols.len() > 1 && self.columns.len() > 10 {
             let name_to_idx = self._names_to_idx_map();
             cols.iter()
@@ -1578,7 +1604,7 @@ impl DataFrame {
     }
 
     /// A non generic implementation to reduce compiler bloat.
-    fn select_series_impl(&self, cols: &[SmartString]) -> PolarsResult<Vec<Series>> {
+    fn select_series_impl(&self, cols: &[PlSmallStr]) -> PolarsResult<Vec<Series>> {
         let selected = if cols.len() > 1 && self.columns.len() > 10 {
             // we hash, because there are user that having millions of columns.
             // # https://github.com/pola-rs/polars/issues/1023
@@ -1773,7 +1799,7 @@ impl DataFrame {
     /// Sort [`DataFrame`] in place by a column.
     pub fn sort_in_place(
         &mut self,
-        by_column: impl IntoVec<SmartString>,
+        by_column: impl IntoVec<PlSmallStr>,
         descending: impl IntoVec<bool>,
         maintain_order: bool,
     ) -> PolarsResult<&mut Self> {
@@ -1905,7 +1931,7 @@ impl DataFrame {
     /// ```
     pub fn sort(
         &self,
-        by_column: impl IntoVec<SmartString>,
+        by_column: impl IntoVec<PlSmallStr>,
         descending: impl IntoVec<bool>,
         maintain_order: bool,
     ) -> PolarsResult<Self> {
@@ -2801,7 +2827,8 @@ impl DataFrame {
     /// Aggregate the column horizontally to their min values.
     #[cfg(feature = "zip_with")]
     pub fn hmin(&self) -> PolarsResult<Option<Series>> {
-        let min_fn = |acc: &Series, s: &Series| min_max_binary_series(acc, s, true);
+        let min_fn =
+            |acc: &Series, s: &Series| min_max_binary_series(acc, s, true, NullStrategy::Ignore);
 
         match self.columns.len() {
             0 => Ok(None),
@@ -2827,7 +2854,8 @@ impl DataFrame {
     /// Aggregate the column horizontally to their max values.
     #[cfg(feature = "zip_with")]
     pub fn hmax(&self) -> PolarsResult<Option<Series>> {
-        let max_fn = |acc: &Series, s: &Series| min_max_binary_series(acc, s, false);
+        let max_fn =
+            |acc: &Series, s: &Series| min_max_binary_series(acc, s, false, NullStrategy::Ignore);
 
         match self.columns.len() {
             0 => Ok(None),
@@ -3167,6 +3195,13 @@ impl DataFrame {
         Ok(acc_ca.rechunk())
     }
 
+    /// Hash and combine the row values with a seeded hash that is stable across processes,
+    /// platforms and versions. See [`crate::hashing::stable`] for the algorithm.
+    #[cfg(feature = "row_hash")]
+    pub fn hash_rows_stable(&self, seed: u64) -> PolarsResult<UInt64Chunked> {
+        stable_hash_rows(&self.columns, seed)
+    }
+
     /// Get the supertype of the columns in this DataFrame
     pub fn get_supertype(&self) -> Option<PolarsResult<DataType>> {
         self.columns
@@ -3536,6 +3571,23 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn chunk_lengths() -> PolarsResult<()> {
+        let mut df = df!(
+            "a" => [1, 2, 3],
+            "b" => [1, 2, 3]
+        )?;
+        let mut s = Series::new("c", 0..2);
+        s.append(&Series::new("c", 0..1))?;
+        let out = df.with_column(s)?;
+        assert_eq!(out.chunk_lengths(), vec![vec![3], vec![3], vec![2, 1]]);
+
+        out.align_chunks();
+        assert!(!out.should_rechunk());
+        assert_eq!(out.chunk_lengths(), vec![vec![3], vec![3], vec![3]]);
+        Ok(())
+    }
+
     #[test]
     fn test_duplicate_column() {
         let mut df = df! {
@@ -1,11 +1,11 @@
 #[cfg(feature = "dtype-struct")]
 use polars_utils::slice::GetSaferUnchecked;
 use polars_utils::unreachable_unchecked_release;
-#[cfg(feature = "dtype-struct")]
-use smartstring::alias::String as SmartString;
 
 use super::*;
 #[cfg(feature = "dtype-struct")]
+use crate::datatypes::PlSmallStr;
+#[cfg(feature = "dtype-struct")]
 use crate::prelude::any_value::arr_to_any_value;
 
 #[derive(Clone)]
@@ -314,7 +314,7 @@ pub enum AnyValueBufferTrusted<'a> {
     Utf8(Utf8ChunkedBuilder),
     #[cfg(feature = "dtype-struct")]
     // not the trusted variant!
-    Struct(Vec<(AnyValueBuffer<'a>, SmartString)>),
+    Struct(Vec<(AnyValueBuffer<'a>, PlSmallStr)>),
     All(DataType, Vec<AnyValue<'a>>),
 }
 
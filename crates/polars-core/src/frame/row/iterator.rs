@@ -0,0 +1,364 @@
+use std::slice::ChunksExact;
+
+#[cfg(feature = "dtype-categorical")]
+use polars_utils::sync::SyncPtr;
+
+use super::*;
+use crate::prelude::any_value::arr_to_any_value;
+
+/// Iterator over the rows of a [`DataFrame`] in batches, see [`DataFrame::iter_rows`].
+pub struct RowsIter<'a> {
+    columns: &'a [Series],
+    /// Index of the current chunk and the offset in that chunk per column.
+    positions: Vec<(usize, usize)>,
+    buffer: Vec<AnyValue<'a>>,
+    buffer_size: usize,
+    offset: usize,
+    height: usize,
+}
+
+impl<'a> RowsIter<'a> {
+    fn new(df: &'a DataFrame, buffer_size: usize) -> Self {
+        let columns = df.get_columns();
+        RowsIter {
+            columns,
+            positions: vec![(0, 0); columns.len()],
+            buffer: Vec::with_capacity(buffer_size * columns.len()),
+            buffer_size: std::cmp::max(buffer_size, 1),
+            offset: 0,
+            height: df.height(),
+        }
+    }
+
+    /// Get the next batch of at most `buffer_size` rows, as one slice of values per row.
+    /// Returns `None` if all rows are returned.
+    ///
+    /// The buffer of the batch is reused by the next call.
+    pub fn next_batch(&mut self) -> Option<ChunksExact<'_, AnyValue<'a>>> {
+        let width = self.columns.len();
+        if self.offset >= self.height || width == 0 {
+            return None;
+        }
+        let n_rows = std::cmp::min(self.buffer_size, self.height - self.offset);
+        self.buffer.clear();
+        self.buffer.resize(n_rows * width, AnyValue::Null);
+
+        // Fill the buffer column by column, so that the dtype of a column is dispatched on once
+        // per batch instead of once per value.
+        for (col, (s, pos)) in self
+            .columns
+            .iter()
+            .zip(self.positions.iter_mut())
+            .enumerate()
+        {
+            let out = self.buffer[col..].iter_mut().step_by(width);
+            fill_column(s, pos, self.offset, out);
+        }
+        self.offset += n_rows;
+        Some(self.buffer.chunks_exact(width))
+    }
+}
+
+/// Write the values of `s` that start at row `offset`, which is at the chunk index and offset in
+/// `pos`, to the slots of `out` and advance `pos` past them.
+#[allow(unused_variables)]
+fn fill_column<'a, 'b>(
+    s: &'a Series,
+    pos: &mut (usize, usize),
+    offset: usize,
+    out: impl Iterator<Item = &'b mut AnyValue<'a>>,
+) where
+    'a: 'b,
+{
+    macro_rules! fill {
+        ($ca:ty, |$arr:ident, $i:ident| $av:expr) => {
+            // SAFETY: the chunks of a column with this dtype are of this array type and
+            // `fill_chunks` only passes indices that are in bounds.
+            fill_chunks(s.chunks(), pos, out, |$arr, $i| unsafe {
+                let $arr = &*($arr as *const dyn Array as *const $ca);
+                $av
+            })
+        };
+    }
+    macro_rules! fill_primitive {
+        ($ca:ty, $variant:ident) => {
+            fill!($ca, |arr, i| AnyValue::$variant(arr.value_unchecked(i)))
+        };
+    }
+
+    match s.dtype() {
+        DataType::Boolean => fill_primitive!(BooleanArray, Boolean),
+        DataType::UInt8 => fill_primitive!(UInt8Array, UInt8),
+        DataType::UInt16 => fill_primitive!(UInt16Array, UInt16),
+        DataType::UInt32 => fill_primitive!(UInt32Array, UInt32),
+        DataType::UInt64 => fill_primitive!(UInt64Array, UInt64),
+        DataType::Int8 => fill_primitive!(Int8Array, Int8),
+        DataType::Int16 => fill_primitive!(Int16Array, Int16),
+        DataType::Int32 => fill_primitive!(Int32Array, Int32),
+        DataType::Int64 => fill_primitive!(Int64Array, Int64),
+        DataType::Float32 => fill_primitive!(Float32Array, Float32),
+        DataType::Float64 => fill_primitive!(Float64Array, Float64),
+        DataType::Utf8 => fill_primitive!(LargeStringArray, Utf8),
+        DataType::Binary => fill_primitive!(LargeBinaryArray, Binary),
+        #[cfg(feature = "dtype-date")]
+        DataType::Date => fill_primitive!(Int32Array, Date),
+        #[cfg(feature = "dtype-datetime")]
+        DataType::Datetime(tu, tz) => {
+            fill!(Int64Array, |arr, i| AnyValue::Datetime(
+                arr.value_unchecked(i),
+                *tu,
+                tz
+            ))
+        },
+        #[cfg(feature = "dtype-duration")]
+        DataType::Duration(tu) => {
+            fill!(Int64Array, |arr, i| AnyValue::Duration(
+                arr.value_unchecked(i),
+                *tu
+            ))
+        },
+        #[cfg(feature = "dtype-time")]
+        DataType::Time => fill_primitive!(Int64Array, Time),
+        #[cfg(feature = "dtype-decimal")]
+        DataType::Decimal(_, scale) => {
+            let scale = scale.unwrap_or_else(|| unreachable!());
+            fill!(Int128Array, |arr, i| AnyValue::Decimal(
+                arr.value_unchecked(i),
+                scale
+            ))
+        },
+        #[cfg(feature = "dtype-categorical")]
+        DataType::Categorical(rev_map) => {
+            let rev_map = rev_map.as_deref().unwrap();
+            fill!(UInt32Array, |arr, i| AnyValue::Categorical(
+                arr.value_unchecked(i),
+                rev_map,
+                SyncPtr::new_null()
+            ))
+        },
+        #[cfg(feature = "object")]
+        DataType::Object(_) => {
+            // objects are not stored in arrow arrays
+            for (row, slot) in out.enumerate() {
+                *slot = unsafe { s.get_unchecked(offset + row) };
+            }
+        },
+        // Nested values are built as a `Series` per value, which outweighs the dispatch.
+        dtype => fill_chunks(s.chunks(), pos, out, |arr, i| {
+            // SAFETY: `i` is in bounds of the chunk and `dtype` is the dtype of the chunk.
+            unsafe { arr_to_any_value(arr, i, dtype) }
+        }),
+    }
+}
+
+/// Write the values of `chunks` from the chunk index and offset in `pos` onwards to the slots of
+/// `out` and advance `pos` past them. `get` is only called for valid values.
+#[inline]
+fn fill_chunks<'a, 'b, F>(
+    chunks: &'a [ArrayRef],
+    pos: &mut (usize, usize),
+    out: impl Iterator<Item = &'b mut AnyValue<'a>>,
+    get: F,
+) where
+    'a: 'b,
+    F: Fn(&'a dyn Array, usize) -> AnyValue<'a>,
+{
+    let mut out = out.peekable();
+    while out.peek().is_some() {
+        let arr = &*chunks[pos.0];
+        let validity = arr.validity();
+        for (i, slot) in (pos.1..arr.len()).zip(&mut out) {
+            *slot = match validity {
+                Some(validity) if !unsafe { validity.get_bit_unchecked(i) } => AnyValue::Null,
+                _ => get(arr, i),
+            };
+            pos.1 = i + 1;
+        }
+        if pos.1 == arr.len() {
+            pos.0 += 1;
+            pos.1 = 0;
+        }
+    }
+}
+
+/// Conversion of a single value of a row, see [`FromRow`].
+pub trait FromAnyValue<'a>: Sized {
+    fn from_any_value(av: &AnyValue<'a>) -> PolarsResult<Self>;
+}
+
+macro_rules! impl_from_any_value_numeric {
+    ($($T:ty),*) => {
+        $(
+            impl<'a> FromAnyValue<'a> for $T {
+                fn from_any_value(av: &AnyValue<'a>) -> PolarsResult<Self> {
+                    av.try_extract()
+                }
+            }
+        )*
+    };
+}
+
+impl_from_any_value_numeric!(i8, i16, i32, i64, u8, u16, u32, u64, f32, f64);
+
+impl<'a> FromAnyValue<'a> for bool {
+    fn from_any_value(av: &AnyValue<'a>) -> PolarsResult<Self> {
+        match av {
+            AnyValue::Boolean(v) => Ok(*v),
+            av => polars_bail!(ComputeError: "cannot extract a bool from {}", av),
+        }
+    }
+}
+
+impl<'a> FromAnyValue<'a> for &'a str {
+    fn from_any_value(av: &AnyValue<'a>) -> PolarsResult<Self> {
+        match av {
+            AnyValue::Utf8(v) => Ok(*v),
+            av => polars_bail!(ComputeError: "cannot extract a borrowed str from {}", av),
+        }
+    }
+}
+
+impl<'a> FromAnyValue<'a> for String {
+    fn from_any_value(av: &AnyValue<'a>) -> PolarsResult<Self> {
+        match av.get_str() {
+            Some(v) => Ok(v.to_string()),
+            None => polars_bail!(ComputeError: "cannot extract a string from {}", av),
+        }
+    }
+}
+
+impl<'a> FromAnyValue<'a> for AnyValue<'a> {
+    fn from_any_value(av: &AnyValue<'a>) -> PolarsResult<Self> {
+        Ok(av.clone())
+    }
+}
+
+impl<'a, T: FromAnyValue<'a>> FromAnyValue<'a> for Option<T> {
+    fn from_any_value(av: &AnyValue<'a>) -> PolarsResult<Self> {
+        match av {
+            AnyValue::Null => Ok(None),
+            av => T::from_any_value(av).map(Some),
+        }
+    }
+}
+
+/// Conversion of a row of a [`DataFrame`] into a typed value, see [`DataFrame::iter_rows_as`].
+///
+/// This is implemented for tuples of up to 12 values that implement [`FromAnyValue`]. Nulls
+/// can only be converted to an [`Option`].
+pub trait FromRow<'a>: Sized {
+    fn from_row(row: &[AnyValue<'a>]) -> PolarsResult<Self>;
+}
+
+macro_rules! impl_from_row_tuple {
+    ($n:expr; $($T:ident $idx:tt),+) => {
+        impl<'a, $($T: FromAnyValue<'a>),+> FromRow<'a> for ($($T,)+) {
+            fn from_row(row: &[AnyValue<'a>]) -> PolarsResult<Self> {
+                polars_ensure!(
+                    row.len() == $n,
+                    ShapeMismatch: "cannot convert a row of {} values into a tuple of {}",
+                    row.len(), $n
+                );
+                Ok(($($T::from_any_value(&row[$idx])?,)+))
+            }
+        }
+    };
+}
+
+impl_from_row_tuple!(1; A 0);
+impl_from_row_tuple!(2; A 0, B 1);
+impl_from_row_tuple!(3; A 0, B 1, C 2);
+impl_from_row_tuple!(4; A 0, B 1, C 2, D 3);
+impl_from_row_tuple!(5; A 0, B 1, C 2, D 3, E 4);
+impl_from_row_tuple!(6; A 0, B 1, C 2, D 3, E 4, F 5);
+impl_from_row_tuple!(7; A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_from_row_tuple!(8; A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+impl_from_row_tuple!(9; A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8);
+impl_from_row_tuple!(10; A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9);
+impl_from_row_tuple!(11; A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10);
+impl_from_row_tuple!(12; A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11);
+
+/// Iterator over the rows of a [`DataFrame`] converted to `T`, see
+/// [`DataFrame::iter_rows_as`].
+pub struct RowsIterAs<'a, T> {
+    rows: RowsIter<'a>,
+    batch: std::vec::IntoIter<PolarsResult<T>>,
+}
+
+impl<'a, T: FromRow<'a>> Iterator for RowsIterAs<'a, T> {
+    type Item = PolarsResult<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(row) = self.batch.next() {
+                return Some(row);
+            }
+            let rows = self.rows.next_batch()?;
+            self.batch = rows.map(T::from_row).collect::<Vec<_>>().into_iter();
+        }
+    }
+}
+
+impl DataFrame {
+    /// Iterate over the rows of the [`DataFrame`] in batches of `buffer_size` rows.
+    ///
+    /// This is much faster than [`DataFrame::get_row`], as the values are materialized per
+    /// column and the buffer of the rows is reused between batches.
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// # fn example(df: &DataFrame) {
+    /// let mut rows = df.iter_rows(1024);
+    /// while let Some(batch) = rows.next_batch() {
+    ///     for row in batch {
+    ///         println!("{:?}", row);
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn iter_rows(&self, buffer_size: usize) -> RowsIter<'_> {
+        RowsIter::new(self, buffer_size)
+    }
+
+    /// Iterate over the rows of the [`DataFrame`] converted to `T`, e.g. a tuple of the types
+    /// of the columns. The rows are materialized in batches of `buffer_size` rows.
+    pub fn iter_rows_as<'a, T: FromRow<'a>>(&'a self, buffer_size: usize) -> RowsIterAs<'a, T> {
+        RowsIterAs {
+            rows: self.iter_rows(buffer_size),
+            batch: Vec::new().into_iter(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_iter_rows() -> PolarsResult<()> {
+        let mut df = df![
+            "a" => [1i32, 2],
+            "b" => [Some("x"), None],
+        ]?;
+        df.vstack_mut(&df![
+            "a" => [3i32],
+            "b" => [Some("z")],
+        ]?)?;
+
+        let mut rows = df.iter_rows(2);
+        let batch = rows.next_batch().unwrap().collect::<Vec<_>>();
+        assert_eq!(batch[0], &[AnyValue::Int32(1), AnyValue::Utf8("x")]);
+        assert_eq!(batch[1], &[AnyValue::Int32(2), AnyValue::Null]);
+        let batch = rows.next_batch().unwrap().collect::<Vec<_>>();
+        assert_eq!(batch, &[&[AnyValue::Int32(3), AnyValue::Utf8("z")]]);
+        assert!(rows.next_batch().is_none());
+
+        let rows = df
+            .iter_rows_as::<(i64, Option<&str>)>(2)
+            .collect::<PolarsResult<Vec<_>>>()?;
+        assert_eq!(rows, &[(1, Some("x")), (2, None), (3, Some("z"))]);
+        assert!(df.iter_rows_as::<(i64, &str)>(2).any(|row| row.is_err()));
+        assert!(df.iter_rows_as::<(i64,)>(2).all(|row| row.is_err()));
+        Ok(())
+    }
+}
@@ -1,5 +1,6 @@
 mod av_buffer;
 mod dataframe;
+mod iterator;
 mod transpose;
 
 use std::borrow::Borrow;
@@ -8,6 +9,7 @@ use std::hint::unreachable_unchecked;
 
 use arrow::bitmap::Bitmap;
 pub use av_buffer::*;
+pub use iterator::*;
 use rayon::prelude::*;
 
 use crate::prelude::*;
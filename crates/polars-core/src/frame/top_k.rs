@@ -3,7 +3,6 @@ use std::cmp::Ordering;
 use polars_error::PolarsResult;
 use polars_utils::iter::EnumerateIdxTrait;
 use polars_utils::IdxSize;
-use smartstring::alias::String as SmartString;
 
 use crate::datatypes::IdxCa;
 use crate::frame::DataFrame;
@@ -42,7 +41,7 @@ impl DataFrame {
         &self,
         k: usize,
         descending: impl IntoVec<bool>,
-        by_column: impl IntoVec<SmartString>,
+        by_column: impl IntoVec<PlSmallStr>,
     ) -> PolarsResult<DataFrame> {
         let by_column = self.select_series(by_column)?;
         let descending = descending.into_vec();
@@ -1,6 +1,8 @@
 mod fx;
 mod identity;
 pub mod partition;
+#[cfg(feature = "row_hash")]
+pub mod stable;
 pub(crate) mod vector_hasher;
 
 use std::hash::{BuildHasher, BuildHasherDefault, Hash, Hasher};
@@ -0,0 +1,187 @@
+//! Seeded hashing that gives the same output in every process, on every platform and in every
+//! version of polars. Use this for hashes that leave the process, e.g. to partition rows in a
+//! distributed shuffle or as keys of a persistent cache.
+//!
+//! Every value is hashed with XXH3-64 and the given seed over a canonical little-endian encoding:
+//!
+//! * integers are widened to `i64` if signed and to `u64` if unsigned, so a value has the same
+//!   hash regardless of its integer width;
+//! * floats are widened to `f64`, `-0.0` is normalized to `0.0` and every NaN to a single NaN;
+//! * booleans are a single byte, `0` or `1`;
+//! * strings and binary values are hashed as their bytes;
+//! * categoricals are hashed as their string values and other logical types as their physical
+//!   values;
+//! * the fields of a struct are hashed separately and combined.
+//!
+//! Nulls hash to XXH3-64 of the bytes `null` with the bitwise complement of the seed. The hashes of
+//! the columns in a row are combined from left to right with [`_boost_hash_combine`].
+use rayon::prelude::*;
+use xxhash_rust::xxh3::xxh3_64_with_seed;
+
+use super::_boost_hash_combine;
+use crate::prelude::*;
+use crate::POOL;
+
+fn hash_values<I, B>(iter: I, seed: u64, null_h: u64) -> Vec<u64>
+where
+    I: Iterator<Item = Option<B>>,
+    B: AsRef<[u8]>,
+{
+    iter.map(|opt_v| match opt_v {
+        Some(v) => xxh3_64_with_seed(v.as_ref(), seed),
+        None => null_h,
+    })
+    .collect()
+}
+
+#[inline]
+fn canonical_f64(v: f64) -> f64 {
+    if v.is_nan() {
+        f64::NAN
+    } else if v == 0.0 {
+        0.0
+    } else {
+        v
+    }
+}
+
+fn combine<I: Iterator<Item = Vec<u64>>>(mut hashes: I) -> Vec<u64> {
+    let mut acc = hashes.next().unwrap_or_default();
+    for h in hashes {
+        acc.iter_mut()
+            .zip(h)
+            .for_each(|(l, r)| *l = _boost_hash_combine(*l, r));
+    }
+    acc
+}
+
+fn stable_hash_series(s: &Series, seed: u64, null_h: u64) -> PolarsResult<Vec<u64>> {
+    use DataType::*;
+    let out = match s.dtype() {
+        Null => vec![null_h; s.len()],
+        Boolean => hash_values(
+            s.bool()?.into_iter().map(|opt_v| opt_v.map(|v| [v as u8])),
+            seed,
+            null_h,
+        ),
+        Utf8 => hash_values(s.utf8()?.into_iter(), seed, null_h),
+        Binary => hash_values(s.binary()?.into_iter(), seed, null_h),
+        dt if dt.is_float() => {
+            let s = s.cast(&Float64)?;
+            hash_values(
+                s.f64()?
+                    .into_iter()
+                    .map(|opt_v| opt_v.map(|v| canonical_f64(v).to_le_bytes())),
+                seed,
+                null_h,
+            )
+        },
+        dt if dt.is_signed() => {
+            let s = s.cast(&Int64)?;
+            hash_values(
+                s.i64()?
+                    .into_iter()
+                    .map(|opt_v| opt_v.map(i64::to_le_bytes)),
+                seed,
+                null_h,
+            )
+        },
+        dt if dt.is_unsigned() => {
+            let s = s.cast(&UInt64)?;
+            hash_values(
+                s.u64()?
+                    .into_iter()
+                    .map(|opt_v| opt_v.map(u64::to_le_bytes)),
+                seed,
+                null_h,
+            )
+        },
+        #[cfg(feature = "dtype-categorical")]
+        Categorical(_) => stable_hash_series(&s.cast(&Utf8)?, seed, null_h)?,
+        #[cfg(feature = "dtype-struct")]
+        Struct(_) => {
+            let fields = s
+                .struct_()?
+                .fields()
+                .iter()
+                .map(|s| stable_hash_series(s, seed, null_h))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            combine(fields.into_iter())
+        },
+        dt if dt.is_logical() => stable_hash_series(&s.to_physical_repr(), seed, null_h)?,
+        dt => polars_bail!(InvalidOperation: "stable hashing is not supported for dtype {}", dt),
+    };
+    Ok(out)
+}
+
+/// Hash and combine the row values of `columns` with the stable hash described in the
+/// [module docs](self).
+pub fn stable_hash_rows(columns: &[Series], seed: u64) -> PolarsResult<UInt64Chunked> {
+    let null_h = xxh3_64_with_seed(b"null", !seed);
+    let hashes = POOL.install(|| {
+        columns
+            .par_iter()
+            .map(|s| stable_hash_series(s, seed, null_h))
+            .collect::<PolarsResult<Vec<_>>>()
+    })?;
+    Ok(UInt64Chunked::from_vec("", combine(hashes.into_iter())))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_stable_hash_rows() -> PolarsResult<()> {
+        let a = Series::new("a", [Some(1i32), None, Some(3)]);
+        let b = Series::new("b", [Some(0.0f64), Some(-0.0), None]);
+        let c = Series::new("c", ["x", "", "z"]);
+
+        let h = stable_hash_rows(&[a.clone(), b.clone(), c.clone()], 42)?;
+        assert_eq!(h.len(), 3);
+        assert_eq!(h.null_count(), 0);
+
+        // The layout of the chunks and the integer width don't influence the hash.
+        let mut a_chunked = a.slice(0, 1);
+        a_chunked.append(&a.slice(1, 2))?;
+        let a_wide = a.cast(&DataType::Int64)?;
+        for a in [a_chunked, a_wide] {
+            let other = stable_hash_rows(&[a, b.clone(), c.clone()], 42)?;
+            assert_eq!(Vec::from(&h), Vec::from(&other));
+        }
+
+        // `-0.0` equals `0.0`, but a null doesn't equal an empty string.
+        let b_h = stable_hash_rows(&[b], 42)?;
+        assert_eq!(b_h.get(0), b_h.get(1));
+        let null_h = stable_hash_rows(&[Series::new_null("n", 1)], 42)?;
+        let c_h = stable_hash_rows(&[c], 42)?;
+        assert_ne!(null_h.get(0), c_h.get(1));
+
+        // The seed changes the hash.
+        assert_ne!(
+            stable_hash_rows(&[a.clone()], 0)?.get(0),
+            stable_hash_rows(&[a], 42)?.get(0)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_stable_hash_rows_golden() -> PolarsResult<()> {
+        // These values must never change, they are part of the contract of the stable hash.
+        let columns = [
+            Series::new("a", [Some(1i32), None, Some(3)]),
+            Series::new("b", [Some(0.5f64), Some(-0.0), None]),
+            Series::new("c", ["x", "", "z"]),
+        ];
+        let h = stable_hash_rows(&columns, 42)?;
+        assert_eq!(
+            Vec::from(&h),
+            &[
+                Some(15874456706399031779),
+                Some(5147062472345068972),
+                Some(10162124208234798799)
+            ]
+        );
+        Ok(())
+    }
+}
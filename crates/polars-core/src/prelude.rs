@@ -47,7 +47,7 @@ pub use crate::schema::*;
 #[cfg(feature = "checked_arithmetic")]
 pub use crate::series::arithmetic::checked::NumOpsDispatchChecked;
 pub use crate::series::arithmetic::{LhsNumOps, NumOpsDispatch};
-pub use crate::series::{IntoSeries, Series, SeriesTrait};
+pub use crate::series::{IntoSeries, Series, SeriesStats, SeriesTrait};
 pub use crate::testing::*;
 pub(crate) use crate::utils::CustomIterTools;
 pub use crate::utils::IntoVec;
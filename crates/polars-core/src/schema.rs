@@ -4,16 +4,15 @@ use indexmap::map::MutableKeys;
 use indexmap::IndexMap;
 #[cfg(feature = "serde-lazy")]
 use serde::{Deserialize, Serialize};
-use smartstring::alias::String as SmartString;
 
 use crate::prelude::*;
 use crate::utils::try_get_supertype;
 
-/// A map from field/column name ([`String`](smartstring::alias::String)) to the type of that field/column ([`DataType`])
+/// A map from field/column name ([`PlSmallStr`]) to the type of that field/column ([`DataType`])
 #[derive(Eq, Clone, Default)]
 #[cfg_attr(feature = "serde-lazy", derive(Serialize, Deserialize))]
 pub struct Schema {
-    inner: PlIndexMap<SmartString, DataType>,
+    inner: PlIndexMap<PlSmallStr, DataType>,
 }
 
 // Schemas will only compare equal if they have the same fields in the same order. We can't use `self.inner ==
@@ -103,7 +102,7 @@ impl Schema {
     ///
     /// If `old` is not present in the schema, the schema is not modified and `None` is returned. Otherwise the schema
     /// is updated and `Some(old_name)` is returned.
-    pub fn rename(&mut self, old: &str, new: SmartString) -> Option<SmartString> {
+    pub fn rename(&mut self, old: &str, new: PlSmallStr) -> Option<PlSmallStr> {
         // Remove `old`, get the corresponding index and dtype, and move the last item in the map to that position
         let (old_index, old_name, dtype) = self.inner.swap_remove_full(old)?;
         // Insert the same dtype under the new name at the end of the map and store that index
@@ -130,7 +129,7 @@ impl Schema {
     pub fn new_inserting_at_index(
         &self,
         index: usize,
-        name: SmartString,
+        name: PlSmallStr,
         dtype: DataType,
     ) -> PolarsResult<Self> {
         polars_ensure!(
@@ -168,7 +167,7 @@ impl Schema {
     pub fn insert_at_index(
         &mut self,
         mut index: usize,
-        name: SmartString,
+        name: PlSmallStr,
         dtype: DataType,
     ) -> PolarsResult<Option<DataType>> {
         polars_ensure!(
@@ -211,14 +210,14 @@ impl Schema {
     /// Return all data about the field named `name`: its index in the schema, its name, and its dtype
     ///
     /// Returns `Some((index, &name, &dtype))` if the field exists, `None` if it doesn't.
-    pub fn get_full(&self, name: &str) -> Option<(usize, &SmartString, &DataType)> {
+    pub fn get_full(&self, name: &str) -> Option<(usize, &PlSmallStr, &DataType)> {
         self.inner.get_full(name)
     }
 
     /// Return all data about the field named `name`: its index in the schema, its name, and its dtype
     ///
     /// Returns `Ok((index, &name, &dtype))` if the field exists, `Err(PolarsErr)` if it doesn't.
-    pub fn try_get_full(&self, name: &str) -> PolarsResult<(usize, &SmartString, &DataType)> {
+    pub fn try_get_full(&self, name: &str) -> PolarsResult<(usize, &PlSmallStr, &DataType)> {
         self.inner
             .get_full(name)
             .ok_or_else(|| polars_err!(SchemaFieldNotFound: "{}", name))
@@ -253,11 +252,11 @@ impl Schema {
     ///
     /// If `index` is inbounds, returns `Some((&name, &dtype))`, else `None`. See
     /// [`get_at_index_mut`][Self::get_at_index_mut] for a mutable version.
-    pub fn get_at_index(&self, index: usize) -> Option<(&SmartString, &DataType)> {
+    pub fn get_at_index(&self, index: usize) -> Option<(&PlSmallStr, &DataType)> {
         self.inner.get_index(index)
     }
 
-    pub fn try_get_at_index(&self, index: usize) -> PolarsResult<(&SmartString, &DataType)> {
+    pub fn try_get_at_index(&self, index: usize) -> PolarsResult<(&PlSmallStr, &DataType)> {
         self.inner.get_index(index).ok_or_else(|| polars_err!(ComputeError: "index {index} out of bounds with 'schema' of len: {}", self.len()))
     }
 
@@ -265,7 +264,7 @@ impl Schema {
     ///
     /// If `index` is inbounds, returns `Some((&mut name, &mut dtype))`, else `None`. See
     /// [`get_at_index`][Self::get_at_index] for an immutable version.
-    pub fn get_at_index_mut(&mut self, index: usize) -> Option<(&mut SmartString, &mut DataType)> {
+    pub fn get_at_index_mut(&mut self, index: usize) -> Option<(&mut PlSmallStr, &mut DataType)> {
         self.inner.get_index_mut2(index)
     }
 
@@ -296,7 +295,7 @@ impl Schema {
     ///
     /// This method does a `shift_remove`, which preserves the order of the fields in the schema but **is O(n)**. For a
     /// faster, but not order-preserving, method, use [`remove`][Self::remove].
-    pub fn shift_remove_index(&mut self, index: usize) -> Option<(SmartString, DataType)> {
+    pub fn shift_remove_index(&mut self, index: usize) -> Option<(PlSmallStr, DataType)> {
         self.inner.shift_remove_index(index)
     }
 
@@ -341,7 +340,7 @@ impl Schema {
     /// To enforce the index of the resulting field, use [`insert_at_index`][Self::insert_at_index].
     ///
     /// Computes in **O(1)** time (amortized average).
-    pub fn with_column(&mut self, name: SmartString, dtype: DataType) -> Option<DataType> {
+    pub fn with_column(&mut self, name: PlSmallStr, dtype: DataType) -> Option<DataType> {
         self.inner.insert(name, dtype)
     }
 
@@ -382,14 +381,14 @@ impl Schema {
     }
 
     /// Iterates over references to the names in this schema
-    pub fn iter_names(&self) -> impl Iterator<Item = &SmartString> + '_ + ExactSizeIterator {
+    pub fn iter_names(&self) -> impl Iterator<Item = &PlSmallStr> + '_ + ExactSizeIterator {
         self.inner.iter().map(|(name, _dtype)| name)
     }
 
     /// Iterates over the `(&name, &dtype)` pairs in this schema
     ///
     /// For an owned version, use [`iter_fields`][Self::iter_fields], which clones the data to iterate owned `Field`s
-    pub fn iter(&self) -> impl Iterator<Item = (&SmartString, &DataType)> + '_ {
+    pub fn iter(&self) -> impl Iterator<Item = (&PlSmallStr, &DataType)> + '_ {
         self.inner.iter()
     }
 
@@ -412,8 +411,8 @@ impl Schema {
 pub type SchemaRef = Arc<Schema>;
 
 impl IntoIterator for Schema {
-    type Item = (SmartString, DataType);
-    type IntoIter = <PlIndexMap<SmartString, DataType> as IntoIterator>::IntoIter;
+    type Item = (PlSmallStr, DataType);
+    type IntoIter = <PlIndexMap<PlSmallStr, DataType> as IntoIterator>::IntoIter;
 
     fn into_iter(self) -> Self::IntoIter {
         self.inner.into_iter()
@@ -11,6 +11,7 @@ mod into;
 pub(crate) mod iterator;
 pub mod ops;
 mod series_trait;
+mod stats;
 pub mod unstable;
 
 use std::borrow::Cow;
@@ -26,6 +27,7 @@ pub use iterator::{SeriesIter, SeriesPhysIter};
 use num_traits::NumCast;
 use rayon::prelude::*;
 pub use series_trait::{IsSorted, *};
+pub use stats::SeriesStats;
 
 use crate::chunked_array::Settings;
 #[cfg(feature = "zip_with")]
@@ -187,6 +189,11 @@ impl Series {
         ca.chunks_mut()
     }
 
+    /// Compute the [`SeriesStats`] of the values.
+    pub fn stats(&self) -> SeriesStats {
+        SeriesStats::compute(self)
+    }
+
     pub fn is_sorted_flag(&self) -> IsSorted {
         let flags = self.get_flags();
         if flags.contains(Settings::SORTED_DSC) {
@@ -0,0 +1,84 @@
+use crate::prelude::*;
+
+/// Statistics of the values of a [`Series`], see [`Series::stats`].
+#[derive(Clone, Debug)]
+pub struct SeriesStats {
+    min: Series,
+    max: Series,
+    null_count: usize,
+    n_unique: Option<usize>,
+}
+
+impl SeriesStats {
+    pub(crate) fn compute(s: &Series) -> Self {
+        SeriesStats {
+            min: s.min_as_series(),
+            max: s.max_as_series(),
+            null_count: s.null_count(),
+            n_unique: s.n_unique().ok(),
+        }
+    }
+
+    /// The minimum, or null if there are no non-null values or the data type has no order.
+    pub fn min(&self) -> AnyValue<'_> {
+        self.min.get(0).unwrap_or(AnyValue::Null)
+    }
+
+    /// The maximum, or null if there are no non-null values or the data type has no order.
+    pub fn max(&self) -> AnyValue<'_> {
+        self.max.get(0).unwrap_or(AnyValue::Null)
+    }
+
+    /// The minimum as a [`Series`] of length 1 with the data type of the values.
+    pub fn min_as_series(&self) -> &Series {
+        &self.min
+    }
+
+    /// The maximum as a [`Series`] of length 1 with the data type of the values.
+    pub fn max_as_series(&self) -> &Series {
+        &self.max
+    }
+
+    pub fn null_count(&self) -> usize {
+        self.null_count
+    }
+
+    /// The number of unique values, or `None` if the data type doesn't support it.
+    pub fn n_unique(&self) -> Option<usize> {
+        self.n_unique
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_stats() -> PolarsResult<()> {
+        let s = Series::new("a", &[Some(3i32), None, Some(1), Some(3)]);
+        let stats = s.stats();
+        assert_eq!(stats.min(), AnyValue::Int32(1));
+        assert_eq!(stats.max(), AnyValue::Int32(3));
+        assert_eq!(stats.null_count(), 1);
+        assert_eq!(stats.n_unique(), Some(3));
+
+        // the statistics have the logical type of the values
+        #[cfg(feature = "dtype-date")]
+        {
+            let s = s.cast(&DataType::Date)?;
+            assert_eq!(s.stats().max().dtype(), DataType::Date);
+        }
+        #[cfg(all(feature = "dtype-datetime", feature = "timezones"))]
+        {
+            let tz = Some("Europe/Amsterdam".to_string());
+            let s = s
+                .cast(&DataType::Int64)?
+                .cast(&DataType::Datetime(TimeUnit::Milliseconds, tz.clone()))?;
+            assert_eq!(
+                s.stats().max().dtype(),
+                DataType::Datetime(TimeUnit::Milliseconds, tz)
+            );
+        }
+        Ok(())
+    }
+}
@@ -4,16 +4,16 @@ mod supertype;
 use std::borrow::Cow;
 use std::ops::{Deref, DerefMut};
 
+pub use arrow;
 use arrow::bitmap::bitmask::BitMask;
 use arrow::bitmap::Bitmap;
 use flatten::*;
 use num_traits::{One, Zero};
 pub use polars_arrow::utils::{TrustMyLength, *};
+pub use rayon;
 use rayon::prelude::*;
 pub use series::*;
-use smartstring::alias::String as SmartString;
 pub use supertype::*;
-pub use {arrow, rayon};
 
 pub use crate::chunked_array::ops::sort::arg_sort_no_nulls;
 use crate::prelude::*;
@@ -786,12 +786,12 @@ where
     }
 }
 
-impl<I, S> IntoVec<SmartString> for I
+impl<I, S> IntoVec<PlSmallStr> for I
 where
     I: IntoIterator<Item = S>,
     S: AsRef<str>,
 {
-    fn into_vec(self) -> Vec<SmartString> {
+    fn into_vec(self) -> Vec<PlSmallStr> {
         self.into_iter().map(|s| s.as_ref().into()).collect()
     }
 }
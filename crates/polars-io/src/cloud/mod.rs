@@ -7,12 +7,17 @@ use std::str::FromStr;
 #[cfg(feature = "cloud")]
 use std::sync::Arc;
 
+use bytes::Bytes;
 #[cfg(feature = "cloud")]
 use object_store::local::LocalFileSystem;
 #[cfg(feature = "cloud")]
+use object_store::path::Path as ObjectPath;
+#[cfg(feature = "cloud")]
 use object_store::ObjectStore;
 #[cfg(feature = "cloud")]
 use polars_core::prelude::{polars_bail, PolarsError, PolarsResult};
+#[cfg(feature = "cloud")]
+use polars_error::to_compute_err;
 
 #[cfg(feature = "cloud")]
 mod adaptors;
@@ -95,3 +100,35 @@ pub async fn build_object_store(url: &str, _options: Option<&CloudOptions>) -> B
     }?;
     Ok((cloud_location, store))
 }
+
+/// The bytes of an object that was downloaded as a whole, see `fetch_object`.
+#[derive(Clone)]
+pub struct ObjectBytes(pub Bytes);
+
+impl AsRef<[u8]> for ObjectBytes {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl std::fmt::Debug for ObjectBytes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ObjectBytes({} bytes)", self.0.len())
+    }
+}
+
+/// Download the whole object at the url. This is used for formats that can't be read by byte
+/// ranges, e.g. CSV.
+#[cfg(feature = "cloud")]
+pub async fn fetch_object(url: &str, options: Option<&CloudOptions>) -> PolarsResult<ObjectBytes> {
+    let (CloudLocation { prefix, .. }, store) = build_object_store(url, options).await?;
+    let path = ObjectPath::from_url_path(prefix).map_err(to_compute_err)?;
+    store
+        .get(&path)
+        .await
+        .map_err(to_compute_err)?
+        .bytes()
+        .await
+        .map(ObjectBytes)
+        .map_err(to_compute_err)
+}
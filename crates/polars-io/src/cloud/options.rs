@@ -19,18 +19,18 @@ use object_store::{BackoffConfig, RetryConfig};
 #[cfg(feature = "aws")]
 use once_cell::sync::Lazy;
 use polars_core::error::{PolarsError, PolarsResult};
+#[cfg(feature = "aws")]
+use polars_core::prelude::PlSmallStr;
 use polars_error::*;
 #[cfg(feature = "aws")]
 use polars_utils::cache::FastFixedCache;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
-#[cfg(feature = "aws")]
-use smartstring::alias::String as SmartString;
 #[cfg(feature = "cloud")]
 use url::Url;
 
 #[cfg(feature = "aws")]
-static BUCKET_REGION: Lazy<tokio::sync::Mutex<FastFixedCache<SmartString, SmartString>>> =
+static BUCKET_REGION: Lazy<tokio::sync::Mutex<FastFixedCache<PlSmallStr, PlSmallStr>>> =
     Lazy::new(|| tokio::sync::Mutex::new(FastFixedCache::default()));
 
 /// The type of the config keys must satisfy the following requirements:
@@ -51,7 +51,7 @@ mod write;
 pub(super) mod write_impl;
 
 use std::fs::File;
-use std::io::Write;
+use std::io::{Cursor, Write};
 use std::path::PathBuf;
 
 use polars_core::prelude::*;
@@ -69,4 +69,4 @@ use crate::csv::read_impl::CoreReader;
 use crate::mmap::MmapBytesReader;
 use crate::predicates::PhysicalIoExpr;
 use crate::utils::{get_reader_bytes, resolve_homedir};
-use crate::{RowCount, SerReader, SerWriter};
+use crate::{ProgressCallback, RowCount, SerReader, SerWriter};
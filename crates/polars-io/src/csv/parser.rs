@@ -364,6 +364,8 @@ pub(super) fn parse_lines<'a>(
     // length of original schema
     schema_len: usize,
     schema: &Schema,
+    // if given, the byte offset of every parsed line is pushed here
+    mut byte_offsets: Option<&mut Vec<u64>>,
 ) -> PolarsResult<usize> {
     assert!(
         !projection.is_empty(),
@@ -409,6 +411,10 @@ pub(super) fn parse_lines<'a>(
             }
         }
 
+        if let Some(byte_offsets) = byte_offsets.as_mut() {
+            byte_offsets.push((offset + bytes.as_ptr() as usize - start) as u64);
+        }
+
         // Every line we only need to parse the columns that are projected.
         // Therefore we check if the idx of the field is in our projected columns.
         // If it is not, we skip the field.
@@ -3,7 +3,7 @@ use crate::csv::read_impl::{
     to_batched_owned_mmap, to_batched_owned_read, BatchedCsvReaderMmap, BatchedCsvReaderRead,
     OwnedBatchedCsvReader, OwnedBatchedCsvReaderMmap,
 };
-use crate::csv::utils::infer_file_schema;
+use crate::csv::utils::{infer_file_schema, transcode_to_utf8};
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -12,6 +12,14 @@ pub enum CsvEncoding {
     Utf8,
     /// Utf8 encoding and unknown bytes are replaced with �
     LossyUtf8,
+    /// Latin-1 (ISO-8859-1) encoding, transcoded to utf8 before parsing
+    Latin1,
+    /// Little-endian UTF-16 encoding, transcoded to utf8 before parsing. Invalid code units
+    /// are replaced with �
+    Utf16Le,
+    /// Big-endian UTF-16 encoding, transcoded to utf8 before parsing. Invalid code units
+    /// are replaced with �
+    Utf16Be,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -125,6 +133,8 @@ where
     skip_rows_after_header: usize,
     try_parse_dates: bool,
     row_count: Option<RowCount>,
+    byte_offset: Option<String>,
+    progress: Option<ProgressCallback>,
     /// Aggregates chunk afterwards to a single chunk.
     rechunk: bool,
     raise_if_empty: bool,
@@ -152,6 +162,23 @@ where
         self
     }
 
+    /// Add a `UInt64` column with this name that holds the byte offset of every row in the
+    /// file. For compressed files these are offsets into the decompressed bytes.
+    ///
+    /// This is not supported by the batched readers.
+    pub fn with_byte_offset(mut self, name: Option<String>) -> Self {
+        self.byte_offset = name;
+        self
+    }
+
+    /// Call this function every time a chunk of the file is parsed, see [`ProgressCallback`].
+    ///
+    /// This is not supported by the batched reader that doesn't memory map the file.
+    pub fn with_progress_callback(mut self, callback: Option<ProgressCallback>) -> Self {
+        self.progress = callback;
+        self
+    }
+
     /// Sets the chunk size used by the parser. This influences performance
     pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
         self.chunk_size = chunk_size;
@@ -342,6 +369,26 @@ impl<'a> CsvReader<'a, File> {
     }
 }
 
+impl<'a> CsvReader<'a, Cursor<&'a [u8]>> {
+    /// Create a csv reader that parses `bytes` in place, without copying them into a buffer
+    /// first. This also holds for a [`Cursor`] over any other byte buffer, e.g. `bytes::Bytes`.
+    pub fn from_slice(bytes: &'a [u8]) -> Self {
+        Self::new(Cursor::new(bytes))
+    }
+
+    /// Turn the reader into a streaming reader over the slice. The batches are parsed from the
+    /// slice when they are requested, so only the rows of the current batches are materialized,
+    /// and the batched reader borrows the slice rather than this reader.
+    pub fn batched_slice(mut self) -> PolarsResult<BatchedCsvReaderMmap<'a>> {
+        let (csv_reader, has_cat) = self.batched_core_reader()?;
+        // extend lifetime. The reader bytes are either owned, if they had to be transcoded or
+        // decompressed, or borrowed from the slice, which lives for 'a, but never from `self`.
+        let csv_reader =
+            unsafe { std::mem::transmute::<CoreReader<'_>, CoreReader<'a>>(csv_reader) };
+        csv_reader.batched_mmap(has_cat)
+    }
+}
+
 impl<'a, R: MmapBytesReader + 'a> CsvReader<'a, R> {
     fn core_reader<'b>(
         &'b mut self,
@@ -382,6 +429,8 @@ impl<'a, R: MmapBytesReader + 'a> CsvReader<'a, R> {
             self.try_parse_dates,
             self.raise_if_empty,
             self.truncate_ragged_lines,
+            std::mem::take(&mut self.byte_offset),
+            self.progress.clone(),
         )
     }
 
@@ -443,29 +492,29 @@ impl<'a, R: MmapBytesReader + 'a> CsvReader<'a, R> {
         }
     }
 
-    pub fn batched_borrowed_mmap(&'a mut self) -> PolarsResult<BatchedCsvReaderMmap<'a>> {
+    /// The [`CoreReader`] of the batched readers and whether the schema has categoricals.
+    fn batched_core_reader(&mut self) -> PolarsResult<(CoreReader<'_>, bool)> {
+        polars_ensure!(
+            self.byte_offset.is_none(),
+            InvalidOperation: "byte offsets are not supported by the batched csv reader"
+        );
         if let Some(schema) = self.schema_overwrite.as_deref() {
             let (schema, to_cast, has_cat) = self.prepare_schema_overwrite(schema)?;
             let schema = Arc::new(schema);
 
-            let csv_reader = self.core_reader(Some(schema), to_cast)?;
-            csv_reader.batched_mmap(has_cat)
+            Ok((self.core_reader(Some(schema), to_cast)?, has_cat))
         } else {
-            let csv_reader = self.core_reader(self.schema.clone(), vec![])?;
-            csv_reader.batched_mmap(false)
+            Ok((self.core_reader(self.schema.clone(), vec![])?, false))
         }
     }
-    pub fn batched_borrowed_read(&'a mut self) -> PolarsResult<BatchedCsvReaderRead<'a>> {
-        if let Some(schema) = self.schema_overwrite.as_deref() {
-            let (schema, to_cast, has_cat) = self.prepare_schema_overwrite(schema)?;
-            let schema = Arc::new(schema);
 
-            let csv_reader = self.core_reader(Some(schema), to_cast)?;
-            csv_reader.batched_read(has_cat)
-        } else {
-            let csv_reader = self.core_reader(self.schema.clone(), vec![])?;
-            csv_reader.batched_read(false)
-        }
+    pub fn batched_borrowed_mmap(&'a mut self) -> PolarsResult<BatchedCsvReaderMmap<'a>> {
+        let (csv_reader, has_cat) = self.batched_core_reader()?;
+        csv_reader.batched_mmap(has_cat)
+    }
+    pub fn batched_borrowed_read(&'a mut self) -> PolarsResult<BatchedCsvReaderRead<'a>> {
+        let (csv_reader, has_cat) = self.batched_core_reader()?;
+        csv_reader.batched_read(has_cat)
     }
 }
 
@@ -477,7 +526,8 @@ impl<'a> CsvReader<'a, Box<dyn MmapBytesReader>> {
         match schema {
             Some(schema) => Ok(to_batched_owned_mmap(self, schema)),
             None => {
-                let reader_bytes = get_reader_bytes(&mut self.reader)?;
+                let reader_bytes =
+                    transcode_to_utf8(get_reader_bytes(&mut self.reader)?, self.encoding);
 
                 let (inferred_schema, _, _) = infer_file_schema(
                     &reader_bytes,
@@ -506,7 +556,8 @@ impl<'a> CsvReader<'a, Box<dyn MmapBytesReader>> {
         match schema {
             Some(schema) => Ok(to_batched_owned_read(self, schema)),
             None => {
-                let reader_bytes = get_reader_bytes(&mut self.reader)?;
+                let reader_bytes =
+                    transcode_to_utf8(get_reader_bytes(&mut self.reader)?, self.encoding);
 
                 let (inferred_schema, _, _) = infer_file_schema(
                     &reader_bytes,
@@ -565,6 +616,8 @@ where
             skip_rows_after_header: 0,
             try_parse_dates: false,
             row_count: None,
+            byte_offset: None,
+            progress: None,
             raise_if_empty: true,
             truncate_ragged_lines: false,
         }
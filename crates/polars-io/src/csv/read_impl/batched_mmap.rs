@@ -132,6 +132,9 @@ impl<'a> CoreReader<'a> {
         let projection = self.get_projection();
 
         let str_columns = self.get_string_columns(&projection)?;
+        let progress = self
+            .progress
+            .map(|callback| ProgressTracker::new(callback, bytes.len()));
 
         // RAII structure that will ensure we maintain a global stringcache
         #[cfg(feature = "dtype-categorical")]
@@ -167,6 +170,7 @@ impl<'a> CoreReader<'a> {
             delimiter: self.delimiter,
             schema: self.schema,
             rows_read: 0,
+            progress,
             _cat_lock,
         })
     }
@@ -195,6 +199,7 @@ pub struct BatchedCsvReaderMmap<'a> {
     delimiter: u8,
     schema: SchemaRef,
     rows_read: IdxSize,
+    progress: Option<ProgressTracker>,
     #[cfg(feature = "dtype-categorical")]
     _cat_lock: Option<polars_core::StringCacheHolder>,
     #[cfg(not(feature = "dtype-categorical"))]
@@ -250,7 +255,11 @@ impl<'a> BatchedCsvReaderMmap<'a> {
                         self.chunk_size,
                         stop_at_nbytes,
                         self.starting_point_offset,
+                        None,
                     )?;
+                    if let Some(progress) = &self.progress {
+                        progress.update(stop_at_nbytes - bytes_offset_thread, df.height());
+                    }
 
                     cast_columns(&mut df, &self.to_cast, false, self.ignore_errors)?;
 
@@ -43,8 +43,30 @@ pub(crate) fn get_offsets(
     }
 }
 
+/// The source of a [`ChunkReader`].
+enum ChunkSource<'a> {
+    File(&'a File),
+    /// Bytes that are already in memory, e.g. a file that was transcoded to utf8, and the
+    /// position that is read next.
+    Bytes(ReaderBytes<'a>, usize),
+}
+
+impl Read for ChunkSource<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        match self {
+            ChunkSource::File(file) => file.read(buf),
+            ChunkSource::Bytes(bytes, position) => {
+                let mut remaining = &bytes[*position..];
+                let read = remaining.read(buf)?;
+                *position += read;
+                Ok(read)
+            },
+        }
+    }
+}
+
 struct ChunkReader<'a> {
-    file: &'a File,
+    source: ChunkSource<'a>,
     buf: Vec<u8>,
     finished: bool,
     page_size: u64,
@@ -64,7 +86,7 @@ struct ChunkReader<'a> {
 
 impl<'a> ChunkReader<'a> {
     fn new(
-        file: &'a File,
+        source: ChunkSource<'a>,
         rows_per_batch: usize,
         expected_fields: usize,
         delimiter: u8,
@@ -73,7 +95,7 @@ impl<'a> ChunkReader<'a> {
         page_size: u64,
     ) -> Self {
         Self {
-            file,
+            source,
             buf: vec![],
             buf_end: 0,
             offsets: VecDeque::new(),
@@ -115,7 +137,8 @@ impl<'a> ChunkReader<'a> {
 
         if self.buf.len() <= self.page_size as usize {
             let read = self
-                .file
+                .source
+                .by_ref()
                 .take(self.page_size)
                 .read_to_end(&mut self.buf)
                 .unwrap();
@@ -141,7 +164,8 @@ impl<'a> ChunkReader<'a> {
                     break;
                 } else {
                     let read = self
-                        .file
+                        .source
+                        .by_ref()
                         .take(self.page_size)
                         .read_to_end(&mut self.buf)
                         .unwrap();
@@ -159,7 +183,8 @@ impl<'a> ChunkReader<'a> {
         if self.buf.len() < expected_bytes {
             let to_read = expected_bytes - self.buf.len();
             let read = self
-                .file
+                .source
+                .by_ref()
                 .take(to_read as u64)
                 .read_to_end(&mut self.buf)
                 .unwrap();
@@ -192,18 +217,23 @@ impl<'a> CoreReader<'a> {
     pub fn batched_read(mut self, _has_cat: bool) -> PolarsResult<BatchedCsvReaderRead<'a>> {
         let reader_bytes = self.reader_bytes.take().unwrap();
 
-        let ReaderBytes::Mapped(bytes, mut file) = &reader_bytes else {
-            unreachable!()
-        };
         let (_, starting_point_offset) =
-            self.find_starting_point(bytes, self.quote_char, self.eol_char)?;
-        if let Some(starting_point_offset) = starting_point_offset {
-            file.seek(SeekFrom::Current(starting_point_offset as i64))
-                .unwrap();
-        }
+            self.find_starting_point(&reader_bytes, self.quote_char, self.eol_char)?;
+        let source = match reader_bytes {
+            ReaderBytes::Mapped(_, mut file) => {
+                if let Some(starting_point_offset) = starting_point_offset {
+                    file.seek(SeekFrom::Current(starting_point_offset as i64))
+                        .unwrap();
+                }
+                ChunkSource::File(file)
+            },
+            // transcoded files and files that are read into memory on wasm targets, see
+            // `get_reader_bytes`
+            bytes => ChunkSource::Bytes(bytes, starting_point_offset.unwrap_or(0)),
+        };
 
         let chunk_iter = ChunkReader::new(
-            file,
+            source,
             self.chunk_size,
             self.schema.len(),
             self.delimiter,
@@ -347,6 +377,7 @@ impl<'a> BatchedCsvReaderRead<'a> {
                         self.chunk_size,
                         stop_at_n_bytes,
                         self.starting_point_offset,
+                        None,
                     )?;
 
                     cast_columns(&mut df, &self.to_cast, false, self.ignore_errors)?;
@@ -26,7 +26,7 @@ use crate::csv::{CsvEncoding, NullValues};
 use crate::mmap::ReaderBytes;
 use crate::predicates::PhysicalIoExpr;
 use crate::utils::update_row_counts;
-use crate::RowCount;
+use crate::{ProgressCallback, ProgressTracker, RowCount};
 
 pub(crate) fn cast_columns(
     df: &mut DataFrame,
@@ -123,6 +123,8 @@ pub(crate) struct CoreReader<'a> {
     to_cast: Vec<Field>,
     row_count: Option<RowCount>,
     truncate_ragged_lines: bool,
+    byte_offset: Option<String>,
+    progress: Option<ProgressCallback>,
 }
 
 impl<'a> fmt::Debug for CoreReader<'a> {
@@ -215,9 +217,15 @@ impl<'a> CoreReader<'a> {
         try_parse_dates: bool,
         raise_if_empty: bool,
         truncate_ragged_lines: bool,
+        byte_offset: Option<String>,
+        progress: Option<ProgressCallback>,
     ) -> PolarsResult<CoreReader<'a>> {
-        #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
-        let mut reader_bytes = reader_bytes;
+        // compressed bytes are transcoded after they are decompressed
+        #[cfg_attr(
+            not(any(feature = "decompress", feature = "decompress-fast")),
+            allow(unused_mut)
+        )]
+        let mut reader_bytes = transcode_to_utf8(reader_bytes, encoding);
 
         #[cfg(not(any(feature = "decompress", feature = "decompress-fast")))]
         if is_compressed(&reader_bytes) {
@@ -241,7 +249,7 @@ impl<'a> CoreReader<'a> {
                     if let Some(b) =
                         decompress(&reader_bytes, n_rows, delimiter, quote_char, eol_char)
                     {
-                        reader_bytes = ReaderBytes::Owned(b);
+                        reader_bytes = transcode_to_utf8(ReaderBytes::Owned(b), encoding);
                     }
 
                     let (inferred_schema, _, _) = infer_file_schema(
@@ -313,6 +321,8 @@ impl<'a> CoreReader<'a> {
             to_cast,
             row_count,
             truncate_ragged_lines,
+            byte_offset,
+            progress,
         })
     }
 
@@ -559,6 +569,10 @@ impl<'a> CoreReader<'a> {
         if bytes.is_empty() {
             return Ok(DataFrame::from(self.schema.as_ref()));
         }
+        let progress = self
+            .progress
+            .clone()
+            .map(|callback| ProgressTracker::new(callback, bytes.len()));
 
         // all the buffers returned from the threads
         // Structure:
@@ -593,6 +607,10 @@ impl<'a> CoreReader<'a> {
                             )?;
 
                             let local_bytes = &bytes[read..stop_at_nbytes];
+                            let mut byte_offsets = self
+                                .byte_offset
+                                .as_ref()
+                                .map(|_| Vec::with_capacity(chunk_size));
 
                             last_read = read;
                             let offset = read + starting_point_offset.unwrap();
@@ -612,15 +630,18 @@ impl<'a> CoreReader<'a> {
                                 chunk_size,
                                 self.schema.len(),
                                 &self.schema,
+                                byte_offsets.as_mut(),
                             )?;
 
-                            let mut local_df = DataFrame::new_no_checks(
-                                buffers
-                                    .into_iter()
-                                    .map(|buf| buf.into_series())
-                                    .collect::<PolarsResult<_>>()?,
-                            );
+                            let mut local_df = buffers_into_df(
+                                buffers,
+                                self.byte_offset.as_deref(),
+                                byte_offsets,
+                            )?;
                             let current_row_count = local_df.height() as IdxSize;
+                            if let Some(progress) = &progress {
+                                progress.update(read - last_read, current_row_count as usize);
+                            }
                             if let Some(rc) = &self.row_count {
                                 local_df.with_row_count_mut(&rc.name, Some(rc.offset));
                             };
@@ -682,8 +703,13 @@ impl<'a> CoreReader<'a> {
                             usize::MAX,
                             stop_at_nbytes,
                             starting_point_offset,
+                            self.byte_offset.as_deref(),
                         )?;
 
+                        if let Some(progress) = &progress {
+                            progress.update(stop_at_nbytes - bytes_offset_thread, df.height());
+                        }
+
                         // update the running str bytes statistics
                         if !self.low_memory {
                             update_string_stats(&str_capacities, &str_columns, &df)?;
@@ -714,9 +740,16 @@ impl<'a> CoreReader<'a> {
                                 self.ignore_errors,
                             )?;
 
+                            let mut byte_offsets = self
+                                .byte_offset
+                                .as_ref()
+                                .map(|_| Vec::with_capacity(remaining_rows));
+                            let offset = remaining_bytes.as_ptr() as usize
+                                - bytes.as_ptr() as usize
+                                + starting_point_offset.unwrap();
                             parse_lines(
                                 remaining_bytes,
-                                0,
+                                offset,
                                 self.delimiter,
                                 self.comment_char,
                                 self.quote_char,
@@ -730,14 +763,10 @@ impl<'a> CoreReader<'a> {
                                 remaining_rows - 1,
                                 self.schema.len(),
                                 self.schema.as_ref(),
+                                byte_offsets.as_mut(),
                             )?;
 
-                            DataFrame::new_no_checks(
-                                buffers
-                                    .into_iter()
-                                    .map(|buf| buf.into_series())
-                                    .collect::<PolarsResult<_>>()?,
-                            )
+                            buffers_into_df(buffers, self.byte_offset.as_deref(), byte_offsets)?
                         };
 
                         cast_columns(&mut df, &self.to_cast, false, self.ignore_errors)?;
@@ -812,6 +841,7 @@ fn read_chunk(
     chunk_size: usize,
     stop_at_nbytes: usize,
     starting_point_offset: Option<usize>,
+    byte_offset: Option<&str>,
 ) -> PolarsResult<DataFrame> {
     let mut read = bytes_offset_thread;
     let mut buffers = init_buffers(
@@ -823,6 +853,7 @@ fn read_chunk(
         encoding,
         ignore_errors,
     )?;
+    let mut byte_offsets = byte_offset.map(|_| Vec::with_capacity(capacity));
 
     let mut last_read = usize::MAX;
     loop {
@@ -849,15 +880,28 @@ fn read_chunk(
             chunk_size,
             schema.len(),
             schema,
+            byte_offsets.as_mut(),
         )?;
     }
 
-    Ok(DataFrame::new_no_checks(
-        buffers
-            .into_iter()
-            .map(|buf| buf.into_series())
-            .collect::<PolarsResult<_>>()?,
-    ))
+    buffers_into_df(buffers, byte_offset, byte_offsets)
+}
+
+/// Create a [`DataFrame`] from the parsed buffers. If requested, the byte offsets of the
+/// parsed lines are added as last column.
+fn buffers_into_df(
+    buffers: Vec<Buffer>,
+    byte_offset: Option<&str>,
+    byte_offsets: Option<Vec<u64>>,
+) -> PolarsResult<DataFrame> {
+    let mut columns = buffers
+        .into_iter()
+        .map(|buf| buf.into_series())
+        .collect::<PolarsResult<Vec<_>>>()?;
+    if let (Some(name), Some(byte_offsets)) = (byte_offset, byte_offsets) {
+        columns.push(UInt64Chunked::from_vec(name, byte_offsets).into_series())
+    }
+    Ok(DataFrame::new_no_checks(columns))
 }
 
 /// List of strings, which are stored inside of a [Schema].
@@ -124,13 +124,49 @@ pub(crate) fn parse_bytes_with_encoding(
     encoding: CsvEncoding,
 ) -> PolarsResult<Cow<str>> {
     Ok(match encoding {
-        CsvEncoding::Utf8 => simdutf8::basic::from_utf8(bytes)
-            .map_err(|_| polars_err!(ComputeError: "invalid utf-8 sequence"))?
-            .into(),
+        // the other encodings are transcoded to utf8 before parsing
+        CsvEncoding::Utf8 | CsvEncoding::Latin1 | CsvEncoding::Utf16Le | CsvEncoding::Utf16Be => {
+            simdutf8::basic::from_utf8(bytes)
+                .map_err(|_| polars_err!(ComputeError: "invalid utf-8 sequence"))?
+                .into()
+        },
         CsvEncoding::LossyUtf8 => String::from_utf8_lossy(bytes),
     })
 }
 
+/// Transcode the bytes of a CSV file in `encoding` to utf8, so that they can be parsed.
+///
+/// Bytes in one of the utf8 encodings and compressed bytes are returned as is. The latter are
+/// transcoded after they are decompressed.
+pub fn transcode_to_utf8(bytes: ReaderBytes<'_>, encoding: CsvEncoding) -> ReaderBytes<'_> {
+    if is_compressed(&bytes) {
+        return bytes;
+    }
+    let out = match encoding {
+        CsvEncoding::Utf8 | CsvEncoding::LossyUtf8 => return bytes,
+        // every byte is the code point of its char
+        CsvEncoding::Latin1 => bytes.iter().map(|&b| b as char).collect::<String>(),
+        CsvEncoding::Utf16Le => decode_utf16(&bytes, u16::from_le_bytes),
+        CsvEncoding::Utf16Be => decode_utf16(&bytes, u16::from_be_bytes),
+    };
+    ReaderBytes::Owned(out.into_bytes())
+}
+
+fn decode_utf16(bytes: &[u8], to_u16: fn([u8; 2]) -> u16) -> String {
+    let units = bytes.chunks_exact(2);
+    let has_remainder = !units.remainder().is_empty();
+    let mut out = String::with_capacity(bytes.len() / 2);
+    // a byte order mark is transcoded as well and skipped by the parser
+    out.extend(
+        char::decode_utf16(units.map(|unit| to_u16([unit[0], unit[1]])))
+            .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER)),
+    );
+    if has_remainder {
+        out.push(char::REPLACEMENT_CHARACTER);
+    }
+    out
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn infer_file_schema_inner(
     reader_bytes: &ReaderBytes,
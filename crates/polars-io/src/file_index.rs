@@ -0,0 +1,349 @@
+//! # Data skipping index files.
+//!
+//! A file index is a small sidecar file written next to a data file. It holds whole-file
+//! statistics (min, max and null count) and optional bloom filters per column. Scans consult it
+//! to skip files that cannot match a predicate, without opening the data file itself. This
+//! prunes beyond parquet row group statistics and also works for IPC files.
+//!
+//! ## Example
+//!
+//! ```no_run
+//! use polars_core::prelude::*;
+//! use polars_io::file_index::write_file_index;
+//! use polars_io::prelude::*;
+//! use std::fs::File;
+//! use std::path::Path;
+//!
+//! fn example(df: &mut DataFrame) -> PolarsResult<()> {
+//!     let path = Path::new("data.parquet");
+//!     ParquetWriter::new(File::create(path)?).finish(df)?;
+//!     // writes `data.parquet.plidx`
+//!     write_file_index(df, path, &["id".to_string()])
+//! }
+//! ```
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use arrow::io::parquet::bloom_filter::hash_byte;
+use polars_core::prelude::*;
+
+use crate::parquet::{BloomFilter, ParquetReader, ParquetWriter};
+use crate::predicates::{BatchStats, ColumnStats};
+use crate::SerReader;
+
+/// Extension appended to the path of the data file.
+pub const FILE_INDEX_EXTENSION: &str = "plidx";
+
+const FILE_SIZE: &str = "meta:file_size";
+const MODIFIED: &str = "meta:modified";
+const CHECKSUM: &str = "meta:checksum";
+/// Number of bytes at the start and at the end of the data file covered by the checksum. For
+/// parquet and IPC files this includes the footer, which holds the schema and the offsets (and for
+/// parquet the statistics) of the row groups.
+const CHECKSUM_BYTES: u64 = 64 * 1024;
+const MIN: &str = "min";
+const MAX: &str = "max";
+const NULL_COUNT: &str = "null_count";
+const BLOOM: &str = "bloom";
+
+/// Get the path of the index that belongs to the data file at `path`.
+pub fn file_index_path(path: &Path) -> PathBuf {
+    let mut index_path = path.as_os_str().to_owned();
+    index_path.push(".");
+    index_path.push(FILE_INDEX_EXTENSION);
+    PathBuf::from(index_path)
+}
+
+/// Identifies the version of a data file an index was computed for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FileFingerprint {
+    /// Size of the data file in bytes.
+    pub size: u64,
+    /// Modification time in nanoseconds since the unix epoch, or 0 if the platform doesn't record
+    /// it.
+    pub modified: u64,
+    /// Hash of the first and last 64 KiB of the data file.
+    pub checksum: u64,
+}
+
+impl FileFingerprint {
+    /// Compute the fingerprint of the data file at `path`.
+    pub fn new(path: &Path) -> PolarsResult<Self> {
+        let metadata = std::fs::metadata(path)?;
+        let size = metadata.len();
+        let modified = metadata
+            .modified()
+            .ok()
+            .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+            .map_or(0, |d| d.as_nanos() as u64);
+
+        let mut file = File::open(path)?;
+        let mut bytes = Vec::with_capacity(std::cmp::min(size, 2 * CHECKSUM_BYTES) as usize);
+        (&mut file).take(CHECKSUM_BYTES).read_to_end(&mut bytes)?;
+        let tail_start = std::cmp::max(CHECKSUM_BYTES, size.saturating_sub(CHECKSUM_BYTES));
+        if tail_start < size {
+            file.seek(SeekFrom::Start(tail_start))?;
+            file.read_to_end(&mut bytes)?;
+        }
+
+        Ok(Self {
+            size,
+            modified,
+            checksum: hash_byte(&bytes),
+        })
+    }
+}
+
+/// Whole-file statistics of a data file.
+#[derive(Debug)]
+pub struct FileIndex {
+    /// Fingerprint of the data file at the moment the index was written.
+    /// Used to detect stale indexes.
+    fingerprint: FileFingerprint,
+    stats: BatchStats,
+}
+
+impl FileIndex {
+    /// Compute the index of `df`. Bloom filters are only built for `bloom_filter_columns`.
+    pub fn new(
+        df: &DataFrame,
+        bloom_filter_columns: &[String],
+        fingerprint: FileFingerprint,
+    ) -> PolarsResult<Self> {
+        let mut schema = Schema::with_capacity(df.width());
+        let mut stats = Vec::with_capacity(df.width());
+
+        for s in df.get_columns() {
+            if !ColumnStats::use_min_max(s.dtype()) {
+                continue;
+            }
+            let null_count = Series::new(NULL_COUNT, &[s.null_count() as IdxSize]);
+            let mut column_stats = ColumnStats::new(
+                s.field().into_owned(),
+                Some(null_count),
+                Some(s.min_as_series()),
+                Some(s.max_as_series()),
+            );
+            if bloom_filter_columns.iter().any(|name| name == s.name()) {
+                if let Some(bloom_filter) = BloomFilter::from_series(s)? {
+                    column_stats = column_stats.with_bloom_filter(bloom_filter);
+                }
+            }
+            schema.with_column(s.name().into(), s.dtype().clone());
+            stats.push(column_stats);
+        }
+
+        Ok(Self {
+            fingerprint,
+            stats: BatchStats::new(schema, stats),
+        })
+    }
+
+    /// The fingerprint of the data file the index was computed for.
+    pub fn fingerprint(&self) -> FileFingerprint {
+        self.fingerprint
+    }
+
+    /// The statistics of the data file.
+    pub fn stats(&self) -> &BatchStats {
+        &self.stats
+    }
+
+    /// Write the index to `path`.
+    pub fn write(&self, path: &Path) -> PolarsResult<()> {
+        let mut columns = vec![
+            Series::new(FILE_SIZE, &[self.fingerprint.size]),
+            Series::new(MODIFIED, &[self.fingerprint.modified]),
+            Series::new(CHECKSUM, &[self.fingerprint.checksum]),
+        ];
+
+        for (column_stats, (name, _)) in self
+            .stats
+            .column_stats()
+            .iter()
+            .zip(self.stats.schema().iter())
+        {
+            let stat_name = |stat: &str| format!("{stat}:{name}");
+            if let Some(min) = column_stats.get_min_state() {
+                columns.push(min.clone().with_name(&stat_name(MIN)));
+            }
+            if let Some(max) = column_stats.get_max_state() {
+                columns.push(max.clone().with_name(&stat_name(MAX)));
+            }
+            if let Some(null_count) = column_stats.null_count() {
+                columns.push(Series::new(
+                    &stat_name(NULL_COUNT),
+                    &[null_count as IdxSize],
+                ));
+            }
+            if let Some(bloom_filter) = column_stats.bloom_filter() {
+                let bitset = [bloom_filter.bitset()];
+                columns.push(BinaryChunked::from_slice(&stat_name(BLOOM), &bitset).into_series());
+            }
+        }
+
+        let mut df = DataFrame::new(columns)?;
+        ParquetWriter::new(File::create(path)?).finish(&mut df)?;
+        Ok(())
+    }
+
+    /// Read an index written by [`FileIndex::write`].
+    pub fn read(path: &Path) -> PolarsResult<Self> {
+        let df = ParquetReader::new(File::open(path)?).finish()?;
+        let meta = |name: &str| -> PolarsResult<u64> {
+            match df.column(name).ok().and_then(|s| s.u64().ok()?.get(0)) {
+                Some(v) => Ok(v),
+                None => polars_bail!(
+                    ComputeError: "invalid file index {}: missing {}", path.display(), name
+                ),
+            }
+        };
+        let fingerprint = FileFingerprint {
+            size: meta(FILE_SIZE)?,
+            modified: meta(MODIFIED)?,
+            checksum: meta(CHECKSUM)?,
+        };
+
+        // stats are keyed by the column name and stored in order min, max, null count, bloom
+        let mut columns: PlIndexMap<&str, [Option<&Series>; 4]> = PlIndexMap::default();
+        for s in df.get_columns() {
+            let Some((stat, name)) = s.name().split_once(':') else {
+                continue;
+            };
+            let idx = match stat {
+                MIN => 0,
+                MAX => 1,
+                NULL_COUNT => 2,
+                BLOOM => 3,
+                _ => continue,
+            };
+            columns.entry(name).or_default()[idx] = Some(s);
+        }
+
+        let mut schema = Schema::with_capacity(columns.len());
+        let mut stats = Vec::with_capacity(columns.len());
+        for (name, [min, max, null_count, bloom]) in columns {
+            let Some(dtype) = min.or(max).map(|s| s.dtype()) else {
+                continue;
+            };
+            let mut column_stats = ColumnStats::new(
+                Field::new(name, dtype.clone()),
+                null_count.cloned(),
+                min.map(|s| s.clone().with_name(name)),
+                max.map(|s| s.clone().with_name(name)),
+            );
+            if let Some(bitset) = bloom.and_then(|s| s.binary().ok()?.get(0)) {
+                column_stats =
+                    column_stats.with_bloom_filter(BloomFilter::from_bitset(bitset.to_vec()));
+            }
+            schema.with_column(name.into(), dtype.clone());
+            stats.push(column_stats);
+        }
+
+        Ok(Self {
+            fingerprint,
+            stats: BatchStats::new(schema, stats),
+        })
+    }
+}
+
+/// Write the index of the data file at `path`, which holds the data of `df`.
+///
+/// The data file must be written before the index, as the index records its fingerprint.
+pub fn write_file_index(
+    df: &DataFrame,
+    path: &Path,
+    bloom_filter_columns: &[String],
+) -> PolarsResult<()> {
+    let fingerprint = FileFingerprint::new(path)?;
+    FileIndex::new(df, bloom_filter_columns, fingerprint)?.write(&file_index_path(path))
+}
+
+/// Read the index of the data file at `path`.
+///
+/// Returns `None` if there is no index, or if the data file changed after the index was written.
+/// A change is detected by the size, the modification time or the checksum of the data file, so
+/// a file that is rewritten with the same size isn't pruned with stale statistics.
+pub fn read_file_index(path: &Path) -> PolarsResult<Option<FileIndex>> {
+    let index_path = file_index_path(path);
+    if !index_path.exists() {
+        return Ok(None);
+    }
+    let index = FileIndex::read(&index_path)?;
+    if index.fingerprint != FileFingerprint::new(path)? {
+        if polars_core::config::verbose() {
+            eprintln!("ignoring stale file index {}", index_path.display());
+        }
+        return Ok(None);
+    }
+    Ok(Some(index))
+}
+
+#[cfg(test)]
+mod test {
+    use polars_core::df;
+    use tempdir::TempDir;
+
+    use super::*;
+    use crate::parquet::ParquetCompression;
+
+    #[test]
+    fn test_file_index_round_trip() -> PolarsResult<()> {
+        let tempdir = TempDir::new("file-index")?;
+        let path = tempdir.path().join("data.parquet");
+
+        let mut df = df!(
+            "id" => [3i64, 1, 7],
+            "name" => [Some("a"), None, Some("c")]
+        )?;
+        ParquetWriter::new(File::create(&path)?).finish(&mut df)?;
+        write_file_index(&df, &path, &["id".to_string()])?;
+
+        let index = read_file_index(&path)?.unwrap();
+        let id = index.stats().get_stats("id")?;
+        assert_eq!(id.to_min().unwrap().get(0)?, AnyValue::Int64(1));
+        assert_eq!(id.get_max_state().unwrap().get(0)?, AnyValue::Int64(7));
+        assert!(id.may_contain(&Series::new("", &[7i64])));
+        assert_eq!(index.stats().get_stats("name")?.null_count(), Some(1));
+
+        // a rewritten data file invalidates the index
+        let mut df = df.head(Some(1));
+        ParquetWriter::new(File::create(&path)?).finish(&mut df)?;
+        assert!(read_file_index(&path)?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_file_index_same_size_rewrite() -> PolarsResult<()> {
+        let tempdir = TempDir::new("file-index")?;
+        let path = tempdir.path().join("data.parquet");
+        let write = |ids: [i64; 3]| -> PolarsResult<()> {
+            let mut df = df!("id" => ids)?;
+            ParquetWriter::new(File::create(&path)?)
+                .with_compression(ParquetCompression::Uncompressed)
+                .with_statistics(false)
+                .finish(&mut df)?;
+            Ok(())
+        };
+
+        write([3, 1, 7])?;
+        let size = std::fs::metadata(&path)?.len();
+        write_file_index(&df!("id" => [3i64, 1, 7])?, &path, &[])?;
+        assert!(read_file_index(&path)?.is_some());
+
+        // the new file holds an id the index would prune, and has the same size
+        write([3, 1, 8])?;
+        assert_eq!(std::fs::metadata(&path)?.len(), size);
+        let index = read_file_index(&path)?;
+        assert!(index.is_none());
+
+        // the checksum detects the rewrite even if the modification time is unchanged
+        let mut fingerprint = FileFingerprint::new(&path)?;
+        let index = FileIndex::read(&file_index_path(&path))?;
+        fingerprint.modified = index.fingerprint().modified;
+        assert_ne!(fingerprint, index.fingerprint());
+        Ok(())
+    }
+}
@@ -71,10 +71,12 @@ pub struct IpcReader<R: MmapBytesReader> {
     pub(super) projection: Option<Vec<usize>>,
     pub(crate) columns: Option<Vec<String>>,
     pub(super) row_count: Option<RowCount>,
+    #[cfg_attr(target_family = "wasm", allow(dead_code))]
     memmap: bool,
     metadata: Option<read::FileMetadata>,
 }
 
+#[cfg(not(target_family = "wasm"))]
 fn check_mmap_err(err: PolarsError) -> PolarsResult<()> {
     if let PolarsError::ArrowError(ref e) = err {
         if let arrow::error::Error::NotYetImplemented(s) = e.as_ref() {
@@ -144,7 +146,8 @@ impl<R: MmapBytesReader> IpcReader<R> {
         self
     }
 
-    /// Set if the file is to be memory_mapped. Only works with uncompressed files.
+    /// Set if the file is to be memory_mapped. Only works with uncompressed files. Files are
+    /// never memory mapped on wasm targets.
     pub fn memory_mapped(mut self, toggle: bool) -> Self {
         self.memmap = toggle;
         self
@@ -157,6 +160,7 @@ impl<R: MmapBytesReader> IpcReader<R> {
         predicate: Option<Arc<dyn PhysicalIoExpr>>,
         verbose: bool,
     ) -> PolarsResult<DataFrame> {
+        #[cfg(not(target_family = "wasm"))]
         if self.memmap && self.reader.to_file().is_some() {
             if verbose {
                 eprintln!("memory map ipc file")
@@ -210,6 +214,7 @@ impl<R: MmapBytesReader> SerReader<R> for IpcReader<R> {
     }
 
     fn finish(mut self) -> PolarsResult<DataFrame> {
+        #[cfg(not(target_family = "wasm"))]
         if self.memmap && self.reader.to_file().is_some() {
             match self.finish_memmapped(None) {
                 Ok(df) => return Ok(df),
@@ -5,6 +5,7 @@ mod ipc_file;
 
 #[cfg(feature = "ipc_streaming")]
 mod ipc_stream;
+#[cfg(not(target_family = "wasm"))]
 mod mmap;
 #[cfg(any(feature = "ipc", feature = "ipc_streaming"))]
 mod write;
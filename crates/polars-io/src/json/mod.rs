@@ -72,6 +72,7 @@ use polars_core::error::to_compute_err;
 use polars_core::prelude::*;
 use polars_core::utils::try_get_supertype;
 use polars_json::json::infer;
+use polars_json::json::write::FallibleStreamingIterator;
 use simd_json::BorrowedValue;
 
 use crate::mmap::{MmapBytesReader, ReaderBytes};
@@ -101,13 +102,20 @@ pub enum JsonFormat {
 /// Under the hood, this uses [`arrow2::io::json`](https://docs.rs/arrow2/latest/arrow2/io/json/write/fn.write.html).
 /// `arrow2` generally serializes types that are not JSON primitives, such as Date and DateTime, as their
 /// `Display`-formatted versions. For instance, a (naive) DateTime column is formatted as the String `"yyyy-mm-dd
-/// HH:MM:SS"`. To control how non-primitive columns are serialized, convert them to String or another primitive type
-/// before serializing.
+/// HH:MM:SS"`. The format of temporal columns can be set with [`JsonWriter::with_date_format`],
+/// [`JsonWriter::with_time_format`] and [`JsonWriter::with_datetime_format`]. To control how other non-primitive columns
+/// are serialized, convert them to String or another primitive type before serializing.
+///
+/// Nested struct and list columns are serialized as JSON objects and arrays.
 #[must_use]
 pub struct JsonWriter<W: Write> {
     /// File or Stream handler
     buffer: W,
     json_format: JsonFormat,
+    column_oriented: bool,
+    date_format: Option<String>,
+    time_format: Option<String>,
+    datetime_format: Option<String>,
 }
 
 impl<W: Write> JsonWriter<W> {
@@ -115,6 +123,105 @@ impl<W: Write> JsonWriter<W> {
         self.json_format = format;
         self
     }
+
+    /// Write a single JSON object that maps the name of every column to an array of its values, instead of an object
+    /// per row. Only supported for [`JsonFormat::Json`].
+    pub fn with_column_oriented(mut self, column_oriented: bool) -> Self {
+        self.column_oriented = column_oriented;
+        self
+    }
+
+    /// Set the format of Date columns, e.g. `%d/%m/%Y`.
+    /// See [chrono strftime](https://docs.rs/chrono/latest/chrono/format/strftime/index.html).
+    pub fn with_date_format(mut self, format: Option<String>) -> Self {
+        self.date_format = format;
+        self
+    }
+
+    /// Set the format of Time columns, e.g. `%H:%M`.
+    pub fn with_time_format(mut self, format: Option<String>) -> Self {
+        self.time_format = format;
+        self
+    }
+
+    /// Set the format of Datetime columns, e.g. `%Y-%m-%dT%H:%M:%S%.3f`.
+    pub fn with_datetime_format(mut self, format: Option<String>) -> Self {
+        self.datetime_format = format;
+        self
+    }
+
+    /// Format the temporal values of `s`, including the fields of structs, as strings.
+    fn format_temporal(&self, s: &Series) -> PolarsResult<Series> {
+        let out = match s.dtype() {
+            #[cfg(feature = "dtype-date")]
+            DataType::Date if self.date_format.is_some() => {
+                let format = self.date_format.as_deref().unwrap();
+                s.date()?.try_to_string(format)?.into_series()
+            },
+            #[cfg(feature = "dtype-time")]
+            DataType::Time if self.time_format.is_some() => {
+                let format = self.time_format.as_deref().unwrap();
+                s.time()?.try_to_string(format)?.into_series()
+            },
+            #[cfg(feature = "dtype-datetime")]
+            DataType::Datetime(_, _) if self.datetime_format.is_some() => {
+                let format = self.datetime_format.as_deref().unwrap();
+                s.datetime()?.to_string(format)?.into_series()
+            },
+            #[cfg(feature = "dtype-struct")]
+            DataType::Struct(_) => {
+                let fields = s
+                    .struct_()?
+                    .fields()
+                    .iter()
+                    .map(|s| self.format_temporal(s))
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                StructChunked::new(s.name(), &fields)?.into_series()
+            },
+            _ => return Ok(s.clone()),
+        };
+        Ok(out.with_name(s.name()))
+    }
+
+    fn format_temporal_columns(&self, df: &DataFrame) -> PolarsResult<Option<DataFrame>> {
+        if self.date_format.is_none()
+            && self.time_format.is_none()
+            && self.datetime_format.is_none()
+        {
+            return Ok(None);
+        }
+        let columns = df
+            .get_columns()
+            .iter()
+            .map(|s| self.format_temporal(s))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        Ok(Some(DataFrame::new_no_checks(columns)))
+    }
+}
+
+/// Write `df` as a single JSON object that maps the name of every column to an array of its values.
+fn write_columns<W: Write>(writer: &mut W, df: &DataFrame) -> PolarsResult<()> {
+    writer.write_all(b"{")?;
+    for (i, s) in df.get_columns().iter().enumerate() {
+        if i > 0 {
+            writer.write_all(b",")?;
+        }
+        let name = serde_json::to_string(s.name()).map_err(to_compute_err)?;
+        writer.write_all(name.as_bytes())?;
+        writer.write_all(b":[")?;
+        // the serializer writes the comma separated values of an array
+        let s = s.rechunk();
+        if !s.is_empty() {
+            let arrays = std::iter::once(Ok(s.to_arrow(0)));
+            let mut serializer = polars_json::json::write::Serializer::new(arrays, vec![]);
+            if let Some(values) = serializer.next()? {
+                writer.write_all(values)?;
+            }
+        }
+        writer.write_all(b"]")?;
+    }
+    writer.write_all(b"}")?;
+    Ok(())
 }
 
 impl<W> SerWriter<W> for JsonWriter<W>
@@ -127,10 +234,24 @@ where
         JsonWriter {
             buffer,
             json_format: JsonFormat::JsonLines,
+            column_oriented: false,
+            date_format: None,
+            time_format: None,
+            datetime_format: None,
         }
     }
 
     fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
+        let mut formatted = self.format_temporal_columns(df)?;
+        let df = formatted.as_mut().unwrap_or(df);
+        if self.column_oriented {
+            polars_ensure!(
+                matches!(self.json_format, JsonFormat::Json),
+                InvalidOperation: "column-oriented JSON can only be written with `JsonFormat::Json`"
+            );
+            return write_columns(&mut self.buffer, df);
+        }
+
         df.align_chunks();
         let fields = df.iter().map(|s| s.field().to_arrow()).collect::<Vec<_>>();
         let batches = df
@@ -9,6 +9,8 @@ pub mod cloud;
 pub mod csv;
 #[cfg(feature = "parquet")]
 pub mod export;
+#[cfg(feature = "parquet")]
+pub mod file_index;
 #[cfg(any(feature = "ipc", feature = "ipc_streaming"))]
 pub mod ipc;
 #[cfg(feature = "json")]
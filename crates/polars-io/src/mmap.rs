@@ -1,6 +1,8 @@
 use std::fs::File;
 use std::io::{BufReader, Cursor, Read, Seek};
 
+use polars_core::prelude::*;
+
 /// Trait used to get a hold to file handler or to the underlying bytes
 /// without performing a Read.
 pub trait MmapBytesReader: Read + Seek + Send + Sync {
@@ -34,6 +36,35 @@ where
     }
 }
 
+/// Adapts a [`Read`] + [`Seek`] source that is neither a file nor a byte buffer, e.g. a
+/// socket or an entry of an archive, to a [`MmapBytesReader`], so that it can be passed to
+/// the readers. The readers read such a source into memory.
+///
+/// ```no_run
+/// # use std::io::{Read, Seek};
+/// # use polars_core::prelude::*;
+/// # use polars_io::prelude::*;
+/// # use polars_io::mmap::ReadSeekReader;
+/// fn read<R: Read + Seek + Send + Sync>(source: R) -> PolarsResult<DataFrame> {
+///     CsvReader::new(ReadSeekReader(source)).finish()
+/// }
+/// ```
+pub struct ReadSeekReader<R>(pub R);
+
+impl<R: Read> Read for ReadSeekReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.0.read(buf)
+    }
+}
+
+impl<R: Seek> Seek for ReadSeekReader<R> {
+    fn seek(&mut self, pos: std::io::SeekFrom) -> std::io::Result<u64> {
+        self.0.seek(pos)
+    }
+}
+
+impl<R: Read + Seek + Send + Sync> MmapBytesReader for ReadSeekReader<R> {}
+
 impl<T: MmapBytesReader + ?Sized> MmapBytesReader for Box<T> {
     fn to_file(&self) -> Option<&File> {
         T::to_file(self)
@@ -54,6 +85,29 @@ impl<T: MmapBytesReader> MmapBytesReader for &mut T {
     }
 }
 
+/// Read all bytes of a file. Files cannot be memory mapped on wasm targets, so they are read
+/// into memory instead.
+#[cfg(target_family = "wasm")]
+pub(crate) fn read_file(mut file: &File) -> std::io::Result<Vec<u8>> {
+    file.rewind()?;
+    let mut bytes = Vec::with_capacity(file.metadata().map_or(0, |m| m.len() as usize));
+    file.read_to_end(&mut bytes)?;
+    Ok(bytes)
+}
+
+/// Map a file into memory, or read it into memory on wasm targets.
+pub(crate) fn map_file(file: &File) -> PolarsResult<ReaderBytes<'_>> {
+    #[cfg(not(target_family = "wasm"))]
+    {
+        let mmap = unsafe { memmap::Mmap::map(file)? };
+        Ok(ReaderBytes::Mapped(mmap, file))
+    }
+    #[cfg(target_family = "wasm")]
+    {
+        Ok(ReaderBytes::Owned(read_file(file)?))
+    }
+}
+
 // Handle various forms of input bytes
 pub enum ReaderBytes<'a> {
     Borrowed(&'a [u8]),
@@ -76,11 +130,7 @@ impl<'a, T: 'a + MmapBytesReader> From<&'a T> for ReaderBytes<'a> {
     fn from(m: &'a T) -> Self {
         match m.to_bytes() {
             Some(s) => ReaderBytes::Borrowed(s),
-            None => {
-                let f = m.to_file().unwrap();
-                let mmap = unsafe { memmap::Mmap::map(f).unwrap() };
-                ReaderBytes::Mapped(mmap, f)
-            },
+            None => map_file(m.to_file().unwrap()).unwrap(),
         }
     }
 }
@@ -1,3 +1,7 @@
+use std::fmt::{Debug, Formatter};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
 use polars_arrow::prelude::IdxSize;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -8,3 +12,71 @@ pub struct RowCount {
     pub name: String,
     pub offset: IdxSize,
 }
+
+/// The progress of a reader, passed to a [`ProgressCallback`].
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct ReadProgress {
+    /// The number of bytes that are parsed. For compressed or transcoded files these are
+    /// bytes of the decompressed, utf8 data.
+    pub bytes_read: usize,
+    /// The number of bytes that will be parsed in total, in the same unit as `bytes_read`.
+    /// If the number of rows is limited this is an upper bound.
+    pub bytes_total: usize,
+    /// The number of rows that are parsed, before a predicate is applied.
+    pub rows_read: usize,
+}
+
+/// A function that a reader calls every time it parsed a chunk of its input, e.g. to render
+/// a progress bar. It is called from the threads that parse the chunks, so it should return
+/// quickly.
+#[derive(Clone)]
+pub struct ProgressCallback(Arc<dyn Fn(ReadProgress) + Send + Sync>);
+
+impl ProgressCallback {
+    pub fn new(f: impl Fn(ReadProgress) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(f))
+    }
+}
+
+impl Debug for ProgressCallback {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ProgressCallback")
+    }
+}
+
+impl PartialEq for ProgressCallback {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for ProgressCallback {}
+
+/// Accumulates the progress of the threads of a reader and reports it to a [`ProgressCallback`].
+pub(crate) struct ProgressTracker {
+    callback: ProgressCallback,
+    bytes_total: usize,
+    bytes_read: AtomicUsize,
+    rows_read: AtomicUsize,
+}
+
+impl ProgressTracker {
+    pub(crate) fn new(callback: ProgressCallback, bytes_total: usize) -> Self {
+        Self {
+            callback,
+            bytes_total,
+            bytes_read: AtomicUsize::new(0),
+            rows_read: AtomicUsize::new(0),
+        }
+    }
+
+    pub(crate) fn update(&self, bytes: usize, rows: usize) {
+        let bytes_read = self.bytes_read.fetch_add(bytes, Ordering::Relaxed) + bytes;
+        let rows_read = self.rows_read.fetch_add(rows, Ordering::Relaxed) + rows;
+        (self.callback.0)(ReadProgress {
+            bytes_read: bytes_read.min(self.bytes_total),
+            bytes_total: self.bytes_total,
+            rows_read,
+        })
+    }
+}
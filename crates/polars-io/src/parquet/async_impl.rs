@@ -13,7 +13,6 @@ use polars_core::datatypes::PlHashMap;
 use polars_core::error::{to_compute_err, PolarsResult};
 use polars_core::prelude::*;
 use polars_core::schema::Schema;
-use smartstring::alias::String as SmartString;
 
 use super::cloud::{build_object_store, CloudLocation, CloudReader};
 use super::mmap;
@@ -121,7 +120,7 @@ async fn read_columns_async2(
 /// Download rowgroups for the column whose indexes are given in `projection`.
 /// We concurrently download the columns for each field.
 async fn download_projection(
-    fields: &[SmartString],
+    fields: &[PlSmallStr],
     row_groups: &[RowGroupMetaData],
     async_reader: &Arc<ParquetObjectStore>,
 ) -> PolarsResult<Vec<Vec<(u64, Bytes)>>> {
@@ -154,7 +153,7 @@ async fn download_projection(
 pub struct FetchRowGroupsFromObjectStore {
     reader: Arc<ParquetObjectStore>,
     row_groups_metadata: Vec<RowGroupMetaData>,
-    projected_fields: Vec<SmartString>,
+    projected_fields: Vec<PlSmallStr>,
     logging: bool,
 }
 
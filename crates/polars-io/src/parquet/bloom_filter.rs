@@ -0,0 +1,206 @@
+use arrow::io::parquet::bloom_filter::{hash_byte, hash_native, insert, is_in_set};
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Size of a block of the split block bloom filter in bytes.
+const BLOCK_SIZE: usize = 32;
+/// Upper bound of the size of a bloom filter built by polars.
+const MAX_BYTES: usize = 128 * 1024 * 1024;
+
+/// A split block bloom filter as specified by the parquet format.
+///
+/// Values are hashed as their parquet physical type, so filters read from parquet files
+/// and filters built by [`BloomFilter::from_series`] can be probed in the same way.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BloomFilter {
+    bitset: Vec<u8>,
+}
+
+impl BloomFilter {
+    pub fn from_bitset(bitset: Vec<u8>) -> Self {
+        Self { bitset }
+    }
+
+    pub fn bitset(&self) -> &[u8] {
+        &self.bitset
+    }
+
+    /// Build a bloom filter containing the non-null values of `s`.
+    ///
+    /// Returns `None` if the data type of `s` cannot be hashed.
+    pub fn from_series(s: &Series) -> PolarsResult<Option<Self>> {
+        let Some(hashes) = hashes(s) else {
+            return Ok(None);
+        };
+        // ~10 bits per distinct value gives a false positive rate of about 1%. Other
+        // writers use a power of two number of bytes, so some readers may expect that.
+        let n_unique = s.n_unique()?;
+        let n_bytes = (n_unique * 10 / 8)
+            .next_power_of_two()
+            .clamp(BLOCK_SIZE, MAX_BYTES);
+
+        let mut bitset = vec![0; n_bytes];
+        for hash in hashes {
+            insert(&mut bitset, hash);
+        }
+        Ok(Some(Self { bitset }))
+    }
+
+    /// Check if `value` (a literal of length 1) may be present in the filter.
+    ///
+    /// `false` is a proof that the value is not present, `true` means that it might be.
+    pub fn may_contain(&self, value: &Series) -> bool {
+        if self.bitset.is_empty() || self.bitset.len() % BLOCK_SIZE != 0 {
+            return true;
+        }
+        let hashes = match value.dtype() {
+            DataType::Float32 | DataType::Float64 => float_probe_hashes(value),
+            _ => hashes(value),
+        };
+        match hashes {
+            Some(hashes) if !hashes.is_empty() => {
+                hashes.into_iter().any(|hash| is_in_set(&self.bitset, hash))
+            },
+            _ => true,
+        }
+    }
+}
+
+/// Hash the non-null values of the float literal `s` for probing. Equal floats don't need to
+/// have equal bits, so both zeros are probed for a zero and a NaN can't be probed at all.
+fn float_probe_hashes(s: &Series) -> Option<Vec<u64>> {
+    macro_rules! probe {
+        ($ca:expr) => {{
+            let mut out = vec![];
+            for v in $ca.into_iter().flatten() {
+                if v.is_nan() {
+                    return None;
+                }
+                out.push(hash_native(v));
+                if v == 0.0 {
+                    out.push(hash_native(-v));
+                }
+            }
+            out
+        }};
+    }
+    let out = match s.dtype() {
+        DataType::Float32 => probe!(s.f32().unwrap()),
+        DataType::Float64 => probe!(s.f64().unwrap()),
+        _ => unreachable!(),
+    };
+    Some(out)
+}
+
+/// Hash the non-null values of `s` as their parquet physical type.
+fn hashes(s: &Series) -> Option<Vec<u64>> {
+    use DataType::*;
+    let s = match s.dtype() {
+        // Categoricals are stored as their string values.
+        #[cfg(feature = "dtype-categorical")]
+        Categorical(_) => s.cast(&Utf8).ok()?,
+        _ => s.to_physical_repr().into_owned(),
+    };
+    let out = match s.dtype() {
+        Int8 | Int16 | Int32 | UInt8 | UInt16 => {
+            let s = s.cast(&Int32).ok()?;
+            s.i32()
+                .unwrap()
+                .into_iter()
+                .flatten()
+                .map(hash_native)
+                .collect()
+        },
+        // Unsigned integers are stored bit-casted to their signed counterpart.
+        UInt32 => s
+            .u32()
+            .unwrap()
+            .into_iter()
+            .flatten()
+            .map(|v| hash_native(v as i32))
+            .collect(),
+        Int64 => s
+            .i64()
+            .unwrap()
+            .into_iter()
+            .flatten()
+            .map(hash_native)
+            .collect(),
+        UInt64 => s
+            .u64()
+            .unwrap()
+            .into_iter()
+            .flatten()
+            .map(|v| hash_native(v as i64))
+            .collect(),
+        Float32 => s
+            .f32()
+            .unwrap()
+            .into_iter()
+            .flatten()
+            .map(hash_native)
+            .collect(),
+        Float64 => s
+            .f64()
+            .unwrap()
+            .into_iter()
+            .flatten()
+            .map(hash_native)
+            .collect(),
+        Utf8 => s
+            .utf8()
+            .unwrap()
+            .into_iter()
+            .flatten()
+            .map(hash_byte)
+            .collect(),
+        Binary => s
+            .binary()
+            .unwrap()
+            .into_iter()
+            .flatten()
+            .map(hash_byte)
+            .collect(),
+        _ => return None,
+    };
+    Some(out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_bloom_filter_may_contain() -> PolarsResult<()> {
+        let s = Series::new("a", &[1i64, 2, 3, 100]);
+        let bloom = BloomFilter::from_series(&s)?.unwrap();
+        for v in [1i64, 2, 3, 100] {
+            assert!(bloom.may_contain(&Series::new("", &[v])));
+        }
+
+        let s = Series::new("a", &["foo", "bar"]);
+        let bloom = BloomFilter::from_series(&s)?.unwrap();
+        assert!(bloom.may_contain(&Series::new("", &["foo"])));
+        // a null literal can never be proven absent
+        assert!(bloom.may_contain(&Series::new("", &[None::<&str>])));
+        Ok(())
+    }
+
+    #[test]
+    fn test_bloom_filter_may_contain_float() -> PolarsResult<()> {
+        let s = Series::new("a", &[-0.0f64, 1.5]);
+        let bloom = BloomFilter::from_series(&s)?.unwrap();
+        // `0.0 == -0.0`, so neither zero can prove the other absent
+        assert!(bloom.may_contain(&Series::new("", &[0.0f64])));
+        assert!(bloom.may_contain(&Series::new("", &[-0.0f64])));
+        assert!(bloom.may_contain(&Series::new("", &[f64::NAN])));
+
+        let s = Series::new("a", &[0.0f32, 1.5]);
+        let bloom = BloomFilter::from_series(&s)?.unwrap();
+        assert!(bloom.may_contain(&Series::new("", &[-0.0f32])));
+        assert!(bloom.may_contain(&Series::new("", &[1.5f32])));
+        Ok(())
+    }
+}
@@ -1,4 +1,4 @@
-use arrow::datatypes::Field;
+use arrow::datatypes::{DataType as ArrowDataType, Field};
 use arrow::io::parquet::read::{
     column_iter_to_arrays, get_field_columns, ArrayIter, BasicDecompressor, ColumnChunkMetaData,
     PageReader,
@@ -26,15 +26,26 @@ pub enum ColumnStore<'a> {
     Fetched(PlHashMap<u64, Bytes>),
 }
 
-/// For local files memory maps all columns that are part of the parquet field `field_name`.
+/// For local files memory maps all columns that are part of the parquet field `field`.
 /// For cloud files the relevant memory regions should have been prefetched.
+///
+/// A struct field may hold a subset of the fields in the file, only the columns of those
+/// fields are mapped.
 pub(super) fn mmap_columns<'a>(
     store: &'a ColumnStore,
     columns: &'a [ColumnChunkMetaData],
-    field_name: &str,
+    field: &Field,
 ) -> Vec<(&'a ColumnChunkMetaData, &'a [u8])> {
-    get_field_columns(columns, field_name)
+    get_field_columns(columns, &field.name)
         .into_iter()
+        .filter(|meta| match &field.data_type {
+            ArrowDataType::Struct(fields) => meta
+                .descriptor()
+                .path_in_schema
+                .get(1)
+                .map_or(true, |name| fields.iter().any(|f| &f.name == name)),
+            _ => true,
+        })
         .map(|meta| _mmap_single_column(store, meta))
         .collect()
 }
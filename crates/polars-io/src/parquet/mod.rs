@@ -16,12 +16,14 @@
 //!
 #[cfg(feature = "cloud")]
 pub(super) mod async_impl;
+mod bloom_filter;
 pub(super) mod mmap;
 pub mod predicates;
 mod read;
 mod read_impl;
 mod write;
 
+pub use bloom_filter::BloomFilter;
 pub use read::*;
 pub use write::{BrotliLevel, GzipLevel, ZstdLevel, *};
 
@@ -108,4 +110,120 @@ mod test {
         assert_eq!(df_read.shape(), (3, 2));
         df_read.frame_equal(&expected);
     }
+
+    #[test]
+    fn test_parquet_column_options_round_trip() -> PolarsResult<()> {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!(
+            "floats" => [Some(1.5f64), None, Some(-3.25), Some(f64::MAX)],
+            "strings" => [Some("a"), Some("b"), None, Some("a")],
+            "ints" => [1i64, 1, 2, 2]
+        )?;
+
+        ParquetWriter::new(&mut buf)
+            .with_column_options(
+                "floats",
+                ParquetColumnOptions {
+                    byte_stream_split: true,
+                    compression: Some(ParquetCompression::Uncompressed),
+                    ..Default::default()
+                },
+            )
+            .with_column_options(
+                "strings",
+                ParquetColumnOptions {
+                    dictionary: Some(true),
+                    statistics: Some(true),
+                    ..Default::default()
+                },
+            )
+            .with_column_options(
+                "ints",
+                ParquetColumnOptions {
+                    dictionary: Some(true),
+                    compression: Some(ParquetCompression::Snappy),
+                    ..Default::default()
+                },
+            )
+            .finish(&mut df)?;
+        buf.set_position(0);
+
+        let read = ParquetReader::new(buf).finish()?;
+        assert!(read.frame_equal_missing(&df));
+        Ok(())
+    }
+
+    #[test]
+    fn test_parquet_row_group_bloom_filters() -> PolarsResult<()> {
+        use super::predicates::{collect_statistics, read_bloom_filters};
+        use super::BloomFilter;
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!("ints" => [1i64, 5, 9])?;
+        ParquetWriter::new(&mut buf).finish(&mut df)?;
+        buf.set_position(0);
+
+        let metadata = arrow::io::parquet::read::read_metadata(&mut buf)?;
+        let schema = arrow::io::parquet::read::infer_schema(&metadata)?;
+        // files without bloom filters don't prune anything
+        let bloom_filters = read_bloom_filters(&mut buf, &metadata, &schema)?;
+        assert!(bloom_filters.iter().flatten().all(|bf| bf.is_none()));
+
+        // 3 lies between min and max, only the bloom filter proves it is absent
+        let bloom_filter = BloomFilter::from_series(df.column("ints")?)?;
+        let stats = collect_statistics(
+            &metadata.row_groups,
+            &schema,
+            Some(0),
+            Some(&[bloom_filter]),
+        )?
+        .unwrap();
+        let ints = stats.get_stats("ints")?;
+        assert!(ints.may_contain(&Series::new("", &[5i64])));
+        assert!(!ints.may_contain(&Series::new("", &[3i64])));
+        Ok(())
+    }
+
+    #[test]
+    fn test_parquet_write_bloom_filters() -> PolarsResult<()> {
+        use super::predicates::read_bloom_filters;
+        use super::BloomFilter;
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut df = df!(
+            "ints" => [1i64, 5, 9, 13],
+            "strings" => ["a", "b", "c", "d"],
+            "floats" => [1.0f64, 2.0, 3.0, 4.0]
+        )?;
+        let options = ParquetColumnOptions {
+            bloom_filter: true,
+            ..Default::default()
+        };
+        ParquetWriter::new(&mut buf)
+            .with_statistics(true)
+            .with_row_group_size(Some(2))
+            .with_column_options("ints", options)
+            .with_column_options("strings", options)
+            .finish(&mut df)?;
+        buf.set_position(0);
+
+        let metadata = arrow::io::parquet::read::read_metadata(&mut buf)?;
+        let schema = arrow::io::parquet::read::infer_schema(&metadata)?;
+        let bloom_filters = read_bloom_filters(&mut buf, &metadata, &schema)?;
+        assert_eq!(bloom_filters.len(), 2);
+        for (rg, bloom_filters) in bloom_filters.iter().enumerate() {
+            let expected = df.slice(rg as i64 * 2, 2);
+            for (i, name) in ["ints", "strings"].into_iter().enumerate() {
+                let written = BloomFilter::from_series(expected.column(name)?)?;
+                assert_eq!(bloom_filters[i], written);
+            }
+            // columns without the option have no bloom filter
+            assert!(bloom_filters[2].is_none());
+        }
+
+        buf.set_position(0);
+        let read = ParquetReader::new(buf).finish()?;
+        assert!(read.frame_equal(&df));
+        Ok(())
+    }
 }
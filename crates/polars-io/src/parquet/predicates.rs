@@ -1,10 +1,17 @@
+use std::io::{Read, Seek};
+
+use arrow::io::parquet::bloom_filter;
 use arrow::io::parquet::read::statistics::{deserialize, Statistics};
-use arrow::io::parquet::read::RowGroupMetaData;
+use arrow::io::parquet::read::{get_field_columns, FileMetaData, RowGroupMetaData};
 use polars_core::prelude::*;
 
+use crate::parquet::BloomFilter;
 use crate::predicates::{BatchStats, ColumnStats, PhysicalIoExpr};
 use crate::ArrowResult;
 
+/// The bloom filters of a file, per row group and per field of the schema.
+pub(super) type RowGroupBloomFilters = Vec<Vec<Option<BloomFilter>>>;
+
 impl ColumnStats {
     fn from_arrow_stats(stats: Statistics, field: &ArrowField) -> Self {
         Self::new(
@@ -16,16 +23,50 @@ impl ColumnStats {
     }
 }
 
+/// Read the bloom filters of all row groups in the file.
+///
+/// Only fields that map to a single parquet column can have a bloom filter.
+pub(super) fn read_bloom_filters<R: Read + Seek>(
+    reader: &mut R,
+    file_metadata: &FileMetaData,
+    schema: &ArrowSchema,
+) -> PolarsResult<RowGroupBloomFilters> {
+    file_metadata
+        .row_groups
+        .iter()
+        .map(|md| {
+            schema
+                .fields
+                .iter()
+                .map(|fld| {
+                    let columns = get_field_columns(md.columns(), &fld.name);
+                    let [column] = columns.as_slice() else {
+                        return Ok(None);
+                    };
+                    let mut bitset = vec![];
+                    bloom_filter::read(column, &mut *reader, &mut bitset)
+                        .map_err(arrow::error::Error::from)?;
+                    // an empty bitset means that the column chunk has no bloom filter
+                    Ok((!bitset.is_empty()).then(|| BloomFilter::from_bitset(bitset)))
+                })
+                .collect()
+        })
+        .collect()
+}
+
 /// Collect the statistics in a column chunk.
+///
+/// If `bloom_filters` of the selected row group are given, they are attached to the statistics.
 pub(crate) fn collect_statistics(
     md: &[RowGroupMetaData],
     arrow_schema: &ArrowSchema,
     rg: Option<usize>,
+    bloom_filters: Option<&[Option<BloomFilter>]>,
 ) -> ArrowResult<Option<BatchStats>> {
     let mut schema = Schema::with_capacity(arrow_schema.fields.len());
     let mut stats = vec![];
 
-    for fld in &arrow_schema.fields {
+    for (i, fld) in arrow_schema.fields.iter().enumerate() {
         // note that we only select a single row group.
         let st = match rg {
             None => deserialize(fld, md)?,
@@ -33,7 +74,11 @@ pub(crate) fn collect_statistics(
             Some(rg) => deserialize(fld, &md[rg..rg + 1])?,
         };
         schema.with_column((&fld.name).into(), (&fld.data_type).into());
-        stats.push(ColumnStats::from_arrow_stats(st, fld));
+        let mut column_stats = ColumnStats::from_arrow_stats(st, fld);
+        if let Some(bloom_filter) = bloom_filters.and_then(|bf| bf[i].clone()) {
+            column_stats = column_stats.with_bloom_filter(bloom_filter);
+        }
+        stats.push(column_stats);
     }
 
     Ok(if stats.is_empty() {
@@ -45,13 +90,17 @@ pub(crate) fn collect_statistics(
 
 pub(super) fn read_this_row_group(
     predicate: Option<&Arc<dyn PhysicalIoExpr>>,
-    file_metadata: &arrow::io::parquet::read::FileMetaData,
+    file_metadata: &FileMetaData,
     schema: &ArrowSchema,
     rg: usize,
+    bloom_filters: Option<&RowGroupBloomFilters>,
 ) -> PolarsResult<bool> {
     if let Some(pred) = &predicate {
         if let Some(pred) = pred.as_stats_evaluator() {
-            if let Some(stats) = collect_statistics(&file_metadata.row_groups, schema, Some(rg))? {
+            let bloom_filters = bloom_filters.map(|bf| bf[rg].as_slice());
+            if let Some(stats) =
+                collect_statistics(&file_metadata.row_groups, schema, Some(rg), bloom_filters)?
+            {
                 let should_read = pred.should_read(&stats);
                 // a parquet file may not have statistics of all columns
                 if matches!(should_read, Ok(false)) {
@@ -54,6 +54,26 @@ pub struct ParquetReader<R: Read + Seek> {
     metadata: Option<Arc<FileMetaData>>,
     hive_partition_columns: Option<Vec<Series>>,
     use_statistics: bool,
+    use_bloom_filters: bool,
+    struct_fields: Option<Arc<Vec<(String, Vec<String>)>>>,
+}
+
+/// Remove the fields of struct columns that are not in `struct_fields`.
+fn prune_struct_fields(schema: &mut ArrowSchema, struct_fields: &[(String, Vec<String>)]) {
+    for (name, keep) in struct_fields {
+        if let Some(field) = schema.fields.iter_mut().find(|f| &f.name == name) {
+            if let ArrowDataType::Struct(fields) = &field.data_type {
+                let fields = fields
+                    .iter()
+                    .filter(|f| keep.contains(&f.name))
+                    .cloned()
+                    .collect::<Vec<_>>();
+                if !fields.is_empty() {
+                    field.data_type = ArrowDataType::Struct(fields);
+                }
+            }
+        }
+    }
 }
 
 impl<R: MmapBytesReader> ParquetReader<R> {
@@ -66,7 +86,10 @@ impl<R: MmapBytesReader> ParquetReader<R> {
     ) -> PolarsResult<DataFrame> {
         // this path takes predicates and parallelism into account
         let metadata = read::read_metadata(&mut self.reader)?;
-        let schema = read::schema::infer_schema(&metadata)?;
+        let mut schema = read::schema::infer_schema(&metadata)?;
+        if let Some(struct_fields) = &self.struct_fields {
+            prune_struct_fields(&mut schema, struct_fields);
+        }
 
         let rechunk = self.rechunk;
         read_parquet(
@@ -79,6 +102,7 @@ impl<R: MmapBytesReader> ParquetReader<R> {
             self.parallel,
             self.row_count,
             self.use_statistics,
+            self.use_bloom_filters,
             self.hive_partition_columns.as_deref(),
         )
         .map(|mut df| {
@@ -141,6 +165,20 @@ impl<R: MmapBytesReader> ParquetReader<R> {
         self
     }
 
+    /// Use the bloom filters in the parquet to determine if row groups
+    /// can be skipped for equality predicates. Off by default, as reading
+    /// the bloom filters costs extra IO.
+    ///
+    /// Only has an effect if [`ParquetReader::use_statistics`] is set. The [`ParquetWriter`]
+    /// writes bloom filters if [`ParquetColumnOptions::bloom_filter`] is set.
+    ///
+    /// [`ParquetWriter`]: crate::parquet::ParquetWriter
+    /// [`ParquetColumnOptions::bloom_filter`]: crate::parquet::ParquetColumnOptions::bloom_filter
+    pub fn use_bloom_filters(mut self, toggle: bool) -> Self {
+        self.use_bloom_filters = toggle;
+        self
+    }
+
     /// Number of rows in the parquet file.
     pub fn num_rows(&mut self) -> PolarsResult<usize> {
         let metadata = self.get_metadata()?;
@@ -152,6 +190,16 @@ impl<R: MmapBytesReader> ParquetReader<R> {
         self
     }
 
+    /// Only read some fields of struct columns. Every entry maps the name of a struct column
+    /// to the names of the fields to read, the columns of the other fields are not read.
+    pub fn with_struct_fields(
+        mut self,
+        struct_fields: Option<Arc<Vec<(String, Vec<String>)>>>,
+    ) -> Self {
+        self.struct_fields = struct_fields;
+        self
+    }
+
     pub fn get_metadata(&mut self) -> PolarsResult<&Arc<FileMetaData>> {
         if self.metadata.is_none() {
             self.metadata = Some(Arc::new(read::read_metadata(&mut self.reader)?));
@@ -192,7 +240,9 @@ impl<R: MmapBytesReader> SerReader<R> for ParquetReader<R> {
             low_memory: false,
             metadata: None,
             use_statistics: true,
+            use_bloom_filters: false,
             hive_partition_columns: None,
+            struct_fields: None,
         }
     }
 
@@ -203,7 +253,10 @@ impl<R: MmapBytesReader> SerReader<R> for ParquetReader<R> {
 
     fn finish(mut self) -> PolarsResult<DataFrame> {
         let metadata = read::read_metadata(&mut self.reader)?;
-        let schema = read::schema::infer_schema(&metadata)?;
+        let mut schema = read::schema::infer_schema(&metadata)?;
+        if let Some(struct_fields) = &self.struct_fields {
+            prune_struct_fields(&mut schema, struct_fields);
+        }
 
         if let Some(cols) = &self.columns {
             self.projection = Some(columns_to_projection(cols, &schema)?);
@@ -219,6 +272,7 @@ impl<R: MmapBytesReader> SerReader<R> for ParquetReader<R> {
             self.parallel,
             self.row_count,
             self.use_statistics,
+            self.use_bloom_filters,
             self.hive_partition_columns.as_deref(),
         )
         .map(|mut df| {
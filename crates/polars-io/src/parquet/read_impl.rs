@@ -17,7 +17,7 @@ use crate::mmap::{MmapBytesReader, ReaderBytes};
 #[cfg(feature = "cloud")]
 use crate::parquet::async_impl::FetchRowGroupsFromObjectStore;
 use crate::parquet::mmap::mmap_columns;
-use crate::parquet::predicates::read_this_row_group;
+use crate::parquet::predicates::{read_bloom_filters, read_this_row_group, RowGroupBloomFilters};
 use crate::parquet::{mmap, ParallelStrategy};
 #[cfg(feature = "async")]
 use crate::pl_async::get_runtime;
@@ -54,7 +54,7 @@ fn column_idx_to_series(
 
     field.data_type = enlarge_data_type(field.data_type);
 
-    let columns = mmap_columns(store, md.columns(), &field.name);
+    let columns = mmap_columns(store, md.columns(), &field);
     let iter = mmap::to_deserializer(columns, field.clone(), remaining_rows, Some(chunk_size))?;
 
     if remaining_rows < md.num_rows() {
@@ -125,6 +125,7 @@ fn rg_to_dfs(
     parallel: ParallelStrategy,
     projection: &[usize],
     use_statistics: bool,
+    bloom_filters: Option<&RowGroupBloomFilters>,
     hive_partition_columns: Option<&[Series]>,
 ) -> PolarsResult<Vec<DataFrame>> {
     let mut dfs = Vec::with_capacity(row_group_end - row_group_start);
@@ -133,7 +134,9 @@ fn rg_to_dfs(
         let md = &file_metadata.row_groups[rg];
         let current_row_count = md.num_rows() as IdxSize;
 
-        if use_statistics && !read_this_row_group(predicate.as_ref(), file_metadata, schema, rg)? {
+        if use_statistics
+            && !read_this_row_group(predicate.as_ref(), file_metadata, schema, rg, bloom_filters)?
+        {
             *previous_row_count += current_row_count;
             continue;
         }
@@ -203,6 +206,7 @@ fn rg_to_dfs_par(
     row_count: Option<RowCount>,
     projection: &[usize],
     use_statistics: bool,
+    bloom_filters: Option<&RowGroupBloomFilters>,
     hive_partition_columns: Option<&[Series]>,
 ) -> PolarsResult<Vec<DataFrame>> {
     // compute the limits per row group and the row count offsets
@@ -228,7 +232,13 @@ fn rg_to_dfs_par(
         .map(|(rg_idx, md, local_limit, row_count_start)| {
             if local_limit == 0
                 || use_statistics
-                    && !read_this_row_group(predicate.as_ref(), file_metadata, schema, rg_idx)?
+                    && !read_this_row_group(
+                        predicate.as_ref(),
+                        file_metadata,
+                        schema,
+                        rg_idx,
+                        bloom_filters,
+                    )?
             {
                 return Ok(None);
             }
@@ -272,6 +282,7 @@ pub fn read_parquet<R: MmapBytesReader>(
     mut parallel: ParallelStrategy,
     row_count: Option<RowCount>,
     use_statistics: bool,
+    use_bloom_filters: bool,
     hive_partition_columns: Option<&[Series]>,
 ) -> PolarsResult<DataFrame> {
     let file_metadata = metadata
@@ -311,6 +322,13 @@ pub fn read_parquet<R: MmapBytesReader>(
         parallel = ParallelStrategy::None;
     }
 
+    // bloom filters are only useful to prune row groups with a predicate
+    let bloom_filters = if use_statistics && use_bloom_filters && predicate.is_some() {
+        Some(read_bloom_filters(&mut reader, &file_metadata, schema)?)
+    } else {
+        None
+    };
+
     let reader = ReaderBytes::from(&reader);
     let bytes = reader.deref();
     let store = mmap::ColumnStore::Local(bytes);
@@ -328,6 +346,7 @@ pub fn read_parquet<R: MmapBytesReader>(
             parallel,
             &projection,
             use_statistics,
+            bloom_filters.as_ref(),
             hive_partition_columns,
         )?,
         ParallelStrategy::RowGroups => rg_to_dfs_par(
@@ -342,6 +361,7 @@ pub fn read_parquet<R: MmapBytesReader>(
             row_count,
             &projection,
             use_statistics,
+            bloom_filters.as_ref(),
             hive_partition_columns,
         )?,
         // auto should already be replaced by Columns or RowGroups
@@ -503,6 +523,7 @@ impl BatchedParquetReader {
                         ParallelStrategy::Columns,
                         &self.projection,
                         self.use_statistics,
+                        None,
                         self.hive_partition_columns.as_deref(),
                     )?;
                     self.row_group_offset += n;
@@ -521,6 +542,7 @@ impl BatchedParquetReader {
                         self.row_count.clone(),
                         &self.projection,
                         self.use_statistics,
+                        None,
                         self.hive_partition_columns.as_deref(),
                     )?;
                     self.row_group_offset += n;
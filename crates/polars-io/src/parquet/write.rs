@@ -2,7 +2,8 @@ use std::io::Write;
 
 use arrow::array::Array;
 use arrow::chunk::Chunk;
-use arrow::datatypes::{DataType as ArrowDataType, PhysicalType};
+use arrow::compute::cast::{cast, CastOptions};
+use arrow::datatypes::{DataType as ArrowDataType, IntegerType, PhysicalType};
 use arrow::error::Error as ArrowError;
 use arrow::io::parquet::read::ParquetError;
 use arrow::io::parquet::write::{self, DynIter, DynStreamingIterator, Encoding, FileWriter, *};
@@ -17,6 +18,8 @@ use write::{
     ZstdLevel as ZstdLevelParquet,
 };
 
+use crate::parquet::BloomFilter;
+
 #[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GzipLevel(u8);
@@ -90,6 +93,26 @@ impl From<ParquetCompression> for CompressionOptions {
     }
 }
 
+/// Options that override the writer-wide settings for a single column.
+///
+/// Fields that are `None` fall back to the setting of the [`ParquetWriter`].
+#[derive(Debug, Default, Eq, PartialEq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ParquetColumnOptions {
+    /// Data page compression of this column.
+    pub compression: Option<ParquetCompression>,
+    /// Dictionary encode this column. Only applies to string, binary and integer columns.
+    pub dictionary: Option<bool>,
+    /// Compute and write statistics of this column.
+    pub statistics: Option<bool>,
+    /// Write this column with the `BYTE_STREAM_SPLIT` encoding. This often compresses
+    /// float columns better than `PLAIN`. Only applies to float columns.
+    pub byte_stream_split: bool,
+    /// Write a bloom filter of this column for every row group, so that readers can skip
+    /// row groups on equality predicates. Only applies to columns that are not nested.
+    pub bloom_filter: bool,
+}
+
 /// Write a DataFrame to parquet format
 ///
 #[must_use]
@@ -105,6 +128,8 @@ pub struct ParquetWriter<W> {
     data_pagesize_limit: Option<usize>,
     /// Serialize columns in parallel
     parallel: bool,
+    /// Per column overrides of the options above.
+    column_options: PlHashMap<String, ParquetColumnOptions>,
 }
 
 impl<W> ParquetWriter<W>
@@ -123,6 +148,7 @@ where
             row_group_size: None,
             data_pagesize_limit: None,
             parallel: true,
+            column_options: Default::default(),
         }
     }
 
@@ -160,6 +186,12 @@ where
         self
     }
 
+    /// Override the compression, encoding and statistics options for the column `name`.
+    pub fn with_column_options(mut self, name: &str, options: ParquetColumnOptions) -> Self {
+        self.column_options.insert(name.to_string(), options);
+        self
+    }
+
     fn materialize_options(&self) -> WriteOptions {
         WriteOptions {
             write_statistics: self.statistics,
@@ -174,15 +206,15 @@ where
         let schema = ArrowSchema::from(fields);
 
         let parquet_schema = to_parquet_schema(&schema)?;
-        let encodings = get_encodings(&schema);
         let options = self.materialize_options();
+        let columns =
+            get_column_write_options(&schema, &parquet_schema, options, &self.column_options);
         let writer = FileWriter::try_new(self.writer, schema, options)?;
 
         Ok(BatchedWriter {
             writer,
             parquet_schema,
-            encodings,
-            options,
+            columns,
             parallel: self.parallel,
         })
     }
@@ -202,34 +234,121 @@ where
     }
 }
 
+/// The resolved write settings of a single (top-level) column.
+struct ColumnWriteOptions {
+    options: WriteOptions,
+    encodings: Vec<Encoding>,
+    /// Dictionary encode the column before serializing.
+    dictionary: bool,
+    /// Write bloom filters of the parquet column with this index.
+    bloom_filter: Option<usize>,
+}
+
 // Note that the df should be rechunked
 fn prepare_rg_iter<'a>(
     df: &'a DataFrame,
     parquet_schema: &'a SchemaDescriptor,
-    encodings: &'a [Vec<Encoding>],
-    options: WriteOptions,
+    columns: &'a [ColumnWriteOptions],
     parallel: bool,
 ) -> impl Iterator<Item = Result<RowGroupIter<'a, ArrowError>, ArrowError>> + 'a {
     let rb_iter = df.iter_chunks();
     rb_iter.filter_map(move |batch| match batch.len() {
         0 => None,
         _ => {
-            let row_group =
-                create_serializer(batch, parquet_schema.fields(), encodings, options, parallel);
+            let row_group = create_serializer(batch, parquet_schema.fields(), columns, parallel);
 
             Some(row_group)
         },
     })
 }
 
-fn get_encodings(schema: &ArrowSchema) -> Vec<Vec<Encoding>> {
+fn get_column_write_options(
+    schema: &ArrowSchema,
+    parquet_schema: &SchemaDescriptor,
+    options: WriteOptions,
+    column_options: &PlHashMap<String, ParquetColumnOptions>,
+) -> Vec<ColumnWriteOptions> {
+    let mut n_leaves = 0;
     schema
         .fields
         .iter()
-        .map(|f| transverse(&f.data_type, encoding_map))
+        .zip(parquet_schema.fields())
+        .map(|(f, type_)| {
+            let leaf = n_leaves;
+            n_leaves += to_parquet_leaves(type_.clone()).len();
+
+            let encodings = transverse(&f.data_type, encoding_map);
+            match column_options.get(&f.name) {
+                None => ColumnWriteOptions {
+                    options,
+                    encodings,
+                    dictionary: false,
+                    bloom_filter: None,
+                },
+                Some(col_options) => {
+                    let mut options = options;
+                    if let Some(compression) = col_options.compression {
+                        options.compression = compression.into();
+                    }
+                    if let Some(statistics) = col_options.statistics {
+                        options.write_statistics = statistics;
+                    }
+                    let dictionary =
+                        col_options.dictionary == Some(true) && can_dictionary_encode(&f.data_type);
+                    let encodings = if dictionary {
+                        vec![Encoding::RleDictionary]
+                    } else if col_options.byte_stream_split
+                        && matches!(f.data_type, ArrowDataType::Float32 | ArrowDataType::Float64)
+                    {
+                        vec![Encoding::ByteStreamSplit]
+                    } else {
+                        encodings
+                    };
+                    // only a column that is not nested maps to a single parquet column
+                    let bloom_filter = (col_options.bloom_filter
+                        && matches!(type_, ParquetType::PrimitiveType(_)))
+                    .then_some(leaf);
+                    ColumnWriteOptions {
+                        options,
+                        encodings,
+                        dictionary,
+                        bloom_filter,
+                    }
+                },
+            }
+        })
         .collect()
 }
 
+fn can_dictionary_encode(data_type: &ArrowDataType) -> bool {
+    matches!(
+        data_type,
+        ArrowDataType::Utf8
+            | ArrowDataType::LargeUtf8
+            | ArrowDataType::Binary
+            | ArrowDataType::LargeBinary
+            | ArrowDataType::Int8
+            | ArrowDataType::Int16
+            | ArrowDataType::Int32
+            | ArrowDataType::Int64
+            | ArrowDataType::UInt8
+            | ArrowDataType::UInt16
+            | ArrowDataType::UInt32
+            | ArrowDataType::UInt64
+    )
+}
+
+/// Dictionary encode an array. The parquet schema of a dictionary array is the same as the schema
+/// of its values, so the file schema is not affected.
+fn to_dictionary(array: &ArrayRef) -> Result<ArrayRef, ArrowError> {
+    let data_type = ArrowDataType::Dictionary(
+        IntegerType::UInt32,
+        Box::new(array.data_type().clone()),
+        false,
+    );
+    cast(array.as_ref(), &data_type, CastOptions::default())
+}
+
 /// Declare encodings
 fn encoding_map(data_type: &ArrowDataType) -> Encoding {
     match data_type.to_physical_type() {
@@ -242,8 +361,7 @@ fn encoding_map(data_type: &ArrowDataType) -> Encoding {
 pub struct BatchedWriter<W: Write> {
     writer: FileWriter<W>,
     parquet_schema: SchemaDescriptor,
-    encodings: Vec<Vec<Encoding>>,
-    options: WriteOptions,
+    columns: Vec<ColumnWriteOptions>,
     parallel: bool,
 }
 
@@ -253,19 +371,60 @@ impl<W: Write> BatchedWriter<W> {
     /// # Panics
     /// The caller must ensure the chunks in the given [`DataFrame`] are aligned.
     pub fn write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
-        let row_group_iter = prepare_rg_iter(
-            df,
-            &self.parquet_schema,
-            &self.encodings,
-            self.options,
-            self.parallel,
-        );
-        for group in row_group_iter {
+        let bloom_filters = self.bloom_filters(df)?;
+        let row_group_iter =
+            prepare_rg_iter(df, &self.parquet_schema, &self.columns, self.parallel);
+        for (i, group) in row_group_iter.enumerate() {
             self.writer.write(group?)?;
+            for (column, bloom_filter) in bloom_filters.get(i).into_iter().flatten() {
+                self.writer
+                    .add_bloom_filter(*column, bloom_filter.bitset().to_vec())?;
+            }
         }
         Ok(())
     }
 
+    /// Build the bloom filters of the row groups in `df`, i.e. of the non-empty chunks.
+    fn bloom_filters(&self, df: &DataFrame) -> PolarsResult<Vec<Vec<(usize, BloomFilter)>>> {
+        let columns = df
+            .get_columns()
+            .iter()
+            .zip(&self.columns)
+            .filter_map(|(s, options)| options.bloom_filter.map(|column| (s, column)))
+            .collect::<Vec<_>>();
+        let Some((first, _)) = columns.first() else {
+            return Ok(vec![]);
+        };
+
+        let chunk_lengths = first
+            .chunks()
+            .iter()
+            .map(|arr| arr.len())
+            .collect::<Vec<_>>();
+        chunk_lengths
+            .into_iter()
+            .enumerate()
+            .filter(|(_, len)| *len > 0)
+            .map(|(chunk_idx, _)| {
+                let mut bloom_filters = Vec::with_capacity(columns.len());
+                for (s, column) in &columns {
+                    // SAFETY: the chunk has the data type of the series.
+                    let chunk = unsafe {
+                        Series::from_chunks_and_dtype_unchecked(
+                            s.name(),
+                            vec![s.chunks()[chunk_idx].clone()],
+                            s.dtype(),
+                        )
+                    };
+                    if let Some(bloom_filter) = BloomFilter::from_series(&chunk)? {
+                        bloom_filters.push((*column, bloom_filter));
+                    }
+                }
+                Ok(bloom_filters)
+            })
+            .collect()
+    }
+
     /// Writes the footer of the parquet file. Returns the total size of the file.
     pub fn finish(&mut self) -> PolarsResult<u64> {
         let size = self.writer.end(None)?;
@@ -276,12 +435,17 @@ impl<W: Write> BatchedWriter<W> {
 fn create_serializer<'a>(
     batch: Chunk<Box<dyn Array>>,
     fields: &[ParquetType],
-    encodings: &[Vec<Encoding>],
-    options: WriteOptions,
+    columns: &[ColumnWriteOptions],
     parallel: bool,
 ) -> Result<RowGroupIter<'a, ArrowError>, ArrowError> {
-    let func = move |((array, type_), encoding): ((&ArrayRef, &ParquetType), &Vec<Encoding>)| {
-        let encoded_columns = array_to_columns(array, type_.clone(), options, encoding).unwrap();
+    let func = move |((array, type_), column): ((&ArrayRef, &ParquetType), &ColumnWriteOptions)| {
+        let options = column.options;
+        let encoded_columns = if column.dictionary {
+            let array = to_dictionary(array).unwrap();
+            array_to_columns(array, type_.clone(), options, &column.encodings).unwrap()
+        } else {
+            array_to_columns(array, type_.clone(), options, &column.encodings).unwrap()
+        };
 
         encoded_columns
             .into_iter()
@@ -313,7 +477,7 @@ fn create_serializer<'a>(
                 .columns()
                 .par_iter()
                 .zip(fields)
-                .zip(encodings)
+                .zip(columns)
                 .flat_map(func)
                 .collect::<Vec<_>>()
         })
@@ -322,7 +486,7 @@ fn create_serializer<'a>(
             .columns()
             .iter()
             .zip(fields)
-            .zip(encodings)
+            .zip(columns)
             .flat_map(func)
             .collect::<Vec<_>>()
     };
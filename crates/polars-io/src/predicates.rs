@@ -54,7 +54,7 @@ pub(crate) fn apply_predicate(
 /// - max value
 /// - min value
 /// - null_count
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ColumnStats {
     field: Field,
@@ -63,6 +63,8 @@ pub struct ColumnStats {
     null_count: Option<Series>,
     min_value: Option<Series>,
     max_value: Option<Series>,
+    #[cfg(feature = "parquet")]
+    bloom_filter: Option<crate::parquet::BloomFilter>,
 }
 
 impl ColumnStats {
@@ -77,7 +79,31 @@ impl ColumnStats {
             null_count,
             min_value,
             max_value,
+            #[cfg(feature = "parquet")]
+            bloom_filter: None,
+        }
+    }
+
+    /// Attach a bloom filter of the values of this column.
+    #[cfg(feature = "parquet")]
+    pub fn with_bloom_filter(mut self, bloom_filter: crate::parquet::BloomFilter) -> Self {
+        self.bloom_filter = Some(bloom_filter);
+        self
+    }
+
+    /// Check if the literal `value` may be present in this column.
+    ///
+    /// `false` is a proof that no value equals `value`.
+    #[cfg_attr(not(feature = "parquet"), allow(unused_variables))]
+    pub fn may_contain(&self, value: &Series) -> bool {
+        #[cfg(feature = "parquet")]
+        if let Some(bloom_filter) = &self.bloom_filter {
+            return match value.cast(self.dtype()) {
+                Ok(value) => bloom_filter.may_contain(&value),
+                Err(_) => true,
+            };
         }
+        true
     }
 
     pub fn from_column_literal(s: Series) -> Self {
@@ -87,6 +113,8 @@ impl ColumnStats {
             null_count: None,
             min_value: Some(s.clone()),
             max_value: Some(s),
+            #[cfg(feature = "parquet")]
+            bloom_filter: None,
         }
     }
 
@@ -133,6 +161,15 @@ impl ColumnStats {
         self.min_value.as_ref()
     }
 
+    pub fn get_max_state(&self) -> Option<&Series> {
+        self.max_value.as_ref()
+    }
+
+    #[cfg(feature = "parquet")]
+    pub fn bloom_filter(&self) -> Option<&crate::parquet::BloomFilter> {
+        self.bloom_filter.as_ref()
+    }
+
     pub fn to_min(&self) -> Option<&Series> {
         let min_val = self.min_value.as_ref()?;
         let dtype = min_val.dtype();
@@ -163,7 +200,7 @@ impl ColumnStats {
         }
     }
 
-    fn use_min_max(dtype: &DataType) -> bool {
+    pub(crate) fn use_min_max(dtype: &DataType) -> bool {
         dtype.is_numeric() || matches!(dtype, DataType::Utf8 | DataType::Binary | DataType::Boolean)
     }
 }
@@ -6,7 +6,7 @@ use polars_core::frame::DataFrame;
 use polars_core::prelude::*;
 use regex::{Regex, RegexBuilder};
 
-use crate::mmap::{MmapBytesReader, ReaderBytes};
+use crate::mmap::{map_file, MmapBytesReader, ReaderBytes};
 #[cfg(any(
     feature = "ipc",
     feature = "ipc_streaming",
@@ -20,13 +20,11 @@ pub fn get_reader_bytes<'a, R: Read + MmapBytesReader + ?Sized>(
 ) -> PolarsResult<ReaderBytes<'a>> {
     // we have a file so we can mmap
     if let Some(file) = reader.to_file() {
-        let mmap = unsafe { memmap::Mmap::map(file)? };
-
         // somehow bck thinks borrows alias
         // this is sound as file was already bound to 'a
         use std::fs::File;
         let file = unsafe { std::mem::transmute::<&File, &'a File>(file) };
-        Ok(ReaderBytes::Mapped(mmap, file))
+        map_file(file)
     } else {
         // we can get the bytes for free
         if reader.to_bytes().is_some() {
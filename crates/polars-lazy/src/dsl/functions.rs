@@ -23,7 +23,7 @@ pub(crate) fn concat_impl<L: AsRef<[LazyFrame]>>(
             .ok_or_else(|| polars_err!(NoData: "empty container given"))?,
     );
 
-    let mut opt_state = lf.opt_state;
+    let mut opt_state = lf.opt_state.clone();
     let options = UnionOptions {
         parallel,
         from_partitioned_ds,
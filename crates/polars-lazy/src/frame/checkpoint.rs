@@ -0,0 +1,151 @@
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use polars_core::prelude::*;
+use polars_io::prelude::IpcWriter;
+use polars_io::SerWriter;
+use polars_plan::logical_plan::{to_alp, ArenaLpIter};
+use xxhash_rust::xxh3::Xxh3;
+
+use crate::prelude::*;
+
+/// Extension appended to the path of the checkpoint to store its fingerprint.
+const FINGERPRINT_EXTENSION: &str = "fingerprint";
+/// Seed of the fingerprint hash. Fingerprints are persisted, so the hash must not depend on
+/// the process, the platform or the version.
+const FINGERPRINT_SEED: u64 = 0;
+
+fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut path = path.as_os_str().to_owned();
+    path.push(".");
+    path.push(suffix);
+    PathBuf::from(path)
+}
+
+fn fingerprint_path(path: &Path) -> PathBuf {
+    with_suffix(path, FINGERPRINT_EXTENSION)
+}
+
+/// Fingerprint the plan and the data it reads: the size and modification time of
+/// the scanned files and the content of the in-memory frames.
+fn plan_fingerprint(lp: &LogicalPlan) -> PolarsResult<u64> {
+    let mut hasher = Xxh3::with_seed(FINGERPRINT_SEED);
+    hasher.update(lp.describe().as_bytes());
+
+    let mut lp_arena = Arena::with_capacity(16);
+    let mut expr_arena = Arena::with_capacity(16);
+    let root = to_alp(lp.clone(), &mut expr_arena, &mut lp_arena)?;
+
+    for (_, lp) in (&lp_arena).iter(root) {
+        match lp {
+            ALogicalPlan::Scan {
+                scan_type: FileScan::Anonymous { .. },
+                ..
+            } => polars_bail!(ComputeError: "cannot checkpoint a plan with an anonymous scan"),
+            #[cfg(feature = "python")]
+            ALogicalPlan::PythonScan { .. } => {
+                polars_bail!(ComputeError: "cannot checkpoint a plan with a python scan")
+            },
+            ALogicalPlan::Scan { path, .. } => {
+                let metadata = std::fs::metadata(path).map_err(|e| {
+                    polars_err!(
+                        ComputeError: "cannot checkpoint a scan of {}: {}", path.display(), e
+                    )
+                })?;
+                hasher.update(path.to_string_lossy().as_bytes());
+                hasher.update(&metadata.len().to_le_bytes());
+                if let Ok(modified) = metadata.modified() {
+                    if let Ok(since_epoch) = modified.duration_since(UNIX_EPOCH) {
+                        hasher.update(&since_epoch.as_nanos().to_le_bytes());
+                    }
+                }
+            },
+            ALogicalPlan::DataFrameScan { df, .. } => {
+                let (height, width) = df.shape();
+                hasher.update(&(height as u64).to_le_bytes());
+                hasher.update(&(width as u64).to_le_bytes());
+                if width > 0 && height > 0 {
+                    let hashes = df.hash_rows_stable(FINGERPRINT_SEED)?;
+                    hashes
+                        .into_no_null_iter()
+                        .for_each(|h| hasher.update(&h.to_le_bytes()));
+                }
+            },
+            _ => {},
+        }
+    }
+    Ok(hasher.digest())
+}
+
+fn read_fingerprint(path: &Path) -> Option<u64> {
+    let fingerprint = std::fs::read_to_string(fingerprint_path(path)).ok()?;
+    u64::from_str_radix(fingerprint.trim(), 16).ok()
+}
+
+fn write_checkpoint(df: &mut DataFrame, path: &Path, fingerprint: u64) -> PolarsResult<()> {
+    let fingerprint_path = fingerprint_path(path);
+
+    // The old fingerprint is removed before the data is replaced, and the new fingerprint is
+    // written last. Both files are written to a temporary file first and renamed into place.
+    // An interrupted write therefore never pairs a fingerprint with data it doesn't describe;
+    // at worst the checkpoint is missing its fingerprint and is computed again.
+    match std::fs::remove_file(&fingerprint_path) {
+        Err(e) if e.kind() != std::io::ErrorKind::NotFound => return Err(e.into()),
+        _ => {},
+    }
+
+    let tmp_path = with_suffix(path, "tmp");
+    IpcWriter::new(File::create(&tmp_path)?).finish(df)?;
+    std::fs::rename(&tmp_path, path)?;
+
+    let tmp_path = with_suffix(&fingerprint_path, "tmp");
+    std::fs::write(&tmp_path, format!("{fingerprint:016x}"))?;
+    std::fs::rename(&tmp_path, &fingerprint_path)?;
+    Ok(())
+}
+
+impl LazyFrame {
+    /// Persist the result of this query at `path` in IPC format, so that a later run of
+    /// the same query can resume from the checkpoint instead of computing it again.
+    ///
+    /// The checkpoint is used only if it was written by the same plan over the same inputs.
+    /// This is validated by a fingerprint of the plan, of the size and modification time of
+    /// the scanned files and of the content of the in-memory frames. Note that the
+    /// fingerprint cannot see changes in the code of user defined functions. In-memory frames
+    /// are fingerprinted with [`DataFrame::hash_rows_stable`], so they can only hold data types
+    /// that it supports.
+    ///
+    /// The decision to resume is made when this method is called; the checkpoint is written
+    /// when the query is executed. Optimizations are not pushed through the checkpoint, so
+    /// it always holds the complete result of this query.
+    pub fn checkpoint(self, path: impl AsRef<Path>) -> PolarsResult<LazyFrame> {
+        let path = path.as_ref().to_owned();
+        let fingerprint = plan_fingerprint(&self.logical_plan)?;
+
+        if path.exists() && read_fingerprint(&path) == Some(fingerprint) {
+            if polars_core::config::verbose() {
+                eprintln!("resume from checkpoint {}", path.display());
+            }
+            let opt_state = self.get_opt_state();
+            let lf = LazyFrame::scan_ipc(&path, Default::default())?;
+            return Ok(LazyFrame::from_logical_plan(lf.logical_plan, opt_state));
+        }
+
+        let optimizations = AllowedOptimizations {
+            predicate_pushdown: false,
+            projection_pushdown: false,
+            streaming: false,
+            ..Default::default()
+        };
+        Ok(self.map(
+            move |mut df| {
+                write_checkpoint(&mut df, &path, fingerprint)?;
+                Ok(df)
+            },
+            optimizations,
+            None,
+            Some("CHECKPOINT"),
+        ))
+    }
+}
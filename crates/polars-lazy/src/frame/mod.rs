@@ -2,9 +2,13 @@
 #[cfg(feature = "python")]
 mod python;
 
+#[cfg(feature = "checkpoint")]
+mod checkpoint;
 mod err;
 #[cfg(feature = "pivot")]
 pub mod pivot;
+#[cfg(feature = "validate")]
+mod validate;
 
 use std::borrow::Cow;
 #[cfg(any(feature = "parquet", feature = "ipc", feature = "csv"))]
@@ -30,8 +34,9 @@ use polars_plan::global::FETCH_ROWS;
 #[cfg(any(feature = "ipc", feature = "parquet", feature = "csv"))]
 use polars_plan::logical_plan::collect_fingerprints;
 use polars_plan::logical_plan::optimize;
-use polars_plan::utils::expr_to_leaf_column_names;
-use smartstring::alias::String as SmartString;
+use polars_plan::utils::{expr_output_name, expr_to_leaf_column_names};
+#[cfg(feature = "validate")]
+pub use validate::Constraints;
 
 use crate::fallible;
 use crate::physical_plan::executors::Executor;
@@ -93,7 +98,7 @@ impl LazyFrame {
     }
 
     fn get_opt_state(&self) -> OptState {
-        self.opt_state
+        self.opt_state.clone()
     }
 
     fn from_logical_plan(logical_plan: LogicalPlan, opt_state: OptState) -> Self {
@@ -105,7 +110,7 @@ impl LazyFrame {
 
     /// Get current optimizations.
     pub fn get_current_optimizations(&self) -> OptState {
-        self.opt_state
+        self.opt_state.clone()
     }
 
     /// Set allowed optimizations.
@@ -129,8 +134,10 @@ impl LazyFrame {
             #[cfg(feature = "cse")]
             comm_subexpr_elim: false,
             streaming: false,
+            streaming_memory_limit: None,
             eager: false,
             fast_projection: false,
+            custom_rules: Default::default(),
         })
     }
 
@@ -184,11 +191,35 @@ impl LazyFrame {
         self
     }
 
+    /// Set the number of bytes a streaming sink may hold before it goes out-of-core.
+    pub fn with_streaming_memory_limit(mut self, memory_limit: Option<usize>) -> Self {
+        self.opt_state.streaming_memory_limit = memory_limit;
+        self
+    }
+
     pub fn _with_eager(mut self, toggle: bool) -> Self {
         self.opt_state.eager = toggle;
         self
     }
 
+    /// Add an [`OptimizationRule`] that runs alongside the built-in optimizations when this
+    /// query is optimized. See [`CustomRules`] for the contract of the rules.
+    ///
+    /// Adding a rule under an existing `name` replaces that rule.
+    pub fn with_optimization_rule<F>(mut self, name: &str, factory: F) -> Self
+    where
+        F: Fn() -> Box<dyn OptimizationRule> + Send + Sync + 'static,
+    {
+        self.opt_state.custom_rules.insert(name, factory);
+        self
+    }
+
+    /// Remove the [`OptimizationRule`] that was added under `name`.
+    pub fn without_optimization_rule(mut self, name: &str) -> Self {
+        self.opt_state.custom_rules.remove(name);
+        self
+    }
+
     /// Return a String describing the naive (un-optimized) logical plan.
     pub fn describe_plan(&self) -> String {
         self.logical_plan.describe()
@@ -349,7 +380,7 @@ impl LazyFrame {
 
     /// Check the if the `names` are available in the `schema`, if not
     /// return a `LogicalPlan` that raises an `Error`.
-    fn check_names(&self, names: &[SmartString], schema: Option<&SchemaRef>) -> Option<Self> {
+    fn check_names(&self, names: &[PlSmallStr], schema: Option<&SchemaRef>) -> Option<Self> {
         let schema = schema
             .map(Cow::Borrowed)
             .unwrap_or_else(|| Cow::Owned(self.schema().unwrap()));
@@ -369,7 +400,7 @@ impl LazyFrame {
                 .get_plan_builder()
                 .add_err(polars_err!(SchemaFieldNotFound: "{}", name))
                 .build();
-            Some(Self::from_logical_plan(lp, self.opt_state))
+            Some(Self::from_logical_plan(lp, self.get_opt_state()))
         } else {
             None
         }
@@ -390,8 +421,8 @@ impl LazyFrame {
     {
         let iter = existing.into_iter();
         let cap = iter.size_hint().0;
-        let mut existing_vec: Vec<SmartString> = Vec::with_capacity(cap);
-        let mut new_vec: Vec<SmartString> = Vec::with_capacity(cap);
+        let mut existing_vec: Vec<PlSmallStr> = Vec::with_capacity(cap);
+        let mut new_vec: Vec<PlSmallStr> = Vec::with_capacity(cap);
 
         // todo! should this error if `existing` and `new` have different lengths?
         // Currently, the longer of the two is truncated.
@@ -542,9 +573,10 @@ impl LazyFrame {
     ) -> PolarsResult<Node> {
         #[allow(unused_mut)]
         let mut opt_state = self.opt_state;
-        let streaming = self.opt_state.streaming;
+        let streaming = opt_state.streaming;
+        let _memory_limit = opt_state.streaming_memory_limit;
         #[cfg(feature = "cse")]
-        if streaming && self.opt_state.comm_subplan_elim {
+        if streaming && opt_state.comm_subplan_elim {
             polars_warn!(
                 "Cannot combine 'streaming' with 'comm_subplan_elim'. CSE will be turned off."
             );
@@ -573,7 +605,15 @@ impl LazyFrame {
         if streaming {
             #[cfg(feature = "streaming")]
             {
-                insert_streaming_nodes(lp_top, lp_arena, expr_arena, scratch, _fmt, true)?;
+                insert_streaming_nodes(
+                    lp_top,
+                    lp_arena,
+                    expr_arena,
+                    scratch,
+                    _fmt,
+                    true,
+                    _memory_limit,
+                )?;
             }
             #[cfg(not(feature = "streaming"))]
             {
@@ -665,6 +705,20 @@ impl LazyFrame {
         Ok((out, timer_df))
     }
 
+    /// Execute all the lazy operations and send the events of every executed node to
+    /// `observer`, e.g. to export metrics of the query.
+    ///
+    /// Only the default engine reports the events of nodes, they are the same as in
+    /// [`LazyFrame::profile`]. Streaming sorts and group-bys report when they spilled to disk.
+    pub fn collect_with_observer(
+        self,
+        observer: Arc<dyn ExecutionObserver>,
+    ) -> PolarsResult<DataFrame> {
+        let (mut state, mut physical_plan, _) = self.prepare_collect(false)?;
+        state.set_observer(observer);
+        physical_plan.execute(&mut state)
+    }
+
     /// Stream a query result into a parquet file. This is useful if the final result doesn't fit
     /// into memory. This methods will return an error if the query cannot be completely done in a
     /// streaming fashion.
@@ -832,6 +886,25 @@ impl LazyFrame {
         )
     }
 
+    /// Select the expressions of the `nodes` built with `builder`, see [`AExprBuilder`].
+    ///
+    /// The nodes are added to the plan as they are, without converting them to [`Expr`].
+    pub fn select_nodes(self, builder: AExprBuilder, nodes: &[Node]) -> Self {
+        let opt_state = self.get_opt_state();
+        let lp = self
+            .get_plan_builder()
+            .project_nodes(
+                builder.into_arena(),
+                nodes.to_vec(),
+                ProjectionOptions {
+                    run_parallel: true,
+                    duplicate_check: true,
+                },
+            )
+            .build();
+        Self::from_logical_plan(lp, opt_state)
+    }
+
     fn select_impl(self, exprs: Vec<Expr>, options: ProjectionOptions) -> Self {
         let opt_state = self.get_opt_state();
         let lp = self.get_plan_builder().project(exprs, options).build();
@@ -968,6 +1041,33 @@ impl LazyFrame {
         }
     }
 
+    /// Group into sessions based on a time value (or index value of type Int32, Int64).
+    ///
+    /// A new session starts when the time since the previous row is larger than `gap`,
+    /// e.g. `"30m"`. The sessions are determined per group of the `by` keys, and the index
+    /// column must be sorted within these groups.
+    ///
+    /// The groups are keyed by `by` and a column named `session_column` that numbers the
+    /// sessions of every `by` group, starting at zero. A column of that name in the input is
+    /// replaced.
+    #[cfg(feature = "dynamic_group_by")]
+    pub fn group_by_session<E: AsRef<[Expr]>>(
+        self,
+        index_column: Expr,
+        by: E,
+        gap: &str,
+        session_column: &str,
+    ) -> LazyGroupBy {
+        let mut keys = by.as_ref().to_vec();
+        let mut session_id = index_column.dt().session_id(gap);
+        if !keys.is_empty() {
+            session_id = session_id.over(&keys);
+        }
+        keys.push(col(session_column));
+        self.with_column(session_id.alias(session_column))
+            .group_by_stable(keys)
+    }
+
     /// Similar to [`group_by`][`Self::group_by`], but order of the DataFrame is maintained.
     pub fn group_by_stable<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(self, by: E) -> LazyGroupBy {
         let keys = by
@@ -1238,6 +1338,26 @@ impl LazyFrame {
         )
     }
 
+    /// Add the expressions of the `nodes` built with `builder` as columns, see
+    /// [`AExprBuilder`].
+    ///
+    /// The nodes are added to the plan as they are, without converting them to [`Expr`].
+    pub fn with_columns_nodes(self, builder: AExprBuilder, nodes: &[Node]) -> LazyFrame {
+        let opt_state = self.get_opt_state();
+        let lp = self
+            .get_plan_builder()
+            .with_columns_nodes(
+                builder.into_arena(),
+                nodes.to_vec(),
+                ProjectionOptions {
+                    run_parallel: true,
+                    duplicate_check: true,
+                },
+            )
+            .build();
+        Self::from_logical_plan(lp, opt_state)
+    }
+
     /// Add multiple columns to a DataFrame, but evaluate them sequentially.
     pub fn with_columns_seq<E: AsRef<[Expr]>>(self, exprs: E) -> LazyFrame {
         let exprs = exprs.as_ref().to_vec();
@@ -1513,6 +1633,29 @@ impl LazyFrame {
         Self::from_logical_plan(lp, opt_state)
     }
 
+    /// Cast the columns to the data types of `schema`, adding missing columns as nulls and
+    /// dropping columns that are not in the schema. Values that cannot be cast become null and
+    /// are collected in `rejects`, as are the added and dropped columns, see
+    /// [`Rejects::cast_frame`].
+    #[cfg(feature = "rejects")]
+    pub fn cast_collect_rejects(self, schema: SchemaRef, rejects: Rejects) -> LazyFrame {
+        let optimizations = AllowedOptimizations {
+            predicate_pushdown: false,
+            projection_pushdown: false,
+            streaming: false,
+            ..Default::default()
+        };
+        let output_schema = schema.clone();
+        self.map(
+            move |df| rejects.cast_frame(&df, &schema),
+            optimizations,
+            Some(Arc::new(move |_: &Schema| -> PolarsResult<SchemaRef> {
+                Ok(output_schema.clone())
+            })),
+            Some("CAST_COLLECT_REJECTS"),
+        )
+    }
+
     #[cfg(feature = "python")]
     pub fn map_python(
         self,
@@ -1668,6 +1811,47 @@ impl LazyGroupBy {
         LazyFrame::from_logical_plan(lp, self.opt_state)
     }
 
+    /// Group by and aggregate, but don't add the keys to the output.
+    ///
+    /// The key expressions are evaluated by the group by itself, so grouping on computed
+    /// keys doesn't require adding helper columns with `with_columns` first.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn example(df: DataFrame) -> LazyFrame {
+    ///       df.lazy()
+    ///        .group_by([col("id") % lit(10)])
+    ///        .agg_without_keys([col("value").sum()])
+    /// }
+    /// ```
+    ///
+    /// An aggregation with the output name of a key raises a duplicate error.
+    pub fn agg_without_keys<E: AsRef<[Expr]>>(self, aggs: E) -> LazyFrame {
+        let keys = self
+            .keys
+            .iter()
+            .filter_map(|k| expr_output_name(k).ok())
+            .collect::<Vec<_>>();
+        // the keys are dropped by name, so they would take such an aggregation with them
+        if let Some(name) = aggs
+            .as_ref()
+            .iter()
+            .filter_map(|e| expr_output_name(e).ok())
+            .find(|name| keys.contains(name))
+        {
+            let lp = LogicalPlan::Error {
+                input: Box::new(self.logical_plan),
+                err: polars_err!(duplicate = name).into(),
+            };
+            return LazyFrame::from_logical_plan(lp, self.opt_state);
+        }
+        self.agg(aggs).select([all().exclude(keys)])
+    }
+
     /// Return first n rows of each group
     pub fn head(self, n: Option<usize>) -> LazyFrame {
         let keys = self
@@ -1734,6 +1918,7 @@ pub struct JoinBuilder {
     force_parallel: bool,
     suffix: Option<String>,
     validation: JoinValidation,
+    strategy: JoinStrategy,
 }
 impl JoinBuilder {
     /// Create the `JoinBuilder` with the provided `LazyFrame` as the left table.
@@ -1748,6 +1933,7 @@ impl JoinBuilder {
             force_parallel: false,
             suffix: None,
             validation: Default::default(),
+            strategy: Default::default(),
         }
     }
 
@@ -1768,6 +1954,12 @@ impl JoinBuilder {
         self
     }
 
+    /// Hint on the algorithm used to join the tables.
+    pub fn strategy(mut self, strategy: JoinStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
     /// The expressions you want to join both tables on.
     ///
     /// The passed expressions must be valid in both `LazyFrame`s in the join.
@@ -1826,6 +2018,7 @@ impl JoinBuilder {
             validation: self.validation,
             suffix: self.suffix,
             slice: None,
+            strategy: self.strategy,
         };
 
         let lp = self
@@ -98,3 +98,42 @@ where
         separator,
     )
 }
+
+/// Do a [`pivot_stable`] and add margins with the totals of every row and column.
+/// See [`polars_ops::pivot::pivot_with_margins`].
+#[allow(clippy::too_many_arguments)]
+pub fn pivot_with_margins<I0, S0, I1, S1, I2, S2>(
+    df: &DataFrame,
+    values: I0,
+    index: I1,
+    columns: I2,
+    sort_columns: bool,
+    agg_expr: Option<Expr>,
+    // used as separator/delimiter in generated column names.
+    separator: Option<&str>,
+    margins_name: &str,
+) -> PolarsResult<DataFrame>
+where
+    I0: IntoIterator<Item = S0>,
+    S0: AsRef<str>,
+    I1: IntoIterator<Item = S1>,
+    S1: AsRef<str>,
+    I2: IntoIterator<Item = S2>,
+    S2: AsRef<str>,
+{
+    // make sure that the root column is replaced
+    let agg_expr = agg_expr.map(|agg_expr| {
+        let expr = prepare_eval_expr(agg_expr);
+        PivotAgg::Expr(Arc::new(PivotExpr(expr)))
+    });
+    polars_ops::pivot::pivot_with_margins(
+        df,
+        values,
+        index,
+        columns,
+        sort_columns,
+        agg_expr,
+        separator,
+        margins_name,
+    )
+}
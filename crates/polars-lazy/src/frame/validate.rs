@@ -0,0 +1,244 @@
+use polars_core::prelude::*;
+
+use crate::prelude::*;
+
+const CONSTRAINT: &str = "constraint";
+const ROW_NR: &str = "row_nr";
+
+#[derive(Clone)]
+enum Constraint {
+    NotNull(String),
+    Unique(Vec<String>),
+    InRange {
+        column: String,
+        lower: Option<Expr>,
+        upper: Option<Expr>,
+    },
+    #[cfg(all(feature = "strings", feature = "regex"))]
+    Matches {
+        column: String,
+        pattern: String,
+    },
+    #[cfg(feature = "semi_anti_join")]
+    References {
+        columns: Vec<String>,
+        other: LazyFrame,
+        other_columns: Vec<String>,
+    },
+}
+
+/// The rows in which none of `columns` is null.
+fn has_key(columns: &[String]) -> Expr {
+    columns
+        .iter()
+        .map(|c| col(c).is_not_null())
+        .reduce(|acc, e| acc.and(e))
+        .unwrap_or_else(|| lit(true))
+}
+
+impl Constraint {
+    fn name(&self) -> String {
+        use Constraint::*;
+        match self {
+            NotNull(column) => format!("not_null({column})"),
+            Unique(columns) => format!("unique({})", columns.join(", ")),
+            InRange { column, .. } => format!("in_range({column})"),
+            #[cfg(all(feature = "strings", feature = "regex"))]
+            Matches { column, pattern } => format!("matches({column}, {pattern})"),
+            #[cfg(feature = "semi_anti_join")]
+            References { columns, .. } => format!("references({})", columns.join(", ")),
+        }
+    }
+
+    /// Get the rows of `lf` that violate this constraint. Nulls only violate `NotNull`.
+    fn violations(&self, lf: LazyFrame) -> LazyFrame {
+        use Constraint::*;
+        let violations = match self {
+            NotNull(column) => lf.filter(col(column).is_null()),
+            Unique(columns) => {
+                let key = match columns.as_slice() {
+                    [column] => col(column),
+                    _ => as_struct(columns.iter().map(|c| col(c)).collect()),
+                };
+                lf.filter(has_key(columns)).filter(key.is_duplicated())
+            },
+            InRange {
+                column,
+                lower,
+                upper,
+            } => {
+                let mut valid = lit(true);
+                if let Some(lower) = lower {
+                    valid = valid.and(col(column).gt_eq(lower.clone()))
+                }
+                if let Some(upper) = upper {
+                    valid = valid.and(col(column).lt_eq(upper.clone()))
+                }
+                lf.filter(valid.not())
+            },
+            #[cfg(all(feature = "strings", feature = "regex"))]
+            Matches { column, pattern } => lf.filter(
+                col(column)
+                    .str()
+                    .contains(lit(pattern.as_str()), true)
+                    .not(),
+            ),
+            #[cfg(feature = "semi_anti_join")]
+            References {
+                columns,
+                other,
+                other_columns,
+            } => {
+                let left_on = columns.iter().map(|c| col(c)).collect::<Vec<_>>();
+                let right_on = other_columns.iter().map(|c| col(c)).collect::<Vec<_>>();
+                lf.filter(has_key(columns)).join(
+                    other.clone(),
+                    left_on,
+                    right_on,
+                    JoinArgs::new(JoinType::Anti),
+                )
+            },
+        };
+        violations.select([lit(self.name()).alias(CONSTRAINT), col(ROW_NR)])
+    }
+}
+
+/// A set of data quality constraints on the columns of a frame.
+///
+/// The constraints are checked with [`LazyFrame::validate`], which builds a query that
+/// reports the violations, or eagerly with [`Constraints::validate`].
+///
+/// # Example
+///
+/// ```rust
+/// use polars_core::prelude::*;
+/// use polars_lazy::prelude::*;
+///
+/// fn example(df: DataFrame) -> PolarsResult<DataFrame> {
+///     let constraints = Constraints::new()
+///         .not_null("id")
+///         .unique(&["id"])
+///         .in_range("age", Some(lit(0)), Some(lit(130)));
+///     df.lazy().validate(&constraints)?.collect()
+/// }
+/// ```
+#[derive(Clone, Default)]
+#[must_use]
+pub struct Constraints {
+    schema: Schema,
+    constraints: Vec<Constraint>,
+}
+
+impl Constraints {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Require the columns of `schema` to exist with the given data types.
+    ///
+    /// If the schema doesn't match, only the schema violations are reported, as the other
+    /// constraints cannot be checked.
+    pub fn with_schema(mut self, schema: Schema) -> Self {
+        self.schema.merge(schema);
+        self
+    }
+
+    /// Require `column` to have no null values.
+    pub fn not_null(mut self, column: &str) -> Self {
+        self.constraints
+            .push(Constraint::NotNull(column.to_string()));
+        self
+    }
+
+    /// Require the combination of `columns` to be unique. All duplicated rows are reported.
+    /// Rows with a null in one of `columns` are not checked, so nulls are never duplicates.
+    pub fn unique<S: AsRef<str>>(mut self, columns: &[S]) -> Self {
+        let columns = columns.iter().map(|c| c.as_ref().to_string()).collect();
+        self.constraints.push(Constraint::Unique(columns));
+        self
+    }
+
+    /// Require the values of `column` to be within the (inclusive) bounds.
+    pub fn in_range(mut self, column: &str, lower: Option<Expr>, upper: Option<Expr>) -> Self {
+        self.constraints.push(Constraint::InRange {
+            column: column.to_string(),
+            lower,
+            upper,
+        });
+        self
+    }
+
+    /// Require the strings of `column` to match the regex `pattern`.
+    #[cfg(all(feature = "strings", feature = "regex"))]
+    pub fn matches(mut self, column: &str, pattern: &str) -> Self {
+        self.constraints.push(Constraint::Matches {
+            column: column.to_string(),
+            pattern: pattern.to_string(),
+        });
+        self
+    }
+
+    /// Require the keys in `columns` to exist in `other_columns` of `other`, like a foreign
+    /// key. Rows with a null key are not checked.
+    #[cfg(feature = "semi_anti_join")]
+    pub fn references<S: AsRef<str>>(
+        mut self,
+        columns: &[S],
+        other: LazyFrame,
+        other_columns: &[S],
+    ) -> Self {
+        let to_vec = |columns: &[S]| {
+            columns
+                .iter()
+                .map(|c| c.as_ref().to_string())
+                .collect::<Vec<_>>()
+        };
+        self.constraints.push(Constraint::References {
+            columns: to_vec(columns),
+            other,
+            other_columns: to_vec(other_columns),
+        });
+        self
+    }
+
+    /// Check `df` eagerly. See [`LazyFrame::validate`].
+    pub fn validate(&self, df: &DataFrame) -> PolarsResult<DataFrame> {
+        df.clone().lazy().validate(self)?.collect()
+    }
+}
+
+fn schema_violations(constraints: &Constraints, schema: &Schema) -> PolarsResult<DataFrame> {
+    let violations: Vec<_> = constraints
+        .schema
+        .iter()
+        .filter(|(name, dtype)| schema.get(name) != Some(*dtype))
+        .map(|(name, dtype)| format!("schema({name}: {dtype})"))
+        .collect();
+    let n_violations = violations.len();
+    DataFrame::new(vec![
+        Series::new(CONSTRAINT, violations),
+        Series::full_null(ROW_NR, n_violations, &IDX_DTYPE),
+    ])
+}
+
+impl LazyFrame {
+    /// Check this frame against `constraints`.
+    ///
+    /// Returns a query with a row for every violation: the `constraint` that is violated and
+    /// the `row_nr` of the violating row. Schema violations have a null `row_nr`.
+    pub fn validate(self, constraints: &Constraints) -> PolarsResult<LazyFrame> {
+        let schema_violations = schema_violations(constraints, &self.schema()?)?;
+        if schema_violations.height() > 0 || constraints.constraints.is_empty() {
+            return Ok(schema_violations.lazy());
+        }
+
+        // the input is shared by all constraints, so we cache it
+        let lf = self.with_row_count(ROW_NR, None).cache();
+        let violations = constraints
+            .constraints
+            .iter()
+            .map(|constraint| constraint.violations(lf.clone()))
+            .collect::<Vec<_>>();
+        concat(violations, Default::default())
+    }
+}
@@ -44,7 +44,7 @@ impl Executor for FilterExec {
             )
         })?;
 
-        let profile_name = if state.has_node_timer() {
+        let profile_name = if state.records_nodes() {
             Cow::Owned(format!(".filter({})", &self.predicate.as_ref()))
         } else {
             Cow::Borrowed("")
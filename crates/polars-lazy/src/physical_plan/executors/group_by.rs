@@ -1,20 +1,85 @@
+use polars_core::frame::group_by::aggregations::agg_n_unique_many;
+use polars_core::frame::group_by::GroupByMethod;
 use rayon::prelude::*;
 
 use super::*;
 
+/// Returns the input column and the output name of a (possibly aliased)
+/// `col(name).n_unique()` aggregation.
+fn n_unique_column(expr: &dyn PhysicalExpr) -> Option<(&str, &str)> {
+    match expr.as_expression() {
+        Some(Expr::Alias(e, name)) => match e.as_ref() {
+            Expr::Agg(AggExpr::NUnique(e)) => match e.as_ref() {
+                Expr::Column(column) => Some((column.as_ref(), name.as_ref())),
+                _ => None,
+            },
+            _ => None,
+        },
+        _ => match expr.as_aggregation()? {
+            (input, GroupByMethod::NUnique) => match input.as_expression()? {
+                Expr::Column(name) => Some((name.as_ref(), name.as_ref())),
+                _ => None,
+            },
+            _ => None,
+        },
+    }
+}
+
+fn evaluate_agg(
+    df: &DataFrame,
+    expr: &dyn PhysicalExpr,
+    groups: &GroupsProxy,
+    state: &ExecutionState,
+) -> PolarsResult<Series> {
+    let agg = expr.evaluate_on_groups(df, groups, state)?.finalize();
+    polars_ensure!(agg.len() == groups.len(), agg_len = agg.len(), groups.len());
+    Ok(agg)
+}
+
 pub(super) fn evaluate_aggs(
     df: &DataFrame,
     aggs: &[Arc<dyn PhysicalExpr>],
     groups: &GroupsProxy,
     state: &ExecutionState,
 ) -> PolarsResult<Vec<Series>> {
+    let (n_unique, other): (Vec<_>, Vec<_>) = aggs
+        .iter()
+        .enumerate()
+        .map(|(i, expr)| (i, n_unique_column(expr.as_ref())))
+        .partition(|(_, n_unique)| n_unique.is_some());
+
+    // Distinct counts of multiple columns share a single pass over the groups.
+    if n_unique.len() > 1 {
+        let columns = n_unique
+            .iter()
+            .map(|(_, n_unique)| df.column(n_unique.unwrap().0).cloned())
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let (fused, evaluated) = POOL.install(|| {
+            rayon::join(
+                || agg_n_unique_many(&columns, groups),
+                || {
+                    other
+                        .par_iter()
+                        .map(|(i, _)| evaluate_agg(df, aggs[*i].as_ref(), groups, state))
+                        .collect::<PolarsResult<Vec<_>>>()
+                },
+            )
+        });
+
+        let mut out = vec![Series::default(); aggs.len()];
+        for ((i, n_unique), s) in n_unique.iter().zip(fused?) {
+            out[*i] = s.with_name(n_unique.unwrap().1);
+        }
+        for ((i, _), s) in other.iter().zip(evaluated?) {
+            out[*i] = s;
+        }
+        return Ok(out);
+    }
+
     POOL.install(|| {
         aggs.par_iter()
-            .map(|expr| {
-                let agg = expr.evaluate_on_groups(df, groups, state)?.finalize();
-                polars_ensure!(agg.len() == groups.len(), agg_len = agg.len(), groups.len());
-                Ok(agg)
-            })
+            .map(|expr| evaluate_agg(df, expr.as_ref(), groups, state))
             .collect::<PolarsResult<Vec<_>>>()
     })
 }
@@ -126,7 +191,7 @@ impl Executor for GroupByExec {
         }
         let df = self.input.execute(state)?;
 
-        let profile_name = if state.has_node_timer() {
+        let profile_name = if state.records_nodes() {
             let by = self
                 .keys
                 .iter()
@@ -138,7 +203,7 @@ impl Executor for GroupByExec {
             Cow::Borrowed("")
         };
 
-        if state.has_node_timer() {
+        if state.records_nodes() {
             let new_state = state.clone();
             new_state.record(|| self.execute_impl(state, df), profile_name)
         } else {
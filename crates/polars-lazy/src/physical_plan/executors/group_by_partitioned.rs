@@ -255,6 +255,7 @@ impl PartitionGroupByExec {
             &mut vec![],
             false,
             false,
+            None,
         )
         .unwrap();
 
@@ -371,7 +372,7 @@ impl Executor for PartitionGroupByExec {
         }
         let original_df = self.input.execute(state)?;
 
-        let profile_name = if state.has_node_timer() {
+        let profile_name = if state.records_nodes() {
             let by = self
                 .phys_keys
                 .iter()
@@ -382,7 +383,7 @@ impl Executor for PartitionGroupByExec {
         } else {
             Cow::Borrowed("")
         };
-        if state.has_node_timer() {
+        if state.records_nodes() {
             let new_state = state.clone();
             new_state.record(|| self.execute_impl(state, original_df), profile_name)
         } else {
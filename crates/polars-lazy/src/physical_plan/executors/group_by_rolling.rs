@@ -107,7 +107,7 @@ impl Executor for GroupByRollingExec {
             }
         }
         let df = self.input.execute(state)?;
-        let profile_name = if state.has_node_timer() {
+        let profile_name = if state.records_nodes() {
             let by = self
                 .keys
                 .iter()
@@ -119,7 +119,7 @@ impl Executor for GroupByRollingExec {
             Cow::Borrowed("")
         };
 
-        if state.has_node_timer() {
+        if state.records_nodes() {
             let new_state = state.clone();
             new_state.record(|| self.execute_impl(state, df), profile_name)
         } else {
@@ -68,7 +68,7 @@ impl Executor for JoinExec {
         let mut df_left = df_left?;
         let mut df_right = df_right?;
 
-        let profile_name = if state.has_node_timer() {
+        let profile_name = if state.records_nodes() {
             let by = self
                 .left_on
                 .iter()
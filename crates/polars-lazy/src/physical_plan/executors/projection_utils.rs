@@ -1,6 +1,6 @@
+use polars_core::prelude::PlSmallStr;
 use polars_utils::format_smartstring;
 use polars_utils::iter::EnumerateIdxTrait;
-use smartstring::alias::String as SmartString;
 
 use super::*;
 
@@ -8,7 +8,7 @@ pub(super) fn profile_name(
     s: &dyn PhysicalExpr,
     input_schema: &Schema,
     has_cse: bool,
-) -> PolarsResult<SmartString> {
+) -> PolarsResult<PlSmallStr> {
     match (has_cse, s.to_field(input_schema)) {
         (false, Err(e)) => Err(e),
         (true, Err(_)) => Ok(expr_to_leaf_column_names_iter(s.as_expression().unwrap())
@@ -36,7 +36,7 @@ fn execute_projection_cached_window_fns(
     #[allow(clippy::type_complexity)]
     // String: partition_name,
     // u32: index,
-    let mut windows: PlHashMap<SmartString, Vec<IdAndExpression>> = PlHashMap::default();
+    let mut windows: PlHashMap<PlSmallStr, Vec<IdAndExpression>> = PlHashMap::default();
     #[cfg(feature = "dynamic_group_by")]
     let mut rolling: PlHashMap<&RollingGroupOptions, Vec<IdAndExpression>> = PlHashMap::default();
     let mut other = Vec::with_capacity(exprs.len());
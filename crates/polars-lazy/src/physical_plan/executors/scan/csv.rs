@@ -1,5 +1,12 @@
+use std::io::Cursor;
 use std::path::PathBuf;
 
+use polars_io::cloud::{CloudOptions, ObjectBytes};
+use polars_io::is_cloud_url;
+use polars_io::mmap::MmapBytesReader;
+#[cfg(feature = "cloud")]
+use polars_io::pl_async::get_runtime;
+
 use super::*;
 
 pub struct CsvExec {
@@ -8,10 +15,38 @@ pub struct CsvExec {
     pub options: CsvParserOptions,
     pub file_options: FileScanOptions,
     pub predicate: Option<Arc<dyn PhysicalExpr>>,
+    pub cloud_options: Option<CloudOptions>,
+    pub object: Option<ObjectBytes>,
 }
 
 impl CsvExec {
     fn read(&mut self) -> PolarsResult<DataFrame> {
+        // a cloud object is downloaded once, when the schema is inferred
+        if let Some(object) = self.object.take() {
+            return self.read_with(CsvReader::new(Cursor::new(object)));
+        }
+        if is_cloud_url(&self.path) {
+            #[cfg(not(feature = "cloud"))]
+            panic!(
+                "One or more of the cloud storage features ('aws', 'gcp', ...) must be enabled."
+            );
+
+            #[cfg(feature = "cloud")]
+            {
+                let bytes = {
+                    let uri = self.path.to_string_lossy();
+                    get_runtime().block_on(polars_io::cloud::fetch_object(
+                        &uri,
+                        self.cloud_options.as_ref(),
+                    ))?
+                };
+                return self.read_with(CsvReader::new(Cursor::new(bytes)));
+            }
+        }
+        self.read_with(CsvReader::from_path(&self.path).unwrap())
+    }
+
+    fn read_with<R: MmapBytesReader>(&mut self, reader: CsvReader<R>) -> PolarsResult<DataFrame> {
         let mut with_columns = mem::take(&mut self.file_options.with_columns);
         let mut projected_len = 0;
         with_columns.as_ref().map(|columns| {
@@ -25,8 +60,7 @@ impl CsvExec {
         let n_rows = _set_n_rows_for_scan(self.file_options.n_rows);
         let predicate = self.predicate.clone().map(phys_expr_to_io_expr);
 
-        CsvReader::from_path(&self.path)
-            .unwrap()
+        reader
             .has_header(self.options.has_header)
             .with_dtypes(Some(self.schema.clone()))
             .with_delimiter(self.options.delimiter)
@@ -44,6 +78,8 @@ impl CsvExec {
             .with_encoding(self.options.encoding)
             .with_rechunk(self.file_options.rechunk)
             .with_row_count(std::mem::take(&mut self.file_options.row_count))
+            .with_byte_offset(self.options.byte_offset.clone())
+            .with_progress_callback(self.options.progress.clone())
             .with_try_parse_dates(self.options.try_parse_dates)
             .truncate_ragged_lines(self.options.truncate_ragged_lines)
             .raise_if_empty(self.options.raise_if_empty)
@@ -62,7 +98,7 @@ impl Executor for CsvExec {
             slice: (self.options.skip_rows, self.file_options.n_rows),
         };
 
-        let profile_name = if state.has_node_timer() {
+        let profile_name = if state.records_nodes() {
             let mut ids = vec![self.path.to_string_lossy().into()];
             if self.predicate.is_some() {
                 ids.push("predicate".into())
@@ -78,7 +114,9 @@ impl Executor for CsvExec {
                 state
                     .file_cache
                     .read(finger_print, self.file_options.file_counter, &mut || {
-                        self.read()
+                        let out = self.read();
+                        state.record_bytes_read(&self.path);
+                        out
                     })
             },
             profile_name,
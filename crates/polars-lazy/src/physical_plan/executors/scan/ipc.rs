@@ -12,6 +12,17 @@ pub struct IpcExec {
 
 impl IpcExec {
     fn read(&mut self, verbose: bool) -> PolarsResult<DataFrame> {
+        #[cfg(feature = "parquet")]
+        if let Some(df) = skip_by_file_index(
+            &self.path,
+            self.predicate.as_ref(),
+            &self.schema,
+            &self.file_options,
+            verbose,
+        )? {
+            return Ok(df);
+        }
+
         let (file, projection, n_rows, predicate) = prepare_scan_args(
             &self.path,
             &self.predicate,
@@ -42,7 +53,7 @@ impl Executor for IpcExec {
             slice: (0, self.file_options.n_rows),
         };
 
-        let profile_name = if state.has_node_timer() {
+        let profile_name = if state.records_nodes() {
             let mut ids = vec![self.path.to_string_lossy().into()];
             if self.predicate.is_some() {
                 ids.push("predicate".into())
@@ -58,7 +69,9 @@ impl Executor for IpcExec {
                 state
                     .file_cache
                     .read(finger_print, self.file_options.file_counter, &mut || {
-                        self.read(state.verbose())
+                        let out = self.read(state.verbose());
+                        state.record_bytes_read(&self.path);
+                        out
                     })
             },
             profile_name,
@@ -62,12 +62,77 @@ fn prepare_scan_args(
     (file, projection, n_rows, predicate)
 }
 
+/// Consult the data skipping index next to `path` (see [`polars_io::file_index`]).
+///
+/// Returns an empty [`DataFrame`] with the output schema of the scan if the index proves that
+/// no row can pass the predicate.
+#[cfg(feature = "parquet")]
+fn skip_by_file_index(
+    path: &std::path::Path,
+    predicate: Option<&Arc<dyn PhysicalExpr>>,
+    schema: &Schema,
+    file_options: &FileScanOptions,
+    verbose: bool,
+) -> PolarsResult<Option<DataFrame>> {
+    let Some(stats_evaluator) = predicate.and_then(|p| p.as_stats_evaluator()) else {
+        return Ok(None);
+    };
+    let Some(index) = polars_io::file_index::read_file_index(path)? else {
+        return Ok(None);
+    };
+    if stats_evaluator.should_read(index.stats())? {
+        return Ok(None);
+    }
+    if verbose {
+        eprintln!(
+            "skipping {}: the file index proves no row matches the predicate",
+            path.display()
+        );
+    }
+
+    let mut df = DataFrame::from(schema);
+    if let Some(with_columns) = &file_options.with_columns {
+        df = df.select(with_columns.iter().filter(|name| schema.contains(name)))?;
+    }
+    if let Some(rc) = &file_options.row_count {
+        if df.find_idx_by_name(&rc.name).is_none() {
+            df.insert_at_idx(0, Series::new_empty(&rc.name, &IDX_DTYPE))?;
+        }
+    }
+    Ok(Some(df))
+}
+
 /// Producer of an in memory DataFrame
 pub struct DataFrameExec {
     pub(crate) df: Arc<DataFrame>,
     pub(crate) selection: Option<Arc<dyn PhysicalExpr>>,
     pub(crate) projection: Option<Arc<Vec<String>>>,
     pub(crate) predicate_has_windows: bool,
+    /// The memoized statistics of the scan node.
+    pub(crate) stats: ScanStatsCache,
+    /// The columns the statistics are checked for.
+    pub(crate) predicate_columns: Vec<Arc<str>>,
+}
+
+impl DataFrameExec {
+    /// Check the statistics of the predicate columns against the predicate. Returns `false` if
+    /// they prove that no row can pass the predicate.
+    fn should_filter_by_stats(
+        &self,
+        df: &DataFrame,
+        predicate: &dyn PhysicalExpr,
+    ) -> PolarsResult<bool> {
+        let Some(stats_evaluator) = predicate.as_stats_evaluator() else {
+            return Ok(true);
+        };
+        let stats = self
+            .stats
+            .get_or_compute(df, self.predicate_columns.iter().map(|name| name.as_ref()));
+        if stats.column_stats().is_empty() {
+            return Ok(true);
+        }
+        stats_evaluator.should_read(&stats)
+    }
 }
 
 impl Executor for DataFrameExec {
@@ -82,6 +147,12 @@ impl Executor for DataFrameExec {
         }
 
         if let Some(selection) = &self.selection {
+            if !self.should_filter_by_stats(&df, selection.as_ref())? {
+                if state.verbose() {
+                    eprintln!("the column statistics prove no row matches the predicate")
+                }
+                return Ok(df.clear());
+            }
             if self.predicate_has_windows {
                 state.insert_has_window_function_flag()
             }
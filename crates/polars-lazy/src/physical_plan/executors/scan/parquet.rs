@@ -38,7 +38,19 @@ impl ParquetExec {
         }
     }
 
-    fn read(&mut self) -> PolarsResult<DataFrame> {
+    fn read(&mut self, verbose: bool) -> PolarsResult<DataFrame> {
+        if self.options.use_statistics {
+            if let Some(df) = skip_by_file_index(
+                &self.path,
+                self.predicate.as_ref(),
+                &self.file_info.schema,
+                &self.file_options,
+                verbose,
+            )? {
+                return Ok(df);
+            }
+        }
+
         let hive_partitions = self
             .file_info
             .hive_parts
@@ -63,6 +75,8 @@ impl ParquetExec {
                 .set_rechunk(self.file_options.rechunk)
                 .set_low_memory(self.options.low_memory)
                 .use_statistics(self.options.use_statistics)
+                .use_bloom_filters(self.options.use_bloom_filters)
+                .with_struct_fields(self.options.struct_fields.clone())
                 .with_hive_partition_columns(hive_partitions)
                 ._finish_with_scan_ops(predicate, projection.as_ref().map(|v| v.as_ref()))
         } else if is_cloud_url(self.path.as_path()) {
@@ -105,7 +119,7 @@ impl Executor for ParquetExec {
             slice: (0, self.file_options.n_rows),
         };
 
-        let profile_name = if state.has_node_timer() {
+        let profile_name = if state.records_nodes() {
             let mut ids = vec![self.path.to_string_lossy().into()];
             if self.predicate.is_some() {
                 ids.push("predicate".into())
@@ -121,7 +135,9 @@ impl Executor for ParquetExec {
                 state
                     .file_cache
                     .read(finger_print, self.file_options.file_counter, &mut || {
-                        self.read()
+                        let out = self.read(state.verbose());
+                        state.record_bytes_read(&self.path);
+                        out
                     })
             },
             profile_name,
@@ -1,4 +1,6 @@
 use super::*;
+#[cfg(feature = "streaming")]
+use crate::physical_plan::observer::with_pipeline_observer;
 
 pub(crate) struct UdfExec {
     pub(crate) input: Box<dyn Executor>,
@@ -15,11 +17,20 @@ impl Executor for UdfExec {
         }
         let df = self.input.execute(state)?;
 
-        let profile_name = if state.has_node_timer() {
+        let profile_name = if state.records_nodes() {
             Cow::Owned(format!("{}", self.function))
         } else {
             Cow::Borrowed("")
         };
+        #[cfg(feature = "streaming")]
+        if let FunctionNode::Pipeline { .. } = &self.function {
+            // the pipeline reports its spills to the observer of this query
+            let observer = state.observer().cloned();
+            return state.record(
+                || with_pipeline_observer(observer, || self.function.evaluate(df)),
+                profile_name,
+            );
+        }
         state.record(|| self.function.evaluate(df), profile_name)
     }
 }
@@ -37,6 +37,10 @@ impl PhysicalExpr for AggregationExpr {
         None
     }
 
+    fn as_aggregation(&self) -> Option<(&dyn PhysicalExpr, GroupByMethod)> {
+        Some((self.input.as_ref(), self.agg_type))
+    }
+
     fn evaluate(&self, _df: &DataFrame, _state: &ExecutionState) -> PolarsResult<Series> {
         unimplemented!()
     }
@@ -4,9 +4,8 @@ use std::sync::Arc;
 use polars_core::frame::group_by::GroupsProxy;
 use polars_core::prelude::*;
 use polars_core::POOL;
-#[cfg(feature = "parquet")]
 use polars_io::predicates::{BatchStats, StatsEvaluator};
-#[cfg(feature = "parquet")]
+use polars_ops::prelude::{group_arg_max, group_arg_min};
 use polars_plan::dsl::FunctionExpr;
 use rayon::prelude::*;
 
@@ -224,6 +223,39 @@ impl ApplyExpr {
         let ac = acs.swap_remove(0);
         self.finish_apply_groups(ac, ca)
     }
+
+    /// Evaluate `min_by`/`max_by` on the groups by finding the position of the extremum of `by`
+    /// in every group and taking the values at those positions at once. Returns `None` if the
+    /// inputs are not both flat columns with the same groups.
+    fn apply_extremum_by_groups<'a>(
+        &self,
+        acs: &mut Vec<AggregationContext<'a>>,
+        max: bool,
+    ) -> PolarsResult<Option<AggregationContext<'a>>> {
+        let [values, by] = acs.as_mut_slice() else {
+            return Ok(None);
+        };
+        if !matches!(values.agg_state(), AggState::NotAggregated(_))
+            || !matches!(by.agg_state(), AggState::NotAggregated(_))
+            // a taken list would be seen as an aggregated list
+            || matches!(values.series().dtype(), DataType::List(_))
+            || values.series().len() != by.series().len()
+            || !std::ptr::eq(values.groups().as_ref(), by.groups().as_ref())
+        {
+            return Ok(None);
+        }
+
+        let by_s = by.series().clone();
+        let idx = if max {
+            group_arg_max(&by_s, by.groups())
+        } else {
+            group_arg_min(&by_s, by.groups())
+        };
+        let mut ac = acs.swap_remove(0);
+        let taken = ac.series().take(&idx)?;
+        ac.with_series(taken, true, Some(&self.expr))?;
+        Ok(Some(ac))
+    }
 }
 
 fn all_unit_length(ca: &ListChunked) -> bool {
@@ -295,6 +327,17 @@ impl PhysicalExpr for ApplyExpr {
         } else {
             let mut acs = self.prepare_multiple_inputs(df, groups, state)?;
 
+            if let Expr::Function {
+                function: function @ (FunctionExpr::MinBy | FunctionExpr::MaxBy),
+                ..
+            } = &self.expr
+            {
+                let max = matches!(function, FunctionExpr::MaxBy);
+                if let Some(ac) = self.apply_extremum_by_groups(&mut acs, max)? {
+                    return Ok(ac);
+                }
+            }
+
             match self.collect_groups {
                 ApplyOptions::ApplyList => {
                     let mut s = acs.iter_mut().map(|ac| ac.aggregated()).collect::<Vec<_>>();
@@ -330,7 +373,6 @@ impl PhysicalExpr for ApplyExpr {
     fn is_valid_aggregation(&self) -> bool {
         matches!(self.collect_groups, ApplyOptions::ApplyGroups)
     }
-    #[cfg(feature = "parquet")]
     fn as_stats_evaluator(&self) -> Option<&dyn polars_io::predicates::StatsEvaluator> {
         let function = match &self.expr {
             Expr::Function { function, .. } => function,
@@ -409,7 +451,6 @@ fn apply_multiple_elementwise<'a>(
     }
 }
 
-#[cfg(feature = "parquet")]
 impl StatsEvaluator for ApplyExpr {
     fn should_read(&self, stats: &BatchStats) -> PolarsResult<bool> {
         let read = self.should_read_impl(stats)?;
@@ -425,7 +466,6 @@ impl StatsEvaluator for ApplyExpr {
     }
 }
 
-#[cfg(feature = "parquet")]
 impl ApplyExpr {
     fn should_read_impl(&self, stats: &BatchStats) -> PolarsResult<bool> {
         let (function, input) = match &self.expr {
@@ -232,7 +232,6 @@ impl PhysicalExpr for BinaryExpr {
         Some(self)
     }
 
-    #[cfg(feature = "parquet")]
     fn as_stats_evaluator(&self) -> Option<&dyn polars_io::predicates::StatsEvaluator> {
         Some(self)
     }
@@ -245,7 +244,6 @@ impl PhysicalExpr for BinaryExpr {
     }
 }
 
-#[cfg(feature = "parquet")]
 mod stats {
     use polars_io::predicates::{BatchStats, StatsEvaluator};
 
@@ -389,26 +387,35 @@ mod stats {
             let out = match (self.left.is_literal(), self.right.is_literal()) {
                 (false, true) => {
                     let l = stats.get_stats(fld_l.name())?;
-                    match l.to_min_max() {
-                        None => Ok(true),
-                        Some(min_max_s) => {
-                            // will be incorrect if not
-                            debug_assert_eq!(min_max_s.null_count(), 0);
-                            let lit_s = self.right.evaluate(&dummy, &state).unwrap();
-                            Ok(apply_operator_stats_rhs_lit(&min_max_s, &lit_s, self.op))
-                        },
+                    let lit_s = self.right.evaluate(&dummy, &state).unwrap();
+                    // The bloom filter proves absence of values min/max can't rule out.
+                    if self.op == Operator::Eq && !l.may_contain(&lit_s) {
+                        Ok(false)
+                    } else {
+                        match l.to_min_max() {
+                            None => Ok(true),
+                            Some(min_max_s) => {
+                                // will be incorrect if not
+                                debug_assert_eq!(min_max_s.null_count(), 0);
+                                Ok(apply_operator_stats_rhs_lit(&min_max_s, &lit_s, self.op))
+                            },
+                        }
                     }
                 },
                 (true, false) => {
                     let r = stats.get_stats(fld_r.name())?;
-                    match r.to_min_max() {
-                        None => Ok(true),
-                        Some(min_max_s) => {
-                            // will be incorrect if not
-                            debug_assert_eq!(min_max_s.null_count(), 0);
-                            let lit_s = self.left.evaluate(&dummy, &state).unwrap();
-                            Ok(apply_operator_stats_lhs_lit(&lit_s, &min_max_s, self.op))
-                        },
+                    let lit_s = self.left.evaluate(&dummy, &state).unwrap();
+                    if self.op == Operator::Eq && !r.may_contain(&lit_s) {
+                        Ok(false)
+                    } else {
+                        match r.to_min_max() {
+                            None => Ok(true),
+                            Some(min_max_s) => {
+                                // will be incorrect if not
+                                debug_assert_eq!(min_max_s.null_count(), 0);
+                                Ok(apply_operator_stats_lhs_lit(&lit_s, &min_max_s, self.op))
+                            },
+                        }
                     }
                 },
                 // Default: read the file
@@ -0,0 +1,91 @@
+use std::borrow::Cow;
+use std::sync::Arc;
+
+use polars_core::frame::group_by::GroupsProxy;
+use polars_core::prelude::*;
+
+use crate::physical_plan::state::ExecutionState;
+use crate::prelude::*;
+
+/// Adapts an element-wise [`ExprExtension`] to a [`SeriesUdf`], so that it is executed by an
+/// [`ApplyExpr`].
+pub(crate) struct ExtensionUdf(pub(crate) Arc<dyn ExprExtension>);
+
+impl SeriesUdf for ExtensionUdf {
+    fn call_udf(&self, s: &mut [Series]) -> PolarsResult<Option<Series>> {
+        self.0.evaluate(s).map(Some)
+    }
+}
+
+/// Executes an aggregating [`ExprExtension`].
+pub struct ExtensionExpr {
+    pub(crate) inputs: Vec<Arc<dyn PhysicalExpr>>,
+    pub(crate) function: SpecialEq<Arc<dyn ExprExtension>>,
+    pub(crate) expr: Expr,
+}
+
+impl PhysicalExpr for ExtensionExpr {
+    fn as_expression(&self) -> Option<&Expr> {
+        Some(&self.expr)
+    }
+
+    fn evaluate(&self, df: &DataFrame, state: &ExecutionState) -> PolarsResult<Series> {
+        let inputs = self
+            .inputs
+            .iter()
+            .map(|e| e.evaluate(df, state))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        self.function.evaluate(&inputs)
+    }
+
+    #[allow(clippy::ptr_arg)]
+    fn evaluate_on_groups<'a>(
+        &self,
+        df: &DataFrame,
+        groups: &'a GroupsProxy,
+        state: &ExecutionState,
+    ) -> PolarsResult<AggregationContext<'a>> {
+        let mut groups_out = None;
+        let mut inputs: Vec<Series> = Vec::with_capacity(self.inputs.len());
+        for e in &self.inputs {
+            let ac = e.evaluate_on_groups(df, groups, state)?;
+            polars_ensure!(
+                !matches!(ac.agg_state(), AggState::AggregatedFlat(_)),
+                expr = self.expr,
+                ComputeError: "the input of '{}' is already aggregated", self.function.name()
+            );
+            let (s, input_groups) = ac.get_final_aggregation();
+            // All inputs must be grouped in the same way, which is the case if they have the
+            // same length.
+            if let Some(first) = inputs.first() {
+                polars_ensure!(
+                    s.len() == first.len(),
+                    expr = self.expr,
+                    ShapeMismatch: "the inputs of '{}' have different lengths in the group_by context",
+                    self.function.name()
+                );
+            } else {
+                groups_out = Some(input_groups);
+            }
+            inputs.push(s);
+        }
+        let groups_out = groups_out.unwrap_or(Cow::Borrowed(groups));
+
+        let out = self.function.evaluate_on_groups(&inputs, &groups_out)?;
+        polars_ensure!(
+            out.len() == groups_out.len(),
+            expr = self.expr,
+            ComputeError: "'{}' should give a value per group, got {} values for {} groups",
+            self.function.name(), out.len(), groups_out.len()
+        );
+        Ok(AggregationContext::new(out, groups_out, true))
+    }
+
+    fn to_field(&self, input_schema: &Schema) -> PolarsResult<Field> {
+        self.expr.to_field(input_schema, Context::Default)
+    }
+
+    fn is_valid_aggregation(&self) -> bool {
+        true
+    }
+}
@@ -5,6 +5,7 @@ mod binary;
 mod cast;
 mod column;
 mod count;
+mod extension;
 mod filter;
 mod group_iter;
 mod literal;
@@ -27,10 +28,12 @@ pub(crate) use binary::*;
 pub(crate) use cast::*;
 pub(crate) use column::*;
 pub(crate) use count::*;
+pub use extension::ExtensionExpr;
+pub(crate) use extension::ExtensionUdf;
 pub(crate) use filter::*;
 pub(crate) use literal::*;
 use polars_arrow::utils::CustomIterTools;
-use polars_core::frame::group_by::GroupsProxy;
+use polars_core::frame::group_by::{GroupByMethod, GroupsProxy};
 use polars_core::prelude::*;
 use polars_io::predicates::PhysicalIoExpr;
 #[cfg(feature = "dynamic_group_by")]
@@ -574,6 +577,11 @@ pub trait PhysicalExpr: Send + Sync {
     /// Get the output field of this expr
     fn to_field(&self, input_schema: &Schema) -> PolarsResult<Field>;
 
+    /// The input and the method of a plain aggregation, e.g. `col("a").n_unique()`.
+    fn as_aggregation(&self) -> Option<(&dyn PhysicalExpr, GroupByMethod)> {
+        None
+    }
+
     /// Convert to a partitioned aggregator.
     fn as_partitioned_aggregator(&self) -> Option<&dyn PartitionedAggregation> {
         None
@@ -582,7 +590,6 @@ pub trait PhysicalExpr: Send + Sync {
     /// Can take &dyn Statistics and determine of a file should be
     /// read -> `true`
     /// or not -> `false`
-    #[cfg(feature = "parquet")]
     fn as_stats_evaluator(&self) -> Option<&dyn polars_io::predicates::StatsEvaluator> {
         None
     }
@@ -621,7 +628,6 @@ impl PhysicalIoExpr for PhysicalIoHelper {
         self.expr.evaluate(df, &state)
     }
 
-    #[cfg(feature = "parquet")]
     fn as_stats_evaluator(&self) -> Option<&dyn polars_io::predicates::StatsEvaluator> {
         self.expr.as_stats_evaluator()
     }
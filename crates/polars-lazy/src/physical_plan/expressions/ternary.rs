@@ -283,6 +283,7 @@ impl PhysicalExpr for TernaryExpr {
                             Expr::Agg(_) => has_agg = true,
                             Expr::Function { options, .. }
                             | Expr::AnonymousFunction { options, .. }
+                            | Expr::Extension { options, .. }
                                 if options.is_groups_sensitive() =>
                             {
                                 has_agg = true
@@ -300,7 +300,8 @@ impl WindowExpr {
                             agg_col = true;
                         },
                         Expr::Function { options, .. }
-                        | Expr::AnonymousFunction { options, .. } => {
+                        | Expr::AnonymousFunction { options, .. }
+                        | Expr::Extension { options, .. } => {
                             if options.auto_explode
                                 && matches!(options.collect_groups, ApplyOptions::ApplyGroups)
                             {
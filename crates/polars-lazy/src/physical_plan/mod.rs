@@ -5,6 +5,7 @@ pub mod expressions;
 #[cfg(any(feature = "ipc", feature = "parquet", feature = "csv"))]
 mod file_cache;
 mod node_timer;
+mod observer;
 pub mod planner;
 pub(crate) mod state;
 #[cfg(feature = "streaming")]
@@ -12,5 +13,6 @@ pub(crate) mod streaming;
 
 use polars_core::prelude::*;
 
+pub use crate::physical_plan::observer::ExecutionObserver;
 use crate::physical_plan::state::ExecutionState;
 use crate::prelude::*;
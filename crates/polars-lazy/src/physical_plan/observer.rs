@@ -0,0 +1,53 @@
+#[cfg(feature = "streaming")]
+use std::cell::RefCell;
+use std::path::Path;
+#[cfg(feature = "streaming")]
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Receives the events of a query, e.g. to export metrics.
+///
+/// The names of the nodes are the same as in [`LazyFrame::profile`]. The methods are
+/// called from the threads that execute the nodes, they should return quickly.
+///
+/// [`LazyFrame::profile`]: crate::frame::LazyFrame::profile
+pub trait ExecutionObserver: Send + Sync {
+    /// A node of the physical plan started executing. Its inputs are already executed.
+    fn node_started(&self, _name: &str) {}
+
+    /// A node of the physical plan finished after `duration`. `rows` is the height of its
+    /// output, or `None` if the node failed.
+    fn node_finished(&self, _name: &str, _duration: Duration, _rows: Option<usize>) {}
+
+    /// A scan read the local file at `path`, which is `bytes` large. Scans of cached or
+    /// remote files don't report this.
+    fn bytes_read(&self, _path: &Path, _bytes: u64) {}
+
+    /// A streaming `operation`, e.g. `"sort"` or `"group_by"`, ran out of memory and wrote
+    /// `rows` rows to `bytes` bytes of temporary files. This is called once per operation
+    /// that spilled, when it finishes.
+    fn spilled(&self, _operation: &str, _rows: usize, _bytes: u64) {}
+}
+
+#[cfg(feature = "streaming")]
+thread_local! {
+    // The observer of the query whose streaming pipeline starts on this thread.
+    static PIPELINE_OBSERVER: RefCell<Option<Arc<dyn ExecutionObserver>>> = RefCell::new(None);
+}
+
+/// Make `observer` available to the streaming pipelines that `f` starts on this thread.
+#[cfg(feature = "streaming")]
+pub(crate) fn with_pipeline_observer<T>(
+    observer: Option<Arc<dyn ExecutionObserver>>,
+    f: impl FnOnce() -> T,
+) -> T {
+    let prev = PIPELINE_OBSERVER.with(|cell| cell.replace(observer));
+    let out = f();
+    PIPELINE_OBSERVER.with(|cell| *cell.borrow_mut() = prev);
+    out
+}
+
+#[cfg(feature = "streaming")]
+pub(crate) fn pipeline_observer() -> Option<Arc<dyn ExecutionObserver>> {
+    PIPELINE_OBSERVER.with(|cell| cell.borrow().clone())
+}
@@ -492,6 +492,39 @@ pub(crate) fn create_physical_expr(
                 allow_group_aware: options.allow_group_aware,
             }))
         },
+        Extension {
+            input,
+            function,
+            options,
+        } => {
+            let input = create_physical_expressions(&input, ctxt, expr_arena, schema, state)?;
+            let expr = node_to_expr(expression, expr_arena);
+
+            if function.is_elementwise() {
+                let function =
+                    SpecialEq::new(Arc::new(phys_expr::ExtensionUdf(Arc::clone(&function)))
+                        as Arc<dyn SeriesUdf>);
+                Ok(Arc::new(ApplyExpr {
+                    inputs: input,
+                    function,
+                    expr,
+                    collect_groups: options.collect_groups,
+                    auto_explode: options.auto_explode,
+                    allow_rename: options.allow_rename,
+                    pass_name_to_apply: options.pass_name_to_apply,
+                    input_schema: schema.cloned(),
+                    allow_threading: !state.has_cache,
+                    check_lengths: options.check_lengths(),
+                    allow_group_aware: options.allow_group_aware,
+                }))
+            } else {
+                Ok(Arc::new(phys_expr::ExtensionExpr {
+                    inputs: input,
+                    function,
+                    expr,
+                }))
+            }
+        },
         Function {
             input,
             function,
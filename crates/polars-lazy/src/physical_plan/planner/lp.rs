@@ -213,12 +213,16 @@ pub fn create_physical_plan(
                 #[cfg(feature = "csv")]
                 FileScan::Csv {
                     options: csv_options,
+                    cloud_options,
+                    object,
                 } => Ok(Box::new(executors::CsvExec {
                     path,
                     schema: file_info.schema,
                     options: csv_options,
                     predicate,
                     file_options,
+                    cloud_options,
+                    object,
                 })),
                 #[cfg(feature = "ipc")]
                 FileScan::Ipc { options } => Ok(Box::new(executors::IpcExec {
@@ -298,9 +302,13 @@ pub fn create_physical_plan(
             projection,
             selection: predicate,
             schema,
+            stats,
             ..
         } => {
             let mut state = ExpressionConversionState::default();
+            let predicate_columns = predicate
+                .map(|pred| aexpr_to_leaf_names(pred, expr_arena))
+                .unwrap_or_default();
             let selection = predicate
                 .map(|pred| {
                     create_physical_expr(
@@ -317,6 +325,8 @@ pub fn create_physical_plan(
                 projection,
                 selection,
                 predicate_has_windows: state.has_windows,
+                stats,
+                predicate_columns,
             }))
         },
         Sort {
@@ -14,6 +14,7 @@ use polars_plan::logical_plan::FileFingerPrint;
 #[cfg(any(feature = "ipc", feature = "parquet", feature = "csv"))]
 use super::file_cache::FileCache;
 use crate::physical_plan::node_timer::NodeTimer;
+use crate::physical_plan::observer::ExecutionObserver;
 
 pub type JoinTuplesCache = Arc<Mutex<PlHashMap<String, ChunkJoinOptIds>>>;
 pub type GroupsProxyCache = Arc<RwLock<PlHashMap<String, GroupsProxy>>>;
@@ -77,6 +78,7 @@ pub struct ExecutionState {
     pub(super) flags: AtomicU8,
     pub(super) ext_contexts: Arc<Vec<DataFrame>>,
     node_timer: Option<NodeTimer>,
+    observer: Option<Arc<dyn ExecutionObserver>>,
 }
 
 impl ExecutionState {
@@ -84,26 +86,58 @@ impl ExecutionState {
     pub(crate) fn time_nodes(&mut self) {
         self.node_timer = Some(NodeTimer::new())
     }
-    pub(super) fn has_node_timer(&self) -> bool {
-        self.node_timer.is_some()
+
+    /// Send the events of the nodes to `observer`.
+    pub(crate) fn set_observer(&mut self, observer: Arc<dyn ExecutionObserver>) {
+        self.observer = Some(observer)
+    }
+
+    pub(crate) fn observer(&self) -> Option<&Arc<dyn ExecutionObserver>> {
+        self.observer.as_ref()
+    }
+
+    /// Report the size of the local file at `path` to the observer, if any.
+    #[cfg(any(feature = "parquet", feature = "csv", feature = "ipc"))]
+    pub(super) fn record_bytes_read(&self, path: &std::path::Path) {
+        if let Some(observer) = &self.observer {
+            if let Ok(metadata) = std::fs::metadata(path) {
+                observer.bytes_read(path, metadata.len())
+            }
+        }
+    }
+
+    /// If the executors should name their nodes and call [`ExecutionState::record`].
+    pub(super) fn records_nodes(&self) -> bool {
+        self.node_timer.is_some() || self.observer.is_some()
     }
 
     pub(crate) fn finish_timer(self) -> PolarsResult<DataFrame> {
         self.node_timer.unwrap().finish()
     }
 
-    pub(super) fn record<T, F: FnOnce() -> T>(&self, func: F, name: Cow<'static, str>) -> T {
-        match &self.node_timer {
-            None => func(),
-            Some(timer) => {
-                let start = std::time::Instant::now();
-                let out = func();
-                let end = std::time::Instant::now();
+    pub(super) fn record<F: FnOnce() -> PolarsResult<DataFrame>>(
+        &self,
+        func: F,
+        name: Cow<'static, str>,
+    ) -> PolarsResult<DataFrame> {
+        if !self.records_nodes() {
+            return func();
+        }
+        if let Some(observer) = &self.observer {
+            observer.node_started(name.as_ref());
+        }
+        let start = std::time::Instant::now();
+        let out = func();
+        let end = std::time::Instant::now();
 
-                timer.store(start, end, name.as_ref().to_string());
-                out
-            },
+        if let Some(observer) = &self.observer {
+            let rows = out.as_ref().ok().map(|df| df.height());
+            observer.node_finished(name.as_ref(), end - start, rows);
+        }
+        if let Some(timer) = &self.node_timer {
+            timer.store(start, end, name.as_ref().to_string());
         }
+        out
     }
 
     /// Partially clones and partially clears state
@@ -119,6 +153,7 @@ impl ExecutionState {
             flags: AtomicU8::new(self.flags.load(Ordering::Relaxed)),
             ext_contexts: self.ext_contexts.clone(),
             node_timer: self.node_timer.clone(),
+            observer: self.observer.clone(),
         }
     }
 
@@ -135,6 +170,7 @@ impl ExecutionState {
             flags: AtomicU8::new(self.flags.load(Ordering::Relaxed)),
             ext_contexts: self.ext_contexts.clone(),
             node_timer: self.node_timer.clone(),
+            observer: self.observer.clone(),
         }
     }
 
@@ -155,6 +191,7 @@ impl ExecutionState {
             flags: AtomicU8::new(StateFlags::init().as_u8()),
             ext_contexts: Default::default(),
             node_timer: None,
+            observer: None,
         }
     }
 
@@ -174,6 +211,7 @@ impl ExecutionState {
             flags: AtomicU8::new(StateFlags::init().as_u8()),
             ext_contexts: Default::default(),
             node_timer: None,
+            observer: None,
         }
     }
     pub(crate) fn set_schema(&self, schema: SchemaRef) {
@@ -11,6 +11,7 @@ use polars_pipe::pipeline::{create_pipeline, get_dummy_operator, get_operator, P
 use polars_pipe::SExecutionContext;
 use polars_utils::IdxSize;
 
+use crate::physical_plan::observer::pipeline_observer;
 use crate::physical_plan::planner::{create_physical_expr, ExpressionConversionState};
 use crate::physical_plan::state::ExecutionState;
 use crate::physical_plan::streaming::tree::{PipelineNode, Tree};
@@ -90,6 +91,7 @@ pub(super) fn construct(
     lp_arena: &mut Arena<ALogicalPlan>,
     expr_arena: &mut Arena<AExpr>,
     fmt: bool,
+    memory_limit: Option<usize>,
 ) -> PolarsResult<Option<Node>> {
     use ALogicalPlan::*;
 
@@ -177,6 +179,7 @@ pub(super) fn construct(
             expr_arena,
             to_physical_piped_expr,
             is_verbose,
+            memory_limit,
             &mut sink_cache,
         )?;
         pipelines.push((execution_id, pipeline));
@@ -232,6 +235,12 @@ impl SExecutionContext for ExecutionState {
     fn as_any(&self) -> &dyn Any {
         self
     }
+
+    fn spilled(&self, operation: &str, rows: usize, bytes: u64) {
+        if let Some(observer) = self.observer() {
+            observer.spilled(operation, rows, bytes)
+        }
+    }
 }
 
 fn get_pipeline_node(
@@ -248,6 +257,7 @@ fn get_pipeline_node(
         output_schema: None,
         projection: None,
         selection: None,
+        stats: Default::default(),
     });
 
     ALogicalPlan::MapFunction {
@@ -258,6 +268,9 @@ fn get_pipeline_node(
                     eprintln!("RUN STREAMING PIPELINE")
                 }
                 state.set_in_streaming_engine();
+                if let Some(observer) = pipeline_observer() {
+                    state.set_observer(observer);
+                }
                 let state = Box::new(state) as Box<dyn SExecutionContext>;
                 pipeline.execute(state)
             }),
@@ -70,6 +70,7 @@ pub(crate) fn insert_streaming_nodes(
     // whether the full plan needs to be translated
     // to streaming
     allow_partial: bool,
+    memory_limit: Option<usize>,
 ) -> PolarsResult<bool> {
     scratch.clear();
 
@@ -201,10 +202,11 @@ pub(crate) fn insert_streaming_nodes(
                 }
             },
             Scan {
+                path,
                 file_options: options,
                 scan_type,
                 ..
-            } if scan_type.streamable() => {
+            } if scan_type.streamable(path) => {
                 if state.streamable {
                     #[cfg(feature = "csv")]
                     if matches!(scan_type, FileScan::Csv { .. }) {
@@ -436,7 +438,8 @@ pub(crate) fn insert_streaming_nodes(
     let mut inserted = false;
     for tree in pipeline_trees {
         if is_valid_tree(&tree)
-            && super::construct_pipeline::construct(tree, lp_arena, expr_arena, fmt)?.is_some()
+            && super::construct_pipeline::construct(tree, lp_arena, expr_arena, fmt, memory_limit)?
+                .is_some()
         {
             inserted = true;
         }
@@ -1,7 +1,10 @@
+#[cfg(feature = "rejects")]
+pub use polars_ops::prelude::Rejects;
 pub(crate) use polars_ops::prelude::*;
-pub use polars_ops::prelude::{JoinArgs, JoinType, JoinValidation};
+pub use polars_ops::prelude::{JoinArgs, JoinStrategy, JoinType, JoinValidation};
 pub use polars_plan::logical_plan::{
-    AnonymousScan, AnonymousScanOptions, Literal, LiteralValue, LogicalPlan, Null, NULL,
+    visitor, AExpr, AExprBuilder, ALogicalPlan, AnonymousScan, AnonymousScanOptions, CustomRules,
+    Literal, LiteralValue, LogicalPlan, Null, OptimizationRule, NULL,
 };
 #[cfg(feature = "csv")]
 pub use polars_plan::prelude::CsvWriterOptions;
@@ -11,12 +14,16 @@ pub use polars_plan::prelude::IpcWriterOptions;
 pub use polars_plan::prelude::ParquetWriteOptions;
 pub(crate) use polars_plan::prelude::*;
 #[cfg(feature = "rolling_window")]
-pub use polars_time::{prelude::RollingOptions, Duration};
+pub use polars_time::prelude::{CenterBias, RollingOptions};
+#[cfg(feature = "rolling_window")]
+pub use polars_time::Duration;
 #[cfg(feature = "dynamic_group_by")]
 pub use polars_time::{DynamicGroupOptions, PolarsTemporalGroupby, RollingGroupOptions};
-pub(crate) use polars_utils::arena::{Arena, Node};
+pub(crate) use polars_utils::arena::Arena;
+pub use polars_utils::arena::Node;
 
 pub use crate::dsl::*;
 pub use crate::frame::*;
 pub use crate::physical_plan::expressions::*;
+pub use crate::physical_plan::ExecutionObserver;
 pub(crate) use crate::scan::*;
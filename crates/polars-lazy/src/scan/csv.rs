@@ -1,10 +1,14 @@
 use std::path::{Path, PathBuf};
 
 use polars_core::prelude::*;
-use polars_io::csv::utils::infer_file_schema;
-use polars_io::csv::{CsvEncoding, NullValues};
+use polars_io::cloud::CloudOptions;
+use polars_io::csv::utils::{infer_file_schema, transcode_to_utf8};
+use polars_io::csv::{CsvEncoding, CsvReader, NullValues};
+use polars_io::mmap::{MmapBytesReader, ReaderBytes};
+#[cfg(feature = "cloud")]
+use polars_io::pl_async::get_runtime;
 use polars_io::utils::get_reader_bytes;
-use polars_io::RowCount;
+use polars_io::{is_cloud_url, ProgressCallback, RowCount, SerReader};
 
 use crate::frame::LazyFileListReader;
 use crate::prelude::*;
@@ -35,6 +39,13 @@ pub struct LazyCsvReader<'a> {
     row_count: Option<RowCount>,
     try_parse_dates: bool,
     raise_if_empty: bool,
+    file_path_column: Option<String>,
+    file_row_count: Option<String>,
+    byte_offset: Option<String>,
+    progress: Option<ProgressCallback>,
+    cloud_options: Option<CloudOptions>,
+    projection: Option<Vec<usize>>,
+    new_columns: Option<Vec<String>>,
 }
 
 #[cfg(feature = "csv")]
@@ -64,6 +75,13 @@ impl<'a> LazyCsvReader<'a> {
             try_parse_dates: false,
             raise_if_empty: true,
             truncate_ragged_lines: false,
+            file_path_column: None,
+            file_row_count: None,
+            byte_offset: None,
+            progress: None,
+            cloud_options: None,
+            projection: None,
+            new_columns: None,
         }
     }
 
@@ -81,6 +99,47 @@ impl<'a> LazyCsvReader<'a> {
         self
     }
 
+    /// Add a column with this name that holds the path of the file every row is read from.
+    #[must_use]
+    pub fn with_file_path_column(mut self, name: Option<&str>) -> Self {
+        self.file_path_column = name.map(|name| name.to_string());
+        self
+    }
+
+    /// Add a column with this name that holds the row number of every row within the file it
+    /// is read from.
+    #[must_use]
+    pub fn with_file_row_count(mut self, name: Option<&str>) -> Self {
+        self.file_row_count = name.map(|name| name.to_string());
+        self
+    }
+
+    /// Add a `UInt64` column with this name that holds the byte offset of every row within
+    /// the file it is read from. A scan with byte offsets doesn't run in the streaming engine.
+    #[must_use]
+    pub fn with_byte_offset(mut self, name: Option<&str>) -> Self {
+        self.byte_offset = name.map(|name| name.to_string());
+        self
+    }
+
+    /// Call this function every time a chunk of a file is parsed, e.g. to render a progress
+    /// bar. See [`ProgressCallback`] for what is reported. When a glob matches multiple files,
+    /// every file reports its own progress.
+    #[must_use]
+    pub fn with_progress_callback(mut self, callback: Option<ProgressCallback>) -> Self {
+        self.progress = callback;
+        self
+    }
+
+    /// Set the [`CloudOptions`] used to read CSV files from cloud storage, e.g. `s3://`
+    /// urls. A file is downloaded as a whole when its schema is inferred and the query reads
+    /// that download. Cloud files are not read in the streaming engine.
+    #[must_use]
+    pub fn with_cloud_options(mut self, cloud_options: Option<CloudOptions>) -> Self {
+        self.cloud_options = cloud_options;
+        self
+    }
+
     /// Try to stop parsing when `n` rows are parsed. During multithreaded parsing the upper bound `n` cannot
     /// be guaranteed.
     #[must_use]
@@ -127,13 +186,30 @@ impl<'a> LazyCsvReader<'a> {
         self
     }
 
-    /// Set whether the CSV file has headers
+    /// Set whether the CSV file has headers. Without headers the columns are named
+    /// `column_1`, `column_2`, ... unless they are renamed with [`Self::with_new_columns`].
     #[must_use]
     pub fn has_header(mut self, has_header: bool) -> Self {
         self.has_header = has_header;
         self
     }
 
+    /// Only read the columns at these indices of the file, in this order.
+    #[must_use]
+    pub fn with_projection(mut self, projection: Option<Vec<usize>>) -> Self {
+        self.projection = projection;
+        self
+    }
+
+    /// Rename the columns of the file, in order. If fewer names than columns are given, the
+    /// remaining columns keep their name. The dtypes of
+    /// [`with_dtype_overwrite`](Self::with_dtype_overwrite) refer to the new names.
+    #[must_use]
+    pub fn with_new_columns(mut self, names: Option<Vec<String>>) -> Self {
+        self.new_columns = names;
+        self
+    }
+
     /// Set the CSV file's column delimiter as a byte character
     #[must_use]
     pub fn with_delimiter(mut self, delimiter: u8) -> Self {
@@ -225,16 +301,37 @@ impl<'a> LazyCsvReader<'a> {
     where
         F: Fn(Schema) -> PolarsResult<Schema>,
     {
-        let mut file = if let Some(mut paths) = self.glob()? {
-            let path = match paths.next() {
+        let path = if let Some(mut paths) = self.glob()? {
+            match paths.next() {
                 Some(globresult) => globresult?,
                 None => polars_bail!(ComputeError: "globbing pattern did not match any files"),
-            };
-            polars_utils::open_file(path)
+            }
+        } else {
+            self.path.clone()
+        };
+        let mut file;
+        #[cfg(feature = "cloud")]
+        let fetched;
+        let reader_bytes = if is_cloud_url(&path) {
+            #[cfg(not(feature = "cloud"))]
+            panic!(
+                "One or more of the cloud storage features ('aws', 'gcp', ...) must be enabled."
+            );
+
+            #[cfg(feature = "cloud")]
+            {
+                let uri = path.to_string_lossy();
+                fetched = get_runtime().block_on(polars_io::cloud::fetch_object(
+                    &uri,
+                    self.cloud_options.as_ref(),
+                ))?;
+                ReaderBytes::Borrowed(fetched.as_ref())
+            }
         } else {
-            polars_utils::open_file(&self.path)
-        }?;
-        let reader_bytes = get_reader_bytes(&mut file).expect("could not mmap file");
+            file = polars_utils::open_file(&path)?;
+            get_reader_bytes(&mut file).expect("could not mmap file")
+        };
+        let reader_bytes = transcode_to_utf8(reader_bytes, self.encoding);
         let mut skip_rows = self.skip_rows;
 
         let (schema, _, _) = infer_file_schema(
@@ -266,8 +363,37 @@ impl<'a> LazyCsvReader<'a> {
     }
 }
 
+fn rename_columns(schema: Schema, names: &[String]) -> PolarsResult<Schema> {
+    polars_ensure!(
+        names.len() <= schema.len(),
+        ShapeMismatch: "got {} new column names for a CSV file with {} columns",
+        names.len(), schema.len()
+    );
+    Ok(schema
+        .iter()
+        .enumerate()
+        .map(|(i, (name, dtype))| {
+            let name = names.get(i).map_or(name.as_str(), |name| name.as_str());
+            Field::new(name, dtype.clone())
+        })
+        .collect())
+}
+
 impl LazyFileListReader for LazyCsvReader<'_> {
-    fn finish_no_glob(self) -> PolarsResult<LazyFrame> {
+    fn finish_no_glob(mut self) -> PolarsResult<LazyFrame> {
+        if let Some(names) = self.new_columns.take() {
+            self = match self.schema.take() {
+                Some(schema) => {
+                    let schema = rename_columns(schema.as_ref().clone(), &names)?;
+                    self.with_schema(Some(Arc::new(schema)))
+                },
+                None => self.with_schema_modify(|schema| rename_columns(schema, &names))?,
+            };
+        }
+        let projection = self.projection.take();
+        let has_row_count = self.row_count.is_some();
+        let has_byte_offset = self.byte_offset.is_some();
+
         let mut lf: LazyFrame = LogicalPlanBuilder::scan_csv(
             self.path,
             self.delimiter,
@@ -291,10 +417,36 @@ impl LazyFileListReader for LazyCsvReader<'_> {
             self.try_parse_dates,
             self.raise_if_empty,
             self.truncate_ragged_lines,
+            self.byte_offset,
+            self.progress,
+            self.cloud_options,
         )?
         .build()
         .into();
         lf.opt_state.file_caching = true;
+
+        if let Some(projection) = projection {
+            // the row count and byte offset columns are not columns of the file and are kept
+            let schema = lf.schema()?;
+            let names = schema
+                .iter_names()
+                .map(|name| name.as_str())
+                .collect::<Vec<_>>();
+            let offset = has_row_count as usize;
+            let n_columns = names.len() - offset - has_byte_offset as usize;
+            let mut exprs = Vec::with_capacity(projection.len() + 2);
+            if has_row_count {
+                exprs.push(col(names[0]));
+            }
+            for i in projection {
+                polars_ensure!(i < n_columns, oob = i, n_columns);
+                exprs.push(col(names[i + offset]));
+            }
+            if has_byte_offset {
+                exprs.push(col(names[names.len() - 1]));
+            }
+            lf = lf.select(exprs);
+        }
         Ok(lf)
     }
 
@@ -329,8 +481,202 @@ impl LazyFileListReader for LazyCsvReader<'_> {
         self.row_count.as_ref()
     }
 
+    fn with_row_count(mut self, row_count: Option<RowCount>) -> Self {
+        self.row_count = row_count;
+        self
+    }
+
+    fn file_path_column(&self) -> Option<&str> {
+        self.file_path_column.as_deref()
+    }
+
+    fn file_row_count(&self) -> Option<&str> {
+        self.file_row_count.as_deref()
+    }
+
+    fn cloud_options(&self) -> Option<&CloudOptions> {
+        self.cloud_options.as_ref()
+    }
+
     fn concat_impl(&self, lfs: Vec<LazyFrame>) -> PolarsResult<LazyFrame> {
         // set to false, as the csv parser has full thread utilization
         concat_impl(&lfs, self.rechunk(), false, true, false)
     }
 }
+
+/// Opens a new reader over the same data every time it is called.
+pub type ReaderFactory = Arc<dyn Fn() -> PolarsResult<Box<dyn MmapBytesReader>> + Send + Sync>;
+
+/// Lazily read CSV data that is not behind a file path, e.g. an in-memory buffer, a socket or
+/// an entry of an archive.
+///
+/// The data is opened by a [`ReaderFactory`] when the schema is inferred and every time the
+/// query is executed.
+#[derive(Clone)]
+pub struct LazyCsvFactoryReader {
+    factory: ReaderFactory,
+    delimiter: u8,
+    has_header: bool,
+    comment_char: Option<u8>,
+    quote_char: Option<u8>,
+    encoding: CsvEncoding,
+    rechunk: bool,
+    schema: Option<Schema>,
+    infer_schema_length: Option<usize>,
+    n_rows: Option<usize>,
+    row_count: Option<RowCount>,
+}
+
+impl LazyCsvFactoryReader {
+    pub fn new<F>(factory: F) -> Self
+    where
+        F: Fn() -> PolarsResult<Box<dyn MmapBytesReader>> + Send + Sync + 'static,
+    {
+        LazyCsvFactoryReader {
+            factory: Arc::new(factory),
+            delimiter: b',',
+            has_header: true,
+            comment_char: None,
+            quote_char: Some(b'"'),
+            encoding: CsvEncoding::Utf8,
+            rechunk: true,
+            schema: None,
+            infer_schema_length: Some(100),
+            n_rows: None,
+            row_count: None,
+        }
+    }
+
+    /// Set the CSV file's column delimiter as a byte character
+    #[must_use]
+    pub fn with_delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Set whether the CSV file has headers
+    #[must_use]
+    pub fn has_header(mut self, has_header: bool) -> Self {
+        self.has_header = has_header;
+        self
+    }
+
+    /// Set the comment character. Lines starting with this character will be ignored.
+    #[must_use]
+    pub fn with_comment_char(mut self, comment_char: Option<u8>) -> Self {
+        self.comment_char = comment_char;
+        self
+    }
+
+    /// Set the `char` used as quote char. The default is `b'"'`. If set to `[None]` quoting is disabled.
+    #[must_use]
+    pub fn with_quote_char(mut self, quote: Option<u8>) -> Self {
+        self.quote_char = quote;
+        self
+    }
+
+    /// Set  [`CsvEncoding`]
+    #[must_use]
+    pub fn with_encoding(mut self, enc: CsvEncoding) -> Self {
+        self.encoding = enc;
+        self
+    }
+
+    /// Set the CSV file's schema
+    #[must_use]
+    pub fn with_schema(mut self, schema: Option<Schema>) -> Self {
+        self.schema = schema;
+        self
+    }
+
+    /// Set the number of rows to use when inferring the csv schema.
+    /// the default is 100 rows.
+    /// Setting to `None` will do a full table scan, very slow.
+    #[must_use]
+    pub fn with_infer_schema_length(mut self, num_rows: Option<usize>) -> Self {
+        self.infer_schema_length = num_rows;
+        self
+    }
+
+    /// Try to stop parsing when `n` rows are parsed. During multithreaded parsing the upper bound `n` cannot
+    /// be guaranteed.
+    #[must_use]
+    pub fn with_n_rows(mut self, num_rows: Option<usize>) -> Self {
+        self.n_rows = num_rows;
+        self
+    }
+
+    /// Add a `row_count` column. The column is added to the output of the scan, see
+    /// [`LazyFrame::anonymous_scan`].
+    #[must_use]
+    pub fn with_row_count(mut self, row_count: Option<RowCount>) -> Self {
+        self.row_count = row_count;
+        self
+    }
+
+    /// Rechunk the memory to contiguous chunks when parsing is done.
+    #[must_use]
+    pub fn with_rechunk(mut self, toggle: bool) -> Self {
+        self.rechunk = toggle;
+        self
+    }
+
+    pub fn finish(self) -> PolarsResult<LazyFrame> {
+        let options = ScanArgsAnonymous {
+            name: "CSV SCAN",
+            infer_schema_length: self.infer_schema_length,
+            n_rows: self.n_rows,
+            row_count: self.row_count.clone(),
+            schema: self.schema.clone(),
+            ..ScanArgsAnonymous::default()
+        };
+        LazyFrame::anonymous_scan(Arc::new(self), options)
+    }
+}
+
+impl AnonymousScan for LazyCsvFactoryReader {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn scan(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+        CsvReader::new((self.factory)()?)
+            .has_header(self.has_header)
+            .with_dtypes(Some(scan_opts.schema))
+            .with_delimiter(self.delimiter)
+            .with_comment_char(self.comment_char)
+            .with_quote_char(self.quote_char)
+            .with_encoding(self.encoding)
+            .with_n_rows(scan_opts.n_rows)
+            .with_columns(scan_opts.with_columns.map(|cols| cols.as_ref().clone()))
+            .with_rechunk(self.rechunk)
+            .finish()
+    }
+
+    fn schema(&self, infer_schema_length: Option<usize>) -> PolarsResult<Schema> {
+        let mut reader = (self.factory)()?;
+        let reader_bytes = get_reader_bytes(&mut reader)?;
+        let reader_bytes = transcode_to_utf8(reader_bytes, self.encoding);
+        let mut skip_rows = 0;
+        let (schema, _, _) = infer_file_schema(
+            &reader_bytes,
+            self.delimiter,
+            infer_schema_length,
+            self.has_header,
+            None,
+            &mut skip_rows,
+            0,
+            self.comment_char,
+            self.quote_char,
+            b'\n',
+            None,
+            false,
+            true,
+        )?;
+        Ok(schema)
+    }
+
+    fn allows_projection_pushdown(&self) -> bool {
+        true
+    }
+}
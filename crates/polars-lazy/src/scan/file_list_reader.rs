@@ -9,6 +9,43 @@ use crate::prelude::*;
 
 pub type GlobIterator = Box<dyn Iterator<Item = PolarsResult<PathBuf>>>;
 
+/// Add the provenance columns of `reader` to the [LazyFrame] of the file at `path`.
+///
+/// `row_count` is the row count that was added by the scan of the file.
+fn with_provenance<R: LazyFileListReader>(
+    reader: &R,
+    mut lf: LazyFrame,
+    path: &Path,
+    row_count: Option<&RowCount>,
+) -> LazyFrame {
+    if let Some(name) = reader.file_row_count() {
+        lf = match row_count {
+            // the row count of the scan starts at its offset
+            Some(rc) => lf.with_column((col(&rc.name) - lit(rc.offset)).alias(name)),
+            None => lf.with_row_count(name, None),
+        };
+    }
+    if let Some(name) = reader.file_path_column() {
+        lf = lf.with_column(lit(path.to_string_lossy().as_ref()).alias(name));
+    }
+    lf
+}
+
+/// The provenance columns and the row count are added next to each other, so their names must
+/// differ.
+fn ensure_unique_provenance_names<R: LazyFileListReader>(reader: &R) -> PolarsResult<()> {
+    let names = [
+        reader.row_count().map(|rc| rc.name.as_str()),
+        reader.file_row_count(),
+        reader.file_path_column(),
+    ];
+    let names = names.into_iter().flatten().collect::<Vec<_>>();
+    for (i, name) in names.iter().enumerate() {
+        polars_ensure!(!names[..i].contains(name), duplicate = name);
+    }
+    Ok(())
+}
+
 // cloud_options is used only with async feature
 #[allow(unused_variables)]
 fn polars_glob(pattern: &str, cloud_options: Option<&CloudOptions>) -> PolarsResult<GlobIterator> {
@@ -35,15 +72,18 @@ fn polars_glob(pattern: &str, cloud_options: Option<&CloudOptions>) -> PolarsRes
 pub trait LazyFileListReader: Clone {
     /// Get the final [LazyFrame].
     fn finish(mut self) -> PolarsResult<LazyFrame> {
+        ensure_unique_provenance_names(&self)?;
         if let Some(paths) = self.glob()? {
             let lfs = paths
                 .enumerate()
                 .map(|(i, r)| {
                     let path = r?;
+                    // the row count is added to the concatenated files below
                     let lf = self
                         .clone()
                         .with_path(path.clone())
                         .with_rechunk(false)
+                        .with_row_count(None)
                         .finish_no_glob()
                         .map_err(|e| {
                             polars_err!(
@@ -54,9 +94,9 @@ pub trait LazyFileListReader: Clone {
                     if i == 0 {
                         let lf = lf?;
                         self.set_known_schema(lf.schema()?);
-                        Ok(lf)
+                        Ok(with_provenance(&self, lf, &path, None))
                     } else {
-                        lf
+                        lf.map(|lf| with_provenance(&self, lf, &path, None))
                     }
                 })
                 .collect::<PolarsResult<Vec<_>>>()?;
@@ -76,7 +116,9 @@ pub trait LazyFileListReader: Clone {
 
             Ok(lf)
         } else {
-            self.finish_no_glob()
+            let path = self.path().to_owned();
+            let lf = self.clone().finish_no_glob()?;
+            Ok(with_provenance(&self, lf, &path, self.row_count()))
         }
     }
 
@@ -117,6 +159,21 @@ pub trait LazyFileListReader: Clone {
     /// Add a `row_count` column.
     fn row_count(&self) -> Option<&RowCount>;
 
+    /// Set the `row_count` column.
+    #[must_use]
+    fn with_row_count(self, row_count: Option<RowCount>) -> Self;
+
+    /// Name of the column with the path of the file every row is read from.
+    fn file_path_column(&self) -> Option<&str> {
+        None
+    }
+
+    /// Name of the column with the row number of every row within the file it is read from.
+    /// Unlike [LazyFileListReader::row_count], this count restarts at every file of a glob.
+    fn file_row_count(&self) -> Option<&str> {
+        None
+    }
+
     /// [CloudOptions] used to list files.
     fn cloud_options(&self) -> Option<&CloudOptions> {
         None
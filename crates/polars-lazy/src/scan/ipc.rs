@@ -12,6 +12,11 @@ pub struct ScanArgsIpc {
     pub rechunk: bool,
     pub row_count: Option<RowCount>,
     pub memmap: bool,
+    /// Add a column with this name that holds the path of the file every row is read from.
+    pub file_path_column: Option<String>,
+    /// Add a column with this name that holds the row number of every row within the file
+    /// it is read from.
+    pub file_row_count: Option<String>,
 }
 
 impl Default for ScanArgsIpc {
@@ -22,6 +27,8 @@ impl Default for ScanArgsIpc {
             rechunk: true,
             row_count: None,
             memmap: true,
+            file_path_column: None,
+            file_row_count: None,
         }
     }
 }
@@ -91,6 +98,19 @@ impl LazyFileListReader for LazyIpcReader {
     fn row_count(&self) -> Option<&RowCount> {
         self.args.row_count.as_ref()
     }
+
+    fn with_row_count(mut self, row_count: Option<RowCount>) -> Self {
+        self.args.row_count = row_count;
+        self
+    }
+
+    fn file_path_column(&self) -> Option<&str> {
+        self.args.file_path_column.as_deref()
+    }
+
+    fn file_row_count(&self) -> Option<&str> {
+        self.args.file_row_count.as_deref()
+    }
 }
 
 impl LazyFrame {
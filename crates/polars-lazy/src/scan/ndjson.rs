@@ -16,6 +16,8 @@ pub struct LazyJsonLineReader {
     pub(crate) row_count: Option<RowCount>,
     pub(crate) infer_schema_length: Option<usize>,
     pub(crate) n_rows: Option<usize>,
+    pub(crate) file_path_column: Option<String>,
+    pub(crate) file_row_count: Option<String>,
 }
 
 impl LazyJsonLineReader {
@@ -29,6 +31,8 @@ impl LazyJsonLineReader {
             row_count: None,
             infer_schema_length: Some(100),
             n_rows: None,
+            file_path_column: None,
+            file_row_count: None,
         }
     }
     /// Add a `row_count` column.
@@ -37,6 +41,19 @@ impl LazyJsonLineReader {
         self.row_count = row_count;
         self
     }
+    /// Add a column with this name that holds the path of the file every row is read from.
+    #[must_use]
+    pub fn with_file_path_column(mut self, name: Option<&str>) -> Self {
+        self.file_path_column = name.map(|name| name.to_string());
+        self
+    }
+    /// Add a column with this name that holds the row number of every row within the file it
+    /// is read from.
+    #[must_use]
+    pub fn with_file_row_count(mut self, name: Option<&str>) -> Self {
+        self.file_row_count = name.map(|name| name.to_string());
+        self
+    }
     /// Try to stop parsing when `n` rows are parsed. During multithreaded parsing the upper bound `n` cannot
     /// be guaranteed.
     #[must_use]
@@ -117,4 +134,17 @@ impl LazyFileListReader for LazyJsonLineReader {
     fn row_count(&self) -> Option<&RowCount> {
         self.row_count.as_ref()
     }
+
+    fn with_row_count(mut self, row_count: Option<RowCount>) -> Self {
+        self.row_count = row_count;
+        self
+    }
+
+    fn file_path_column(&self) -> Option<&str> {
+        self.file_path_column.as_deref()
+    }
+
+    fn file_row_count(&self) -> Option<&str> {
+        self.file_row_count.as_deref()
+    }
 }
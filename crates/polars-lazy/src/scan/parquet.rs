@@ -17,7 +17,14 @@ pub struct ScanArgsParquet {
     pub low_memory: bool,
     pub cloud_options: Option<CloudOptions>,
     pub use_statistics: bool,
+    /// Use parquet bloom filters to skip row groups on equality predicates.
+    pub use_bloom_filters: bool,
     pub hive_partitioning: bool,
+    /// Add a column with this name that holds the path of the file every row is read from.
+    pub file_path_column: Option<String>,
+    /// Add a column with this name that holds the row number of every row within the file
+    /// it is read from.
+    pub file_row_count: Option<String>,
 }
 
 impl Default for ScanArgsParquet {
@@ -31,7 +38,10 @@ impl Default for ScanArgsParquet {
             low_memory: false,
             cloud_options: None,
             use_statistics: true,
+            use_bloom_filters: false,
             hive_partitioning: false,
+            file_path_column: None,
+            file_row_count: None,
         }
     }
 }
@@ -68,6 +78,7 @@ impl LazyFileListReader for LazyParquetReader {
             self.args.low_memory,
             self.args.cloud_options,
             self.args.use_statistics,
+            self.args.use_bloom_filters,
             self.args.hive_partitioning,
             known_schema,
         )?
@@ -120,6 +131,19 @@ impl LazyFileListReader for LazyParquetReader {
     fn row_count(&self) -> Option<&RowCount> {
         self.args.row_count.as_ref()
     }
+
+    fn with_row_count(mut self, row_count: Option<RowCount>) -> Self {
+        self.args.row_count = row_count;
+        self
+    }
+
+    fn file_path_column(&self) -> Option<&str> {
+        self.args.file_path_column.as_deref()
+    }
+
+    fn file_row_count(&self) -> Option<&str> {
+        self.args.file_row_count.as_deref()
+    }
 }
 
 impl LazyFrame {
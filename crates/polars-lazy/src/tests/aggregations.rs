@@ -568,3 +568,204 @@ fn test_take_in_groups() -> PolarsResult<()> {
     );
     Ok(())
 }
+
+#[test]
+fn test_agg_without_keys() -> PolarsResult<()> {
+    let df = df![
+        "id" => [1, 11, 2, 12, 21],
+        "value" => [1, 2, 3, 4, 5],
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .group_by_stable([col("id") % lit(10)])
+        .agg_without_keys([col("value").sum()])
+        .collect()?;
+
+    assert_eq!(out.get_column_names(), &["value"]);
+    assert_eq!(Vec::from(out.column("value")?.i32()?), &[Some(8), Some(7)]);
+
+    // an aggregation named like a key isn't dropped with the keys
+    let out = df
+        .lazy()
+        .group_by_stable([col("id") % lit(10)])
+        .agg_without_keys([col("value").sum().alias("id")])
+        .collect();
+    assert!(matches!(out, Err(PolarsError::Duplicate(_))));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "round_series")]
+fn test_clip_quantile_per_group() -> PolarsResult<()> {
+    let df = df![
+        "g" => ["a", "a", "a", "b", "b", "b"],
+        "x" => [1.0, 2.0, 100.0, -50.0, 5.0, 6.0],
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([col("x")
+            .clip_quantile(0.0, 0.5, QuantileInterpolOptions::Nearest)
+            .over([col("g")])])
+        .collect()?;
+
+    assert_eq!(
+        Vec::from(out.column("x")?.f64()?),
+        &[
+            Some(1.0),
+            Some(2.0),
+            Some(2.0),
+            Some(-50.0),
+            Some(5.0),
+            Some(5.0)
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "scale")]
+fn test_scale_per_group() -> PolarsResult<()> {
+    let df = df![
+        "g" => ["a", "a", "a", "b", "b"],
+        "x" => [1, 2, 3, 10, 30],
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([
+            col("x").standardize(1).over([col("g")]).alias("standard"),
+            col("x").min_max_scale().over([col("g")]).alias("min_max"),
+        ])
+        .collect()?;
+
+    let standard = out.column("standard")?.f64()?;
+    let expected = [
+        -1.0,
+        0.0,
+        1.0,
+        -std::f64::consts::FRAC_1_SQRT_2,
+        std::f64::consts::FRAC_1_SQRT_2,
+    ];
+    for (v, e) in standard.into_no_null_iter().zip(expected) {
+        assert!((v - e).abs() < 1e-12);
+    }
+    assert_eq!(
+        Vec::from(out.column("min_max")?.f64()?),
+        &[Some(0.0), Some(0.5), Some(1.0), Some(0.0), Some(1.0)]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-struct")]
+fn test_agg_as_struct() -> PolarsResult<()> {
+    let df = df![
+        "g" => ["a", "a", "b"],
+        "a" => [1, 2, 3],
+        "b" => [1.0, 2.0, 4.0]
+    ]?;
+
+    let lf = df
+        .lazy()
+        .group_by_stable([col("g")])
+        .agg([[col("a").sum(), col("b").mean()].as_struct("stats")]);
+    let dtype = DataType::Struct(vec![
+        Field::new("a", DataType::Int32),
+        Field::new("b", DataType::Float64),
+    ]);
+    assert_eq!(lf.schema()?.get("stats"), Some(&dtype));
+
+    let out = lf.collect()?;
+    let stats = out.column("stats")?;
+    assert_eq!(stats.dtype(), &dtype);
+    let stats = stats.struct_()?;
+    assert_eq!(
+        Vec::from(stats.field_by_name("a")?.i32()?),
+        &[Some(3), Some(3)]
+    );
+    assert_eq!(
+        Vec::from(stats.field_by_name("b")?.f64()?),
+        &[Some(1.5), Some(4.0)]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_expr_extension() -> PolarsResult<()> {
+    struct SumProduct;
+
+    impl ExprExtension for SumProduct {
+        fn name(&self) -> &str {
+            "sum_product"
+        }
+
+        fn evaluate(&self, inputs: &[Series]) -> PolarsResult<Series> {
+            let a = inputs[0].cast(&DataType::Float64)?;
+            let b = inputs[1].cast(&DataType::Float64)?;
+            Ok((&a * &b).sum_as_series())
+        }
+
+        fn to_field(&self, fields: &[Field]) -> PolarsResult<Field> {
+            Ok(Field::new(fields[0].name(), DataType::Float64))
+        }
+    }
+
+    struct Double;
+
+    impl ExprExtension for Double {
+        fn name(&self) -> &str {
+            "double"
+        }
+
+        fn evaluate(&self, inputs: &[Series]) -> PolarsResult<Series> {
+            Ok(&inputs[0] * 2)
+        }
+
+        fn to_field(&self, fields: &[Field]) -> PolarsResult<Field> {
+            Ok(fields[0].clone())
+        }
+
+        fn is_elementwise(&self) -> bool {
+            true
+        }
+    }
+
+    let df = df![
+        "g" => ["a", "a", "b", "b", "b"],
+        "a" => [1, 2, 3, 4, 5],
+        "b" => [1.0, 0.5, 2.0, 1.0, 0.0]
+    ]?;
+    let sum_product = col("a").extension(Arc::new(SumProduct), &[col("b")]);
+    let double = col("a").extension(Arc::new(Double), &[]);
+
+    let out = df.clone().lazy().select([sum_product.clone()]).collect()?;
+    assert_eq!(Vec::from(out.column("a")?.f64()?), &[Some(12.0)]);
+
+    let out = df
+        .clone()
+        .lazy()
+        .group_by_stable([col("g")])
+        .agg([
+            sum_product.clone(),
+            double.clone().alias("double"),
+            double.sum().alias("double_sum"),
+        ])
+        .collect()?;
+    assert_eq!(Vec::from(out.column("a")?.f64()?), &[Some(2.0), Some(10.0)]);
+    assert_eq!(
+        out.column("double")?
+            .explode()?
+            .i32()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        &[2, 4, 6, 8, 10]
+    );
+    assert_eq!(
+        Vec::from(out.column("double_sum")?.i32()?),
+        &[Some(6), Some(24)]
+    );
+    Ok(())
+}
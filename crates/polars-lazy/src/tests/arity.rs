@@ -74,3 +74,49 @@ fn test_lazy_ternary() {
         .unwrap();
     assert_eq!(Some(43), df.column("new").unwrap().sum::<i32>());
 }
+
+#[test]
+fn test_select_nodes() -> PolarsResult<()> {
+    let df = df! {
+        "x" => [1, 2, 3, 4],
+    }?;
+
+    let mut b = AExprBuilder::new();
+    let x = b.col("x");
+    let branches = ["a", "b", "c"]
+        .iter()
+        .enumerate()
+        .map(|(i, out)| {
+            let value = b.lit(i as i32 + 1);
+            (b.binary(x, Operator::Eq, value), b.lit(*out))
+        })
+        .collect::<Vec<_>>();
+    let otherwise = b.lit("z");
+    let node = b.when_then(&branches, otherwise);
+    let y = b.alias(node, "y");
+
+    let out = df.clone().lazy().select_nodes(b, &[x, y]).collect()?;
+    let y = out.column("y")?.utf8()?;
+    assert_eq!(Vec::from(y), &[Some("a"), Some("b"), Some("c"), Some("z")]);
+
+    // the nodes are copied into the arena of the plan with their inputs
+    let mut b = AExprBuilder::new();
+    let x = b.col("x");
+    let two = b.lit(2);
+    let doubled = b.binary(x, Operator::Multiply, two);
+    let doubled = b.alias(doubled, "doubled");
+    let lf = df.lazy().filter(col("x").gt(lit(1)));
+
+    let out = lf.clone().with_columns_nodes(b, &[doubled]).collect()?;
+    assert_eq!(out.get_column_names(), &["x", "doubled"]);
+    assert_eq!(
+        Vec::from(out.column("doubled")?.i32()?),
+        &[Some(4), Some(6), Some(8)]
+    );
+
+    let mut b = AExprBuilder::new();
+    let sliced = b.expr(col("x").slice(1, 2).alias("sliced"));
+    let out = lf.select_nodes(b, &[sliced]).collect()?;
+    assert_eq!(Vec::from(out.column("sliced")?.i32()?), &[Some(3), Some(4)]);
+    Ok(())
+}
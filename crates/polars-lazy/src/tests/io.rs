@@ -203,6 +203,7 @@ fn test_ipc_globbing() -> PolarsResult<()> {
             rechunk: false,
             row_count: None,
             memmap: true,
+            ..Default::default()
         },
     )?
     .collect()?;
@@ -259,6 +260,104 @@ fn test_csv_globbing() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_csv_from_reader_factory() -> PolarsResult<()> {
+    let factory = || {
+        let data = "a,b\n1,x\n2,y\n3,z\n";
+        Ok(Box::new(std::io::Cursor::new(data)) as Box<dyn polars_io::mmap::MmapBytesReader>)
+    };
+    let lf = LazyCsvFactoryReader::new(factory).finish()?;
+    assert_eq!(lf.schema()?.get("a"), Some(&DataType::Int64));
+
+    let out = lf
+        .filter(col("a").gt(lit(1)))
+        .select([col("b")])
+        .collect()?;
+    assert_eq!(out.shape(), (2, 1));
+    assert_eq!(out.column("b")?.utf8()?.get(0), Some("y"));
+
+    let factory = || {
+        let data = "a;b\n# comment\n1;'x;y'\n2;z\n";
+        Ok(Box::new(std::io::Cursor::new(data)) as Box<dyn polars_io::mmap::MmapBytesReader>)
+    };
+    let out = LazyCsvFactoryReader::new(factory)
+        .with_delimiter(b';')
+        .with_comment_char(Some(b'#'))
+        .with_quote_char(Some(b'\''))
+        .with_row_count(Some(RowCount {
+            name: "row_nr".into(),
+            offset: 10,
+        }))
+        .finish()?
+        .collect()?;
+    assert_eq!(out.get_column_names(), &["row_nr", "a", "b"]);
+    assert_eq!(
+        Vec::from(out.column("row_nr")?.idx()?),
+        &[Some(10), Some(11)]
+    );
+    assert_eq!(out.column("b")?.utf8()?.get(0), Some("x;y"));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "cloud")]
+fn test_scan_csv_object_store() -> PolarsResult<()> {
+    // `file://` urls are read through the local object store
+    let path = std::fs::canonicalize(FOODS_CSV)?;
+    let url = format!("file://{}", path.display());
+    let expected = LazyCsvReader::new(FOODS_CSV).finish()?.collect()?;
+
+    let lf = LazyCsvReader::new(&url).finish()?;
+    assert_eq!(lf.schema()?.as_ref(), &expected.schema());
+    let out = lf.with_streaming(true).collect()?;
+    assert!(out.frame_equal(&expected));
+
+    let out = LazyCsvReader::new(&url)
+        .with_n_rows(Some(5))
+        .finish()?
+        .select([col("calories")])
+        .collect()?;
+    assert!(out.frame_equal(&expected.select(["calories"])?.head(Some(5))));
+
+    // the query reads the object that was downloaded to infer the schema
+    let path = std::env::temp_dir().join(format!("polars-cloud-{}.csv", std::process::id()));
+    std::fs::copy(FOODS_CSV, &path)?;
+    let lf = LazyCsvReader::new(format!("file://{}", path.display())).finish()?;
+    std::fs::remove_file(&path)?;
+    assert!(lf.collect()?.frame_equal(&expected));
+    Ok(())
+}
+
+#[test]
+fn test_scan_csv_projection_new_columns() -> PolarsResult<()> {
+    let df = LazyCsvReader::new(FOODS_CSV)
+        .with_projection(Some(vec![3, 1]))
+        .finish()?
+        .collect()?;
+    assert_eq!(df.get_column_names(), &["sugars_g", "calories"]);
+
+    let df = LazyCsvReader::new(FOODS_CSV)
+        .has_header(false)
+        .with_skip_rows(1)
+        .with_new_columns(Some(vec!["a".into(), "b".into()]))
+        .with_row_count(Some(RowCount {
+            name: "rc".into(),
+            offset: 0,
+        }))
+        .with_projection(Some(vec![1, 2]))
+        .finish()?
+        .collect()?;
+    assert_eq!(df.get_column_names(), &["rc", "b", "column_3"]);
+    assert_eq!(df.height(), 27);
+    assert_eq!(df.column("b")?.get(0)?, AnyValue::Int64(45));
+
+    assert!(LazyCsvReader::new(FOODS_CSV)
+        .with_projection(Some(vec![4]))
+        .finish()
+        .is_err());
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "json")]
 fn test_ndjson_globbing() -> PolarsResult<()> {
@@ -457,3 +556,215 @@ fn scan_small_dtypes() -> PolarsResult<()> {
     }
     Ok(())
 }
+
+#[test]
+fn test_scan_provenance() -> PolarsResult<()> {
+    let lf = LazyCsvReader::new(GLOB_CSV)
+        .with_file_path_column(Some("file"))
+        .with_file_row_count(Some("file_row"))
+        .with_byte_offset(Some("offset"))
+        .finish()?;
+
+    let df = lf.clone().collect()?;
+    assert_eq!(df.height(), 135);
+    assert_eq!(df.column("file")?.n_unique()?, 5);
+    assert_eq!(df.column("file_row")?.max::<IdxSize>(), Some(26));
+
+    // the first row of every file is right after the header
+    let first_rows = df.filter(&df.column("file_row")?.equal(0)?)?;
+    assert_eq!(
+        Vec::from(first_rows.column("offset")?.u64()?),
+        &[Some(34); 5]
+    );
+
+    // filtering doesn't change the provenance of the remaining rows
+    let filtered = lf.filter(col("calories").gt(lit(100))).collect()?;
+    let n_filtered = filtered.height();
+    let on = [col("file"), col("file_row")];
+    let joined = filtered
+        .lazy()
+        .join(df.lazy(), on.clone(), on, JoinArgs::new(JoinType::Inner))
+        .collect()?;
+    assert_eq!(joined.height(), n_filtered);
+    assert!(joined
+        .column("offset")?
+        .series_equal(joined.column("offset_right")?));
+    assert!(joined
+        .column("calories")?
+        .series_equal(joined.column("calories_right")?));
+    Ok(())
+}
+
+#[test]
+fn test_scan_provenance_with_row_count() -> PolarsResult<()> {
+    let row_count = Some(RowCount {
+        name: "row".to_string(),
+        offset: 10,
+    });
+    let df = LazyCsvReader::new(GLOB_CSV)
+        .with_row_count(row_count.clone())
+        .with_file_path_column(Some("file"))
+        .with_file_row_count(Some("file_row"))
+        .finish()?
+        .collect()?;
+
+    // the row count runs over all files, the file row count restarts at every file
+    assert_eq!(df.get_column_names()[..2], ["row", "file_row"]);
+    let rows = df.column("row")?.idx()?;
+    assert!(rows.into_no_null_iter().eq(10..10 + df.height() as IdxSize));
+    assert_eq!(df.column("file_row")?.max::<IdxSize>(), Some(26));
+    assert_eq!(
+        df.column("file_row")?.equal(0)?.sum(),
+        Some(df.column("file")?.n_unique()? as IdxSize)
+    );
+
+    // the provenance columns can't have the name of the row count
+    let out = LazyCsvReader::new(GLOB_CSV)
+        .with_row_count(row_count)
+        .with_file_row_count(Some("row"))
+        .finish();
+    assert!(matches!(out, Err(PolarsError::Duplicate(_))));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "checkpoint")]
+fn test_checkpoint_resume() -> PolarsResult<()> {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static RUNS: AtomicUsize = AtomicUsize::new(0);
+
+    // remove the checkpoint files, also if an assertion fails
+    struct Cleanup(std::path::PathBuf);
+    impl Drop for Cleanup {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_file(&self.0);
+            let _ = std::fs::remove_file(self.0.with_extension("ipc.fingerprint"));
+        }
+    }
+
+    let path = std::env::temp_dir().join(format!("polars-checkpoint-{}.ipc", std::process::id()));
+    let _cleanup = Cleanup(path.clone());
+    let query = |df: DataFrame| {
+        df.lazy()
+            .map(
+                |df| {
+                    RUNS.fetch_add(1, Ordering::Relaxed);
+                    Ok(df)
+                },
+                Default::default(),
+                None,
+                None,
+            )
+            .with_column((col("a") * lit(2)).alias("b"))
+            .checkpoint(&path)
+    };
+    let df = df![
+        "a" => [1, 2, 3],
+    ]?;
+
+    let first = query(df.clone())?.collect()?;
+    assert_eq!(RUNS.load(Ordering::Relaxed), 1);
+    // the same plan over the same data resumes from the checkpoint
+    let resumed = query(df.clone())?.filter(col("a").gt(lit(1))).collect()?;
+    assert_eq!(RUNS.load(Ordering::Relaxed), 1);
+    assert!(resumed.frame_equal(&first.slice(1, 2)));
+
+    // changed data invalidates the checkpoint
+    let df = df![
+        "a" => [1, 2, 4],
+    ]?;
+    let out = query(df)?.collect()?;
+    assert_eq!(RUNS.load(Ordering::Relaxed), 2);
+    assert_eq!(
+        Vec::from(out.column("b")?.i32()?),
+        &[Some(2), Some(4), Some(8)]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-struct")]
+fn test_scan_parquet_struct_fields() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join(format!("polars-struct-{}.parquet", std::process::id()));
+    let s = StructChunked::new(
+        "s",
+        &[
+            Series::new("a", [1i32, 2, 3]),
+            Series::new("b", ["x", "y", "z"]),
+            Series::new("c", [1.0f64, 2.0, 3.0]),
+        ],
+    )?;
+    let mut df = DataFrame::new(vec![Series::new("id", [1u32, 2, 3]), s.into_series()])?;
+    ParquetWriter::new(std::fs::File::create(&path)?).finish(&mut df)?;
+
+    let struct_fields = |q: LazyFrame| {
+        let (mut expr_arena, mut lp_arena) = get_arenas();
+        let lp = q.optimize(&mut lp_arena, &mut expr_arena).unwrap();
+        (&lp_arena).iter(lp).find_map(|(_, lp)| match lp {
+            ALogicalPlan::Scan {
+                scan_type: FileScan::Parquet { options, .. },
+                ..
+            } => options.struct_fields.clone(),
+            _ => None,
+        })
+    };
+    let field = |name: &str| col("s").struct_().field_by_name(name);
+
+    let q = LazyFrame::scan_parquet(&path, Default::default())?
+        .filter(field("c").gt(lit(1.0)))
+        .select([col("id"), field("a")]);
+    assert_eq!(
+        struct_fields(q.clone()).as_deref(),
+        Some(&vec![(
+            "s".to_string(),
+            vec!["a".to_string(), "c".to_string()]
+        )])
+    );
+    let out = q.collect()?;
+    assert_eq!(Vec::from(out.column("a")?.i32()?), &[Some(2), Some(3)]);
+
+    // the struct itself is part of the output
+    let q = LazyFrame::scan_parquet(&path, Default::default())?
+        .with_column(field("a").alias("a"))
+        .select([col("s"), col("a")]);
+    assert!(struct_fields(q.clone()).is_none());
+    let out = q.collect()?;
+    assert!(out.column("s")?.series_equal(df.column("s")?));
+
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
+
+#[test]
+fn test_explain_scan_annotations() -> PolarsResult<()> {
+    let plan = scan_foods_parquet(false)
+        .sort("category", Default::default())
+        .select([col("category")])
+        .describe_optimized_plan()?;
+    assert!(plan.contains(r#"PROJECT 1/4 COLUMNS: ["category"]"#));
+    // the number of rows is known from the metadata and preserved by the sort
+    assert_eq!(plan.matches("[ROWS: 27]").count(), 2);
+
+    let plan = scan_foods_parquet(false)
+        .limit(3)
+        .describe_optimized_plan()?;
+    assert!(plan.contains("[ROWS: 3]"));
+
+    // the slice is pushed down into the sort, which then limits its output
+    let plan = scan_foods_parquet(false)
+        .sort("category", Default::default())
+        .limit(3)
+        .describe_optimized_plan()?;
+    let sort = plan.lines().find(|line| line.contains("SORT BY")).unwrap();
+    assert!(sort.contains("[ROWS: 3]"));
+    assert!(plan.contains("[ROWS: 27]"));
+
+    // a pushed down predicate removes an unknown number of rows
+    let plan = scan_foods_parquet(false)
+        .filter(col("calories").gt(lit(100)))
+        .describe_optimized_plan()?;
+    assert!(plan.contains("SELECTION:"));
+    assert!(!plan.contains("ROWS:"));
+    Ok(())
+}
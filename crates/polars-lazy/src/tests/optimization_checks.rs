@@ -185,6 +185,42 @@ pub fn test_simple_slice() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "csv")]
+pub fn test_slice_pushdown_with_columns() -> PolarsResult<()> {
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    let q = scan_foods_csv()
+        .with_columns([(col("calories") * lit(2)).alias("double")])
+        .limit(3);
+    assert!(slice_at_scan(q.clone()));
+    let out = q.collect()?;
+    let expected = scan_foods_csv().collect()?.head(Some(3));
+    assert_eq!(out.column("calories")?, expected.column("calories")?);
+    assert_eq!(
+        out.column("double")?,
+        &(expected.column("calories")? * 2).with_name("double")
+    );
+
+    // a sum depends on the rows outside the slice
+    let q = scan_foods_csv()
+        .with_columns([col("calories").sum().alias("total")])
+        .limit(3);
+    assert!(!slice_at_scan(q));
+
+    // a UDF may depend on the rows outside the slice
+    let q = scan_foods_csv()
+        .with_columns([col("calories")
+            .map(
+                |s| Ok(Some(&s - s.sum::<i64>().unwrap())),
+                GetOutput::same_type(),
+            )
+            .alias("rest")])
+        .limit(3);
+    assert!(!slice_at_scan(q));
+
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "parquet")]
 #[cfg(feature = "cse")]
@@ -414,6 +450,170 @@ fn test_with_row_count_opts() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_predicate_pushdown_group_by_keys() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 2, 2, 3, 3],
+        "b" => [1, 2, 3, 4, 5]
+    ]?;
+
+    // a predicate on the keys filters the input of the group_by
+    let q = df
+        .clone()
+        .lazy()
+        .group_by_stable([col("a")])
+        .agg([col("b").sum()])
+        .filter(col("a").gt(lit(1)));
+    assert!(predicate_at_scan(q.clone()));
+    let out = q.collect()?;
+    assert_eq!(out.column("a")?, &Series::new("a", [2, 3]));
+    assert_eq!(out.column("b")?, &Series::new("b", [5, 9]));
+
+    // a predicate on an aggregation is applied on the result
+    let q = df
+        .clone()
+        .lazy()
+        .group_by_stable([col("a")])
+        .agg([col("b").sum()])
+        .filter(col("b").gt(lit(3)));
+    assert!(!predicate_at_scan(q.clone()));
+    assert_eq!(q.collect()?.column("a")?, &Series::new("a", [2, 3]));
+
+    // keys that are computed are not pushed down
+    let q = df
+        .lazy()
+        .group_by_stable([(col("a") * lit(2)).alias("a")])
+        .agg([col("b").sum()])
+        .filter(col("a").gt(lit(2)));
+    assert!(!predicate_at_scan(q));
+
+    Ok(())
+}
+
+fn aggregate_below_join(q: LazyFrame) -> bool {
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let lp = q.optimize(&mut lp_arena, &mut expr_arena).unwrap();
+
+    (&lp_arena).iter(lp).any(|(_, lp)| {
+        use ALogicalPlan::*;
+        match lp {
+            Join {
+                input_left,
+                input_right,
+                ..
+            } => [input_left, input_right]
+                .iter()
+                .any(|input| matches!(lp_arena.get(**input), Aggregate { .. })),
+            _ => false,
+        }
+    })
+}
+
+#[test]
+fn test_pre_aggregate_below_join() -> PolarsResult<()> {
+    // a boolean key has at most 3 groups, that is half of the rows of the facts
+    let facts = df![
+        "key" => [true, false, true, false, false, true],
+        "v" => [1, 2, 3, 4, 5, 6],
+    ]?;
+    let dims = df![
+        "key" => [true, false, false],
+        "cat" => ["a", "b", "c"],
+    ]?;
+
+    let q = facts
+        .clone()
+        .lazy()
+        .join(
+            dims.clone().lazy(),
+            [col("key")],
+            [col("key")],
+            JoinType::Left.into(),
+        )
+        .group_by([col("key"), col("cat")])
+        .agg([
+            col("v").sum().alias("sum"),
+            col("v").min().alias("min"),
+            col("v").max(),
+            col("v").count().alias("count"),
+        ])
+        .sort_by_exprs([col("key"), col("cat")], [false, false], true, false);
+    assert!(aggregate_below_join(q.clone()));
+
+    let expected = df![
+        "key" => [false, false, true],
+        "cat" => ["b", "c", "a"],
+        "sum" => [11, 11, 10],
+        "min" => [2, 2, 1],
+        "v" => [5, 5, 6],
+        "count" => [3 as IdxSize, 3, 3],
+    ]?;
+    assert!(q.collect()?.frame_equal_missing(&expected));
+
+    // the right input has the most rows, so that one is pre-aggregated
+    let q = dims
+        .clone()
+        .lazy()
+        .inner_join(facts.clone().lazy(), col("key"), col("key"))
+        .group_by([col("key"), col("cat")])
+        .agg([col("v").sum()])
+        .sort_by_exprs([col("key"), col("cat")], [false, false], true, false);
+    assert!(aggregate_below_join(q.clone()));
+    let expected = df![
+        "key" => [false, false, true],
+        "cat" => ["b", "c", "a"],
+        "v" => [11, 11, 10],
+    ]?;
+    assert!(q.collect()?.frame_equal(&expected));
+
+    // a mean can't be computed from partial means
+    let q = facts
+        .clone()
+        .lazy()
+        .inner_join(dims.clone().lazy(), col("key"), col("key"))
+        .group_by([col("key")])
+        .agg([col("v").mean()]);
+    assert!(!aggregate_below_join(q));
+
+    // the group_by is not on the join keys
+    let q = facts
+        .clone()
+        .lazy()
+        .inner_join(dims.lazy(), col("key"), col("key"))
+        .group_by([col("cat")])
+        .agg([col("v").sum()]);
+    assert!(!aggregate_below_join(q));
+
+    // the pre-aggregation would drop the left `v`, so the right `v` wouldn't be suffixed
+    let other = df![
+        "key" => [true, false, false],
+        "v" => [1, 2, 3],
+    ]?;
+    let q = facts
+        .lazy()
+        .inner_join(other.lazy(), col("key"), col("key"))
+        .group_by([col("key"), col("v_right")])
+        .agg([col("v").sum()]);
+    assert!(!aggregate_below_join(q));
+
+    // the type of the key doesn't bound the number of groups
+    let q = df![
+        "key" => [1, 1, 1, 1],
+        "v" => [1, 2, 3, 4],
+    ]?
+    .lazy()
+    .inner_join(
+        df!["key" => [1], "cat" => ["a"]]?.lazy(),
+        col("key"),
+        col("key"),
+    )
+    .group_by([col("key")])
+    .agg([col("v").sum()]);
+    assert!(!aggregate_below_join(q));
+
+    Ok(())
+}
+
 #[test]
 fn test_group_by_ternary_literal_predicate() -> PolarsResult<()> {
     let df = df![
@@ -595,3 +795,48 @@ fn test_flatten_unions() -> PolarsResult<()> {
     }
     Ok(())
 }
+
+#[test]
+fn test_custom_optimization_rule() -> PolarsResult<()> {
+    struct ReplacePlaceholder {}
+
+    impl OptimizationRule for ReplacePlaceholder {
+        fn optimize_expr(
+            &mut self,
+            expr_arena: &mut Arena<AExpr>,
+            expr_node: Node,
+            _lp_arena: &Arena<ALogicalPlan>,
+            _lp_node: Node,
+        ) -> PolarsResult<Option<AExpr>> {
+            match expr_arena.get(expr_node) {
+                AExpr::Literal(LiteralValue::Utf8(s)) if s == "__placeholder__" => Ok(Some(
+                    AExpr::Literal(LiteralValue::Utf8("replaced".to_string())),
+                )),
+                _ => Ok(None),
+            }
+        }
+    }
+
+    let q = df! {
+        "a" => [1, 2],
+    }?
+    .lazy()
+    .select([lit("__placeholder__").alias("b")]);
+
+    let out = q
+        .clone()
+        .with_optimization_rule("replace_placeholder", || Box::new(ReplacePlaceholder {}))
+        .select([col("b")])
+        .collect()?;
+    assert_eq!(out.column("b")?.utf8()?.get(0), Some("replaced"));
+
+    // the rule only applies to the query it was added to
+    let out = q.clone().collect()?;
+    assert_eq!(out.column("b")?.utf8()?.get(0), Some("__placeholder__"));
+    let out = q
+        .with_optimization_rule("replace_placeholder", || Box::new(ReplacePlaceholder {}))
+        .without_optimization_rule("replace_placeholder")
+        .collect()?;
+    assert_eq!(out.column("b")?.utf8()?.get(0), Some("__placeholder__"));
+    Ok(())
+}
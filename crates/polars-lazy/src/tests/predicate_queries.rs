@@ -302,3 +302,35 @@ fn test_push_join_col_predicates_to_both_sides_7247() -> PolarsResult<()> {
     assert_eq!(out, expected);
     Ok(())
 }
+
+#[test]
+fn test_filter_by_scan_stats() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 2, 3],
+        "b" => ["x", "y", "z"],
+        "c" => [None::<i32>, None, None],
+    ]?;
+    // the statistics are memoized on the scan node and shared by its clones
+    let lf = df.clone().lazy().filter(col("a").gt(lit(3)));
+    for _ in 0..2 {
+        let out = lf.clone().collect()?;
+        assert_eq!(out.shape(), (0, 3));
+        assert_eq!(out.schema(), df.schema());
+    }
+
+    let out = df
+        .clone()
+        .lazy()
+        .filter(col("a").gt(lit(2)).or(col("b").eq(lit("x"))))
+        .collect()?;
+    assert_eq!(Vec::from(out.column("a")?.i32()?), &[Some(1), Some(3)]);
+
+    // a column of only nulls has no minimum and maximum
+    let out = df
+        .clone()
+        .lazy()
+        .filter(col("c").lt(lit(1)).or(col("a").eq(lit(1))))
+        .collect()?;
+    assert_eq!(out.height(), 1);
+    Ok(())
+}
@@ -1902,3 +1902,286 @@ fn test_sort_maintain_order_true() -> PolarsResult<()> {
     ]?));
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "rle")]
+fn test_rle_decode_and_group_by_runs() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 1, 2, 2, 2, 1],
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([col("a").rle().rle_decode()])
+        .collect()?;
+    assert!(out.frame_equal(&df));
+
+    let out = df
+        .lazy()
+        .group_by_stable([col("a").rle_id().alias("run")])
+        .agg([col("a").first(), col("a").count().alias("len")])
+        .collect()?;
+    assert!(out.frame_equal(&df![
+        "run" => [0u32, 1, 2],
+        "a" => [1, 2, 1],
+        "len" => [2 as IdxSize, 3, 1],
+    ]?));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "to_dummies")]
+fn test_to_dummies_expr() -> PolarsResult<()> {
+    let df = df![
+        "a" => ["x", "y", "x", "z"],
+    ]?;
+
+    let lf = df
+        .clone()
+        .lazy()
+        .select([col("a").to_dummies(Some(":"), true)]);
+    // The fields are only known once the data is seen.
+    assert_eq!(lf.schema()?.get("a"), Some(&DataType::Unknown));
+    let out = lf.collect()?.unnest(["a"])?;
+
+    let expected = df![
+        "a:y" => [0u8, 1, 0, 0],
+        "a:z" => [0u8, 0, 0, 1],
+    ]?;
+    assert!(out.frame_equal(&expected));
+    assert_eq!(out.get_column_names(), &["a:y", "a:z"]);
+
+    let eager = df.columns_to_dummies(vec!["a"], Some(":"), true)?;
+    assert!(eager.frame_equal(&expected));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "rejects")]
+fn test_cast_collect_rejects() -> PolarsResult<()> {
+    let df = df![
+        "a" => ["1", "2", "x"],
+        "b" => ["2021", "y", "2023"],
+    ]?;
+
+    let rejects = Rejects::new();
+    let out = df
+        .clone()
+        .lazy()
+        .select([
+            col("a").cast_collect_rejects(DataType::Int32, rejects.clone()),
+            col("b").cast_collect_rejects(DataType::Int32, rejects.clone()),
+        ])
+        .collect()?;
+
+    assert_eq!(
+        Vec::from(out.column("a")?.i32()?),
+        &[Some(1), Some(2), None]
+    );
+    assert_eq!(
+        Vec::from(out.column("b")?.i32()?),
+        &[Some(2021), None, Some(2023)]
+    );
+
+    let taken = rejects.take()?.sort(["column"], false, false)?;
+    assert_eq!(
+        Vec::from(taken.column("row_nr")?.idx()?),
+        &[Some(2), Some(1)]
+    );
+    assert_eq!(
+        Vec::from(taken.column("value")?.utf8()?),
+        &[Some("x"), Some("y")]
+    );
+
+    let schema = Arc::new(Schema::from_iter([
+        Field::new("a", DataType::Int32),
+        Field::new("c", DataType::Boolean),
+    ]));
+    let out = df
+        .lazy()
+        .cast_collect_rejects(schema, rejects.clone())
+        .collect()?;
+    assert_eq!(out.get_column_names(), &["a", "c"]);
+    assert_eq!(out.column("c")?.null_count(), 3);
+
+    let rejects = rejects.take()?;
+    assert_eq!(
+        Vec::from(rejects.column("column")?.utf8()?),
+        &[Some("a"), Some("c"), Some("b")]
+    );
+    assert_eq!(
+        Vec::from(rejects.column("row_nr")?.idx()?),
+        &[Some(2), None, None]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "validate")]
+fn test_validate_constraints() -> PolarsResult<()> {
+    let df = df![
+        "id" => [Some(1), Some(2), Some(2), None],
+        "age" => [Some(30), Some(-1), Some(40), Some(200)],
+    ]?;
+
+    let constraints = Constraints::new()
+        .with_schema(Schema::from_iter([Field::new("id", DataType::Int32)]))
+        .not_null("id")
+        .unique(&["id"])
+        .in_range("age", Some(lit(0)), Some(lit(130)));
+    let violations = df
+        .clone()
+        .lazy()
+        .validate(&constraints)?
+        .sort_by_exprs(
+            [col("constraint"), col("row_nr")],
+            [false, false],
+            false,
+            false,
+        )
+        .collect()?;
+    assert_eq!(
+        Vec::from(violations.column("constraint")?.utf8()?),
+        &[
+            Some("in_range(age)"),
+            Some("in_range(age)"),
+            Some("not_null(id)"),
+            Some("unique(id)"),
+            Some("unique(id)"),
+        ]
+    );
+    assert_eq!(
+        Vec::from(violations.column("row_nr")?.idx()?),
+        &[Some(1), Some(3), Some(3), Some(1), Some(2)]
+    );
+
+    // a schema mismatch is reported instead of the value violations
+    let constraints =
+        constraints.with_schema(Schema::from_iter([Field::new("name", DataType::Utf8)]));
+    let violations = constraints.validate(&df)?;
+    assert_eq!(
+        Vec::from(violations.column("constraint")?.utf8()?),
+        &[Some("schema(name: str)")]
+    );
+    assert_eq!(violations.column("row_nr")?.null_count(), 1);
+    Ok(())
+}
+
+#[test]
+fn test_validate_unique_nulls() -> PolarsResult<()> {
+    let df = df![
+        "a" => [Some(1), None, None, Some(1), Some(1)],
+        "b" => [None, Some(2), Some(2), None, Some(3)],
+    ]?;
+
+    // nulls are not duplicates, neither in a single column nor in a combination
+    let violations = Constraints::new()
+        .unique(&["b"])
+        .unique(&["a", "b"])
+        .validate(&df)?;
+    assert_eq!(
+        Vec::from(violations.column("constraint")?.utf8()?),
+        &[Some("unique(b)"), Some("unique(b)")]
+    );
+    assert_eq!(
+        Vec::from(violations.column("row_nr")?.idx()?),
+        &[Some(1), Some(2)]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_collect_with_observer() -> PolarsResult<()> {
+    #[derive(Default)]
+    struct Events(std::sync::Mutex<Vec<(String, Option<usize>)>>);
+
+    impl ExecutionObserver for Events {
+        fn node_started(&self, name: &str) {
+            self.0.lock().unwrap().push((name.to_string(), None));
+        }
+
+        fn node_finished(&self, name: &str, _duration: std::time::Duration, rows: Option<usize>) {
+            self.0.lock().unwrap().push((name.to_string(), rows));
+        }
+    }
+
+    let events = Arc::new(Events::default());
+    let out = load_df()
+        .lazy()
+        .with_column((col("a") * lit(2)).alias("d"))
+        .sort("d", SortOptions::default())
+        .collect_with_observer(events.clone())?;
+    assert_eq!(out.height(), 5);
+
+    let events = events.0.lock().unwrap();
+    // inputs finish before the nodes that consume them start
+    let names = events
+        .iter()
+        .map(|(name, _)| name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        names,
+        &["with_column(d)", "with_column(d)", "sort(d)", "sort(d)"]
+    );
+    assert_eq!(events[1].1, Some(5));
+    assert_eq!(events[3].1, Some(5));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "csv")]
+fn test_collect_with_observer_bytes_read() -> PolarsResult<()> {
+    #[derive(Default)]
+    struct BytesRead(std::sync::Mutex<Vec<(std::path::PathBuf, u64)>>);
+
+    impl ExecutionObserver for BytesRead {
+        fn bytes_read(&self, path: &std::path::Path, bytes: u64) {
+            self.0.lock().unwrap().push((path.to_path_buf(), bytes));
+        }
+    }
+
+    let observer = Arc::new(BytesRead::default());
+    scan_foods_csv()
+        .filter(col("calories").gt(lit(100)))
+        .collect_with_observer(observer.clone())?;
+
+    let bytes_read = observer.0.lock().unwrap();
+    let file_size = std::fs::metadata(FOODS_CSV)?.len();
+    assert_eq!(
+        bytes_read.as_slice(),
+        &[(std::path::PathBuf::from(FOODS_CSV), file_size)]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_group_by_n_unique_many() -> PolarsResult<()> {
+    let df = df![
+        "g" => [1, 2, 1, 2, 3, 1],
+        "a" => [Some(1), None, Some(1), Some(2), None, None],
+        "b" => ["x", "y", "z", "y", "x", "x"]
+    ]?;
+
+    let out = df
+        .lazy()
+        .group_by_stable([col("g")])
+        .agg([
+            col("a").n_unique(),
+            col("a").sum().alias("a_sum"),
+            col("b").n_unique().alias("b_unique"),
+        ])
+        .collect()?;
+
+    assert_eq!(out.get_column_names(), &["g", "a", "a_sum", "b_unique"]);
+    assert_eq!(
+        Vec::from(out.column("a")?.idx()?),
+        &[Some(2), Some(2), Some(1)]
+    );
+    assert_eq!(out.column("a_sum")?.i32()?.get(0), Some(2));
+    assert_eq!(
+        Vec::from(out.column("b_unique")?.idx()?),
+        &[Some(2), Some(1), Some(1)]
+    );
+    Ok(())
+}
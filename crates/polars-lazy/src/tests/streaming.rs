@@ -45,6 +45,38 @@ fn test_streaming_parquet() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_streaming_observer_spilled() -> PolarsResult<()> {
+    #[derive(Default)]
+    struct Spills(std::sync::Mutex<Vec<(String, usize, u64)>>);
+
+    impl ExecutionObserver for Spills {
+        fn spilled(&self, operation: &str, rows: usize, bytes: u64) {
+            self.0
+                .lock()
+                .unwrap()
+                .push((operation.to_string(), rows, bytes));
+        }
+    }
+
+    let observer = Arc::new(Spills::default());
+    // a limit of a single byte makes the sort go out-of-core on the first chunk
+    let out = get_csv_file()
+        .sort("sugars_g", Default::default())
+        .with_streaming(true)
+        .with_streaming_memory_limit(Some(1))
+        .collect_with_observer(observer.clone())?;
+
+    let spills = observer.0.lock().unwrap();
+    assert_eq!(spills.len(), 1);
+    let (operation, rows, bytes) = &spills[0];
+    assert_eq!(operation, "sort");
+    // the sorted partitions are written again
+    assert!(*rows >= out.height());
+    assert!(*bytes > 0);
+    Ok(())
+}
+
 #[test]
 fn test_streaming_csv() -> PolarsResult<()> {
     let q = get_csv_file();
@@ -388,3 +420,48 @@ fn test_sort_maintain_order_streaming() -> PolarsResult<()> {
     ]?));
     Ok(())
 }
+
+#[test]
+#[cfg(all(feature = "approx_quantile", feature = "reservoir_sample"))]
+fn test_streaming_sketch_aggregations() -> PolarsResult<()> {
+    let q = df![
+        "g" => [1, 2, 1, 2, 1, 2, 1],
+        "x" => [1.0, 10.0, 3.0, 30.0, 2.0, 20.0, 4.0],
+    ]?
+    .lazy()
+    .group_by([col("g")])
+    .agg([
+        col("x").approx_median().alias("median"),
+        col("x").reservoir_sample(2, Some(0)).alias("sample"),
+    ])
+    .sort("g", Default::default());
+
+    let q_streaming = q.clone().with_streaming(true);
+    assert!(optimization_checks::is_pipeline(q_streaming.clone()));
+
+    for out in [q_streaming.collect()?, q.collect()?] {
+        assert_eq!(
+            Vec::from(out.column("median")?.f64()?),
+            &[Some(2.5), Some(20.0)]
+        );
+        let sample = out.column("sample")?.list()?;
+        assert!(sample.lst_lengths().into_no_null_iter().all(|len| len == 2));
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "csv")]
+fn test_streaming_sink_csv() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join(format!("polars-sink-{}.csv", std::process::id()));
+    let q = get_csv_file()
+        .filter(col("calories").gt(lit(50)))
+        .select([col("category"), col("calories")]);
+
+    q.clone().sink_csv(path.clone(), Default::default())?;
+    let out = LazyCsvReader::new(&path).finish()?.collect()?;
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(out, q.collect()?);
+    Ok(())
+}
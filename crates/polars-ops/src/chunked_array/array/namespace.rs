@@ -18,6 +18,31 @@ fn get_agg(ca: &ArrayChunked, agg_type: AggType) -> Series {
     min_max::array_dispatch(ca.name(), &values, width, agg_type)
 }
 
+/// Apply `f` to every subarray after the values are cast to `Float64`.
+fn float_agg<F>(ca: &ArrayChunked, f: F) -> PolarsResult<Series>
+where
+    F: Fn(&Float64Chunked) -> Option<f64>,
+{
+    let ca = ca.cast(&DataType::Array(Box::new(DataType::Float64), ca.width()))?;
+    let ca = ca.array()?;
+    let out: Float64Chunked = ca
+        .amortized_iter()
+        .map(|s| s.and_then(|s| f(s.as_ref().f64().unwrap())))
+        .collect();
+    Ok(out.with_name(ca.name()).into_series())
+}
+
+fn bool_agg<F>(ca: &ArrayChunked, f: F) -> Series
+where
+    F: Fn(&BooleanChunked) -> bool,
+{
+    let out: BooleanChunked = ca
+        .amortized_iter()
+        .map(|s| s.map(|s| f(s.as_ref().bool().unwrap())))
+        .collect();
+    out.with_name(ca.name()).into_series()
+}
+
 pub trait ArrayNameSpace: AsArray {
     fn array_max(&self) -> Series {
         let ca = self.as_array();
@@ -42,6 +67,62 @@ pub trait ArrayNameSpace: AsArray {
         }
     }
 
+    fn array_mean(&self) -> PolarsResult<Series> {
+        let ca = self.as_array();
+        let inner_dtype = ca.inner_dtype();
+        polars_ensure!(inner_dtype.is_numeric(), opq = mean, ca.dtype());
+
+        if has_inner_nulls(ca) {
+            return float_agg(ca, |ca| ca.mean());
+        }
+        // every subarray has `width` values
+        let sum = self.array_sum()?.cast(&DataType::Float64)?;
+        Ok(&sum / ca.width() as f64)
+    }
+
+    fn array_std(&self, ddof: u8) -> PolarsResult<Series> {
+        let ca = self.as_array();
+        polars_ensure!(ca.inner_dtype().is_numeric(), opq = std, ca.dtype());
+        float_agg(ca, |ca| ca.std(ddof))
+    }
+
+    fn array_any(&self) -> PolarsResult<Series> {
+        let ca = self.as_array();
+        polars_ensure!(ca.inner_dtype() == DataType::Boolean, opq = any, ca.dtype());
+        Ok(bool_agg(ca, |ca| ca.any()))
+    }
+
+    fn array_all(&self) -> PolarsResult<Series> {
+        let ca = self.as_array();
+        polars_ensure!(ca.inner_dtype() == DataType::Boolean, opq = all, ca.dtype());
+        Ok(bool_agg(ca, |ca| ca.all()))
+    }
+
+    fn array_arg_min(&self) -> IdxCa {
+        let ca = self.as_array();
+        let out: IdxCa = ca
+            .amortized_iter()
+            .map(|s| s.and_then(|s| s.as_ref().arg_min().map(|idx| idx as IdxSize)))
+            .collect();
+        out.with_name(ca.name())
+    }
+
+    fn array_arg_max(&self) -> IdxCa {
+        let ca = self.as_array();
+        let out: IdxCa = ca
+            .amortized_iter()
+            .map(|s| s.and_then(|s| s.as_ref().arg_max().map(|idx| idx as IdxSize)))
+            .collect();
+        out.with_name(ca.name())
+    }
+
+    /// Convert to a [`ListChunked`] with the same values.
+    fn array_to_list(&self) -> PolarsResult<ListChunked> {
+        let ca = self.as_array();
+        let out = ca.cast(&DataType::List(Box::new(ca.inner_dtype())))?;
+        Ok(out.list()?.clone())
+    }
+
     fn array_unique(&self) -> PolarsResult<ListChunked> {
         let ca = self.as_array();
         ca.try_apply_amortized(|s| s.as_ref().unique())
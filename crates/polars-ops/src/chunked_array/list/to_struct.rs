@@ -1,6 +1,6 @@
 use polars_core::export::rayon::prelude::*;
+use polars_core::prelude::PlSmallStr;
 use polars_utils::format_smartstring;
-use smartstring::alias::String as SmartString;
 
 use super::*;
 
@@ -47,9 +47,9 @@ fn det_n_fields(ca: &ListChunked, n_fields: ListToStructWidthStrategy) -> usize
     }
 }
 
-pub type NameGenerator = Arc<dyn Fn(usize) -> SmartString + Send + Sync>;
+pub type NameGenerator = Arc<dyn Fn(usize) -> PlSmallStr + Send + Sync>;
 
-pub fn _default_struct_name_gen(idx: usize) -> SmartString {
+pub fn _default_struct_name_gen(idx: usize) -> PlSmallStr {
     format_smartstring!("field_{idx}")
 }
 
@@ -3,20 +3,29 @@ use polars_arrow::array::default_arrays::FromDataUtf8;
 use polars_core::prelude::*;
 
 // Vertically concatenate all strings in a Utf8Chunked.
-pub fn str_concat(ca: &Utf8Chunked, delimiter: &str) -> Utf8Chunked {
-    if ca.len() <= 1 {
+// Null values are skipped if `ignore_nulls` is set, otherwise they are written as "null".
+pub fn str_concat(ca: &Utf8Chunked, delimiter: &str, ignore_nulls: bool) -> Utf8Chunked {
+    let skip_nulls = ignore_nulls && ca.null_count() > 0;
+    if ca.len() <= 1 && !skip_nulls {
         return ca.clone();
     }
 
     // Calculate capacity.
     let null_str_len = 4;
-    let capacity =
-        ca.get_values_size() + ca.null_count() * null_str_len + delimiter.len() * (ca.len() - 1);
+    let (n_values, nulls_len) = if ignore_nulls {
+        (ca.len() - ca.null_count(), 0)
+    } else {
+        (ca.len(), ca.null_count() * null_str_len)
+    };
+    let capacity = ca.get_values_size() + nulls_len + delimiter.len() * n_values.saturating_sub(1);
 
     let mut buf = String::with_capacity(capacity);
     let mut first = true;
     for arr in ca.downcast_iter() {
         for val in arr.into_iter() {
+            if val.is_none() && ignore_nulls {
+                continue;
+            }
             if !first {
                 buf.push_str(delimiter);
             }
@@ -131,10 +140,15 @@ mod test {
     fn test_str_concat() {
         let ca = Int32Chunked::new("foo", &[Some(1), None, Some(3)]);
         let ca_str = ca.cast(&DataType::Utf8).unwrap();
-        let out = str_concat(&ca_str.utf8().unwrap(), "-");
+        let out = str_concat(&ca_str.utf8().unwrap(), "-", false);
 
         let out = out.get(0);
         assert_eq!(out, Some("1-null-3"));
+
+        let out = str_concat(&ca_str.utf8().unwrap(), "-", true);
+        assert_eq!(out.get(0), Some("1-3"));
+        let out = str_concat(&ca_str.utf8().unwrap().slice(1, 1), "-", true);
+        assert_eq!(out.get(0), Some(""));
     }
 
     #[test]
@@ -412,6 +412,28 @@ pub trait Utf8NameSpaceImpl: AsUtf8 {
         split_helper(ca, by, str::split_inclusive)
     }
 
+    /// Split every string on the matches of the regex `by`.
+    fn split_regex(&self, by: &Utf8Chunked) -> PolarsResult<ListChunked> {
+        let ca = self.as_utf8();
+
+        split_regex_helper(ca, by, false)
+    }
+
+    /// Split every string on the matches of the regex `by` and keep the matches.
+    fn split_regex_inclusive(&self, by: &Utf8Chunked) -> PolarsResult<ListChunked> {
+        let ca = self.as_utf8();
+
+        split_regex_helper(ca, by, true)
+    }
+
+    #[cfg(feature = "dtype-struct")]
+    /// Split every string on the matches of the regex `by`, returning at most `n` items.
+    fn splitn_regex(&self, by: &Utf8Chunked, n: usize) -> PolarsResult<StructChunked> {
+        let ca = self.as_utf8();
+
+        splitn_regex_to_struct(ca, by, n)
+    }
+
     /// Extract each successive non-overlapping regex match in an individual string as an array.
     fn extract_all_many(&self, pat: &Utf8Chunked) -> PolarsResult<ListChunked> {
         let ca = self.as_utf8();
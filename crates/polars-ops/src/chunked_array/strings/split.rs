@@ -1,6 +1,8 @@
 #[cfg(feature = "dtype-struct")]
 use polars_arrow::export::arrow::array::{MutableArray, MutableUtf8Array};
 use polars_core::chunked_array::ops::arity::binary_elementwise_for_each;
+use polars_core::export::regex::Regex;
+use polars_utils::cache::FastFixedCache;
 
 use super::*;
 
@@ -65,6 +67,11 @@ where
         })
     }
 
+    fields_to_struct(ca.name(), arrs)
+}
+
+#[cfg(feature = "dtype-struct")]
+fn fields_to_struct(name: &str, arrs: Vec<MutableUtf8Array<i64>>) -> PolarsResult<StructChunked> {
     let fields = arrs
         .into_iter()
         .enumerate()
@@ -73,7 +80,7 @@ where
         })
         .collect::<Vec<_>>();
 
-    StructChunked::new(ca.name(), &fields)
+    StructChunked::new(name, &fields)
 }
 
 pub fn split_helper<'a, F, I>(ca: &'a Utf8Chunked, by: &'a Utf8Chunked, op: F) -> ListChunked
@@ -111,3 +118,97 @@ where
         builder.finish()
     }
 }
+
+/// Call `op` with the regex and the string of every row, or with `None` if either is null.
+/// `by` is either a single pattern or holds a pattern for every row.
+fn for_each_regex<'a, F>(ca: &'a Utf8Chunked, by: &'a Utf8Chunked, mut op: F) -> PolarsResult<()>
+where
+    F: FnMut(Option<(&Regex, &'a str)>),
+{
+    if by.len() == 1 {
+        match by.get(0) {
+            Some(pat) => {
+                let reg = Regex::new(pat)?;
+                ca.for_each(|opt_s| op(opt_s.map(|s| (&reg, s))));
+            },
+            None => ca.for_each(|_| op(None)),
+        }
+    } else {
+        polars_ensure!(
+            ca.len() == by.len(),
+            ComputeError: "pattern's length: {} does not match that of the argument series: {}",
+            by.len(), ca.len(),
+        );
+        // A sqrt(n) regex cache is not too small, not too large.
+        let mut reg_cache = FastFixedCache::new((ca.len() as f64).sqrt() as usize);
+        for (opt_s, opt_pat) in ca.into_iter().zip(by) {
+            match (opt_s, opt_pat) {
+                (Some(s), Some(pat)) => {
+                    let reg = reg_cache.try_get_or_insert_with(pat, |p| Regex::new(p))?;
+                    op(Some((&*reg, s)))
+                },
+                _ => op(None),
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Like [`str::split_inclusive`], but splits on the matches of `reg`.
+fn regex_split_inclusive<'a>(reg: &'a Regex, s: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+    let mut matches = reg.find_iter(s);
+    let mut last = 0;
+    std::iter::from_fn(move || match matches.next() {
+        Some(m) => {
+            let out = &s[last..m.end()];
+            last = m.end();
+            Some(out)
+        },
+        None if last < s.len() => {
+            let out = &s[last..];
+            last = s.len();
+            Some(out)
+        },
+        None => None,
+    })
+}
+
+pub fn split_regex_helper(
+    ca: &Utf8Chunked,
+    by: &Utf8Chunked,
+    inclusive: bool,
+) -> PolarsResult<ListChunked> {
+    let mut builder = ListUtf8ChunkedBuilder::new(ca.name(), ca.len(), ca.get_values_size());
+    for_each_regex(ca, by, |opt| match opt {
+        Some((reg, s)) if inclusive => builder.append_values_iter(regex_split_inclusive(reg, s)),
+        Some((reg, s)) => builder.append_values_iter(reg.split(s)),
+        None => builder.append_null(),
+    })?;
+    Ok(builder.finish())
+}
+
+#[cfg(feature = "dtype-struct")]
+pub fn splitn_regex_to_struct(
+    ca: &Utf8Chunked,
+    by: &Utf8Chunked,
+    n: usize,
+) -> PolarsResult<StructChunked> {
+    let mut arrs = (0..n)
+        .map(|_| MutableUtf8Array::<i64>::with_capacity(ca.len()))
+        .collect::<Vec<_>>();
+
+    for_each_regex(ca, by, |opt| {
+        let mut arr_iter = arrs.iter_mut();
+        if let Some((reg, s)) = opt {
+            reg.splitn(s, n)
+                .zip(&mut arr_iter)
+                .for_each(|(splitted, arr)| arr.push(Some(splitted)));
+        }
+        // fill the remaining with null
+        for arr in arr_iter {
+            arr.push_null()
+        }
+    })?;
+
+    fields_to_struct(ca.name(), arrs)
+}
@@ -0,0 +1,168 @@
+use polars_core::prelude::*;
+
+use super::*;
+
+/// Suffix of the new values of the compared columns in [`FrameDiff::changed`].
+const NEW_SUFFIX: &str = "_new";
+
+/// The differences between an old and a new version of a frame, matched by key.
+///
+/// See [`DataFrameOps::diff_frames`].
+#[derive(Clone, Debug)]
+pub struct FrameDiff {
+    /// Rows of the new frame with a key that is not in the old frame.
+    pub added: DataFrame,
+    /// Rows of the old frame with a key that is not in the new frame.
+    pub removed: DataFrame,
+    /// Rows with a key in both frames in which at least one compared column changed.
+    /// A compared column `c` holds the old value and `c_new` holds the new value, so the frames
+    /// can't have a column named `c_new` as well.
+    pub changed: DataFrame,
+    /// The number of `changes` per compared `column`.
+    pub change_counts: DataFrame,
+}
+
+impl FrameDiff {
+    /// Whether the frames have the same rows.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+fn ensure_unique_keys(df: &DataFrame, keys: &[&str], frame: &str) -> PolarsResult<()> {
+    let n_keys = df.group_by(keys)?.get_groups().len();
+    polars_ensure!(
+        n_keys == df.height(),
+        ComputeError: "the keys of the {} frame are not unique", frame
+    );
+    Ok(())
+}
+
+pub(super) fn diff_frames(
+    old: &DataFrame,
+    new: &DataFrame,
+    keys: &[&str],
+) -> PolarsResult<FrameDiff> {
+    polars_ensure!(!keys.is_empty(), ComputeError: "cannot diff frames without key columns");
+    ensure_unique_keys(old, keys, "old")?;
+    ensure_unique_keys(new, keys, "new")?;
+
+    // compare the columns that are in both frames
+    let mut compared = Vec::with_capacity(old.width());
+    for s in old.get_columns() {
+        let name = s.name();
+        if keys.contains(&name) {
+            continue;
+        }
+        if let Ok(other) = new.column(name) {
+            polars_ensure!(
+                s.dtype() == other.dtype(),
+                SchemaMismatch: "column `{}` has dtype {} in the old frame and {} in the new frame",
+                name, s.dtype(), other.dtype()
+            );
+            compared.push(name);
+        }
+    }
+    // the new values of `c` are named `c_new`, which must not be the name of another column
+    let selection = || keys.iter().chain(compared.iter());
+    for name in &compared {
+        let new_name = format!("{name}{NEW_SUFFIX}");
+        polars_ensure!(
+            !selection().any(|c| *c == new_name),
+            Duplicate: "cannot diff column `{}`: the name of its new values `{}` is already in use",
+            name, new_name
+        );
+    }
+
+    let removed = old.join(
+        &new.select(keys)?,
+        keys,
+        keys,
+        JoinArgs::new(JoinType::Anti),
+    )?;
+    let added = new.join(
+        &old.select(keys)?,
+        keys,
+        keys,
+        JoinArgs::new(JoinType::Anti),
+    )?;
+
+    let mut args = JoinArgs::new(JoinType::Inner);
+    args.suffix = Some(NEW_SUFFIX.to_string());
+    let matched = old
+        .select(selection())?
+        .join(&new.select(selection())?, keys, keys, args)?;
+
+    let mut any_changed = BooleanChunked::full("", false, matched.height());
+    let mut changes = Vec::with_capacity(compared.len());
+    for name in &compared {
+        let new_name = format!("{name}{NEW_SUFFIX}");
+        let changed = matched
+            .column(name)?
+            .not_equal_missing(matched.column(&new_name)?)?;
+        changes.push(changed.sum().unwrap_or(0));
+        any_changed = &any_changed | &changed;
+    }
+    let changed = matched.filter(&any_changed)?;
+    let change_counts = DataFrame::new(vec![
+        Series::new("column", compared),
+        Series::new("changes", changes),
+    ])?;
+
+    Ok(FrameDiff {
+        added,
+        removed,
+        changed,
+        change_counts,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_diff_frames() -> PolarsResult<()> {
+        let old = df![
+            "id" => [1, 2, 3, 4],
+            "a" => [Some(1), Some(2), None, Some(4)],
+            "b" => ["x", "y", "z", "w"],
+        ]?;
+        let new = df![
+            "id" => [2, 3, 4, 5],
+            "a" => [Some(2), Some(3), Some(4), Some(5)],
+            "b" => ["y", "z", "v", "u"],
+            "c" => [true, true, true, true],
+        ]?;
+
+        let diff = old.diff_frames(&new, &["id"])?;
+        assert!(!diff.is_empty());
+        assert_eq!(Vec::from(diff.removed.column("id")?.i32()?), &[Some(1)]);
+        assert_eq!(Vec::from(diff.added.column("id")?.i32()?), &[Some(5)]);
+        assert_eq!(diff.added.width(), 4);
+
+        let changed = diff.changed.sort(["id"], false, false)?;
+        assert_eq!(Vec::from(changed.column("id")?.i32()?), &[Some(3), Some(4)]);
+        assert_eq!(Vec::from(changed.column("a")?.i32()?), &[None, Some(4)]);
+        assert_eq!(
+            Vec::from(changed.column("a_new")?.i32()?),
+            &[Some(3), Some(4)]
+        );
+        assert_eq!(
+            Vec::from(diff.change_counts.column("changes")?.idx()?),
+            &[Some(1), Some(1)]
+        );
+
+        assert!(old.diff_frames(&old, &["id"])?.is_empty());
+        assert!(old.vstack(&old)?.diff_frames(&new, &["id"]).is_err());
+
+        // the new values of "a" can't be named "a_new"
+        let old = old.hstack(&[Series::new("a_new", [1, 2, 3, 4])])?;
+        let new = new.hstack(&[Series::new("a_new", [1, 2, 3, 4])])?;
+        assert!(matches!(
+            old.diff_frames(&new, &["id"]),
+            Err(PolarsError::Duplicate(_))
+        ));
+        Ok(())
+    }
+}
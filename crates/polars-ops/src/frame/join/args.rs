@@ -28,6 +28,7 @@ pub struct JoinArgs {
     pub validation: JoinValidation,
     pub suffix: Option<String>,
     pub slice: Option<(i64, usize)>,
+    pub strategy: JoinStrategy,
 }
 
 impl JoinArgs {
@@ -37,9 +38,15 @@ impl JoinArgs {
             validation: Default::default(),
             suffix: None,
             slice: None,
+            strategy: Default::default(),
         }
     }
 
+    pub fn with_strategy(mut self, strategy: JoinStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
     pub fn suffix(&self) -> &str {
         self.suffix.as_deref().unwrap_or("_right")
     }
@@ -91,6 +98,39 @@ impl Debug for JoinType {
     }
 }
 
+/// Hint on the algorithm used to find the join tuples.
+///
+/// The hint is used by single key inner and left joins. If the requested algorithm
+/// cannot be used on the join keys, the join falls back to a hash join.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum JoinStrategy {
+    /// Pick the algorithm based on the sortedness and the sizes of the keys.
+    #[default]
+    Auto,
+    /// Hash join on partitioned hash tables.
+    Hash,
+    /// Sorted merge join. Keys that are not sorted are sorted first, which pays off
+    /// if the other key is already sorted.
+    /// Requires numeric keys without nulls.
+    SortMerge,
+    /// Build a single hash table of the right relation that is shared by all threads.
+    /// Fastest if the right relation is small.
+    Broadcast,
+}
+
+impl Display for JoinStrategy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            JoinStrategy::Auto => "auto",
+            JoinStrategy::Hash => "hash",
+            JoinStrategy::SortMerge => "sort-merge",
+            JoinStrategy::Broadcast => "broadcast",
+        };
+        write!(f, "{s}")
+    }
+}
+
 #[derive(Copy, Clone, PartialEq, Eq, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum JoinValidation {
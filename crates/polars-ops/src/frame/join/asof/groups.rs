@@ -7,10 +7,10 @@ use arrow::types::NativeType;
 use num_traits::{Bounded, Zero};
 use polars_core::hashing::partition::AsU64;
 use polars_core::hashing::{_df_rows_to_hashes_threaded_vertical, _HASHMAP_INIT_SIZE};
+use polars_core::prelude::PlSmallStr;
 use polars_core::utils::{split_ca, split_df};
 use polars_core::POOL;
 use rayon::prelude::*;
-use smartstring::alias::String as SmartString;
 
 use super::*;
 use crate::frame::IntoDf;
@@ -763,8 +763,8 @@ pub trait AsofJoinBy: IntoDf {
         other: &DataFrame,
         left_on: &str,
         right_on: &str,
-        left_by: Vec<SmartString>,
-        right_by: Vec<SmartString>,
+        left_by: Vec<PlSmallStr>,
+        right_by: Vec<PlSmallStr>,
         strategy: AsofStrategy,
         tolerance: Option<AnyValue<'static>>,
         suffix: Option<&str>,
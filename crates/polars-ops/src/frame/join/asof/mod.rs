@@ -10,7 +10,6 @@ use polars_core::utils::{ensure_sorted_arg, slice_slice};
 use polars_core::with_match_physical_numeric_polars_type;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
-use smartstring::alias::String as SmartString;
 
 #[cfg(feature = "dtype-categorical")]
 use super::_check_categorical_src;
@@ -31,9 +30,9 @@ pub struct AsOfOptions {
     /// - "2h15m"
     /// - "1d6h"
     /// etc
-    pub tolerance_str: Option<SmartString>,
-    pub left_by: Option<Vec<SmartString>>,
-    pub right_by: Option<Vec<SmartString>>,
+    pub tolerance_str: Option<PlSmallStr>,
+    pub left_by: Option<Vec<PlSmallStr>>,
+    pub right_by: Option<Vec<PlSmallStr>>,
 }
 
 fn check_asof_columns(a: &Series, b: &Series, check_sorted: bool) -> PolarsResult<()> {
@@ -1,7 +1,7 @@
+use polars_core::prelude::PlSmallStr;
 use polars_core::series::IsSorted;
 use polars_core::utils::{concat_df_unchecked, slice_offsets, CustomIterTools, NoNull};
 use polars_core::POOL;
-use smartstring::alias::String as SmartString;
 
 use super::*;
 
@@ -101,7 +101,7 @@ pub trait CrossJoin: IntoDf {
     fn _cross_join_with_names(
         &self,
         other: &DataFrame,
-        names: &[SmartString],
+        names: &[PlSmallStr],
     ) -> PolarsResult<DataFrame> {
         let (mut l_df, r_df) = self.cross_join_dfs(other, None, false)?;
 
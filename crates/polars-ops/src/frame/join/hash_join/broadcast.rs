@@ -0,0 +1,183 @@
+use polars_core::utils::_split_offsets;
+use polars_core::utils::flatten::flatten_par;
+
+use super::*;
+
+/// Build a single hash table of the right relation. It is shared by all threads
+/// that probe a part of the left relation.
+fn build_broadcast_table<K, I>(
+    build: I,
+    build_len: usize,
+    validate: JoinValidation,
+) -> PolarsResult<PlHashMap<K, Vec<IdxSize>>>
+where
+    K: Hash + Eq,
+    I: Iterator<Item = K>,
+{
+    let mut table: PlHashMap<K, Vec<IdxSize>> = PlHashMap::with_capacity(build_len);
+    for (idx, k) in build.enumerate() {
+        table.entry(k).or_default().push(idx as IdxSize);
+    }
+    validate.validate_build(table.len(), build_len, false)?;
+    Ok(table)
+}
+
+fn broadcast_join_inner_impl<K, B, P, PI>(
+    build: B,
+    build_len: usize,
+    probe: P,
+    probe_len: usize,
+    validate: JoinValidation,
+) -> PolarsResult<InnerJoinIds>
+where
+    K: Hash + Eq + Sync,
+    B: Iterator<Item = K>,
+    P: Fn(usize, usize) -> PI + Sync,
+    PI: Iterator<Item = K>,
+{
+    let table = build_broadcast_table(build, build_len, validate)?;
+    let offsets = _split_offsets(probe_len, POOL.current_num_threads());
+
+    let results = POOL.install(|| {
+        offsets
+            .into_par_iter()
+            .map(|(offset, len)| {
+                let mut left = Vec::with_capacity(len);
+                let mut right = Vec::with_capacity(len);
+                for (idx, k) in probe(offset, len).enumerate() {
+                    if let Some(indexes) = table.get(&k) {
+                        let idx = (idx + offset) as IdxSize;
+                        left.extend(std::iter::repeat(idx).take(indexes.len()));
+                        right.extend_from_slice(indexes);
+                    }
+                }
+                (left, right)
+            })
+            .collect::<Vec<_>>()
+    });
+    let lefts = results.iter().map(|t| &t.0).collect::<Vec<_>>();
+    let rights = results.iter().map(|t| &t.1).collect::<Vec<_>>();
+    Ok((flatten_par(&lefts), flatten_par(&rights)))
+}
+
+fn broadcast_join_left_impl<K, B, P, PI>(
+    build: B,
+    build_len: usize,
+    probe: P,
+    probe_len: usize,
+    validate: JoinValidation,
+) -> PolarsResult<LeftJoinIds>
+where
+    K: Hash + Eq + Sync,
+    B: Iterator<Item = K>,
+    P: Fn(usize, usize) -> PI + Sync,
+    PI: Iterator<Item = K>,
+{
+    let table = build_broadcast_table(build, build_len, validate)?;
+    let offsets = _split_offsets(probe_len, POOL.current_num_threads());
+
+    let results = POOL.install(|| {
+        offsets
+            .into_par_iter()
+            .map(|(offset, len)| {
+                let mut left = Vec::with_capacity(len);
+                let mut right = Vec::with_capacity(len);
+                for (idx, k) in probe(offset, len).enumerate() {
+                    let idx = (idx + offset) as IdxSize;
+                    match table.get(&k) {
+                        Some(indexes) => {
+                            left.extend(std::iter::repeat(idx).take(indexes.len()));
+                            right.extend(indexes.iter().map(|idx| Some(*idx)));
+                        },
+                        None => {
+                            left.push(idx);
+                            right.push(None);
+                        },
+                    }
+                }
+                (left, right)
+            })
+            .collect::<Vec<_>>()
+    });
+    let lefts = results.iter().map(|t| &t.0).collect::<Vec<_>>();
+    let rights = results.iter().map(|t| &t.1).collect::<Vec<_>>();
+    Ok(to_left_join_ids(flatten_par(&lefts), flatten_par(&rights)))
+}
+
+macro_rules! broadcast_dispatch {
+    ($impl:ident, $s_left:expr, $s_right:expr, $validate:expr) => {{
+        let (lhs, rhs) = ($s_left.to_physical_repr(), $s_right.to_physical_repr());
+        $validate.validate_probe(&lhs, &rhs, false)?;
+
+        // the relations are rechunked so we can index into a single array
+        match lhs.dtype() {
+            DataType::Utf8 | DataType::Binary => {
+                let lhs = lhs.cast(&DataType::Binary).unwrap();
+                let rhs = rhs.cast(&DataType::Binary).unwrap();
+                let lhs = lhs.binary().unwrap().rechunk();
+                let rhs = rhs.binary().unwrap().rechunk();
+                let (lhs, rhs) = (
+                    lhs.downcast_iter().next().unwrap(),
+                    rhs.downcast_iter().next().unwrap(),
+                );
+                $impl(
+                    rhs.iter(),
+                    rhs.len(),
+                    |offset, len| (offset..offset + len).map(move |i| lhs.get(i)),
+                    lhs.len(),
+                    $validate,
+                )
+            },
+            _ if lhs.bit_repr_is_large() => {
+                let lhs = lhs.bit_repr_large().rechunk();
+                let rhs = rhs.bit_repr_large().rechunk();
+                let (lhs, rhs) = (
+                    lhs.downcast_iter().next().unwrap(),
+                    rhs.downcast_iter().next().unwrap(),
+                );
+                $impl(
+                    rhs.iter().map(|v| v.copied()),
+                    rhs.len(),
+                    |offset, len| (offset..offset + len).map(move |i| lhs.get(i)),
+                    lhs.len(),
+                    $validate,
+                )
+            },
+            _ => {
+                let lhs = lhs.bit_repr_small().rechunk();
+                let rhs = rhs.bit_repr_small().rechunk();
+                let (lhs, rhs) = (
+                    lhs.downcast_iter().next().unwrap(),
+                    rhs.downcast_iter().next().unwrap(),
+                );
+                $impl(
+                    rhs.iter().map(|v| v.copied()),
+                    rhs.len(),
+                    |offset, len| (offset..offset + len).map(move |i| lhs.get(i)),
+                    lhs.len(),
+                    $validate,
+                )
+            },
+        }
+    }};
+}
+
+/// Inner join that builds a single hash table of the right relation, which is broadcasted
+/// to all threads probing the left relation. The left join tuples are sorted.
+pub(super) fn broadcast_join_inner(
+    s_left: &Series,
+    s_right: &Series,
+    validate: JoinValidation,
+) -> PolarsResult<InnerJoinIds> {
+    broadcast_dispatch!(broadcast_join_inner_impl, s_left, s_right, validate)
+}
+
+/// Left join that builds a single hash table of the right relation, which is broadcasted
+/// to all threads probing the left relation.
+pub(super) fn broadcast_join_left(
+    s_left: &Series,
+    s_right: &Series,
+    validate: JoinValidation,
+) -> PolarsResult<LeftJoinIds> {
+    broadcast_dispatch!(broadcast_join_left_impl, s_left, s_right, validate)
+}
@@ -1,3 +1,4 @@
+mod broadcast;
 pub(super) mod multiple_keys;
 pub(super) mod single_keys;
 mod single_keys_dispatch;
@@ -10,6 +11,7 @@ pub(super) mod sort_merge;
 mod zip_outer;
 
 pub use args::*;
+use broadcast::*;
 pub use multiple_keys::private_left_join_multiple_keys;
 pub(super) use multiple_keys::*;
 use polars_core::utils::{_set_partition_size, slice_slice, split_ca};
@@ -226,7 +228,7 @@ pub trait JoinDispatch: IntoDf {
             right.as_single_chunk_par();
             s_right = s_right.rechunk();
         }
-        let ids = sort_or_hash_left(&s_left, &s_right, verbose, args.validation)?;
+        let ids = sort_or_hash_left(&s_left, &s_right, verbose, args.validation, args.strategy)?;
         left._finish_left_join(ids, &right.drop(s_right.name()).unwrap(), args)
     }
 
@@ -154,8 +154,10 @@ pub(super) fn par_sorted_merge_inner_no_nulls(
     }
 }
 
-#[cfg(feature = "performant")]
-fn to_left_join_ids(left_idx: Vec<IdxSize>, right_idx: Vec<Option<IdxSize>>) -> LeftJoinIds {
+pub(super) fn to_left_join_ids(
+    left_idx: Vec<IdxSize>,
+    right_idx: Vec<Option<IdxSize>>,
+) -> LeftJoinIds {
     #[cfg(feature = "chunked_ids")]
     {
         (Either::Left(left_idx), Either::Left(right_idx))
@@ -173,14 +175,74 @@ fn create_reverse_map_from_arg_sort(mut arg_sort: IdxCa) -> Vec<IdxSize> {
     primitive_to_vec::<IdxSize>(arr).unwrap()
 }
 
+#[cfg(feature = "performant")]
+fn arg_sort_ascending(s: &Series) -> IdxCa {
+    s.arg_sort(SortOptions {
+        descending: false,
+        nulls_last: false,
+        multithreaded: true,
+        maintain_order: false,
+    })
+}
+
+/// Sorted merge inner join on keys that don't have to be sorted. The keys that are not
+/// sorted are sorted first and the join tuples are mapped back to the original order.
+///
+/// Returns the join tuples and whether or not the lhs tuples are sorted.
+#[cfg(feature = "performant")]
+fn sort_merge_inner(
+    s_left: &Series,
+    s_right: &Series,
+    sort_left: bool,
+    sort_right: bool,
+) -> (InnerJoinIds, bool) {
+    let left_sort_idx = sort_left.then(|| arg_sort_ascending(s_left));
+    let right_sort_idx = sort_right.then(|| arg_sort_ascending(s_right));
+    let s_left_sorted = left_sort_idx
+        .as_ref()
+        .map(|idx| unsafe { s_left.take_unchecked(idx) });
+    let s_right_sorted = right_sort_idx
+        .as_ref()
+        .map(|idx| unsafe { s_right.take_unchecked(idx) });
+
+    let (mut left, mut right) = par_sorted_merge_inner_no_nulls(
+        s_left_sorted.as_ref().unwrap_or(s_left),
+        s_right_sorted.as_ref().unwrap_or(s_right),
+    );
+
+    for (idx, sort_idx) in [(&mut left, left_sort_idx), (&mut right, right_sort_idx)] {
+        if let Some(sort_idx) = sort_idx {
+            let reverse_idx_map = create_reverse_map_from_arg_sort(sort_idx);
+            POOL.install(|| {
+                idx.par_iter_mut().for_each(|idx| {
+                    *idx = unsafe { *reverse_idx_map.get_unchecked(*idx as usize) };
+                });
+            });
+        }
+    }
+
+    // the lhs tuples are not sorted anymore if we sorted the left key.
+    ((left, right), !sort_left)
+}
+
 #[cfg(not(feature = "performant"))]
 pub fn _sort_or_hash_inner(
     s_left: &Series,
     s_right: &Series,
-    _verbose: bool,
+    verbose: bool,
     validate: JoinValidation,
+    strategy: JoinStrategy,
 ) -> PolarsResult<(InnerJoinIds, bool)> {
-    s_left.hash_join_inner(s_right, validate)
+    match strategy {
+        JoinStrategy::Broadcast => Ok((broadcast_join_inner(s_left, s_right, validate)?, true)),
+        JoinStrategy::SortMerge => {
+            if verbose {
+                eprintln!("inner join: sorted merge join is not available: use hash join");
+            }
+            s_left.hash_join_inner(s_right, validate)
+        },
+        _ => s_left.hash_join_inner(s_right, validate),
+    }
 }
 
 #[cfg(feature = "performant")]
@@ -189,7 +251,19 @@ pub fn _sort_or_hash_inner(
     s_right: &Series,
     verbose: bool,
     validate: JoinValidation,
+    strategy: JoinStrategy,
 ) -> PolarsResult<(InnerJoinIds, bool)> {
+    match strategy {
+        JoinStrategy::Hash => return s_left.hash_join_inner(s_right, validate),
+        JoinStrategy::Broadcast => {
+            if verbose {
+                eprintln!("inner join: use broadcast hash join");
+            }
+            return Ok((broadcast_join_inner(s_left, s_right, validate)?, true));
+        },
+        JoinStrategy::SortMerge | JoinStrategy::Auto => {},
+    }
+
     // We check if keys are sorted.
     // - If they are we can do a sorted merge join
     // If one of the keys is not, it can still be faster to sort that key and use
@@ -200,12 +274,25 @@ pub fn _sort_or_hash_inner(
         .map(|s| s.parse::<f32>().unwrap())
         .unwrap_or(1.0);
     let is_numeric = s_left.dtype().to_physical().is_numeric();
+    let no_nulls = s_left.null_count() == 0 && s_right.null_count() == 0;
+
+    if let JoinStrategy::SortMerge = strategy {
+        if is_numeric && no_nulls && !validate.needs_checks() {
+            if verbose {
+                eprintln!("inner join: use sorted merge join");
+            }
+            let sort_left = !matches!(s_left.is_sorted_flag(), IsSorted::Ascending);
+            let sort_right = !matches!(s_right.is_sorted_flag(), IsSorted::Ascending);
+            return Ok(sort_merge_inner(s_left, s_right, sort_left, sort_right));
+        } else if verbose {
+            eprintln!("inner join: keys don't support sorted merge join: use hash join");
+        }
+    }
 
     if validate.needs_checks() {
         return s_left.hash_join_inner(s_right, validate);
     }
 
-    let no_nulls = s_left.null_count() == 0 && s_right.null_count() == 0;
     match (s_left.is_sorted_flag(), s_right.is_sorted_flag(), no_nulls) {
         (IsSorted::Ascending, IsSorted::Ascending, true) if is_numeric => {
             if verbose {
@@ -219,26 +306,7 @@ pub fn _sort_or_hash_inner(
             if verbose {
                 eprintln!("right key will be descending sorted in inner join operation.")
             }
-
-            let sort_idx = s_right.arg_sort(SortOptions {
-                descending: false,
-                nulls_last: false,
-                multithreaded: true,
-                maintain_order: false,
-            });
-            let s_right = unsafe { s_right.take_unchecked(&sort_idx) };
-            let ids = par_sorted_merge_inner_no_nulls(s_left, &s_right);
-            let reverse_idx_map = create_reverse_map_from_arg_sort(sort_idx);
-
-            let (left, mut right) = ids;
-
-            POOL.install(|| {
-                right.par_iter_mut().for_each(|idx| {
-                    *idx = unsafe { *reverse_idx_map.get_unchecked(*idx as usize) };
-                });
-            });
-
-            Ok(((left, right), true))
+            Ok(sort_merge_inner(s_left, s_right, false, true))
         },
         (_, IsSorted::Ascending, true)
             if is_numeric && size_factor_lhs < size_factor_acceptable =>
@@ -246,27 +314,7 @@ pub fn _sort_or_hash_inner(
             if verbose {
                 eprintln!("left key will be descending sorted in inner join operation.")
             }
-
-            let sort_idx = s_left.arg_sort(SortOptions {
-                descending: false,
-                nulls_last: false,
-                multithreaded: true,
-                maintain_order: false,
-            });
-            let s_left = unsafe { s_left.take_unchecked(&sort_idx) };
-            let ids = par_sorted_merge_inner_no_nulls(&s_left, s_right);
-            let reverse_idx_map = create_reverse_map_from_arg_sort(sort_idx);
-
-            let (mut left, right) = ids;
-
-            POOL.install(|| {
-                left.par_iter_mut().for_each(|idx| {
-                    *idx = unsafe { *reverse_idx_map.get_unchecked(*idx as usize) };
-                });
-            });
-
-            // set sorted to `false` as we descending sorted the left key.
-            Ok(((left, right), false))
+            Ok(sort_merge_inner(s_left, s_right, true, false))
         },
         _ => s_left.hash_join_inner(s_right, validate),
     }
@@ -276,10 +324,20 @@ pub fn _sort_or_hash_inner(
 pub(super) fn sort_or_hash_left(
     s_left: &Series,
     s_right: &Series,
-    _verbose: bool,
+    verbose: bool,
     validate: JoinValidation,
+    strategy: JoinStrategy,
 ) -> PolarsResult<LeftJoinIds> {
-    s_left.hash_join_left(s_right, validate)
+    match strategy {
+        JoinStrategy::Broadcast => broadcast_join_left(s_left, s_right, validate),
+        JoinStrategy::SortMerge => {
+            if verbose {
+                eprintln!("left join: sorted merge join is not available: use hash join");
+            }
+            s_left.hash_join_left(s_right, validate)
+        },
+        _ => s_left.hash_join_left(s_right, validate),
+    }
 }
 
 #[cfg(feature = "performant")]
@@ -288,15 +346,34 @@ pub(super) fn sort_or_hash_left(
     s_right: &Series,
     verbose: bool,
     validate: JoinValidation,
+    strategy: JoinStrategy,
 ) -> PolarsResult<LeftJoinIds> {
+    match strategy {
+        JoinStrategy::Hash => return s_left.hash_join_left(s_right, validate),
+        JoinStrategy::Broadcast => {
+            if verbose {
+                eprintln!("left join: use broadcast hash join");
+            }
+            return broadcast_join_left(s_left, s_right, validate);
+        },
+        JoinStrategy::SortMerge | JoinStrategy::Auto => {},
+    }
+
     if validate.needs_checks() {
+        if verbose && strategy == JoinStrategy::SortMerge {
+            eprintln!("left join: sorted merge join does not support validation: use hash join");
+        }
         return s_left.hash_join_left(s_right, validate);
     }
 
     let size_factor_rhs = s_right.len() as f32 / s_left.len() as f32;
-    let size_factor_acceptable = std::env::var("POLARS_JOIN_SORT_FACTOR")
-        .map(|s| s.parse::<f32>().unwrap())
-        .unwrap_or(1.0);
+    let size_factor_acceptable = if strategy == JoinStrategy::SortMerge {
+        f32::INFINITY
+    } else {
+        std::env::var("POLARS_JOIN_SORT_FACTOR")
+            .map(|s| s.parse::<f32>().unwrap())
+            .unwrap_or(1.0)
+    };
     let is_numeric = s_left.dtype().to_physical().is_numeric();
 
     let no_nulls = s_left.null_count() == 0 && s_right.null_count() == 0;
@@ -316,12 +393,7 @@ pub(super) fn sort_or_hash_left(
                 eprintln!("right key will be reverse sorted in left join operation.")
             }
 
-            let sort_idx = s_right.arg_sort(SortOptions {
-                descending: false,
-                nulls_last: false,
-                multithreaded: true,
-                maintain_order: false,
-            });
+            let sort_idx = arg_sort_ascending(s_right);
             let s_right = unsafe { s_right.take_unchecked(&sort_idx) };
 
             let ids = par_sorted_merge_left(s_left, &s_right);
@@ -338,6 +410,11 @@ pub(super) fn sort_or_hash_left(
             Ok(to_left_join_ids(left, right))
         },
         // don't reverse sort a left join key yet. Have to figure out how to set sorted flag
-        _ => s_left.hash_join_left(s_right, validate),
+        _ => {
+            if verbose && strategy == JoinStrategy::SortMerge {
+                eprintln!("left join: keys don't support sorted merge join: use hash join");
+            }
+            s_left.hash_join_left(s_right, validate)
+        },
     }
 }
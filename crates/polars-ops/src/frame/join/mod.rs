@@ -449,6 +449,40 @@ pub trait DataFrameJoinOps: IntoDf {
     {
         self.join(other, left_on, right_on, JoinArgs::new(JoinType::Outer))
     }
+
+    /// Keep (`keep = true`) or remove (`keep = false`) the rows whose values in the `on` columns
+    /// appear in the same columns of `other`.
+    ///
+    /// This is a semi or anti join: the keys of `other` are hashed once and only the columns of
+    /// `self` are returned, in their original order.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use polars_core::prelude::*;
+    /// # use polars_ops::prelude::*;
+    /// let df: DataFrame = df!("id" => &[1, 2, 3, 4],
+    ///                         "value" => &["a", "b", "c", "d"])?;
+    /// let blocked: DataFrame = df!("id" => &[2, 4])?;
+    ///
+    /// let out = df.filter_by_keys(&blocked, ["id"], false)?;
+    /// assert_eq!(out.shape(), (2, 2));
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    #[cfg(feature = "semi_anti_join")]
+    fn filter_by_keys<I, S>(&self, other: &DataFrame, on: I, keep: bool) -> PolarsResult<DataFrame>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let on = on
+            .into_iter()
+            .map(|s| s.as_ref().to_string())
+            .collect::<Vec<_>>();
+        polars_ensure!(!on.is_empty(), ComputeError: "'filter_by_keys' needs at least one key column");
+        let how = if keep { JoinType::Semi } else { JoinType::Anti };
+        self.join(other, &on, &on, JoinArgs::new(how))
+    }
 }
 
 trait DataFrameJoinOpsPrivate: IntoDf {
@@ -469,7 +503,7 @@ trait DataFrameJoinOpsPrivate: IntoDf {
         #[cfg(feature = "dtype-categorical")]
         _check_categorical_src(s_left.dtype(), s_right.dtype())?;
         let ((join_tuples_left, join_tuples_right), sorted) =
-            _sort_or_hash_inner(s_left, s_right, verbose, args.validation)?;
+            _sort_or_hash_inner(s_left, s_right, verbose, args.validation, args.strategy)?;
 
         let mut join_tuples_left = &*join_tuples_left;
         let mut join_tuples_right = &*join_tuples_right;
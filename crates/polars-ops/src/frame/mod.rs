@@ -1,8 +1,12 @@
+#[cfg(feature = "diff_frames")]
+mod diff_frames;
 mod hashing;
 pub mod join;
 #[cfg(feature = "pivot")]
 pub mod pivot;
 
+#[cfg(feature = "diff_frames")]
+pub use diff_frames::FrameDiff;
 pub use join::*;
 #[cfg(feature = "to_dummies")]
 use polars_core::export::rayon::prelude::*;
@@ -112,4 +116,40 @@ pub trait DataFrameOps: IntoDf {
 
         accumulate_dataframes_horizontal(cols)
     }
+
+    /// Compare this frame with a `new` version of it. Rows are matched by the `keys`, which
+    /// must be unique in both frames, and the columns that are in both frames are compared.
+    ///
+    /// Returns the added, removed and changed rows and the number of changes per column.
+    /// Nulls are equal to nulls.
+    #[cfg(feature = "diff_frames")]
+    fn diff_frames(&self, new: &DataFrame, keys: &[&str]) -> PolarsResult<FrameDiff> {
+        diff_frames::diff_frames(self.to_df(), new, keys)
+    }
+
+    /// Check if the frames are equal, comparing the float columns with a tolerance. See
+    /// [`approx_eq`] for the tolerances. Nulls are equal to nulls.
+    #[cfg(feature = "approx_eq")]
+    fn approx_equals(&self, other: &DataFrame, rel_tol: f64, abs_tol: f64) -> PolarsResult<bool> {
+        let df = self.to_df();
+        if df.shape() != other.shape() {
+            return Ok(false);
+        }
+        for (left, right) in df.get_columns().iter().zip(other.get_columns()) {
+            if left.name() != right.name() || left.dtype() != right.dtype() {
+                return Ok(false);
+            }
+            let equal = if left.dtype().is_float() {
+                let both_null = left.is_null() & right.is_null();
+                let eq = approx_eq(left, right, rel_tol, abs_tol)?.fill_null_with_values(false)?;
+                (&eq | &both_null).all()
+            } else {
+                left.series_equal_missing(right)
+            };
+            if !equal {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
 }
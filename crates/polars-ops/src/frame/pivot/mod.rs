@@ -4,6 +4,7 @@ use std::borrow::Cow;
 
 use polars_core::export::rayon::prelude::*;
 use polars_core::frame::group_by::expr::PhysicalAggExpr;
+use polars_core::frame::group_by::GroupsIndicator;
 use polars_core::prelude::*;
 use polars_core::utils::_split_offsets;
 use polars_core::{downcast_as_macro_arg_physical, POOL};
@@ -114,6 +115,7 @@ where
         sort_columns,
         false,
         separator,
+        None,
     )
 }
 
@@ -161,9 +163,161 @@ where
         sort_columns,
         true,
         separator,
+        None,
     )
 }
 
+/// Do a [`pivot_stable`] and add margins: a column with the total of every row and a row
+/// with the total of every column and the grand total.
+///
+/// The totals are computed with `agg_fn` over the rows of `pivot_df`, not over the pivoted
+/// cells, so they are also correct for aggregations like the mean. The groups of the totals
+/// are merged from the groups of the pivot itself. The margins are named
+/// `margins_name`; with multiple values columns, the total columns are named
+/// `{value}{separator}{margins_name}`. The first index column is cast to `Utf8` to hold the
+/// name of the margin row, the other index columns are null in that row.
+///
+/// Returns an error if `margins_name` is a value of the first index column or the name of a
+/// pivoted column, as the margins couldn't be told apart from the data.
+#[allow(clippy::too_many_arguments)]
+pub fn pivot_with_margins<I0, S0, I1, S1, I2, S2>(
+    pivot_df: &DataFrame,
+    values: I0,
+    index: I1,
+    columns: I2,
+    sort_columns: bool,
+    agg_fn: Option<PivotAgg>,
+    separator: Option<&str>,
+    margins_name: &str,
+) -> PolarsResult<DataFrame>
+where
+    I0: IntoIterator<Item = S0>,
+    S0: AsRef<str>,
+    I1: IntoIterator<Item = S1>,
+    S1: AsRef<str>,
+    I2: IntoIterator<Item = S2>,
+    S2: AsRef<str>,
+{
+    let values = values
+        .into_iter()
+        .map(|s| s.as_ref().to_string())
+        .collect::<Vec<_>>();
+    let index = index
+        .into_iter()
+        .map(|s| s.as_ref().to_string())
+        .collect::<Vec<_>>();
+    let columns = columns
+        .into_iter()
+        .map(|s| s.as_ref().to_string())
+        .collect::<Vec<_>>();
+    polars_ensure!(
+        agg_fn.is_some(),
+        ComputeError: "pivot margins require an aggregation function"
+    );
+
+    pivot_impl(
+        pivot_df,
+        &values,
+        &index,
+        &columns,
+        agg_fn,
+        sort_columns,
+        true,
+        separator,
+        Some(margins_name),
+    )
+}
+
+/// Merge the `groups` of a pivot that have the same location into one group per location.
+fn merge_groups(
+    groups: &GroupsProxy,
+    locations: &[IdxSize],
+    n_locations: usize,
+) -> Vec<Vec<IdxSize>> {
+    let mut merged = vec![vec![]; n_locations];
+    for (group, location) in groups.iter().zip(locations) {
+        let idx: &mut Vec<IdxSize> = &mut merged[*location as usize];
+        match group {
+            GroupsIndicator::Idx((_, group)) => idx.extend_from_slice(group),
+            GroupsIndicator::Slice([first, len]) => idx.extend(first..first + len),
+        }
+    }
+    merged
+}
+
+fn to_groups(mut all: Vec<Vec<IdxSize>>) -> GroupsProxy {
+    all.iter_mut().for_each(|idx| idx.sort_unstable());
+    let first = all
+        .iter()
+        .map(|idx| idx.first().copied().unwrap_or(0))
+        .collect();
+    GroupsIdx::new(first, all, false).into()
+}
+
+/// Add the margin row to the index columns of a pivot.
+fn add_margin_row(
+    row_index: &mut [Series],
+    index: &[String],
+    margins_name: &str,
+) -> PolarsResult<()> {
+    let first_index = row_index[0].cast(&DataType::Utf8)?;
+    polars_ensure!(
+        !first_index.utf8()?.into_iter().any(|v| v == Some(margins_name)),
+        ComputeError: "margins name '{}' is also a value of index column '{}'",
+        margins_name, index[0]
+    );
+    row_index[0] = first_index;
+    for s in row_index.iter_mut() {
+        let margin = match s.name() == index[0] {
+            true => Series::new(s.name(), [margins_name]),
+            false => Series::full_null(s.name(), 1, s.dtype()),
+        };
+        s.append(&margin)?;
+    }
+    Ok(())
+}
+
+/// Aggregate the `value_col` of every group with `agg_fn`.
+///
+/// # Safety
+/// The groups must be in bounds of `value_col`.
+unsafe fn aggregate_values(
+    value_col: &Series,
+    value_col_name: &str,
+    groups: &GroupsProxy,
+    agg_fn: &Option<PivotAgg>,
+) -> PolarsResult<Series> {
+    use PivotAgg::*;
+    let out = match agg_fn {
+        None => match value_col.len() > groups.len() {
+            true => {
+                polars_bail!(ComputeError: "found multiple elements in the same group, please specify an aggregation function")
+            },
+            false => value_col.agg_first(groups),
+        },
+        Some(agg_fn) => match agg_fn {
+            Sum => value_col.agg_sum(groups),
+            Min => value_col.agg_min(groups),
+            Max => value_col.agg_max(groups),
+            Last => value_col.agg_last(groups),
+            First => value_col.agg_first(groups),
+            Mean => value_col.agg_mean(groups),
+            Median => value_col.agg_median(groups),
+            Count => groups.group_count().into_series(),
+            Expr(ref expr) => {
+                let name = expr.root_name()?;
+                let mut value_col = value_col.clone();
+                value_col.rename(name);
+                let tmp_df = DataFrame::new_no_checks(vec![value_col]);
+                let mut aggregated = expr.evaluate(&tmp_df, groups)?;
+                aggregated.rename(value_col_name);
+                aggregated
+            },
+        },
+    };
+    Ok(out)
+}
+
 #[allow(clippy::too_many_arguments)]
 fn pivot_impl(
     pivot_df: &DataFrame,
@@ -180,11 +334,15 @@ fn pivot_impl(
     stable: bool,
     // used as separator/delimiter in generated column names.
     separator: Option<&str>,
+    // add a row and column with the totals under this name
+    margins_name: Option<&str>,
 ) -> PolarsResult<DataFrame> {
     let sep = separator.unwrap_or("_");
     polars_ensure!(!index.is_empty(), ComputeError: "index cannot be zero length");
 
     let mut final_cols = vec![];
+    // the groups of the row totals, followed by a group with all rows for the grand total
+    let mut row_margin_groups = None;
 
     let mut count = 0;
     let out: PolarsResult<()> = POOL.install(|| {
@@ -203,48 +361,51 @@ fn pivot_impl(
                 || positioning::compute_col_idx(pivot_df, column_column_name, &groups),
                 || positioning::compute_row_idx(pivot_df, index, &groups, count),
             );
-            let (col_locations, column_agg) = col?;
-            let (row_locations, n_rows, mut row_index) = row?;
+            let (mut col_locations, column_agg) = col?;
+            let (mut row_locations, mut n_rows, mut row_index) = row?;
+
+            let column_headers = column_agg.unique_stable()?.cast(&DataType::Utf8)?;
+            let n_cols = column_headers.len();
+
+            // the totals of the pivoted columns are aggregated over the merged groups of the
+            // pivot and positioned in an extra row
+            let col_margin_groups = match margins_name {
+                Some(margins_name) => {
+                    if count == 0 {
+                        let mut all = merge_groups(&groups, &row_locations, n_rows);
+                        all.push((0..pivot_df.height() as IdxSize).collect());
+                        row_margin_groups = Some(to_groups(all));
+                        add_margin_row(row_index.as_mut().unwrap(), index, margins_name)?;
+                    }
+                    let col_margin_groups =
+                        to_groups(merge_groups(&groups, &col_locations, n_cols));
+                    row_locations.extend(std::iter::repeat(n_rows as IdxSize).take(n_cols));
+                    col_locations.extend(0..n_cols as IdxSize);
+                    n_rows += 1;
+                    Some(col_margin_groups)
+                },
+                None => None,
+            };
 
             for value_col_name in values {
                 let value_col = pivot_df.column(value_col_name)?;
 
-                use PivotAgg::*;
-                let value_agg = unsafe {
-                    match &agg_fn {
-                        None => match value_col.len() > groups.len() {
-                            true => polars_bail!(ComputeError: "found multiple elements in the same group, please specify an aggregation function"),
-                            false => value_col.agg_first(&groups),
-                        }
-                        Some(agg_fn) => match agg_fn {
-                            Sum => value_col.agg_sum(&groups),
-                            Min => value_col.agg_min(&groups),
-                            Max => value_col.agg_max(&groups),
-                            Last => value_col.agg_last(&groups),
-                            First => value_col.agg_first(&groups),
-                            Mean => value_col.agg_mean(&groups),
-                            Median => value_col.agg_median(&groups),
-                            Count => groups.group_count().into_series(),
-                            Expr(ref expr) => {
-                                let name = expr.root_name()?;
-                                let mut value_col = value_col.clone();
-                                value_col.rename(name);
-                                let tmp_df = DataFrame::new_no_checks(vec![value_col]);
-                                let mut aggregated = expr.evaluate(&tmp_df, &groups)?;
-                                aggregated.rename(value_col_name);
-                                aggregated
-                            }
-                        },
-                    }
-                };
+                let mut value_agg =
+                    unsafe { aggregate_values(value_col, value_col_name, &groups, &agg_fn)? };
+                if let Some(col_margin_groups) = &col_margin_groups {
+                    let col_totals = unsafe {
+                        aggregate_values(value_col, value_col_name, col_margin_groups, &agg_fn)?
+                    };
+                    value_agg.append(&col_totals)?;
+                }
 
-                let headers = column_agg.unique_stable()?.cast(&DataType::Utf8)?;
-                let mut headers = headers.utf8().unwrap().clone();
+                let mut headers = column_headers.utf8().unwrap().clone();
                 if values.len() > 1 {
-                    headers = headers.apply_values(|v| Cow::from(format!("{value_col_name}{sep}{column_column_name}{sep}{v}")))
+                    headers = headers.apply_values(|v| {
+                        Cow::from(format!("{value_col_name}{sep}{column_column_name}{sep}{v}"))
+                    })
                 }
 
-                let n_cols = headers.len();
                 let value_agg_phys = value_agg.to_physical_repr();
                 let logical_type = value_agg.dtype();
 
@@ -296,5 +457,24 @@ fn pivot_impl(
         Ok(())
     });
     out?;
+
+    if let (Some(margins_name), Some(row_margin_groups)) = (margins_name, &row_margin_groups) {
+        for value_col_name in values {
+            let name = match values.len() {
+                1 => margins_name.to_string(),
+                _ => format!("{value_col_name}{sep}{margins_name}"),
+            };
+            polars_ensure!(
+                !final_cols.iter().any(|s| s.name() == name),
+                ComputeError: "margins name '{}' collides with the pivoted column '{}'",
+                margins_name, name
+            );
+            let value_col = pivot_df.column(value_col_name)?;
+            let mut row_totals =
+                unsafe { aggregate_values(value_col, value_col_name, row_margin_groups, &agg_fn)? };
+            row_totals.rename(&name);
+            final_cols.push(row_totals);
+        }
+    }
     Ok(DataFrame::new_no_checks(final_cols))
 }
@@ -5,5 +5,7 @@ pub use crate::chunked_array::*;
 #[cfg(feature = "merge_sorted")]
 pub use crate::frame::_merge_sorted_dfs;
 pub use crate::frame::join::*;
+#[cfg(feature = "diff_frames")]
+pub use crate::frame::FrameDiff;
 pub use crate::frame::{DataFrameJoinOps, DataFrameOps};
 pub use crate::series::*;
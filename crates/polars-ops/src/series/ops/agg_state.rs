@@ -0,0 +1,240 @@
+#[cfg(feature = "approx_unique")]
+use polars_core::export::ahash::RandomState;
+use polars_core::prelude::*;
+
+#[cfg(feature = "approx_unique")]
+use crate::series::HyperLogLog;
+#[cfg(feature = "approx_quantile")]
+use crate::series::TDigest;
+
+/// Fixed seeds, so that the states of different processes hash values the same way.
+#[cfg(feature = "approx_unique")]
+const SEED: RandomState = RandomState::with_seeds(
+    0x2a5e1c9d6b3f7048_u64,
+    0x93d1f6e05a7c2b84_u64,
+    0x5c07a3e9d18b6f21_u64,
+    0xe4b8290f7d6a1c35_u64,
+);
+
+/// The partial result of an aggregation over a part of the data.
+///
+/// States of the same kind computed on different partitions can be merged, the result of
+/// [`PartialAggState::finish`] on the merged state is the aggregation over all partitions. This
+/// allows map-reduce style aggregation where every worker sends its states to a reducer.
+#[derive(Clone, Debug)]
+pub enum PartialAggState {
+    /// The number of non-null values.
+    Count(IdxSize),
+    /// The sum as a Series of length 1.
+    Sum(Series),
+    /// The minimum as a Series of length 1.
+    Min(Series),
+    /// The maximum as a Series of length 1.
+    Max(Series),
+    /// The sum and the number of the non-null values.
+    Mean { sum: f64, count: IdxSize },
+    /// A t-digest of the values, see [`crate::series::approx_quantile`].
+    #[cfg(feature = "approx_quantile")]
+    Quantile { digest: TDigest, quantile: f64 },
+    /// A HyperLogLog of the hashes of the values, see [`crate::series::approx_n_unique`].
+    #[cfg(feature = "approx_unique")]
+    NUnique(HyperLogLog<u64>),
+}
+
+impl PartialAggState {
+    pub fn count(s: &Series) -> Self {
+        PartialAggState::Count((s.len() - s.null_count()) as IdxSize)
+    }
+
+    pub fn sum(s: &Series) -> Self {
+        PartialAggState::Sum(s.sum_as_series())
+    }
+
+    pub fn min(s: &Series) -> Self {
+        PartialAggState::Min(s.min_as_series())
+    }
+
+    pub fn max(s: &Series) -> Self {
+        PartialAggState::Max(s.max_as_series())
+    }
+
+    pub fn mean(s: &Series) -> PolarsResult<Self> {
+        polars_ensure!(s.dtype().is_numeric(), opq = mean, s.dtype());
+        let s = s.cast(&DataType::Float64)?;
+        let ca = s.f64().unwrap();
+        Ok(PartialAggState::Mean {
+            sum: ca.sum().unwrap_or(0.0),
+            count: (ca.len() - ca.null_count()) as IdxSize,
+        })
+    }
+
+    #[cfg(feature = "approx_quantile")]
+    pub fn approx_quantile(s: &Series, quantile: f64) -> PolarsResult<Self> {
+        polars_ensure!(s.dtype().is_numeric(), opq = approx_quantile, s.dtype());
+        polars_ensure!(
+            (0.0..=1.0).contains(&quantile),
+            ComputeError: "quantile should be between 0.0 and 1.0, got {}", quantile
+        );
+        let s = s.cast(&DataType::Float64)?;
+        let mut digest = TDigest::default();
+        s.f64()?.into_iter().flatten().for_each(|v| digest.add(v));
+        Ok(PartialAggState::Quantile { digest, quantile })
+    }
+
+    #[cfg(feature = "approx_unique")]
+    pub fn approx_n_unique(s: &Series) -> PolarsResult<Self> {
+        // the physical values of a categorical depend on its rev-map
+        #[cfg(feature = "dtype-categorical")]
+        let s = if matches!(s.dtype(), DataType::Categorical(_)) {
+            s.cast(&DataType::Utf8)?
+        } else {
+            s.clone()
+        };
+        let s = s.to_physical_repr();
+        let mut hashes = Vec::with_capacity(s.len());
+        s.0.vec_hash(SEED, &mut hashes)?;
+
+        let mut hll = HyperLogLog::new();
+        hashes.iter().for_each(|h| hll.add(h));
+        Ok(PartialAggState::NUnique(hll))
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            PartialAggState::Count(_) => "count",
+            PartialAggState::Sum(_) => "sum",
+            PartialAggState::Min(_) => "min",
+            PartialAggState::Max(_) => "max",
+            PartialAggState::Mean { .. } => "mean",
+            #[cfg(feature = "approx_quantile")]
+            PartialAggState::Quantile { .. } => "approx_quantile",
+            #[cfg(feature = "approx_unique")]
+            PartialAggState::NUnique(_) => "approx_n_unique",
+        }
+    }
+
+    /// Merge the state of another partition into this state.
+    pub fn merge(&mut self, other: &PartialAggState) -> PolarsResult<()> {
+        fn reduce(
+            acc: &mut Series,
+            other: &Series,
+            f: impl Fn(&Series) -> Series,
+        ) -> PolarsResult<()> {
+            acc.append(other)?;
+            *acc = f(acc);
+            Ok(())
+        }
+
+        match (self, other) {
+            (PartialAggState::Count(acc), PartialAggState::Count(other)) => *acc += *other,
+            (PartialAggState::Sum(acc), PartialAggState::Sum(other)) => {
+                reduce(acc, other, Series::sum_as_series)?
+            },
+            (PartialAggState::Min(acc), PartialAggState::Min(other)) => {
+                reduce(acc, other, |s| s.min_as_series())?
+            },
+            (PartialAggState::Max(acc), PartialAggState::Max(other)) => {
+                reduce(acc, other, |s| s.max_as_series())?
+            },
+            (
+                PartialAggState::Mean { sum, count },
+                PartialAggState::Mean {
+                    sum: other_sum,
+                    count: other_count,
+                },
+            ) => {
+                *sum += *other_sum;
+                *count += *other_count;
+            },
+            #[cfg(feature = "approx_quantile")]
+            (
+                PartialAggState::Quantile { digest, quantile },
+                PartialAggState::Quantile {
+                    digest: other_digest,
+                    quantile: other_quantile,
+                },
+            ) => {
+                polars_ensure!(
+                    quantile == other_quantile,
+                    ComputeError: "cannot merge the states of quantile {} and quantile {}",
+                    quantile, other_quantile
+                );
+                digest.merge(other_digest)
+            },
+            #[cfg(feature = "approx_unique")]
+            (PartialAggState::NUnique(acc), PartialAggState::NUnique(other)) => acc.merge(other),
+            (acc, other) => polars_bail!(
+                ComputeError: "cannot merge a '{}' state with a '{}' state",
+                acc.name(), other.name()
+            ),
+        }
+        Ok(())
+    }
+
+    /// The result of the aggregation as a Series of length 1.
+    pub fn finish(&self, name: &str) -> Series {
+        match self {
+            PartialAggState::Count(count) => Series::new(name, &[*count]),
+            PartialAggState::Sum(s) | PartialAggState::Min(s) | PartialAggState::Max(s) => {
+                let mut s = s.clone();
+                s.rename(name);
+                s
+            },
+            PartialAggState::Mean { sum, count } => {
+                let mean = (*count > 0).then(|| *sum / *count as f64);
+                Series::new(name, &[mean])
+            },
+            #[cfg(feature = "approx_quantile")]
+            PartialAggState::Quantile { digest, quantile } => {
+                Series::new(name, &[digest.clone().quantile(*quantile)])
+            },
+            #[cfg(feature = "approx_unique")]
+            PartialAggState::NUnique(hll) => Series::new(name, &[hll.count() as IdxSize]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn merged<F>(s: &Series, f: F) -> PolarsResult<Series>
+    where
+        F: Fn(&Series) -> PolarsResult<PartialAggState>,
+    {
+        let mut state = f(&s.slice(0, 3))?;
+        state.merge(&f(&s.slice(3, s.len()))?)?;
+        Ok(state.finish(s.name()))
+    }
+
+    #[test]
+    fn test_agg_state_merge() -> PolarsResult<()> {
+        let s = Series::new("a", &[Some(3i32), None, Some(1), Some(4), Some(2), None]);
+
+        let out = merged(&s, |s| Ok(PartialAggState::count(s)))?;
+        assert_eq!(out, Series::new("a", &[4 as IdxSize]));
+        let out = merged(&s, |s| Ok(PartialAggState::sum(s)))?;
+        assert_eq!(out, Series::new("a", &[10i32]));
+        let out = merged(&s, |s| Ok(PartialAggState::min(s)))?;
+        assert_eq!(out, Series::new("a", &[1i32]));
+        let out = merged(&s, |s| Ok(PartialAggState::max(s)))?;
+        assert_eq!(out, Series::new("a", &[4i32]));
+        let out = merged(&s, PartialAggState::mean)?;
+        assert_eq!(out, Series::new("a", &[2.5f64]));
+        #[cfg(feature = "approx_quantile")]
+        {
+            let out = merged(&s, |s| PartialAggState::approx_quantile(s, 0.0))?;
+            assert_eq!(out, Series::new("a", &[1.0f64]));
+        }
+        #[cfg(feature = "approx_unique")]
+        {
+            let s = Series::new("a", &["x", "y", "x", "z", "y", "x"]);
+            let out = merged(&s, PartialAggState::approx_n_unique)?;
+            assert_eq!(out, Series::new("a", &[3 as IdxSize]));
+        }
+
+        let mut state = PartialAggState::count(&s);
+        assert!(state.merge(&PartialAggState::sum(&s)).is_err());
+        Ok(())
+    }
+}
@@ -1,5 +1,13 @@
 #[cfg(feature = "approx_unique")]
 mod hyperloglogplus;
+#[cfg(feature = "reservoir_sample")]
+mod reservoir;
+#[cfg(feature = "approx_quantile")]
+mod tdigest;
 
 #[cfg(feature = "approx_unique")]
 pub use hyperloglogplus::*;
+#[cfg(feature = "reservoir_sample")]
+pub use reservoir::*;
+#[cfg(feature = "approx_quantile")]
+pub use tdigest::*;
@@ -0,0 +1,123 @@
+//! # Reservoir
+//!
+//! `reservoir` module contains an implementation of reservoir sampling (algorithm R), which
+//! draws a uniform sample without replacement of a fixed size from an input of unknown
+//! length in a single pass. Reservoirs of disjoint inputs can be merged into a uniform
+//! sample of the union, so it can be used by [`crate::series::reservoir_sample`] and by the
+//! streaming engine.
+
+use rand::prelude::*;
+use rand::rngs::SmallRng;
+
+#[derive(Clone, Debug)]
+pub struct Reservoir<T> {
+    capacity: usize,
+    /// The number of items offered to the reservoir.
+    seen: u64,
+    items: Vec<T>,
+    rng: SmallRng,
+}
+
+impl<T> Reservoir<T> {
+    /// Create a reservoir that keeps a sample of at most `capacity` items.
+    pub fn new(capacity: usize, seed: Option<u64>) -> Self {
+        let rng = match seed {
+            Some(seed) => SmallRng::seed_from_u64(seed),
+            None => SmallRng::from_entropy(),
+        };
+        Self {
+            capacity,
+            seen: 0,
+            items: Vec::with_capacity(capacity.min(1024)),
+            rng,
+        }
+    }
+
+    pub fn push(&mut self, item: T) {
+        self.seen += 1;
+        if self.items.len() < self.capacity {
+            self.items.push(item)
+        } else {
+            let idx = self.rng.gen_range(0..self.seen);
+            if idx < self.capacity as u64 {
+                self.items[idx as usize] = item
+            }
+        }
+    }
+
+    /// Merge the sample of a disjoint input into this reservoir.
+    ///
+    /// # Panics
+    /// Panics if the reservoirs have a different capacity.
+    pub fn merge(&mut self, mut other: Reservoir<T>) {
+        // A reservoir with a smaller capacity holds fewer items than its inputs account for, so
+        // the draws below could pick an input that has run out of items.
+        assert_eq!(
+            self.capacity, other.capacity,
+            "can only merge reservoirs of the same capacity"
+        );
+        let mut items = std::mem::take(&mut self.items);
+        let (mut seen_self, mut seen_other) = (self.seen, other.seen);
+        let n = self.capacity.min(items.len() + other.items.len());
+
+        // Draw without replacement from the union of both inputs: every draw comes from
+        // an input with a probability proportional to the number of items it has left.
+        let mut merged = Vec::with_capacity(n);
+        for _ in 0..n {
+            let from_self = self.rng.gen_range(0..seen_self + seen_other) < seen_self;
+            let (source, seen) = if from_self {
+                (&mut items, &mut seen_self)
+            } else {
+                (&mut other.items, &mut seen_other)
+            };
+            let idx = self.rng.gen_range(0..source.len());
+            merged.push(source.swap_remove(idx));
+            *seen -= 1;
+        }
+        self.items = merged;
+        self.seen += other.seen;
+    }
+
+    /// The number of items offered to the reservoir.
+    pub fn seen(&self) -> u64 {
+        self.seen
+    }
+
+    pub fn into_items(self) -> Vec<T> {
+        self.items
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_reservoir() {
+        let mut reservoir = Reservoir::new(10, Some(0));
+        (0..5).for_each(|v| reservoir.push(v));
+        // an input smaller than the capacity is kept as a whole
+        assert_eq!(reservoir.clone().into_items(), &[0, 1, 2, 3, 4]);
+
+        let mut other = Reservoir::new(10, Some(1));
+        (5..1000).for_each(|v| other.push(v));
+        reservoir.merge(other);
+        assert_eq!(reservoir.seen(), 1000);
+
+        let mut items = reservoir.into_items();
+        items.sort();
+        items.dedup();
+        assert_eq!(items.len(), 10);
+        assert!(items.iter().all(|v| (0..1000).contains(v)));
+    }
+
+    #[test]
+    #[should_panic(expected = "same capacity")]
+    fn test_reservoir_merge_capacity() {
+        let mut reservoir = Reservoir::new(2, Some(0));
+        (0..10).for_each(|v| reservoir.push(v));
+        let mut other = Reservoir::new(5, Some(1));
+        (10..20).for_each(|v| other.push(v));
+        reservoir.merge(other);
+    }
+}
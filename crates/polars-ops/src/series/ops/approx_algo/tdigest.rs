@@ -0,0 +1,200 @@
+//! # TDigest
+//!
+//! `tdigest` module contains an implementation of the merging t-digest of Ted Dunning,
+//! used to estimate quantiles in bounded memory so that [`crate::series::approx_quantile`]
+//! and the streaming engine can aggregate unbounded inputs.
+//!
+//! A digest keeps a sorted list of centroids (a mean and a weight). Centroids near the tails
+//! are kept small, which makes the estimates of extreme quantiles accurate. Digests are
+//! mergeable, so partial digests of different threads or partitions can be combined.
+//!
+//! # Examples
+//!
+//! ```ignore
+//!     let mut digest = TDigest::default();
+//!     (1..=100).for_each(|v| digest.add(v as f64));
+//!
+//!     assert_eq!(digest.quantile(0.5), Some(50.5));
+//! ```
+
+use std::f64::consts::PI;
+
+/// The default compression. The digest holds at most ~`2 * compression` centroids.
+pub const DEFAULT_COMPRESSION: f64 = 100.0;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Centroid {
+    mean: f64,
+    weight: f64,
+}
+
+#[derive(Clone, Debug)]
+pub struct TDigest {
+    compression: f64,
+    /// Merged centroids, sorted by mean.
+    centroids: Vec<Centroid>,
+    /// Values that are not yet merged into the centroids.
+    buffer: Vec<f64>,
+    min: f64,
+    max: f64,
+}
+
+impl Default for TDigest {
+    fn default() -> Self {
+        Self::new(DEFAULT_COMPRESSION)
+    }
+}
+
+impl TDigest {
+    pub fn new(compression: f64) -> Self {
+        Self {
+            compression,
+            centroids: vec![],
+            buffer: vec![],
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+
+    fn buffer_capacity(&self) -> usize {
+        (self.compression as usize * 5).max(32)
+    }
+
+    /// Add a value to the digest. NaN values are ignored.
+    pub fn add(&mut self, value: f64) {
+        if value.is_nan() {
+            return;
+        }
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+        self.buffer.push(value);
+        if self.buffer.len() >= self.buffer_capacity() {
+            self.compress()
+        }
+    }
+
+    /// Merge the values of `other` into this digest.
+    pub fn merge(&mut self, other: &TDigest) {
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+        self.buffer.extend_from_slice(&other.buffer);
+        self.centroids.extend_from_slice(&other.centroids);
+        self.compress()
+    }
+
+    /// The number of values added to the digest.
+    pub fn count(&self) -> f64 {
+        self.centroids.iter().map(|c| c.weight).sum::<f64>() + self.buffer.len() as f64
+    }
+
+    /// Scale function `k1` of the t-digest paper.
+    fn k(&self, q: f64) -> f64 {
+        self.compression / (2.0 * PI) * (2.0 * q - 1.0).asin()
+    }
+
+    fn k_inverse(&self, k: f64) -> f64 {
+        ((k * 2.0 * PI / self.compression).sin() + 1.0) / 2.0
+    }
+
+    fn compress(&mut self) {
+        if self.buffer.is_empty() && self.centroids.len() <= 1 {
+            return;
+        }
+        let mut items = std::mem::take(&mut self.centroids);
+        items.extend(
+            self.buffer
+                .drain(..)
+                .map(|mean| Centroid { mean, weight: 1.0 }),
+        );
+        items.sort_unstable_by(|a, b| a.mean.total_cmp(&b.mean));
+
+        let total: f64 = items.iter().map(|c| c.weight).sum();
+        let mut merged = Vec::with_capacity(items.len().min(2 * self.compression as usize));
+        let mut items = items.into_iter();
+        let mut current = items.next().unwrap();
+        let mut weight_so_far = 0.0;
+        let mut q_limit = self.k_inverse(self.k(0.0) + 1.0);
+
+        for item in items {
+            let q = (weight_so_far + current.weight + item.weight) / total;
+            if q <= q_limit {
+                let weight = current.weight + item.weight;
+                current.mean += (item.mean - current.mean) * item.weight / weight;
+                current.weight = weight;
+            } else {
+                weight_so_far += current.weight;
+                merged.push(current);
+                q_limit = self.k_inverse(self.k(weight_so_far / total) + 1.0);
+                current = item;
+            }
+        }
+        merged.push(current);
+        self.centroids = merged;
+    }
+
+    /// Estimate the `quantile` of the values in the digest.
+    ///
+    /// Returns `None` if the digest is empty.
+    pub fn quantile(&mut self, quantile: f64) -> Option<f64> {
+        self.compress();
+        let centroids = &self.centroids;
+        match centroids.len() {
+            0 => return None,
+            1 => return Some(centroids[0].mean),
+            _ => {},
+        }
+        let quantile = quantile.clamp(0.0, 1.0);
+        let total: f64 = centroids.iter().map(|c| c.weight).sum();
+        let target = quantile * total;
+
+        // the weight of a centroid is spread evenly around its mean, so we
+        // interpolate between the midpoints of neighbouring centroids
+        let first = centroids[0];
+        if target < first.weight / 2.0 {
+            let frac = target / (first.weight / 2.0);
+            return Some(self.min + (first.mean - self.min) * frac);
+        }
+        let mut cumulative = 0.0;
+        for window in centroids.windows(2) {
+            let (left, right) = (window[0], window[1]);
+            let left_mid = cumulative + left.weight / 2.0;
+            let right_mid = cumulative + left.weight + right.weight / 2.0;
+            if target <= right_mid {
+                let frac = (target - left_mid) / (right_mid - left_mid);
+                return Some(left.mean + (right.mean - left.mean) * frac);
+            }
+            cumulative += left.weight;
+        }
+        let last = centroids[centroids.len() - 1];
+        let last_mid = total - last.weight / 2.0;
+        let frac = ((target - last_mid) / (last.weight / 2.0)).min(1.0);
+        Some(last.mean + (self.max - last.mean) * frac)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_tdigest_quantile() {
+        let mut digest = TDigest::default();
+        assert_eq!(digest.quantile(0.5), None);
+
+        // small inputs are exact
+        [3.0, 1.0, 2.0].into_iter().for_each(|v| digest.add(v));
+        assert_eq!(digest.quantile(0.5), Some(2.0));
+
+        // merged partial digests estimate the quantiles of the whole input
+        let mut left = TDigest::default();
+        let mut right = TDigest::default();
+        (0..50_000).for_each(|v| left.add(v as f64));
+        (50_000..100_000).for_each(|v| right.add(v as f64));
+        left.merge(&right);
+        assert_eq!(left.count(), 100_000.0);
+        for q in [0.01, 0.25, 0.5, 0.75, 0.99] {
+            let estimate = left.quantile(q).unwrap();
+            assert!((estimate - q * 100_000.0).abs() < 100.0, "{q}: {estimate}");
+        }
+    }
+}
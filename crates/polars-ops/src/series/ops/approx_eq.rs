@@ -0,0 +1,124 @@
+use num_traits::{Float, NumCast};
+use polars_core::prelude::arity::binary_elementwise_values;
+use polars_core::prelude::*;
+
+fn approx_eq_ca<T>(
+    left: &ChunkedArray<T>,
+    right: &ChunkedArray<T>,
+    rel_tol: f64,
+    abs_tol: f64,
+) -> BooleanChunked
+where
+    T: PolarsFloatType,
+    T::Native: Float,
+{
+    let rel_tol: T::Native = NumCast::from(rel_tol).unwrap();
+    let abs_tol: T::Native = NumCast::from(abs_tol).unwrap();
+    binary_elementwise_values(left, right, |a: T::Native, b: T::Native| {
+        // the equality check covers the infinities, the other infinities are never close
+        a == b
+            || (a.is_finite()
+                && b.is_finite()
+                && (a - b).abs() <= (rel_tol * a.abs().max(b.abs())).max(abs_tol))
+    })
+}
+
+/// Check if the values of `s` and `other` are approximately equal, e.g. to compare the results
+/// of numerical computations.
+///
+/// Two values are equal if `|a - b| <= max(rel_tol * max(|a|, |b|), abs_tol)`, like Python's
+/// `math.isclose`. NaN is not equal to anything and a null in either series gives a null.
+/// The data is compared as `Float64`, unless both series are `Float32`.
+pub fn approx_eq(
+    s: &Series,
+    other: &Series,
+    rel_tol: f64,
+    abs_tol: f64,
+) -> PolarsResult<BooleanChunked> {
+    polars_ensure!(
+        rel_tol >= 0.0 && abs_tol >= 0.0,
+        ComputeError: "the tolerances of `approx_eq` must be non-negative"
+    );
+    polars_ensure!(
+        s.len() == other.len(),
+        ShapeMismatch: "cannot compare series of lengths {} and {}", s.len(), other.len()
+    );
+    polars_ensure!(
+        s.dtype().is_numeric() && other.dtype().is_numeric(),
+        InvalidOperation: "`approx_eq` operation not supported for dtypes `{}` and `{}`",
+        s.dtype(), other.dtype()
+    );
+    let out = match (s.dtype(), other.dtype()) {
+        (DataType::Float32, DataType::Float32) => {
+            approx_eq_ca(s.f32()?, other.f32()?, rel_tol, abs_tol)
+        },
+        _ => {
+            let s = s.cast(&DataType::Float64)?;
+            let other = other.cast(&DataType::Float64)?;
+            approx_eq_ca(s.f64()?, other.f64()?, rel_tol, abs_tol)
+        },
+    };
+    Ok(out.with_name(s.name()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_approx_eq() -> PolarsResult<()> {
+        let a = Series::new(
+            "a",
+            &[
+                Some(1.0),
+                Some(1.0),
+                None,
+                Some(f64::NAN),
+                Some(f64::INFINITY),
+                Some(0.0),
+            ],
+        );
+        let b = Series::new(
+            "b",
+            &[
+                Some(1.0 + 1e-12),
+                Some(1.1),
+                Some(1.0),
+                Some(f64::NAN),
+                Some(f64::INFINITY),
+                Some(1e-12),
+            ],
+        );
+        let out = approx_eq(&a, &b, 1e-9, 0.0)?;
+        assert_eq!(
+            out.into_iter().collect::<Vec<_>>(),
+            &[
+                Some(true),
+                Some(false),
+                None,
+                Some(false),
+                Some(true),
+                Some(false)
+            ]
+        );
+        let out = approx_eq(&a, &b, 1e-9, 1e-9)?;
+        assert_eq!(out.get(5), Some(true));
+
+        let ints = Series::new("ints", &[1, 2, 3]);
+        let floats = Series::new("floats", &[1.0f32, 2.05, 3.0]);
+        let out = approx_eq(&ints, &floats, 0.01, 0.0)?;
+        assert_eq!(
+            out.into_iter().collect::<Vec<_>>(),
+            &[Some(true), Some(false), Some(true)]
+        );
+        assert!(approx_eq(&ints, &floats, -1.0, 0.0).is_err());
+
+        let df = df!["x" => [Some(1.0), None, Some(3.0)], "y" => ["a", "b", "c"]]?;
+        let other = df!["x" => [Some(1.0 + 1e-12), None, Some(3.0)], "y" => ["a", "b", "c"]]?;
+        assert!(df.approx_equals(&other, 1e-9, 0.0)?);
+        assert!(!df.approx_equals(&other, 0.0, 0.0)?);
+        assert!(!df.approx_equals(&df.select(["x"])?, 1e-9, 0.0)?);
+        Ok(())
+    }
+}
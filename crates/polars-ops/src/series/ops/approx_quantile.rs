@@ -0,0 +1,37 @@
+use polars_core::prelude::*;
+
+use crate::series::TDigest;
+
+/// Approximate the `quantile` of the values.
+///
+/// This is done with a t-digest, which needs bounded memory regardless of the length of
+/// the input. NaN and null values are ignored.
+pub fn approx_quantile(s: &Series, quantile: f64) -> PolarsResult<Series> {
+    polars_ensure!(s.dtype().is_numeric(), opq = approx_quantile, s.dtype());
+    polars_ensure!(
+        (0.0..=1.0).contains(&quantile),
+        ComputeError: "quantile should be between 0.0 and 1.0, got {}", quantile
+    );
+    let s = s.cast(&DataType::Float64)?;
+    let mut digest = TDigest::default();
+    s.f64()?.into_iter().flatten().for_each(|v| digest.add(v));
+
+    Ok(Series::new(s.name(), &[digest.quantile(quantile)]))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_approx_quantile() -> PolarsResult<()> {
+        let s = Series::new("a", &[Some(1i32), None, Some(3), Some(2)]);
+        let out = approx_quantile(&s, 0.5)?;
+        assert_eq!(out.f64()?.get(0), Some(2.0));
+
+        let s = Series::new_null("a", 3).cast(&DataType::Int32)?;
+        let out = approx_quantile(&s, 0.5)?;
+        assert_eq!(out.f64()?.get(0), None);
+        Ok(())
+    }
+}
@@ -1,8 +1,10 @@
 use argminmax::ArgMinMax;
 use arrow::array::Array;
 use polars_arrow::bit_util::*;
+use polars_core::frame::group_by::GroupsProxy;
 use polars_core::series::IsSorted;
-use polars_core::with_match_physical_numeric_polars_type;
+use polars_core::{with_match_physical_numeric_polars_type, POOL};
+use rayon::prelude::*;
 
 use super::*;
 
@@ -72,6 +74,90 @@ impl ArgAgg for Series {
     }
 }
 
+/// Get the index of the minimal value of every group.
+///
+/// The indices point into `s`, not into the group. Groups that only contain nulls get a null
+/// index, as do all groups if the data type has no order. Categoricals are compared by their
+/// ordering: lexical or by the physical category index.
+pub fn group_arg_min(s: &Series, groups: &GroupsProxy) -> IdxCa {
+    group_arg_min_max(s, groups, false)
+}
+
+/// Get the index of the maximal value of every group, see [`group_arg_min`].
+pub fn group_arg_max(s: &Series, groups: &GroupsProxy) -> IdxCa {
+    group_arg_min_max(s, groups, true)
+}
+
+fn group_arg_min_max(s: &Series, groups: &GroupsProxy, max: bool) -> IdxCa {
+    use DataType::*;
+    #[cfg(feature = "dtype-categorical")]
+    if let Ok(ca) = s.categorical() {
+        if ca.uses_lexical_ordering() {
+            let rev_map = ca.get_rev_map();
+            let cats = ca.logical().rechunk();
+            let arr = cats.downcast_iter().next().unwrap();
+            return group_arg_extremum(groups, max, |i| {
+                arr.is_valid(i).then(|| rev_map.get(arr.value(i)))
+            });
+        }
+    }
+    let s = s.to_physical_repr().rechunk();
+    match s.dtype() {
+        Utf8 => {
+            let arr = s.utf8().unwrap().downcast_iter().next().unwrap();
+            group_arg_extremum(groups, max, |i| arr.is_valid(i).then(|| arr.value(i)))
+        },
+        Boolean => {
+            let arr = s.bool().unwrap().downcast_iter().next().unwrap();
+            group_arg_extremum(groups, max, |i| arr.is_valid(i).then(|| arr.value(i)))
+        },
+        dt if dt.is_numeric() => {
+            with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
+                let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+                let arr = ca.downcast_iter().next().unwrap();
+                group_arg_extremum(groups, max, |i| arr.is_valid(i).then(|| arr.value(i)))
+            })
+        },
+        _ => IdxCa::full_null(s.name(), groups.len()),
+    }
+}
+
+/// Find the first position of the extremum in every group in a single pass over its indices.
+fn group_arg_extremum<T, F>(groups: &GroupsProxy, max: bool, get: F) -> IdxCa
+where
+    T: PartialOrd,
+    F: Fn(usize) -> Option<T> + Sync,
+{
+    let arg_extremum = |idx: &mut dyn Iterator<Item = IdxSize>| {
+        let mut out: Option<(IdxSize, T)> = None;
+        for i in idx {
+            if let Some(val) = get(i as usize) {
+                let better = match &out {
+                    None => true,
+                    Some((_, acc)) if max => val > *acc,
+                    Some((_, acc)) => val < *acc,
+                };
+                if better {
+                    out = Some((i, val));
+                }
+            }
+        }
+        out.map(|(i, _)| i)
+    };
+
+    POOL.install(|| match groups {
+        GroupsProxy::Idx(groups) => groups
+            .all()
+            .par_iter()
+            .map(|idx| arg_extremum(&mut idx.iter().copied()))
+            .collect(),
+        GroupsProxy::Slice { groups, .. } => groups
+            .par_iter()
+            .map(|&[first, len]| arg_extremum(&mut (first..first + len)))
+            .collect(),
+    })
+}
+
 pub(crate) fn arg_max_bool(ca: &BooleanChunked) -> Option<usize> {
     if ca.is_empty() || ca.null_count() == ca.len() {
         None
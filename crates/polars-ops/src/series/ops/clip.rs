@@ -149,3 +149,53 @@ pub fn clip_min(s: &Series, min: &Series) -> PolarsResult<Series> {
         dt => polars_bail!(opq = clippy_min, dt),
     }
 }
+
+/// Clamp underlying values to the `lower` and `upper` quantiles of `s`.
+///
+/// The quantiles are computed once, after which the values are clamped in a single pass.
+pub fn clip_quantile(
+    s: &Series,
+    lower: f64,
+    upper: f64,
+    interpol: QuantileInterpolOptions,
+) -> PolarsResult<Series> {
+    polars_ensure!(s.dtype().is_numeric(), InvalidOperation: "clip_quantile is only supported on numeric types, got {}", s.dtype());
+    polars_ensure!(
+        (0.0..=1.0).contains(&lower) && (0.0..=1.0).contains(&upper) && lower <= upper,
+        ComputeError: "quantiles must be between 0.0 and 1.0 and `lower` may not exceed `upper`, got {} and {}", lower, upper
+    );
+    let min = s.quantile_as_series(lower, interpol)?;
+    let max = s.quantile_as_series(upper, interpol)?;
+    clip(s, &min, &max)
+}
+
+/// Winsorize `s`: replace the values below the `lower` quantile and above the `upper`
+/// quantile by the nearest remaining value. E.g. `(0.05, 0.95)` limits the values to the
+/// 5th and 95th percentile.
+pub fn winsorize(s: &Series, lower: f64, upper: f64) -> PolarsResult<Series> {
+    clip_quantile(s, lower, upper, QuantileInterpolOptions::Nearest)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_winsorize() -> PolarsResult<()> {
+        let s = Series::new("a", (0..10).collect::<Vec<i32>>());
+        let out = winsorize(&s, 0.1, 0.8)?;
+        let expected = Series::new("a", &[1, 1, 2, 3, 4, 5, 6, 7, 7, 7]);
+        assert!(out.series_equal(&expected));
+
+        // only the tails are replaced, the interior values survive
+        let s = Series::new("a", (0..20).collect::<Vec<i32>>());
+        let out = winsorize(&s, 0.05, 0.95)?;
+        let mut expected = (0..20).collect::<Vec<i32>>();
+        expected[0] = 1;
+        expected[19] = 18;
+        assert!(out.series_equal(&Series::new("a", expected)));
+
+        assert!(clip_quantile(&s, 0.6, 0.4, QuantileInterpolOptions::Linear).is_err());
+        Ok(())
+    }
+}
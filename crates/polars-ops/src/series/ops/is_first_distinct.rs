@@ -5,6 +5,7 @@ use arrow::bitmap::MutableBitmap;
 use polars_arrow::bit_util::*;
 use polars_arrow::utils::CustomIterTools;
 use polars_core::prelude::*;
+use polars_core::utils::NoNull;
 use polars_core::with_match_physical_integer_polars_type;
 fn is_first_distinct_numeric<T>(ca: &ChunkedArray<T>) -> BooleanChunked
 where
@@ -151,3 +152,20 @@ pub fn is_first_distinct(s: &Series) -> PolarsResult<BooleanChunked> {
     };
     Ok(out)
 }
+
+/// Get the number of distinct values up to and including every element of `s`.
+/// Null is counted as a distinct value.
+pub fn cum_n_unique(s: &Series) -> PolarsResult<IdxCa> {
+    let first = is_first_distinct(s)?;
+    let mut n_unique = 0 as IdxSize;
+    let out: NoNull<IdxCa> = first
+        .into_iter()
+        .map(|first| {
+            n_unique += (first == Some(true)) as IdxSize;
+            n_unique
+        })
+        .collect();
+    let mut out = out.into_inner();
+    out.rename(s.name());
+    Ok(out)
+}
@@ -1,4 +1,10 @@
+#[cfg(feature = "agg_state")]
+mod agg_state;
 mod approx_algo;
+#[cfg(feature = "approx_eq")]
+mod approx_eq;
+#[cfg(feature = "approx_quantile")]
+mod approx_quantile;
 #[cfg(feature = "approx_unique")]
 mod approx_unique;
 mod arg_min_max;
@@ -21,22 +27,35 @@ mod is_last_distinct;
 mod is_unique;
 #[cfg(feature = "log")]
 mod log;
+mod nan_to_null;
 #[cfg(feature = "rank")]
 mod rank;
+#[cfg(feature = "rejects")]
+mod rejects;
+#[cfg(feature = "reservoir_sample")]
+mod reservoir_sample;
 #[cfg(feature = "rle")]
 mod rle;
 #[cfg(feature = "rolling_window")]
 mod rolling;
+#[cfg(feature = "scale")]
+mod scale;
 #[cfg(feature = "search_sorted")]
 mod search_sorted;
 #[cfg(feature = "to_dummies")]
 mod to_dummies;
 mod various;
 
+#[cfg(feature = "agg_state")]
+pub use agg_state::*;
 pub use approx_algo::*;
+#[cfg(feature = "approx_eq")]
+pub use approx_eq::*;
+#[cfg(feature = "approx_quantile")]
+pub use approx_quantile::*;
 #[cfg(feature = "approx_unique")]
 pub use approx_unique::*;
-pub use arg_min_max::ArgAgg;
+pub use arg_min_max::{group_arg_max, group_arg_min, ArgAgg};
 pub use clip::*;
 #[cfg(feature = "cutqcut")]
 pub use cut::*;
@@ -56,13 +75,20 @@ pub use is_last_distinct::*;
 pub use is_unique::*;
 #[cfg(feature = "log")]
 pub use log::*;
+pub use nan_to_null::*;
 use polars_core::prelude::*;
 #[cfg(feature = "rank")]
 pub use rank::*;
+#[cfg(feature = "rejects")]
+pub use rejects::*;
+#[cfg(feature = "reservoir_sample")]
+pub use reservoir_sample::*;
 #[cfg(feature = "rle")]
 pub use rle::*;
 #[cfg(feature = "rolling_window")]
 pub use rolling::*;
+#[cfg(feature = "scale")]
+pub use scale::*;
 #[cfg(feature = "search_sorted")]
 pub use search_sorted::*;
 #[cfg(feature = "to_dummies")]
@@ -0,0 +1,17 @@
+use polars_core::prelude::*;
+
+/// Replace the NaN values of a float series by null. Other data is returned unchanged.
+pub fn nan_to_null(s: &Series) -> PolarsResult<Series> {
+    let out = match s.dtype() {
+        DataType::Float32 => s
+            .f32()?
+            .apply_generic(|opt_v| opt_v.filter(|v| !v.is_nan()))
+            .into_series(),
+        DataType::Float64 => s
+            .f64()?
+            .apply_generic(|opt_v| opt_v.filter(|v| !v.is_nan()))
+            .into_series(),
+        _ => s.clone(),
+    };
+    Ok(out)
+}
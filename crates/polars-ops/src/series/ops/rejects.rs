@@ -0,0 +1,242 @@
+use std::sync::{Arc, Mutex};
+
+use polars_core::utils::{accumulate_dataframes_vertical, NoNull};
+
+use super::*;
+
+const ROW_NR: &str = "row_nr";
+const COLUMN: &str = "column";
+const VALUE: &str = "value";
+const ERROR: &str = "error";
+
+/// Rows that failed an operation, collected instead of failing the operation as a whole.
+///
+/// A collector can be shared by several operations; it is cheap to clone and all clones
+/// collect into the same frame. The rejects have the columns `row_nr`, `column`, `value`
+/// (the input value formatted as string) and `error`.
+///
+/// The collector keeps a row count per column, so when a column is processed in batches, e.g.
+/// a scan over several files, the row numbers continue over the batches.
+#[derive(Clone, Debug, Default)]
+pub struct Rejects {
+    frames: Arc<Mutex<Vec<DataFrame>>>,
+    offsets: Arc<Mutex<PlHashMap<String, IdxSize>>>,
+}
+
+impl Rejects {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&self, rejects: DataFrame) {
+        if rejects.height() > 0 {
+            self.frames.lock().unwrap().push(rejects)
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.lock().unwrap().is_empty()
+    }
+
+    /// Take the collected rejects, leaving the collector empty and restarting the row numbers.
+    pub fn take(&self) -> PolarsResult<DataFrame> {
+        let frames = std::mem::take(&mut *self.frames.lock().unwrap());
+        self.offsets.lock().unwrap().clear();
+        if frames.is_empty() {
+            Ok(empty_rejects())
+        } else {
+            accumulate_dataframes_vertical(frames)
+        }
+    }
+
+    /// Reserve `len` row numbers of `column`. Returns the first of them and whether the column
+    /// was seen before.
+    fn reserve_rows(&self, column: &str, len: usize) -> (IdxSize, bool) {
+        let mut offsets = self.offsets.lock().unwrap();
+        match offsets.get_mut(column) {
+            Some(offset) => {
+                let start = *offset;
+                *offset += len as IdxSize;
+                (start, true)
+            },
+            None => {
+                offsets.insert(column.to_string(), len as IdxSize);
+                (0, false)
+            },
+        }
+    }
+
+    /// Cast `s` to `dtype` like [`cast_collect_rejects`] and collect the rejects. The row
+    /// numbers continue after the rows of the previous batches of the column.
+    pub fn cast(&self, s: &Series, dtype: &DataType) -> PolarsResult<Series> {
+        let (offset, _) = self.reserve_rows(s.name(), s.len());
+        let (out, rejects) = cast_with_offset(s, dtype, offset)?;
+        self.push(rejects);
+        Ok(out)
+    }
+
+    /// Cast the columns of `df` to the data types of `schema` and collect the values that
+    /// cannot be cast, see [`Rejects::cast`].
+    ///
+    /// The output has the columns of `schema` in schema order. A column of the schema that is
+    /// missing in `df` is added as nulls and a column of `df` that is not in the schema is
+    /// dropped. Both are collected once, as a reject without row number and value. A renamed
+    /// column shows up as a dropped and an added column.
+    pub fn cast_frame(&self, df: &DataFrame, schema: &Schema) -> PolarsResult<DataFrame> {
+        let height = df.height();
+        let mut columns = Vec::with_capacity(schema.len());
+        for (name, dtype) in schema.iter() {
+            match df.column(name) {
+                Ok(s) => columns.push(self.cast(s, dtype)?),
+                Err(_) => {
+                    if !self.reserve_rows(name, height).1 {
+                        self.push(column_reject(name, "column not found, filled with nulls"));
+                    }
+                    columns.push(Series::full_null(name, height, dtype));
+                },
+            }
+        }
+        for s in df.get_columns() {
+            if !schema.contains(s.name()) && !self.reserve_rows(s.name(), height).1 {
+                self.push(column_reject(s.name(), "column not in schema, dropped"));
+            }
+        }
+        Ok(DataFrame::new_no_checks(columns))
+    }
+}
+
+fn column_reject(name: &str, error: &str) -> DataFrame {
+    DataFrame::new_no_checks(vec![
+        Series::full_null(ROW_NR, 1, &IDX_DTYPE),
+        Series::new(COLUMN, &[name]),
+        Series::full_null(VALUE, 1, &DataType::Utf8),
+        Series::new(ERROR, &[error]),
+    ])
+}
+
+fn empty_rejects() -> DataFrame {
+    DataFrame::new_no_checks(vec![
+        Series::new_empty(ROW_NR, &IDX_DTYPE),
+        Series::new_empty(COLUMN, &DataType::Utf8),
+        Series::new_empty(VALUE, &DataType::Utf8),
+        Series::new_empty(ERROR, &DataType::Utf8),
+    ])
+}
+
+/// Cast `s` to `dtype`. Values that cannot be cast become null and are returned as rejects,
+/// instead of failing the cast as a whole as a strict cast does.
+pub fn cast_collect_rejects(s: &Series, dtype: &DataType) -> PolarsResult<(Series, DataFrame)> {
+    cast_with_offset(s, dtype, 0)
+}
+
+fn cast_with_offset(
+    s: &Series,
+    dtype: &DataType,
+    offset: IdxSize,
+) -> PolarsResult<(Series, DataFrame)> {
+    let out = s.cast(dtype)?;
+    let failed = &s.is_not_null() & &out.is_null();
+    if !failed.any() {
+        return Ok((out, empty_rejects()));
+    }
+
+    let row_nr: NoNull<IdxCa> = failed
+        .into_iter()
+        .enumerate()
+        .filter_map(|(idx, failed)| (failed == Some(true)).then_some(offset + idx as IdxSize))
+        .collect();
+    let n_rejects = row_nr.len();
+    let values = s.filter(&failed)?;
+    let values = values
+        .cast(&DataType::Utf8)
+        .unwrap_or_else(|_| Series::full_null("", n_rejects, &DataType::Utf8));
+    let error = format!("conversion from `{}` to `{}` failed", s.dtype(), dtype);
+
+    let rejects = DataFrame::new_no_checks(vec![
+        row_nr.into_inner().with_name(ROW_NR).into_series(),
+        Utf8Chunked::full(COLUMN, s.name(), n_rejects).into_series(),
+        values.with_name(VALUE),
+        Utf8Chunked::full(ERROR, &error, n_rejects).into_series(),
+    ]);
+    Ok((out, rejects))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_cast_collect_rejects() -> PolarsResult<()> {
+        let s = Series::new("a", &[Some("1"), Some("x"), None, Some("3"), Some("1.5")]);
+        let (out, rejects) = cast_collect_rejects(&s, &DataType::Int64)?;
+        assert_eq!(Vec::from(out.i64()?), &[Some(1), None, None, Some(3), None]);
+        assert_eq!(
+            Vec::from(rejects.column("row_nr")?.idx()?),
+            &[Some(1), Some(4)]
+        );
+        assert_eq!(
+            Vec::from(rejects.column("value")?.utf8()?),
+            &[Some("x"), Some("1.5")]
+        );
+
+        let collector = Rejects::new();
+        collector.push(rejects);
+        let (_, rejects) = cast_collect_rejects(&s.slice(0, 1), &DataType::Int64)?;
+        collector.push(rejects);
+        assert_eq!(collector.take()?.height(), 2);
+        assert!(collector.is_empty());
+        assert_eq!(collector.take()?.width(), 4);
+        Ok(())
+    }
+
+    #[test]
+    fn test_rejects_continue_over_batches() -> PolarsResult<()> {
+        let s = Series::new("a", &["x", "1", "y", "2", "z"]);
+        let collector = Rejects::new();
+        collector.cast(&s.slice(0, 2), &DataType::Int64)?;
+        collector.cast(&s.slice(2, 3), &DataType::Int64)?;
+        let rejects = collector.take()?;
+        assert_eq!(
+            Vec::from(rejects.column("row_nr")?.idx()?),
+            &[Some(0), Some(2), Some(4)]
+        );
+
+        // taking the rejects restarts the row numbers
+        collector.cast(&s.slice(2, 1), &DataType::Int64)?;
+        assert_eq!(
+            Vec::from(collector.take()?.column("row_nr")?.idx()?),
+            &[Some(0)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_cast_frame_collect_rejects() -> PolarsResult<()> {
+        let schema = Schema::from_iter([
+            Field::new("a", DataType::Int64),
+            Field::new("c", DataType::Float64),
+        ]);
+        let collector = Rejects::new();
+        for batch in [
+            df!["a" => ["1", "x"], "b" => [1, 2]]?,
+            df!["a" => ["y", "4"], "b" => [3, 4]]?,
+        ] {
+            let out = collector.cast_frame(&batch, &schema)?;
+            assert_eq!(out.get_column_names(), &["a", "c"]);
+            assert_eq!(out.column("c")?.dtype(), &DataType::Float64);
+            assert_eq!(out.column("c")?.null_count(), 2);
+        }
+
+        let rejects = collector.take()?;
+        assert_eq!(
+            Vec::from(rejects.column("row_nr")?.idx()?),
+            &[Some(1), None, None, Some(2)]
+        );
+        assert_eq!(
+            Vec::from(rejects.column("column")?.utf8()?),
+            &[Some("a"), Some("c"), Some("b"), Some("a")]
+        );
+        assert_eq!(rejects.column("value")?.null_count(), 2);
+        Ok(())
+    }
+}
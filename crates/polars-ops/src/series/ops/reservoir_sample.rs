@@ -0,0 +1,32 @@
+use polars_core::prelude::*;
+
+use crate::series::Reservoir;
+
+/// Take a uniform sample of at most `n` values, without replacement.
+///
+/// The sample is drawn with reservoir sampling, so the memory needed depends on `n` and not
+/// on the length of the input.
+pub fn reservoir_sample(s: &Series, n: usize, seed: Option<u64>) -> PolarsResult<Series> {
+    let mut reservoir = Reservoir::new(n, seed);
+    (0..s.len() as IdxSize).for_each(|idx| reservoir.push(idx));
+    let idx = IdxCa::from_vec("", reservoir.into_items());
+    s.take(&idx)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_reservoir_sample() -> PolarsResult<()> {
+        let s = Series::new("a", (0..100).collect::<Vec<i32>>());
+        let out = reservoir_sample(&s, 10, Some(0))?;
+        assert_eq!(out.len(), 10);
+        assert_eq!(out.n_unique()?, 10);
+        assert!(out.series_equal(&reservoir_sample(&s, 10, Some(0))?));
+
+        let out = reservoir_sample(&s.head(Some(3)), 10, None)?;
+        assert!(out.series_equal(&s.head(Some(3))));
+        Ok(())
+    }
+}
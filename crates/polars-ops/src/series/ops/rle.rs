@@ -1,11 +1,19 @@
 use polars_core::prelude::*;
+use polars_core::series::IsSorted;
 
 /// Get the lengths of runs of identical values.
 pub fn rle(s: &Series) -> PolarsResult<Series> {
+    if s.is_empty() {
+        let outvals = vec![
+            Series::new_empty("lengths", &DataType::Int32),
+            Series::new_empty("values", s.dtype()),
+        ];
+        return Ok(StructChunked::new("rle", &outvals)?.into_series());
+    }
     let (s1, s2) = (s.slice(0, s.len() - 1), s.slice(1, s.len()));
     let s_neq = s1.not_equal_missing(&s2)?;
     let n_runs = s_neq.sum().unwrap() + 1;
-    let mut lengths = Vec::with_capacity(n_runs as usize);
+    let mut lengths = Vec::<i32>::with_capacity(n_runs as usize);
     lengths.push(1);
     let mut vals = Series::new_empty("values", s.dtype());
     let vals = vals.extend(&s.head(Some(1)))?.extend(&s2.filter(&s_neq)?)?;
@@ -23,7 +31,29 @@ pub fn rle(s: &Series) -> PolarsResult<Series> {
     Ok(StructChunked::new("rle", &outvals)?.into_series())
 }
 
+/// Expand the runs produced by [`rle`] back into the original values.
+pub fn rle_decode(s: &Series) -> PolarsResult<Series> {
+    let ca = s.struct_()?;
+    let [lengths, values] = ca.fields() else {
+        polars_bail!(ComputeError: "rle_decode expects a struct with the fields 'lengths' and 'values'")
+    };
+    let lengths = lengths.cast(&IDX_DTYPE)?;
+    let lengths = lengths.idx()?;
+    polars_ensure!(lengths.null_count() == 0, ComputeError: "run lengths must be positive integers");
+
+    let mut idx = Vec::with_capacity(lengths.sum().unwrap_or(0) as usize);
+    for (i, len) in lengths.into_no_null_iter().enumerate() {
+        idx.extend(std::iter::repeat(i as IdxSize).take(len as usize));
+    }
+    let idx = IdxCa::from_vec("", idx);
+    // SAFETY: every index points to a run.
+    let out = unsafe { values.take_unchecked(&idx) };
+    Ok(out.with_name(s.name()))
+}
+
 /// Similar to `rle`, but maps values to run IDs.
+///
+/// The run IDs are sorted, so grouping by them creates contiguous groups.
 pub fn rle_id(s: &Series) -> PolarsResult<Series> {
     if s.len() == 0 {
         return Ok(Series::new_empty("id", &DataType::UInt32));
@@ -40,5 +70,7 @@ pub fn rle_id(s: &Series) -> PolarsResult<Series> {
             out.push(last);
         }
     }
-    Ok(Series::from_vec("id", out))
+    let mut out = UInt32Chunked::from_vec("id", out);
+    out.set_sorted_flag(IsSorted::Ascending);
+    Ok(out.into_series())
 }
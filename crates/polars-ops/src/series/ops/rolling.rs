@@ -1,59 +1,112 @@
 use polars_core::prelude::*;
 #[cfg(feature = "moment")]
 use {
-    polars_core::export::num::{self, Float, FromPrimitive},
-    polars_core::utils::with_unstable_series,
-    std::ops::SubAssign,
+    arrow::array::PrimitiveArray, polars_arrow::kernels::rolling,
+    polars_core::with_match_physical_float_polars_type, std::sync::Arc,
 };
 
 use crate::series::ops::SeriesSealed;
 
 #[cfg(feature = "moment")]
-fn rolling_skew<T>(
+#[allow(clippy::type_complexity)]
+fn rolling_moment<T>(
     ca: &ChunkedArray<T>,
     window_size: usize,
-    bias: bool,
+    fn_params: DynArgs,
+    rolling_fn: &dyn Fn(&[T::Native], &RollingOptionsFixedWindow) -> PolarsResult<ArrayRef>,
+    rolling_fn_nulls: &dyn Fn(&PrimitiveArray<T::Native>, &RollingOptionsFixedWindow) -> ArrayRef,
 ) -> PolarsResult<ChunkedArray<T>>
 where
-    ChunkedArray<T>: IntoSeries,
     T: PolarsFloatType,
-    T::Native: Float + IsFloat + SubAssign + num::pow::Pow<T::Native, Output = T::Native>,
 {
-    with_unstable_series(ca.dtype(), |us| {
-        ca.rolling_map_float(window_size, |arr| {
-            let arr = unsafe { arr.chunks_mut().get_mut(0).unwrap() };
-
-            us.with_array(arr, |us| {
-                us.as_ref()
-                    .skew(bias)
-                    .unwrap()
-                    .map(|flt| T::Native::from_f64(flt).unwrap())
-            })
-        })
-    })
+    polars_ensure!(window_size > 0, ComputeError: "window size should be strictly positive");
+    let options = RollingOptionsFixedWindow {
+        window_size,
+        min_periods: 1,
+        fn_params,
+        ..Default::default()
+    };
+    let ca = ca.rechunk();
+    let arr = ca.downcast_iter().next().unwrap();
+    let out = match arr.null_count() {
+        0 => rolling_fn(arr.values().as_slice(), &options)?,
+        _ => rolling_fn_nulls(arr, &options),
+    };
+    // SAFETY: the kernels output the data type of the input.
+    let out = unsafe { ChunkedArray::<T>::from_chunks(ca.name(), vec![out]) };
+
+    // The window must be filled before the first value, the nulls in it are skipped.
+    let n = std::cmp::min(window_size - 1, ca.len());
+    let mut head = ChunkedArray::full_null(ca.name(), n);
+    head.append(&out.slice(n as i64, ca.len() - n));
+    Ok(head)
 }
 
 pub trait RollingSeries: SeriesSealed {
+    /// Compute the skewness of the values in a window of `window_size` values, see
+    /// [`Series::skew`].
     #[cfg(feature = "moment")]
     fn rolling_skew(&self, window_size: usize, bias: bool) -> PolarsResult<Series> {
         let s = self.as_series();
+        polars_ensure!(s.dtype().is_numeric(), opq = rolling_skew, s.dtype());
+        let s = s.to_float()?;
+        let params: DynArgs = Some(Arc::new(RollingSkewParams { bias }));
+        with_match_physical_float_polars_type!(s.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            rolling_moment(
+                ca,
+                window_size,
+                params,
+                &rolling::no_nulls::rolling_skew,
+                &rolling::nulls::rolling_skew,
+            )
+            .map(|ca| ca.into_series())
+        })
+    }
 
-        match s.dtype() {
-            DataType::Float64 => {
-                let ca = s.f64().unwrap();
-                rolling_skew(ca, window_size, bias).map(|ca| ca.into_series())
-            },
-            DataType::Float32 => {
-                let ca = s.f32().unwrap();
-                rolling_skew(ca, window_size, bias).map(|ca| ca.into_series())
-            },
-            dt if dt.is_numeric() => {
-                let s = s.cast(&DataType::Float64).unwrap();
-                s.rolling_skew(window_size, bias)
-            },
-            dt => polars_bail!(opq = rolling_skew, dt),
-        }
+    /// Compute the kurtosis of the values in a window of `window_size` values, see
+    /// [`Series::kurtosis`].
+    #[cfg(feature = "moment")]
+    fn rolling_kurtosis(
+        &self,
+        window_size: usize,
+        fisher: bool,
+        bias: bool,
+    ) -> PolarsResult<Series> {
+        let s = self.as_series();
+        polars_ensure!(s.dtype().is_numeric(), opq = rolling_kurtosis, s.dtype());
+        let s = s.to_float()?;
+        let params: DynArgs = Some(Arc::new(RollingKurtosisParams { fisher, bias }));
+        with_match_physical_float_polars_type!(s.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            rolling_moment(
+                ca,
+                window_size,
+                params,
+                &rolling::no_nulls::rolling_kurtosis,
+                &rolling::nulls::rolling_kurtosis,
+            )
+            .map(|ca| ca.into_series())
+        })
     }
 }
 
 impl RollingSeries for Series {}
+
+#[cfg(test)]
+#[cfg(feature = "moment")]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rolling_kurtosis() -> PolarsResult<()> {
+        let s = Series::new("a", &[Some(1.0f64), Some(2.0), None, Some(4.0), Some(8.0)]);
+        let out = s.rolling_kurtosis(4, true, true)?;
+        let out = out.f64()?;
+        assert_eq!(out.null_count(), 3);
+        // the window of the last value skips the null
+        let expected = Series::new("", &[2.0f64, 4.0, 8.0]).kurtosis(true, true)?;
+        assert!((out.get(4).unwrap() - expected.unwrap()).abs() < 1e-12);
+        Ok(())
+    }
+}
@@ -0,0 +1,138 @@
+use num_traits::{Float, NumCast};
+use polars_core::prelude::*;
+use polars_core::with_match_physical_float_polars_type;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The method used by [`scale`].
+///
+/// If the scale of the values is zero, e.g. because all values are equal, the values are only
+/// centered, so every non-null value becomes `0`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ScaleMethod {
+    /// Subtract the mean and divide by the standard deviation (z-score).
+    Standard { ddof: u8 },
+    /// Subtract the minimum and divide by the range, mapping the values to `[0, 1]`.
+    MinMax,
+    /// Subtract the median and divide by the interquartile range.
+    Robust,
+}
+
+/// Get the center and the scale of the values in `ca`.
+fn scale_params<T>(ca: &ChunkedArray<T>, method: ScaleMethod) -> PolarsResult<(f64, f64)>
+where
+    T: PolarsFloatType,
+    T::Native: Float,
+    ChunkedArray<T>: ChunkQuantile<T::Native>,
+{
+    let params = match method {
+        ScaleMethod::Standard { ddof } => {
+            // Welford's algorithm computes the mean and variance in a single pass.
+            let mut count = 0.0;
+            let mut mean = 0.0;
+            let mut m2 = 0.0;
+            for v in ca.into_iter().flatten() {
+                let v = v.to_f64().unwrap();
+                count += 1.0;
+                let delta = v - mean;
+                mean += delta / count;
+                m2 += delta * (v - mean);
+            }
+            // Equal values have no spread, even if there are too few of them for the ddof.
+            let std = if m2 == 0.0 {
+                0.0
+            } else {
+                (m2 / (count - ddof as f64)).sqrt()
+            };
+            (mean, std)
+        },
+        ScaleMethod::MinMax => {
+            let (min, max) = ca.into_iter().flatten().fold(
+                (f64::INFINITY, f64::NEG_INFINITY),
+                |(min, max), v| {
+                    let v = v.to_f64().unwrap();
+                    (min.min(v), max.max(v))
+                },
+            );
+            (min, max - min)
+        },
+        ScaleMethod::Robust => {
+            let quantile = |q| {
+                ca.quantile(q, QuantileInterpolOptions::Linear)
+                    .map(|v| v.and_then(|v| v.to_f64()).unwrap_or(f64::NAN))
+            };
+            let median = quantile(0.5)?;
+            (median, quantile(0.75)? - quantile(0.25)?)
+        },
+    };
+    Ok(params)
+}
+
+/// Scale the values of `s` with the given [`ScaleMethod`].
+///
+/// Integers are cast to `Float64`, `Float32` stays `Float32`.
+pub fn scale(s: &Series, method: ScaleMethod) -> PolarsResult<Series> {
+    polars_ensure!(
+        s.dtype().is_numeric(),
+        InvalidOperation: "scaling is only supported on numeric types, got {}", s.dtype()
+    );
+    let s = match s.dtype() {
+        DataType::Float32 | DataType::Float64 => s.clone(),
+        _ => s.cast(&DataType::Float64)?,
+    };
+
+    with_match_physical_float_polars_type!(s.dtype(), |$T| {
+        let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+        let (center, scale) = scale_params(ca, method)?;
+        let no_spread = scale == 0.0;
+        let center: <$T as PolarsNumericType>::Native = NumCast::from(center).unwrap();
+        let scale: <$T as PolarsNumericType>::Native = NumCast::from(scale).unwrap();
+        let out = if no_spread {
+            ca.apply_values(|v| v - center)
+        } else {
+            ca.apply_values(|v| (v - center) / scale)
+        };
+        Ok(out.into_series())
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_scale() -> PolarsResult<()> {
+        let s = Series::new("a", &[Some(1i32), None, Some(3), Some(5)]);
+
+        let out = scale(&s, ScaleMethod::Standard { ddof: 1 })?;
+        let expected = Series::new("a", &[Some(-1.0), None, Some(0.0), Some(1.0)]);
+        assert!(out.series_equal_missing(&expected));
+
+        let out = scale(&s, ScaleMethod::MinMax)?;
+        let expected = Series::new("a", &[Some(0.0), None, Some(0.5), Some(1.0)]);
+        assert!(out.series_equal_missing(&expected));
+
+        let out = scale(&s, ScaleMethod::Robust)?;
+        let expected = Series::new("a", &[Some(-1.0), None, Some(0.0), Some(1.0)]);
+        assert!(out.series_equal_missing(&expected));
+        Ok(())
+    }
+
+    #[test]
+    fn test_scale_constant() -> PolarsResult<()> {
+        let s = Series::new("a", &[Some(2.0f32), None, Some(2.0)]);
+        let expected = Series::new("a", &[Some(0.0f32), None, Some(0.0)]);
+        for method in [
+            ScaleMethod::Standard { ddof: 1 },
+            ScaleMethod::MinMax,
+            ScaleMethod::Robust,
+        ] {
+            assert!(scale(&s, method)?.series_equal_missing(&expected));
+        }
+        // a single value has no spread either
+        let out = scale(&s.slice(0, 1), ScaleMethod::Standard { ddof: 1 })?;
+        assert_eq!(Vec::from(out.f32()?), &[Some(0.0)]);
+        Ok(())
+    }
+}
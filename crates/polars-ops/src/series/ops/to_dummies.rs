@@ -2,17 +2,13 @@ use polars_core::frame::group_by::GroupsIndicator;
 
 use super::*;
 
-#[cfg(feature = "dtype-u8")]
 type DummyType = u8;
-#[cfg(feature = "dtype-u8")]
 type DummyCa = UInt8Chunked;
 
-#[cfg(not(feature = "dtype-u8"))]
-type DummyType = i32;
-#[cfg(not(feature = "dtype-u8"))]
-type DummyCa = Int32Chunked;
-
 pub trait ToDummies {
+    /// Create a `UInt8` indicator column per unique value.
+    ///
+    /// The columns are named `{name}{separator}{value}` and sorted by name.
     fn to_dummies(&self, separator: Option<&str>, drop_first: bool) -> PolarsResult<DataFrame>;
 }
 
@@ -1,3 +1,6 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
 #[cfg(feature = "hash")]
 use polars_core::export::ahash;
 #[cfg(feature = "dtype-struct")]
@@ -29,6 +32,40 @@ pub trait SeriesMethods: SeriesSealed {
         }
     }
 
+    /// Create a [`DataFrame`] with the `k` most frequent `values` of this [`Series`] and a
+    /// column `"counts"`, ordered by descending count. Ties are ordered by first occurrence.
+    ///
+    /// Unlike sorting the output of [`SeriesMethods::value_counts`], only `k` groups are kept
+    /// while the groups are scanned, which is cheaper for columns with many unique values.
+    fn most_frequent(&self, k: usize, parallel: bool) -> PolarsResult<DataFrame> {
+        let s = self.as_series();
+        polars_ensure!(
+            s.name() != "counts",
+            Duplicate: "using `most_frequent` on a column named 'counts' would lead to duplicate column names"
+        );
+        let groups = s.group_tuples(parallel, false)?;
+
+        // a min-heap of the best groups so far, the earliest group wins a tie
+        let mut heap = BinaryHeap::with_capacity(k.min(groups.len()) + 1);
+        if k > 0 {
+            for g in groups.iter() {
+                heap.push(Reverse((g.len() as IdxSize, Reverse(g.first()))));
+                if heap.len() > k {
+                    heap.pop();
+                }
+            }
+        }
+        let (counts, first): (Vec<_>, Vec<_>) = heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse((count, Reverse(first)))| (count, first))
+            .unzip();
+
+        let values = s.take(&IdxCa::from_vec("", first))?;
+        let counts = IdxCa::from_vec("counts", counts);
+        Ok(DataFrame::new_no_checks(vec![values, counts.into_series()]))
+    }
+
     #[cfg(feature = "hash")]
     fn hash(&self, build_hasher: ahash::RandomState) -> UInt64Chunked {
         let s = self.as_series().to_physical_repr();
@@ -0,0 +1,112 @@
+use std::any::Any;
+
+use polars_core::datatypes::{AnyValue, DataType};
+use polars_core::export::num::ToPrimitive;
+use polars_core::prelude::*;
+use polars_ops::prelude::TDigest;
+use polars_utils::unwrap::UnwrapUncheckedRelease;
+
+use super::*;
+use crate::operators::IdxSize;
+
+/// Approximate quantile that keeps a t-digest per group, so the memory
+/// needed per group is bounded.
+pub(crate) struct ApproxQuantileAgg {
+    quantile: f64,
+    digest: TDigest,
+}
+
+impl ApproxQuantileAgg {
+    pub(crate) fn new(quantile: f64) -> Self {
+        Self {
+            quantile,
+            digest: TDigest::default(),
+        }
+    }
+
+    pub(crate) fn split(&self) -> Self {
+        Self::new(self.quantile)
+    }
+
+    fn pre_agg_primitive<T: ToPrimitive>(&mut self, item: Option<T>) {
+        if let Some(v) = item.and_then(|v| v.to_f64()) {
+            self.digest.add(v)
+        }
+    }
+}
+
+impl AggregateFn for ApproxQuantileAgg {
+    fn has_physical_agg(&self) -> bool {
+        true
+    }
+    fn pre_agg_i8(&mut self, _chunk_idx: IdxSize, item: Option<i8>) {
+        self.pre_agg_primitive(item)
+    }
+    fn pre_agg_u8(&mut self, _chunk_idx: IdxSize, item: Option<u8>) {
+        self.pre_agg_primitive(item)
+    }
+    fn pre_agg_i16(&mut self, _chunk_idx: IdxSize, item: Option<i16>) {
+        self.pre_agg_primitive(item)
+    }
+    fn pre_agg_u16(&mut self, _chunk_idx: IdxSize, item: Option<u16>) {
+        self.pre_agg_primitive(item)
+    }
+    fn pre_agg_i32(&mut self, _chunk_idx: IdxSize, item: Option<i32>) {
+        self.pre_agg_primitive(item)
+    }
+    fn pre_agg_i64(&mut self, _chunk_idx: IdxSize, item: Option<i64>) {
+        self.pre_agg_primitive(item)
+    }
+    fn pre_agg_u32(&mut self, _chunk_idx: IdxSize, item: Option<u32>) {
+        self.pre_agg_primitive(item)
+    }
+    fn pre_agg_u64(&mut self, _chunk_idx: IdxSize, item: Option<u64>) {
+        self.pre_agg_primitive(item)
+    }
+    fn pre_agg_f32(&mut self, _chunk_idx: IdxSize, item: Option<f32>) {
+        self.pre_agg_primitive(item)
+    }
+    fn pre_agg_f64(&mut self, _chunk_idx: IdxSize, item: Option<f64>) {
+        self.pre_agg_primitive(item)
+    }
+
+    fn pre_agg(&mut self, _chunk_idx: IdxSize, item: &mut dyn ExactSizeIterator<Item = AnyValue>) {
+        let item = unsafe { item.next().unwrap_unchecked_release() };
+        self.pre_agg_primitive(item.extract::<f64>())
+    }
+
+    fn pre_agg_ordered(
+        &mut self,
+        _chunk_idx: IdxSize,
+        offset: IdxSize,
+        length: IdxSize,
+        values: &Series,
+    ) {
+        let values = values
+            .slice(offset as i64, length as usize)
+            .cast(&DataType::Float64)
+            .unwrap();
+        let ca = values.f64().unwrap();
+        ca.into_iter().for_each(|v| self.pre_agg_primitive(v))
+    }
+
+    fn dtype(&self) -> DataType {
+        DataType::Float64
+    }
+
+    fn combine(&mut self, other: &dyn Any) {
+        let other = unsafe { other.downcast_ref::<Self>().unwrap_unchecked_release() };
+        self.digest.merge(&other.digest)
+    }
+
+    fn finalize(&mut self) -> AnyValue<'static> {
+        self.digest
+            .quantile(self.quantile)
+            .map(AnyValue::Float64)
+            .unwrap_or(AnyValue::Null)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
@@ -6,17 +6,23 @@ use polars_core::error::PolarsResult;
 use polars_core::prelude::{DataType, SchemaRef, Series, IDX_DTYPE};
 use polars_core::schema::Schema;
 use polars_plan::dsl::Expr;
+#[cfg(any(feature = "approx_quantile", feature = "reservoir_sample"))]
+use polars_plan::dsl::FunctionExpr;
 use polars_plan::logical_plan::{ArenaExprIter, Context};
 use polars_plan::prelude::{AAggExpr, AExpr};
 use polars_utils::arena::{Arena, Node};
 use polars_utils::IdxSize;
 
+#[cfg(feature = "approx_quantile")]
+use crate::executors::sinks::group_by::aggregates::approx_quantile::ApproxQuantileAgg;
 use crate::executors::sinks::group_by::aggregates::count::CountAgg;
 use crate::executors::sinks::group_by::aggregates::first::FirstAgg;
 use crate::executors::sinks::group_by::aggregates::last::LastAgg;
 use crate::executors::sinks::group_by::aggregates::mean::MeanAgg;
 use crate::executors::sinks::group_by::aggregates::min_max::{new_max, new_min};
 use crate::executors::sinks::group_by::aggregates::null::NullAgg;
+#[cfg(feature = "reservoir_sample")]
+use crate::executors::sinks::group_by::aggregates::reservoir_sample::ReservoirSampleAgg;
 use crate::executors::sinks::group_by::aggregates::{AggregateFunction, SumAgg};
 use crate::expressions::PhysicalPipedExpr;
 use crate::operators::DataChunk;
@@ -39,6 +45,24 @@ impl PhysicalPipedExpr for Count {
     }
 }
 
+/// Functions that aggregate in bounded memory and can be used as aggregation in the
+/// streaming group-by.
+fn is_sketch_agg(ae: &AExpr) -> bool {
+    match ae {
+        #[cfg(feature = "approx_quantile")]
+        AExpr::Function {
+            function: FunctionExpr::ApproxQuantile(_),
+            ..
+        } => true,
+        #[cfg(feature = "reservoir_sample")]
+        AExpr::Function {
+            function: FunctionExpr::ReservoirSample { .. },
+            ..
+        } => true,
+        _ => false,
+    }
+}
+
 pub fn can_convert_to_hash_agg(
     mut node: Node,
     expr_arena: &Arena<AExpr>,
@@ -57,13 +81,14 @@ pub fn can_convert_to_hash_agg(
                 | AExpr::BinaryExpr { .. }
                 | AExpr::Ternary { .. }
                 | AExpr::Alias(_, _) => {},
+                ae if is_sketch_agg(ae) => {},
                 _ => {
                     can_run_partitioned = false;
                 },
             }
             ae
         })
-        .filter(|ae| matches!(ae, AExpr::Agg(_) | AExpr::Count))
+        .filter(|ae| matches!(ae, AExpr::Agg(_) | AExpr::Count) || is_sketch_agg(ae))
         .count()
         == 1
         && can_run_partitioned
@@ -99,6 +124,25 @@ pub fn can_convert_to_hash_agg(
                     }
                 })
             },
+            ae @ AExpr::Function { input, .. } if is_sketch_agg(ae) => {
+                let Ok(field) =
+                    expr_arena
+                        .get(input[0])
+                        .to_field(input_schema, Context::Default, expr_arena)
+                else {
+                    return false;
+                };
+                match ae {
+                    #[cfg(feature = "approx_quantile")]
+                    AExpr::Function {
+                        function: FunctionExpr::ApproxQuantile(_),
+                        ..
+                    } => field.dtype.to_physical().is_numeric(),
+                    // the sample is collected as physical values, which cannot be
+                    // mapped back to local categoricals
+                    _ => field.dtype.to_physical() == field.dtype || field.dtype.is_temporal(),
+                }
+            },
             _ => false,
         }
     } else {
@@ -252,6 +296,38 @@ where
             },
             agg => panic!("{agg:?} not yet implemented."),
         },
+        #[cfg(feature = "approx_quantile")]
+        AExpr::Function {
+            input,
+            function: FunctionExpr::ApproxQuantile(quantile),
+            ..
+        } => {
+            let phys_expr = to_physical(input[0], expr_arena, Some(schema)).unwrap();
+            let logical_dtype = phys_expr.field(schema).unwrap().dtype;
+            (
+                logical_dtype,
+                phys_expr,
+                AggregateFunction::ApproxQuantile(ApproxQuantileAgg::new(*quantile)),
+            )
+        },
+        #[cfg(feature = "reservoir_sample")]
+        AExpr::Function {
+            input,
+            function: FunctionExpr::ReservoirSample { n, seed },
+            ..
+        } => {
+            let phys_expr = to_physical(input[0], expr_arena, Some(schema)).unwrap();
+            let logical_dtype = phys_expr.field(schema).unwrap().dtype;
+            (
+                logical_dtype.clone(),
+                phys_expr,
+                AggregateFunction::ReservoirSample(ReservoirSampleAgg::new(
+                    *n,
+                    *seed,
+                    logical_dtype.to_physical(),
+                )),
+            )
+        },
         _ => todo!(),
     }
 }
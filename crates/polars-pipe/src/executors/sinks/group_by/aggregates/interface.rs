@@ -5,12 +5,16 @@ use enum_dispatch::enum_dispatch;
 use polars_core::datatypes::DataType;
 use polars_core::prelude::{AnyValue, Series};
 
+#[cfg(feature = "approx_quantile")]
+use crate::executors::sinks::group_by::aggregates::approx_quantile::ApproxQuantileAgg;
 use crate::executors::sinks::group_by::aggregates::count::CountAgg;
 use crate::executors::sinks::group_by::aggregates::first::FirstAgg;
 use crate::executors::sinks::group_by::aggregates::last::LastAgg;
 use crate::executors::sinks::group_by::aggregates::mean::MeanAgg;
 use crate::executors::sinks::group_by::aggregates::min_max::MinMaxAgg;
 use crate::executors::sinks::group_by::aggregates::null::NullAgg;
+#[cfg(feature = "reservoir_sample")]
+use crate::executors::sinks::group_by::aggregates::reservoir_sample::ReservoirSampleAgg;
 use crate::executors::sinks::group_by::aggregates::SumAgg;
 use crate::operators::IdxSize;
 
@@ -93,6 +97,10 @@ pub(crate) enum AggregateFunction {
     MinMaxI16(MinMaxAgg<i16, fn(&i16, &i16) -> Ordering>),
     MinMaxI32(MinMaxAgg<i32, fn(&i32, &i32) -> Ordering>),
     MinMaxI64(MinMaxAgg<i64, fn(&i64, &i64) -> Ordering>),
+    #[cfg(feature = "approx_quantile")]
+    ApproxQuantile(ApproxQuantileAgg),
+    #[cfg(feature = "reservoir_sample")]
+    ReservoirSample(ReservoirSampleAgg),
 }
 
 impl AggregateFunction {
@@ -121,6 +129,10 @@ impl AggregateFunction {
             MinMaxI16(inner) => MinMaxI16(inner.split()),
             MinMaxI32(inner) => MinMaxI32(inner.split()),
             MinMaxI64(inner) => MinMaxI64(inner.split()),
+            #[cfg(feature = "approx_quantile")]
+            ApproxQuantile(inner) => ApproxQuantile(inner.split()),
+            #[cfg(feature = "reservoir_sample")]
+            ReservoirSample(inner) => ReservoirSample(inner.split()),
         }
     }
 }
@@ -1,3 +1,5 @@
+#[cfg(feature = "approx_quantile")]
+mod approx_quantile;
 mod convert;
 mod count;
 mod first;
@@ -6,6 +8,8 @@ mod last;
 mod mean;
 mod min_max;
 mod null;
+#[cfg(feature = "reservoir_sample")]
+mod reservoir_sample;
 mod sum;
 
 pub use convert::*;
@@ -0,0 +1,83 @@
+use std::any::Any;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use polars_core::datatypes::{AnyValue, DataType};
+use polars_core::prelude::Series;
+use polars_ops::prelude::Reservoir;
+use polars_utils::unwrap::UnwrapUncheckedRelease;
+
+use super::*;
+use crate::operators::IdxSize;
+
+/// Uniform sample of at most `n` values per group, so the memory
+/// needed per group is bounded.
+pub(crate) struct ReservoirSampleAgg {
+    n: usize,
+    seed: Option<u64>,
+    /// The number of splits of this aggregation, used to derive their seeds.
+    splits: AtomicU64,
+    reservoir: Reservoir<AnyValue<'static>>,
+    pub(crate) dtype: DataType,
+}
+
+impl ReservoirSampleAgg {
+    pub(crate) fn new(n: usize, seed: Option<u64>, dtype: DataType) -> Self {
+        Self {
+            n,
+            seed,
+            splits: AtomicU64::new(0),
+            reservoir: Reservoir::new(n, seed),
+            dtype,
+        }
+    }
+
+    /// Every split gets its own seed, otherwise the splits that are merged into one group
+    /// would draw the same positions of their inputs.
+    pub(crate) fn split(&self) -> Self {
+        let split_idx = self.splits.fetch_add(1, Ordering::Relaxed) + 1;
+        let seed = self
+            .seed
+            .map(|seed| seed ^ split_idx.wrapping_mul(0x9E37_79B9_7F4A_7C15));
+        Self::new(self.n, seed, self.dtype.clone())
+    }
+}
+
+impl AggregateFn for ReservoirSampleAgg {
+    fn pre_agg(&mut self, _chunk_idx: IdxSize, item: &mut dyn ExactSizeIterator<Item = AnyValue>) {
+        let item = unsafe { item.next().unwrap_unchecked_release() };
+        self.reservoir.push(item.into_static().unwrap())
+    }
+
+    fn pre_agg_ordered(
+        &mut self,
+        _chunk_idx: IdxSize,
+        offset: IdxSize,
+        length: IdxSize,
+        values: &Series,
+    ) {
+        for idx in offset..offset + length {
+            let item = unsafe { values.get_unchecked(idx as usize) };
+            self.reservoir.push(item.into_static().unwrap())
+        }
+    }
+
+    fn dtype(&self) -> DataType {
+        DataType::List(Box::new(self.dtype.clone()))
+    }
+
+    fn combine(&mut self, other: &dyn Any) {
+        let other = unsafe { other.downcast_ref::<Self>().unwrap_unchecked_release() };
+        self.reservoir.merge(other.reservoir.clone())
+    }
+
+    fn finalize(&mut self) -> AnyValue<'static> {
+        let reservoir = std::mem::replace(&mut self.reservoir, Reservoir::new(self.n, self.seed));
+        let values = reservoir.into_items();
+        let s = Series::from_any_values_and_dtype("", &values, &self.dtype, true).unwrap();
+        AnyValue::List(s)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
@@ -1,5 +1,5 @@
 use std::collections::LinkedList;
-use std::sync::atomic::{AtomicU16, Ordering};
+use std::sync::atomic::{AtomicU16, AtomicUsize, Ordering};
 use std::sync::Mutex;
 
 use polars_core::utils::accumulate_dataframes_vertical_unchecked;
@@ -12,6 +12,8 @@ use crate::pipeline::{FORCE_OOC, PARTITION_SIZE};
 struct SpillPartitions {
     // outer vec: partitions (factor of 2)
     partitions: PartitionVec<Mutex<LinkedList<SpillPayload>>>,
+    // bytes of the payloads that are not yet merged or dumped
+    spilled_bytes: AtomicUsize,
 }
 
 impl SpillPartitions {
@@ -19,13 +21,18 @@ impl SpillPartitions {
         let mut partitions = Vec::with_capacity(PARTITION_SIZE);
         partitions.resize_with(PARTITION_SIZE, Default::default);
 
-        Self { partitions }
+        Self {
+            partitions,
+            spilled_bytes: Default::default(),
+        }
     }
 
     #[inline]
     fn insert(&self, partition: usize, to_spill: SpillPayload) -> usize {
         let partition = &self.partitions[partition];
         let mut partition = partition.lock().unwrap();
+        self.spilled_bytes
+            .fetch_add(to_spill.estimated_size(), Ordering::Relaxed);
         partition.push_back(to_spill);
         partition.len()
     }
@@ -38,7 +45,10 @@ impl SpillPartitions {
         let partition = &self.partitions[partition];
         let mut partition = partition.lock().unwrap();
         if partition.len() > min_size {
-            Some(std::mem::take(&mut partition))
+            let bucket = std::mem::take(&mut *partition);
+            let bytes = bucket.iter().map(|pl| pl.estimated_size()).sum();
+            self.spilled_bytes.fetch_sub(bytes, Ordering::Relaxed);
+            Some(bucket)
         } else {
             None
         }
@@ -107,6 +117,11 @@ impl GlobalTable {
         self.process_partition(partition)
     }
 
+    /// The bytes of the spilled payloads that are still held in memory.
+    pub(super) fn spilled_bytes(&self) -> usize {
+        self.spill_partitions.spilled_bytes.load(Ordering::Relaxed)
+    }
+
     pub(super) fn get_ooc_dump_schema(&self) -> Option<Schema> {
         self.spill_partitions.spill_schema()
     }
@@ -60,6 +60,14 @@ impl SpillPayload {
         &self.chunk_idx
     }
 
+    fn estimated_size(&self) -> usize {
+        self.hashes.len() * std::mem::size_of::<u64>()
+            + self.chunk_idx.len() * std::mem::size_of::<IdxSize>()
+            + self.keys.values().len()
+            + self.keys.len() * std::mem::size_of::<i64>()
+            + self.aggs.iter().map(|s| s.estimated_size()).sum::<usize>()
+    }
+
     fn get_schema(&self) -> Schema {
         let mut schema = Schema::with_capacity(self.aggs.len() + 2);
         schema.with_column(HASH_COL.into(), DataType::UInt64);
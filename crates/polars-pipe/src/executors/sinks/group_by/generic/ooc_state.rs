@@ -19,24 +19,6 @@ pub(super) struct OocState {
     to_disk_threshold: f64,
 }
 
-impl Default for OocState {
-    fn default() -> Self {
-        let to_disk_threshold = if std::env::var(FORCE_OOC).is_ok() {
-            1.0
-        } else {
-            TO_DISK_THRESHOLD
-        };
-
-        Self {
-            mem_track: MemTracker::new(morsels_per_sink()),
-            ooc: false,
-            io_thread: Default::default(),
-            count: 0,
-            to_disk_threshold,
-        }
-    }
-}
-
 // If this is reached we early merge the overflow buckets
 // to free up memory
 const EARLY_MERGE_THRESHOLD: f64 = 0.5;
@@ -51,6 +33,22 @@ pub(super) enum SpillAction {
 }
 
 impl OocState {
+    pub(super) fn new(memory_limit: Option<usize>) -> Self {
+        let to_disk_threshold = if std::env::var(FORCE_OOC).is_ok() {
+            1.0
+        } else {
+            TO_DISK_THRESHOLD
+        };
+
+        Self {
+            mem_track: MemTracker::new(morsels_per_sink(), memory_limit),
+            ooc: false,
+            io_thread: Default::default(),
+            count: 0,
+            to_disk_threshold,
+        }
+    }
+
     fn init_ooc(&mut self, spill_schema: Schema) -> PolarsResult<()> {
         if verbose() {
             eprintln!("OOC group_by started");
@@ -65,17 +63,25 @@ impl OocState {
         Ok(())
     }
 
+    /// `spilled_bytes` are the bytes of the spilled payloads that are still held in memory.
     pub(super) fn check_memory_usage(
         &mut self,
+        spilled_bytes: usize,
         spill_schema: &dyn Fn() -> Option<Schema>,
     ) -> PolarsResult<SpillAction> {
+        let exceeds_limit = self.mem_track.exceeds_memory_limit(spilled_bytes);
         if self.ooc {
-            return Ok(SpillAction::Dump);
+            // with a memory limit we only have to dump until we are back under the limit
+            return if exceeds_limit || !self.mem_track.has_memory_limit() {
+                Ok(SpillAction::Dump)
+            } else {
+                Ok(SpillAction::None)
+            };
         }
         let free_frac = self.mem_track.free_memory_fraction_since_start();
         self.count += 1;
 
-        if free_frac < self.to_disk_threshold {
+        if free_frac < self.to_disk_threshold || exceeds_limit {
             if let Some(schema) = spill_schema() {
                 self.init_ooc(schema)?;
                 Ok(SpillAction::Dump)
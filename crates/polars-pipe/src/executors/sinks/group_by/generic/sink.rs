@@ -25,6 +25,7 @@ impl GenericGroupby2 {
         output_schema: SchemaRef,
         agg_input_dtypes: Vec<DataType>,
         slice: Option<(i64, usize)>,
+        memory_limit: Option<usize>,
     ) -> Self {
         let key_dtypes: Arc<[DataType]> = Arc::from(
             output_schema
@@ -52,7 +53,7 @@ impl GenericGroupby2 {
             global_table: Arc::new(global_map),
             eval: Eval::new(key_columns, aggregation_columns),
             slice,
-            ooc_state: Default::default(),
+            ooc_state: OocState::new(memory_limit),
         }
     }
 }
@@ -97,8 +98,9 @@ impl Sink for GenericGroupby2 {
         // other scenario could be that we must spill to disk
         match self
             .ooc_state
-            .check_memory_usage(&|| self.global_table.get_ooc_dump_schema())?
-        {
+            .check_memory_usage(self.global_table.spilled_bytes(), &|| {
+                self.global_table.get_ooc_dump_schema()
+            })? {
             SpillAction::None => {},
             SpillAction::EarlyMerge => self.global_table.early_merge(),
             SpillAction::Dump => {
@@ -167,6 +169,7 @@ impl Sink for GenericGroupby2 {
                     &self.ooc_state.io_thread,
                     self.slice,
                     self.global_table.clone(),
+                    context,
                 )?)))
             }
         }
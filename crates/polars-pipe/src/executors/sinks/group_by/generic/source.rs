@@ -21,6 +21,7 @@ impl GroupBySource {
         io_thread: &IOThreadRef,
         slice: Option<(i64, usize)>,
         global_table: Arc<GlobalTable>,
+        context: &PExecutionContext,
     ) -> PolarsResult<Self> {
         let mut io_thread = io_thread.lock().unwrap();
         let io_thread = io_thread.take().unwrap();
@@ -30,6 +31,7 @@ impl GroupBySource {
         }
 
         block_thread_until_io_thread_done(&io_thread);
+        io_thread.report_spilled(context);
         Ok(Self {
             _io_thread: io_thread,
             slice,
@@ -25,7 +25,7 @@ pub(super) struct OocState {
 impl OocState {
     pub(super) fn new(io_thread: Option<Arc<Mutex<Option<IOThread>>>>, ooc: bool) -> Self {
         Self {
-            _mem_track: MemTracker::new(morsels_per_sink()),
+            _mem_track: MemTracker::new(morsels_per_sink(), None),
             ooc,
             io_thread: io_thread.unwrap_or_default(),
         }
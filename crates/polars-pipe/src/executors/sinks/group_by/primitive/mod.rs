@@ -453,7 +453,7 @@ where
             });
     }
 
-    fn finalize(&mut self, _context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
+    fn finalize(&mut self, context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
         let dfs = self.pre_finalize()?;
         let payload = if self.ooc_state.ooc {
             let mut iot = self.ooc_state.io_thread.lock().unwrap();
@@ -467,7 +467,7 @@ where
         } else {
             None
         };
-        finalize_group_by(dfs, &self.output_schema, self.slice, payload)
+        finalize_group_by(dfs, &self.output_schema, self.slice, payload, context)
     }
 
     fn split(&self, thread_no: usize) -> Box<dyn Sink> {
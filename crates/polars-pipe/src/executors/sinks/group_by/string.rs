@@ -62,7 +62,7 @@ pub struct Utf8GroupbySink {
     // by:
     //      * offset = (idx)
     //      * end = (offset + 1)
-    keys: Vec<Option<smartstring::alias::String>>,
+    keys: Vec<Option<PlSmallStr>>,
     aggregators: Vec<AggregateFunction>,
     // the key that will be aggregated on
     key_column: Arc<dyn PhysicalPipedExpr>,
@@ -495,7 +495,7 @@ impl Sink for Utf8GroupbySink {
         Box::new(new)
     }
 
-    fn finalize(&mut self, _context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
+    fn finalize(&mut self, context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
         let dfs = self.pre_finalize()?;
         let payload = if self.ooc_state.ooc {
             let mut iot = self.ooc_state.io_thread.lock().unwrap();
@@ -509,7 +509,7 @@ impl Sink for Utf8GroupbySink {
         } else {
             None
         };
-        finalize_group_by(dfs, &self.output_schema, self.slice, payload)
+        finalize_group_by(dfs, &self.output_schema, self.slice, payload, context)
     }
 
     fn as_any(&mut self) -> &mut dyn Any {
@@ -585,7 +585,7 @@ fn get_entry<'a>(
     key_val: Option<&str>,
     h: u64,
     current_partition: &'a mut PlIdHashMap<Key, IdxSize>,
-    keys: &[Option<smartstring::alias::String>],
+    keys: &[Option<PlSmallStr>],
 ) -> RawEntryMut<'a, Key, IdxSize, IdBuildHasher> {
     current_partition.raw_entry_mut().from_hash(h, |key| {
         // first compare the hash before we incur the cache miss
@@ -4,7 +4,7 @@ use polars_core::utils::{accumulate_dataframes_vertical_unchecked, slice_offsets
 
 use crate::executors::sinks::group_by::ooc::GroupBySource;
 use crate::executors::sinks::io::{block_thread_until_io_thread_done, IOThread};
-use crate::operators::{DataChunk, FinalizedSink, Sink};
+use crate::operators::{DataChunk, FinalizedSink, PExecutionContext, Sink};
 
 pub(super) fn default_slices<K, V, HB>(
     pre_agg_partitions: &[HashMap<K, V, HB>],
@@ -55,6 +55,7 @@ pub(super) fn finalize_group_by(
     output_schema: &Schema,
     slice: Option<(i64, usize)>,
     ooc_payload: Option<(IOThread, Box<dyn Sink>)>,
+    context: &PExecutionContext,
 ) -> PolarsResult<FinalizedSink> {
     let df = if dfs.is_empty() {
         DataFrame::from(output_schema)
@@ -69,6 +70,7 @@ pub(super) fn finalize_group_by(
         Some((iot, sink)) => {
             // we wait until all chunks are spilled
             block_thread_until_io_thread_done(&iot);
+            iot.report_spilled(context);
 
             Ok(FinalizedSink::Source(Box::new(GroupBySource::new(
                 iot, df, sink, slice,
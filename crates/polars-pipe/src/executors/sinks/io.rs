@@ -1,6 +1,6 @@
 use std::fs::File;
 use std::path::{Path, PathBuf};
-use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
@@ -9,6 +9,7 @@ use polars_core::prelude::*;
 use polars_core::utils::arrow::temporal_conversions::SECONDS_IN_DAY;
 use polars_io::prelude::*;
 
+use crate::operators::PExecutionContext;
 use crate::pipeline::morsels_per_sink;
 
 pub(in crate::executors::sinks) type DfIter =
@@ -25,6 +26,24 @@ pub(crate) struct IOThread {
     pub(in crate::executors::sinks) total: Arc<AtomicUsize>,
     pub(in crate::executors::sinks) thread_local_count: Arc<AtomicUsize>,
     schema: SchemaRef,
+    operation_name: &'static str,
+    spilled: Arc<SpillCount>,
+}
+
+/// The number of rows and bytes written to disk.
+#[derive(Default)]
+struct SpillCount {
+    rows: AtomicUsize,
+    bytes: AtomicU64,
+}
+
+impl SpillCount {
+    fn add(&self, rows: usize, path: &Path) {
+        self.rows.fetch_add(rows, Ordering::Relaxed);
+        if let Ok(metadata) = std::fs::metadata(path) {
+            self.bytes.fetch_add(metadata.len(), Ordering::Relaxed);
+        }
+    }
 }
 
 fn get_lockfile_path(dir: &Path) -> PathBuf {
@@ -103,8 +122,10 @@ impl IOThread {
         let sent: Arc<AtomicUsize> = Default::default();
         let total: Arc<AtomicUsize> = Default::default();
         let thread_local_count: Arc<AtomicUsize> = Default::default();
+        let spilled: Arc<SpillCount> = Default::default();
 
         let dir2 = dir.clone();
+        let spilled2 = spilled.clone();
         let total2 = total.clone();
         let lockfile2 = lockfile.clone();
         let schema2 = schema.clone();
@@ -130,25 +151,29 @@ impl IOThread {
                         let _ = std::fs::create_dir(&path);
                         path.push(format!("{count}.ipc"));
 
-                        let file = File::create(path).unwrap();
+                        let file = File::create(&path).unwrap();
                         let writer = IpcWriter::new(file);
                         let mut writer = writer.batched(&schema).unwrap();
                         writer.write_batch(&df).unwrap();
                         writer.finish().unwrap();
+                        spilled2.add(df.height(), &path);
                         count += 1;
                     }
                 } else {
                     let mut path = dir2.clone();
                     path.push(format!("{count}.ipc"));
 
-                    let file = File::create(path).unwrap();
+                    let file = File::create(&path).unwrap();
                     let writer = IpcWriter::new(file);
                     let mut writer = writer.batched(&schema).unwrap();
 
+                    let mut rows = 0;
                     for df in iter {
+                        rows += df.height();
                         writer.write_batch(&df).unwrap();
                     }
                     writer.finish().unwrap();
+                    spilled2.add(rows, &path);
 
                     count += 1;
                 }
@@ -164,6 +189,8 @@ impl IOThread {
             _lockfile: lockfile,
             thread_local_count,
             schema,
+            operation_name,
+            spilled,
         })
     }
 
@@ -177,9 +204,10 @@ impl IOThread {
             // duplicates
             path.push(format!("_{count}.ipc"));
 
-            let file = File::create(path).unwrap();
+            let file = File::create(&path).unwrap();
             let mut writer = IpcWriter::new(file);
             writer.finish(&mut df).unwrap();
+            self.spilled.add(df.height(), &path);
         } else {
             let iter = Box::new(std::iter::once(df));
             self.dump_iter(None, iter)
@@ -205,11 +233,12 @@ impl IOThread {
         // thread local name we start with an underscore to ensure we don't get
         // duplicates
         path.push(format!("_{count}.ipc"));
-        let file = File::create(path).unwrap();
+        let file = File::create(&path).unwrap();
         let writer = IpcWriter::new(file);
         let mut writer = writer.batched(&self.schema).unwrap();
         writer.write_batch(&df).unwrap();
         writer.finish().unwrap();
+        self.spilled.add(df.height(), &path);
     }
 
     pub(in crate::executors::sinks) fn dump_iter(&self, partition: Option<IdxCa>, iter: DfIter) {
@@ -217,6 +246,16 @@ impl IOThread {
         self.sender.send((partition, iter)).unwrap();
         self.sent.fetch_add(add, Ordering::Relaxed);
     }
+
+    /// Report everything that was written to disk so far to the execution state. Call this
+    /// once the operation stopped spilling and the IO thread is done.
+    pub(in crate::executors::sinks) fn report_spilled(&self, context: &PExecutionContext) {
+        context.execution_state.spilled(
+            self.operation_name,
+            self.spilled.rows.load(Ordering::Relaxed),
+            self.spilled.bytes.load(Ordering::Relaxed),
+        )
+    }
 }
 
 impl Drop for IOThread {
@@ -6,8 +6,8 @@ use std::vec;
 
 use polars_core::error::PolarsResult;
 use polars_core::frame::DataFrame;
+use polars_core::prelude::PlSmallStr;
 use polars_ops::prelude::CrossJoin as CrossJoinTrait;
-use smartstring::alias::String as SmartString;
 
 use crate::operators::{
     chunks_to_df_unchecked, DataChunk, FinalizedSink, Operator, OperatorResult, PExecutionContext,
@@ -17,11 +17,11 @@ use crate::operators::{
 #[derive(Default)]
 pub struct CrossJoin {
     chunks: Vec<DataChunk>,
-    suffix: SmartString,
+    suffix: PlSmallStr,
 }
 
 impl CrossJoin {
-    pub(crate) fn new(suffix: SmartString) -> Self {
+    pub(crate) fn new(suffix: PlSmallStr) -> Self {
         CrossJoin {
             chunks: vec![],
             suffix,
@@ -76,7 +76,7 @@ pub struct CrossJoinProbe {
     in_process_left: Option<StepBy<Range<usize>>>,
     in_process_right: Option<StepBy<Range<usize>>>,
     in_process_left_df: DataFrame,
-    output_names: Option<Vec<SmartString>>,
+    output_names: Option<Vec<PlSmallStr>>,
 }
 
 impl Operator for CrossJoinProbe {
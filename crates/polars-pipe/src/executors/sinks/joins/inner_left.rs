@@ -12,7 +12,6 @@ use polars_ops::prelude::JoinType;
 use polars_row::RowsEncoded;
 use polars_utils::hash_to_partition;
 use polars_utils::slice::GetSaferUnchecked;
-use smartstring::alias::String as SmartString;
 
 use crate::executors::sinks::joins::generic_build::*;
 use crate::executors::sinks::utils::hash_rows;
@@ -58,7 +57,7 @@ pub struct GenericJoinProbe {
     // these column locations need to be dropped from the rhs
     join_column_idx: Option<Vec<usize>>,
     // cached output names
-    output_names: Option<Vec<SmartString>>,
+    output_names: Option<Vec<PlSmallStr>>,
     how: JoinType,
 }
 
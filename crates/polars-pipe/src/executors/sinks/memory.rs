@@ -16,10 +16,12 @@ pub(super) struct MemTracker {
     thread_count: usize,
     available_at_start: usize,
     refresh_interval: usize,
+    // the number of bytes this node may use
+    memory_limit: Option<usize>,
 }
 
 impl MemTracker {
-    pub(super) fn new(thread_count: usize) -> Self {
+    pub(super) fn new(thread_count: usize, memory_limit: Option<usize>) -> Self {
         let refresh_interval = if std::env::var(FORCE_OOC).is_ok() {
             1
         } else {
@@ -33,17 +35,25 @@ impl MemTracker {
             thread_count,
             available_at_start: 0,
             refresh_interval,
+            memory_limit,
         };
-        let available = MEMINFO.free() as usize;
+        let available = out.available_memory();
         out.available_mem.store(available, Ordering::Relaxed);
         out.available_at_start = available;
         out
     }
 
+    /// The free memory of the system, capped by the memory limit.
+    fn available_memory(&self) -> usize {
+        let free = MEMINFO.free() as usize;
+        self.memory_limit
+            .map_or(free, |limit| std::cmp::min(free, limit))
+    }
+
     /// This shouldn't be called often as this is expensive.
     fn refresh_memory(&self) {
         self.available_mem
-            .store(MEMINFO.free() as usize, Ordering::Relaxed);
+            .store(self.available_memory(), Ordering::Relaxed);
     }
 
     /// Get available memory of the system measured on latest refresh.
@@ -60,6 +70,9 @@ impl MemTracker {
     pub(super) fn free_memory_fraction_since_start(&self) -> f64 {
         // we divide first to reduce the precision loss in floats
         let available_at_start = (self.available_at_start / TO_MB) as f64;
+        if available_at_start == 0.0 {
+            return 0.0;
+        }
         let available = (self.get_available() / TO_MB) as f64;
         available / available_at_start
     }
@@ -68,4 +81,13 @@ impl MemTracker {
     pub(super) fn fetch_add(&self, add: usize) -> usize {
         self.used_by_node.fetch_add(add, Ordering::Relaxed)
     }
+
+    pub(super) fn has_memory_limit(&self) -> bool {
+        self.memory_limit.is_some()
+    }
+
+    /// Whether `resident` bytes held by this node exceed the memory limit.
+    pub(super) fn exceeds_memory_limit(&self, resident: usize) -> bool {
+        self.memory_limit.map_or(false, |limit| resident > limit)
+    }
 }
@@ -38,7 +38,12 @@ pub struct SortSink {
 }
 
 impl SortSink {
-    pub(crate) fn new(sort_idx: usize, sort_args: SortArguments, schema: SchemaRef) -> Self {
+    pub(crate) fn new(
+        sort_idx: usize,
+        sort_args: SortArguments,
+        schema: SchemaRef,
+        memory_limit: Option<usize>,
+    ) -> Self {
         // for testing purposes
         let ooc = std::env::var(FORCE_OOC).is_ok();
         let n_morsels_per_sink = morsels_per_sink();
@@ -46,7 +51,7 @@ impl SortSink {
         let mut out = Self {
             schema,
             chunks: Default::default(),
-            mem_track: MemTracker::new(n_morsels_per_sink),
+            mem_track: MemTracker::new(n_morsels_per_sink, memory_limit),
             ooc,
             io_thread: Default::default(),
             sort_idx,
@@ -183,14 +188,16 @@ impl Sink for SortSink {
 
             block_thread_until_io_thread_done(io_thread);
 
-            sort_ooc(
+            let out = sort_ooc(
                 io_thread,
                 dist,
                 self.sort_idx,
                 self.sort_args.descending[0],
                 self.sort_args.slice,
                 context.verbose,
-            )
+            );
+            io_thread.report_spilled(context);
+            out
         } else {
             let chunks = std::mem::take(&mut self.chunks);
             let df = accumulate_dataframes_vertical_unchecked(chunks);
@@ -140,6 +140,7 @@ impl SortSinkMultiple {
         sort_args: SortArguments,
         output_schema: SchemaRef,
         sort_idx: Vec<usize>,
+        memory_limit: Option<usize>,
     ) -> Self {
         let can_decode = sort_column_can_be_decoded(&output_schema, &sort_idx);
         let mut schema = (*output_schema).clone();
@@ -173,6 +174,7 @@ impl SortSinkMultiple {
                 maintain_order: false,
             },
             Arc::new(schema),
+            memory_limit,
         ));
 
         SortSinkMultiple {
@@ -79,6 +79,7 @@ impl CsvSource {
             .with_rechunk(false)
             .with_chunk_size(chunk_size)
             .with_row_count(file_options.row_count)
+            .with_progress_callback(options.progress)
             .with_try_parse_dates(options.try_parse_dates)
             .truncate_ragged_lines(options.truncate_ragged_lines)
             .raise_if_empty(options.raise_if_empty);
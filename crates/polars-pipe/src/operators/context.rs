@@ -2,6 +2,10 @@ use std::any::Any;
 
 pub trait SExecutionContext: Send + Sync {
     fn as_any(&self) -> &dyn Any;
+
+    /// `operation` wrote `rows` rows to `bytes` bytes of temporary files because it ran out
+    /// of memory.
+    fn spilled(&self, _operation: &str, _rows: usize, _bytes: u64) {}
 }
 
 pub struct PExecutionContext {
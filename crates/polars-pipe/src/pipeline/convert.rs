@@ -86,6 +86,7 @@ where
                 #[cfg(feature = "csv")]
                 FileScan::Csv {
                     options: csv_options,
+                    ..
                 } => {
                     let src = sources::CsvSource::new(
                         path,
@@ -125,6 +126,7 @@ pub fn get_sink<F>(
     lp_arena: &Arena<ALogicalPlan>,
     expr_arena: &mut Arena<AExpr>,
     to_physical: &F,
+    memory_limit: Option<usize>,
 ) -> PolarsResult<Box<dyn SinkTrait>>
 where
     F: Fn(Node, &Arena<AExpr>, Option<&SchemaRef>) -> PolarsResult<Arc<dyn PhysicalPipedExpr>>,
@@ -258,7 +260,7 @@ where
                     .unwrap();
                 let index = input_schema.try_index_of(by_column.as_ref())?;
 
-                let sort_sink = SortSink::new(index, args.clone(), input_schema);
+                let sort_sink = SortSink::new(index, args.clone(), input_schema, memory_limit);
                 Box::new(sort_sink) as Box<dyn SinkTrait>
             } else {
                 let sort_idx = by_column
@@ -269,7 +271,8 @@ where
                     })
                     .collect::<PolarsResult<Vec<_>>>()?;
 
-                let sort_sink = SortSinkMultiple::new(args.clone(), input_schema, sort_idx);
+                let sort_sink =
+                    SortSinkMultiple::new(args.clone(), input_schema, sort_idx, memory_limit);
                 Box::new(sort_sink) as Box<dyn SinkTrait>
             }
         },
@@ -353,6 +356,7 @@ where
                 output_schema,
                 input_agg_dtypes,
                 options.slice,
+                memory_limit,
             ));
 
             Box::new(ReProjectSink::new(input_schema, group_by_sink))
@@ -386,7 +390,9 @@ where
             }
             let aggregation_columns = Arc::new(aggregation_columns);
 
-            if std::env::var("POLARS_STREAMING_GB2").as_deref() == Ok("1") {
+            // only the generic sink can spill to disk when it reaches the memory limit
+            if std::env::var("POLARS_STREAMING_GB2").as_deref() == Ok("1") || memory_limit.is_some()
+            {
                 Box::new(GenericGroupby2::new(
                     key_columns,
                     aggregation_columns,
@@ -394,6 +400,7 @@ where
                     output_schema.clone(),
                     input_agg_dtypes,
                     options.slice,
+                    memory_limit,
                 ))
             } else {
                 match (
@@ -427,6 +434,7 @@ where
                         output_schema.clone(),
                         input_agg_dtypes,
                         options.slice,
+                        memory_limit,
                     )),
                 }
             }
@@ -568,6 +576,7 @@ pub fn create_pipeline<F>(
     expr_arena: &mut Arena<AExpr>,
     to_physical: F,
     verbose: bool,
+    memory_limit: Option<usize>,
     sink_cache: &mut PlHashMap<usize, Box<dyn SinkTrait>>,
 ) -> PolarsResult<PipeLine>
 where
@@ -632,11 +641,12 @@ where
             // ensure that shared sinks are really shared
             // to achieve this we store/fetch them in a cache
             let sink = if *shared_count.borrow() == 1 {
-                get_sink(node, lp_arena, expr_arena, &to_physical)?
+                get_sink(node, lp_arena, expr_arena, &to_physical, memory_limit)?
             } else {
                 match sink_cache.entry(node.0) {
                     Entry::Vacant(entry) => {
-                        let sink = get_sink(node, lp_arena, expr_arena, &to_physical)?;
+                        let sink =
+                            get_sink(node, lp_arena, expr_arena, &to_physical, memory_limit)?;
                         entry.insert(sink.split(0));
                         sink
                     },
@@ -268,6 +268,29 @@ impl LogicalPlan {
                 self.write_dot(acc_str, prev_node, current_node, id_map)?;
                 input.dot(acc_str, (branch, id + 1), current_node, id_map)
             },
+            ArenaProjection {
+                input,
+                exprs,
+                hstack,
+                ..
+            } => {
+                let fmt = if *hstack {
+                    format!("WITH COLUMNS {}", exprs.len())
+                } else {
+                    let schema = input.schema().map_err(|_| {
+                        eprintln!("could not determine schema");
+                        std::fmt::Error
+                    })?;
+                    format!("π {}/{}", exprs.len(), schema.len())
+                };
+                let current_node = DotNode {
+                    branch,
+                    id,
+                    fmt: &fmt,
+                };
+                self.write_dot(acc_str, prev_node, current_node, id_map)?;
+                input.dot(acc_str, (branch, id + 1), current_node, id_map)
+            },
             Distinct { input, options, .. } => {
                 let mut fmt = String::with_capacity(128);
                 fmt.push_str("DISTINCT");
@@ -26,6 +26,67 @@ impl ArrayNameSpace {
             .map_private(FunctionExpr::ArrayExpr(ArrayFunction::Sum))
     }
 
+    /// Compute the mean of the items in every subarray.
+    pub fn mean(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::ArrayExpr(ArrayFunction::Mean))
+    }
+
+    /// Compute the standard deviation of the items in every subarray.
+    pub fn std(self, ddof: u8) -> Expr {
+        self.0
+            .map_private(FunctionExpr::ArrayExpr(ArrayFunction::Std(ddof)))
+    }
+
+    /// Evaluate whether any boolean value in every subarray is true.
+    pub fn any(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::ArrayExpr(ArrayFunction::Any))
+    }
+
+    /// Evaluate whether all boolean values in every subarray are true.
+    pub fn all(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::ArrayExpr(ArrayFunction::All))
+    }
+
+    /// Return the index of the minimal value of every subarray.
+    pub fn arg_min(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::ArrayExpr(ArrayFunction::ArgMin))
+    }
+
+    /// Return the index of the maximum value of every subarray.
+    pub fn arg_max(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::ArrayExpr(ArrayFunction::ArgMax))
+    }
+
+    #[cfg(feature = "is_in")]
+    /// Check if the subarrays contain an element.
+    pub fn contains<E: Into<Expr>>(self, other: E) -> Expr {
+        let other = other.into();
+
+        self.0
+            .map_many_private(
+                FunctionExpr::ArrayExpr(ArrayFunction::Contains),
+                &[other],
+                true,
+                false,
+            )
+            .with_function_options(|mut options| {
+                options.input_wildcard_expansion = true;
+                options
+            })
+    }
+
+    #[allow(clippy::wrong_self_convention)]
+    /// Convert every subarray to a `List` with the same items.
+    pub fn to_list(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::ArrayExpr(ArrayFunction::ToList))
+    }
+
     /// Keep only the unique values in every sub-array.
     pub fn unique(self) -> Expr {
         self.0
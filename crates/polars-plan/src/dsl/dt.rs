@@ -9,6 +9,8 @@ pub struct DateLikeNameSpace(pub(crate) Expr);
 impl DateLikeNameSpace {
     /// Convert from Date/Time/Datetime into Utf8 with the given format.
     /// See [chrono strftime/strptime](https://docs.rs/chrono/0.4.19/chrono/format/strftime/index.html).
+    ///
+    /// Durations are formatted with `"iso"` (ISO 8601) or `"polars"`.
     pub fn to_string(self, format: &str) -> Expr {
         let format = format.to_string();
         let function = move |s: Series| TemporalMethods::to_string(&s, &format).map(Some);
@@ -263,8 +265,25 @@ impl DateLikeNameSpace {
         )
     }
 
+    /// Assign a session id to every value of a sorted time or index column. The ids start at
+    /// zero and increment when the time since the previous value is larger than `gap`, e.g.
+    /// `"30m"`. Calendar months, quarters and years are not supported as gap.
+    ///
+    /// To sessionize per partition, use a window expression, e.g.
+    /// `col("time").dt().session_id("30m").over([col("user")])`.
+    pub fn session_id(self, gap: &str) -> Expr {
+        self.0
+            .apply_private(FunctionExpr::TemporalExpr(TemporalFunction::SessionId(
+                Duration::parse(gap),
+            )))
+    }
+
     /// Offset this `Date/Datetime` by a given offset [`Duration`].
     /// This will take leap years/ months into account.
+    ///
+    /// Suffix a calendar offset with `"_saturating"` to clamp days past the end of the
+    /// resulting month, or with `"_eom"` to also keep month ends at the month end, e.g.
+    /// `"1mo_eom"` offsets 2022-02-28 to 2022-03-31.
     #[cfg(feature = "date_offset")]
     pub fn offset_by(self, by: Expr) -> Expr {
         self.0
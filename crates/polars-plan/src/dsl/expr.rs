@@ -161,6 +161,13 @@ pub enum Expr {
     /// `Expr::Wildcard`
     /// `Expr::Exclude`
     Selector(super::selector::Selector),
+    /// An expression implemented outside of polars, see [`Expr::extension`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    Extension {
+        input: Vec<Expr>,
+        function: SpecialEq<Arc<dyn ExprExtension>>,
+        options: FunctionOptions,
+    },
 }
 
 #[allow(clippy::derived_hash_with_manual_eq)]
@@ -220,6 +227,15 @@ impl Hash for Expr {
                 std::mem::discriminant(function).hash(state);
                 options.hash(state);
             },
+            Expr::Extension {
+                input,
+                function,
+                options,
+            } => {
+                input.hash(state);
+                function.name().hash(state);
+                options.hash(state);
+            },
             // already hashed by discriminant
             Expr::Wildcard | Expr::Count => {},
             #[allow(unreachable_code)]
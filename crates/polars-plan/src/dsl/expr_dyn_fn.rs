@@ -1,6 +1,7 @@
 use std::fmt::Formatter;
 use std::ops::Deref;
 
+use polars_core::frame::group_by::GroupsIndicator;
 use polars_core::utils::get_supertype;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -140,6 +141,73 @@ impl Debug for dyn RenameAliasFn {
     }
 }
 
+/// An expression that is implemented outside of polars, see [`Expr::extension`].
+///
+/// Unlike a [`SeriesUdf`], an extension is aware of the groups in a `group_by` or window
+/// context: [`ExprExtension::evaluate_on_groups`] gets the values of all groups at once and
+/// can compute all groups in a single pass.
+pub trait ExprExtension: Send + Sync {
+    /// The name of the expression, used when formatting the plan.
+    fn name(&self) -> &str;
+
+    /// Evaluate the expression on its evaluated inputs.
+    fn evaluate(&self, inputs: &[Series]) -> PolarsResult<Series>;
+
+    /// Evaluate the expression per group and return a [`Series`] with a value per group.
+    ///
+    /// `groups` are the groups of the values of `inputs`. The default calls
+    /// [`ExprExtension::evaluate`] on the values of every group, which must give a single value.
+    ///
+    /// This is not called if the expression is element-wise.
+    fn evaluate_on_groups(&self, inputs: &[Series], groups: &GroupsProxy) -> PolarsResult<Series> {
+        let mut out: Option<Series> = None;
+        for g in groups.iter() {
+            let inputs = inputs
+                .iter()
+                .map(|s| match &g {
+                    GroupsIndicator::Idx((_, idx)) => s.take_slice(idx),
+                    GroupsIndicator::Slice([first, len]) => {
+                        Ok(s.slice(*first as i64, *len as usize))
+                    },
+                })
+                .collect::<PolarsResult<Vec<_>>>()?;
+            let agg = self.evaluate(&inputs)?;
+            polars_ensure!(
+                agg.len() == 1,
+                ComputeError: "expression '{}' should give a single value per group, got {}",
+                self.name(), agg.len()
+            );
+            match out.as_mut() {
+                Some(out) => {
+                    out.append(&agg)?;
+                },
+                None => out = Some(agg),
+            }
+        }
+        let fields = inputs
+            .iter()
+            .map(|s| s.field().into_owned())
+            .collect::<Vec<_>>();
+        let field = self.to_field(&fields)?;
+        Ok(out.unwrap_or_else(|| Series::new_empty(field.name(), field.data_type())))
+    }
+
+    /// The output field of the expression, given the fields of its inputs.
+    fn to_field(&self, fields: &[Field]) -> PolarsResult<Field>;
+
+    /// Whether the expression is element-wise. An element-wise expression is evaluated on the
+    /// values of all groups at once with [`ExprExtension::evaluate`] and keeps the groups.
+    fn is_elementwise(&self) -> bool {
+        false
+    }
+}
+
+impl Debug for dyn ExprExtension {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
 #[derive(Clone)]
 /// Wrapper type that has special equality properties
 /// depending on the inner type specialization
@@ -8,6 +8,15 @@ pub enum ArrayFunction {
     Min,
     Max,
     Sum,
+    Mean,
+    Std(u8),
+    Any,
+    All,
+    ArgMin,
+    ArgMax,
+    #[cfg(feature = "is_in")]
+    Contains,
+    ToList,
     Unique(bool),
 }
 
@@ -18,6 +27,15 @@ impl Display for ArrayFunction {
             Min => "min",
             Max => "max",
             Sum => "sum",
+            Mean => "mean",
+            Std(_) => "std",
+            Any => "any",
+            All => "all",
+            ArgMin => "arg_min",
+            ArgMax => "arg_max",
+            #[cfg(feature = "is_in")]
+            Contains => "contains",
+            ToList => "to_list",
             Unique(_) => "unique",
         };
 
@@ -37,6 +55,46 @@ pub(super) fn sum(s: &Series) -> PolarsResult<Series> {
     s.array()?.array_sum()
 }
 
+pub(super) fn mean(s: &Series) -> PolarsResult<Series> {
+    s.array()?.array_mean()
+}
+
+pub(super) fn std(s: &Series, ddof: u8) -> PolarsResult<Series> {
+    s.array()?.array_std(ddof)
+}
+
+pub(super) fn any(s: &Series) -> PolarsResult<Series> {
+    s.array()?.array_any()
+}
+
+pub(super) fn all(s: &Series) -> PolarsResult<Series> {
+    s.array()?.array_all()
+}
+
+pub(super) fn arg_min(s: &Series) -> PolarsResult<Series> {
+    Ok(s.array()?.array_arg_min().into_series())
+}
+
+pub(super) fn arg_max(s: &Series) -> PolarsResult<Series> {
+    Ok(s.array()?.array_arg_max().into_series())
+}
+
+#[cfg(feature = "is_in")]
+pub(super) fn contains(args: &mut [Series]) -> PolarsResult<Option<Series>> {
+    let array = &args[0];
+    let item = &args[1];
+
+    let list = array.array()?.array_to_list()?.into_series();
+    polars_ops::prelude::is_in(item, &list).map(|mut ca| {
+        ca.rename(array.name());
+        Some(ca.into_series())
+    })
+}
+
+pub(super) fn to_list(s: &Series) -> PolarsResult<Series> {
+    s.array()?.array_to_list().map(|ca| ca.into_series())
+}
+
 pub(super) fn unique(s: &Series, stable: bool) -> PolarsResult<Series> {
     let ca = s.array()?;
     let out = if stable {
@@ -8,3 +8,12 @@ pub(super) fn clip(s: &[Series], has_min: bool, has_max: bool) -> PolarsResult<S
         _ => unreachable!(),
     }
 }
+
+pub(super) fn clip_quantile(
+    s: &Series,
+    lower: f64,
+    upper: f64,
+    interpol: QuantileInterpolOptions,
+) -> PolarsResult<Series> {
+    polars_ops::prelude::clip_quantile(s, lower, upper, interpol)
+}
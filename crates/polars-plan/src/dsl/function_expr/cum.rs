@@ -1,7 +1,27 @@
 use super::*;
 
-pub(super) fn cumcount(s: &Series, reverse: bool) -> PolarsResult<Series> {
-    if reverse {
+pub(super) fn cumcount(s: &Series, reverse: bool, ignore_nulls: bool) -> PolarsResult<Series> {
+    if ignore_nulls {
+        let mut valid = s.is_not_null();
+        if reverse {
+            valid = valid.reverse();
+        }
+        let mut count = 0 as IdxSize;
+        let ca: NoNull<IdxCa> = valid
+            .into_no_null_iter()
+            .map(|valid| {
+                let out = count;
+                count += valid as IdxSize;
+                out
+            })
+            .collect();
+        let mut ca = ca.into_inner();
+        if reverse {
+            ca = ca.reverse();
+        }
+        ca.rename(s.name());
+        Ok(ca.into_series())
+    } else if reverse {
         let ca: NoNull<UInt32Chunked> = (0u32..s.len() as u32).rev().collect();
         let mut ca = ca.into_inner();
         ca.rename(s.name());
@@ -14,6 +34,16 @@ pub(super) fn cumcount(s: &Series, reverse: bool) -> PolarsResult<Series> {
     }
 }
 
+#[cfg(feature = "is_first_distinct")]
+pub(super) fn cum_n_unique(s: &Series, reverse: bool) -> PolarsResult<Series> {
+    if reverse {
+        let out = polars_ops::prelude::cum_n_unique(&s.reverse())?;
+        Ok(out.reverse().into_series())
+    } else {
+        polars_ops::prelude::cum_n_unique(s).map(|ca| ca.into_series())
+    }
+}
+
 pub(super) fn cumsum(s: &Series, reverse: bool) -> PolarsResult<Series> {
     Ok(s.cumsum(reverse))
 }
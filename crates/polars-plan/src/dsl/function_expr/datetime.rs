@@ -41,6 +41,7 @@ pub enum TemporalFunction {
     #[cfg(feature = "timezones")]
     DSTOffset,
     Round(String, String),
+    SessionId(Duration),
     #[cfg(feature = "timezones")]
     ReplaceTimeZone(Option<TimeZone>),
     Combine(TimeUnit),
@@ -83,6 +84,7 @@ impl Display for TemporalFunction {
             #[cfg(feature = "timezones")]
             DSTOffset => "dst_offset",
             Round(..) => "round",
+            SessionId(_) => "session_id",
             #[cfg(feature = "timezones")]
             ReplaceTimeZone(_) => "replace_time_zone",
             DatetimeFunction { .. } => return write!(f, "dt.datetime"),
@@ -201,11 +203,21 @@ pub(super) fn timestamp(s: &Series, tu: TimeUnit) -> PolarsResult<Series> {
     s.timestamp(tu).map(|ca| ca.into_series())
 }
 
+pub(super) fn session_id(s: &Series, gap: &Duration) -> PolarsResult<Series> {
+    polars_time::session_id(s, gap).map(|ca| ca.into_series())
+}
+
 pub(super) fn truncate(s: &[Series], offset: &str) -> PolarsResult<Series> {
     let time_series = &s[0];
     let every = s[1].utf8()?;
     let ambiguous = s[2].utf8()?;
 
+    let preserve_sortedness = match time_series.dtype() {
+        // an offset may push times past midnight, where they wrap around
+        #[cfg(feature = "dtype-time")]
+        DataType::Time => Duration::parse(offset).is_zero(),
+        _ => true,
+    };
     let mut out = match time_series.dtype() {
         DataType::Datetime(_, tz) => match tz {
             #[cfg(feature = "timezones")]
@@ -222,9 +234,16 @@ pub(super) fn truncate(s: &[Series], offset: &str) -> PolarsResult<Series> {
             .date()?
             .truncate(None, every, offset, ambiguous)?
             .into_series(),
-        dt => polars_bail!(opq = round, got = dt, expected = "date/datetime"),
+        #[cfg(feature = "dtype-time")]
+        DataType::Time => time_series
+            .time()?
+            .truncate(None, every, offset, ambiguous)?
+            .into_series(),
+        dt => polars_bail!(opq = truncate, got = dt, expected = "date/datetime/time"),
     };
-    out.set_sorted_flag(time_series.is_sorted_flag());
+    if preserve_sortedness {
+        out.set_sorted_flag(time_series.is_sorted_flag());
+    }
     Ok(out)
 }
 
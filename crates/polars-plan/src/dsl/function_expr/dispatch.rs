@@ -23,6 +23,37 @@ pub(super) fn interpolate(s: &Series, method: InterpolationMethod) -> PolarsResu
     Ok(polars_ops::prelude::interpolate(s, method))
 }
 
+pub(super) fn min_by(s: &[Series]) -> PolarsResult<Series> {
+    extremum_by(s, "min_by", |by| by.arg_min())
+}
+
+pub(super) fn max_by(s: &[Series]) -> PolarsResult<Series> {
+    extremum_by(s, "max_by", |by| by.arg_max())
+}
+
+fn extremum_by(
+    s: &[Series],
+    name: &str,
+    arg_extremum: fn(&Series) -> Option<usize>,
+) -> PolarsResult<Series> {
+    let values = &s[0];
+    let by = &s[1];
+    polars_ensure!(
+        values.len() == by.len(),
+        ShapeMismatch: "`{}` expected `by` of length {}, got {}", name, values.len(), by.len()
+    );
+    // a lexically ordered categorical is compared by its strings, not by its physical indices
+    #[cfg(feature = "dtype-categorical")]
+    let by = &match by.categorical() {
+        Ok(ca) if ca.uses_lexical_ordering() => by.cast(&DataType::Utf8)?,
+        _ => by.clone(),
+    };
+    Ok(match arg_extremum(by) {
+        Some(idx) => values.slice(idx as i64, 1),
+        None => Series::full_null(values.name(), 1, values.dtype()),
+    })
+}
+
 pub(super) fn to_physical(s: &Series) -> PolarsResult<Series> {
     Ok(s.to_physical_repr().into_owned())
 }
@@ -33,6 +33,8 @@ pub enum ListFunction {
     #[cfg(feature = "list_any_all")]
     All,
     Join,
+    #[cfg(feature = "dtype-array")]
+    ToArray(usize),
 }
 
 impl Display for ListFunction {
@@ -73,6 +75,8 @@ impl Display for ListFunction {
             #[cfg(feature = "list_any_all")]
             All => "all",
             Join => "join",
+            #[cfg(feature = "dtype-array")]
+            ToArray(_) => "to_array",
         };
         write!(f, "list.{name}")
     }
@@ -359,3 +363,9 @@ pub(super) fn join(s: &[Series]) -> PolarsResult<Series> {
     let separator = s[1].utf8()?;
     Ok(ca.lst_join(separator)?.into_series())
 }
+
+#[cfg(feature = "dtype-array")]
+pub(super) fn to_array(s: &Series, width: usize) -> PolarsResult<Series> {
+    let inner = s.list()?.inner_dtype();
+    s.cast(&DataType::Array(Box::new(inner), width))
+}
@@ -67,10 +67,13 @@ pub(crate) use correlation::CorrelationMethod;
 pub(crate) use fused::FusedOperator;
 pub(super) use list::ListFunction;
 use polars_core::prelude::*;
+use polars_ops::prelude::nan_to_null;
+#[cfg(feature = "scale")]
+use polars_ops::prelude::ScaleMethod;
 #[cfg(feature = "cutqcut")]
 use polars_ops::prelude::{cut, qcut};
 #[cfg(feature = "rle")]
-use polars_ops::prelude::{rle, rle_id};
+use polars_ops::prelude::{rle, rle_decode, rle_id};
 #[cfg(feature = "random")]
 pub(crate) use random::RandomMethod;
 use schema::FieldsMapper;
@@ -86,10 +89,12 @@ pub(super) use self::datetime::TemporalFunction;
 pub(super) use self::pow::PowFunction;
 #[cfg(feature = "range")]
 pub(super) use self::range::RangeFunction;
+#[cfg(all(feature = "rolling_window", feature = "moment"))]
+pub(super) use self::rolling::RollingFunction;
 #[cfg(feature = "strings")]
 pub(crate) use self::strings::StringFunction;
 #[cfg(feature = "dtype-struct")]
-pub(super) use self::struct_::StructFunction;
+pub(crate) use self::struct_::StructFunction;
 #[cfg(feature = "trigonometry")]
 pub(super) use self::trigonometry::TrigonometricFunction;
 use super::*;
@@ -126,20 +131,25 @@ pub enum FunctionExpr {
         super_type: DataType,
     },
     #[cfg(all(feature = "rolling_window", feature = "moment"))]
-    // if we add more, make a sub enum
-    RollingSkew {
-        window_size: usize,
-        bias: bool,
-    },
+    Rolling(RollingFunction),
     ShiftAndFill {
         periods: i64,
     },
     DropNans,
+    NanToNull,
     #[cfg(feature = "round_series")]
     Clip {
         has_min: bool,
         has_max: bool,
     },
+    #[cfg(feature = "round_series")]
+    ClipQuantile {
+        lower: f64,
+        upper: f64,
+        interpol: QuantileInterpolOptions,
+    },
+    #[cfg(feature = "scale")]
+    Scale(ScaleMethod),
     ListExpr(ListFunction),
     #[cfg(feature = "dtype-array")]
     ArrayExpr(ArrayFunction),
@@ -152,6 +162,11 @@ pub enum FunctionExpr {
     Shift(i64),
     Cumcount {
         reverse: bool,
+        ignore_nulls: bool,
+    },
+    #[cfg(feature = "is_first_distinct")]
+    CumNUnique {
+        reverse: bool,
     },
     Cumsum {
         reverse: bool,
@@ -169,6 +184,13 @@ pub enum FunctionExpr {
     Boolean(BooleanFunction),
     #[cfg(feature = "approx_unique")]
     ApproxNUnique,
+    #[cfg(feature = "approx_quantile")]
+    ApproxQuantile(f64),
+    #[cfg(feature = "reservoir_sample")]
+    ReservoirSample {
+        n: usize,
+        seed: Option<u64>,
+    },
     #[cfg(feature = "dtype-categorical")]
     Categorical(CategoricalFunction),
     Coalesce,
@@ -201,6 +223,8 @@ pub enum FunctionExpr {
     Ceil,
     UpperBound,
     LowerBound,
+    MinBy,
+    MaxBy,
     #[cfg(feature = "fused")]
     Fused(fused::FusedOperator),
     ConcatExpr(bool),
@@ -230,6 +254,8 @@ pub enum FunctionExpr {
     #[cfg(feature = "rle")]
     RLE,
     #[cfg(feature = "rle")]
+    RLEDecode,
+    #[cfg(feature = "rle")]
     RLEID,
     ToPhysical,
     #[cfg(feature = "random")]
@@ -254,6 +280,8 @@ impl Hash for FunctionExpr {
             FunctionExpr::SearchSorted(f) => f.hash(state),
             FunctionExpr::BinaryExpr(f) => f.hash(state),
             FunctionExpr::Boolean(f) => f.hash(state),
+            #[cfg(all(feature = "rolling_window", feature = "moment"))]
+            FunctionExpr::Rolling(f) => f.hash(state),
             #[cfg(feature = "strings")]
             FunctionExpr::StringExpr(f) => f.hash(state),
             FunctionExpr::ListExpr(f) => f.hash(state),
@@ -263,6 +291,15 @@ impl Hash for FunctionExpr {
             FunctionExpr::StructExpr(f) => f.hash(state),
             #[cfg(feature = "random")]
             FunctionExpr::Random { method, .. } => method.hash(state),
+            #[cfg(feature = "scale")]
+            FunctionExpr::Scale(method) => method.hash(state),
+            #[cfg(feature = "approx_quantile")]
+            FunctionExpr::ApproxQuantile(quantile) => quantile.to_bits().hash(state),
+            #[cfg(feature = "reservoir_sample")]
+            FunctionExpr::ReservoirSample { n, seed } => {
+                n.hash(state);
+                seed.hash(state);
+            },
             FunctionExpr::Correlation { method, .. } => method.hash(state),
             #[cfg(feature = "range")]
             FunctionExpr::Range(f) => f.hash(state),
@@ -320,9 +357,10 @@ impl Display for FunctionExpr {
             Sign => "sign",
             FillNull { .. } => "fill_null",
             #[cfg(all(feature = "rolling_window", feature = "moment"))]
-            RollingSkew { .. } => "rolling_skew",
+            Rolling(func) => return write!(f, "{func}"),
             ShiftAndFill { .. } => "shift_and_fill",
             DropNans => "drop_nans",
+            NanToNull => "nan_to_null",
             #[cfg(feature = "round_series")]
             Clip { has_min, has_max } => match (has_min, has_max) {
                 (true, true) => "clip",
@@ -330,6 +368,14 @@ impl Display for FunctionExpr {
                 (true, false) => "clip_min",
                 _ => unreachable!(),
             },
+            #[cfg(feature = "round_series")]
+            ClipQuantile { .. } => "clip_quantile",
+            #[cfg(feature = "scale")]
+            Scale(method) => match method {
+                ScaleMethod::Standard { .. } => "standardize",
+                ScaleMethod::MinMax => "min_max_scale",
+                ScaleMethod::Robust => "robust_scale",
+            },
             ListExpr(func) => return write!(f, "{func}"),
             #[cfg(feature = "dtype-struct")]
             StructExpr(func) => return write!(f, "{func}"),
@@ -345,6 +391,8 @@ impl Display for FunctionExpr {
             },
             Shift(_) => "shift",
             Cumcount { .. } => "cumcount",
+            #[cfg(feature = "is_first_distinct")]
+            CumNUnique { .. } => "cum_n_unique",
             Cumsum { .. } => "cumsum",
             Cumprod { .. } => "cumprod",
             Cummin { .. } => "cummin",
@@ -353,6 +401,10 @@ impl Display for FunctionExpr {
             Boolean(func) => return write!(f, "{func}"),
             #[cfg(feature = "approx_unique")]
             ApproxNUnique => "approx_n_unique",
+            #[cfg(feature = "approx_quantile")]
+            ApproxQuantile(_) => "approx_quantile",
+            #[cfg(feature = "reservoir_sample")]
+            ReservoirSample { .. } => "reservoir_sample",
             #[cfg(feature = "dtype-categorical")]
             Categorical(func) => return write!(f, "{func}"),
             Coalesce => "coalesce",
@@ -384,6 +436,8 @@ impl Display for FunctionExpr {
             Ceil => "ceil",
             UpperBound => "upper_bound",
             LowerBound => "lower_bound",
+            MinBy => "min_by",
+            MaxBy => "max_by",
             #[cfg(feature = "fused")]
             Fused(fused) => return Display::fmt(fused, f),
             #[cfg(feature = "dtype-array")]
@@ -401,6 +455,8 @@ impl Display for FunctionExpr {
             #[cfg(feature = "rle")]
             RLE => "rle",
             #[cfg(feature = "rle")]
+            RLEDecode => "rle_decode",
+            #[cfg(feature = "rle")]
             RLEID => "rle_id",
             ToPhysical => "to_physical",
             #[cfg(feature = "random")]
@@ -548,17 +604,24 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             },
 
             #[cfg(all(feature = "rolling_window", feature = "moment"))]
-            RollingSkew { window_size, bias } => {
-                map!(rolling::rolling_skew, window_size, bias)
-            },
+            Rolling(func) => func.into(),
             ShiftAndFill { periods } => {
                 map_as_slice!(shift_and_fill::shift_and_fill, periods)
             },
             DropNans => map_owned!(nan::drop_nans),
+            NanToNull => map!(nan_to_null),
             #[cfg(feature = "round_series")]
             Clip { has_min, has_max } => {
                 map_as_slice!(clip::clip, has_min, has_max)
             },
+            #[cfg(feature = "round_series")]
+            ClipQuantile {
+                lower,
+                upper,
+                interpol,
+            } => map!(clip::clip_quantile, lower, upper, interpol),
+            #[cfg(feature = "scale")]
+            Scale(method) => map!(polars_ops::prelude::scale, method),
             ListExpr(lf) => {
                 use ListFunction::*;
                 match lf {
@@ -589,6 +652,8 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
                     #[cfg(feature = "list_any_all")]
                     All => map!(list::lst_all),
                     Join => map_as_slice!(list::join),
+                    #[cfg(feature = "dtype-array")]
+                    ToArray(width) => map!(list::to_array, width),
                 }
             },
             #[cfg(feature = "dtype-array")]
@@ -598,6 +663,15 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
                     Min => map!(array::min),
                     Max => map!(array::max),
                     Sum => map!(array::sum),
+                    Mean => map!(array::mean),
+                    Std(ddof) => map!(array::std, ddof),
+                    Any => map!(array::any),
+                    All => map!(array::all),
+                    ArgMin => map!(array::arg_min),
+                    ArgMax => map!(array::arg_max),
+                    #[cfg(feature = "is_in")]
+                    Contains => wrap!(array::contains),
+                    ToList => map!(array::to_list),
                     Unique(stable) => map!(array::unique, stable),
                 }
             },
@@ -619,7 +693,12 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
                 map_as_slice!(top_k, descending)
             },
             Shift(periods) => map!(dispatch::shift, periods),
-            Cumcount { reverse } => map!(cum::cumcount, reverse),
+            Cumcount {
+                reverse,
+                ignore_nulls,
+            } => map!(cum::cumcount, reverse, ignore_nulls),
+            #[cfg(feature = "is_first_distinct")]
+            CumNUnique { reverse } => map!(cum::cum_n_unique, reverse),
             Cumsum { reverse } => map!(cum::cumsum, reverse),
             Cumprod { reverse } => map!(cum::cumprod, reverse),
             Cummin { reverse } => map!(cum::cummin, reverse),
@@ -628,6 +707,10 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             Boolean(func) => func.into(),
             #[cfg(feature = "approx_unique")]
             ApproxNUnique => map!(dispatch::approx_n_unique),
+            #[cfg(feature = "approx_quantile")]
+            ApproxQuantile(quantile) => map!(polars_ops::prelude::approx_quantile, quantile),
+            #[cfg(feature = "reservoir_sample")]
+            ReservoirSample { n, seed } => map!(polars_ops::prelude::reservoir_sample, n, seed),
             #[cfg(feature = "dtype-categorical")]
             Categorical(func) => func.into(),
             Coalesce => map_as_slice!(fill_null::coalesce),
@@ -655,6 +738,8 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             Ceil => map!(round::ceil),
             UpperBound => map!(bounds::upper_bound),
             LowerBound => map!(bounds::lower_bound),
+            MinBy => map_as_slice!(dispatch::min_by),
+            MaxBy => map_as_slice!(dispatch::max_by),
             #[cfg(feature = "fused")]
             Fused(op) => map_as_slice!(fused::fused, op),
             ConcatExpr(rechunk) => map_as_slice!(concat::concat_expr, rechunk),
@@ -694,6 +779,8 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             #[cfg(feature = "rle")]
             RLE => map!(rle),
             #[cfg(feature = "rle")]
+            RLEDecode => map!(rle_decode),
+            #[cfg(feature = "rle")]
             RLEID => map!(rle_id),
             ToPhysical => map!(dispatch::to_physical),
             #[cfg(feature = "random")]
@@ -768,8 +855,15 @@ impl From<StringFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             SplitExact { n, inclusive } => map_as_slice!(strings::split_exact, n, inclusive),
             #[cfg(feature = "dtype-struct")]
             SplitN(n) => map_as_slice!(strings::splitn, n),
+            #[cfg(feature = "regex")]
+            SplitRegex(inclusive) => map_as_slice!(strings::split_regex, inclusive),
+            #[cfg(all(feature = "regex", feature = "dtype-struct"))]
+            SplitNRegex(n) => map_as_slice!(strings::splitn_regex, n),
             #[cfg(feature = "concat_str")]
-            ConcatVertical(delimiter) => map!(strings::concat, &delimiter),
+            ConcatVertical {
+                delimiter,
+                ignore_nulls,
+            } => map!(strings::concat, &delimiter, ignore_nulls),
             #[cfg(feature = "concat_str")]
             ConcatHorizontal(delimiter) => map_as_slice!(strings::concat_hor, &delimiter),
             #[cfg(feature = "regex")]
@@ -851,6 +945,7 @@ impl From<TemporalFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             #[cfg(feature = "timezones")]
             DSTOffset => map!(datetime::dst_offset),
             Round(every, offset) => map_as_slice!(datetime::round, &every, &offset),
+            SessionId(gap) => map!(datetime::session_id, &gap),
             #[cfg(feature = "timezones")]
             ReplaceTimeZone(tz) => {
                 map_as_slice!(dispatch::replace_time_zone, tz.as_deref())
@@ -1,5 +1,53 @@
 use super::*;
 
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, PartialEq, Debug, Eq, Hash)]
+pub enum RollingFunction {
+    Skew {
+        window_size: usize,
+        bias: bool,
+    },
+    Kurtosis {
+        window_size: usize,
+        fisher: bool,
+        bias: bool,
+    },
+}
+
+impl Display for RollingFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use RollingFunction::*;
+        let name = match self {
+            Skew { .. } => "rolling_skew",
+            Kurtosis { .. } => "rolling_kurtosis",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl From<RollingFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
+    fn from(func: RollingFunction) -> Self {
+        use RollingFunction::*;
+        match func {
+            Skew { window_size, bias } => map!(rolling_skew, window_size, bias),
+            Kurtosis {
+                window_size,
+                fisher,
+                bias,
+            } => map!(rolling_kurtosis, window_size, fisher, bias),
+        }
+    }
+}
+
 pub(super) fn rolling_skew(s: &Series, window_size: usize, bias: bool) -> PolarsResult<Series> {
     s.rolling_skew(window_size, bias)
 }
+
+pub(super) fn rolling_kurtosis(
+    s: &Series,
+    window_size: usize,
+    fisher: bool,
+    bias: bool,
+) -> PolarsResult<Series> {
+    s.rolling_kurtosis(window_size, fisher, bias)
+}
@@ -55,6 +55,7 @@ impl FunctionExpr {
                     #[cfg(feature = "timezones")]
                     DSTOffset => DataType::Duration(TimeUnit::Milliseconds),
                     Round(..) => mapper.with_same_dtype().unwrap().dtype,
+                    SessionId(_) => IDX_DTYPE,
                     #[cfg(feature = "timezones")]
                     ReplaceTimeZone(tz) => return mapper.map_datetime_dtype_timezone(tz.as_ref()),
                     DatetimeFunction {
@@ -89,11 +90,13 @@ impl FunctionExpr {
             Sign => mapper.with_dtype(DataType::Int64),
             FillNull { super_type, .. } => mapper.with_dtype(super_type.clone()),
             #[cfg(all(feature = "rolling_window", feature = "moment"))]
-            RollingSkew { .. } => mapper.map_to_float_dtype(),
+            Rolling(_) => mapper.map_to_float_dtype(),
             ShiftAndFill { .. } => mapper.with_same_dtype(),
-            DropNans => mapper.with_same_dtype(),
+            DropNans | NanToNull => mapper.with_same_dtype(),
             #[cfg(feature = "round_series")]
-            Clip { .. } => mapper.with_same_dtype(),
+            Clip { .. } | ClipQuantile { .. } => mapper.with_same_dtype(),
+            #[cfg(feature = "scale")]
+            Scale(_) => mapper.map_to_float_dtype(),
             ListExpr(l) => {
                 use ListFunction::*;
                 match l {
@@ -124,6 +127,14 @@ impl FunctionExpr {
                     #[cfg(feature = "list_any_all")]
                     All => mapper.with_dtype(DataType::Boolean),
                     Join => mapper.with_dtype(DataType::Utf8),
+                    #[cfg(feature = "dtype-array")]
+                    ToArray(width) => mapper.try_map_dtype(|dt| {
+                        if let DataType::List(inner) = dt {
+                            Ok(DataType::Array(inner.clone(), *width))
+                        } else {
+                            polars_bail!(ComputeError: "expected list dtype")
+                        }
+                    }),
                 }
             },
             #[cfg(feature = "dtype-array")]
@@ -132,7 +143,12 @@ impl FunctionExpr {
                 match af {
                     Min | Max => mapper.with_same_dtype(),
                     Sum => mapper.nested_sum_type(),
-                    Unique(_) => mapper.try_map_dtype(|dt| {
+                    Mean | Std(_) => mapper.with_dtype(DataType::Float64),
+                    Any | All => mapper.with_dtype(DataType::Boolean),
+                    ArgMin | ArgMax => mapper.with_dtype(IDX_DTYPE),
+                    #[cfg(feature = "is_in")]
+                    Contains => mapper.with_dtype(DataType::Boolean),
+                    ToList | Unique(_) => mapper.try_map_dtype(|dt| {
                         if let DataType::Array(inner, _) = dt {
                             Ok(DataType::List(inner.clone()))
                         } else {
@@ -155,12 +171,18 @@ impl FunctionExpr {
             #[cfg(feature = "dtype-categorical")]
             Categorical(func) => func.get_field(mapper),
             Cumcount { .. } => mapper.with_dtype(IDX_DTYPE),
+            #[cfg(feature = "is_first_distinct")]
+            CumNUnique { .. } => mapper.with_dtype(IDX_DTYPE),
             Cumsum { .. } => mapper.map_dtype(cum::dtypes::cumsum),
             Cumprod { .. } => mapper.map_dtype(cum::dtypes::cumprod),
             Cummin { .. } => mapper.with_same_dtype(),
             Cummax { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "approx_unique")]
             ApproxNUnique => mapper.with_dtype(IDX_DTYPE),
+            #[cfg(feature = "approx_quantile")]
+            ApproxQuantile(_) => mapper.with_dtype(DataType::Float64),
+            #[cfg(feature = "reservoir_sample")]
+            ReservoirSample { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "diff")]
             Diff(_, _) => mapper.map_dtype(|dt| match dt {
                 #[cfg(feature = "dtype-datetime")]
@@ -205,6 +227,7 @@ impl FunctionExpr {
             #[cfg(feature = "round_series")]
             Round { .. } | Floor | Ceil => mapper.with_same_dtype(),
             UpperBound | LowerBound => mapper.with_same_dtype(),
+            MinBy | MaxBy => mapper.with_same_dtype(),
             #[cfg(feature = "fused")]
             Fused(_) => mapper.map_to_supertype(),
             ConcatExpr(_) => mapper.map_to_supertype(),
@@ -252,11 +275,18 @@ impl FunctionExpr {
             #[cfg(feature = "rle")]
             RLE => mapper.map_dtype(|dt| {
                 DataType::Struct(vec![
-                    Field::new("lengths", DataType::UInt64),
+                    Field::new("lengths", DataType::Int32),
                     Field::new("values", dt.clone()),
                 ])
             }),
             #[cfg(feature = "rle")]
+            RLEDecode => mapper.try_map_dtype(|dt| match dt {
+                DataType::Struct(fields) if fields.len() == 2 => Ok(fields[1].data_type().clone()),
+                dt => polars_bail!(
+                    SchemaMismatch: "rle_decode expects a struct with the fields 'lengths' and 'values', got {}", dt
+                ),
+            }),
+            #[cfg(feature = "rle")]
             RLEID => mapper.with_dtype(DataType::UInt32),
             ToPhysical => mapper.to_physical_type(),
             #[cfg(feature = "random")]
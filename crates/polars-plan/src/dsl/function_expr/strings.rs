@@ -23,7 +23,10 @@ pub enum StringFunction {
     #[cfg(feature = "concat_str")]
     ConcatHorizontal(String),
     #[cfg(feature = "concat_str")]
-    ConcatVertical(String),
+    ConcatVertical {
+        delimiter: String,
+        ignore_nulls: bool,
+    },
     #[cfg(feature = "regex")]
     Contains {
         literal: bool,
@@ -83,9 +86,13 @@ pub enum StringFunction {
     },
     #[cfg(feature = "dtype-struct")]
     SplitN(usize),
+    #[cfg(all(feature = "regex", feature = "dtype-struct"))]
+    SplitNRegex(usize),
     #[cfg(feature = "temporal")]
     Strptime(DataType, StrptimeOptions),
     Split(bool),
+    #[cfg(feature = "regex")]
+    SplitRegex(bool),
     #[cfg(feature = "dtype-decimal")]
     ToDecimal(usize),
     #[cfg(feature = "nightly")]
@@ -100,7 +107,7 @@ impl StringFunction {
         use StringFunction::*;
         match self {
             #[cfg(feature = "concat_str")]
-            ConcatVertical(_) | ConcatHorizontal(_) => mapper.with_dtype(DataType::Utf8),
+            ConcatVertical { .. } | ConcatHorizontal(_) => mapper.with_dtype(DataType::Utf8),
             #[cfg(feature = "regex")]
             Contains { .. } => mapper.with_dtype(DataType::Boolean),
             CountMatches(_) => mapper.with_dtype(DataType::UInt32),
@@ -121,6 +128,8 @@ impl StringFunction {
             #[cfg(feature = "temporal")]
             Strptime(dtype, _) => mapper.with_dtype(dtype.clone()),
             Split(_) => mapper.with_dtype(DataType::List(Box::new(DataType::Utf8))),
+            #[cfg(feature = "regex")]
+            SplitRegex(_) => mapper.with_dtype(DataType::List(Box::new(DataType::Utf8))),
             #[cfg(feature = "nightly")]
             Titlecase => mapper.with_same_dtype(),
             #[cfg(feature = "dtype-decimal")]
@@ -147,6 +156,12 @@ impl StringFunction {
                     .map(|i| Field::from_owned(format_smartstring!("field_{i}"), DataType::Utf8))
                     .collect(),
             )),
+            #[cfg(all(feature = "regex", feature = "dtype-struct"))]
+            SplitNRegex(n) => mapper.with_dtype(DataType::Struct(
+                (0..*n)
+                    .map(|i| Field::from_owned(format_smartstring!("field_{i}"), DataType::Utf8))
+                    .collect(),
+            )),
         }
     }
 }
@@ -162,7 +177,7 @@ impl Display for StringFunction {
             #[cfg(feature = "concat_str")]
             StringFunction::ConcatHorizontal(_) => "concat_horizontal",
             #[cfg(feature = "concat_str")]
-            StringFunction::ConcatVertical(_) => "concat_vertical",
+            StringFunction::ConcatVertical { .. } => "concat_vertical",
             StringFunction::Explode => "explode",
             StringFunction::ExtractAll => "extract_all",
             #[cfg(feature = "extract_groups")]
@@ -197,6 +212,8 @@ impl Display for StringFunction {
             },
             #[cfg(feature = "dtype-struct")]
             StringFunction::SplitN(_) => "splitn",
+            #[cfg(all(feature = "regex", feature = "dtype-struct"))]
+            StringFunction::SplitNRegex(_) => "splitn_regex",
             #[cfg(feature = "temporal")]
             StringFunction::Strptime(_, _) => "strptime",
             StringFunction::Split(inclusive) => {
@@ -206,6 +223,14 @@ impl Display for StringFunction {
                     "split_inclusive"
                 }
             },
+            #[cfg(feature = "regex")]
+            StringFunction::SplitRegex(inclusive) => {
+                if *inclusive {
+                    "split_regex_inclusive"
+                } else {
+                    "split_regex"
+                }
+            },
             #[cfg(feature = "nightly")]
             StringFunction::Titlecase => "titlecase",
             #[cfg(feature = "dtype-decimal")]
@@ -380,6 +405,8 @@ pub(super) fn strptime(
             to_datetime(s, &time_unit, time_zone.as_ref(), options)
         },
         DataType::Time => to_time(&s[0], options),
+        #[cfg(feature = "dtype-duration")]
+        DataType::Duration(time_unit) => to_duration(&s[0], time_unit, options),
         dt => polars_bail!(ComputeError: "not implemented for dtype {}", dt),
     }
 }
@@ -404,6 +431,26 @@ pub(super) fn splitn(s: &[Series], n: usize) -> PolarsResult<Series> {
     ca.splitn(by, n).map(|ca| ca.into_series())
 }
 
+#[cfg(all(feature = "regex", feature = "dtype-struct"))]
+pub(super) fn splitn_regex(s: &[Series], n: usize) -> PolarsResult<Series> {
+    let ca = s[0].utf8()?;
+    let by = s[1].utf8()?;
+
+    ca.splitn_regex(by, n).map(|ca| ca.into_series())
+}
+
+#[cfg(feature = "regex")]
+pub(super) fn split_regex(s: &[Series], inclusive: bool) -> PolarsResult<Series> {
+    let ca = s[0].utf8()?;
+    let by = s[1].utf8()?;
+
+    if inclusive {
+        ca.split_regex_inclusive(by).map(|ca| ca.into_series())
+    } else {
+        ca.split_regex(by).map(|ca| ca.into_series())
+    }
+}
+
 pub(super) fn split(s: &[Series], inclusive: bool) -> PolarsResult<Series> {
     let ca = s[0].utf8()?;
     let by = s[1].utf8()?;
@@ -485,6 +532,22 @@ fn to_datetime(
 ) -> PolarsResult<Series> {
     let datetime_strings = &s[0].utf8().unwrap();
     let ambiguous = &s[1].utf8().unwrap();
+    // parsing with a single format is much faster than inferring the format per value
+    let inferred;
+    let options = match (&options.format, options.infer) {
+        (None, true) => {
+            use polars_time::chunkedarray::utf8::infer::{
+                infer_datetime_format, INFER_SAMPLE_SIZE,
+            };
+            let format = infer_datetime_format(datetime_strings, INFER_SAMPLE_SIZE);
+            inferred = StrptimeOptions {
+                format: format.map(|format| format.to_string()),
+                ..options.clone()
+            };
+            &inferred
+        },
+        _ => options,
+    };
     let tz_aware = match &options.format {
         #[cfg(feature = "timezones")]
         Some(format) => TZ_AWARE_RE.is_match(format),
@@ -546,10 +609,23 @@ fn to_time(s: &Series, options: &StrptimeOptions) -> PolarsResult<Series> {
     Ok(out.into_series())
 }
 
+#[cfg(feature = "dtype-duration")]
+fn to_duration(s: &Series, time_unit: TimeUnit, options: &StrptimeOptions) -> PolarsResult<Series> {
+    let ca = s.utf8()?;
+    let out = ca
+        .as_duration(time_unit, options.format.as_deref())?
+        .into_series();
+
+    if options.strict && ca.null_count() != out.null_count() {
+        handle_temporal_parsing_error(ca, &out, options.format.as_deref(), false)?;
+    }
+    Ok(out)
+}
+
 #[cfg(feature = "concat_str")]
-pub(super) fn concat(s: &Series, delimiter: &str) -> PolarsResult<Series> {
+pub(super) fn concat(s: &Series, delimiter: &str, ignore_nulls: bool) -> PolarsResult<Series> {
     let str_s = s.cast(&DataType::Utf8)?;
-    let concat = polars_ops::chunked_array::str_concat(str_s.utf8()?, delimiter);
+    let concat = polars_ops::chunked_array::str_concat(str_s.utf8()?, delimiter, ignore_nulls);
     Ok(concat.into_series())
 }
 
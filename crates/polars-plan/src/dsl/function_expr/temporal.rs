@@ -204,6 +204,30 @@ pub(super) fn date_offset(s: &[Series]) -> PolarsResult<Series> {
             };
             out.cast(&DataType::Datetime(*tu, tz.clone()))
         },
+        #[cfg(feature = "dtype-time")]
+        DataType::Time => {
+            const NS_IN_DAY: i64 = 86_400_000_000_000;
+            let time = ts.time().unwrap();
+            // offset the time as a datetime on the first day of the epoch and wrap around
+            // midnight
+            let out = match offsets.len() {
+                1 => match offsets.get(0) {
+                    Some(offset) => {
+                        let offset = Duration::parse(offset);
+                        time.0
+                            .try_apply(|t| offset.add_ns(t, None).map(|t| t.rem_euclid(NS_IN_DAY)))
+                    },
+                    _ => Ok(time.0.apply(|_| None)),
+                },
+                _ => try_binary_elementwise_values(&time.0, offsets, |t: i64, offset: &str| {
+                    let offset = Duration::parse(offset);
+                    offset.add_ns(t, None).map(|t| t.rem_euclid(NS_IN_DAY))
+                }),
+            };
+            // wrapping around midnight does not preserve sortedness
+            preserve_sortedness = false;
+            out.map(|ca| ca.into_time().into_series())
+        },
         dt => polars_bail!(
             ComputeError: "cannot use 'date_offset' on Series of datatype {}", dt,
         ),
@@ -15,3 +15,18 @@ pub fn as_struct(exprs: Vec<Expr>) -> Expr {
         },
     }
 }
+
+/// Collect several expressions into a struct with the given name, e.g. to pack the
+/// aggregates of a group by into a single column:
+/// `[col("a").sum(), col("b").mean()].as_struct("stats")`.
+#[cfg(feature = "dtype-struct")]
+pub trait AsStructExtension {
+    fn as_struct(self, name: &str) -> Expr;
+}
+
+#[cfg(feature = "dtype-struct")]
+impl<E: AsRef<[Expr]>> AsStructExtension for E {
+    fn as_struct(self, name: &str) -> Expr {
+        as_struct(self.as_ref().to_vec()).alias(name)
+    }
+}
@@ -196,6 +196,16 @@ impl ListNameSpace {
         self.slice(lit(0i64) - n.clone().cast(DataType::Int64), n)
     }
 
+    #[cfg(feature = "dtype-array")]
+    #[allow(clippy::wrong_self_convention)]
+    /// Convert this `List` to an `Array` of the given `width`.
+    /// # Error
+    /// This errors if a sublist does not have exactly `width` items.
+    pub fn to_array(self, width: usize) -> Expr {
+        self.0
+            .map_private(FunctionExpr::ListExpr(ListFunction::ToArray(width)))
+    }
+
     #[cfg(feature = "list_to_struct")]
     #[allow(clippy::wrong_self_convention)]
     /// Convert this `List` to a `Series` of type `Struct`. The width will be determined according to
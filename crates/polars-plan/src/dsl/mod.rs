@@ -55,6 +55,12 @@ use polars_core::prelude::*;
 use polars_core::series::ops::NullBehavior;
 use polars_core::series::IsSorted;
 use polars_core::utils::{try_get_supertype, NoNull};
+#[cfg(feature = "rejects")]
+use polars_ops::prelude::Rejects;
+#[cfg(feature = "scale")]
+use polars_ops::prelude::ScaleMethod;
+#[cfg(feature = "to_dummies")]
+use polars_ops::prelude::ToDummies;
 #[cfg(feature = "rolling_window")]
 use polars_time::prelude::SeriesOpsTime;
 pub(crate) use selector::Selector;
@@ -394,6 +400,22 @@ impl Expr {
         )
     }
 
+    /// Get the value at the position of the minimum of `by`.
+    ///
+    /// In a group_by context this is computed per group in a single pass, without sorting.
+    /// Null values in `by` are ignored; if `by` only contains nulls the result is null.
+    pub fn min_by<E: Into<Expr>>(self, by: E) -> Self {
+        self.apply_many_private(FunctionExpr::MinBy, &[by.into()], true, false)
+    }
+
+    /// Get the value at the position of the maximum of `by`.
+    ///
+    /// In a group_by context this is computed per group in a single pass, without sorting.
+    /// Null values in `by` are ignored; if `by` only contains nulls the result is null.
+    pub fn max_by<E: Into<Expr>>(self, by: E) -> Self {
+        self.apply_many_private(FunctionExpr::MaxBy, &[by.into()], true, false)
+    }
+
     /// Get the index values that would sort this expression.
     pub fn arg_sort(self, sort_options: SortOptions) -> Self {
         let options = FunctionOptions {
@@ -445,6 +467,22 @@ impl Expr {
         }
     }
 
+    /// Cast expression to another data type. Values that cannot be cast become null and are
+    /// collected in `rejects` with their row number and an error message, so the query
+    /// continues where a strict cast would fail.
+    ///
+    /// The row numbers are relative to the input of the operation this expression is part of
+    /// and continue over the batches of that input, see [`Rejects`].
+    #[cfg(feature = "rejects")]
+    pub fn cast_collect_rejects(self, data_type: DataType, rejects: Rejects) -> Self {
+        let dtype = data_type.clone();
+        self.map(
+            move |s| rejects.cast(&s, &dtype).map(Some),
+            GetOutput::from_type(data_type),
+        )
+        .with_fmt("cast_collect_rejects")
+    }
+
     /// Take the values by idx.
     pub fn take<E: Into<Expr>>(self, idx: E) -> Self {
         Expr::Take {
@@ -553,6 +591,33 @@ impl Expr {
         }
     }
 
+    /// Apply an expression implemented outside of polars on `self` and the other `arguments`.
+    ///
+    /// In a `group_by` context, an extension that is not element-wise is an aggregation and
+    /// gives a value per group, see [`ExprExtension::evaluate_on_groups`].
+    pub fn extension(self, function: Arc<dyn ExprExtension>, arguments: &[Expr]) -> Self {
+        let mut input = vec![self];
+        input.extend_from_slice(arguments);
+
+        let options = if function.is_elementwise() {
+            FunctionOptions {
+                collect_groups: ApplyOptions::ApplyFlat,
+                ..Default::default()
+            }
+        } else {
+            FunctionOptions {
+                collect_groups: ApplyOptions::ApplyGroups,
+                auto_explode: true,
+                ..Default::default()
+            }
+        };
+        Expr::Extension {
+            input,
+            function: SpecialEq::new(function),
+            options,
+        }
+    }
+
     /// Apply a function/closure once the logical plan get executed.
     ///
     /// This function is very similar to [apply](Expr::apply), but differs in how it handles aggregations.
@@ -727,6 +792,11 @@ impl Expr {
         self.map_private(BooleanFunction::IsNotNan.into())
     }
 
+    /// Replace the NaN values by null, so that they compare and sort like nulls.
+    pub fn nan_to_null(self) -> Self {
+        self.map_private(FunctionExpr::NanToNull)
+    }
+
     /// Shift the values in the array by some period. See [the eager implementation](polars_core::series::SeriesTrait::shift).
     pub fn shift(self, periods: i64) -> Self {
         self.apply_private(FunctionExpr::Shift(periods))
@@ -744,7 +814,26 @@ impl Expr {
 
     /// Cumulatively count values from 0 to len.
     pub fn cumcount(self, reverse: bool) -> Self {
-        self.apply_private(FunctionExpr::Cumcount { reverse })
+        self.apply_private(FunctionExpr::Cumcount {
+            reverse,
+            ignore_nulls: false,
+        })
+    }
+
+    /// Cumulatively count the non-null values. Every element gets the number of non-null
+    /// values before it, so without nulls this equals [`cumcount`][`Self::cumcount`].
+    pub fn cumcount_ignore_nulls(self, reverse: bool) -> Self {
+        self.apply_private(FunctionExpr::Cumcount {
+            reverse,
+            ignore_nulls: true,
+        })
+    }
+
+    /// Get the number of distinct values up to and including every element. Null is
+    /// counted as a distinct value, like in [`n_unique`][`Self::n_unique`].
+    #[cfg(feature = "is_first_distinct")]
+    pub fn cum_n_unique(self, reverse: bool) -> Self {
+        self.apply_private(FunctionExpr::CumNUnique { reverse })
     }
 
     /// Get an array with the cumulative sum computed at every element.
@@ -875,6 +964,54 @@ impl Expr {
         )
     }
 
+    /// Clip underlying values to the `lower` and `upper` quantiles.
+    ///
+    /// In a group context the quantiles are computed per group.
+    #[cfg(feature = "round_series")]
+    pub fn clip_quantile(self, lower: f64, upper: f64, interpol: QuantileInterpolOptions) -> Self {
+        self.apply_private(FunctionExpr::ClipQuantile {
+            lower,
+            upper,
+            interpol,
+        })
+    }
+
+    /// Winsorize the values: replace the values below the `lower` quantile and above the
+    /// `upper` quantile by the nearest remaining value.
+    ///
+    /// In a group context the limits are computed per group.
+    #[cfg(feature = "round_series")]
+    pub fn winsorize(self, lower: f64, upper: f64) -> Self {
+        self.clip_quantile(lower, upper, QuantileInterpolOptions::Nearest)
+    }
+
+    /// Standardize the values: subtract the mean and divide by the standard deviation.
+    ///
+    /// In a group context the statistics are computed per group. A constant column,
+    /// or group, becomes `0`.
+    #[cfg(feature = "scale")]
+    pub fn standardize(self, ddof: u8) -> Self {
+        self.apply_private(FunctionExpr::Scale(ScaleMethod::Standard { ddof }))
+    }
+
+    /// Scale the values to the range `[0, 1]`.
+    ///
+    /// In a group context the minimum and maximum are computed per group. A constant column,
+    /// or group, becomes `0`.
+    #[cfg(feature = "scale")]
+    pub fn min_max_scale(self) -> Self {
+        self.apply_private(FunctionExpr::Scale(ScaleMethod::MinMax))
+    }
+
+    /// Scale the values robustly: subtract the median and divide by the interquartile range.
+    ///
+    /// In a group context the quantiles are computed per group. A constant column,
+    /// or group, becomes `0`.
+    #[cfg(feature = "scale")]
+    pub fn robust_scale(self) -> Self {
+        self.apply_private(FunctionExpr::Scale(ScaleMethod::Robust))
+    }
+
     /// Convert all values to their absolute/positive value.
     #[cfg(feature = "abs")]
     pub fn abs(self) -> Self {
@@ -1034,6 +1171,29 @@ impl Expr {
             })
     }
 
+    /// Get an approximation of the `quantile`, computed with a t-digest in bounded memory.
+    #[cfg(feature = "approx_quantile")]
+    pub fn approx_quantile(self, quantile: f64) -> Self {
+        self.apply_private(FunctionExpr::ApproxQuantile(quantile))
+            .with_function_options(|mut options| {
+                options.auto_explode = true;
+                options
+            })
+    }
+
+    /// Get an approximation of the median, computed with a t-digest in bounded memory.
+    #[cfg(feature = "approx_quantile")]
+    pub fn approx_median(self) -> Self {
+        self.approx_quantile(0.5)
+    }
+
+    /// Take a uniform sample of at most `n` values with reservoir sampling.
+    /// In a group-by context a sample is taken per group.
+    #[cfg(feature = "reservoir_sample")]
+    pub fn reservoir_sample(self, n: usize, seed: Option<u64>) -> Self {
+        self.apply_private(FunctionExpr::ReservoirSample { n, seed })
+    }
+
     /// "and" operation.
     pub fn and<E: Into<Expr>>(self, expr: E) -> Self {
         binary_expr(self, Operator::And, expr.into())
@@ -1243,55 +1403,8 @@ impl Expr {
         >,
         output_type: GetOutput,
     ) -> Expr {
-        if let Some(ref by) = options.by {
-            self.apply_many(
-                move |s| {
-                    let mut by = s[1].clone();
-                    by = by.rechunk();
-                    let s = &s[0];
-
-                    polars_ensure!(
-                        options.weights.is_none(),
-                        ComputeError: "`weights` is not supported in 'rolling by' expression"
-                    );
-                    let (by, tz) = match by.dtype() {
-                        DataType::Datetime(tu, tz) => {
-                            (by.cast(&DataType::Datetime(*tu, None))?, tz)
-                        },
-                        DataType::Date => (
-                            by.cast(&DataType::Datetime(TimeUnit::Milliseconds, None))?,
-                            &None,
-                        ),
-                        dt => polars_bail!(opq = expr_name, got = dt, expected = "date/datetime"),
-                    };
-                    ensure_sorted_arg(&by, expr_name)?;
-                    let by = by.datetime().unwrap();
-                    let by_values = by.cont_slice().map_err(|_| {
-                        polars_err!(
-                            ComputeError:
-                            "`by` column should not have null values in 'rolling by' expression"
-                        )
-                    })?;
-                    let tu = by.time_unit();
-
-                    let options = RollingOptionsImpl {
-                        window_size: options.window_size,
-                        min_periods: options.min_periods,
-                        weights: None,
-                        center: options.center,
-                        by: Some(by_values),
-                        tu: Some(tu),
-                        tz: tz.as_ref(),
-                        closed_window: options.closed_window,
-                        fn_params: options.fn_params.clone(),
-                    };
-
-                    rolling_fn(s, options).map(Some)
-                },
-                &[col(by)],
-                output_type,
-            )
-            .with_fmt(expr_name_by)
+        if let Some(by) = options.by.clone() {
+            self.finish_rolling_by(col(&by), options, expr_name_by, rolling_fn, output_type)
         } else {
             if !options.window_size.parsed_int {
                 panic!("if dynamic windows are used in a rolling aggregation, the 'by' argument must be set")
@@ -1305,6 +1418,67 @@ impl Expr {
         }
     }
 
+    #[cfg(feature = "rolling_window")]
+    #[allow(clippy::type_complexity)]
+    fn finish_rolling_by(
+        self,
+        by: Expr,
+        options: RollingOptions,
+        expr_name: &'static str,
+        rolling_fn: Arc<
+            dyn (Fn(&Series, RollingOptionsImpl) -> PolarsResult<Series>) + Send + Sync,
+        >,
+        output_type: GetOutput,
+    ) -> Expr {
+        self.apply_many(
+            move |s| {
+                let mut by = s[1].clone();
+                by = by.rechunk();
+                let s = &s[0];
+
+                polars_ensure!(
+                    options.weights.is_none(),
+                    ComputeError: "`weights` is not supported in 'rolling by' expression"
+                );
+                let (by, tz) = match by.dtype() {
+                    DataType::Datetime(tu, tz) => (by.cast(&DataType::Datetime(*tu, None))?, tz),
+                    DataType::Date => (
+                        by.cast(&DataType::Datetime(TimeUnit::Milliseconds, None))?,
+                        &None,
+                    ),
+                    dt => polars_bail!(opq = expr_name, got = dt, expected = "date/datetime"),
+                };
+                ensure_sorted_arg(&by, expr_name)?;
+                let by = by.datetime().unwrap();
+                let by_values = by.cont_slice().map_err(|_| {
+                    polars_err!(
+                        ComputeError:
+                        "`by` column should not have null values in 'rolling by' expression"
+                    )
+                })?;
+                let tu = by.time_unit();
+
+                let options = RollingOptionsImpl {
+                    window_size: options.window_size,
+                    min_periods: options.min_periods,
+                    weights: None,
+                    center: options.center,
+                    center_bias: options.center_bias,
+                    by: Some(by_values),
+                    tu: Some(tu),
+                    tz: tz.as_ref(),
+                    closed_window: options.closed_window,
+                    fn_params: options.fn_params.clone(),
+                };
+
+                rolling_fn(s, options).map(Some)
+            },
+            &[by],
+            output_type,
+        )
+        .with_fmt(expr_name)
+    }
+
     /// Apply a rolling minimum.
     ///
     /// See: [`RollingAgg::rolling_min`]
@@ -1352,12 +1526,18 @@ impl Expr {
     /// See: [`RollingAgg::rolling_sum`]
     #[cfg(feature = "rolling_window")]
     pub fn rolling_sum(self, options: RollingOptions) -> Expr {
+        // the mean of the left and the right biased window isn't integral
+        let output_type = if options.is_center_split() {
+            GetOutput::float_type()
+        } else {
+            GetOutput::same_type()
+        };
         self.finish_rolling(
             options,
             "rolling_sum",
             "rolling_sum_by",
             Arc::new(|s, options| s.rolling_sum(options)),
-            GetOutput::same_type(),
+            output_type,
         )
     }
 
@@ -1413,11 +1593,235 @@ impl Expr {
         )
     }
 
+    /// Apply a rolling minimum over the windows of the temporal column `by`, e.g. all values
+    /// within the `options.window_size` (a duration like `"2h"`) before the current time.
+    #[cfg(feature = "rolling_window")]
+    pub fn rolling_min_by(self, by: Expr, options: RollingOptions) -> Expr {
+        self.finish_rolling_by(
+            by,
+            options,
+            "rolling_min_by",
+            Arc::new(|s, options| s.rolling_min(options)),
+            GetOutput::same_type(),
+        )
+    }
+
+    /// Apply a rolling maximum over the windows of the temporal column `by`, e.g. all values
+    /// within the `options.window_size` (a duration like `"2h"`) before the current time.
+    #[cfg(feature = "rolling_window")]
+    pub fn rolling_max_by(self, by: Expr, options: RollingOptions) -> Expr {
+        self.finish_rolling_by(
+            by,
+            options,
+            "rolling_max_by",
+            Arc::new(|s, options| s.rolling_max(options)),
+            GetOutput::same_type(),
+        )
+    }
+
+    /// Apply a rolling mean over the windows of the temporal column `by`, e.g. all values
+    /// within the `options.window_size` (a duration like `"2h"`) before the current time.
+    #[cfg(feature = "rolling_window")]
+    pub fn rolling_mean_by(self, by: Expr, options: RollingOptions) -> Expr {
+        self.finish_rolling_by(
+            by,
+            options,
+            "rolling_mean_by",
+            Arc::new(|s, options| s.rolling_mean(options)),
+            GetOutput::float_type(),
+        )
+    }
+
+    /// Apply a rolling sum over the windows of the temporal column `by`, e.g. all values
+    /// within the `options.window_size` (a duration like `"2h"`) before the current time.
+    #[cfg(feature = "rolling_window")]
+    pub fn rolling_sum_by(self, by: Expr, options: RollingOptions) -> Expr {
+        // the mean of the left and the right biased window isn't integral
+        let output_type = if options.is_center_split() {
+            GetOutput::float_type()
+        } else {
+            GetOutput::same_type()
+        };
+        self.finish_rolling_by(
+            by,
+            options,
+            "rolling_sum_by",
+            Arc::new(|s, options| s.rolling_sum(options)),
+            output_type,
+        )
+    }
+
+    /// Apply a rolling median over the windows of the temporal column `by`, e.g. all values
+    /// within the `options.window_size` (a duration like `"2h"`) before the current time.
+    #[cfg(feature = "rolling_window")]
+    pub fn rolling_median_by(self, by: Expr, options: RollingOptions) -> Expr {
+        self.finish_rolling_by(
+            by,
+            options,
+            "rolling_median_by",
+            Arc::new(|s, options| s.rolling_median(options)),
+            GetOutput::same_type(),
+        )
+    }
+
+    /// Apply a rolling quantile over the windows of the temporal column `by`, e.g. all values
+    /// within the `options.window_size` (a duration like `"2h"`) before the current time.
+    #[cfg(feature = "rolling_window")]
+    pub fn rolling_quantile_by(self, by: Expr, options: RollingOptions) -> Expr {
+        self.finish_rolling_by(
+            by,
+            options,
+            "rolling_quantile_by",
+            Arc::new(|s, options| s.rolling_quantile(options)),
+            GetOutput::float_type(),
+        )
+    }
+
+    /// Apply a rolling variance over the windows of the temporal column `by`, e.g. all values
+    /// within the `options.window_size` (a duration like `"2h"`) before the current time.
+    #[cfg(feature = "rolling_window")]
+    pub fn rolling_var_by(self, by: Expr, options: RollingOptions) -> Expr {
+        self.finish_rolling_by(
+            by,
+            options,
+            "rolling_var_by",
+            Arc::new(|s, options| s.rolling_var(options)),
+            GetOutput::float_type(),
+        )
+    }
+
+    /// Apply a rolling std-dev over the windows of the temporal column `by`, e.g. all values
+    /// within the `options.window_size` (a duration like `"2h"`) before the current time.
+    #[cfg(feature = "rolling_window")]
+    pub fn rolling_std_by(self, by: Expr, options: RollingOptions) -> Expr {
+        self.finish_rolling_by(
+            by,
+            options,
+            "rolling_std_by",
+            Arc::new(|s, options| s.rolling_std(options)),
+            GetOutput::float_type(),
+        )
+    }
+
+    #[cfg(feature = "rolling_window")]
+    #[allow(clippy::type_complexity)]
+    fn finish_expanding(
+        self,
+        min_periods: usize,
+        expr_name: &'static str,
+        rolling_fn: Arc<
+            dyn (Fn(&Series, RollingOptionsImpl) -> PolarsResult<Series>) + Send + Sync,
+        >,
+        output_type: GetOutput,
+    ) -> Expr {
+        self.apply(
+            move |s| {
+                // a window that spans the whole series degenerates the window offsets of the
+                // rolling kernels to `(0, i + 1)`, so the window grows from the start
+                let window_size = std::cmp::max(s.len(), min_periods).max(1);
+                let options = RollingOptionsImpl {
+                    window_size: Duration::new(window_size as i64),
+                    min_periods,
+                    ..Default::default()
+                };
+                rolling_fn(&s, options).map(Some)
+            },
+            output_type,
+        )
+        .with_fmt(expr_name)
+    }
+
+    /// Apply an expanding minimum: the aggregation over all values up to and including
+    /// the current one. The result is null until `min_periods` values are seen.
+    #[cfg(feature = "rolling_window")]
+    pub fn expanding_min(self, min_periods: usize) -> Expr {
+        self.finish_expanding(
+            min_periods,
+            "expanding_min",
+            Arc::new(|s, options| s.rolling_min(options)),
+            GetOutput::same_type(),
+        )
+    }
+
+    /// Apply an expanding maximum: the aggregation over all values up to and including
+    /// the current one. The result is null until `min_periods` values are seen.
+    #[cfg(feature = "rolling_window")]
+    pub fn expanding_max(self, min_periods: usize) -> Expr {
+        self.finish_expanding(
+            min_periods,
+            "expanding_max",
+            Arc::new(|s, options| s.rolling_max(options)),
+            GetOutput::same_type(),
+        )
+    }
+
+    /// Apply an expanding mean: the aggregation over all values up to and including
+    /// the current one. The result is null until `min_periods` values are seen.
+    #[cfg(feature = "rolling_window")]
+    pub fn expanding_mean(self, min_periods: usize) -> Expr {
+        self.finish_expanding(
+            min_periods,
+            "expanding_mean",
+            Arc::new(|s, options| s.rolling_mean(options)),
+            GetOutput::float_type(),
+        )
+    }
+
+    /// Apply an expanding sum: the aggregation over all values up to and including
+    /// the current one. The result is null until `min_periods` values are seen.
+    #[cfg(feature = "rolling_window")]
+    pub fn expanding_sum(self, min_periods: usize) -> Expr {
+        self.finish_expanding(
+            min_periods,
+            "expanding_sum",
+            Arc::new(|s, options| s.rolling_sum(options)),
+            GetOutput::same_type(),
+        )
+    }
+
+    /// Apply an expanding variance: the aggregation over all values up to and including
+    /// the current one. The result is null until `min_periods` values are seen.
+    #[cfg(feature = "rolling_window")]
+    pub fn expanding_var(self, min_periods: usize) -> Expr {
+        self.finish_expanding(
+            min_periods,
+            "expanding_var",
+            Arc::new(|s, options| s.rolling_var(options)),
+            GetOutput::float_type(),
+        )
+    }
+
+    /// Apply an expanding std-dev: the aggregation over all values up to and including
+    /// the current one. The result is null until `min_periods` values are seen.
+    #[cfg(feature = "rolling_window")]
+    pub fn expanding_std(self, min_periods: usize) -> Expr {
+        self.finish_expanding(
+            min_periods,
+            "expanding_std",
+            Arc::new(|s, options| s.rolling_std(options)),
+            GetOutput::float_type(),
+        )
+    }
+
     /// Apply a rolling skew.
     #[cfg(feature = "rolling_window")]
     #[cfg(feature = "moment")]
     pub fn rolling_skew(self, window_size: usize, bias: bool) -> Expr {
-        self.apply_private(FunctionExpr::RollingSkew { window_size, bias })
+        self.apply_private(FunctionExpr::Rolling(RollingFunction::Skew {
+            window_size,
+            bias,
+        }))
+    }
+
+    /// Apply a rolling kurtosis.
+    #[cfg(feature = "rolling_window")]
+    #[cfg(feature = "moment")]
+    pub fn rolling_kurtosis(self, window_size: usize, fisher: bool, bias: bool) -> Expr {
+        self.apply_private(FunctionExpr::Rolling(RollingFunction::Kurtosis {
+            window_size,
+            fisher,
+            bias,
+        }))
     }
 
     #[cfg(feature = "rolling_window")]
@@ -1559,6 +1963,12 @@ impl Expr {
         self.apply_private(FunctionExpr::RLE)
     }
 
+    #[cfg(feature = "rle")]
+    /// Expand the runs produced by `rle` back into the original values.
+    pub fn rle_decode(self) -> Expr {
+        self.apply_private(FunctionExpr::RLEDecode)
+    }
+
     #[cfg(feature = "rle")]
     /// Similar to `rle`, but maps values to run IDs.
     pub fn rle_id(self) -> Expr {
@@ -1773,6 +2183,47 @@ impl Expr {
         .with_fmt("value_counts")
     }
 
+    #[cfg(feature = "dtype-struct")]
+    /// Get the `k` most frequent values and their counts as a struct, ordered by descending count.
+    /// (Note that it is better to turn parallel off in the aggregation context).
+    pub fn most_frequent(self, k: usize, parallel: bool) -> Self {
+        self.apply(
+            move |s| {
+                s.most_frequent(k, parallel)
+                    .map(|df| Some(df.into_struct(s.name()).into_series()))
+            },
+            GetOutput::map_field(|fld| {
+                Field::new(
+                    fld.name(),
+                    DataType::Struct(vec![fld.clone(), Field::new("counts", IDX_DTYPE)]),
+                )
+            }),
+        )
+        .with_function_options(|mut opts| {
+            opts.pass_name_to_apply = true;
+            opts
+        })
+        .with_fmt("most_frequent")
+    }
+
+    #[cfg(feature = "to_dummies")]
+    /// One-hot encode the values into a struct with a `UInt8` indicator field per unique value.
+    /// The fields are named `{name}{separator}{value}`.
+    ///
+    /// The fields depend on the data, so they are only known after the expression is evaluated
+    /// and the schema reports the output as [`DataType::Unknown`].
+    pub fn to_dummies(self, separator: Option<&str>, drop_first: bool) -> Self {
+        let separator = separator.map(|s| s.to_string());
+        self.map(
+            move |s| {
+                s.to_dummies(separator.as_deref(), drop_first)
+                    .map(|df| Some(df.into_struct(s.name()).into_series()))
+            },
+            GetOutput::from_type(DataType::Unknown),
+        )
+        .with_fmt("to_dummies")
+    }
+
     #[cfg(feature = "unique_counts")]
     /// Returns a count of the unique values in the order of appearance.
     /// This method differs from [`Expr::value_counts]` in that it does not return the
@@ -25,6 +25,11 @@ pub struct StrptimeOptions {
     pub exact: bool,
     /// use a cache of unique, converted dates to apply the datetime conversion.
     pub cache: bool,
+    /// If no format is given, infer a single format from a sample of the values and parse
+    /// all values with it, instead of inferring the format per value. Only used by
+    /// `to_datetime`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub infer: bool,
 }
 
 impl Default for StrptimeOptions {
@@ -34,6 +39,7 @@ impl Default for StrptimeOptions {
             strict: true,
             exact: true,
             cache: true,
+            infer: false,
         }
     }
 }
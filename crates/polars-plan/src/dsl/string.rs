@@ -190,6 +190,16 @@ impl StringNameSpace {
         self.strptime(DataType::Time, options, lit("raise"))
     }
 
+    /// Convert a Utf8 column into a Duration column.
+    ///
+    /// Accepts human-readable durations like `"1h30m"` and ISO 8601 durations like
+    /// `"PT1H30M"`. Set the `format` of the options to `"polars"` or `"iso"` to accept only
+    /// one of them.
+    #[cfg(feature = "dtype-duration")]
+    pub fn to_duration(self, time_unit: TimeUnit, options: StrptimeOptions) -> Expr {
+        self.strptime(DataType::Duration(time_unit), options, lit("raise"))
+    }
+
     /// Convert a Utf8 column into a Decimal column.
     #[cfg(feature = "dtype-decimal")]
     pub fn to_decimal(self, infer_length: usize) -> Expr {
@@ -201,10 +211,17 @@ impl StringNameSpace {
     /// # Arguments
     ///
     /// * `delimiter` - A string that will act as delimiter between values.
+    /// * `ignore_nulls` - Skip null values, otherwise they are written as `"null"`.
     #[cfg(feature = "concat_str")]
-    pub fn concat(self, delimiter: &str) -> Expr {
+    pub fn concat(self, delimiter: &str, ignore_nulls: bool) -> Expr {
         self.0
-            .apply_private(StringFunction::ConcatVertical(delimiter.to_owned()).into())
+            .apply_private(
+                StringFunction::ConcatVertical {
+                    delimiter: delimiter.to_owned(),
+                    ignore_nulls,
+                }
+                .into(),
+            )
             .with_function_options(|mut options| {
                 options.auto_explode = true;
                 options
@@ -258,6 +275,34 @@ impl StringNameSpace {
             .map_many_private(StringFunction::SplitN(n).into(), &[by], false, false)
     }
 
+    #[cfg(feature = "regex")]
+    /// Split the string on the matches of the regex `by`. The resulting dtype is `List<Utf8>`.
+    pub fn split_regex(self, by: Expr) -> Expr {
+        self.0.map_many_private(
+            StringFunction::SplitRegex(false).into(),
+            &[by],
+            false,
+            false,
+        )
+    }
+
+    #[cfg(feature = "regex")]
+    /// Split the string on the matches of the regex `by` and keep the matches.
+    /// The resulting dtype is `List<Utf8>`.
+    pub fn split_regex_inclusive(self, by: Expr) -> Expr {
+        self.0
+            .map_many_private(StringFunction::SplitRegex(true).into(), &[by], false, false)
+    }
+
+    #[cfg(all(feature = "regex", feature = "dtype-struct"))]
+    /// Split on the matches of the regex `by`, returning exactly `n` items. If there are more
+    /// possible splits, keeps the remainder of the string intact.
+    /// The resulting dtype is [`DataType::Struct`].
+    pub fn splitn_regex(self, by: Expr, n: usize) -> Expr {
+        self.0
+            .map_many_private(StringFunction::SplitNRegex(n).into(), &[by], false, false)
+    }
+
     #[cfg(feature = "regex")]
     /// Replace values that match a regex `pat` with a `value`.
     pub fn replace(self, pat: Expr, value: Expr, literal: bool) -> Expr {
@@ -1,4 +1,6 @@
-#[derive(Copy, Clone, Debug)]
+use crate::logical_plan::optimizer::CustomRules;
+
+#[derive(Clone, Debug)]
 /// State of the allowed optimizations
 pub struct OptState {
     pub projection_pushdown: bool,
@@ -12,8 +14,12 @@ pub struct OptState {
     #[cfg(feature = "cse")]
     pub comm_subexpr_elim: bool,
     pub streaming: bool,
+    /// The number of bytes a streaming sink may hold before it goes out-of-core.
+    pub streaming_memory_limit: Option<usize>,
     pub eager: bool,
     pub fast_projection: bool,
+    /// Rules that run alongside the built-in optimizations.
+    pub custom_rules: CustomRules,
 }
 
 impl Default for OptState {
@@ -31,8 +37,10 @@ impl Default for OptState {
             #[cfg(feature = "cse")]
             comm_subexpr_elim: true,
             streaming: false,
+            streaming_memory_limit: None,
             fast_projection: true,
             eager: false,
+            custom_rules: Default::default(),
         }
     }
 }
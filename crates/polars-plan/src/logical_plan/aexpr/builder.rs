@@ -0,0 +1,151 @@
+use super::*;
+
+/// Builds expressions directly into an [`Arena`] of [`AExpr`].
+///
+/// Every method adds one expression to the arena and returns its [`Node`]. A node can be used
+/// as input of any number of expressions without cloning it, which makes this cheaper than
+/// building large [`Expr`] trees, e.g. generated `when/then` chains with thousands of branches.
+/// Select the nodes with `LazyFrame::select_nodes`, which adds them to the plan without
+/// converting them to [`Expr`], or convert them with [`AExprBuilder::to_expr`].
+///
+/// The expressions are not expanded, so they cannot contain wildcards or `nth`.
+///
+/// ```rust
+/// # use polars_plan::prelude::*;
+/// let mut b = AExprBuilder::new();
+/// let x = b.col("x");
+/// let branches = (0..3)
+///     .map(|i| {
+///         let value = b.lit(i);
+///         let predicate = b.binary(x, Operator::Eq, value);
+///         (predicate, b.lit(i * 10))
+///     })
+///     .collect::<Vec<_>>();
+/// let otherwise = b.lit(-1);
+/// let node = b.when_then(&branches, otherwise);
+/// let node = b.alias(node, "y");
+/// let expr = b.to_expr(node);
+/// ```
+#[derive(Default)]
+pub struct AExprBuilder {
+    arena: Arena<AExpr>,
+}
+
+impl AExprBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            arena: Arena::with_capacity(capacity),
+        }
+    }
+
+    /// Add any expression to the arena.
+    pub fn expr(&mut self, expr: Expr) -> Node {
+        to_aexpr(expr, &mut self.arena)
+    }
+
+    pub fn col(&mut self, name: &str) -> Node {
+        self.arena.add(AExpr::Column(Arc::from(name)))
+    }
+
+    pub fn lit<L: Literal>(&mut self, value: L) -> Node {
+        self.expr(value.lit())
+    }
+
+    pub fn binary(&mut self, left: Node, op: Operator, right: Node) -> Node {
+        self.arena.add(AExpr::BinaryExpr { left, op, right })
+    }
+
+    pub fn alias(&mut self, input: Node, name: &str) -> Node {
+        self.arena.add(AExpr::Alias(input, Arc::from(name)))
+    }
+
+    /// Cast to `data_type`, values that cannot be cast become null. See [`Expr::cast`].
+    pub fn cast(&mut self, input: Node, data_type: DataType) -> Node {
+        self.arena.add(AExpr::Cast {
+            expr: input,
+            data_type,
+            strict: false,
+        })
+    }
+
+    pub fn ternary(&mut self, predicate: Node, truthy: Node, falsy: Node) -> Node {
+        self.arena.add(AExpr::Ternary {
+            predicate,
+            truthy,
+            falsy,
+        })
+    }
+
+    /// A `when/then/otherwise` chain of the `(predicate, value)` branches. The value of the
+    /// first branch of which the predicate is true is taken.
+    pub fn when_then(&mut self, branches: &[(Node, Node)], otherwise: Node) -> Node {
+        // nest the branches from the last to the first, as `ChainedThen::otherwise` does
+        branches
+            .iter()
+            .rev()
+            .fold(otherwise, |falsy, &(predicate, truthy)| {
+                self.ternary(predicate, truthy, falsy)
+            })
+    }
+
+    pub fn arena(&self) -> &Arena<AExpr> {
+        &self.arena
+    }
+
+    pub fn into_arena(self) -> Arena<AExpr> {
+        self.arena
+    }
+
+    pub fn to_expr(&self, node: Node) -> Expr {
+        node_to_expr(node, &self.arena)
+    }
+
+    pub fn to_exprs(&self, nodes: &[Node]) -> Vec<Expr> {
+        nodes.iter().map(|node| self.to_expr(*node)).collect()
+    }
+}
+
+/// Add all expressions of `from` to `to`. The nodes of `from` are shifted by the returned
+/// offset in `to`.
+pub(crate) fn append_arena(from: &Arena<AExpr>, to: &mut Arena<AExpr>) -> usize {
+    let offset = to.len();
+    let mut inputs = vec![];
+    for i in 0..from.len() {
+        let ae = from.get(Node(i));
+        ae.nodes(&mut inputs);
+        for node in &mut inputs {
+            node.0 += offset;
+        }
+        to.add(ae.clone().replace_inputs(&inputs));
+        inputs.clear();
+    }
+    offset
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_aexpr_builder_when_then() {
+        let mut b = AExprBuilder::new();
+        let x = b.col("x");
+        let branches = [("a", 1), ("b", 2)].map(|(value, out)| {
+            let value = b.lit(value);
+            (b.binary(x, Operator::Eq, value), b.lit(out))
+        });
+        let otherwise = b.lit(3);
+        let node = b.when_then(&branches, otherwise);
+
+        let expected = when(col("x").eq(lit("a")))
+            .then(lit(1))
+            .when(col("x").eq(lit("b")))
+            .then(lit(2))
+            .otherwise(lit(3));
+        assert_eq!(b.to_expr(node), expected);
+    }
+}
@@ -21,6 +21,12 @@ impl Hash for AExpr {
             AExpr::AnonymousFunction { options, .. } => {
                 options.hash(state);
             },
+            AExpr::Extension {
+                options, function, ..
+            } => {
+                options.hash(state);
+                function.name().hash(state)
+            },
             AExpr::Agg(agg) => agg.hash(state),
             AExpr::SortBy { descending, .. } => descending.hash(state),
             AExpr::Cast { strict, .. } => strict.hash(state),
@@ -1,9 +1,12 @@
+mod builder;
 mod hash;
 mod schema;
 
 use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
+pub(crate) use builder::append_arena;
+pub use builder::AExprBuilder;
 use polars_arrow::prelude::QuantileInterpolOptions;
 use polars_core::frame::group_by::GroupByMethod;
 use polars_core::prelude::*;
@@ -175,6 +178,11 @@ pub enum AExpr {
         function: FunctionExpr,
         options: FunctionOptions,
     },
+    Extension {
+        input: Vec<Node>,
+        function: SpecialEq<Arc<dyn ExprExtension>>,
+        options: FunctionOptions,
+    },
     Window {
         function: Node,
         partition_by: Vec<Node>,
@@ -216,7 +224,9 @@ impl AExpr {
     pub(crate) fn groups_sensitive(&self) -> bool {
         use AExpr::*;
         match self {
-            Function { options, .. } | AnonymousFunction { options, .. } => {
+            Function { options, .. }
+            | AnonymousFunction { options, .. }
+            | Extension { options, .. } => {
                 options.is_groups_sensitive()
             }
             Sort { .. }
@@ -299,7 +309,7 @@ impl AExpr {
                 // latest, so that it is popped first
                 container.push(*truthy);
             },
-            AnonymousFunction { input, .. } | Function { input, .. } =>
+            AnonymousFunction { input, .. } | Function { input, .. } | Extension { input, .. } =>
             // we iterate in reverse order, so that the lhs is popped first and will be found
             // as the root columns/ input columns by `_suffix` and `_keep_name` etc.
             {
@@ -340,7 +350,17 @@ impl AExpr {
             Column(_) | Literal(_) | Wildcard | Count | Nth(_) => return self,
             Alias(input, _) => input,
             Cast { expr, .. } => expr,
-            Explode(input) | Slice { input, .. } => input,
+            Explode(input) => input,
+            Slice {
+                input,
+                offset,
+                length,
+            } => {
+                *length = inputs[0];
+                *offset = inputs[1];
+                *input = inputs[2];
+                return self;
+            },
             BinaryExpr { left, right, .. } => {
                 *right = inputs[0];
                 *left = inputs[1];
@@ -385,7 +405,7 @@ impl AExpr {
                 *truthy = inputs[2];
                 return self;
             },
-            AnonymousFunction { input, .. } | Function { input, .. } => {
+            AnonymousFunction { input, .. } | Function { input, .. } | Extension { input, .. } => {
                 input.clear();
                 input.extend(inputs.iter().rev().copied());
                 return self;
@@ -397,7 +417,8 @@ impl AExpr {
             } => {
                 *function = *inputs.last().unwrap();
                 partition_by.clear();
-                partition_by.extend_from_slice(&inputs[..inputs.len() - 1]);
+                // the partitions are pushed in reverse order by `nodes`
+                partition_by.extend(inputs[..inputs.len() - 1].iter().rev().copied());
 
                 return self;
             },
@@ -209,6 +209,16 @@ impl AExpr {
                 polars_ensure!(!fields.is_empty(), ComputeError: "expression: '{}' didn't get any inputs", function);
                 function.get_field(schema, ctxt, &fields)
             },
+            Extension {
+                function, input, ..
+            } => {
+                let fields = input
+                    .iter()
+                    // default context because `col()` would return a list in aggregation context
+                    .map(|node| arena.get(*node).to_field(schema, Context::Default, arena))
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                function.to_field(&fields)
+            },
             Slice { input, .. } => arena.get(*input).to_field(schema, ctxt, arena),
             Wildcard => panic!("should be no wildcard at this point"),
             Nth(_) => panic!("should be no nth at this point"),
@@ -46,6 +46,7 @@ pub enum ALogicalPlan {
         output_schema: Option<SchemaRef>,
         projection: Option<Arc<Vec<String>>>,
         selection: Option<Node>,
+        stats: ScanStatsCache,
     },
     Projection {
         input: Node,
@@ -319,6 +320,7 @@ impl ALogicalPlan {
                 output_schema,
                 projection,
                 selection,
+                stats,
             } => {
                 let mut new_selection = None;
                 if selection.is_some() {
@@ -331,6 +333,7 @@ impl ALogicalPlan {
                     output_schema: output_schema.clone(),
                     projection: projection.clone(),
                     selection: new_selection,
+                    stats: stats.clone(),
                 }
             },
             MapFunction { function, .. } => MapFunction {
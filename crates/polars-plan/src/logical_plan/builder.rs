@@ -1,17 +1,16 @@
-#[cfg(feature = "csv")]
-use std::io::{Read, Seek};
-
 use polars_core::frame::explode::MeltArgs;
 use polars_core::prelude::*;
-#[cfg(feature = "parquet")]
+#[cfg(any(feature = "parquet", feature = "csv"))]
 use polars_io::cloud::CloudOptions;
+#[cfg(feature = "csv")]
+use polars_io::cloud::ObjectBytes;
 #[cfg(feature = "ipc")]
 use polars_io::ipc::IpcReader;
 #[cfg(all(feature = "parquet", feature = "async"))]
 use polars_io::parquet::ParquetAsyncReader;
 #[cfg(feature = "parquet")]
 use polars_io::parquet::ParquetReader;
-#[cfg(all(feature = "cloud", feature = "parquet"))]
+#[cfg(all(feature = "cloud", any(feature = "parquet", feature = "csv")))]
 use polars_io::pl_async::get_runtime;
 #[cfg(any(
     feature = "parquet",
@@ -22,10 +21,12 @@ use polars_io::pl_async::get_runtime;
 use polars_io::RowCount;
 #[cfg(feature = "csv")]
 use polars_io::{
-    csv::utils::{infer_file_schema, is_compressed},
+    csv::utils::{infer_file_schema, is_compressed, transcode_to_utf8},
     csv::CsvEncoding,
     csv::NullValues,
+    mmap::ReaderBytes,
     utils::get_reader_bytes,
+    ProgressCallback,
 };
 
 use super::builder_functions::*;
@@ -145,6 +146,7 @@ impl LogicalPlanBuilder {
         low_memory: bool,
         cloud_options: Option<CloudOptions>,
         use_statistics: bool,
+        use_bloom_filters: bool,
         hive_partitioning: bool,
         // used to prevent multiple cloud calls
         known_schema: Option<SchemaRef>,
@@ -209,6 +211,8 @@ impl LogicalPlanBuilder {
                     parallel,
                     low_memory,
                     use_statistics,
+                    use_bloom_filters,
+                    struct_fields: None,
                 },
                 cloud_options,
                 metadata,
@@ -286,29 +290,53 @@ impl LogicalPlanBuilder {
         try_parse_dates: bool,
         raise_if_empty: bool,
         truncate_ragged_lines: bool,
+        byte_offset: Option<String>,
+        progress: Option<ProgressCallback>,
+        cloud_options: Option<CloudOptions>,
     ) -> PolarsResult<Self> {
         let path = path.into();
-        let mut file = polars_utils::open_file(&path).map_err(|e| {
-            let path = path.to_string_lossy();
-            if path.len() > 88 {
-                let path: String = path.chars().skip(path.len() - 88).collect();
-                polars_err!(ComputeError: "error open file: ...{}, {}", path, e)
-            } else {
-                polars_err!(ComputeError: "error open file: {}, {}", path, e)
+        // CSV can't be read by byte ranges, the downloaded object is kept for when the query runs
+        let object: Option<ObjectBytes> = if polars_io::is_cloud_url(&path) {
+            #[cfg(not(feature = "cloud"))]
+            panic!(
+                "One or more of the cloud storage features ('aws', 'gcp', ...) must be enabled."
+            );
+
+            #[cfg(feature = "cloud")]
+            {
+                let uri = path.to_string_lossy();
+                Some(
+                    get_runtime()
+                        .block_on(polars_io::cloud::fetch_object(&uri, cloud_options.as_ref()))?,
+                )
             }
-        })?;
+        } else {
+            None
+        };
+        let mut file;
+        let reader_bytes = if let Some(object) = &object {
+            ReaderBytes::Borrowed(object.as_ref())
+        } else {
+            file = polars_utils::open_file(&path).map_err(|e| {
+                let path = path.to_string_lossy();
+                if path.len() > 88 {
+                    let path: String = path.chars().skip(path.len() - 88).collect();
+                    polars_err!(ComputeError: "error open file: ...{}, {}", path, e)
+                } else {
+                    polars_err!(ComputeError: "error open file: {}, {}", path, e)
+                }
+            })?;
+            get_reader_bytes(&mut file).expect("could not mmap file")
+        };
 
-        let mut magic_nr = [0u8; 2];
-        let res = file.read_exact(&mut magic_nr);
         if raise_if_empty {
-            res.map_err(|_| polars_err!(NoData: "empty CSV"))?;
-        };
+            polars_ensure!(reader_bytes.len() >= 2, NoData: "empty CSV");
+        }
         polars_ensure!(
-            !is_compressed(&magic_nr),
+            !is_compressed(&reader_bytes),
             ComputeError: "cannot scan compressed csv; use `read_csv` for compressed data",
         );
-        file.rewind()?;
-        let reader_bytes = get_reader_bytes(&mut file).expect("could not mmap file");
+        let reader_bytes = transcode_to_utf8(reader_bytes, encoding);
 
         // TODO! delay inferring schema until absolutely necessary
         // this needs a way to estimated bytes/rows.
@@ -343,7 +371,10 @@ impl LogicalPlanBuilder {
             }
         }
 
-        let schema = schema.unwrap_or_else(|| Arc::new(inferred_schema));
+        let mut schema = schema.unwrap_or_else(|| Arc::new(inferred_schema));
+        if let Some(name) = &byte_offset {
+            Arc::make_mut(&mut schema).with_column(name.as_str().into(), DataType::UInt64);
+        }
         let n_bytes = reader_bytes.len();
         let estimated_n_rows = (rows_read as f64 / bytes_read as f64 * n_bytes as f64) as usize;
 
@@ -380,7 +411,11 @@ impl LogicalPlanBuilder {
                     try_parse_dates,
                     raise_if_empty,
                     truncate_ragged_lines,
+                    byte_offset,
+                    progress,
                 },
+                cloud_options,
+                object: object.clone(),
             },
         }
         .into())
@@ -520,6 +555,81 @@ impl LogicalPlanBuilder {
         .into()
     }
 
+    /// Select the expressions of the `exprs` nodes in `arena`, see [`AExprBuilder`].
+    pub fn project_nodes(
+        self,
+        arena: Arena<AExpr>,
+        exprs: Vec<Node>,
+        options: ProjectionOptions,
+    ) -> Self {
+        if exprs.is_empty() {
+            return self.project(vec![], options);
+        }
+        self.arena_projection(arena, exprs, options, false)
+    }
+
+    /// Add the expressions of the `exprs` nodes in `arena` as columns, see [`AExprBuilder`].
+    pub fn with_columns_nodes(
+        self,
+        arena: Arena<AExpr>,
+        exprs: Vec<Node>,
+        options: ProjectionOptions,
+    ) -> Self {
+        if exprs.is_empty() {
+            return self;
+        }
+        self.arena_projection(arena, exprs, options, true)
+    }
+
+    fn arena_projection(
+        self,
+        arena: Arena<AExpr>,
+        exprs: Vec<Node>,
+        options: ProjectionOptions,
+        hstack: bool,
+    ) -> Self {
+        let input_schema = try_delayed!(self.0.schema(), &self.0, into);
+        let mut schema = if hstack {
+            (**input_schema).clone()
+        } else {
+            Schema::with_capacity(exprs.len())
+        };
+        let mut output_names = PlHashSet::with_capacity(exprs.len());
+
+        for node in &exprs {
+            if has_aexpr(*node, &arena, |e| {
+                matches!(e, AExpr::Wildcard | AExpr::Nth(_))
+            }) {
+                let err = polars_err!(
+                    ComputeError: "expressions built in an arena cannot contain a wildcard or `nth`"
+                );
+                return raise_err!(err, &self.0, into);
+            }
+            let field = try_delayed!(
+                arena
+                    .get(*node)
+                    .to_field(&input_schema, Context::Default, &arena),
+                &self.0,
+                into
+            );
+            if !output_names.insert(field.name().clone()) {
+                let err = polars_err!(Duplicate: "the name '{}' is duplicate", field.name());
+                return raise_err!(err, &self.0, into);
+            }
+            schema.with_column(field.name, field.dtype);
+        }
+
+        LogicalPlan::ArenaProjection {
+            input: Box::new(self.0),
+            exprs,
+            arena: Arc::new(arena),
+            schema: Arc::new(schema),
+            options,
+            hstack,
+        }
+        .into()
+    }
+
     pub fn add_err(self, err: PolarsError) -> Self {
         LogicalPlan::Error {
             input: Box::new(self.0),
@@ -714,6 +824,7 @@ impl LogicalPlanBuilder {
             output_schema: None,
             projection: None,
             selection: None,
+            stats: Default::default(),
         }
         .into()
     }
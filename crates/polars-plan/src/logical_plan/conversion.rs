@@ -116,6 +116,15 @@ pub fn to_aexpr(expr: Expr, arena: &mut Arena<AExpr>) -> Node {
             output_type,
             options,
         },
+        Expr::Extension {
+            input,
+            function,
+            options,
+        } => AExpr::Extension {
+            input: to_aexprs(input, arena),
+            function,
+            options,
+        },
         Expr::Function {
             input,
             function,
@@ -210,12 +219,14 @@ pub fn to_alp(
             output_schema,
             projection,
             selection,
+            stats,
         } => ALogicalPlan::DataFrameScan {
             df,
             schema,
             output_schema,
             projection,
             selection: selection.map(|expr| to_aexpr(expr, expr_arena)),
+            stats,
         },
         LogicalPlan::Projection {
             expr,
@@ -322,6 +333,36 @@ pub fn to_alp(
                 options,
             }
         },
+        LogicalPlan::ArenaProjection {
+            input,
+            exprs,
+            arena,
+            schema,
+            options,
+            hstack,
+        } => {
+            let offset = append_arena(&arena, expr_arena);
+            let exprs = exprs
+                .into_iter()
+                .map(|node| Node(node.0 + offset))
+                .collect();
+            let input = to_alp(*input, expr_arena, lp_arena)?;
+            if hstack {
+                ALogicalPlan::HStack {
+                    input,
+                    exprs,
+                    schema,
+                    options,
+                }
+            } else {
+                ALogicalPlan::Projection {
+                    expr: exprs,
+                    input,
+                    schema,
+                    options,
+                }
+            }
+        },
         LogicalPlan::Distinct { input, options } => {
             let input = to_alp(*input, expr_arena, lp_arena)?;
             ALogicalPlan::Distinct { input, options }
@@ -540,6 +581,15 @@ pub fn node_to_expr(node: Node, expr_arena: &Arena<AExpr>) -> Expr {
             output_type,
             options,
         },
+        AExpr::Extension {
+            input,
+            function,
+            options,
+        } => Expr::Extension {
+            input: nodes_to_exprs(&input, expr_arena),
+            function,
+            options,
+        },
         AExpr::Function {
             input,
             function,
@@ -577,7 +627,7 @@ pub fn node_to_expr(node: Node, expr_arena: &Arena<AExpr>) -> Expr {
     }
 }
 
-fn nodes_to_exprs(nodes: &[Node], expr_arena: &Arena<AExpr>) -> Vec<Expr> {
+pub(crate) fn nodes_to_exprs(nodes: &[Node], expr_arena: &Arena<AExpr>) -> Vec<Expr> {
     nodes.iter().map(|n| node_to_expr(*n, expr_arena)).collect()
 }
 
@@ -641,12 +691,14 @@ impl ALogicalPlan {
                 output_schema,
                 projection,
                 selection,
+                stats,
             } => LogicalPlan::DataFrameScan {
                 df,
                 schema,
                 output_schema,
                 projection,
                 selection: selection.map(|n| node_to_expr(n, expr_arena)),
+                stats,
             },
             ALogicalPlan::Projection {
                 expr,
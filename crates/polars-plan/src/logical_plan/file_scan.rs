@@ -1,5 +1,9 @@
+use std::path::Path;
+
 #[cfg(feature = "parquet")]
 use arrow::io::parquet::write::FileMetaData;
+#[cfg(feature = "csv")]
+use polars_io::cloud::ObjectBytes;
 
 use super::*;
 
@@ -7,7 +11,13 @@ use super::*;
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum FileScan {
     #[cfg(feature = "csv")]
-    Csv { options: CsvParserOptions },
+    Csv {
+        options: CsvParserOptions,
+        cloud_options: Option<CloudOptions>,
+        /// The file if it was downloaded from cloud storage to infer the schema.
+        #[cfg_attr(feature = "serde", serde(skip))]
+        object: Option<ObjectBytes>,
+    },
     #[cfg(feature = "parquet")]
     Parquet {
         options: ParquetOptions,
@@ -28,7 +38,18 @@ impl PartialEq for FileScan {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
             #[cfg(feature = "csv")]
-            (FileScan::Csv { options: l }, FileScan::Csv { options: r }) => l == r,
+            (
+                FileScan::Csv {
+                    options: opt_l,
+                    cloud_options: c_l,
+                    ..
+                },
+                FileScan::Csv {
+                    options: opt_r,
+                    cloud_options: c_r,
+                    ..
+                },
+            ) => opt_l == opt_r && c_l == c_r,
             #[cfg(feature = "parquet")]
             (
                 FileScan::Parquet {
@@ -54,11 +75,21 @@ impl FileScan {
         #[allow(unreachable_patterns)]
         match self {
             #[cfg(feature = "csv")]
-            Self::Csv { options } => options.skip_rows,
+            Self::Csv { options, .. } => options.skip_rows,
             _ => 0,
         }
     }
 
+    /// Name of the column with the byte offsets of the rows, which is generated by the scan.
+    pub(crate) fn byte_offset(&self) -> Option<&str> {
+        #[allow(unreachable_patterns)]
+        match self {
+            #[cfg(feature = "csv")]
+            Self::Csv { options, .. } => options.byte_offset.as_deref(),
+            _ => None,
+        }
+    }
+
     pub(crate) fn sort_projection(&self, _file_options: &FileScanOptions) -> bool {
         match self {
             #[cfg(feature = "csv")]
@@ -72,10 +103,15 @@ impl FileScan {
         }
     }
 
-    pub fn streamable(&self) -> bool {
+    /// Whether the scan of the file at `path` can run in the streaming engine.
+    #[cfg_attr(not(feature = "csv"), allow(unused_variables))]
+    pub fn streamable(&self, path: &Path) -> bool {
         match self {
             #[cfg(feature = "csv")]
-            Self::Csv { .. } => true,
+            // the batched csv reader cannot determine byte offsets and only reads local files
+            Self::Csv { options, .. } => {
+                options.byte_offset.is_none() && !polars_io::is_cloud_url(path)
+            },
             #[cfg(feature = "ipc")]
             Self::Ipc { .. } => false,
             #[cfg(feature = "parquet")]
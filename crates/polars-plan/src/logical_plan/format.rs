@@ -11,23 +11,27 @@ fn write_scan<P: Display>(
     name: &str,
     path: &Path,
     indent: usize,
-    n_columns: i64,
+    with_columns: Option<&[String]>,
     total_columns: usize,
     predicate: &Option<P>,
     n_rows: Option<usize>,
+    row_estimation: Option<(Option<usize>, usize)>,
 ) -> fmt::Result {
     if indent != 0 {
         writeln!(f)?;
     }
     write!(f, "{:indent$}{} SCAN {}", "", name, path.display())?;
-    if n_columns > 0 {
-        write!(
-            f,
-            "\n{:indent$}PROJECT {n_columns}/{total_columns} COLUMNS",
-            "",
-        )?;
-    } else {
-        write!(f, "\n{:indent$}PROJECT */{total_columns} COLUMNS", "",)?;
+    write_row_estimation(f, row_estimation)?;
+    match with_columns {
+        Some(columns) if !columns.is_empty() => {
+            let n_columns = columns.len();
+            write!(
+                f,
+                "\n{:indent$}PROJECT {n_columns}/{total_columns} COLUMNS: {columns:?}",
+                "",
+            )?;
+        },
+        _ => write!(f, "\n{:indent$}PROJECT */{total_columns} COLUMNS", "",)?,
     }
     if let Some(predicate) = predicate {
         write!(f, "\n{:indent$}SELECTION: {predicate}", "")?;
@@ -38,8 +42,28 @@ fn write_scan<P: Display>(
     Ok(())
 }
 
+/// Annotate a node with the number of rows it produces, see [`LogicalPlan::row_estimation`].
+fn write_row_estimation(
+    f: &mut Formatter,
+    row_estimation: Option<(Option<usize>, usize)>,
+) -> fmt::Result {
+    match row_estimation {
+        Some((Some(known), _)) => write!(f, " [ROWS: {known}]"),
+        Some((None, estimated)) => write!(f, " [ESTIMATED ROWS: {estimated}]"),
+        None => Ok(()),
+    }
+}
+
+/// Row estimations of the formatted nodes, see [`LogicalPlan::cached_row_estimation`].
+type RowEstimationCache = PlHashMap<*const LogicalPlan, Option<(Option<usize>, usize)>>;
+
 impl LogicalPlan {
-    fn _format(&self, f: &mut Formatter, indent: usize) -> fmt::Result {
+    fn _format(
+        &self,
+        f: &mut Formatter,
+        indent: usize,
+        cache: &mut RowEstimationCache,
+    ) -> fmt::Result {
         if indent != 0 {
             writeln!(f)?;
         }
@@ -49,21 +73,17 @@ impl LogicalPlan {
             #[cfg(feature = "python")]
             PythonScan { options } => {
                 let total_columns = options.schema.len();
-                let n_columns = options
-                    .with_columns
-                    .as_ref()
-                    .map(|s| s.len() as i64)
-                    .unwrap_or(-1);
 
                 write_scan(
                     f,
                     "PYTHON",
                     Path::new(""),
                     sub_indent,
-                    n_columns,
+                    options.with_columns.as_deref().map(|v| v.as_slice()),
                     total_columns,
                     &options.predicate,
                     options.n_rows,
+                    None,
                 )
             },
             Union { inputs, options } => {
@@ -82,13 +102,14 @@ impl LogicalPlan {
                 write!(f, "{:indent$}{}", "", name)?;
                 for (i, plan) in inputs.iter().enumerate() {
                     write!(f, "\n{:sub_indent$}PLAN {i}:", "")?;
-                    plan._format(f, sub_sub_indent)?;
+                    plan._format(f, sub_sub_indent, cache)?;
                 }
                 write!(f, "\n{:indent$}END {}", "", name)
             },
             Cache { input, id, count } => {
                 write!(f, "{:indent$}CACHE[id: {:x}, count: {}]", "", *id, *count)?;
-                input._format(f, sub_indent)
+                write_row_estimation(f, self.cached_row_estimation(cache))?;
+                input._format(f, sub_indent, cache)
             },
             Scan {
                 path,
@@ -97,27 +118,21 @@ impl LogicalPlan {
                 scan_type,
                 file_options,
                 ..
-            } => {
-                let n_columns = file_options
-                    .with_columns
-                    .as_ref()
-                    .map(|columns| columns.len() as i64)
-                    .unwrap_or(-1);
-                write_scan(
-                    f,
-                    scan_type.into(),
-                    path,
-                    sub_indent,
-                    n_columns,
-                    file_info.schema.len(),
-                    predicate,
-                    file_options.n_rows,
-                )
-            },
+            } => write_scan(
+                f,
+                scan_type.into(),
+                path,
+                sub_indent,
+                file_options.with_columns.as_deref().map(|v| v.as_slice()),
+                file_info.schema.len(),
+                predicate,
+                file_options.n_rows,
+                self.cached_row_estimation(cache),
+            ),
             Selection { predicate, input } => {
                 // this one is writeln because we don't increase indent (which inserts a line)
                 writeln!(f, "{:indent$}FILTER {predicate:?} FROM", "")?;
-                input._format(f, indent)
+                input._format(f, indent, cache)
             },
             DataFrameScan {
                 schema,
@@ -142,24 +157,26 @@ impl LogicalPlan {
                     n_columns,
                     total_columns,
                     selection,
-                )
+                )?;
+                write_row_estimation(f, self.cached_row_estimation(cache))
             },
             Projection { expr, input, .. } => {
                 write!(f, "{:indent$} SELECT {expr:?} FROM", "")?;
-                input._format(f, sub_indent)
+                input._format(f, sub_indent, cache)
             },
             Sort {
                 input, by_column, ..
             } => {
                 write!(f, "{:indent$}SORT BY {by_column:?}", "")?;
-                input._format(f, sub_indent)
+                write_row_estimation(f, self.cached_row_estimation(cache))?;
+                input._format(f, sub_indent, cache)
             },
             Aggregate {
                 input, keys, aggs, ..
             } => {
                 write!(f, "{:indent$}AGGREGATE", "")?;
                 write!(f, "\n{:indent$}\t{aggs:?} BY {keys:?} FROM", "")?;
-                input._format(f, sub_indent)
+                input._format(f, sub_indent, cache)
             },
             Join {
                 input_left,
@@ -172,35 +189,54 @@ impl LogicalPlan {
                 let how = &options.args.how;
                 write!(f, "{:indent$}{how} JOIN:", "")?;
                 write!(f, "\n{:indent$}LEFT PLAN ON: {left_on:?}", "")?;
-                input_left._format(f, sub_indent)?;
+                input_left._format(f, sub_indent, cache)?;
                 write!(f, "\n{:indent$}RIGHT PLAN ON: {right_on:?}", "")?;
-                input_right._format(f, sub_indent)?;
+                input_right._format(f, sub_indent, cache)?;
                 write!(f, "\n{:indent$}END {} JOIN", "", how)
             },
             HStack { input, exprs, .. } => {
                 write!(f, "{:indent$} WITH_COLUMNS:", "",)?;
+                write_row_estimation(f, self.cached_row_estimation(cache))?;
                 write!(f, "\n{:indent$} {exprs:?}", "")?;
-                input._format(f, sub_indent)
+                input._format(f, sub_indent, cache)
+            },
+            ArenaProjection {
+                input,
+                exprs,
+                arena,
+                hstack,
+                ..
+            } => {
+                let exprs = nodes_to_exprs(exprs, arena);
+                if *hstack {
+                    write!(f, "{:indent$} WITH_COLUMNS:", "",)?;
+                    write_row_estimation(f, self.cached_row_estimation(cache))?;
+                    write!(f, "\n{:indent$} {exprs:?}", "")?;
+                } else {
+                    write!(f, "{:indent$} SELECT {exprs:?} FROM", "")?;
+                }
+                input._format(f, sub_indent, cache)
             },
             Distinct { input, options } => {
                 write!(f, "{:indent$}UNIQUE BY {:?}", "", options.subset)?;
-                input._format(f, sub_indent)
+                input._format(f, sub_indent, cache)
             },
             Slice { input, offset, len } => {
                 write!(f, "{:indent$}SLICE[offset: {offset}, len: {len}]", "")?;
-                input._format(f, sub_indent)
+                write_row_estimation(f, self.cached_row_estimation(cache))?;
+                input._format(f, sub_indent, cache)
             },
             MapFunction {
                 input, function, ..
             } => {
                 let function_fmt = format!("{function}");
                 write!(f, "{:indent$}{function_fmt}", "")?;
-                input._format(f, sub_indent)
+                input._format(f, sub_indent, cache)
             },
             Error { input, err } => write!(f, "{err:?}\n{input:?}"),
             ExtContext { input, .. } => {
                 write!(f, "{:indent$}EXTERNAL_CONTEXT", "")?;
-                input._format(f, sub_indent)
+                input._format(f, sub_indent, cache)
             },
             Sink { input, payload, .. } => {
                 let name = match payload {
@@ -210,7 +246,7 @@ impl LogicalPlan {
                     SinkType::Cloud { .. } => "SINK (cloud)",
                 };
                 write!(f, "{:indent$}{}", "", name)?;
-                input._format(f, sub_indent)
+                input._format(f, sub_indent, cache)
             },
         }
     }
@@ -218,7 +254,7 @@ impl LogicalPlan {
 
 impl Debug for LogicalPlan {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        self._format(f, 0)
+        self._format(f, 0, &mut RowEstimationCache::default())
     }
 }
 
@@ -346,6 +382,15 @@ impl Debug for Expr {
                     write!(f, "{:?}.{}()", input[0], options.fmt_str)
                 }
             },
+            Extension {
+                input, function, ..
+            } => {
+                if input.len() >= 2 {
+                    write!(f, "{:?}.{}({:?})", input[0], function.name(), &input[1..])
+                } else {
+                    write!(f, "{:?}.{}()", input[0], function.name())
+                }
+            },
             Slice {
                 input,
                 offset,
@@ -1,5 +1,5 @@
 use super::*;
 
-pub(super) fn explode_impl(df: DataFrame, columns: &[SmartString]) -> PolarsResult<DataFrame> {
+pub(super) fn explode_impl(df: DataFrame, columns: &[PlSmallStr]) -> PolarsResult<DataFrame> {
     df.explode(columns)
 }
@@ -13,7 +13,6 @@ use polars_core::prelude::*;
 use polars_core::StringCacheHolder;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
-use smartstring::alias::String as SmartString;
 
 #[cfg(feature = "python")]
 use crate::dsl::python_udf::PythonFunction;
@@ -58,7 +57,7 @@ pub enum FunctionNode {
         columns: Arc<[Arc<str>]>,
     },
     FastProjection {
-        columns: Arc<[SmartString]>,
+        columns: Arc<[PlSmallStr]>,
         duplicate_check: bool,
     },
     DropNulls {
@@ -75,8 +74,8 @@ pub enum FunctionNode {
         column: Arc<str>,
     },
     Rename {
-        existing: Arc<[SmartString]>,
-        new: Arc<[SmartString]>,
+        existing: Arc<[PlSmallStr]>,
+        new: Arc<[PlSmallStr]>,
         // A column name gets swapped with an existing column
         swapping: bool,
     },
@@ -64,6 +64,7 @@ macro_rules! push_expr {
             // as the root columns/ input columns by `_suffix` and `_keep_name` etc.
             AnonymousFunction { input, .. } => input.$iter().rev().for_each(|e| $push(e)),
             Function { input, .. } => input.$iter().rev().for_each(|e| $push(e)),
+            Extension { input, .. } => input.$iter().rev().for_each(|e| $push(e)),
             Explode(e) => $push(e),
             Window {
                 function,
@@ -3,7 +3,8 @@ use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
 
 use polars_core::prelude::*;
-#[cfg(any(feature = "cloud", feature = "parquet"))]
+use polars_core::utils::slice_offsets;
+#[cfg(any(feature = "cloud", feature = "parquet", feature = "csv"))]
 use polars_io::cloud::CloudOptions;
 
 use crate::logical_plan::LogicalPlan::DataFrameScan;
@@ -34,6 +35,7 @@ mod projection_expr;
 #[cfg(feature = "python")]
 mod pyarrow;
 mod schema;
+mod stats_cache;
 #[cfg(any(feature = "meta", feature = "cse"))]
 pub(crate) mod tree_format;
 pub mod visitor;
@@ -53,6 +55,7 @@ pub use optimizer::*;
 pub use schema::*;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+pub use stats_cache::*;
 use strum_macros::IntoStaticStr;
 
 #[cfg(any(feature = "ipc", feature = "parquet", feature = "csv", feature = "cse"))]
@@ -171,6 +174,8 @@ pub enum LogicalPlan {
         output_schema: Option<SchemaRef>,
         projection: Option<Arc<Vec<String>>>,
         selection: Option<Expr>,
+        #[cfg_attr(feature = "serde", serde(skip))]
+        stats: ScanStatsCache,
     },
     /// Column selection
     Projection {
@@ -206,6 +211,18 @@ pub enum LogicalPlan {
         schema: SchemaRef,
         options: ProjectionOptions,
     },
+    /// Column selection or addition of expressions that are already in an arena, see
+    /// [`AExprBuilder`]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    ArenaProjection {
+        input: Box<LogicalPlan>,
+        exprs: Vec<Node>,
+        arena: Arc<Arena<AExpr>>,
+        schema: SchemaRef,
+        options: ProjectionOptions,
+        /// Add the expressions as columns to the input instead of selecting them.
+        hstack: bool,
+    },
     /// Remove duplicates from the table
     Distinct {
         input: Box<LogicalPlan>,
@@ -260,6 +277,7 @@ impl Default for LogicalPlan {
             output_schema: None,
             projection: None,
             selection: None,
+            stats: Default::default(),
         }
     }
 }
@@ -269,6 +287,94 @@ impl LogicalPlan {
         format!("{self:#?}")
     }
 
+    /// The number of rows this node produces as `(known, estimated)`, if it can be derived
+    /// from the scans without running the query. This is shown when describing the plan.
+    pub fn row_estimation(&self) -> Option<(Option<usize>, usize)> {
+        let input = self
+            .row_estimation_input()
+            .and_then(|input| input.row_estimation());
+        self.row_estimation_from_input(input)
+    }
+
+    /// Like [`LogicalPlan::row_estimation`], but memoized in `cache` so that the estimations
+    /// of all nodes of a plan are computed in a single bottom-up pass.
+    pub(crate) fn cached_row_estimation(
+        &self,
+        cache: &mut PlHashMap<*const LogicalPlan, Option<(Option<usize>, usize)>>,
+    ) -> Option<(Option<usize>, usize)> {
+        let key = self as *const LogicalPlan;
+        if let Some(estimation) = cache.get(&key) {
+            return *estimation;
+        }
+        let input = self
+            .row_estimation_input()
+            .and_then(|input| input.cached_row_estimation(cache));
+        let estimation = self.row_estimation_from_input(input);
+        cache.insert(key, estimation);
+        estimation
+    }
+
+    /// The input whose number of rows determines the number of rows of this node.
+    fn row_estimation_input(&self) -> Option<&LogicalPlan> {
+        use LogicalPlan::*;
+        match self {
+            Slice { input, .. }
+            | Sort { input, .. }
+            | HStack { input, .. }
+            | ArenaProjection {
+                input,
+                hstack: true,
+                ..
+            }
+            | Cache { input, .. } => Some(&**input),
+            _ => None,
+        }
+    }
+
+    /// Derive the row estimation of this node from the estimation of its
+    /// [input](LogicalPlan::row_estimation_input).
+    fn row_estimation_from_input(
+        &self,
+        input: Option<(Option<usize>, usize)>,
+    ) -> Option<(Option<usize>, usize)> {
+        use LogicalPlan::*;
+        let slice = |(known, estimated): (Option<usize>, usize), offset: i64, len: usize| {
+            let slice_len = |n| slice_offsets(offset, len, n).1;
+            (known.map(slice_len), slice_len(estimated))
+        };
+        match self {
+            Scan {
+                file_info,
+                file_options,
+                predicate: None,
+                ..
+            } => {
+                let (known, estimated) = file_info.row_estimation;
+                if estimated == usize::MAX {
+                    return None;
+                }
+                match file_options.n_rows {
+                    Some(n_rows) => Some((known.map(|k| k.min(n_rows)), estimated.min(n_rows))),
+                    None => Some((known, estimated)),
+                }
+            },
+            DataFrameScan {
+                df,
+                selection: None,
+                ..
+            } => Some((Some(df.height()), df.height())),
+            Slice { offset, len, .. } => Some(slice(input?, *offset, *len as usize)),
+            // a slice pushed down into the sort limits its output
+            Sort { args, .. } => match args.slice {
+                Some((offset, len)) => Some(slice(input?, offset, len)),
+                None => input,
+            },
+            HStack { .. } | ArenaProjection { hstack: true, .. } | Cache { .. } => input,
+            // a predicate, aggregation or function may change the number of rows
+            _ => None,
+        }
+    }
+
     pub fn to_alp(self) -> PolarsResult<(Node, Arena<ALogicalPlan>, Arena<AExpr>)> {
         let mut lp_arena = Arena::with_capacity(16);
         let mut expr_arena = Arena::with_capacity(16);
@@ -329,7 +329,7 @@ impl ExprIdentifierVisitor<'_> {
                 function: FunctionExpr::Random { .. },
                 ..
             } => REFUSE_NO_MEMBER,
-            AExpr::AnonymousFunction { .. } => REFUSE_NO_MEMBER,
+            AExpr::AnonymousFunction { .. } | AExpr::Extension { .. } => REFUSE_NO_MEMBER,
             _ => {
                 // During aggregation we only store elementwise operation in the state
                 // other operations we cannot add to the state as they have the output size of the
@@ -339,7 +339,9 @@ impl ExprIdentifierVisitor<'_> {
                         return REFUSE_NO_MEMBER;
                     }
                     match ae {
-                        AExpr::AnonymousFunction { .. } | AExpr::Filter { .. } => REFUSE_NO_MEMBER,
+                        AExpr::AnonymousFunction { .. }
+                        | AExpr::Extension { .. }
+                        | AExpr::Filter { .. } => REFUSE_NO_MEMBER,
                         AExpr::Cast { .. } => REFUSE_ALLOW_MEMBER,
                         _ => ACCEPT,
                     }
@@ -0,0 +1,59 @@
+use std::fmt;
+use std::sync::Arc;
+
+use super::OptimizationRule;
+
+type RuleFactory = Arc<dyn Fn() -> Box<dyn OptimizationRule> + Send + Sync>;
+
+/// The custom [`OptimizationRule`]s of a query, see [`OptState::custom_rules`].
+///
+/// A rule may hold state, so a rule is registered as a factory that is called to create a new
+/// rule every time the query is optimized. The rules are applied alongside the built-in rules
+/// until none of them changes the plan anymore; a rule must therefore return `None` for a
+/// (sub)plan or expression it has already rewritten.
+///
+/// [`OptState::custom_rules`]: crate::frame::OptState::custom_rules
+#[derive(Clone, Default)]
+pub struct CustomRules(Vec<(String, RuleFactory)>);
+
+impl CustomRules {
+    /// Add a rule under `name`, in the order of insertion. This replaces a rule that already
+    /// exists under `name`.
+    pub fn insert<F>(&mut self, name: &str, factory: F)
+    where
+        F: Fn() -> Box<dyn OptimizationRule> + Send + Sync + 'static,
+    {
+        let factory: RuleFactory = Arc::new(factory);
+        match self.0.iter_mut().find(|(n, _)| n == name) {
+            Some((_, f)) => *f = factory,
+            None => self.0.push((name.to_string(), factory)),
+        }
+    }
+
+    /// Remove the rule under `name`. Returns whether a rule was removed.
+    pub fn remove(&mut self, name: &str) -> bool {
+        let len = self.0.len();
+        self.0.retain(|(n, _)| n != name);
+        self.0.len() != len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// The names of the rules, in the order they run.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.0.iter().map(|(name, _)| name.as_str())
+    }
+
+    /// Create the rules, in the order they were inserted.
+    pub(super) fn create(&self) -> Vec<Box<dyn OptimizationRule>> {
+        self.0.iter().map(|(_, factory)| factory()).collect()
+    }
+}
+
+impl fmt::Debug for CustomRules {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.names()).finish()
+    }
+}
@@ -1,7 +1,6 @@
 use std::collections::BTreeSet;
 
 use polars_core::prelude::*;
-use smartstring::SmartString;
 
 use super::*;
 use crate::logical_plan::alp::ALogicalPlan;
@@ -41,7 +40,7 @@ fn impl_fast_projection(
     let mut columns = Vec::with_capacity(expr.len());
     for node in expr.iter() {
         if let AExpr::Column(name) = expr_arena.get(*node) {
-            columns.push(SmartString::from(name.as_ref()))
+            columns.push(PlSmallStr::from(name.as_ref()))
         } else {
             break;
         }
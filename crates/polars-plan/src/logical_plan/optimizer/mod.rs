@@ -12,26 +12,32 @@ mod drop_nulls;
 mod collect_members;
 #[cfg(feature = "cse")]
 mod cse_expr;
+mod custom_rules;
 mod fast_projection;
 #[cfg(any(feature = "ipc", feature = "parquet", feature = "csv", feature = "cse"))]
 pub(crate) mod file_caching;
 mod flatten_union;
 #[cfg(feature = "fused")]
 mod fused;
+mod pre_aggregate;
 mod predicate_pushdown;
 mod projection_pushdown;
 mod simplify_expr;
 mod slice_pushdown_expr;
 mod slice_pushdown_lp;
 mod stack_opt;
+#[cfg(all(feature = "parquet", feature = "dtype-struct"))]
+mod struct_fields;
 mod type_coercion;
 
+pub use custom_rules::CustomRules;
 use delay_rechunk::DelayRechunk;
 use drop_nulls::ReplaceDropNulls;
 use fast_projection::FastProjectionAndCollapse;
 #[cfg(any(feature = "ipc", feature = "parquet", feature = "csv"))]
 use file_caching::{find_column_union_and_fingerprints, FileCacher};
 use polars_io::predicates::PhysicalIoExpr;
+use pre_aggregate::PreAggregateJoin;
 pub use predicate_pushdown::PredicatePushDown;
 pub use projection_pushdown::ProjectionPushDown;
 pub use simplify_expr::{SimplifyBooleanRule, SimplifyExprRule};
@@ -143,6 +149,12 @@ pub fn optimize(
         lp_arena.replace(lp_top, alp);
     }
 
+    // must run after predicate pushdown, so that the predicates of the scans are known
+    #[cfg(all(feature = "parquet", feature = "dtype-struct"))]
+    if projection_pushdown {
+        struct_fields::prune_struct_fields(lp_top, lp_arena, expr_arena);
+    }
+
     // make sure its before slice pushdown.
     if fast_projection {
         rules.push(Box::new(FastProjectionAndCollapse::new(eager)));
@@ -205,7 +217,9 @@ pub fn optimize(
     rules.push(Box::new(ReplaceDropNulls {}));
     if !eager {
         rules.push(Box::new(FlattenUnionRule {}));
+        rules.push(Box::new(PreAggregateJoin {}));
     }
+    rules.extend(opt_state.custom_rules.create());
 
     lp_top = opt.optimize_loop(&mut rules, expr_arena, lp_arena, lp_top)?;
 
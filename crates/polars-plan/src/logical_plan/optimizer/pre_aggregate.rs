@@ -0,0 +1,314 @@
+use super::*;
+use crate::constants::PARTIAL_AGG;
+use crate::logical_plan::ALogicalPlan::*;
+
+/// Aggregate an input of a join before the join if the join is followed by a group_by on the
+/// join keys.
+///
+/// `join(left, right, on=k).group_by([k, ..]).agg([sum(a)])` becomes
+/// `join(left.group_by([k, ..]).agg([sum(a)]), right, on=k).group_by([k, ..]).agg([sum(a)])`
+///
+/// Every group of the pre-aggregation has a single value for the join keys, so it joins with
+/// the same rows of the other input as every row of the group did. That holds for inner and
+/// left joins and for the aggregations `sum`, `count`, `min` and `max`, which can be computed
+/// from partial results.
+///
+/// The pre-aggregation only pays off if it reduces the rows of the input a lot, so the rule
+/// picks the input with the most rows and only fires if the types of its group keys bound the
+/// number of groups well below its number of rows, see [`few_groups`].
+pub(super) struct PreAggregateJoin {}
+
+/// The largest fraction of groups per row for which the pre-aggregation pays off.
+const MAX_GROUPS_PER_ROW: f64 = 0.5;
+
+/// The number of rows `node` produces at most, if the scans know it.
+fn estimated_rows(node: Node, lp_arena: &Arena<ALogicalPlan>) -> Option<usize> {
+    match lp_arena.get(node) {
+        DataFrameScan { df, .. } => Some(df.height()),
+        Scan { file_info, .. } => match file_info.row_estimation.1 {
+            usize::MAX => None,
+            estimated => Some(estimated),
+        },
+        Selection { input, .. }
+        | Projection { input, .. }
+        | HStack { input, .. }
+        | Sort { input, .. }
+        | Cache { input, .. } => estimated_rows(*input, lp_arena),
+        _ => None,
+    }
+}
+
+/// The number of distinct values a column of `dtype` can have, including null, if the type
+/// bounds it.
+fn n_distinct_bound(dtype: &DataType) -> Option<usize> {
+    let n = match dtype {
+        DataType::Boolean => 2,
+        #[cfg(feature = "dtype-u8")]
+        DataType::UInt8 => 1 << 8,
+        #[cfg(feature = "dtype-i8")]
+        DataType::Int8 => 1 << 8,
+        #[cfg(feature = "dtype-u16")]
+        DataType::UInt16 => 1 << 16,
+        #[cfg(feature = "dtype-i16")]
+        DataType::Int16 => 1 << 16,
+        #[cfg(feature = "dtype-categorical")]
+        DataType::Categorical(Some(rev_map)) => rev_map.len(),
+        _ => return None,
+    };
+    Some(n + 1)
+}
+
+/// Whether grouping `input` by `keys` leaves at most [`MAX_GROUPS_PER_ROW`] of its rows. The
+/// number of groups is bounded by the types of the keys and the number of rows by the scans,
+/// so this is `false` if either is unknown.
+fn few_groups(input: Node, keys: &[Arc<str>], lp_arena: &Arena<ALogicalPlan>) -> bool {
+    let Some(rows) = estimated_rows(input, lp_arena) else {
+        return false;
+    };
+    let schema = lp_arena.get(input).schema(lp_arena);
+    let groups = keys.iter().try_fold(1usize, |groups, key| {
+        let bound = n_distinct_bound(schema.get(key)?)?;
+        Some(groups.saturating_mul(bound))
+    });
+    groups.map_or(false, |groups| {
+        groups as f64 <= rows as f64 * MAX_GROUPS_PER_ROW
+    })
+}
+
+/// Split an aggregation into the partial aggregation and its output name. `is_input_column`
+/// tells whether a column of the join output is a column of the pre-aggregated input.
+fn partial_agg(
+    node: Node,
+    expr_arena: &Arena<AExpr>,
+    is_input_column: &dyn Fn(&str) -> bool,
+) -> Option<(AAggExpr, Arc<str>)> {
+    let (agg, name) = match expr_arena.get(node) {
+        AExpr::Alias(input, name) => (expr_arena.get(*input), Some(name.clone())),
+        ae => (ae, None),
+    };
+    let AExpr::Agg(agg) = agg else { return None };
+    let input = match agg {
+        AAggExpr::Min { input, .. }
+        | AAggExpr::Max { input, .. }
+        | AAggExpr::Sum(input)
+        | AAggExpr::Count(input) => *input,
+        _ => return None,
+    };
+    match expr_arena.get(input) {
+        AExpr::Column(column) if is_input_column(column) => {
+            Some((agg.clone(), name.unwrap_or_else(|| column.clone())))
+        },
+        _ => None,
+    }
+}
+
+/// The aggregation that combines the partial results in `column`.
+fn final_agg(partial: &AAggExpr, column: Node) -> AAggExpr {
+    match partial {
+        AAggExpr::Min { propagate_nans, .. } => AAggExpr::Min {
+            input: column,
+            propagate_nans: *propagate_nans,
+        },
+        AAggExpr::Max { propagate_nans, .. } => AAggExpr::Max {
+            input: column,
+            propagate_nans: *propagate_nans,
+        },
+        // counts are summed
+        AAggExpr::Sum(_) | AAggExpr::Count(_) => AAggExpr::Sum(column),
+        _ => unreachable!(),
+    }
+}
+
+fn column_name(node: Node, expr_arena: &Arena<AExpr>) -> Option<&Arc<str>> {
+    match expr_arena.get(node) {
+        AExpr::Column(name) => Some(name),
+        _ => None,
+    }
+}
+
+impl OptimizationRule for PreAggregateJoin {
+    fn optimize_plan(
+        &mut self,
+        lp_arena: &mut Arena<ALogicalPlan>,
+        expr_arena: &mut Arena<AExpr>,
+        node: Node,
+    ) -> Option<ALogicalPlan> {
+        let Aggregate {
+            input,
+            keys,
+            aggs,
+            schema,
+            apply: None,
+            maintain_order: false,
+            options,
+        } = lp_arena.get(node)
+        else {
+            return None;
+        };
+        #[cfg(feature = "dynamic_group_by")]
+        if options.dynamic.is_some() || options.rolling.is_some() {
+            return None;
+        }
+        if options.slice.is_some() || aggs.is_empty() {
+            return None;
+        }
+        let Join {
+            input_left,
+            input_right,
+            left_on,
+            right_on,
+            options: join_options,
+            ..
+        } = lp_arena.get(*input)
+        else {
+            return None;
+        };
+        let args = &join_options.args;
+        if !matches!(args.how, JoinType::Inner | JoinType::Left) || args.slice.is_some() {
+            return None;
+        }
+
+        let key_names = keys
+            .iter()
+            .map(|node| column_name(*node, expr_arena).cloned())
+            .collect::<Option<Vec<_>>>()?;
+        let all_join_keys_grouped = left_on.iter().all(|node| {
+            column_name(*node, expr_arena).map_or(false, |name| key_names.contains(name))
+        });
+        if !all_join_keys_grouped {
+            return None;
+        }
+        let right_on_names = right_on
+            .iter()
+            .map(|node| column_name(*node, expr_arena).cloned())
+            .collect::<Option<Vec<_>>>()?;
+
+        let left_schema = lp_arena.get(*input_left).schema(lp_arena).into_owned();
+        let right_schema = lp_arena.get(*input_right).schema(lp_arena).into_owned();
+
+        // the right columns that share a name with a left column get a suffix in the join
+        // output; the pre-aggregation drops and renames columns, which changes what collides
+        let suffix = args.suffix();
+        let is_suffixed = |name: &str| {
+            name.strip_suffix(suffix).map_or(false, |name| {
+                left_schema.contains(name)
+                    && right_schema.contains(name)
+                    && !right_on_names.iter().any(|k| k.as_ref() == name)
+            })
+        };
+        let references_suffixed = key_names.iter().any(|name| is_suffixed(name))
+            || aggs.iter().any(|node| {
+                aexpr_to_leaf_names_iter(*node, expr_arena).any(|name| is_suffixed(&name))
+            });
+        if references_suffixed {
+            return None;
+        }
+
+        // pre-aggregate the input with the most rows
+        let pre_aggregate_left = estimated_rows(*input_left, lp_arena).unwrap_or(0)
+            >= estimated_rows(*input_right, lp_arena).unwrap_or(0);
+        let (input_left, input_right) = (*input_left, *input_right);
+        let (left_on, right_on) = (left_on.clone(), right_on.clone());
+        let join_options = join_options.clone();
+        let (keys, schema, options) = (keys.clone(), schema.clone(), options.clone());
+
+        let (pre_agg_input, partial_aggs, pre_agg_keys) = if pre_aggregate_left {
+            // the pre-aggregation makes the keys of the left input unique
+            if !matches!(
+                join_options.args.validation,
+                JoinValidation::ManyToMany | JoinValidation::ManyToOne
+            ) {
+                return None;
+            }
+            // a name of the join output refers to the left column if the left input has it
+            let is_left_column = |name: &str| left_schema.contains(name);
+            let partial_aggs = aggs
+                .iter()
+                .map(|node| partial_agg(*node, expr_arena, &is_left_column))
+                .collect::<Option<Vec<_>>>()?;
+            let pre_agg_keys = keys
+                .iter()
+                .zip(&key_names)
+                .filter(|(_, name)| is_left_column(name))
+                .map(|(node, name)| (*node, name.clone()))
+                .collect::<Vec<_>>();
+            (input_left, partial_aggs, pre_agg_keys)
+        } else {
+            // the pre-aggregation makes the keys of the right input unique
+            if !matches!(
+                join_options.args.validation,
+                JoinValidation::ManyToMany | JoinValidation::OneToMany
+            ) {
+                return None;
+            }
+            // the right join keys are not in the join output, and the right columns that
+            // collide with a left column are suffixed
+            let is_right_column = |name: &str| {
+                right_schema.contains(name)
+                    && !left_schema.contains(name)
+                    && !right_on_names.iter().any(|k| k.as_ref() == name)
+            };
+            let partial_aggs = aggs
+                .iter()
+                .map(|node| partial_agg(*node, expr_arena, &is_right_column))
+                .collect::<Option<Vec<_>>>()?;
+            let pre_agg_keys = right_on
+                .iter()
+                .zip(&right_on_names)
+                .map(|(node, name)| (*node, name.clone()))
+                .chain(
+                    keys.iter()
+                        .zip(&key_names)
+                        .filter(|(_, name)| is_right_column(name))
+                        .map(|(node, name)| (*node, name.clone())),
+                )
+                .collect::<Vec<_>>();
+            (input_right, partial_aggs, pre_agg_keys)
+        };
+        // the input is already aggregated, this also stops the rule from matching its own
+        // output
+        if matches!(lp_arena.get(pre_agg_input), Aggregate { .. }) {
+            return None;
+        }
+        let (pre_agg_keys, pre_agg_key_names): (Vec<_>, Vec<_>) = pre_agg_keys.into_iter().unzip();
+        if !few_groups(pre_agg_input, &pre_agg_key_names, lp_arena) {
+            return None;
+        }
+
+        let mut pre_aggs = Vec::with_capacity(partial_aggs.len());
+        let mut final_aggs = Vec::with_capacity(partial_aggs.len());
+        for (i, (agg, name)) in partial_aggs.into_iter().enumerate() {
+            let partial_name: Arc<str> = Arc::from(format!("{PARTIAL_AGG}{i}"));
+            let column = expr_arena.add(AExpr::Column(partial_name.clone()));
+            let final_node = expr_arena.add(AExpr::Agg(final_agg(&agg, column)));
+            final_aggs.push(expr_arena.add(AExpr::Alias(final_node, name)));
+
+            let partial_node = expr_arena.add(AExpr::Agg(agg));
+            pre_aggs.push(expr_arena.add(AExpr::Alias(partial_node, partial_name)));
+        }
+
+        let pre_agg = ALogicalPlanBuilder::new(pre_agg_input, expr_arena, lp_arena)
+            .group_by(pre_agg_keys, pre_aggs, None, false, Default::default())
+            .build();
+        let pre_agg = lp_arena.add(pre_agg);
+        let (input_left, input_right) = if pre_aggregate_left {
+            (pre_agg, input_right)
+        } else {
+            (input_left, pre_agg)
+        };
+        let join = ALogicalPlanBuilder::new(input_left, expr_arena, lp_arena)
+            .join(input_right, left_on, right_on, join_options)
+            .build();
+        let join = lp_arena.add(join);
+
+        Some(Aggregate {
+            input: join,
+            keys,
+            aggs: final_aggs,
+            schema,
+            apply: None,
+            maintain_order: false,
+            options,
+        })
+    }
+}
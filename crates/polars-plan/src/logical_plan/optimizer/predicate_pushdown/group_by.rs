@@ -0,0 +1,71 @@
+use super::*;
+
+/// Predicates that only refer to the group keys remove or keep entire groups, so they can
+/// be applied before the aggregation. All other predicates are applied on the aggregated
+/// result.
+#[allow(clippy::too_many_arguments)]
+pub(super) fn process_group_by(
+    opt: &PredicatePushDown,
+    lp_arena: &mut Arena<ALogicalPlan>,
+    expr_arena: &mut Arena<AExpr>,
+    input: Node,
+    keys: Vec<Node>,
+    aggs: Vec<Node>,
+    schema: SchemaRef,
+    maintain_order: bool,
+    apply: Option<Arc<dyn DataFrameUdf>>,
+    options: Arc<GroupbyOptions>,
+    mut acc_predicates: PlHashMap<Arc<str>, Node>,
+) -> PolarsResult<ALogicalPlan> {
+    use ALogicalPlan::*;
+
+    #[cfg(feature = "dynamic_group_by")]
+    let is_dynamic = options.dynamic.is_some() || options.rolling.is_some();
+    #[cfg(not(feature = "dynamic_group_by"))]
+    let is_dynamic = false;
+
+    // the windows of a dynamic group_by and the rows of a slice depend on all rows
+    // and a udf may not output the keys
+    if is_dynamic || options.slice.is_some() || apply.is_some() {
+        let lp = Aggregate {
+            input,
+            keys,
+            aggs,
+            schema,
+            apply,
+            maintain_order,
+            options,
+        };
+        return opt.no_pushdown_restart_opt(lp, acc_predicates, lp_arena, expr_arena);
+    }
+
+    // only keys that are plain columns have the same values before and after the aggregation
+    let key_names = keys
+        .iter()
+        .filter_map(|node| match expr_arena.get(*node) {
+            AExpr::Column(name) => Some(name.clone()),
+            _ => None,
+        })
+        .collect::<PlHashSet<_>>();
+
+    let local_predicates = transfer_to_local_by_node(&mut acc_predicates, |node| {
+        let mut leaf_names = aexpr_to_leaf_names_iter(node, expr_arena).peekable();
+        // predicates without columns, e.g. on `count()`, depend on the number of groups
+        leaf_names.peek().is_none()
+            || leaf_names.any(|name| !key_names.contains(&name))
+            || predicate_is_pushdown_boundary(node, expr_arena)
+            || has_aexpr(node, expr_arena, |ae| matches!(ae, AExpr::Count))
+    });
+
+    opt.pushdown_and_assign(input, acc_predicates, lp_arena, expr_arena)?;
+    let lp = Aggregate {
+        input,
+        keys,
+        aggs,
+        schema,
+        apply,
+        maintain_order,
+        options,
+    };
+    Ok(opt.optional_apply_predicate(lp, local_predicates, lp_arena, expr_arena))
+}
@@ -1,3 +1,4 @@
+mod group_by;
 mod join;
 mod keys;
 mod rename;
@@ -11,6 +12,7 @@ use utils::*;
 use super::*;
 use crate::dsl::function_expr::FunctionExpr;
 use crate::logical_plan::optimizer;
+use crate::prelude::optimizer::predicate_pushdown::group_by::process_group_by;
 use crate::prelude::optimizer::predicate_pushdown::join::process_join;
 use crate::prelude::optimizer::predicate_pushdown::rename::process_rename;
 use crate::utils::{check_input_node, has_aexpr};
@@ -211,6 +213,7 @@ impl<'a> PredicatePushDown<'a> {
                 output_schema,
                 projection,
                 selection,
+                stats,
             } => {
                 let selection = predicate_at_scan(acc_predicates, selection, expr_arena);
                 let lp = DataFrameScan {
@@ -219,6 +222,7 @@ impl<'a> PredicatePushDown<'a> {
                     output_schema,
                     projection,
                     selection,
+                    stats,
                 };
                 Ok(lp)
             }
@@ -248,7 +252,8 @@ impl<'a> PredicatePushDown<'a> {
                                     schema: schema.clone(),
                                     output_schema: None,
                                     projection: None,
-                                    selection: None
+                                    selection: None,
+                                    stats: Default::default(),
                                 })
                             }
                         }
@@ -461,11 +466,30 @@ impl<'a> PredicatePushDown<'a> {
             // predicates influence slice sizes
             lp @ Slice { .. }
             // caches will be different
-            | lp @ Cache { .. }
-            // dont push down predicates. An aggregation needs all rows
-            | lp @ Aggregate {..} => {
+            | lp @ Cache { .. } => {
                 self.no_pushdown_restart_opt(lp, acc_predicates, lp_arena, expr_arena)
             }
+            Aggregate {
+                input,
+                keys,
+                aggs,
+                schema,
+                apply,
+                maintain_order,
+                options,
+            } => {
+                process_group_by(self, lp_arena,
+                    expr_arena,
+                    input,
+                    keys,
+                    aggs,
+                    schema,
+                    maintain_order,
+                    apply,
+                    options,
+                    acc_predicates
+                )
+            }
             #[cfg(feature = "python")]
              PythonScan {mut options, predicate} => {
                 if options.pyarrow {
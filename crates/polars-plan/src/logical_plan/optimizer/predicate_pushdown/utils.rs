@@ -92,7 +92,9 @@ fn shifts_elements(node: Node, expr_arena: &Arena<AExpr>) -> bool {
 pub(super) fn predicate_is_sort_boundary(node: Node, expr_arena: &Arena<AExpr>) -> bool {
     let matches = |e: &AExpr| match e {
         AExpr::Window { function, .. } => shifts_elements(*function, expr_arena),
-        AExpr::Function { options, .. } | AExpr::AnonymousFunction { options, .. } => {
+        AExpr::Function { options, .. }
+        | AExpr::AnonymousFunction { options, .. }
+        | AExpr::Extension { options, .. } => {
             // this check for functions that are
             // group sensitive and doesn't auto-explode (e.g. is a reduction/aggregation
             // like sum, min, etc).
@@ -122,6 +124,7 @@ pub(super) fn predicate_is_pushdown_boundary(node: Node, expr_arena: &Arena<AExp
             // both need all values
             | AExpr::AnonymousFunction {options: FunctionOptions { collect_groups: ApplyOptions::ApplyGroups, .. }, ..}
             | AExpr::Function {options: FunctionOptions { collect_groups: ApplyOptions::ApplyGroups, .. }, ..}
+            | AExpr::Extension {options: FunctionOptions { collect_groups: ApplyOptions::ApplyGroups, .. }, ..}
             | AExpr::Explode {..}
             // A group_by needs all rows for aggregation
             | AExpr::Window {..}
@@ -152,6 +155,7 @@ pub(super) fn projection_is_definite_pushdown_boundary(
             // both need all values
             | AnonymousFunction {options: FunctionOptions { collect_groups: ApplyOptions::ApplyGroups, .. }, ..}
             | Function {options: FunctionOptions { collect_groups: ApplyOptions::ApplyGroups, .. }, ..}
+            | Extension {options: FunctionOptions { collect_groups: ApplyOptions::ApplyGroups, .. }, ..}
             // still need to investigate this one
             | Explode {..}
             | Count
@@ -190,6 +194,7 @@ pub(super) fn projection_is_optional_pushdown_boundary(
         match e {
             AnonymousFunction { .. }
             | Function { .. }
+            | Extension { .. }
             | BinaryExpr { .. }
             | Ternary { .. }
             | Cast { .. } => true,
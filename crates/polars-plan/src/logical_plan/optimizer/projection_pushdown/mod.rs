@@ -40,19 +40,20 @@ fn get_scan_columns(
     acc_projections: &mut Vec<Node>,
     expr_arena: &Arena<AExpr>,
     row_count: Option<&RowCount>,
+    byte_offset: Option<&str>,
 ) -> Option<Arc<Vec<String>>> {
     let mut with_columns = None;
     if !acc_projections.is_empty() {
         let mut columns = Vec::with_capacity(acc_projections.len());
         for expr in acc_projections {
             for name in aexpr_to_leaf_names(*expr, expr_arena) {
-                // we shouldn't project the row-count column, as that is generated
-                // in the scan
+                // we shouldn't project the row-count and byte-offset columns, as those
+                // are generated in the scan
                 let push = match row_count {
                     Some(rc) if name.as_ref() != rc.name.as_str() => true,
                     None => true,
                     _ => false,
-                };
+                } && byte_offset != Some(name.as_ref());
                 if push {
                     columns.push((*name).to_owned())
                 }
@@ -336,6 +337,7 @@ impl ProjectionPushDown {
                 schema,
                 mut output_schema,
                 selection,
+                stats,
                 ..
             } => {
                 let mut projection = None;
@@ -346,7 +348,7 @@ impl ProjectionPushDown {
                         &schema,
                         false,
                     )?));
-                    projection = get_scan_columns(&mut acc_projections, expr_arena, None);
+                    projection = get_scan_columns(&mut acc_projections, expr_arena, None, None);
                 }
                 let lp = DataFrameScan {
                     df,
@@ -354,6 +356,7 @@ impl ProjectionPushDown {
                     output_schema,
                     projection,
                     selection,
+                    stats,
                 };
                 Ok(lp)
             },
@@ -362,7 +365,8 @@ impl ProjectionPushDown {
                 mut options,
                 predicate,
             } => {
-                options.with_columns = get_scan_columns(&mut acc_projections, expr_arena, None);
+                options.with_columns =
+                    get_scan_columns(&mut acc_projections, expr_arena, None, None);
 
                 options.output_schema = if options.with_columns.is_none() {
                     None
@@ -394,6 +398,7 @@ impl ProjectionPushDown {
                         &mut acc_projections,
                         expr_arena,
                         file_options.row_count.as_ref(),
+                        scan_type.byte_offset(),
                     );
 
                     output_schema = if file_options.with_columns.is_none() {
@@ -1,6 +1,6 @@
 use std::collections::BTreeSet;
 
-use smartstring::alias::String as SmartString;
+use polars_core::prelude::PlSmallStr;
 
 use super::*;
 
@@ -27,8 +27,8 @@ pub(super) fn process_rename(
     acc_projections: &mut [Node],
     projected_names: &mut PlHashSet<Arc<str>>,
     expr_arena: &mut Arena<AExpr>,
-    existing: &[SmartString],
-    new: &[SmartString],
+    existing: &[PlSmallStr],
+    new: &[PlSmallStr],
     swapping: bool,
 ) -> PolarsResult<()> {
     let mut processed = BTreeSet::new();
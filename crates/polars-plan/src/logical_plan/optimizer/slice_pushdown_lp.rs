@@ -7,6 +7,19 @@ pub(super) struct SlicePushDown {
     pub scratch: Vec<Node>,
 }
 
+/// Whether every row of `node` only depends on the same row of its input, so that a slice
+/// may be evaluated before it. UDFs may use the whole column even if they don't apply over
+/// groups, and a literal with more than one value must keep the height of the input.
+fn is_row_wise(node: Node, expr_arena: &Arena<AExpr>) -> bool {
+    aexpr_is_elementwise(node, expr_arena)
+        && !has_aexpr(node, expr_arena, |e| match e {
+            AExpr::AnonymousFunction { .. } => true,
+            AExpr::Extension { function, .. } => !function.is_elementwise(),
+            AExpr::Literal(LiteralValue::Series(_) | LiteralValue::Range { .. }) => true,
+            _ => false,
+        })
+}
+
 #[derive(Copy, Clone)]
 struct State {
     offset: i64,
@@ -126,7 +139,7 @@ impl SlicePushDown {
                 output_schema,
                 file_options: mut options,
                 predicate,
-                scan_type: FileScan::Csv {options: mut csv_options}
+                scan_type: FileScan::Csv {options: mut csv_options, cloud_options, object}
             }, Some(state)) if predicate.is_none() && state.offset >= 0 =>  {
                 options.n_rows = Some(state.len as usize);
                 csv_options.skip_rows += state.offset as usize;
@@ -135,7 +148,7 @@ impl SlicePushDown {
                     path,
                     file_info,
                     output_schema,
-                    scan_type: FileScan::Csv {options: csv_options},
+                    scan_type: FileScan::Csv {options: csv_options, cloud_options, object},
                     file_options: options,
                     predicate,
                 };
@@ -295,7 +308,6 @@ impl SlicePushDown {
             | m @ (MapFunction {function: FunctionNode::Melt {..}, ..}, _)
             | m @ (Cache {..}, _)
             | m @ (Distinct {..}, _)
-            | m @ (HStack {..},_)
             | m @ (Aggregate{..},_)
             // blocking in streaming
             | m @ (Join{..},_)
@@ -336,6 +348,20 @@ impl SlicePushDown {
                     self.no_pushdown_restart_opt(lp, state, lp_arena, expr_arena)
                 }
             }
+            m @ (HStack {..}, None) => {
+                let (lp, state) = m;
+                self.pushdown_and_continue(lp, state, lp_arena, expr_arena)
+            }
+            // the slice may pass if the new columns are computed per row
+            (HStack {input, exprs, schema, options}, Some(_)) => {
+                if exprs.iter().all(|root| is_row_wise(*root, expr_arena)) {
+                    let lp = HStack {input, exprs, schema, options};
+                    self.pushdown_and_continue(lp, state, lp_arena, expr_arena)
+                } else {
+                    let lp = HStack {input, exprs, schema, options};
+                    self.no_pushdown_restart_opt(lp, state, lp_arena, expr_arena)
+                }
+            }
             (catch_all, state) => {
                 self.no_pushdown_finish_opt(catch_all, state, lp_arena)
             }
@@ -0,0 +1,140 @@
+use super::*;
+use crate::dsl::function_expr::StructFunction;
+use crate::logical_plan::ALogicalPlan::*;
+
+/// How the expressions of a plan use the columns of a scan.
+#[derive(Default)]
+struct ColumnUses {
+    /// The fields that are accessed by name per struct column.
+    fields: PlHashMap<Arc<str>, PlHashSet<Arc<str>>>,
+    /// The columns that are used in any other way.
+    whole: PlHashSet<Arc<str>>,
+}
+
+impl ColumnUses {
+    fn collect(&mut self, node: Node, expr_arena: &Arena<AExpr>) {
+        let mut stack = vec![node];
+        while let Some(node) = stack.pop() {
+            let ae = expr_arena.get(node);
+            match ae {
+                AExpr::Function {
+                    input,
+                    function: FunctionExpr::StructExpr(StructFunction::FieldByName(field)),
+                    ..
+                } => {
+                    if let [input] = input.as_slice() {
+                        if let AExpr::Column(name) = expr_arena.get(*input) {
+                            let fields = self.fields.entry(name.clone()).or_default();
+                            fields.insert(field.clone());
+                            continue;
+                        }
+                    }
+                    ae.nodes(&mut stack)
+                },
+                AExpr::Column(name) => {
+                    self.whole.insert(name.clone());
+                },
+                ae => ae.nodes(&mut stack),
+            }
+        }
+    }
+}
+
+fn is_dynamic(options: &GroupbyOptions) -> bool {
+    #[cfg(feature = "dynamic_group_by")]
+    {
+        options.dynamic.is_some() || options.rolling.is_some()
+    }
+    #[cfg(not(feature = "dynamic_group_by"))]
+    {
+        let _ = options;
+        false
+    }
+}
+
+/// Only read the fields of the struct columns of a parquet scan that the query accesses.
+///
+/// This only handles plans with a single input per node, e.g.
+/// `scan.filter(..).select([col("s").struct_().field_by_name("a")])`, that end in a
+/// projection or aggregation. That way the struct columns with fewer fields never leave the
+/// plan and are only observed through field access.
+///
+/// Should run after projection and predicate pushdown, the predicate of the scan is part of
+/// the query.
+pub(super) fn prune_struct_fields(
+    root: Node,
+    lp_arena: &mut Arena<ALogicalPlan>,
+    expr_arena: &Arena<AExpr>,
+) {
+    let mut uses = ColumnUses::default();
+    let mut has_projection = false;
+    let mut exprs = vec![];
+    let mut node = root;
+    let scan = loop {
+        let lp = lp_arena.get(node);
+        let input = match lp {
+            Projection { input, .. } => {
+                has_projection = true;
+                *input
+            },
+            Aggregate {
+                input,
+                apply: None,
+                options,
+                ..
+            } if !is_dynamic(options) => {
+                has_projection = true;
+                *input
+            },
+            HStack { input, .. }
+            | Selection { input, .. }
+            | Slice { input, .. }
+            | Sort { input, .. } => *input,
+            Scan {
+                scan_type: FileScan::Parquet { .. },
+                ..
+            } => break node,
+            _ => return,
+        };
+        lp.copy_exprs(&mut exprs);
+        node = input;
+    };
+    if !has_projection {
+        return;
+    }
+    lp_arena.get(scan).copy_exprs(&mut exprs);
+    for node in exprs {
+        uses.collect(node, expr_arena);
+    }
+
+    let Scan {
+        file_info,
+        scan_type: FileScan::Parquet { options, .. },
+        ..
+    } = lp_arena.get_mut(scan)
+    else {
+        unreachable!()
+    };
+    let struct_fields = file_info
+        .schema
+        .iter()
+        .filter_map(|(name, dtype)| {
+            let DataType::Struct(fields) = dtype else {
+                return None;
+            };
+            if uses.whole.contains(name.as_str()) {
+                return None;
+            }
+            let accessed = uses.fields.get(name.as_str())?;
+            let keep = fields
+                .iter()
+                .filter(|fld| accessed.contains(fld.name().as_str()))
+                .map(|fld| fld.name().to_string())
+                .collect::<Vec<_>>();
+            (!keep.is_empty() && keep.len() < fields.len()).then(|| (name.to_string(), keep))
+        })
+        .collect::<Vec<_>>();
+    if !struct_fields.is_empty() {
+        options.struct_fields = Some(Arc::new(struct_fields));
+    }
+}
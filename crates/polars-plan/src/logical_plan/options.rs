@@ -9,6 +9,8 @@ use polars_io::csv::{CsvEncoding, NullValues};
 use polars_io::ipc::IpcCompression;
 #[cfg(feature = "parquet")]
 use polars_io::parquet::ParquetCompression;
+#[cfg(feature = "csv")]
+use polars_io::ProgressCallback;
 use polars_io::RowCount;
 #[cfg(feature = "dynamic_group_by")]
 use polars_time::{DynamicGroupOptions, RollingGroupOptions};
@@ -37,6 +39,11 @@ pub struct CsvParserOptions {
     pub try_parse_dates: bool,
     pub raise_if_empty: bool,
     pub truncate_ragged_lines: bool,
+    /// Name of the column with the byte offset of every row in the file.
+    pub byte_offset: Option<String>,
+    /// Called every time a chunk of the file is parsed. Not serialized.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub progress: Option<ProgressCallback>,
 }
 
 #[cfg(feature = "parquet")]
@@ -46,6 +53,10 @@ pub struct ParquetOptions {
     pub parallel: polars_io::parquet::ParallelStrategy,
     pub low_memory: bool,
     pub use_statistics: bool,
+    pub use_bloom_filters: bool,
+    /// The fields to read of struct columns, set by the optimizer when a query only
+    /// accesses some fields of a struct. Structs that are not listed are read entirely.
+    pub struct_fields: Option<Arc<Vec<(String, Vec<String>)>>>,
 }
 
 #[cfg(feature = "parquet")]
@@ -75,7 +86,7 @@ pub struct IpcWriterOptions {
 }
 
 #[cfg(feature = "csv")]
-#[derive(Clone, Debug, PartialEq, Eq, Default)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CsvWriterOptions {
     pub has_header: bool,
@@ -84,6 +95,18 @@ pub struct CsvWriterOptions {
     pub serialize_options: SerializeOptions,
 }
 
+#[cfg(feature = "csv")]
+impl Default for CsvWriterOptions {
+    fn default() -> Self {
+        Self {
+            has_header: true,
+            batch_size: 1024,
+            maintain_order: true,
+            serialize_options: SerializeOptions::default(),
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct IpcScanOptions {
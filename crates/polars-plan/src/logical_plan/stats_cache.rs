@@ -0,0 +1,79 @@
+use std::fmt;
+use std::sync::{Arc, RwLock};
+
+use polars_core::prelude::*;
+use polars_io::predicates::{BatchStats, ColumnStats};
+
+/// Memoized column statistics of an in-memory [`DataFrame`] scan.
+///
+/// The frame of a scan never changes, so the statistics of a column are computed the first time a
+/// predicate on the scan is checked against them and are shared by all clones of the plan.
+#[derive(Clone, Default)]
+pub struct ScanStatsCache(Arc<RwLock<PlHashMap<String, Option<ColumnStats>>>>);
+
+impl ScanStatsCache {
+    /// The statistics of the `columns` of `df`, computing those that aren't cached yet.
+    ///
+    /// Columns without a minimum and maximum, e.g. because all their values are null, are left
+    /// out.
+    pub fn get_or_compute<'a, I>(&self, df: &DataFrame, columns: I) -> BatchStats
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        let mut schema = Schema::new();
+        let mut stats = vec![];
+        for name in columns {
+            if schema.contains(name) {
+                continue;
+            }
+            let Ok(s) = df.column(name) else {
+                continue;
+            };
+            let cached = self.0.read().unwrap().get(name).cloned();
+            let s_stats = match cached {
+                Some(s_stats) => s_stats,
+                None => {
+                    let s_stats = compute_stats(s);
+                    self.0
+                        .write()
+                        .unwrap()
+                        .insert(name.to_string(), s_stats.clone());
+                    s_stats
+                },
+            };
+            if let Some(s_stats) = s_stats {
+                schema.with_column(name.into(), s.dtype().clone());
+                stats.push(s_stats);
+            }
+        }
+        BatchStats::new(schema, stats)
+    }
+}
+
+fn compute_stats(s: &Series) -> Option<ColumnStats> {
+    let dtype = s.dtype();
+    if !(dtype.is_numeric()
+        || matches!(dtype, DataType::Utf8 | DataType::Binary | DataType::Boolean))
+    {
+        return None;
+    }
+    let min = s.min_as_series();
+    let max = s.max_as_series();
+    if min.null_count() > 0 || max.null_count() > 0 {
+        return None;
+    }
+    Some(ColumnStats::new(
+        s.field().into_owned(),
+        Some(Series::new("", [s.null_count() as IdxSize])),
+        Some(min),
+        Some(max),
+    ))
+}
+
+impl fmt::Debug for ScanStatsCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.0.read().unwrap().keys())
+            .finish()
+    }
+}
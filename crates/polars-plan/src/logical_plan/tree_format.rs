@@ -50,6 +50,9 @@ impl UpperExp for AExpr {
                 return write!(f, "anonymous_function: {}", options.fmt_str)
             },
             AExpr::Function { function, .. } => return write!(f, "function: {function}"),
+            AExpr::Extension { function, .. } => {
+                return write!(f, "extension: {}", function.name())
+            },
             AExpr::Window { .. } => "window",
             AExpr::Wildcard => "*",
             AExpr::Slice { .. } => "slice",
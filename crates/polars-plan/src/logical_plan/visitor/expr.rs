@@ -170,6 +170,7 @@ impl AexprNode {
                     },
                 ) => fl == fr && ol == or,
                 (AnonymousFunction { .. }, AnonymousFunction { .. }) => false,
+                (Extension { .. }, Extension { .. }) => false,
                 (BinaryExpr { op: l, .. }, BinaryExpr { op: r, .. }) => l == r,
                 _ => false,
             };
@@ -4,7 +4,6 @@ use std::sync::Arc;
 use std::vec::IntoIter;
 
 use polars_core::prelude::*;
-use smartstring::alias::String as SmartString;
 
 use crate::logical_plan::iterator::ArenaExprIter;
 use crate::logical_plan::Context;
@@ -12,7 +11,7 @@ use crate::prelude::names::COUNT;
 use crate::prelude::*;
 
 /// Utility to write comma delimited strings
-pub fn comma_delimited(mut s: String, items: &[SmartString]) -> String {
+pub fn comma_delimited(mut s: String, items: &[PlSmallStr]) -> String {
     s.push('(');
     for c in items {
         s.push_str(c);
@@ -98,7 +97,9 @@ pub(crate) fn aexpr_is_elementwise(current_node: Node, arena: &Arena<AExpr>) ->
     arena.iter(current_node).all(|(_node, e)| {
         use AExpr::*;
         match e {
-            AnonymousFunction { options, .. } | Function { options, .. } => {
+            AnonymousFunction { options, .. }
+            | Function { options, .. }
+            | Extension { options, .. } => {
                 !matches!(options.collect_groups, ApplyOptions::ApplyGroups)
             },
             Column(_)
@@ -8,31 +8,16 @@ use crate::series::AsSeries;
 fn rolling_agg<T>(
     ca: &ChunkedArray<T>,
     options: RollingOptionsImpl,
-    rolling_agg_fn: &dyn Fn(
-        &[T::Native],
-        usize,
-        usize,
-        bool,
-        Option<&[f64]>,
-        DynArgs,
-    ) -> PolarsResult<ArrayRef>,
+    rolling_agg_fn: &dyn Fn(&[T::Native], &RollingOptionsFixedWindow) -> PolarsResult<ArrayRef>,
     rolling_agg_fn_nulls: &dyn Fn(
         &PrimitiveArray<T::Native>,
-        usize,
-        usize,
-        bool,
-        Option<&[f64]>,
-        DynArgs,
+        &RollingOptionsFixedWindow,
     ) -> ArrayRef,
     rolling_agg_fn_dynamic: Option<
         &dyn Fn(
-            &[T::Native],
-            Duration,
+            &PrimitiveArray<T::Native>,
             &[i64],
-            ClosedWindow,
-            TimeUnit,
-            Option<&TimeZone>,
-            DynArgs,
+            &RollingOptionsByTime,
         ) -> PolarsResult<ArrayRef>,
     >,
 ) -> PolarsResult<Series>
@@ -42,6 +27,30 @@ where
     if ca.is_empty() {
         return Ok(Series::new_empty(ca.name(), ca.dtype()));
     }
+    if is_center_biased(&options) {
+        match options.center_bias {
+            CenterBias::Left => {},
+            CenterBias::Right => {
+                // the left biased window of the reversed values is the right biased window
+                let mut options = options;
+                options.center_bias = CenterBias::Left;
+                if let Some(weights) = options.weights.as_mut() {
+                    weights.reverse()
+                }
+                let out = rolling_agg(
+                    &ca.reverse(),
+                    options,
+                    rolling_agg_fn,
+                    rolling_agg_fn_nulls,
+                    rolling_agg_fn_dynamic,
+                )?;
+                return Ok(out.reverse());
+            },
+            CenterBias::Split => polars_bail!(
+                InvalidOperation: "split center bias is only supported by the rolling sum and mean"
+            ),
+        }
+    }
     let ca = ca.rechunk();
 
     let arr = ca.downcast_iter().next().unwrap();
@@ -51,56 +60,70 @@ where
         check_input(options.window_size, options.min_periods)?;
 
         Ok(match ca.null_count() {
-            0 => rolling_agg_fn(
-                arr.values().as_slice(),
-                options.window_size,
-                options.min_periods,
-                options.center,
-                options.weights.as_deref(),
-                options.fn_params,
-            )?,
-            _ => rolling_agg_fn_nulls(
-                arr,
-                options.window_size,
-                options.min_periods,
-                options.center,
-                options.weights.as_deref(),
-                options.fn_params,
-            ),
+            0 => rolling_agg_fn(arr.values().as_slice(), &options)?,
+            _ => rolling_agg_fn_nulls(arr, &options),
         })
     } else {
-        if arr.null_count() > 0 {
-            panic!("'rolling by' not yet supported for series with null values, consider using 'group_by_rolling'")
-        }
-        let values = arr.values().as_slice();
         let duration = options.window_size;
         polars_ensure!(duration.duration_ns() > 0 && !duration.negative, ComputeError:"window size should be strictly positive");
-        let tu = options.tu.unwrap();
         let by = options.by.unwrap();
-        let closed_window = options.closed_window.expect("closed window  must be set");
         let func = rolling_agg_fn_dynamic.expect(
             "'rolling by' not yet supported for this expression, consider using 'group_by_rolling'",
         );
+        let options = RollingOptionsByTime {
+            window_size: duration,
+            min_periods: options.min_periods,
+            closed_window: options.closed_window.expect("closed window  must be set"),
+            tu: options.tu.unwrap(),
+            tz: options.tz,
+            fn_params: options.fn_params,
+        };
 
-        func(
-            values,
-            duration,
-            by,
-            closed_window,
-            tu,
-            options.tz,
-            options.fn_params,
-        )
+        func(arr, by, &options)
     }?;
     Series::try_from((ca.name(), arr))
 }
 
+/// Whether the window is centered and of an even size, so that the `center_bias` applies.
+fn is_center_biased(options: &RollingOptionsImpl) -> bool {
+    options.center && options.window_size.parsed_int && options.window_size.nanoseconds() % 2 == 0
+}
+
+/// Average the rolling aggregation of the left and the right biased window.
+fn rolling_agg_split(
+    s: &Series,
+    options: RollingOptionsImpl,
+    rolling_fn: impl Fn(&Series, RollingOptionsImpl) -> PolarsResult<Series>,
+) -> PolarsResult<Series> {
+    let s = s.to_float()?;
+    let left = rolling_fn(
+        &s,
+        RollingOptionsImpl {
+            center_bias: CenterBias::Left,
+            ..options.clone()
+        },
+    )?;
+    let right = rolling_fn(
+        &s,
+        RollingOptionsImpl {
+            center_bias: CenterBias::Right,
+            ..options
+        },
+    )?;
+    Ok(&(&left + &right) * 0.5)
+}
+
 pub trait SeriesOpsTime: AsSeries {
     /// Apply a rolling mean to a Series.
     ///
     /// See: [`RollingAgg::rolling_mean`]
     #[cfg(feature = "rolling_window")]
     fn rolling_mean(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        if is_center_biased(&options) && options.center_bias == CenterBias::Split {
+            return rolling_agg_split(self.as_series(), options, |s, options| {
+                s.rolling_mean(options)
+            });
+        }
         let s = self.as_series().to_float()?;
         with_match_physical_float_polars_type!(s.dtype(), |$T| {
             let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
@@ -109,13 +132,18 @@ pub trait SeriesOpsTime: AsSeries {
                 options,
                 &rolling::no_nulls::rolling_mean,
                 &rolling::nulls::rolling_mean,
-                Some(&super::rolling_kernels::no_nulls::rolling_mean),
+                Some(&super::rolling_kernels::rolling_mean),
             )
         })
     }
     /// Apply a rolling sum to a Series.
     #[cfg(feature = "rolling_window")]
     fn rolling_sum(&self, options: RollingOptionsImpl) -> PolarsResult<Series> {
+        if is_center_biased(&options) && options.center_bias == CenterBias::Split {
+            return rolling_agg_split(self.as_series(), options, |s, options| {
+                s.rolling_sum(options)
+            });
+        }
         let mut s = self.as_series().clone();
         if options.weights.is_some() {
             s = s.to_float()?;
@@ -128,7 +156,7 @@ pub trait SeriesOpsTime: AsSeries {
                 options,
                 &rolling::no_nulls::rolling_sum,
                 &rolling::nulls::rolling_sum,
-                Some(&super::rolling_kernels::no_nulls::rolling_sum),
+                Some(&super::rolling_kernels::rolling_sum),
             )
         })
     }
@@ -154,7 +182,7 @@ pub trait SeriesOpsTime: AsSeries {
             options,
             &rolling::no_nulls::rolling_quantile,
             &rolling::nulls::rolling_quantile,
-            Some(&super::rolling_kernels::no_nulls::rolling_quantile),
+            Some(&super::rolling_kernels::rolling_quantile),
         )
         })
     }
@@ -169,7 +197,7 @@ pub trait SeriesOpsTime: AsSeries {
             options,
             &rolling::no_nulls::rolling_quantile,
             &rolling::nulls::rolling_quantile,
-            Some(&super::rolling_kernels::no_nulls::rolling_quantile),
+            Some(&super::rolling_kernels::rolling_quantile),
         )
         })
     }
@@ -189,7 +217,7 @@ pub trait SeriesOpsTime: AsSeries {
                 options,
                 &rolling::no_nulls::rolling_min,
                 &rolling::nulls::rolling_min,
-                Some(&super::rolling_kernels::no_nulls::rolling_min),
+                Some(&super::rolling_kernels::rolling_min),
             )
         })
     }
@@ -208,7 +236,7 @@ pub trait SeriesOpsTime: AsSeries {
                 options,
                 &rolling::no_nulls::rolling_max,
                 &rolling::nulls::rolling_max,
-                Some(&super::rolling_kernels::no_nulls::rolling_max),
+                Some(&super::rolling_kernels::rolling_max),
             )
         })
     }
@@ -224,7 +252,7 @@ pub trait SeriesOpsTime: AsSeries {
                 options,
                 &rolling::no_nulls::rolling_var,
                 &rolling::nulls::rolling_var,
-                Some(&super::rolling_kernels::no_nulls::rolling_var),
+                Some(&super::rolling_kernels::rolling_var),
             )
         })
     }
@@ -8,9 +8,27 @@ pub use dispatch::*;
 use polars_arrow::export::arrow;
 use polars_arrow::kernels::rolling;
 use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 use crate::prelude::*;
 
+/// The placement of a centered window of an even size around its label.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CenterBias {
+    /// The window has one more value before the label than after it.
+    #[default]
+    Left,
+    /// The window has one more value after the label than before it.
+    Right,
+    /// The mean of the left and the right biased window. For a sum or mean without
+    /// nulls this is a window of `window_size + 1` values in which the values at both
+    /// edges count half. Only supported by the rolling sum and mean, the output is always
+    /// a float.
+    Split,
+}
+
 #[derive(Clone)]
 pub struct RollingOptions {
     /// The length of the window.
@@ -22,6 +40,8 @@ pub struct RollingOptions {
     pub weights: Option<Vec<f64>>,
     /// Set the labels at the center of the window.
     pub center: bool,
+    /// The placement of a centered window of an even size.
+    pub center_bias: CenterBias,
     /// Compute the rolling aggregates with a window defined by a time column
     pub by: Option<String>,
     /// The closed window of that time window if given
@@ -37,6 +57,7 @@ impl Default for RollingOptions {
             min_periods: 1,
             weights: None,
             center: false,
+            center_bias: CenterBias::Left,
             by: None,
             closed_window: None,
             fn_params: None,
@@ -44,6 +65,17 @@ impl Default for RollingOptions {
     }
 }
 
+impl RollingOptions {
+    /// Whether the output is the mean of the left and the right biased window, see
+    /// [`CenterBias::Split`].
+    pub fn is_center_split(&self) -> bool {
+        self.center_bias == CenterBias::Split
+            && self.center
+            && self.window_size.parsed_int
+            && self.window_size.nanoseconds() % 2 == 0
+    }
+}
+
 #[derive(Clone)]
 pub struct RollingOptionsImpl<'a> {
     /// The length of the window.
@@ -55,6 +87,8 @@ pub struct RollingOptionsImpl<'a> {
     pub weights: Option<Vec<f64>>,
     /// Set the labels at the center of the window.
     pub center: bool,
+    /// The placement of a centered window of an even size.
+    pub center_bias: CenterBias,
     pub by: Option<&'a [i64]>,
     pub tu: Option<TimeUnit>,
     pub tz: Option<&'a TimeZone>,
@@ -62,6 +96,24 @@ pub struct RollingOptionsImpl<'a> {
     pub fn_params: DynArgs,
 }
 
+/// The options of a window defined by a time column, shared by the `rolling_by` kernels.
+#[derive(Clone)]
+pub struct RollingOptionsByTime<'a> {
+    /// The length of the window.
+    pub window_size: Duration,
+    /// Amount of (non-null) elements in the window that should be filled before computing a
+    /// result.
+    pub min_periods: usize,
+    /// Which sides of the window are closed.
+    pub closed_window: ClosedWindow,
+    /// The time unit of the time column.
+    pub tu: TimeUnit,
+    /// The time zone of the time column.
+    pub tz: Option<&'a TimeZone>,
+    /// Optional parameters for the rolling function
+    pub fn_params: DynArgs,
+}
+
 impl From<RollingOptions> for RollingOptionsImpl<'static> {
     fn from(options: RollingOptions) -> Self {
         let window_size = options.window_size;
@@ -75,6 +127,7 @@ impl From<RollingOptions> for RollingOptionsImpl<'static> {
             min_periods: options.min_periods,
             weights: options.weights,
             center: options.center,
+            center_bias: options.center_bias,
             by: None,
             tu: None,
             tz: None,
@@ -109,6 +162,7 @@ impl Default for RollingOptionsImpl<'static> {
             min_periods: 1,
             weights: None,
             center: false,
+            center_bias: CenterBias::Left,
             by: None,
             tu: None,
             tz: None,
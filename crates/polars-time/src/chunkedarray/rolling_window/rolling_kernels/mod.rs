@@ -1,14 +1,150 @@
-pub(super) mod no_nulls;
 use std::fmt::Debug;
-use std::ops::{AddAssign, Mul, SubAssign};
+use std::ops::{Add, AddAssign, Mul, Sub, SubAssign};
 
 use arrow::array::PrimitiveArray;
 use arrow::types::NativeType;
+#[cfg(feature = "timezones")]
+use chrono_tz::Tz;
 use polars_arrow::data_types::IsFloat;
 use polars_arrow::export::arrow;
 use polars_arrow::index::IdxSize;
-use polars_arrow::trusted_len::TrustedLen;
+use polars_arrow::kernels::rolling::no_nulls::{self, RollingAggWindowNoNulls};
+use polars_arrow::kernels::rolling::nulls::{self, RollingAggWindowNulls};
 use polars_core::export::num::{Bounded, Float, NumCast};
 use polars_core::prelude::*;
 
-use crate::prelude::*;
+use super::*;
+
+/// The windows of the values, as (offset, len), given the time column `time`.
+fn offsets_by_time(
+    time: &[i64],
+    options: &RollingOptionsByTime,
+) -> PolarsResult<Vec<(IdxSize, IdxSize)>> {
+    let RollingOptionsByTime {
+        window_size,
+        closed_window,
+        tu,
+        tz,
+        ..
+    } = *options;
+    match tz {
+        #[cfg(feature = "timezones")]
+        Some(tz) => {
+            group_by_values_iter(window_size, time, closed_window, tu, tz.parse::<Tz>().ok())
+                .collect()
+        },
+        _ => group_by_values_iter(window_size, time, closed_window, tu, None).collect(),
+    }
+}
+
+/// Apply the rolling aggregation over the windows of the time column `time`, with the
+/// `NoNulls` window if `arr` has no null values and the `Nulls` window otherwise.
+fn rolling_apply_by_time<'a, NoNulls, Nulls, T>(
+    arr: &'a PrimitiveArray<T>,
+    time: &[i64],
+    options: &RollingOptionsByTime,
+) -> PolarsResult<ArrayRef>
+where
+    NoNulls: RollingAggWindowNoNulls<'a, T>,
+    Nulls: RollingAggWindowNulls<'a, T>,
+    T: Debug + IsFloat + NativeType,
+{
+    polars_ensure!(
+        arr.len() == time.len(),
+        ShapeMismatch: "the time column should have the length of the values in 'rolling by'"
+    );
+    let offsets = offsets_by_time(time, options)?;
+    let params = options.fn_params.clone();
+    let out = if arr.null_count() > 0 {
+        nulls::rolling_apply_agg_window_by_offsets::<Nulls, _>(
+            arr,
+            &offsets,
+            options.min_periods,
+            params,
+        )
+    } else {
+        no_nulls::rolling_apply_agg_window_by_offsets::<NoNulls, _>(
+            arr.values().as_slice(),
+            &offsets,
+            options.min_periods,
+            params,
+        )
+    };
+    Ok(out)
+}
+
+pub(crate) fn rolling_min<T>(
+    arr: &PrimitiveArray<T>,
+    time: &[i64],
+    options: &RollingOptionsByTime,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + PartialOrd + IsFloat + Bounded + NumCast + Mul<Output = T>,
+{
+    rolling_apply_by_time::<no_nulls::MinWindow<_>, nulls::MinWindow<_>, _>(arr, time, options)
+}
+
+pub(crate) fn rolling_max<T>(
+    arr: &PrimitiveArray<T>,
+    time: &[i64],
+    options: &RollingOptionsByTime,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + PartialOrd + IsFloat + Bounded + NumCast + Mul<Output = T>,
+{
+    rolling_apply_by_time::<no_nulls::MaxWindow<_>, nulls::MaxWindow<_>, _>(arr, time, options)
+}
+
+pub(crate) fn rolling_sum<T>(
+    arr: &PrimitiveArray<T>,
+    time: &[i64],
+    options: &RollingOptionsByTime,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType
+        + std::iter::Sum
+        + NumCast
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + AddAssign
+        + SubAssign
+        + IsFloat,
+{
+    rolling_apply_by_time::<no_nulls::SumWindow<_>, nulls::SumWindow<_>, _>(arr, time, options)
+}
+
+pub(crate) fn rolling_mean<T>(
+    arr: &PrimitiveArray<T>,
+    time: &[i64],
+    options: &RollingOptionsByTime,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + Float + std::iter::Sum<T> + SubAssign + AddAssign + IsFloat,
+{
+    rolling_apply_by_time::<no_nulls::MeanWindow<_>, nulls::MeanWindow<_>, _>(arr, time, options)
+}
+
+pub(crate) fn rolling_var<T>(
+    arr: &PrimitiveArray<T>,
+    time: &[i64],
+    options: &RollingOptionsByTime,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + Float + std::iter::Sum<T> + SubAssign + AddAssign + IsFloat,
+{
+    rolling_apply_by_time::<no_nulls::VarWindow<_>, nulls::VarWindow<_>, _>(arr, time, options)
+}
+
+pub(crate) fn rolling_quantile<T>(
+    arr: &PrimitiveArray<T>,
+    time: &[i64],
+    options: &RollingOptionsByTime,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + Float + std::iter::Sum<T> + SubAssign + AddAssign + IsFloat,
+{
+    rolling_apply_by_time::<no_nulls::QuantileWindow<_>, nulls::QuantileWindow<_>, _>(
+        arr, time, options,
+    )
+}
@@ -0,0 +1,188 @@
+//! Parsing of human-readable durations (`"1h30m"`) and ISO 8601 durations (`"PT1H30M"`).
+//!
+//! Calendar units (months, quarters and years) don't have a fixed length, so they cannot be
+//! represented by a `Duration` value and are not accepted.
+use polars_core::prelude::*;
+
+use crate::windows::calendar::{
+    NS_DAY, NS_HOUR, NS_MICROSECOND, NS_MILLISECOND, NS_MINUTE, NS_SECOND, NS_WEEK,
+};
+
+fn strip_sign(s: &str) -> (bool, &str) {
+    match s.strip_prefix('-') {
+        Some(s) => (true, s),
+        None => (false, s),
+    }
+}
+
+fn out_of_range(s: &str) -> PolarsError {
+    polars_err!(ComputeError: "duration '{}' is out of the range of 64-bit nanoseconds", s)
+}
+
+/// Parse a human-readable duration, e.g. `"1h30m"` or `"-1w2d"`, into nanoseconds.
+///
+/// The units are those of the duration strings of the temporal window functions, see
+/// `Duration::parse`. Returns `None` if `s` is malformed and an error if it overflows.
+pub(super) fn parse_polars_duration(s: &str) -> PolarsResult<Option<i64>> {
+    let (negative, mut rest) = strip_sign(s);
+    if rest.is_empty() {
+        return Ok(None);
+    }
+    let mut total: i64 = 0;
+    while !rest.is_empty() {
+        let n_digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+        let n_unit = rest[n_digits..]
+            .bytes()
+            .take_while(u8::is_ascii_alphabetic)
+            .count();
+        if n_digits == 0 || n_unit == 0 {
+            return Ok(None);
+        }
+        let unit = match &rest[n_digits..n_digits + n_unit] {
+            "ns" => 1,
+            "us" => NS_MICROSECOND,
+            "ms" => NS_MILLISECOND,
+            "s" => NS_SECOND,
+            "m" => NS_MINUTE,
+            "h" => NS_HOUR,
+            "d" => NS_DAY,
+            "w" => NS_WEEK,
+            _ => return Ok(None),
+        };
+        // only digits, so this fails on overflow
+        let n = rest[..n_digits]
+            .parse::<i64>()
+            .map_err(|_| out_of_range(s))?;
+        total = n
+            .checked_mul(unit)
+            .and_then(|ns| total.checked_add(ns))
+            .ok_or_else(|| out_of_range(s))?;
+        rest = &rest[n_digits + n_unit..];
+    }
+    Ok(Some(if negative { -total } else { total }))
+}
+
+/// Parse the number-designator pairs `components` of the ISO 8601 duration `s`. The
+/// designators must appear in the order of `designators` and only the seconds may have a
+/// fraction.
+fn parse_iso_components(
+    s: &str,
+    mut components: &str,
+    designators: &[(u8, i64)],
+) -> PolarsResult<Option<i64>> {
+    let mut designators = designators.iter();
+    let mut total: i64 = 0;
+    while !components.is_empty() {
+        let Some(end) = components.find(|c: char| c.is_ascii_alphabetic()) else {
+            return Ok(None);
+        };
+        let designator = components.as_bytes()[end];
+        let Some(&(_, unit)) = designators.by_ref().find(|(d, _)| *d == designator) else {
+            return Ok(None);
+        };
+
+        let (whole, fraction) = match components[..end].split_once(['.', ',']) {
+            Some((whole, fraction)) => (whole, Some(fraction)),
+            None => (&components[..end], None),
+        };
+        if whole.is_empty() || !whole.bytes().all(|b| b.is_ascii_digit()) {
+            return Ok(None);
+        }
+        let mut ns = whole
+            .parse::<i64>()
+            .ok()
+            .and_then(|n| n.checked_mul(unit))
+            .ok_or_else(|| out_of_range(s))?;
+        if let Some(fraction) = fraction {
+            if unit != NS_SECOND
+                || fraction.is_empty()
+                || fraction.len() > 9
+                || !fraction.bytes().all(|b| b.is_ascii_digit())
+            {
+                return Ok(None);
+            }
+            // at most 9 digits, so this is less than a second
+            let fraction_ns =
+                fraction.parse::<i64>().unwrap() * 10i64.pow(9 - fraction.len() as u32);
+            ns = ns.checked_add(fraction_ns).ok_or_else(|| out_of_range(s))?;
+        }
+        total = total.checked_add(ns).ok_or_else(|| out_of_range(s))?;
+        components = &components[end + 1..];
+    }
+    Ok(Some(total))
+}
+
+/// Parse an ISO 8601 duration, e.g. `"P1DT2H3M4.5S"` or `"-PT1M"`, into nanoseconds.
+///
+/// Returns `None` if `s` is malformed and an error if it overflows.
+pub(super) fn parse_iso_duration(s: &str) -> PolarsResult<Option<i64>> {
+    let (negative, rest) = strip_sign(s);
+    let Some(rest) = rest.strip_prefix('P') else {
+        return Ok(None);
+    };
+    let (date, time) = match rest.split_once('T') {
+        Some((_, "")) => return Ok(None),
+        Some((date, time)) => (date, Some(time)),
+        None if rest.is_empty() => return Ok(None),
+        None => (rest, None),
+    };
+    let Some(mut total) = parse_iso_components(s, date, &[(b'W', NS_WEEK), (b'D', NS_DAY)])? else {
+        return Ok(None);
+    };
+    if let Some(time) = time {
+        let designators = [(b'H', NS_HOUR), (b'M', NS_MINUTE), (b'S', NS_SECOND)];
+        let Some(time) = parse_iso_components(s, time, &designators)? else {
+            return Ok(None);
+        };
+        total = total.checked_add(time).ok_or_else(|| out_of_range(s))?;
+    }
+    Ok(Some(if negative { -total } else { total }))
+}
+
+/// Parse either a human-readable or an ISO 8601 duration into nanoseconds.
+pub(super) fn parse_duration(s: &str) -> PolarsResult<Option<i64>> {
+    if s.trim_start_matches('-').starts_with('P') {
+        parse_iso_duration(s)
+    } else {
+        parse_polars_duration(s)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_duration() -> PolarsResult<()> {
+        let ns_1h30m = 90 * NS_MINUTE;
+        assert_eq!(parse_duration("1h30m")?, Some(ns_1h30m));
+        assert_eq!(parse_duration("PT1H30M")?, Some(ns_1h30m));
+        assert_eq!(parse_duration("-1w2d")?, Some(-9 * NS_DAY));
+        assert_eq!(parse_duration("P1W2D")?, Some(9 * NS_DAY));
+        assert_eq!(
+            parse_duration("P1DT2H3M4.5S")?,
+            Some(NS_DAY + 2 * NS_HOUR + 3 * NS_MINUTE + 4 * NS_SECOND + 500 * NS_MILLISECOND)
+        );
+        assert_eq!(parse_duration("-PT0.000000001S")?, Some(-1));
+        assert_eq!(parse_duration("PT0S")?, Some(0));
+
+        // calendar units and malformed durations
+        for s in [
+            "", "1", "h", "1mo", "1y", "1h 30m", "P", "PT", "P1M", "P1Y", "PT1.5H", "PT1M1H",
+            "1.5h",
+        ] {
+            assert_eq!(parse_duration(s)?, None, "{s}");
+        }
+        // out of range durations
+        for s in [
+            "106752d",
+            "9223372036854775808ns",
+            "106751d1w",
+            "P106752D",
+            "P106751DT24H",
+        ] {
+            assert!(parse_duration(s).is_err(), "{s}");
+        }
+        Ok(())
+    }
+}
@@ -453,6 +453,52 @@ fn transform_tzaware_datetime_ms(val: &str, fmt: &str) -> Option<i64> {
     dt.ok().map(|dt| datetime_to_timestamp_ms(dt.naive_utc()))
 }
 
+/// The number of values that are sampled to infer a datetime format.
+pub const INFER_SAMPLE_SIZE: usize = 100;
+
+/// Infer a single datetime format for `ca` from its first `sample_size` non-null values.
+///
+/// All known formats are tried on the sample and the format that parses the most values is
+/// returned, so that the whole column can be parsed with a single format. On a tie, the
+/// format that is listed first wins. Returns `None` if no format parses any value.
+pub fn infer_datetime_format(ca: &Utf8Chunked, sample_size: usize) -> Option<&'static str> {
+    let sample = ca
+        .into_iter()
+        .flatten()
+        .take(sample_size)
+        .collect::<Vec<_>>();
+    let naive = patterns::DATETIME_D_M_Y
+        .iter()
+        .chain(patterns::DATETIME_Y_M_D)
+        .map(|fmt| {
+            let n_parsed = sample
+                .iter()
+                .filter(|val| {
+                    NaiveDateTime::parse_from_str(val, fmt).is_ok()
+                        || NaiveDate::parse_from_str(val, fmt).is_ok()
+                })
+                .count();
+            (*fmt, n_parsed)
+        });
+    let tz_aware = patterns::DATETIME_Y_M_D_Z.iter().map(|fmt| {
+        let n_parsed = sample
+            .iter()
+            .filter(|val| DateTime::parse_from_str(val, fmt).is_ok())
+            .count();
+        (*fmt, n_parsed)
+    });
+
+    let mut best = None;
+    let mut best_n_parsed = 0;
+    for (fmt, n_parsed) in naive.chain(tz_aware) {
+        if n_parsed > best_n_parsed {
+            best = Some(fmt);
+            best_n_parsed = n_parsed;
+        }
+    }
+    best
+}
+
 pub fn infer_pattern_single(val: &str) -> Option<Pattern> {
     // Dates come first, because we see datetimes as superset of dates
     infer_pattern_date_single(val).or_else(|| infer_pattern_datetime_single(val))
@@ -556,3 +602,35 @@ pub(crate) fn to_date(ca: &Utf8Chunked) -> PolarsResult<DateChunked> {
         },
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_infer_datetime_format() {
+        // the format that parses most of the sample wins
+        let ca = Utf8Chunked::new(
+            "",
+            &[
+                None,
+                Some("2021-01-01T00:00:00.123"),
+                Some("2021-01-01T00:00:00.123456"),
+                Some("2021-01-01T00:00:00.123456"),
+            ],
+        );
+        assert_eq!(
+            infer_datetime_format(&ca, INFER_SAMPLE_SIZE),
+            Some("%FT%H:%M:%S%.f")
+        );
+        assert_eq!(infer_datetime_format(&ca, 1), Some("%Y-%m-%dT%H:%M:%S.%3f"));
+
+        let ca = Utf8Chunked::new("", &["31/12/2021 23:58:01", "foo"]);
+        assert_eq!(
+            infer_datetime_format(&ca, INFER_SAMPLE_SIZE),
+            Some("%d/%m/%Y %H:%M:%S")
+        );
+        let ca = Utf8Chunked::new("", &["foo"]);
+        assert_eq!(infer_datetime_format(&ca, INFER_SAMPLE_SIZE), None);
+    }
+}
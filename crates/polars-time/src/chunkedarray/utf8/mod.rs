@@ -1,3 +1,5 @@
+#[cfg(feature = "dtype-duration")]
+mod duration;
 pub mod infer;
 use chrono::DateTime;
 mod patterns;
@@ -101,6 +103,40 @@ fn sniff_fmt_time(ca_utf8: &Utf8Chunked) -> PolarsResult<&'static str> {
 }
 
 pub trait Utf8Methods: AsUtf8 {
+    #[cfg(feature = "dtype-duration")]
+    /// Parsing string values and return a [`DurationChunked`].
+    ///
+    /// The `format` is `"polars"` for human-readable durations like `"1h30m"`, `"iso"` for
+    /// ISO 8601 durations like `"PT1H30M"` or `None` to accept both. Calendar units (months,
+    /// quarters and years) don't have a fixed length and are not accepted. Values that
+    /// cannot be parsed become null. Returns an error if a duration doesn't fit in 64-bit
+    /// nanoseconds.
+    fn as_duration(&self, tu: TimeUnit, format: Option<&str>) -> PolarsResult<DurationChunked> {
+        let utf8_ca = self.as_utf8();
+        let parse: fn(&str) -> PolarsResult<Option<i64>> = match format {
+            None => duration::parse_duration,
+            Some("iso") => duration::parse_iso_duration,
+            Some("polars") => duration::parse_polars_duration,
+            Some(format) => polars_bail!(
+                InvalidOperation: "format '{}' is not supported for Duration, expected 'iso' or 'polars'",
+                format
+            ),
+        };
+        let divisor = match tu {
+            TimeUnit::Nanoseconds => 1,
+            TimeUnit::Microseconds => 1_000,
+            TimeUnit::Milliseconds => 1_000_000,
+        };
+        let ca = utf8_ca
+            .into_iter()
+            .map(|opt_s| match opt_s {
+                Some(s) => Ok(parse(s)?.map(|ns| ns / divisor)),
+                None => Ok(None),
+            })
+            .collect::<PolarsResult<Int64Chunked>>()?;
+        Ok(ca.with_name(utf8_ca.name()).into_duration(tu))
+    }
+
     #[cfg(feature = "dtype-time")]
     /// Parsing string values and return a [`TimeChunked`]
     fn as_time(&self, fmt: Option<&str>, use_cache: bool) -> PolarsResult<TimeChunked> {
@@ -10,7 +10,6 @@ use polars_core::POOL;
 use polars_utils::slice::{GetSaferUnchecked, SortedSlice};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
-use smartstring::alias::String as SmartString;
 
 use crate::prelude::*;
 
@@ -21,7 +20,7 @@ struct Wrap<T>(pub T);
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DynamicGroupOptions {
     /// Time or index column.
-    pub index_column: SmartString,
+    pub index_column: PlSmallStr,
     /// Start a window at this interval.
     pub every: Duration,
     /// Window duration.
@@ -59,7 +58,7 @@ impl Default for DynamicGroupOptions {
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct RollingGroupOptions {
     /// Time or index column.
-    pub index_column: SmartString,
+    pub index_column: PlSmallStr,
     /// Window duration.
     pub period: Duration,
     pub offset: Duration,
@@ -103,6 +102,65 @@ pub trait PolarsTemporalGroupby {
     ) -> PolarsResult<(Series, Vec<Series>, GroupsProxy)>;
 }
 
+/// Get the physical values of the time or index column `time` and the `gap` in their unit.
+fn session_time_and_gap(time: &Series, gap: &Duration) -> PolarsResult<(Int64Chunked, i64)> {
+    polars_ensure!(
+        gap.months() == 0 && !gap.negative,
+        ComputeError: "session gap should be a non-negative duration without months, quarters or years"
+    );
+    polars_ensure!(time.null_count() == 0, ComputeError: "null values in session group_by not supported, fill nulls.");
+
+    use DataType::*;
+    let (time, tu) = match time.dtype() {
+        Datetime(tu, _) => (time.cast(&Int64)?, *tu),
+        Date => (
+            time.cast(&Datetime(TimeUnit::Milliseconds, None))?
+                .cast(&Int64)?,
+            TimeUnit::Milliseconds,
+        ),
+        Int32 | Int64 => (time.cast(&Int64)?, TimeUnit::Nanoseconds),
+        dt => polars_bail!(
+            ComputeError:
+            "expected any of the following dtypes: {{ Date, Datetime, Int32, Int64 }}, got {}",
+            dt
+        ),
+    };
+    let gap = match tu {
+        TimeUnit::Nanoseconds => gap.duration_ns(),
+        TimeUnit::Microseconds => gap.duration_us(),
+        TimeUnit::Milliseconds => gap.duration_ms(),
+    };
+    Ok((time.i64().unwrap().rechunk(), gap))
+}
+
+/// Assign a session id to every value of the sorted time or index column `time`.
+///
+/// Session ids start at zero and increment when the time since the previous value is
+/// larger than `gap`. To sessionize per partition, apply this to every partition, e.g. with
+/// a window expression.
+pub fn session_id(time: &Series, gap: &Duration) -> PolarsResult<IdxCa> {
+    let (ts, gap) = session_time_and_gap(time, gap)?;
+    let ts = ts.cont_slice().unwrap();
+    if !matches!(time.is_sorted_flag(), IsSorted::Ascending) {
+        check_sortedness_slice(ts)?
+    }
+
+    let mut id = 0;
+    let mut ids = Vec::with_capacity(ts.len());
+    if !ts.is_empty() {
+        ids.push(id);
+    }
+    for w in ts.windows(2) {
+        if w[1] - w[0] > gap {
+            id += 1;
+        }
+        ids.push(id);
+    }
+    let mut out = IdxCa::from_vec(time.name(), ids);
+    out.set_sorted_flag(IsSorted::Ascending);
+    Ok(out)
+}
+
 impl PolarsTemporalGroupby for DataFrame {
     fn group_by_rolling(
         &self,
@@ -956,4 +1014,17 @@ mod test {
         assert!(time_key.series_equal(&lower_bound));
         Ok(())
     }
+
+    #[test]
+    fn test_session_id() -> PolarsResult<()> {
+        let gap = Duration::parse("3i");
+
+        let ids = session_id(&Series::new("t", [1i64, 2, 10, 12, 20]), &gap)?;
+        assert_eq!(
+            Vec::from(&ids),
+            &[Some(0), Some(0), Some(1), Some(1), Some(2)]
+        );
+        assert!(session_id(&Series::new("t", [2i64, 1]), &gap).is_err());
+        Ok(())
+    }
 }
@@ -198,19 +198,21 @@ pub trait TemporalMethods: AsSeries {
         }
     }
 
-    /// Convert Time into Utf8 with the given format.
+    /// Convert a temporal type into Utf8 with the given format.
     /// See [chrono strftime/strptime](https://docs.rs/chrono/0.4.19/chrono/format/strftime/index.html).
+    ///
+    /// Durations are formatted with `"iso"` or `"polars"`, see [`DurationChunked::to_string`].
     fn to_string(&self, format: &str) -> PolarsResult<Series> {
         let s = self.as_series();
         match s.dtype() {
             #[cfg(feature = "dtype-date")]
-            DataType::Date => s.date().map(|ca| ca.to_string(format).into_series()),
+            DataType::Date => Ok(s.date()?.try_to_string(format)?.into_series()),
             #[cfg(feature = "dtype-datetime")]
-            DataType::Datetime(_, _) => s
-                .datetime()
-                .map(|ca| Ok(ca.to_string(format)?.into_series()))?,
+            DataType::Datetime(_, _) => Ok(s.datetime()?.to_string(format)?.into_series()),
             #[cfg(feature = "dtype-time")]
-            DataType::Time => s.time().map(|ca| ca.to_string(format).into_series()),
+            DataType::Time => Ok(s.time()?.try_to_string(format)?.into_series()),
+            #[cfg(feature = "dtype-duration")]
+            DataType::Duration(_) => Ok(s.duration()?.to_string(format)?.into_series()),
             dt => polars_bail!(opq = to_string, dt),
         }
     }
@@ -1,5 +1,9 @@
 #[cfg(feature = "dtype-date")]
-use polars_arrow::export::arrow::temporal_conversions::{MILLISECONDS, SECONDS_IN_DAY};
+use polars_arrow::export::arrow::temporal_conversions::MILLISECONDS;
+#[cfg(feature = "dtype-time")]
+use polars_arrow::export::arrow::temporal_conversions::NANOSECONDS;
+#[cfg(any(feature = "dtype-date", feature = "dtype-time"))]
+use polars_arrow::export::arrow::temporal_conversions::SECONDS_IN_DAY;
 use polars_arrow::time_zone::Tz;
 use polars_core::chunked_array::ops::arity::{try_binary_elementwise, try_ternary_elementwise};
 use polars_core::prelude::*;
@@ -142,3 +146,46 @@ impl PolarsTruncate for DateChunked {
         Ok(out?.into_date())
     }
 }
+
+#[cfg(feature = "dtype-time")]
+impl PolarsTruncate for TimeChunked {
+    /// Times are truncated as if they were datetimes on the first day of the epoch, so any
+    /// `every` of a day or longer truncates to midnight.
+    fn truncate(
+        &self,
+        _tz: Option<&Tz>,
+        every: &Utf8Chunked,
+        offset: &str,
+        _ambiguous: &Utf8Chunked,
+    ) -> PolarsResult<Self> {
+        const NSECS_IN_DAY: i64 = NANOSECONDS * SECONDS_IN_DAY;
+        let offset = Duration::parse(offset);
+        // the offset may move a time past midnight, wrap it around
+        let truncate = |w: &Window, t: i64| {
+            w.truncate_ns(t, None, "raise")
+                .map(|t| t.rem_euclid(NSECS_IN_DAY))
+        };
+        let out = match every.len() {
+            1 => {
+                if let Some(every) = every.get(0) {
+                    let every = Duration::parse(every);
+                    let w = Window::new(every, every, offset);
+                    self.0.try_apply(|t| truncate(&w, t))
+                } else {
+                    Ok(Int64Chunked::full_null(self.name(), self.len()))
+                }
+            },
+            _ => try_binary_elementwise(&self.0, every, |opt_t, opt_every| {
+                match (opt_t, opt_every) {
+                    (Some(t), Some(every)) => {
+                        let every = Duration::parse(every);
+                        let w = Window::new(every, every, offset);
+                        truncate(&w, t).map(Some)
+                    },
+                    _ => Ok(None),
+                }
+            }),
+        };
+        Ok(out?.into_time())
+    }
+}
@@ -41,6 +41,11 @@ pub struct Duration {
     // indicates if an offset to a non-existent date (e.g. 2022-02-29)
     // should saturate (to 2022-02-28) as opposed to erroring
     pub(crate) saturating: bool,
+    // indicates if the last day of a month should be offset to the last
+    // day of the resulting month (e.g. 2022-02-28 -> 2022-03-31)
+    // defaults to false, so serialized durations without it can still be read
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) end_of_month: bool,
 }
 
 impl PartialOrd<Self> for Duration {
@@ -66,6 +71,7 @@ impl Duration {
             negative: fixed_slots < 0,
             parsed_int: true,
             saturating: false,
+            end_of_month: false,
         }
     }
 
@@ -100,6 +106,9 @@ impl Duration {
     /// their month should saturate at the largest date (e.g. 2022-02-29 -> 2022-02-28)
     /// instead of erroring.
     ///
+    /// Suffix with `"_eom"` to additionally keep dates on the last day of their month
+    /// on the last day of the resulting month (e.g. 2022-02-28 + 1mo -> 2022-03-31).
+    ///
     /// By "calendar day", we mean the corresponding time on the next
     /// day (which may not be 24 hours, depending on daylight savings).
     /// Similarly for "calendar week", "calendar month", "calendar quarter",
@@ -121,13 +130,14 @@ impl Duration {
         let mut days = 0;
         let mut months = 0;
         let negative = duration.starts_with('-');
-        let (saturating, mut iter) = match duration.ends_with("_saturating") {
-            true => (
-                true,
-                duration[..duration.len() - "_saturating".len()].char_indices(),
-            ),
-            false => (false, duration.char_indices()),
-        };
+        let (saturating, end_of_month, mut iter) =
+            if let Some(stripped) = duration.strip_suffix("_saturating") {
+                (true, false, stripped.char_indices())
+            } else if let Some(stripped) = duration.strip_suffix("_eom") {
+                (true, true, stripped.char_indices())
+            } else {
+                (false, false, duration.char_indices())
+            };
         let mut start = 0;
 
         // skip the '-' char
@@ -197,6 +207,7 @@ impl Duration {
             negative,
             parsed_int,
             saturating,
+            end_of_month,
         }
     }
 
@@ -264,6 +275,7 @@ impl Duration {
             negative,
             parsed_int: false,
             saturating: false,
+            end_of_month: false,
         }
     }
 
@@ -278,6 +290,7 @@ impl Duration {
             negative,
             parsed_int: false,
             saturating: false,
+            end_of_month: false,
         }
     }
 
@@ -292,6 +305,7 @@ impl Duration {
             negative,
             parsed_int: false,
             saturating: false,
+            end_of_month: false,
         }
     }
 
@@ -306,6 +320,7 @@ impl Duration {
             negative,
             parsed_int: false,
             saturating: false,
+            end_of_month: false,
         }
     }
 
@@ -382,7 +397,16 @@ impl Duration {
         n_months: i64,
         negative: bool,
         saturating: bool,
+        end_of_month: bool,
     ) -> PolarsResult<NaiveDateTime> {
+        let days_in_month = |year: i32, month: i32| {
+            let last_day = last_day_of_month(month);
+            if month == (chrono::Month::February.number_from_month() as i32) && is_leap_year(year) {
+                last_day + 1
+            } else {
+                last_day
+            }
+        };
         let mut months = n_months;
         if negative {
             months = -months;
@@ -393,6 +417,7 @@ impl Duration {
         let mut year = ts.year();
         let mut month = ts.month() as i32;
         let mut day = ts.day();
+        let was_end_of_month = day == days_in_month(year, month);
         year += (months / 12) as i32;
         month += (months % 12) as i32;
 
@@ -409,12 +434,8 @@ impl Duration {
 
         if saturating {
             // Normalize the day if we are past the end of the month.
-            let mut last_day_of_month = last_day_of_month(month);
-            if month == (chrono::Month::February.number_from_month() as i32) && is_leap_year(year) {
-                last_day_of_month += 1;
-            }
-
-            if day > last_day_of_month {
+            let last_day_of_month = days_in_month(year, month);
+            if day > last_day_of_month || (end_of_month && was_end_of_month) {
                 day = last_day_of_month
             }
         }
@@ -621,7 +642,7 @@ impl Duration {
                 Some(tz) => unlocalize_datetime(timestamp_to_datetime(t), tz),
                 _ => timestamp_to_datetime(t),
             };
-            let dt = Self::add_month(ts, d.months, d.negative, d.saturating)?;
+            let dt = Self::add_month(ts, d.months, d.negative, d.saturating, d.end_of_month)?;
             new_t = match tz {
                 #[cfg(feature = "timezones")]
                 Some(tz) => datetime_to_timestamp(localize_datetime(dt, tz, "raise")?),
@@ -782,4 +803,30 @@ mod test {
             one_week_negative.add_ns(t, None).unwrap()
         );
     }
+
+    #[test]
+    fn test_add_month_end_of_month() {
+        let ts = |y, m, d| {
+            datetime_to_timestamp_ms(
+                NaiveDate::from_ymd_opt(y, m, d)
+                    .unwrap()
+                    .and_hms_opt(0, 0, 0)
+                    .unwrap(),
+            )
+        };
+        let add = |duration: &str, t| Duration::parse(duration).add_ms(t, None).unwrap();
+
+        // the last day of the month stays at the last day of the month
+        assert_eq!(add("1mo_eom", ts(2022, 2, 28)), ts(2022, 3, 31));
+        assert_eq!(add("1mo_eom", ts(2024, 1, 31)), ts(2024, 2, 29));
+        assert_eq!(add("-1y_eom", ts(2024, 2, 29)), ts(2023, 2, 28));
+        assert_eq!(add("1y_eom", ts(2023, 2, 28)), ts(2024, 2, 29));
+        // other days are offset like with "_saturating"
+        assert_eq!(add("1mo_eom", ts(2022, 1, 30)), ts(2022, 2, 28));
+        assert_eq!(add("1mo_eom", ts(2022, 2, 15)), ts(2022, 3, 15));
+        assert_eq!(add("1mo_saturating", ts(2022, 2, 28)), ts(2022, 3, 28));
+        assert!(Duration::parse("1mo")
+            .add_ms(ts(2022, 1, 30), None)
+            .is_err());
+    }
 }
@@ -1,10 +1,9 @@
 #[macro_export]
 macro_rules! format_smartstring {
     ($($arg:tt)*) => {{
-        use smartstring::alias::String as SmartString;
         use std::fmt::Write;
 
-        let mut string = SmartString::new();
+        let mut string = $crate::aliases::PlSmallStr::new();
         write!(string, $($arg)*).unwrap();
         string
     }}
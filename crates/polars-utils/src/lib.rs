@@ -7,6 +7,7 @@ pub mod contention_pool;
 mod error;
 mod functions;
 pub mod mem;
+mod pl_str;
 pub mod slice;
 pub mod sort;
 pub mod sync;
@@ -0,0 +1,296 @@
+use std::borrow::{Borrow, Cow};
+use std::cmp::Ordering;
+use std::convert::Infallible;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// The number of bytes a [`PlSmallStr`] stores inline.
+const INLINE_CAP: usize = 22;
+
+#[derive(Clone)]
+enum Repr {
+    Inline { len: u8, buf: [u8; INLINE_CAP] },
+    Heap(Arc<str>),
+}
+
+/// The string type of the names of columns, fields and schema entries.
+///
+/// Strings of up to 22 bytes are stored inline and longer strings are reference counted, so
+/// cloning a name never allocates. It compares, orders and hashes like a [`str`].
+#[derive(Clone)]
+pub struct PlSmallStr(Repr);
+
+impl PlSmallStr {
+    pub const fn new() -> Self {
+        Self(Repr::Inline {
+            len: 0,
+            buf: [0; INLINE_CAP],
+        })
+    }
+
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        match &self.0 {
+            // SAFETY: the first `len` bytes are a copy of a `str`.
+            Repr::Inline { len, buf } => unsafe {
+                std::str::from_utf8_unchecked(buf.get_unchecked(..*len as usize))
+            },
+            Repr::Heap(s) => s,
+        }
+    }
+
+    pub fn push_str(&mut self, s: &str) {
+        match &mut self.0 {
+            Repr::Inline { len, buf } if *len as usize + s.len() <= INLINE_CAP => {
+                let start = *len as usize;
+                buf[start..start + s.len()].copy_from_slice(s.as_bytes());
+                *len += s.len() as u8;
+            },
+            _ => {
+                let mut owned = String::with_capacity(self.len() + s.len());
+                owned.push_str(self);
+                owned.push_str(s);
+                self.0 = Repr::Heap(owned.into());
+            },
+        }
+    }
+
+    pub fn push(&mut self, c: char) {
+        self.push_str(c.encode_utf8(&mut [0; 4]))
+    }
+}
+
+impl Default for PlSmallStr {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Deref for PlSmallStr {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for PlSmallStr {
+    fn as_ref(&self) -> &str {
+        self
+    }
+}
+
+impl Borrow<str> for PlSmallStr {
+    fn borrow(&self) -> &str {
+        self
+    }
+}
+
+impl From<&str> for PlSmallStr {
+    fn from(s: &str) -> Self {
+        if s.len() <= INLINE_CAP {
+            let mut buf = [0; INLINE_CAP];
+            buf[..s.len()].copy_from_slice(s.as_bytes());
+            Self(Repr::Inline {
+                len: s.len() as u8,
+                buf,
+            })
+        } else {
+            Self(Repr::Heap(s.into()))
+        }
+    }
+}
+
+impl From<&mut str> for PlSmallStr {
+    fn from(s: &mut str) -> Self {
+        (&*s).into()
+    }
+}
+
+impl From<&String> for PlSmallStr {
+    fn from(s: &String) -> Self {
+        s.as_str().into()
+    }
+}
+
+impl From<String> for PlSmallStr {
+    fn from(s: String) -> Self {
+        if s.len() <= INLINE_CAP {
+            s.as_str().into()
+        } else {
+            Self(Repr::Heap(s.into()))
+        }
+    }
+}
+
+impl From<Box<str>> for PlSmallStr {
+    fn from(s: Box<str>) -> Self {
+        if s.len() <= INLINE_CAP {
+            (&*s).into()
+        } else {
+            Self(Repr::Heap(s.into()))
+        }
+    }
+}
+
+impl From<Cow<'_, str>> for PlSmallStr {
+    fn from(s: Cow<'_, str>) -> Self {
+        match s {
+            Cow::Borrowed(s) => s.into(),
+            Cow::Owned(s) => s.into(),
+        }
+    }
+}
+
+impl From<Arc<str>> for PlSmallStr {
+    fn from(s: Arc<str>) -> Self {
+        if s.len() <= INLINE_CAP {
+            (&*s).into()
+        } else {
+            Self(Repr::Heap(s))
+        }
+    }
+}
+
+impl From<&Arc<str>> for PlSmallStr {
+    fn from(s: &Arc<str>) -> Self {
+        s.clone().into()
+    }
+}
+
+impl From<PlSmallStr> for String {
+    fn from(s: PlSmallStr) -> Self {
+        s.as_str().to_string()
+    }
+}
+
+impl From<PlSmallStr> for Arc<str> {
+    fn from(s: PlSmallStr) -> Self {
+        match s.0 {
+            Repr::Heap(s) => s,
+            Repr::Inline { .. } => s.as_str().into(),
+        }
+    }
+}
+
+impl FromStr for PlSmallStr {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(s.into())
+    }
+}
+
+impl FromIterator<char> for PlSmallStr {
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
+        let mut out = Self::new();
+        out.extend(iter);
+        out
+    }
+}
+
+impl<'a> FromIterator<&'a str> for PlSmallStr {
+    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Self {
+        let mut out = Self::new();
+        out.extend(iter);
+        out
+    }
+}
+
+impl Extend<char> for PlSmallStr {
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|c| self.push(c))
+    }
+}
+
+impl<'a> Extend<&'a str> for PlSmallStr {
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|s| self.push_str(s))
+    }
+}
+
+impl fmt::Write for PlSmallStr {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.push_str(s);
+        Ok(())
+    }
+}
+
+impl fmt::Debug for PlSmallStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Display for PlSmallStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl PartialEq for PlSmallStr {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        match (&self.0, &other.0) {
+            (Repr::Heap(l), Repr::Heap(r)) if Arc::ptr_eq(l, r) => true,
+            _ => self.as_str() == other.as_str(),
+        }
+    }
+}
+
+impl Eq for PlSmallStr {}
+
+impl PartialOrd for PlSmallStr {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PlSmallStr {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl Hash for PlSmallStr {
+    // Must hash like a `str` for lookups by `&str` in maps keyed by names.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+macro_rules! impl_eq_str {
+    ($($t:ty),*) => {$(
+        impl PartialEq<$t> for PlSmallStr {
+            fn eq(&self, other: &$t) -> bool {
+                self.as_str() == AsRef::<str>::as_ref(other)
+            }
+        }
+
+        impl PartialEq<PlSmallStr> for $t {
+            fn eq(&self, other: &PlSmallStr) -> bool {
+                AsRef::<str>::as_ref(self) == other.as_str()
+            }
+        }
+    )*};
+}
+
+impl_eq_str!(str, &str, String);
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for PlSmallStr {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for PlSmallStr {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Self::from)
+    }
+}
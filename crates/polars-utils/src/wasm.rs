@@ -1,12 +1,17 @@
+//! A thread pool that runs all work on the current thread.
+//!
+//! wasm targets cannot spawn threads, so the global `POOL` of polars is replaced by this
+//! [`Pool`] on those targets. It has the subset of the API of `rayon::ThreadPool` that polars
+//! uses.
 pub struct Pool;
 
 impl Pool {
     pub fn current_num_threads(&self) -> usize {
-        rayon::current_num_threads()
+        1
     }
 
     pub fn current_thread_index(&self) -> Option<usize> {
-        rayon::current_thread_index()
+        Some(0)
     }
 
     pub fn current_thread_has_pending_tasks(&self) -> Option<bool> {
@@ -28,13 +33,22 @@ impl Pool {
         RA: Send,
         RB: Send,
     {
-        rayon::join(oper_a, oper_b)
+        (oper_a(), oper_b())
+    }
+
+    pub fn scope<'scope, OP, R>(&self, op: OP) -> R
+    where
+        OP: FnOnce(&rayon::Scope<'scope>) -> R + Send,
+        R: Send,
+    {
+        // rayon runs the scope on the current thread if it cannot spawn threads
+        rayon::scope(op)
     }
 
     pub fn spawn<F>(&self, func: F)
     where
         F: 'static + FnOnce() + Send,
     {
-        rayon::spawn(func);
+        func()
     }
 }
@@ -614,3 +614,42 @@ fn test_4_threads_bit_offset() -> PolarsResult<()> {
     assert_eq!(out.shape(), (1, 2));
     Ok(())
 }
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_join_strategies() -> PolarsResult<()> {
+    let left = df![
+        "key" => [5i32, 1, 3, 3, 8, 2],
+        "a" => ["a", "b", "c", "d", "e", "f"],
+    ]?;
+    let right = df![
+        "key" => [3i32, 5, 9, 1, 3],
+        "b" => [1, 2, 3, 4, 5],
+    ]?;
+    let sorted_left = left.sort(["key"], false, false)?;
+
+    for how in [JoinType::Inner, JoinType::Left] {
+        for left in [&left, &sorted_left] {
+            let expected = left.join(&right, ["key"], ["key"], how.clone().into())?;
+            let expected = expected.sort(["key", "a", "b"], false, false)?;
+
+            for strategy in [
+                JoinStrategy::Hash,
+                JoinStrategy::SortMerge,
+                JoinStrategy::Broadcast,
+            ] {
+                let args = JoinArgs::new(how.clone()).with_strategy(strategy);
+                let out = left.join(&right, ["key"], ["key"], args)?;
+                // a left join preserves the order of the left table
+                if matches!(how, JoinType::Left) {
+                    assert!(out.column("a")?.series_equal(
+                        &left.join(&right, ["key"], ["key"], how.clone().into())?["a"]
+                    ));
+                }
+                let out = out.sort(["key", "a", "b"], false, false)?;
+                assert!(out.frame_equal_missing(&expected), "{strategy}");
+            }
+        }
+    }
+    Ok(())
+}
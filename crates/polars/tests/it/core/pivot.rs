@@ -1,6 +1,6 @@
 use polars::export::chrono::NaiveDate;
 use polars::prelude::*;
-use polars_ops::pivot::{pivot, pivot_stable, PivotAgg};
+use polars_ops::pivot::{pivot, pivot_stable, pivot_with_margins, PivotAgg};
 
 #[test]
 #[cfg(feature = "dtype-date")]
@@ -245,3 +245,108 @@ fn test_pivot_datetime() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_pivot_with_margins() -> PolarsResult<()> {
+    let df = df![
+        "foo" => ["one", "one", "two", "two", "one", "two"],
+        "bar" => ["y", "y", "y", "x", "x", "x"],
+        "baz" => [1, 2, 3, 4, 5, 6]
+    ]?;
+
+    let out = pivot_with_margins(
+        &df,
+        ["baz"],
+        ["foo"],
+        ["bar"],
+        false,
+        Some(PivotAgg::Sum),
+        None,
+        "All",
+    )?;
+    let expected = df![
+        "foo" => ["one", "two", "All"],
+        "y" => [3, 3, 6],
+        "x" => [5, 10, 15],
+        "All" => [8, 13, 21]
+    ]?;
+    assert!(out.frame_equal(&expected));
+
+    // the margins aggregate the original rows, not the pivoted cells
+    let out = pivot_with_margins(
+        &df,
+        ["baz"],
+        ["foo"],
+        ["bar"],
+        false,
+        Some(PivotAgg::Mean),
+        None,
+        "Total",
+    )?;
+    let margin_row = out.slice(2, 1);
+    assert_eq!(margin_row.column("y")?.f64()?.get(0), Some(2.0));
+    assert_eq!(margin_row.column("x")?.f64()?.get(0), Some(5.0));
+    assert_eq!(margin_row.column("Total")?.f64()?.get(0), Some(3.5));
+
+    assert!(pivot_with_margins(&df, ["baz"], ["foo"], ["bar"], false, None, None, "All").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_pivot_with_margins_multiple_values() -> PolarsResult<()> {
+    let df = df![
+        "foo" => ["one", "one", "two", "two"],
+        "bar" => ["y", "x", "y", "y"],
+        "baz" => [1, 2, 3, 4],
+        "qux" => [10, 20, 30, 40]
+    ]?;
+
+    let out = pivot_with_margins(
+        &df,
+        ["baz", "qux"],
+        ["foo"],
+        ["bar"],
+        false,
+        Some(PivotAgg::Max),
+        None,
+        "All",
+    )?;
+    let expected = df![
+        "foo" => ["one", "two", "All"],
+        "baz_bar_y" => [Some(1), Some(4), Some(4)],
+        "baz_bar_x" => [Some(2), None, Some(2)],
+        "qux_bar_y" => [Some(10), Some(40), Some(40)],
+        "qux_bar_x" => [Some(20), None, Some(20)],
+        "baz_All" => [2, 4, 4],
+        "qux_All" => [20, 40, 40]
+    ]?;
+    assert!(out.frame_equal_missing(&expected));
+    Ok(())
+}
+
+#[test]
+fn test_pivot_with_margins_name_collision() -> PolarsResult<()> {
+    let df = df![
+        "foo" => ["one", "one", "two"],
+        "bar" => ["y", "x", "All"],
+        "baz" => [1, 2, 3]
+    ]?;
+
+    // the name is a value of the pivoted column, or of the index
+    let margins = |index: &str, columns: &str, margins_name: &str| {
+        pivot_with_margins(
+            &df,
+            ["baz"],
+            [index],
+            [columns],
+            false,
+            Some(PivotAgg::Sum),
+            None,
+            margins_name,
+        )
+    };
+    assert!(margins("foo", "bar", "Total").is_ok());
+    assert!(margins("foo", "bar", "All").is_err());
+    assert!(margins("bar", "foo", "All").is_err());
+    Ok(())
+}
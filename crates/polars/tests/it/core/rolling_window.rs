@@ -425,3 +425,115 @@ fn test_median_quantile_types() {
     assert_eq!(*rol_quantile.dtype(), DataType::Float64);
     assert_eq!(*rol_quantile_weighted.dtype(), DataType::Float64);
 }
+
+#[test]
+fn test_rolling_center_bias() -> PolarsResult<()> {
+    let s = Int32Chunked::new("foo", &[1, 2, 3, 4, 5]).into_series();
+    let options = |center_bias| RollingOptionsImpl {
+        window_size: Duration::new(2),
+        min_periods: 1,
+        center: true,
+        center_bias,
+        ..Default::default()
+    };
+
+    let left = s.rolling_mean(options(CenterBias::Left))?;
+    assert_eq!(
+        Vec::from(left.f64()?),
+        &[Some(1.0), Some(1.5), Some(2.5), Some(3.5), Some(4.5)]
+    );
+    let right = s.rolling_mean(options(CenterBias::Right))?;
+    assert_eq!(
+        Vec::from(right.f64()?),
+        &[Some(1.5), Some(2.5), Some(3.5), Some(4.5), Some(5.0)]
+    );
+    let right = s.rolling_max(options(CenterBias::Right))?;
+    assert_eq!(
+        Vec::from(right.i32()?),
+        &[Some(2), Some(3), Some(4), Some(5), Some(5)]
+    );
+
+    let split = s.rolling_mean(options(CenterBias::Split))?;
+    assert_eq!(
+        Vec::from(split.f64()?),
+        &[Some(1.25), Some(2.0), Some(3.0), Some(4.0), Some(4.75)]
+    );
+    let split = s.rolling_sum(options(CenterBias::Split))?;
+    assert_eq!(
+        Vec::from(split.f64()?),
+        &[Some(2.0), Some(4.0), Some(6.0), Some(8.0), Some(7.0)]
+    );
+    assert!(s.rolling_min(options(CenterBias::Split)).is_err());
+
+    // the bias doesn't apply to windows of an odd size
+    let odd = |center_bias| RollingOptionsImpl {
+        window_size: Duration::new(3),
+        ..options(center_bias)
+    };
+    assert!(s
+        .rolling_min(odd(CenterBias::Split))?
+        .series_equal(&s.rolling_min(odd(CenterBias::Left))?));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "lazy")]
+fn test_rolling_sum_center_split_schema() -> PolarsResult<()> {
+    let df = df!["a" => [1, 2, 3, 4, 5]]?;
+    let options = |center_bias| RollingOptions {
+        window_size: Duration::new(2),
+        center: true,
+        center_bias,
+        ..Default::default()
+    };
+
+    for (center_bias, dtype) in [
+        (CenterBias::Left, DataType::Int32),
+        (CenterBias::Split, DataType::Float64),
+    ] {
+        let lf = df
+            .clone()
+            .lazy()
+            .select([col("a").rolling_sum(options(center_bias))]);
+        assert_eq!(lf.schema()?.get("a"), Some(&dtype));
+        assert_eq!(lf.collect()?.column("a")?.dtype(), &dtype);
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "lazy", feature = "dtype-datetime"))]
+fn test_rolling_by() -> PolarsResult<()> {
+    let hour = 3_600_000i64;
+    let mut t = Series::new("t", &[0, hour, 2 * hour, 4 * hour])
+        .cast(&DataType::Datetime(TimeUnit::Milliseconds, None))?;
+    t.set_sorted_flag(IsSorted::Ascending);
+    let df = df![
+        "t" => t,
+        "a" => [Some(1.0), None, Some(3.0), Some(4.0)],
+        "b" => [1.0, 2.0, 3.0, 4.0],
+    ]?;
+    let options = |min_periods| RollingOptions {
+        window_size: Duration::parse("2h"),
+        min_periods,
+        closed_window: Some(ClosedWindow::Right),
+        ..Default::default()
+    };
+
+    let out = df
+        .lazy()
+        .select([
+            col("a").rolling_sum_by(col("t"), options(1)),
+            col("b").rolling_mean_by(col("t"), options(2)),
+        ])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("a")?.f64()?),
+        &[Some(1.0), Some(1.0), Some(3.0), Some(4.0)]
+    );
+    assert_eq!(
+        Vec::from(out.column("b")?.f64()?),
+        &[None, Some(1.5), Some(2.5), None]
+    );
+    Ok(())
+}
@@ -1,6 +1,9 @@
 use std::io::Cursor;
+use std::sync::Mutex;
 
-use polars::io::RowCount;
+use polars::io::mmap::MmapBytesReader;
+use polars::io::{ProgressCallback, RowCount};
+use polars_core::utils::accumulate_dataframes_vertical;
 
 use super::*;
 
@@ -47,6 +50,34 @@ fn test_read_csv_file() {
     assert_eq!(df.shape(), (27, 4));
 }
 
+#[test]
+fn test_read_csv_from_slice() -> PolarsResult<()> {
+    let csv = b"a,b\n1,foo\n2,bar\n";
+    let df = CsvReader::from_slice(csv).finish()?;
+    assert_eq!(df.shape(), (2, 2));
+    assert_eq!(
+        Vec::from(df.column("b")?.utf8()?),
+        &[Some("foo"), Some("bar")]
+    );
+
+    let csv = (0..100).fold(String::from("a,b\n"), |mut csv, i| {
+        csv.push_str(&format!("{i},foo{i}\n"));
+        csv
+    });
+    let mut reader = CsvReader::from_slice(csv.as_bytes())
+        .with_chunk_size(10)
+        .batched_slice()?;
+    let mut batches = vec![];
+    while let Some(dfs) = reader.next_batches(3)? {
+        batches.extend(dfs);
+    }
+    assert!(batches.len() > 1);
+    let df = accumulate_dataframes_vertical(batches)?;
+    assert_eq!(df.shape(), (100, 2));
+    assert_eq!(df.column("b")?.utf8()?.get(99), Some("foo99"));
+    Ok(())
+}
+
 #[test]
 fn test_parser() -> PolarsResult<()> {
     let s = r#"
@@ -961,6 +992,25 @@ fn test_with_row_count() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_with_byte_offset() -> PolarsResult<()> {
+    let csv = "a,b\n1,x\n# comment\n22,yy\n333,z\n";
+    let df = CsvReader::new(Cursor::new(csv))
+        .with_comment_char(Some(b'#'))
+        .with_columns(Some(vec!["b".into()]))
+        .with_byte_offset(Some("offset".into()))
+        .finish()?;
+    assert_eq!(df.get_column_names(), &["b", "offset"]);
+    assert_eq!(
+        df.column("offset")?
+            .u64()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        &[4, 18, 24]
+    );
+    Ok(())
+}
+
 #[test]
 fn test_empty_string_cols() -> PolarsResult<()> {
     let csv = "\nabc\n\nxyz\n";
@@ -1117,3 +1167,95 @@ fn test_leading_whitespace_with_quote() -> PolarsResult<()> {
     assert_eq!(col_2.get(0)?, AnyValue::Float64(4.1));
     Ok(())
 }
+
+#[test]
+fn test_transcode_encodings() -> PolarsResult<()> {
+    let csv = "naïve,prix\ncafé,3\nrôti,€5\n";
+    let expected = CsvReader::new(Cursor::new(csv)).finish()?;
+
+    // the € sign is not in latin-1
+    let latin1 = "naïve,prix\ncafé,3\nrôti,5\n"
+        .chars()
+        .map(|c| c as u8)
+        .collect::<Vec<_>>();
+    let df = CsvReader::new(Cursor::new(latin1))
+        .with_encoding(CsvEncoding::Latin1)
+        .finish()?;
+    assert_eq!(df.get_column_names(), &["naïve", "prix"]);
+    assert!(df.column("naïve")?.series_equal(expected.column("naïve")?));
+    assert_eq!(df.column("prix")?.get(1)?, AnyValue::Int64(5));
+
+    for (encoding, to_bytes) in [
+        (CsvEncoding::Utf16Le, u16::to_le_bytes as fn(u16) -> [u8; 2]),
+        (CsvEncoding::Utf16Be, u16::to_be_bytes),
+    ] {
+        // with a byte order mark
+        let utf16 = std::iter::once(0xFEFF)
+            .chain(csv.encode_utf16())
+            .flat_map(to_bytes)
+            .collect::<Vec<_>>();
+        let df = CsvReader::new(Cursor::new(utf16))
+            .with_encoding(encoding)
+            .finish()?;
+        assert!(df.frame_equal(&expected));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_progress_callback() -> PolarsResult<()> {
+    let events = Arc::new(Mutex::new(vec![]));
+    let callback = {
+        let events = events.clone();
+        ProgressCallback::new(move |progress| events.lock().unwrap().push(progress))
+    };
+    let df = CsvReader::from_path(FOODS_CSV)?
+        .with_progress_callback(Some(callback))
+        .finish()?;
+
+    let events = events.lock().unwrap();
+    assert!(!events.is_empty());
+    // the chunks are parsed in parallel, the last event is not necessarily the final one
+    let bytes_total = events[0].bytes_total;
+    assert!(events.iter().all(|p| p.bytes_total == bytes_total));
+    assert_eq!(events.iter().map(|p| p.bytes_read).max(), Some(bytes_total));
+    assert_eq!(events.iter().map(|p| p.rows_read).max(), Some(df.height()));
+    Ok(())
+}
+
+#[test]
+fn test_batched_transcode_encodings() -> PolarsResult<()> {
+    let csv = (0..100).map(|i| format!("é{i},{i}\n")).collect::<String>();
+    let csv = format!("naïve,n\n{csv}");
+    let expected = CsvReader::new(Cursor::new(csv.clone())).finish()?;
+
+    let latin1 = csv.chars().map(|c| c as u8).collect::<Vec<_>>();
+    let utf16 = csv
+        .encode_utf16()
+        .flat_map(u16::to_le_bytes)
+        .collect::<Vec<_>>();
+    for (bytes, encoding) in [(latin1, CsvEncoding::Latin1), (utf16, CsvEncoding::Utf16Le)] {
+        for low_memory in [false, true] {
+            let reader: Box<dyn MmapBytesReader> = Box::new(Cursor::new(bytes.clone()));
+            let reader = CsvReader::new(reader)
+                .with_encoding(encoding)
+                .with_chunk_size(10);
+            let mut batches = vec![];
+            if low_memory {
+                let mut reader = reader.batched_read(None)?;
+                while let Some(dfs) = reader.next_batches(3)? {
+                    batches.extend(dfs);
+                }
+            } else {
+                let mut reader = reader.batched_mmap(None)?;
+                while let Some(dfs) = reader.next_batches(3)? {
+                    batches.extend(dfs);
+                }
+            }
+            assert!(batches.len() > 1);
+            let df = accumulate_dataframes_vertical(batches)?;
+            assert!(df.frame_equal(&expected));
+        }
+    }
+    Ok(())
+}
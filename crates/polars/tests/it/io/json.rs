@@ -209,3 +209,34 @@ fn test_read_ndjson_iss_6148() {
     let df = JsonLineReader::new(cursor).finish();
     assert!(df.is_ok());
 }
+
+#[test]
+#[cfg(feature = "dtype-date")]
+fn test_write_json_orientation_and_date_format() -> PolarsResult<()> {
+    let mut df = df![
+        "a" => [Some(1), None],
+        "d" => [0, 1]
+    ]?;
+    df.try_apply("d", |s| s.cast(&DataType::Date))?;
+
+    let write = |column_oriented: bool, json_format: JsonFormat| {
+        let mut buf = vec![];
+        let mut writer = JsonWriter::new(&mut buf)
+            .with_json_format(json_format)
+            .with_column_oriented(column_oriented)
+            .with_date_format(Some("%d/%m/%Y".to_string()));
+        writer.finish(&mut df.clone())?;
+        PolarsResult::Ok(String::from_utf8(buf).unwrap())
+    };
+
+    assert_eq!(
+        write(false, JsonFormat::Json)?,
+        r#"[{"a":1,"d":"01/01/1970"},{"a":null,"d":"02/01/1970"}]"#
+    );
+    assert_eq!(
+        write(true, JsonFormat::Json)?,
+        r#"{"a":[1,null],"d":["01/01/1970","02/01/1970"]}"#
+    );
+    assert!(write(true, JsonFormat::JsonLines).is_err());
+    Ok(())
+}
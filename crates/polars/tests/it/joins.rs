@@ -1,4 +1,4 @@
-#[cfg(feature = "lazy")]
+#[cfg(any(feature = "lazy", feature = "semi_anti_join"))]
 use polars::prelude::*;
 
 #[test]
@@ -50,3 +50,32 @@ fn join_empty_datasets() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "semi_anti_join")]
+fn filter_by_keys() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 2, 2, 3, 4],
+        "b" => ["x", "y", "z", "y", "x"],
+        "c" => [1.0, 2.0, 3.0, 4.0, 5.0]
+    ]?;
+    let other = df![
+        "b" => ["y", "x", "y"],
+        "a" => [2, 4, 3],
+        "d" => [true, false, true]
+    ]?;
+
+    let out = df.filter_by_keys(&other, ["a"], true)?;
+    assert_eq!(out.get_column_names(), &["a", "b", "c"]);
+    assert_eq!(out.column("c")?, &Series::new("c", [2.0, 3.0, 4.0, 5.0]));
+
+    let out = df.filter_by_keys(&other, ["a", "b"], true)?;
+    assert_eq!(out.column("c")?, &Series::new("c", [2.0, 4.0, 5.0]));
+
+    let out = df.filter_by_keys(&other, ["a", "b"], false)?;
+    assert_eq!(out.get_column_names(), &["a", "b", "c"]);
+    assert_eq!(out.column("c")?, &Series::new("c", [1.0, 3.0]));
+
+    assert!(df.filter_by_keys(&other, ["d"], true).is_err());
+    Ok(())
+}
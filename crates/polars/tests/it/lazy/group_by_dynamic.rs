@@ -61,3 +61,33 @@ fn test_group_by_dynamic_week_bounds() -> PolarsResult<()> {
     assert_eq!(a.get(1)?, AnyValue::Int32(6));
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "dynamic_group_by")]
+fn test_group_by_session() -> PolarsResult<()> {
+    let df = df![
+        "user" => ["a", "b", "a", "b", "a", "a"],
+        "t" => [1i64, 2, 3, 10, 10, 11],
+        "v" => [1, 2, 3, 4, 5, 6],
+    ]?;
+
+    let out = df
+        .lazy()
+        .group_by_session(col("t"), [col("user")], "3i", "session")
+        .agg([col("v").sum()])
+        .collect()?;
+
+    assert_eq!(
+        Vec::from(out.column("user")?.utf8()?),
+        &[Some("a"), Some("b"), Some("b"), Some("a")]
+    );
+    assert_eq!(
+        Vec::from(out.column("session")?.idx()?),
+        &[Some(0), Some(0), Some(1), Some(1)]
+    );
+    assert_eq!(
+        Vec::from(out.column("v")?.i32()?),
+        &[Some(4), Some(2), Some(4), Some(11)]
+    );
+    Ok(())
+}
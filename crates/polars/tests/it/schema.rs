@@ -544,3 +544,29 @@ fn test_set_dtype() {
         ),
     );
 }
+
+#[test]
+fn test_small_str_names() {
+    use std::fmt::Write;
+
+    let short = PlSmallStr::from("short");
+    let long = PlSmallStr::from("a column name that is too long to be inlined");
+    assert_eq!(short, "short");
+    assert_eq!(long.clone(), long);
+    assert!(short < long);
+
+    let mut name = short.clone();
+    name.push_str("_and_a_suffix_that_spills");
+    assert_eq!(name, "short_and_a_suffix_that_spills");
+    let mut formatted = PlSmallStr::new();
+    write!(formatted, "{short}_{}", 1).unwrap();
+    assert_eq!(formatted, "short_1");
+
+    // names are looked up by `&str`, whatever their length
+    let schema = Schema::from_iter([
+        Field::new(&short, DataType::Int32),
+        Field::new(&long, DataType::Utf8),
+    ]);
+    assert_eq!(schema.get(&long), Some(&DataType::Utf8));
+    assert_eq!(schema.get("short"), Some(&DataType::Int32));
+}
@@ -52,7 +52,11 @@ from polars.dependencies import (
 from polars.dependencies import numpy as np
 from polars.dependencies import pandas as pd
 from polars.dependencies import pyarrow as pa
-from polars.exceptions import NoRowsReturnedError, TooManyRowsReturnedError
+from polars.exceptions import (
+    DuplicateError,
+    NoRowsReturnedError,
+    TooManyRowsReturnedError,
+)
 from polars.functions import col, lit
 from polars.io._utils import _is_glob_pattern, _is_local_file
 from polars.io.spreadsheet._write_utils import (
@@ -69,6 +73,7 @@ from polars.selectors import _expand_selector_dicts, _expand_selectors
 from polars.slice import PolarsSlice
 from polars.utils._construction import (
     _post_apply_columns,
+    arrow_c_stream_to_pydf,
     arrow_to_pydf,
     dict_to_pydf,
     iterable_to_pydf,
@@ -392,6 +397,11 @@ class DataFrame:
                 data, schema=schema, schema_overrides=schema_overrides
             )
 
+        elif hasattr(data, "__arrow_c_stream__"):
+            self._df = arrow_c_stream_to_pydf(
+                data, schema=schema, schema_overrides=schema_overrides
+            )
+
         elif not isinstance(data, Sized) and isinstance(data, (Generator, Iterable)):
             self._df = iterable_to_pydf(
                 data,
@@ -1317,6 +1327,23 @@ class DataFrame:
 
         return PolarsDataFrame(self, allow_copy=allow_copy)
 
+    def __arrow_c_stream__(self, requested_schema: object | None = None) -> object:
+        """
+        Export the DataFrame as an Arrow C stream, wrapped in a PyCapsule.
+
+        This implements the `Arrow PyCapsule interface
+        <https://arrow.apache.org/docs/format/CDataInterface/PyCapsuleInterface.html>`_,
+        which allows other libraries to consume the data without a copy. Every
+        chunk of the DataFrame is exported as a struct array.
+
+        Parameters
+        ----------
+        requested_schema
+            Ignored; the data is exported with its own schema.
+
+        """
+        return self._df.__arrow_c_stream__(requested_schema)
+
     def __dataframe_consortium_standard__(
         self, *, api_version: str | None = None
     ) -> Any:
@@ -6858,6 +6885,8 @@ class DataFrame:
         maintain_order: bool = True,
         sort_columns: bool = False,
         separator: str = "_",
+        margins: bool = False,
+        margins_name: str = "All",
     ) -> Self:
         """
         Create a spreadsheet-style pivot table as a DataFrame.
@@ -6889,6 +6918,15 @@ class DataFrame:
             Sort the transposed columns by name. Default is by order of discovery.
         separator
             Used as separator/delimiter in generated column names.
+        margins
+            Add a column with the total of every row and a row with the total of every
+            column. The totals are computed with the ``aggregate_function`` over the
+            original rows. The first index column is cast to ``Utf8`` to hold the name
+            of the margin row. Requires an ``aggregate_function``; the output order is
+            always maintained.
+        margins_name
+            Name of the margin row and column. It must not be a value of the first
+            index column or the name of a pivoted column.
 
         Returns
         -------
@@ -6914,6 +6952,26 @@ class DataFrame:
         │ two ┆ 3   ┆ 10  │
         └─────┴─────┴─────┘
 
+        Add the totals of the rows and columns:
+
+        >>> df.pivot(
+        ...     values="baz",
+        ...     index="foo",
+        ...     columns="bar",
+        ...     aggregate_function="sum",
+        ...     margins=True,
+        ... )
+        shape: (3, 4)
+        ┌─────┬─────┬─────┬─────┐
+        │ foo ┆ y   ┆ x   ┆ All │
+        │ --- ┆ --- ┆ --- ┆ --- │
+        │ str ┆ i64 ┆ i64 ┆ i64 │
+        ╞═════╪═════╪═════╪═════╡
+        │ one ┆ 3   ┆ 5   ┆ 8   │
+        │ two ┆ 3   ┆ 10  ┆ 13  │
+        │ All ┆ 6   ┆ 15  ┆ 21  │
+        └─────┴─────┴─────┴─────┘
+
         Pivot using selectors to determine the index/values/columns:
 
         >>> import polars.selectors as cs
@@ -7033,6 +7091,7 @@ class DataFrame:
                 sort_columns,
                 aggregate_expr,
                 separator,
+                margins_name if margins else None,
             )
         )
 
@@ -7981,6 +8040,29 @@ class DataFrame:
                 f"\n\nChoose one of {{'first', 'all'}}"
             )
 
+    def chunk_lengths(self) -> list[list[int]]:
+        """
+        Get the length of each individual chunk, for every column.
+
+        Columns whose chunk lengths differ are not aligned, which makes many
+        operations slower. Use :func:`rechunk` to fix this.
+
+        See Also
+        --------
+        n_chunks
+        rechunk
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 2, 3], "b": [4, 5, 6]})
+        >>> pl.concat([df, df], rechunk=False).chunk_lengths()
+        [[3, 3], [3, 3]]
+        >>> pl.concat([df, df]).chunk_lengths()
+        [[6], [6]]
+
+        """
+        return self._df.chunk_lengths()
+
     @overload
     def max(self, axis: Literal[0] = ...) -> Self:
         ...
@@ -8627,6 +8709,97 @@ class DataFrame:
         df = self.lazy().select(expr.n_unique()).collect(eager=True)
         return 0 if df.is_empty() else df.row(0)[0]
 
+    def value_counts(
+        self,
+        subset: str | Sequence[str] | None = None,
+        *,
+        sort: bool = False,
+        as_struct: str | None = None,
+        name: str = "counts",
+    ) -> DataFrame:
+        """
+        Count the occurrences of the unique rows, or of the unique row-subsets.
+
+        Parameters
+        ----------
+        subset
+            One or more columns that define what to count; omit to count the unique
+            rows.
+        sort
+            Sort the output by the counts in descending order.
+        as_struct
+            Pack the key columns into a single struct column of this name, e.g. to
+            join on the composite key downstream.
+        name
+            Name of the column with the counts; must differ from the names of the
+            key columns.
+
+        Returns
+        -------
+        DataFrame
+            The key columns (or the struct column) and a column with the counts.
+
+        Raises
+        ------
+        DuplicateError
+            If ``name`` is also the name of a key column.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "a": [1, 1, 2, 1, 3, 2],
+        ...         "b": ["x", "x", "y", "x", "z", "y"],
+        ...     }
+        ... )
+        >>> df.value_counts(sort=True)
+        shape: (3, 3)
+        ┌─────┬─────┬────────┐
+        │ a   ┆ b   ┆ counts │
+        │ --- ┆ --- ┆ ---    │
+        │ i64 ┆ str ┆ u32    │
+        ╞═════╪═════╪════════╡
+        │ 1   ┆ x   ┆ 3      │
+        │ 2   ┆ y   ┆ 2      │
+        │ 3   ┆ z   ┆ 1      │
+        └─────┴─────┴────────┘
+
+        Pack the key columns into a struct.
+
+        >>> df.value_counts(sort=True, as_struct="key")
+        shape: (3, 2)
+        ┌───────────┬────────┐
+        │ key       ┆ counts │
+        │ ---       ┆ ---    │
+        │ struct[2] ┆ u32    │
+        ╞═══════════╪════════╡
+        │ {1,"x"}   ┆ 3      │
+        │ {2,"y"}   ┆ 2      │
+        │ {3,"z"}   ┆ 1      │
+        └───────────┴────────┘
+
+        """
+        if subset is None:
+            columns = self.columns
+        elif isinstance(subset, str):
+            columns = [subset]
+        else:
+            columns = list(subset)
+
+        keys: list[str | Expr] = list(columns)
+        if as_struct is not None:
+            keys = [F.struct(columns).alias(as_struct)]
+        if name in (columns if as_struct is None else [as_struct]):
+            raise DuplicateError(
+                f"the counts column {name!r} has the name of a key column;"
+                " pass a different `name`"
+            )
+
+        df = self.lazy().group_by(keys).agg(F.count().alias(name))
+        if sort:
+            df = df.sort(name, descending=True)
+        return df.collect(eager=True)
+
     def approx_n_unique(self) -> DataFrame:
         """
         Approximate count of unique values.
@@ -9411,6 +9584,8 @@ class DataFrame:
         seed_1: int | None = None,
         seed_2: int | None = None,
         seed_3: int | None = None,
+        *,
+        stable: bool = False,
     ) -> Series:
         """
         Hash and combine the rows in this DataFrame.
@@ -9427,6 +9602,16 @@ class DataFrame:
             Random seed parameter. Defaults to `seed` if not set.
         seed_3
             Random seed parameter. Defaults to `seed` if not set.
+        stable
+            Use a hash that gives the same result in every process, on every platform
+            and in every version of polars, e.g. to partition rows for a distributed
+            shuffle. Values are hashed with XXH3-64 over a canonical encoding, so
+            integers of different widths hash the same. Only `seed` is used.
+
+        Notes
+        -----
+        The default hash may differ between platforms and versions of polars. Don't
+        persist it or compare it with hashes computed on other machines.
 
         Examples
         --------
@@ -9447,6 +9632,8 @@ class DataFrame:
         ]
 
         """
+        if stable:
+            return wrap_s(self._df.hash_rows_stable(seed))
         k0 = seed
         k1 = seed_1 if seed_1 is not None else seed
         k2 = seed_2 if seed_2 is not None else seed
@@ -2,9 +2,12 @@ from __future__ import annotations
 
 from typing import TYPE_CHECKING
 
+from polars.utils._parse_expr_input import parse_as_expression
 from polars.utils._wrap import wrap_expr
 
 if TYPE_CHECKING:
+    from datetime import date, datetime, time
+
     from polars import Expr
 
 
@@ -88,6 +91,220 @@ class ExprArrayNameSpace:
         """
         return wrap_expr(self._pyexpr.array_sum())
 
+    def mean(self) -> Expr:
+        """
+        Compute the mean values of the sub-arrays.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     data={"a": [[1, 2], [4, 3]]},
+        ...     schema={"a": pl.Array(width=2, inner=pl.Int64)},
+        ... )
+        >>> df.select(pl.col("a").arr.mean())
+        shape: (2, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ f64 │
+        ╞═════╡
+        │ 1.5 │
+        │ 3.5 │
+        └─────┘
+
+        """
+        return wrap_expr(self._pyexpr.array_mean())
+
+    def std(self, ddof: int = 1) -> Expr:
+        """
+        Compute the standard deviation of the sub-arrays.
+
+        Parameters
+        ----------
+        ddof
+            "Delta Degrees of Freedom": the divisor used in the calculation is N - ddof,
+            where N represents the number of elements.
+            By default ddof is 1.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     data={"a": [[1, 2], [4, 3]]},
+        ...     schema={"a": pl.Array(width=2, inner=pl.Int64)},
+        ... )
+        >>> df.select(pl.col("a").arr.std())
+        shape: (2, 1)
+        ┌──────────┐
+        │ a        │
+        │ ---      │
+        │ f64      │
+        ╞══════════╡
+        │ 0.707107 │
+        │ 0.707107 │
+        └──────────┘
+
+        """
+        return wrap_expr(self._pyexpr.array_std(ddof))
+
+    def any(self) -> Expr:
+        """
+        Evaluate whether any boolean value is true for every sub-array.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     data={"a": [[True, True], [False, True], [False, False]]},
+        ...     schema={"a": pl.Array(width=2, inner=pl.Boolean)},
+        ... )
+        >>> df.select(pl.col("a").arr.any())
+        shape: (3, 1)
+        ┌───────┐
+        │ a     │
+        │ ---   │
+        │ bool  │
+        ╞═══════╡
+        │ true  │
+        │ true  │
+        │ false │
+        └───────┘
+
+        """
+        return wrap_expr(self._pyexpr.array_any())
+
+    def all(self) -> Expr:
+        """
+        Evaluate whether all boolean values are true for every sub-array.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     data={"a": [[True, True], [False, True], [False, False]]},
+        ...     schema={"a": pl.Array(width=2, inner=pl.Boolean)},
+        ... )
+        >>> df.select(pl.col("a").arr.all())
+        shape: (3, 1)
+        ┌───────┐
+        │ a     │
+        │ ---   │
+        │ bool  │
+        ╞═══════╡
+        │ true  │
+        │ false │
+        │ false │
+        └───────┘
+
+        """
+        return wrap_expr(self._pyexpr.array_all())
+
+    def arg_min(self) -> Expr:
+        """
+        Retrieve the index of the minimal value in every sub-array.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     data={"a": [[1, 2], [4, 3]]},
+        ...     schema={"a": pl.Array(width=2, inner=pl.Int64)},
+        ... )
+        >>> df.select(pl.col("a").arr.arg_min())
+        shape: (2, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ u32 │
+        ╞═════╡
+        │ 0   │
+        │ 1   │
+        └─────┘
+
+        """
+        return wrap_expr(self._pyexpr.array_arg_min())
+
+    def arg_max(self) -> Expr:
+        """
+        Retrieve the index of the maximum value in every sub-array.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     data={"a": [[1, 2], [4, 3]]},
+        ...     schema={"a": pl.Array(width=2, inner=pl.Int64)},
+        ... )
+        >>> df.select(pl.col("a").arr.arg_max())
+        shape: (2, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ u32 │
+        ╞═════╡
+        │ 1   │
+        │ 0   │
+        └─────┘
+
+        """
+        return wrap_expr(self._pyexpr.array_arg_max())
+
+    def contains(
+        self, item: float | str | bool | int | date | datetime | time | Expr
+    ) -> Expr:
+        """
+        Check if sub-arrays contain the given item.
+
+        Parameters
+        ----------
+        item
+            Item that will be checked for membership
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Boolean`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     data={"a": [[1, 2], [4, 3]]},
+        ...     schema={"a": pl.Array(width=2, inner=pl.Int64)},
+        ... )
+        >>> df.select(pl.col("a").arr.contains(2))
+        shape: (2, 1)
+        ┌───────┐
+        │ a     │
+        │ ---   │
+        │ bool  │
+        ╞═══════╡
+        │ true  │
+        │ false │
+        └───────┘
+
+        """
+        item = parse_as_expression(item, str_as_lit=True)
+        return wrap_expr(self._pyexpr.array_contains(item))
+
+    def to_list(self) -> Expr:
+        """
+        Convert the sub-arrays to lists with the same values.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     data={"a": [[1, 2], [4, 3]]},
+        ...     schema={"a": pl.Array(width=2, inner=pl.Int64)},
+        ... )
+        >>> df.select(pl.col("a").arr.to_list())
+        shape: (2, 1)
+        ┌───────────┐
+        │ a         │
+        │ ---       │
+        │ list[i64] │
+        ╞═══════════╡
+        │ [1, 2]    │
+        │ [4, 3]    │
+        └───────────┘
+
+        """
+        return wrap_expr(self._pyexpr.array_to_list())
+
     def unique(self, *, maintain_order: bool = False) -> Expr:
         """
         Get the unique/distinct values in the array.
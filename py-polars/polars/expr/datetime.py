@@ -40,6 +40,9 @@ class ExprDateTimeNameSpace:
         Each date/datetime is mapped to the start of its bucket using the corresponding
         local datetime. Note that weekly buckets start on Monday.
 
+        Times are bucketed as if they were datetimes on the same day, so an ``every``
+        of a day or longer truncates them to midnight.
+
         Parameters
         ----------
         every
@@ -424,7 +427,7 @@ class ExprDateTimeNameSpace:
 
     def to_string(self, format: str) -> Expr:
         """
-        Convert a Date/Time/Datetime column into a Utf8 column with the given format.
+        Convert a temporal column into a Utf8 column with the given format.
 
         Similar to ``cast(pl.Utf8)``, but this method allows you to customize the
         formatting of the resulting string.
@@ -435,6 +438,9 @@ class ExprDateTimeNameSpace:
             Format to use, refer to the `chrono strftime documentation
             <https://docs.rs/chrono/latest/chrono/format/strftime/index.html>`_
             for specification. Example: ``"%y-%m-%d"``.
+            Duration columns accept ``"iso"``, for ISO 8601 durations such as
+            ``"P1DT2H3M4.5S"``, and ``"polars"``, for the format in which
+            durations are displayed.
 
         Examples
         --------
@@ -1790,6 +1796,9 @@ class ExprDateTimeNameSpace:
             Suffix with `"_saturating"` to indicate that dates too large for
             their month should saturate at the largest date
             (e.g. 2022-02-29 -> 2022-02-28) instead of erroring.
+            Suffix with `"_eom"` to additionally keep dates on the last day of
+            their month on the last day of the resulting month
+            (e.g. 2022-02-28 + 1mo -> 2022-03-31).
 
             By "calendar day", we mean the corresponding time on the next day (which may
             not be 24 hours, due to daylight savings). Similarly for "calendar week",
@@ -1798,7 +1807,8 @@ class ExprDateTimeNameSpace:
         Returns
         -------
         Expr
-            Expression of data type :class:`Date` or :class:`Datetime`.
+            Expression of data type :class:`Date`, :class:`Datetime` or :class:`Time`.
+            Times wrap around midnight.
 
         Examples
         --------
@@ -1851,6 +1861,54 @@ class ExprDateTimeNameSpace:
         by = parse_as_expression(by, str_as_lit=True)
         return wrap_expr(self._pyexpr.dt_offset_by(by))
 
+    def session_id(self, gap: str | timedelta) -> Expr:
+        """
+        Assign a session id to every value of a sorted temporal or integer column.
+
+        The ids start at zero and increment whenever the time since the previous
+        value is larger than ``gap``. To determine the sessions per group, use
+        this expression in a window, e.g. ``.over("user")``.
+
+        Parameters
+        ----------
+        gap
+            Maximum time between two values of the same session, e.g. ``"30m"``.
+            Calendar months, quarters and years are not supported. Use ``"3i"``
+            for a gap of 3 on an integer column.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`UInt32`.
+
+        Examples
+        --------
+        >>> from datetime import datetime
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "time": [
+        ...             datetime(2020, 1, 1, 10, 0),
+        ...             datetime(2020, 1, 1, 10, 20),
+        ...             datetime(2020, 1, 1, 11, 30),
+        ...             datetime(2020, 1, 1, 11, 45),
+        ...         ]
+        ...     }
+        ... )
+        >>> df.with_columns(session=pl.col("time").dt.session_id("30m"))
+        shape: (4, 2)
+        ┌─────────────────────┬─────────┐
+        │ time                ┆ session │
+        │ ---                 ┆ ---     │
+        │ datetime[μs]        ┆ u32     │
+        ╞═════════════════════╪═════════╡
+        │ 2020-01-01 10:00:00 ┆ 0       │
+        │ 2020-01-01 10:20:00 ┆ 0       │
+        │ 2020-01-01 11:30:00 ┆ 1       │
+        │ 2020-01-01 11:45:00 ┆ 1       │
+        └─────────────────────┴─────────┘
+        """
+        return wrap_expr(self._pyexpr.dt_session_id(_timedelta_to_pl_duration(gap)))
+
     def month_start(self) -> Expr:
         """
         Roll backward to the first day of the month.
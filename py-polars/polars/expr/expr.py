@@ -72,6 +72,7 @@ if TYPE_CHECKING:
 
     from polars import DataFrame, LazyFrame, Series
     from polars.type_aliases import (
+        CenterBias,
         ClosedInterval,
         FillNullStrategy,
         InterpolationMethod,
@@ -1655,7 +1656,7 @@ class Expr:
         """
         return self._from_pyexpr(self._pyexpr.cummax(reverse))
 
-    def cumcount(self, *, reverse: bool = False) -> Self:
+    def cumcount(self, *, reverse: bool = False, ignore_nulls: bool = False) -> Self:
         """
         Get an array with the cumulative count computed at every element.
 
@@ -1665,6 +1666,10 @@ class Expr:
         ----------
         reverse
             Reverse the operation.
+        ignore_nulls
+            Count the non-null values instead. Every element gets the number of
+            non-null values before it, so without nulls this is the same as the
+            default count.
 
         Examples
         --------
@@ -1687,8 +1692,62 @@ class Expr:
         │ 3   ┆ 0         │
         └─────┴───────────┘
 
+        Count the non-null values, per group:
+
+        >>> df = pl.DataFrame({"g": [1, 1, 2, 1, 2], "a": [1, None, 3, 4, None]})
+        >>> df.with_columns(
+        ...     pl.col("a").cumcount(ignore_nulls=True).over("g").alias("count")
+        ... )
+        shape: (5, 3)
+        ┌─────┬──────┬───────┐
+        │ g   ┆ a    ┆ count │
+        │ --- ┆ ---  ┆ ---   │
+        │ i64 ┆ i64  ┆ u32   │
+        ╞═════╪══════╪═══════╡
+        │ 1   ┆ 1    ┆ 0     │
+        │ 1   ┆ null ┆ 1     │
+        │ 2   ┆ 3    ┆ 0     │
+        │ 1   ┆ 4    ┆ 1     │
+        │ 2   ┆ null ┆ 1     │
+        └─────┴──────┴───────┘
+
+        """
+        return self._from_pyexpr(self._pyexpr.cumcount(reverse, ignore_nulls))
+
+    def cum_n_unique(self, *, reverse: bool = False) -> Self:
         """
-        return self._from_pyexpr(self._pyexpr.cumcount(reverse))
+        Get the number of distinct values up to and including every element.
+
+        Null is counted as a distinct value, like in :func:`n_unique`.
+
+        Parameters
+        ----------
+        reverse
+            Reverse the operation.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 2, 1, None, 3, 2]})
+        >>> df.with_columns(
+        ...     pl.col("a").cum_n_unique().alias("n_unique"),
+        ...     pl.col("a").cum_n_unique(reverse=True).alias("n_unique_reverse"),
+        ... )
+        shape: (6, 3)
+        ┌──────┬──────────┬──────────────────┐
+        │ a    ┆ n_unique ┆ n_unique_reverse │
+        │ ---  ┆ ---      ┆ ---              │
+        │ i64  ┆ u32      ┆ u32              │
+        ╞══════╪══════════╪══════════════════╡
+        │ 1    ┆ 1        ┆ 4                │
+        │ 2    ┆ 2        ┆ 4                │
+        │ 1    ┆ 2        ┆ 4                │
+        │ null ┆ 3        ┆ 3                │
+        │ 3    ┆ 4        ┆ 2                │
+        │ 2    ┆ 4        ┆ 1                │
+        └──────┴──────────┴──────────────────┘
+
+        """
+        return self._from_pyexpr(self._pyexpr.cum_n_unique(reverse))
 
     def floor(self) -> Self:
         """
@@ -2128,6 +2187,84 @@ class Expr:
         """
         return self._from_pyexpr(self._pyexpr.arg_min())
 
+    def max_by(self, by: IntoExpr) -> Self:
+        """
+        Get the value at the position of the maximal value of `by`.
+
+        In a group by context this is computed in a single pass over every group,
+        without sorting. Null values in `by` are ignored.
+
+        Parameters
+        ----------
+        by
+            Expression to find the maximum of. Accepts expression input. Strings are
+            parsed as column names.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "key": ["a", "b", "a", "b"],
+        ...         "price": [1.0, 2.0, 3.0, 4.0],
+        ...         "ts": [3, 1, 2, 4],
+        ...     }
+        ... )
+        >>> df.group_by("key", maintain_order=True).agg(
+        ...     pl.col("price").max_by("ts")
+        ... )
+        shape: (2, 2)
+        ┌─────┬───────┐
+        │ key ┆ price │
+        │ --- ┆ ---   │
+        │ str ┆ f64   │
+        ╞═════╪═══════╡
+        │ a   ┆ 1.0   │
+        │ b   ┆ 4.0   │
+        └─────┴───────┘
+
+        """
+        by = parse_as_expression(by)
+        return self._from_pyexpr(self._pyexpr.max_by(by))
+
+    def min_by(self, by: IntoExpr) -> Self:
+        """
+        Get the value at the position of the minimal value of `by`.
+
+        In a group by context this is computed in a single pass over every group,
+        without sorting. Null values in `by` are ignored.
+
+        Parameters
+        ----------
+        by
+            Expression to find the minimum of. Accepts expression input. Strings are
+            parsed as column names.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "key": ["a", "b", "a", "b"],
+        ...         "price": [1.0, 2.0, 3.0, 4.0],
+        ...         "ts": [3, 1, 2, 4],
+        ...     }
+        ... )
+        >>> df.group_by("key", maintain_order=True).agg(
+        ...     pl.col("price").min_by("ts")
+        ... )
+        shape: (2, 2)
+        ┌─────┬───────┐
+        │ key ┆ price │
+        │ --- ┆ ---   │
+        │ str ┆ f64   │
+        ╞═════╪═══════╡
+        │ a   ┆ 3.0   │
+        │ b   ┆ 2.0   │
+        └─────┴───────┘
+
+        """
+        by = parse_as_expression(by)
+        return self._from_pyexpr(self._pyexpr.min_by(by))
+
     def search_sorted(self, element: IntoExpr, side: SearchSortedSide = "any") -> Self:
         """
         Find indices where elements should be inserted to maintain order.
@@ -2550,6 +2687,31 @@ class Expr:
         fill_value = parse_as_expression(value, str_as_lit=True)
         return self._from_pyexpr(self._pyexpr.fill_nan(fill_value))
 
+    def nan_to_null(self) -> Self:
+        """
+        Replace floating point NaN values with null.
+
+        Comparisons treat NaN as unequal to every value, whereas sorts place it after
+        all other values. After this conversion NaN values behave like nulls in both.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1.0, None, float("nan")]})
+        >>> df.with_columns(pl.col("a").nan_to_null().alias("b"))
+        shape: (3, 2)
+        ┌──────┬──────┐
+        │ a    ┆ b    │
+        │ ---  ┆ ---  │
+        │ f64  ┆ f64  │
+        ╞══════╪══════╡
+        │ 1.0  ┆ 1.0  │
+        │ null ┆ null │
+        │ NaN  ┆ null │
+        └──────┴──────┘
+
+        """
+        return self._from_pyexpr(self._pyexpr.nan_to_null())
+
     def forward_fill(self, limit: int | None = None) -> Self:
         """
         Fill missing values with the latest seen values.
@@ -3744,6 +3906,34 @@ class Expr:
         """
         return self._from_pyexpr(self._pyexpr.rle())
 
+    def rle_decode(self) -> Self:
+        """
+        Expand the runs produced by :func:`rle` back into the original values.
+
+        The input must be a :class:`Struct` with the fields "lengths" and "values".
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(pl.Series("s", [1, 1, 2, 1, None, 1, 3, 3]))
+        >>> df.select(pl.col("s").rle().rle_decode())
+        shape: (8, 1)
+        ┌──────┐
+        │ s    │
+        │ ---  │
+        │ i64  │
+        ╞══════╡
+        │ 1    │
+        │ 1    │
+        │ 2    │
+        │ 1    │
+        │ null │
+        │ 1    │
+        │ 3    │
+        │ 3    │
+        └──────┘
+        """
+        return self._from_pyexpr(self._pyexpr.rle_decode())
+
     def rle_id(self) -> Self:
         """
         Map values to run IDs.
@@ -5451,6 +5641,7 @@ class Expr:
         min_periods: int | None = None,
         *,
         center: bool = False,
+        center_bias: CenterBias = "left",
         by: str | None = None,
         closed: ClosedInterval = "left",
     ) -> Self:
@@ -5513,6 +5704,11 @@ class Expr:
             a result. If None, it will be set equal to window size.
         center
             Set the labels at the center of the window
+        center_bias : {'left', 'right', 'split'}
+            Placement of a centered window of an even size. With 'left', the window
+            has one more value before the label than after it; with 'right', one more
+            value after it. 'split' averages both windows, so the values at both edges
+            count half; this is only supported by `rolling_mean` and `rolling_sum`.
         by
             If the `window_size` is temporal for instance `"5h"` or `"3s"`, you must
             set the column that will be used to determine the windows. This column must
@@ -5649,7 +5845,7 @@ class Expr:
         )
         return self._from_pyexpr(
             self._pyexpr.rolling_min(
-                window_size, weights, min_periods, center, by, closed
+                window_size, weights, min_periods, center, center_bias, by, closed
             )
         )
 
@@ -5661,6 +5857,7 @@ class Expr:
         min_periods: int | None = None,
         *,
         center: bool = False,
+        center_bias: CenterBias = "left",
         by: str | None = None,
         closed: ClosedInterval = "left",
     ) -> Self:
@@ -5723,6 +5920,11 @@ class Expr:
             a result. If None, it will be set equal to window size.
         center
             Set the labels at the center of the window
+        center_bias : {'left', 'right', 'split'}
+            Placement of a centered window of an even size. With 'left', the window
+            has one more value before the label than after it; with 'right', one more
+            value after it. 'split' averages both windows, so the values at both edges
+            count half; this is only supported by `rolling_mean` and `rolling_sum`.
         by
             If the `window_size` is temporal, for instance `"5h"` or `"3s"`, you must
             set the column that will be used to determine the windows. This column must
@@ -5882,7 +6084,7 @@ class Expr:
         )
         return self._from_pyexpr(
             self._pyexpr.rolling_max(
-                window_size, weights, min_periods, center, by, closed
+                window_size, weights, min_periods, center, center_bias, by, closed
             )
         )
 
@@ -5894,6 +6096,7 @@ class Expr:
         min_periods: int | None = None,
         *,
         center: bool = False,
+        center_bias: CenterBias = "left",
         by: str | None = None,
         closed: ClosedInterval = "left",
     ) -> Self:
@@ -5956,6 +6159,11 @@ class Expr:
             a result. If None, it will be set equal to window size.
         center
             Set the labels at the center of the window
+        center_bias : {'left', 'right', 'split'}
+            Placement of a centered window of an even size. With 'left', the window
+            has one more value before the label than after it; with 'right', one more
+            value after it. 'split' averages both windows, so the values at both edges
+            count half; this is only supported by `rolling_mean` and `rolling_sum`.
         by
             If the `window_size` is temporal for instance `"5h"` or `"3s"`, you must
             set the column that will be used to determine the windows. This column must
@@ -6119,7 +6327,7 @@ class Expr:
         )
         return self._from_pyexpr(
             self._pyexpr.rolling_mean(
-                window_size, weights, min_periods, center, by, closed
+                window_size, weights, min_periods, center, center_bias, by, closed
             )
         )
 
@@ -6131,6 +6339,7 @@ class Expr:
         min_periods: int | None = None,
         *,
         center: bool = False,
+        center_bias: CenterBias = "left",
         by: str | None = None,
         closed: ClosedInterval = "left",
     ) -> Self:
@@ -6193,6 +6402,11 @@ class Expr:
             a result. If None, it will be set equal to window size.
         center
             Set the labels at the center of the window
+        center_bias : {'left', 'right', 'split'}
+            Placement of a centered window of an even size. With 'left', the window
+            has one more value before the label than after it; with 'right', one more
+            value after it. 'split' averages both windows, so the values at both edges
+            count half; this is only supported by `rolling_mean` and `rolling_sum`.
         by
             If the `window_size` is temporal for instance `"5h"` or `"3s"`, you must
             set the column that will be used to determine the windows. This column must
@@ -6352,7 +6566,7 @@ class Expr:
         )
         return self._from_pyexpr(
             self._pyexpr.rolling_sum(
-                window_size, weights, min_periods, center, by, closed
+                window_size, weights, min_periods, center, center_bias, by, closed
             )
         )
 
@@ -6364,6 +6578,7 @@ class Expr:
         min_periods: int | None = None,
         *,
         center: bool = False,
+        center_bias: CenterBias = "left",
         by: str | None = None,
         closed: ClosedInterval = "left",
         ddof: int = 1,
@@ -6423,6 +6638,11 @@ class Expr:
             a result. If None, it will be set equal to window size.
         center
             Set the labels at the center of the window
+        center_bias : {'left', 'right', 'split'}
+            Placement of a centered window of an even size. With 'left', the window
+            has one more value before the label than after it; with 'right', one more
+            value after it. 'split' averages both windows, so the values at both edges
+            count half; this is only supported by `rolling_mean` and `rolling_sum`.
         by
             If the `window_size` is temporal for instance `"5h"` or `"3s"`, you must
             set the column that will be used to determine the windows. This column must
@@ -6588,7 +6808,7 @@ class Expr:
         )
         return self._from_pyexpr(
             self._pyexpr.rolling_std(
-                window_size, weights, min_periods, center, by, closed, ddof
+                window_size, weights, min_periods, center, center_bias, by, closed, ddof
             )
         )
 
@@ -6600,6 +6820,7 @@ class Expr:
         min_periods: int | None = None,
         *,
         center: bool = False,
+        center_bias: CenterBias = "left",
         by: str | None = None,
         closed: ClosedInterval = "left",
         ddof: int = 1,
@@ -6659,6 +6880,11 @@ class Expr:
             a result. If None, it will be set equal to window size.
         center
             Set the labels at the center of the window
+        center_bias : {'left', 'right', 'split'}
+            Placement of a centered window of an even size. With 'left', the window
+            has one more value before the label than after it; with 'right', one more
+            value after it. 'split' averages both windows, so the values at both edges
+            count half; this is only supported by `rolling_mean` and `rolling_sum`.
         by
             If the `window_size` is temporal for instance `"5h"` or `"3s"`, you must
             set the column that will be used to determine the windows. This column must
@@ -6828,6 +7054,7 @@ class Expr:
                 weights,
                 min_periods,
                 center,
+                center_bias,
                 by,
                 closed,
                 ddof,
@@ -6842,6 +7069,7 @@ class Expr:
         min_periods: int | None = None,
         *,
         center: bool = False,
+        center_bias: CenterBias = "left",
         by: str | None = None,
         closed: ClosedInterval = "left",
     ) -> Self:
@@ -6900,6 +7128,11 @@ class Expr:
             a result. If None, it will be set equal to window size.
         center
             Set the labels at the center of the window
+        center_bias : {'left', 'right', 'split'}
+            Placement of a centered window of an even size. With 'left', the window
+            has one more value before the label than after it; with 'right', one more
+            value after it. 'split' averages both windows, so the values at both edges
+            count half; this is only supported by `rolling_mean` and `rolling_sum`.
         by
             If the `window_size` is temporal for instance `"5h"` or `"3s"`, you must
             set the column that will be used to determine the windows. This column must
@@ -6989,7 +7222,7 @@ class Expr:
         )
         return self._from_pyexpr(
             self._pyexpr.rolling_median(
-                window_size, weights, min_periods, center, by, closed
+                window_size, weights, min_periods, center, center_bias, by, closed
             )
         )
 
@@ -7003,6 +7236,7 @@ class Expr:
         min_periods: int | None = None,
         *,
         center: bool = False,
+        center_bias: CenterBias = "left",
         by: str | None = None,
         closed: ClosedInterval = "left",
     ) -> Self:
@@ -7065,6 +7299,11 @@ class Expr:
             a result. If None, it will be set equal to window size.
         center
             Set the labels at the center of the window
+        center_bias : {'left', 'right', 'split'}
+            Placement of a centered window of an even size. With 'left', the window
+            has one more value before the label than after it; with 'right', one more
+            value after it. 'split' averages both windows, so the values at both edges
+            count half; this is only supported by `rolling_mean` and `rolling_sum`.
         by
             If the `window_size` is temporal for instance `"5h"` or `"3s"`, you must
             set the column that will be used to determine the windows. This column must
@@ -7188,6 +7427,7 @@ class Expr:
                 weights,
                 min_periods,
                 center,
+                center_bias,
                 by,
                 closed,
             )
@@ -7231,6 +7471,249 @@ class Expr:
         """
         return self._from_pyexpr(self._pyexpr.rolling_skew(window_size, bias))
 
+    def rolling_kurtosis(
+        self, window_size: int, *, fisher: bool = True, bias: bool = True
+    ) -> Self:
+        """
+        Compute a rolling kurtosis.
+
+        The window at a given row includes the row itself and the
+        `window_size - 1` elements before it. Null values in the window are
+        skipped.
+
+        Parameters
+        ----------
+        window_size
+            Integer size of the rolling window.
+        fisher
+            If True, Fisher's definition is used (normal ==> 0.0). If False,
+            Pearson's definition is used (normal ==> 3.0).
+        bias
+            If False, the calculations are corrected for statistical bias.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 4, 2, 9, 3]})
+        >>> df.select(pl.col("a").rolling_kurtosis(4))
+        shape: (5, 1)
+        ┌───────────┐
+        │ a         │
+        │ ---       │
+        │ f64       │
+        ╞═══════════╡
+        │ null      │
+        │ null      │
+        │ null      │
+        │ -1.0      │
+        │ -0.839477 │
+        └───────────┘
+
+        Note how the first value matches the following:
+
+        >>> pl.Series([1, 4, 2, 9]).kurtosis()
+        -1.0
+
+        """
+        return self._from_pyexpr(
+            self._pyexpr.rolling_kurtosis(window_size, fisher, bias)
+        )
+
+    def expanding_min(self, min_periods: int = 1) -> Self:
+        """
+        Compute an expanding minimum.
+
+        The window at a given row includes the row itself and all the rows
+        before it.
+
+        Parameters
+        ----------
+        min_periods
+            The number of values in the window that should be non-null before
+            computing a result.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 4, 2, 9]})
+        >>> df.select(pl.col("a").expanding_min(min_periods=2))
+        shape: (4, 1)
+        ┌──────┐
+        │ a    │
+        │ ---  │
+        │ i64  │
+        ╞══════╡
+        │ null │
+        │ 1    │
+        │ 1    │
+        │ 1    │
+        └──────┘
+
+        """
+        return self._from_pyexpr(self._pyexpr.expanding_min(min_periods))
+
+    def expanding_max(self, min_periods: int = 1) -> Self:
+        """
+        Compute an expanding maximum.
+
+        The window at a given row includes the row itself and all the rows
+        before it.
+
+        Parameters
+        ----------
+        min_periods
+            The number of values in the window that should be non-null before
+            computing a result.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 4, 2, 9]})
+        >>> df.select(pl.col("a").expanding_max(min_periods=2))
+        shape: (4, 1)
+        ┌──────┐
+        │ a    │
+        │ ---  │
+        │ i64  │
+        ╞══════╡
+        │ null │
+        │ 4    │
+        │ 4    │
+        │ 9    │
+        └──────┘
+
+        """
+        return self._from_pyexpr(self._pyexpr.expanding_max(min_periods))
+
+    def expanding_mean(self, min_periods: int = 1) -> Self:
+        """
+        Compute an expanding mean.
+
+        The window at a given row includes the row itself and all the rows
+        before it.
+
+        Parameters
+        ----------
+        min_periods
+            The number of values in the window that should be non-null before
+            computing a result.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 4, 2, 9]})
+        >>> df.select(pl.col("a").expanding_mean(min_periods=2))
+        shape: (4, 1)
+        ┌──────────┐
+        │ a        │
+        │ ---      │
+        │ f64      │
+        ╞══════════╡
+        │ null     │
+        │ 2.5      │
+        │ 2.333333 │
+        │ 4.0      │
+        └──────────┘
+
+        """
+        return self._from_pyexpr(self._pyexpr.expanding_mean(min_periods))
+
+    def expanding_sum(self, min_periods: int = 1) -> Self:
+        """
+        Compute an expanding sum.
+
+        The window at a given row includes the row itself and all the rows
+        before it.
+
+        Parameters
+        ----------
+        min_periods
+            The number of values in the window that should be non-null before
+            computing a result.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 4, 2, 9]})
+        >>> df.select(pl.col("a").expanding_sum(min_periods=2))
+        shape: (4, 1)
+        ┌──────┐
+        │ a    │
+        │ ---  │
+        │ i64  │
+        ╞══════╡
+        │ null │
+        │ 5    │
+        │ 7    │
+        │ 16   │
+        └──────┘
+
+        """
+        return self._from_pyexpr(self._pyexpr.expanding_sum(min_periods))
+
+    def expanding_var(self, min_periods: int = 1) -> Self:
+        """
+        Compute an expanding variance.
+
+        The window at a given row includes the row itself and all the rows
+        before it.
+
+        The variance is computed with one delta degree of freedom.
+
+        Parameters
+        ----------
+        min_periods
+            The number of values in the window that should be non-null before
+            computing a result.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 4, 2, 9]})
+        >>> df.select(pl.col("a").expanding_var(min_periods=2))
+        shape: (4, 1)
+        ┌───────────┐
+        │ a         │
+        │ ---       │
+        │ f64       │
+        ╞═══════════╡
+        │ null      │
+        │ 4.5       │
+        │ 2.333333  │
+        │ 12.666667 │
+        └───────────┘
+
+        """
+        return self._from_pyexpr(self._pyexpr.expanding_var(min_periods))
+
+    def expanding_std(self, min_periods: int = 1) -> Self:
+        """
+        Compute an expanding standard deviation.
+
+        The window at a given row includes the row itself and all the rows
+        before it.
+
+        The variance is computed with one delta degree of freedom.
+
+        Parameters
+        ----------
+        min_periods
+            The number of values in the window that should be non-null before
+            computing a result.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 4, 2, 9]})
+        >>> df.select(pl.col("a").expanding_std(min_periods=2))
+        shape: (4, 1)
+        ┌──────────┐
+        │ a        │
+        │ ---      │
+        │ f64      │
+        ╞══════════╡
+        │ null     │
+        │ 2.12132  │
+        │ 1.527525 │
+        │ 3.559026 │
+        └──────────┘
+
+        """
+        return self._from_pyexpr(self._pyexpr.expanding_std(min_periods))
+
     def rolling_map(
         self,
         function: Callable[[Series], Any],
@@ -8642,6 +9125,50 @@ class Expr:
         """
         return self._from_pyexpr(self._pyexpr.value_counts(sort, parallel))
 
+    def most_frequent(self, k: int, *, parallel: bool = False) -> Self:
+        """
+        Get the ``k`` most frequent values and their count.
+
+        This is cheaper than sorting the output of :func:`value_counts`, as only the
+        ``k`` most frequent values are kept while counting.
+
+        Parameters
+        ----------
+        k
+            Number of values to return.
+        parallel
+            Execute the computation in parallel.
+
+            .. note::
+                This option should likely not be enabled in a group by context,
+                as the computation is already parallelized per group.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Struct` with the ``k`` most frequent
+            values and their count, ordered by descending count. Values with the
+            same count are ordered by their first occurrence.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"color": ["red", "blue", "red", "green", "blue", "blue"]}
+        ... )
+        >>> df.select(pl.col("color").most_frequent(2))
+        shape: (2, 1)
+        ┌────────────┐
+        │ color      │
+        │ ---        │
+        │ struct[2]  │
+        ╞════════════╡
+        │ {"blue",3} │
+        │ {"red",2}  │
+        └────────────┘
+
+        """
+        return self._from_pyexpr(self._pyexpr.most_frequent(k, parallel))
+
     def unique_counts(self) -> Self:
         """
         Return a count of the unique values in the order of appearance.
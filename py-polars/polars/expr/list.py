@@ -812,6 +812,33 @@ class ExprListNameSpace:
         element = parse_as_expression(element, str_as_lit=True)
         return wrap_expr(self._pyexpr.list_count_matches(element))
 
+    def to_array(self, width: int) -> Expr:
+        """
+        Convert the sublists to arrays of a fixed width.
+
+        Parameters
+        ----------
+        width
+            Width of the resulting arrays. Every sublist must have exactly this
+            many items.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 2], [4, 3]]})
+        >>> df.select(pl.col("a").list.to_array(2))
+        shape: (2, 1)
+        ┌───────────────┐
+        │ a             │
+        │ ---           │
+        │ array[i64, 2] │
+        ╞═══════════════╡
+        │ [1, 2]        │
+        │ [4, 3]        │
+        └───────────────┘
+
+        """
+        return wrap_expr(self._pyexpr.list_to_array(width))
+
     def to_struct(
         self,
         n_field_strategy: ToStructStrategy = "first_non_null",
@@ -91,6 +91,7 @@ class ExprStringNameSpace:
         strict: bool = True,
         exact: bool = True,
         cache: bool = True,
+        infer: bool = False,
         use_earliest: bool | None = None,
         ambiguous: Ambiguous | Expr = "raise",
     ) -> Expr:
@@ -122,6 +123,10 @@ class ExprStringNameSpace:
                 data beforehand will almost certainly be more performant.
         cache
             Use a cache of unique, converted datetimes to apply the conversion.
+        infer
+            If no ``format`` is given, infer a single format from a sample of the
+            values and parse all values with it. This is much faster than inferring
+            the format of every value, but values in other formats fail to parse.
         use_earliest
             Determine how to deal with ambiguous datetimes:
 
@@ -161,6 +166,7 @@ class ExprStringNameSpace:
                 strict,
                 exact,
                 cache,
+                infer,
                 ambiguous._pyexpr,
             )
         )
@@ -203,6 +209,46 @@ class ExprStringNameSpace:
         _validate_format_argument(format)
         return wrap_expr(self._pyexpr.str_to_time(format, strict, cache))
 
+    def to_duration(
+        self,
+        format: str | None = None,
+        *,
+        time_unit: TimeUnit = "us",
+        strict: bool = True,
+    ) -> Expr:
+        """
+        Convert a Utf8 column into a Duration column.
+
+        Parameters
+        ----------
+        format
+            ``"polars"`` to accept human-readable durations such as ``"1h30m"``,
+            ``"iso"`` to accept ISO 8601 durations such as ``"PT1H30M"``. If set to
+            None (default), both are accepted. The units are those of the ``every``
+            and ``period`` arguments of the window functions, e.g. ``"1d2h"``.
+            Calendar units (months, quarters and years) don't have a fixed length
+            and are not accepted.
+        time_unit : {'us', 'ns', 'ms'}
+            Time unit of the resulting Duration column.
+        strict
+            Raise an error if any conversion fails. Durations that don't fit in
+            64-bit nanoseconds always raise an error.
+
+        Examples
+        --------
+        >>> s = pl.Series(["1h30m", "PT45M", "P1DT2H"])
+        >>> s.str.to_duration()
+        shape: (3,)
+        Series: '' [duration[μs]]
+        [
+                1h 30m
+                45m
+                1d 2h
+        ]
+
+        """
+        return wrap_expr(self._pyexpr.str_to_duration(format, time_unit, strict))
+
     def strptime(
         self,
         dtype: PolarsTemporalType,
@@ -434,7 +480,7 @@ class ExprStringNameSpace:
         """
         return wrap_expr(self._pyexpr.str_n_chars())
 
-    def concat(self, delimiter: str = "-") -> Expr:
+    def concat(self, delimiter: str = "-", *, ignore_nulls: bool = False) -> Expr:
         """
         Vertically concat the values in the Series to a single string value.
 
@@ -442,6 +488,9 @@ class ExprStringNameSpace:
         ----------
         delimiter
             The delimiter to insert between consecutive string values.
+        ignore_nulls
+            Skip null values. If set to ``False``, null values are written as
+            ``"null"``.
 
         Returns
         -------
@@ -460,9 +509,18 @@ class ExprStringNameSpace:
         ╞══════════╡
         │ 1-null-2 │
         └──────────┘
+        >>> df.select(pl.col("foo").str.concat("-", ignore_nulls=True))
+        shape: (1, 1)
+        ┌─────┐
+        │ foo │
+        │ --- │
+        │ str │
+        ╞═════╡
+        │ 1-2 │
+        └─────┘
 
         """
-        return wrap_expr(self._pyexpr.str_concat(delimiter))
+        return wrap_expr(self._pyexpr.str_concat(delimiter, ignore_nulls))
 
     def to_uppercase(self) -> Expr:
         """
@@ -1518,7 +1576,9 @@ class ExprStringNameSpace:
         pattern = parse_as_expression(pattern, str_as_lit=True)
         return wrap_expr(self._pyexpr.str_count_matches(pattern, literal))
 
-    def split(self, by: IntoExpr, *, inclusive: bool = False) -> Expr:
+    def split(
+        self, by: IntoExpr, *, inclusive: bool = False, literal: bool = True
+    ) -> Expr:
         """
         Split the string by a substring.
 
@@ -1528,6 +1588,9 @@ class ExprStringNameSpace:
             Substring to split by.
         inclusive
             If True, include the split character/string in the results.
+        literal
+            Treat ``by`` as a literal string. If set to False, ``by`` is interpreted
+            as a regular expression.
 
         Examples
         --------
@@ -1567,6 +1630,20 @@ class ExprStringNameSpace:
         │ foo*bar*baz ┆ *   ┆ ["foo", "bar", "baz"] ┆ ["foo*", "bar*", "baz"] │
         └─────────────┴─────┴───────────────────────┴─────────────────────────┘
 
+        Split on a regular expression:
+
+        >>> df = pl.DataFrame({"s": ["a1b22c", "d"]})
+        >>> df.select(pl.col("s").str.split(r"\d+", literal=False))
+        shape: (2, 1)
+        ┌─────────────────┐
+        │ s               │
+        │ ---             │
+        │ list[str]       │
+        ╞═════════════════╡
+        │ ["a", "b", "c"] │
+        │ ["d"]           │
+        └─────────────────┘
+
         Returns
         -------
         Expr
@@ -1574,6 +1651,10 @@ class ExprStringNameSpace:
 
         """
         by = parse_as_expression(by, str_as_lit=True)
+        if not literal:
+            if inclusive:
+                return wrap_expr(self._pyexpr.str_split_regex_inclusive(by))
+            return wrap_expr(self._pyexpr.str_split_regex(by))
         if inclusive:
             return wrap_expr(self._pyexpr.str_split_inclusive(by))
         return wrap_expr(self._pyexpr.str_split(by))
@@ -1649,7 +1730,7 @@ class ExprStringNameSpace:
             return wrap_expr(self._pyexpr.str_split_exact_inclusive(by, n))
         return wrap_expr(self._pyexpr.str_split_exact(by, n))
 
-    def splitn(self, by: IntoExpr, n: int) -> Expr:
+    def splitn(self, by: IntoExpr, n: int, *, literal: bool = True) -> Expr:
         """
         Split the string by a substring, restricted to returning at most ``n`` items.
 
@@ -1663,6 +1744,9 @@ class ExprStringNameSpace:
             Substring to split by.
         n
             Max number of items to return.
+        literal
+            Treat ``by`` as a literal string. If set to False, ``by`` is interpreted
+            as a regular expression.
 
         Returns
         -------
@@ -1711,6 +1795,8 @@ class ExprStringNameSpace:
 
         """
         by = parse_as_expression(by, str_as_lit=True)
+        if not literal:
+            return wrap_expr(self._pyexpr.str_splitn_regex(by, n))
         return wrap_expr(self._pyexpr.str_splitn(by, n))
 
     def replace(
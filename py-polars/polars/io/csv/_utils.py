@@ -4,6 +4,11 @@ from typing import TYPE_CHECKING, Sequence
 
 if TYPE_CHECKING:
     from polars import DataFrame
+    from polars.type_aliases import CsvEncoding
+
+# encodings that are transcoded by the csv reader itself; other encodings are
+# decoded in python before the data is handed to the csv reader
+NATIVE_ENCODINGS = frozenset(("utf8", "utf8-lossy", "latin1", "utf16-le", "utf16-be"))
 
 
 def _check_arg_is_1byte(
@@ -24,6 +29,13 @@ def _check_arg_is_1byte(
             )
 
 
+def _native_encoding(encoding: str) -> CsvEncoding:
+    """Return the encoding the csv reader should use for data in ``encoding``."""
+    if encoding in NATIVE_ENCODINGS:
+        return encoding  # type: ignore[return-value]
+    return "utf8"
+
+
 def _update_columns(df: DataFrame, new_columns: Sequence[str]) -> DataFrame:
     if df.width > len(new_columns):
         cols = df.columns
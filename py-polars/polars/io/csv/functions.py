@@ -6,7 +6,12 @@ from typing import TYPE_CHECKING, Any, BinaryIO, Callable, Mapping, Sequence, Te
 import polars._reexport as pl
 from polars.datatypes import N_INFER_DEFAULT, Utf8
 from polars.io._utils import _prepare_file_arg
-from polars.io.csv._utils import _check_arg_is_1byte, _update_columns
+from polars.io.csv._utils import (
+    NATIVE_ENCODINGS,
+    _check_arg_is_1byte,
+    _native_encoding,
+    _update_columns,
+)
 from polars.io.csv.batched_reader import BatchedCsvReader
 from polars.utils.various import handle_projection_columns, normalize_filepath
 
@@ -127,11 +132,11 @@ def read_csv(
         Stop reading from CSV file after reading ``n_rows``.
         During multi-threaded parsing, an upper bound of ``n_rows``
         rows cannot be guaranteed.
-    encoding : {'utf8', 'utf8-lossy', ...}
+    encoding : {'utf8', 'utf8-lossy', 'latin1', 'utf16-le', 'utf16-be', ...}
         Lossy means that invalid utf8 values are replaced with ``�``
-        characters. When using other encodings than ``utf8`` or
-        ``utf8-lossy``, the input is first decoded in memory with
-        python. Defaults to ``utf8``.
+        characters. ``latin1``, ``utf16-le`` and ``utf16-be`` are transcoded to
+        utf8 by the csv reader. When using other encodings, the input is first
+        decoded in memory with python. Defaults to ``utf8``.
     low_memory
         Reduce memory usage at expense of performance.
     rechunk
@@ -356,7 +361,7 @@ def read_csv(
 
     with _prepare_file_arg(
         source,
-        encoding=encoding,
+        encoding=None if encoding in NATIVE_ENCODINGS else encoding,
         use_pyarrow=False,
         raise_if_empty=raise_if_empty,
         **storage_options,
@@ -379,7 +384,7 @@ def read_csv(
             infer_schema_length=infer_schema_length,
             batch_size=batch_size,
             n_rows=n_rows,
-            encoding=encoding if encoding == "utf8-lossy" else "utf8",
+            encoding=_native_encoding(encoding),
             low_memory=low_memory,
             rechunk=rechunk,
             skip_rows_after_header=skip_rows_after_header,
@@ -496,11 +501,11 @@ def read_csv_batched(
         Stop reading from CSV file after reading ``n_rows``.
         During multi-threaded parsing, an upper bound of ``n_rows``
         rows cannot be guaranteed.
-    encoding : {'utf8', 'utf8-lossy', ...}
+    encoding : {'utf8', 'utf8-lossy', 'latin1', 'utf16-le', 'utf16-be', ...}
         Lossy means that invalid utf8 values are replaced with ``�``
-        characters. When using other encodings than ``utf8`` or
-        ``utf8-lossy``, the input is first decoded in memory with
-        python. Defaults to ``utf8``.
+        characters. ``latin1``, ``utf16-le`` and ``utf16-be`` are transcoded to
+        utf8 by the csv reader. When using other encodings, the input is first
+        decoded in memory with python. Defaults to ``utf8``.
     low_memory
         Reduce memory usage at expense of performance.
     rechunk
@@ -675,7 +680,7 @@ def read_csv_batched(
         infer_schema_length=infer_schema_length,
         batch_size=batch_size,
         n_rows=n_rows,
-        encoding=encoding if encoding == "utf8-lossy" else "utf8",
+        encoding=_native_encoding(encoding),
         low_memory=low_memory,
         rechunk=rechunk,
         skip_rows_after_header=skip_rows_after_header,
@@ -692,6 +697,7 @@ def scan_csv(
     source: str | Path,
     *,
     has_header: bool = True,
+    columns: Sequence[int] | Sequence[str] | None = None,
     separator: str = ",",
     comment_char: str | None = None,
     quote_char: str | None = r'"',
@@ -716,6 +722,8 @@ def scan_csv(
     new_columns: Sequence[str] | None = None,
     raise_if_empty: bool = True,
     truncate_ragged_lines: bool = False,
+    storage_options: dict[str, Any] | None = None,
+    retries: int = 0,
 ) -> LazyFrame:
     """
     Lazily read from a CSV file or multiple files via glob patterns.
@@ -733,6 +741,10 @@ def scan_csv(
         If set to False, column names will be autogenerated in the
         following format: ``column_x``, with ``x`` being an
         enumeration over every column in the dataset starting at 1.
+    columns
+        Columns to select. Accepts a list of column indices (starting at zero) or a
+        list of column names. Column names refer to the names after ``new_columns``
+        or ``with_column_names`` are applied.
     separator
         Single byte character to use as delimiter in the file.
     comment_char
@@ -777,9 +789,10 @@ def scan_csv(
         If set to ``None``, a full table scan will be done (slow).
     n_rows
         Stop reading from CSV file after reading ``n_rows``.
-    encoding : {'utf8', 'utf8-lossy'}
+    encoding : {'utf8', 'utf8-lossy', 'latin1', 'utf16-le', 'utf16-be'}
         Lossy means that invalid utf8 values are replaced with ``�``
-        characters. Defaults to "utf8".
+        characters. The other encodings are transcoded to utf8 before the file
+        is parsed. Defaults to "utf8".
     low_memory
         Reduce memory usage in expense of performance.
     rechunk
@@ -806,6 +819,21 @@ def scan_csv(
         is set to False, an empty LazyFrame (with no columns) is returned instead.
     truncate_ragged_lines
         Truncate lines that are longer than the schema.
+    storage_options
+        Options that inform use how to connect to the cloud provider when ``source``
+        is a cloud url, e.g. ``s3://bucket/file.csv``.
+        Currently supported providers are: {'aws', 'gcp', 'azure' }.
+        See supported keys here:
+
+        * `aws <https://docs.rs/object_store/0.7.0/object_store/aws/enum.AmazonS3ConfigKey.html>`_
+        * `gcp <https://docs.rs/object_store/0.7.0/object_store/gcp/enum.GoogleConfigKey.html>`_
+        * `azure <https://docs.rs/object_store/0.7.0/object_store/azure/enum.AzureConfigKey.html>`_
+
+        If ``storage_options`` are not provided we will try to infer them from the
+        environment variables. A cloud file is downloaded as a whole when its schema
+        is inferred.
+    retries
+        Number of retries if accessing a cloud instance fails.
 
     Returns
     -------
@@ -895,7 +923,9 @@ def scan_csv(
     if isinstance(source, (str, Path)):
         source = normalize_filepath(source)
 
-    return pl.LazyFrame._scan_csv(
+    projection, columns = handle_projection_columns(columns)
+
+    lf = pl.LazyFrame._scan_csv(
         source,
         has_header=has_header,
         separator=separator,
@@ -921,4 +951,12 @@ def scan_csv(
         eol_char=eol_char,
         raise_if_empty=raise_if_empty,
         truncate_ragged_lines=truncate_ragged_lines,
+        projection=projection,
+        storage_options=storage_options,
+        retries=retries,
     )
+    if columns:
+        if row_count_name is not None:
+            columns = [row_count_name, *columns]
+        lf = lf.select(columns)
+    return lf
@@ -337,6 +337,9 @@ class LazyFrame:
         eol_char: str = "\n",
         raise_if_empty: bool = True,
         truncate_ragged_lines: bool = True,
+        projection: list[int] | None = None,
+        storage_options: dict[str, object] | None = None,
+        retries: int = 0,
     ) -> Self:
         """
         Lazily read from a CSV file or multiple files via glob patterns.
@@ -354,6 +357,8 @@ class LazyFrame:
             for k, v in dtypes.items():
                 dtype_list.append((k, py_type_to_dtype(v)))
         processed_null_values = _process_null_values(null_values)
+        if storage_options is not None:
+            storage_options = list(storage_options.items())  #  type: ignore[assignment]
 
         self = cls.__new__(cls)
         self._ldf = PyLazyFrame.new_from_csv(
@@ -381,6 +386,9 @@ class LazyFrame:
             raise_if_empty=raise_if_empty,
             truncate_ragged_lines=truncate_ragged_lines,
             schema=schema,
+            projection=projection,
+            cloud_options=storage_options,
+            retries=retries,
         )
         return self
 
@@ -1631,6 +1639,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         comm_subplan_elim: bool = True,
         comm_subexpr_elim: bool = True,
         streaming: bool = False,
+        streaming_memory_limit: int | None = None,
         **kwargs: Any,
     ) -> DataFrame:
         """
@@ -1659,6 +1668,10 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             Common subexpressions will be cached and reused.
         streaming
             Run parts of the query in a streaming fashion (this is in an alpha state)
+        streaming_memory_limit
+            The number of bytes a streaming operation may hold in memory before it
+            spills to disk. If None (default), this is bounded by the free memory of
+            the system.
         **kwargs
             For internal use.
 
@@ -1710,6 +1723,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             streaming,
             eager,
         )
+        if streaming_memory_limit is not None:
+            ldf = ldf.with_streaming_memory_limit(streaming_memory_limit)
         return wrap_df(ldf.collect())
 
     @overload
@@ -1886,6 +1901,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         simplify_expression: bool = True,
         no_optimization: bool = False,
         slice_pushdown: bool = True,
+        memory_limit: int | None = None,
     ) -> DataFrame:
         """
         Persists a LazyFrame at the provided path.
@@ -1933,6 +1949,10 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             Turn off (certain) optimizations.
         slice_pushdown
             Slice pushdown optimization.
+        memory_limit
+            The number of bytes an operation of this query may hold in memory before
+            it spills to disk. If None (default), this is bounded by the free memory
+            of the system.
 
         Returns
         -------
@@ -1951,6 +1971,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             simplify_expression=simplify_expression,
             no_optimization=no_optimization,
             slice_pushdown=slice_pushdown,
+            memory_limit=memory_limit,
         )
 
         return lf.sink_parquet(
@@ -1975,6 +1996,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         simplify_expression: bool = True,
         no_optimization: bool = False,
         slice_pushdown: bool = True,
+        memory_limit: int | None = None,
     ) -> DataFrame:
         """
         Persists a LazyFrame at the provided path.
@@ -2003,6 +2025,10 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             Turn off (certain) optimizations.
         slice_pushdown
             Slice pushdown optimization.
+        memory_limit
+            The number of bytes an operation of this query may hold in memory before
+            it spills to disk. If None (default), this is bounded by the free memory
+            of the system.
 
         Returns
         -------
@@ -2021,6 +2047,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             simplify_expression=simplify_expression,
             no_optimization=no_optimization,
             slice_pushdown=slice_pushdown,
+            memory_limit=memory_limit,
         )
 
         return lf.sink_ipc(
@@ -2051,6 +2078,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         simplify_expression: bool = True,
         no_optimization: bool = False,
         slice_pushdown: bool = True,
+        memory_limit: int | None = None,
     ) -> DataFrame:
         """
         Persists a LazyFrame at the provided path.
@@ -2120,6 +2148,10 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             Turn off (certain) optimizations.
         slice_pushdown
             Slice pushdown optimization.
+        memory_limit
+            The number of bytes an operation of this query may hold in memory before
+            it spills to disk. If None (default), this is bounded by the free memory
+            of the system.
 
         Returns
         -------
@@ -2145,6 +2177,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             simplify_expression=simplify_expression,
             no_optimization=no_optimization,
             slice_pushdown=slice_pushdown,
+            memory_limit=memory_limit,
         )
 
         return lf.sink_csv(
@@ -2172,13 +2205,14 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         simplify_expression: bool = True,
         no_optimization: bool = False,
         slice_pushdown: bool = True,
+        memory_limit: int | None = None,
     ) -> PyLazyFrame:
         if no_optimization:
             predicate_pushdown = False
             projection_pushdown = False
             slice_pushdown = False
 
-        return self._ldf.optimization_toggle(
+        lf = self._ldf.optimization_toggle(
             type_coercion,
             predicate_pushdown,
             projection_pushdown,
@@ -2189,6 +2223,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             streaming=True,
             eager=False,
         )
+        if memory_limit is not None:
+            lf = lf.with_streaming_memory_limit(memory_limit)
+        return lf
 
     @deprecate_renamed_parameter(
         "common_subplan_elimination", "comm_subplan_elim", version="0.18.9"
@@ -2929,6 +2966,79 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         )
         return LazyGroupBy(lgb)
 
+    def group_by_session(
+        self,
+        index_column: IntoExpr,
+        *,
+        gap: str | timedelta,
+        by: IntoExpr | Iterable[IntoExpr] | None = None,
+        session_column: str = "session_id",
+    ) -> LazyGroupBy:
+        """
+        Group into sessions based on a time, Int32, or Int64 column.
+
+        A new session starts whenever the time since the previous row is larger
+        than ``gap``. The sessions are determined per group of the ``by`` keys and
+        the index column must be sorted within these groups.
+
+        The groups are keyed by ``by`` and a ``session_column`` column that numbers
+        the sessions of every ``by`` group, starting at zero. The order of the
+        groups is the order in which they first appear.
+
+        Parameters
+        ----------
+        index_column
+            Column used to group based on the time window.
+            Often of type Date/Datetime.
+            This column must be sorted in ascending order (or, if `by` is specified,
+            then it must be sorted in ascending order within each group).
+        gap
+            Maximum time between two rows of the same session, e.g. ``"30m"``.
+            Calendar months, quarters and years are not supported. Use ``"3i"``
+            for a gap of 3 on an integer column.
+        by
+            Also group by this column/these columns.
+        session_column
+            Name of the column that holds the session numbers. A column of this name
+            in the input is replaced, so choose a name that isn't in use.
+
+        Returns
+        -------
+        LazyGroupBy
+            Object you can call ``.agg`` on to aggregate by groups.
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame(
+        ...     {
+        ...         "user": ["a", "b", "a", "b", "a"],
+        ...         "t": [1, 2, 3, 10, 10],
+        ...         "clicks": [1, 2, 3, 4, 5],
+        ...     }
+        ... )
+        >>> lf.group_by_session("t", gap="3i", by="user").agg(
+        ...     pl.col("clicks").sum()
+        ... ).collect()
+        shape: (4, 3)
+        ┌──────┬────────────┬────────┐
+        │ user ┆ session_id ┆ clicks │
+        │ ---  ┆ ---        ┆ ---    │
+        │ str  ┆ u32        ┆ i64    │
+        ╞══════╪════════════╪════════╡
+        │ a    ┆ 0          ┆ 4      │
+        │ b    ┆ 0          ┆ 2      │
+        │ b    ┆ 1          ┆ 4      │
+        │ a    ┆ 1          ┆ 5      │
+        └──────┴────────────┴────────┘
+
+        """
+        index_column = parse_as_expression(index_column)
+        pyexprs_by = parse_as_list_of_expressions(by) if by is not None else []
+        gap = _timedelta_to_pl_duration(gap)
+
+        lgb = self._ldf.group_by_session(index_column, gap, pyexprs_by, session_column)
+        return LazyGroupBy(lgb)
+
     def group_by_dynamic(
         self,
         index_column: IntoExpr,
@@ -5,7 +5,9 @@ from typing import TYPE_CHECKING
 from polars.series.utils import expr_dispatch
 
 if TYPE_CHECKING:
-    from polars import Series
+    from datetime import date, datetime, time
+
+    from polars import Expr, Series
     from polars.polars import PySeries
 
 
@@ -79,6 +81,183 @@ class ArrayNameSpace:
 
         """
 
+    def mean(self) -> Series:
+        """
+        Compute the mean values of the sub-arrays.
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "a", [[1, 2], [4, 3]], dtype=pl.Array(width=2, inner=pl.Int64)
+        ... )
+        >>> s.arr.mean()
+        shape: (2,)
+        Series: 'a' [f64]
+        [
+            1.5
+            3.5
+        ]
+
+        """
+
+    def std(self, ddof: int = 1) -> Series:
+        """
+        Compute the standard deviation of the sub-arrays.
+
+        Parameters
+        ----------
+        ddof
+            "Delta Degrees of Freedom": the divisor used in the calculation is N - ddof,
+            where N represents the number of elements.
+            By default ddof is 1.
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "a", [[1, 2], [4, 3]], dtype=pl.Array(width=2, inner=pl.Int64)
+        ... )
+        >>> s.arr.std()
+        shape: (2,)
+        Series: 'a' [f64]
+        [
+            0.707107
+            0.707107
+        ]
+
+        """
+
+    def any(self) -> Series:
+        """
+        Evaluate whether any boolean value is true for every sub-array.
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "a",
+        ...     [[True, True], [False, True], [False, False]],
+        ...     dtype=pl.Array(width=2, inner=pl.Boolean),
+        ... )
+        >>> s.arr.any()
+        shape: (3,)
+        Series: 'a' [bool]
+        [
+            true
+            true
+            false
+        ]
+
+        """
+
+    def all(self) -> Series:
+        """
+        Evaluate whether all boolean values are true for every sub-array.
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "a",
+        ...     [[True, True], [False, True], [False, False]],
+        ...     dtype=pl.Array(width=2, inner=pl.Boolean),
+        ... )
+        >>> s.arr.all()
+        shape: (3,)
+        Series: 'a' [bool]
+        [
+            true
+            false
+            false
+        ]
+
+        """
+
+    def arg_min(self) -> Series:
+        """
+        Retrieve the index of the minimal value in every sub-array.
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "a", [[1, 2], [4, 3]], dtype=pl.Array(width=2, inner=pl.Int64)
+        ... )
+        >>> s.arr.arg_min()
+        shape: (2,)
+        Series: 'a' [u32]
+        [
+            0
+            1
+        ]
+
+        """
+
+    def arg_max(self) -> Series:
+        """
+        Retrieve the index of the maximum value in every sub-array.
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "a", [[1, 2], [4, 3]], dtype=pl.Array(width=2, inner=pl.Int64)
+        ... )
+        >>> s.arr.arg_max()
+        shape: (2,)
+        Series: 'a' [u32]
+        [
+            1
+            0
+        ]
+
+        """
+
+    def contains(
+        self, item: float | str | bool | int | date | datetime | time | Expr
+    ) -> Series:
+        """
+        Check if sub-arrays contain the given item.
+
+        Parameters
+        ----------
+        item
+            Item that will be checked for membership
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Boolean`.
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "a", [[1, 2], [4, 3]], dtype=pl.Array(width=2, inner=pl.Int64)
+        ... )
+        >>> s.arr.contains(2)
+        shape: (2,)
+        Series: 'a' [bool]
+        [
+            true
+            false
+        ]
+
+        """
+
+    def to_list(self) -> Series:
+        """
+        Convert the sub-arrays to lists with the same values.
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "a", [[1, 2], [4, 3]], dtype=pl.Array(width=2, inner=pl.Int64)
+        ... )
+        >>> s.arr.to_list()
+        shape: (2,)
+        Series: 'a' [list[i64]]
+        [
+            [1, 2]
+            [4, 3]
+        ]
+
+        """
+
     def unique(self, *, maintain_order: bool = False) -> Series:
         """
         Get the unique/distinct values in the array.
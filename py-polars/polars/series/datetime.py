@@ -112,7 +112,7 @@ class DateTimeNameSpace:
 
     def to_string(self, format: str) -> Series:
         """
-        Convert a Date/Time/Datetime column into a Utf8 column with the given format.
+        Convert a temporal column into a Utf8 column with the given format.
 
         Similar to ``cast(pl.Utf8)``, but this method allows you to customize the
         formatting of the resulting string.
@@ -123,6 +123,9 @@ class DateTimeNameSpace:
             Format to use, refer to the `chrono strftime documentation
             <https://docs.rs/chrono/latest/chrono/format/strftime/index.html>`_
             for specification. Example: ``"%y-%m-%d"``.
+            Duration columns accept ``"iso"``, for ISO 8601 durations such as
+            ``"P1DT2H3M4.5S"``, and ``"polars"``, for the format in which
+            durations are displayed.
 
         Examples
         --------
@@ -1381,6 +1384,9 @@ class DateTimeNameSpace:
             Suffix with `"_saturating"` to indicate that dates too large for
             their month should saturate at the largest date
             (e.g. 2022-02-29 -> 2022-02-28) instead of erroring.
+            Suffix with `"_eom"` to additionally keep dates on the last day of
+            their month on the last day of the resulting month
+            (e.g. 2022-02-28 + 1mo -> 2022-03-31).
 
             By "calendar day", we mean the corresponding time on the next day
             (which may not be 24 hours, due to daylight savings). Similarly for
@@ -1390,7 +1396,8 @@ class DateTimeNameSpace:
         Returns
         -------
         Series
-            Series of data type :class:`Date` or :class:`Datetime`.
+            Series of data type :class:`Date`, :class:`Datetime` or :class:`Time`.
+            Times wrap around midnight.
 
         Examples
         --------
@@ -1501,7 +1508,7 @@ class DateTimeNameSpace:
         Returns
         -------
         Series
-            Series of data type :class:`Date` or :class:`Datetime`.
+            Series of data type :class:`Date`, :class:`Datetime` or :class:`Time`.
 
         Examples
         --------
@@ -1764,6 +1771,46 @@ class DateTimeNameSpace:
 
         """
 
+    def session_id(self, gap: str | dt.timedelta) -> Series:
+        """
+        Assign a session id to every value of a sorted temporal or integer Series.
+
+        The ids start at zero and increment whenever the time since the previous
+        value is larger than ``gap``.
+
+        Parameters
+        ----------
+        gap
+            Maximum time between two values of the same session, e.g. ``"30m"``.
+            Calendar months, quarters and years are not supported. Use ``"3i"``
+            for a gap of 3 on an integer Series.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`UInt32`.
+
+        Examples
+        --------
+        >>> from datetime import datetime
+        >>> s = pl.Series(
+        ...     "time",
+        ...     [
+        ...         datetime(2020, 1, 1, 10, 0),
+        ...         datetime(2020, 1, 1, 10, 20),
+        ...         datetime(2020, 1, 1, 11, 30),
+        ...     ],
+        ... )
+        >>> s.dt.session_id("30m")
+        shape: (3,)
+        Series: 'time' [u32]
+        [
+                0
+                0
+                1
+        ]
+        """
+
     def month_start(self) -> Series:
         """
         Roll backward to the first day of the month.
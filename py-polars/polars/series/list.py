@@ -472,6 +472,29 @@ class ListNameSpace:
 
         """
 
+    def to_array(self, width: int) -> Series:
+        """
+        Convert the sublists to arrays of a fixed width.
+
+        Parameters
+        ----------
+        width
+            Width of the resulting arrays. Every sublist must have exactly this
+            many items.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1, 2], [4, 3]])
+        >>> s.list.to_array(2)
+        shape: (2,)
+        Series: 'a' [array[i64, 2]]
+        [
+            [1, 2]
+            [4, 3]
+        ]
+
+        """
+
     def to_struct(
         self,
         n_field_strategy: ToStructStrategy = "first_non_null",
@@ -116,6 +116,7 @@ if TYPE_CHECKING:
     from polars import DataFrame, Expr
     from polars.series._numpy import SeriesView
     from polars.type_aliases import (
+        CenterBias,
         ClosedInterval,
         ComparisonOperator,
         FillNullStrategy,
@@ -316,6 +317,11 @@ class Series:
         ):
             self._s = pandas_to_pyseries(name, values)
 
+        elif hasattr(values, "__arrow_c_stream__"):
+            self._s = PySeries.from_arrow_c_stream(name, values)
+            if dtype is not None:
+                self._s = self.cast(dtype, strict=True)._s
+
         elif _is_generator(values):
             self._s = iterable_to_pyseries(
                 name,
@@ -1108,6 +1114,22 @@ class Series:
         else:
             return self.to_numpy().__array__()
 
+    def __arrow_c_stream__(self, requested_schema: object | None = None) -> object:
+        """
+        Export the Series as an Arrow C stream, wrapped in a PyCapsule.
+
+        This implements the `Arrow PyCapsule interface
+        <https://arrow.apache.org/docs/format/CDataInterface/PyCapsuleInterface.html>`_,
+        which allows other libraries to consume the data without a copy.
+
+        Parameters
+        ----------
+        requested_schema
+            Ignored; the data is exported with its own data type.
+
+        """
+        return self._s.__arrow_c_stream__(requested_schema)
+
     def __array_ufunc__(
         self, ufunc: np.ufunc, method: str, *inputs: Any, **kwargs: Any
     ) -> Series:
@@ -2263,6 +2285,26 @@ class Series:
         └─────────┴────────┘
         """
 
+    def rle_decode(self) -> Series:
+        """
+        Expand the runs produced by :func:`rle` back into the original values.
+
+        The Series must be a :class:`Struct` with the fields "lengths" and "values".
+
+        Examples
+        --------
+        >>> s = pl.Series("s", [1, 1, 2, None])
+        >>> s.rle().rle_decode()
+        shape: (4,)
+        Series: 's' [i64]
+        [
+            1
+            1
+            2
+            null
+        ]
+        """
+
     def rle_id(self) -> Series:
         """
         Map values to run IDs.
@@ -2402,6 +2444,47 @@ class Series:
             .unnest(self.name)
         )
 
+    def most_frequent(self, k: int, *, parallel: bool = False) -> DataFrame:
+        """
+        Get the ``k`` most frequent values and their count.
+
+        This is cheaper than sorting the output of :func:`value_counts`, as only the
+        ``k`` most frequent values are kept while counting.
+
+        Parameters
+        ----------
+        k
+            Number of values to return.
+        parallel
+            Execute the computation in parallel.
+
+        Returns
+        -------
+        DataFrame
+            The ``k`` most frequent values and their count, ordered by descending
+            count. Values with the same count are ordered by their first occurrence.
+
+        Examples
+        --------
+        >>> s = pl.Series("color", ["red", "blue", "red", "green", "blue", "blue"])
+        >>> s.most_frequent(2)
+        shape: (2, 2)
+        ┌───────┬────────┐
+        │ color ┆ counts │
+        │ ---   ┆ ---    │
+        │ str   ┆ u32    │
+        ╞═══════╪════════╡
+        │ blue  ┆ 3      │
+        │ red   ┆ 2      │
+        └───────┴────────┘
+
+        """
+        return (
+            self.to_frame()
+            .select(F.col(self.name).most_frequent(k, parallel=parallel))
+            .unnest(self.name)
+        )
+
     def unique_counts(self) -> Series:
         """
         Return a count of the unique values in the order of appearance.
@@ -2691,6 +2774,32 @@ class Series:
 
         """
 
+    def cum_n_unique(self, *, reverse: bool = False) -> Series:
+        """
+        Get the number of distinct values up to and including every element.
+
+        Null is counted as a distinct value, like in :func:`n_unique`.
+
+        Parameters
+        ----------
+        reverse
+            reverse the operation.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [1, 2, 1, 3])
+        >>> s.cum_n_unique()
+        shape: (4,)
+        Series: 'a' [u32]
+        [
+            1
+            2
+            2
+            3
+        ]
+
+        """
+
     def slice(self, offset: int, length: int | None = None) -> Series:
         """
         Get a slice of this Series.
@@ -4543,6 +4652,24 @@ class Series:
 
         """
 
+    def nan_to_null(self) -> Series:
+        """
+        Replace floating point NaN values with null.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [1.0, None, float("nan")])
+        >>> s.nan_to_null()
+        shape: (3,)
+        Series: 'a' [f64]
+        [
+                1.0
+                null
+                null
+        ]
+
+        """
+
     def fill_null(
         self,
         value: Any | None = None,
@@ -5136,6 +5263,7 @@ class Series:
         min_periods: int | None = None,
         *,
         center: bool = False,
+        center_bias: CenterBias = "left",
     ) -> Series:
         """
         Apply a rolling min (moving min) over the values in this array.
@@ -5159,6 +5287,11 @@ class Series:
             a result. If None, it will be set equal to window size.
         center
             Set the labels at the center of the window
+        center_bias : {'left', 'right', 'split'}
+            Placement of a centered window of an even size. With 'left', the window
+            has one more value before the label than after it; with 'right', one more
+            value after it. 'split' averages both windows, so the values at both edges
+            count half; this is only supported by `rolling_mean` and `rolling_sum`.
 
         Examples
         --------
@@ -5179,7 +5312,11 @@ class Series:
             self.to_frame()
             .select(
                 F.col(self.name).rolling_min(
-                    window_size, weights, min_periods, center=center
+                    window_size,
+                    weights,
+                    min_periods,
+                    center=center,
+                    center_bias=center_bias,
                 )
             )
             .to_series()
@@ -5192,6 +5329,7 @@ class Series:
         min_periods: int | None = None,
         *,
         center: bool = False,
+        center_bias: CenterBias = "left",
     ) -> Series:
         """
         Apply a rolling max (moving max) over the values in this array.
@@ -5215,6 +5353,11 @@ class Series:
             a result. If None, it will be set equal to window size.
         center
             Set the labels at the center of the window
+        center_bias : {'left', 'right', 'split'}
+            Placement of a centered window of an even size. With 'left', the window
+            has one more value before the label than after it; with 'right', one more
+            value after it. 'split' averages both windows, so the values at both edges
+            count half; this is only supported by `rolling_mean` and `rolling_sum`.
 
         Examples
         --------
@@ -5235,7 +5378,11 @@ class Series:
             self.to_frame()
             .select(
                 F.col(self.name).rolling_max(
-                    window_size, weights, min_periods, center=center
+                    window_size,
+                    weights,
+                    min_periods,
+                    center=center,
+                    center_bias=center_bias,
                 )
             )
             .to_series()
@@ -5248,6 +5395,7 @@ class Series:
         min_periods: int | None = None,
         *,
         center: bool = False,
+        center_bias: CenterBias = "left",
     ) -> Series:
         """
         Apply a rolling mean (moving mean) over the values in this array.
@@ -5271,6 +5419,11 @@ class Series:
             a result. If None, it will be set equal to window size.
         center
             Set the labels at the center of the window
+        center_bias : {'left', 'right', 'split'}
+            Placement of a centered window of an even size. With 'left', the window
+            has one more value before the label than after it; with 'right', one more
+            value after it. 'split' averages both windows, so the values at both edges
+            count half; this is only supported by `rolling_mean` and `rolling_sum`.
 
         Examples
         --------
@@ -5291,7 +5444,11 @@ class Series:
             self.to_frame()
             .select(
                 F.col(self.name).rolling_mean(
-                    window_size, weights, min_periods, center=center
+                    window_size,
+                    weights,
+                    min_periods,
+                    center=center,
+                    center_bias=center_bias,
                 )
             )
             .to_series()
@@ -5304,6 +5461,7 @@ class Series:
         min_periods: int | None = None,
         *,
         center: bool = False,
+        center_bias: CenterBias = "left",
     ) -> Series:
         """
         Apply a rolling sum (moving sum) over the values in this array.
@@ -5327,6 +5485,11 @@ class Series:
             a result. If None, it will be set equal to window size.
         center
             Set the labels at the center of the window
+        center_bias : {'left', 'right', 'split'}
+            Placement of a centered window of an even size. With 'left', the window
+            has one more value before the label than after it; with 'right', one more
+            value after it. 'split' averages both windows, so the values at both edges
+            count half; this is only supported by `rolling_mean` and `rolling_sum`.
 
         Examples
         --------
@@ -5347,7 +5510,11 @@ class Series:
             self.to_frame()
             .select(
                 F.col(self.name).rolling_sum(
-                    window_size, weights, min_periods, center=center
+                    window_size,
+                    weights,
+                    min_periods,
+                    center=center,
+                    center_bias=center_bias,
                 )
             )
             .to_series()
@@ -5360,6 +5527,7 @@ class Series:
         min_periods: int | None = None,
         *,
         center: bool = False,
+        center_bias: CenterBias = "left",
         ddof: int = 1,
     ) -> Series:
         """
@@ -5384,6 +5552,11 @@ class Series:
             a result. If None, it will be set equal to window size.
         center
             Set the labels at the center of the window
+        center_bias : {'left', 'right', 'split'}
+            Placement of a centered window of an even size. With 'left', the window
+            has one more value before the label than after it; with 'right', one more
+            value after it. 'split' averages both windows, so the values at both edges
+            count half; this is only supported by `rolling_mean` and `rolling_sum`.
         ddof
             "Delta Degrees of Freedom": The divisor for a length N window is N - ddof
 
@@ -5407,7 +5580,12 @@ class Series:
             self.to_frame()
             .select(
                 F.col(self.name).rolling_std(
-                    window_size, weights, min_periods, center=center, ddof=ddof
+                    window_size,
+                    weights,
+                    min_periods,
+                    center=center,
+                    center_bias=center_bias,
+                    ddof=ddof,
                 )
             )
             .to_series()
@@ -5420,6 +5598,7 @@ class Series:
         min_periods: int | None = None,
         *,
         center: bool = False,
+        center_bias: CenterBias = "left",
         ddof: int = 1,
     ) -> Series:
         """
@@ -5444,6 +5623,11 @@ class Series:
             a result. If None, it will be set equal to window size.
         center
             Set the labels at the center of the window
+        center_bias : {'left', 'right', 'split'}
+            Placement of a centered window of an even size. With 'left', the window
+            has one more value before the label than after it; with 'right', one more
+            value after it. 'split' averages both windows, so the values at both edges
+            count half; this is only supported by `rolling_mean` and `rolling_sum`.
         ddof
             "Delta Degrees of Freedom": The divisor for a length N window is N - ddof
 
@@ -5467,7 +5651,12 @@ class Series:
             self.to_frame()
             .select(
                 F.col(self.name).rolling_var(
-                    window_size, weights, min_periods, center=center, ddof=ddof
+                    window_size,
+                    weights,
+                    min_periods,
+                    center=center,
+                    center_bias=center_bias,
+                    ddof=ddof,
                 )
             )
             .to_series()
@@ -5533,6 +5722,7 @@ class Series:
         min_periods: int | None = None,
         *,
         center: bool = False,
+        center_bias: CenterBias = "left",
     ) -> Series:
         """
         Compute a rolling median.
@@ -5549,6 +5739,11 @@ class Series:
             a result. If None, it will be set equal to window size.
         center
             Set the labels at the center of the window
+        center_bias : {'left', 'right', 'split'}
+            Placement of a centered window of an even size. With 'left', the window
+            has one more value before the label than after it; with 'right', one more
+            value after it. 'split' averages both windows, so the values at both edges
+            count half; this is only supported by `rolling_mean` and `rolling_sum`.
 
         The window at a given row will include the row itself and the `window_size - 1`
         elements before it.
@@ -5576,7 +5771,11 @@ class Series:
             self.to_frame()
             .select(
                 F.col(self.name).rolling_median(
-                    window_size, weights, min_periods, center=center
+                    window_size,
+                    weights,
+                    min_periods,
+                    center=center,
+                    center_bias=center_bias,
                 )
             )
             .to_series()
@@ -5591,6 +5790,7 @@ class Series:
         min_periods: int | None = None,
         *,
         center: bool = False,
+        center_bias: CenterBias = "left",
     ) -> Series:
         """
         Compute a rolling quantile.
@@ -5614,6 +5814,11 @@ class Series:
             a result. If None, it will be set equal to window size.
         center
             Set the labels at the center of the window
+        center_bias : {'left', 'right', 'split'}
+            Placement of a centered window of an even size. With 'left', the window
+            has one more value before the label than after it; with 'right', one more
+            value after it. 'split' averages both windows, so the values at both edges
+            count half; this is only supported by `rolling_mean` and `rolling_sum`.
 
         Examples
         --------
@@ -5655,6 +5860,7 @@ class Series:
                     weights,
                     min_periods,
                     center=center,
+                    center_bias=center_bias,
                 )
             )
             .to_series()
@@ -5693,6 +5899,218 @@ class Series:
 
         """
 
+    def rolling_kurtosis(
+        self, window_size: int, *, fisher: bool = True, bias: bool = True
+    ) -> Series:
+        """
+        Compute a rolling kurtosis.
+
+        The window at a given row includes the row itself and the
+        `window_size - 1` elements before it. Null values in the window are
+        skipped.
+
+        Parameters
+        ----------
+        window_size
+            Integer size of the rolling window.
+        fisher
+            If True, Fisher's definition is used (normal ==> 0.0). If False,
+            Pearson's definition is used (normal ==> 3.0).
+        bias
+            If False, the calculations are corrected for statistical bias.
+
+        Examples
+        --------
+        >>> pl.Series([1, 4, 2, 9, 3]).rolling_kurtosis(4)
+        shape: (5,)
+        Series: '' [f64]
+        [
+            null
+            null
+            null
+            -1.0
+            -0.839477
+        ]
+
+        Note how the first value matches
+
+        >>> pl.Series([1, 4, 2, 9]).kurtosis()
+        -1.0
+
+        """
+
+    def expanding_min(self, min_periods: int = 1) -> Series:
+        """
+        Compute an expanding minimum.
+
+        The window at a given row includes the row itself and all the rows
+        before it.
+
+        Parameters
+        ----------
+        min_periods
+            The number of values in the window that should be non-null before
+            computing a result.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [1, 4, 2, 9])
+        >>> s.expanding_min(min_periods=2)
+        shape: (4,)
+        Series: 'a' [i64]
+        [
+            null
+            1
+            1
+            1
+        ]
+
+        """
+
+    def expanding_max(self, min_periods: int = 1) -> Series:
+        """
+        Compute an expanding maximum.
+
+        The window at a given row includes the row itself and all the rows
+        before it.
+
+        Parameters
+        ----------
+        min_periods
+            The number of values in the window that should be non-null before
+            computing a result.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [1, 4, 2, 9])
+        >>> s.expanding_max(min_periods=2)
+        shape: (4,)
+        Series: 'a' [i64]
+        [
+            null
+            4
+            4
+            9
+        ]
+
+        """
+
+    def expanding_mean(self, min_periods: int = 1) -> Series:
+        """
+        Compute an expanding mean.
+
+        The window at a given row includes the row itself and all the rows
+        before it.
+
+        Parameters
+        ----------
+        min_periods
+            The number of values in the window that should be non-null before
+            computing a result.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [1, 4, 2, 9])
+        >>> s.expanding_mean(min_periods=2)
+        shape: (4,)
+        Series: 'a' [f64]
+        [
+            null
+            2.5
+            2.333333
+            4.0
+        ]
+
+        """
+
+    def expanding_sum(self, min_periods: int = 1) -> Series:
+        """
+        Compute an expanding sum.
+
+        The window at a given row includes the row itself and all the rows
+        before it.
+
+        Parameters
+        ----------
+        min_periods
+            The number of values in the window that should be non-null before
+            computing a result.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [1, 4, 2, 9])
+        >>> s.expanding_sum(min_periods=2)
+        shape: (4,)
+        Series: 'a' [i64]
+        [
+            null
+            5
+            7
+            16
+        ]
+
+        """
+
+    def expanding_var(self, min_periods: int = 1) -> Series:
+        """
+        Compute an expanding variance.
+
+        The window at a given row includes the row itself and all the rows
+        before it.
+
+        The variance is computed with one delta degree of freedom.
+
+        Parameters
+        ----------
+        min_periods
+            The number of values in the window that should be non-null before
+            computing a result.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [1, 4, 2, 9])
+        >>> s.expanding_var(min_periods=2)
+        shape: (4,)
+        Series: 'a' [f64]
+        [
+            null
+            4.5
+            2.333333
+            12.666667
+        ]
+
+        """
+
+    def expanding_std(self, min_periods: int = 1) -> Series:
+        """
+        Compute an expanding standard deviation.
+
+        The window at a given row includes the row itself and all the rows
+        before it.
+
+        The variance is computed with one delta degree of freedom.
+
+        Parameters
+        ----------
+        min_periods
+            The number of values in the window that should be non-null before
+            computing a result.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [1, 4, 2, 9])
+        >>> s.expanding_std(min_periods=2)
+        shape: (4,)
+        Series: 'a' [f64]
+        [
+            null
+            2.12132
+            1.527525
+            3.559026
+        ]
+
+        """
+
     def sample(
         self,
         n: int | None = None,
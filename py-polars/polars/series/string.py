@@ -81,6 +81,7 @@ class StringNameSpace:
         strict: bool = True,
         exact: bool = True,
         cache: bool = True,
+        infer: bool = False,
         utc: bool | None = None,
         use_earliest: bool | None = None,
         ambiguous: Ambiguous | Series = "raise",
@@ -113,6 +114,10 @@ class StringNameSpace:
                 data beforehand will almost certainly be more performant.
         cache
             Use a cache of unique, converted datetimes to apply the conversion.
+        infer
+            If no ``format`` is given, infer a single format from a sample of the
+            values and parse all values with it. This is much faster than inferring
+            the format of every value, but values in other formats fail to parse.
         utc
             Parse time zone aware datetimes as UTC. This may be useful if you have data
             with mixed offsets.
@@ -186,6 +191,45 @@ class StringNameSpace:
 
         """
 
+    def to_duration(
+        self,
+        format: str | None = None,
+        *,
+        time_unit: TimeUnit = "us",
+        strict: bool = True,
+    ) -> Series:
+        """
+        Convert a Utf8 column into a Duration column.
+
+        Parameters
+        ----------
+        format
+            ``"polars"`` to accept human-readable durations such as ``"1h30m"``,
+            ``"iso"`` to accept ISO 8601 durations such as ``"PT1H30M"``. If set to
+            None (default), both are accepted. The units are those of the ``every``
+            and ``period`` arguments of the window functions, e.g. ``"1d2h"``.
+            Calendar units (months, quarters and years) don't have a fixed length
+            and are not accepted.
+        time_unit : {'us', 'ns', 'ms'}
+            Time unit of the resulting Duration column.
+        strict
+            Raise an error if any conversion fails. Durations that don't fit in
+            64-bit nanoseconds always raise an error.
+
+        Examples
+        --------
+        >>> s = pl.Series(["1h30m", "PT45M", "P1DT2H"])
+        >>> s.str.to_duration()
+        shape: (3,)
+        Series: '' [duration[μs]]
+        [
+                1h 30m
+                45m
+                1d 2h
+        ]
+
+        """
+
     def strptime(
         self,
         dtype: PolarsTemporalType,
@@ -376,7 +420,7 @@ class StringNameSpace:
 
         """
 
-    def concat(self, delimiter: str = "-") -> Series:
+    def concat(self, delimiter: str = "-", *, ignore_nulls: bool = False) -> Series:
         """
         Vertically concat the values in the Series to a single string value.
 
@@ -384,6 +428,9 @@ class StringNameSpace:
         ----------
         delimiter
             The delimiter to insert between consecutive string values.
+        ignore_nulls
+            Skip null values. If set to ``False``, null values are written as
+            ``"null"``.
 
         Returns
         -------
@@ -394,6 +441,8 @@ class StringNameSpace:
         --------
         >>> pl.Series([1, None, 2]).str.concat("-")[0]
         '1-null-2'
+        >>> pl.Series([1, None, 2]).str.concat("-", ignore_nulls=True)[0]
+        '1-2'
 
         """
 
@@ -874,7 +923,9 @@ class StringNameSpace:
 
         """
 
-    def split(self, by: IntoExpr, *, inclusive: bool = False) -> Series:
+    def split(
+        self, by: IntoExpr, *, inclusive: bool = False, literal: bool = True
+    ) -> Series:
         """
         Split the string by a substring.
 
@@ -884,6 +935,9 @@ class StringNameSpace:
             Substring to split by.
         inclusive
             If True, include the split character/string in the results.
+        literal
+            Treat ``by`` as a literal string. If set to False, ``by`` is interpreted
+            as a regular expression.
 
         Returns
         -------
@@ -952,7 +1006,7 @@ class StringNameSpace:
 
         """
 
-    def splitn(self, by: IntoExpr, n: int) -> Series:
+    def splitn(self, by: IntoExpr, n: int, *, literal: bool = True) -> Series:
         """
         Split the string by a substring, restricted to returning at most ``n`` items.
 
@@ -966,6 +1020,9 @@ class StringNameSpace:
             Substring to split by.
         n
             Max number of items to return.
+        literal
+            Treat ``by`` as a literal string. If set to False, ``by`` is interpreted
+            as a regular expression.
 
         Examples
         --------
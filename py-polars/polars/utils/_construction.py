@@ -1521,6 +1521,19 @@ def arrow_to_pydf(
     return pydf
 
 
+def arrow_c_stream_to_pydf(
+    data: Any,
+    schema: SchemaDefinition | None = None,
+    *,
+    schema_overrides: SchemaDict | None = None,
+) -> PyDataFrame:
+    """Construct a PyDataFrame from an object that exports an Arrow C stream."""
+    pydf = PyDataFrame.from_arrow_c_stream(data)
+    if schema is not None or schema_overrides:
+        pydf = _post_apply_columns(pydf, schema, schema_overrides=schema_overrides)
+    return pydf
+
+
 def series_to_pydf(
     data: Series,
     schema: SchemaDefinition | None = None,
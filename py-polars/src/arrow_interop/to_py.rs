@@ -1,9 +1,12 @@
+use std::ffi::CString;
+
 use arrow::ffi;
 use polars::prelude::{ArrayRef, ArrowField};
 use polars_core::frame::ArrowChunk;
 use polars_core::utils::arrow;
 use pyo3::ffi::Py_uintptr_t;
 use pyo3::prelude::*;
+use pyo3::types::PyCapsule;
 
 /// Arrow array to Python.
 pub(crate) fn to_py_array(array: ArrayRef, py: Python, pyarrow: &PyModule) -> PyResult<PyObject> {
@@ -45,3 +48,25 @@ pub(crate) fn to_py_rb(
 
     Ok(record.to_object(py))
 }
+
+/// An Arrow C stream that can be moved into a `PyCapsule`.
+#[repr(transparent)]
+struct StreamCapsule(ffi::ArrowArrayStream);
+
+// Safety: the stream owns its arrays and is only consumed once.
+unsafe impl Send for StreamCapsule {}
+
+/// Export `arrays` of type `field` as an Arrow C stream in a `PyCapsule`, see the
+/// [Arrow PyCapsule interface](https://arrow.apache.org/docs/format/CDataInterface/PyCapsuleInterface.html).
+pub(crate) fn to_py_stream_capsule(
+    arrays: Vec<ArrayRef>,
+    field: ArrowField,
+    py: Python,
+) -> PyResult<PyObject> {
+    let iter = Box::new(arrays.into_iter().map(Ok::<_, arrow::error::Error>));
+    let stream = ffi::export_iterator(iter, field);
+    let name = CString::new("arrow_array_stream").unwrap();
+    // The capsule releases the stream on drop, unless a consumer moved it out.
+    let capsule = PyCapsule::new(py, StreamCapsule(stream), Some(name))?;
+    Ok(capsule.to_object(py))
+}
@@ -3,9 +3,10 @@ use polars_core::prelude::*;
 use polars_core::utils::accumulate_dataframes_vertical_unchecked;
 use polars_core::utils::arrow::ffi;
 use polars_core::POOL;
+use pyo3::exceptions::PyValueError;
 use pyo3::ffi::Py_uintptr_t;
 use pyo3::prelude::*;
-use pyo3::types::PyList;
+use pyo3::types::{PyCapsule, PyList};
 
 use crate::error::PyPolarsErr;
 
@@ -40,12 +41,43 @@ pub fn array_to_rust(obj: &PyAny) -> PyResult<ArrayRef> {
     )?;
 
     unsafe {
-        let field = ffi::import_field_from_c(schema.as_ref()).map_err(PyPolarsErr::from)?;
-        let array = ffi::import_array_from_c(*array, field.data_type).map_err(PyPolarsErr::from)?;
+        // view types and run-end encoded arrays are converted to types polars supports
+        let (_, array) = ffi::import_field_and_array_from_c(schema.as_ref(), *array)
+            .map_err(PyPolarsErr::from)?;
         Ok(array)
     }
 }
 
+/// Import the Arrow C stream of an object that implements `__arrow_c_stream__`, see the
+/// [Arrow PyCapsule interface](https://arrow.apache.org/docs/format/CDataInterface/PyCapsuleInterface.html).
+pub fn stream_to_rust(obj: &PyAny) -> PyResult<(ArrowField, Vec<ArrayRef>)> {
+    let capsule: &PyCapsule = obj.call_method0("__arrow_c_stream__")?.downcast()?;
+    match capsule.name()? {
+        Some(name) if name.to_bytes() == b"arrow_array_stream" => {},
+        _ => {
+            return Err(PyValueError::new_err(
+                "expected a PyCapsule named 'arrow_array_stream'",
+            ))
+        },
+    }
+
+    // Move the stream out of the capsule; the capsule then won't release it.
+    let stream = unsafe {
+        std::ptr::replace(
+            capsule.pointer() as *mut ffi::ArrowArrayStream,
+            ffi::ArrowArrayStream::empty(),
+        )
+    };
+    let mut reader = unsafe { ffi::ArrowArrayStreamReader::try_new(Box::new(stream)) }
+        .map_err(PyPolarsErr::from)?;
+    let field = reader.field().clone();
+    let mut arrays = vec![];
+    while let Some(array) = unsafe { reader.next() } {
+        arrays.push(array.map_err(PyPolarsErr::from)?);
+    }
+    Ok((field, arrays))
+}
+
 pub fn to_rust_df(rb: &[&PyAny]) -> PyResult<DataFrame> {
     let schema = rb
         .get(0)
@@ -9,7 +9,7 @@ use polars::frame::NullStrategy;
 use polars::io::avro::AvroCompression;
 #[cfg(feature = "ipc")]
 use polars::io::ipc::IpcCompression;
-use polars::prelude::AnyValue;
+use polars::prelude::{AnyValue, PlSmallStr};
 use polars::series::ops::NullBehavior;
 use polars_core::frame::row::any_values_to_dtype;
 use polars_core::prelude::{IndexOrder, QuantileInterpolOptions};
@@ -26,7 +26,6 @@ use pyo3::types::{
     PyBool, PyBytes, PyDict, PyFloat, PyList, PySequence, PyString, PyTuple, PyType,
 };
 use pyo3::{intern, PyAny, PyResult};
-use smartstring::alias::String as SmartString;
 
 use crate::error::PyPolarsErr;
 #[cfg(feature = "object")]
@@ -1154,6 +1153,22 @@ impl FromPyObject<'_> for Wrap<StartBy> {
     }
 }
 
+impl FromPyObject<'_> for Wrap<CenterBias> {
+    fn extract(ob: &PyAny) -> PyResult<Self> {
+        let parsed = match ob.extract::<&str>()? {
+            "left" => CenterBias::Left,
+            "right" => CenterBias::Right,
+            "split" => CenterBias::Split,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`center_bias` must be one of {{'left', 'right', 'split'}}, got {v}",
+                )))
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 impl FromPyObject<'_> for Wrap<ClosedWindow> {
     fn extract(ob: &PyAny) -> PyResult<Self> {
         let parsed = match ob.extract::<&str>()? {
@@ -1177,9 +1192,12 @@ impl FromPyObject<'_> for Wrap<CsvEncoding> {
         let parsed = match ob.extract::<&str>()? {
             "utf8" => CsvEncoding::Utf8,
             "utf8-lossy" => CsvEncoding::LossyUtf8,
+            "latin1" => CsvEncoding::Latin1,
+            "utf16-le" => CsvEncoding::Utf16Le,
+            "utf16-be" => CsvEncoding::Utf16Be,
             v => {
                 return Err(PyValueError::new_err(format!(
-                    "csv `encoding` must be one of {{'utf8', 'utf8-lossy'}}, got {v}",
+                    "csv `encoding` must be one of {{'utf8', 'utf8-lossy', 'latin1', 'utf16-le', 'utf16-be'}}, got {v}",
                 )))
             },
         };
@@ -1560,7 +1578,7 @@ pub(crate) fn parse_parquet_compression(
     Ok(parsed)
 }
 
-pub(crate) fn strings_to_smartstrings<I, S>(container: I) -> Vec<SmartString>
+pub(crate) fn strings_to_smartstrings<I, S>(container: I) -> Vec<PlSmallStr>
 where
     I: IntoIterator<Item = S>,
     S: AsRef<str>,
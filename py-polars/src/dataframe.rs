@@ -11,6 +11,7 @@ use polars::io::ipc::IpcCompression;
 use polars::io::mmap::ReaderBytes;
 use polars::io::RowCount;
 use polars::prelude::*;
+use polars_core::export::arrow::array::StructArray;
 use polars_core::export::arrow::datatypes::IntegerType;
 use polars_core::frame::explode::MeltArgs;
 use polars_core::frame::*;
@@ -18,7 +19,7 @@ use polars_core::prelude::{IndexOrder, QuantileInterpolOptions};
 use polars_core::utils::arrow::compute::cast::CastOptions;
 use polars_core::utils::try_get_supertype;
 #[cfg(feature = "pivot")]
-use polars_lazy::frame::pivot::{pivot, pivot_stable};
+use polars_lazy::frame::pivot::{pivot, pivot_stable, pivot_with_margins};
 use pyo3::prelude::*;
 use pyo3::types::{PyBytes, PyDict, PyList, PyTuple};
 
@@ -507,6 +508,50 @@ impl PyDataFrame {
         Ok(Self::from(df))
     }
 
+    #[staticmethod]
+    pub fn from_arrow_c_stream(obj: &PyAny) -> PyResult<Self> {
+        let (field, arrays) = arrow_interop::to_rust::stream_to_rust(obj)?;
+        let fields = match &field.data_type {
+            ArrowDataType::Struct(fields) => fields,
+            dt => {
+                return Err(PyPolarsErr::Other(format!(
+                    "expected an Arrow C stream of structs, got {dt:?}"
+                ))
+                .into())
+            },
+        };
+        let to_df = |columns: Vec<Series>| DataFrame::new(columns).map_err(PyPolarsErr::from);
+        let mut dfs = arrays
+            .iter()
+            .map(|arr| {
+                let arr = arr.as_any().downcast_ref::<StructArray>().unwrap();
+                let columns = fields
+                    .iter()
+                    .zip(arr.values())
+                    .map(|(fld, values)| Series::try_from((fld.name.as_str(), values.clone())))
+                    .collect::<PolarsResult<Vec<_>>>()
+                    .map_err(PyPolarsErr::from)?;
+                to_df(columns)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let df = match dfs.len() {
+            0 => to_df(
+                fields
+                    .iter()
+                    .map(|fld| Series::new_empty(&fld.name, &(&fld.data_type).into()))
+                    .collect(),
+            )?,
+            _ => {
+                let mut df = dfs.remove(0);
+                for other in &dfs {
+                    df.vstack_mut(other).map_err(PyPolarsErr::from)?;
+                }
+                df
+            },
+        };
+        Ok(df.into())
+    }
+
     // somehow from_rows did not work
     #[staticmethod]
     pub fn read_rows(
@@ -821,6 +866,39 @@ impl PyDataFrame {
         })
     }
 
+    /// Export the DataFrame as an Arrow C stream of struct arrays, one per chunk, see the
+    /// Arrow PyCapsule interface. `requested_schema` is ignored.
+    #[allow(unused_variables)]
+    #[pyo3(signature = (requested_schema=None))]
+    fn __arrow_c_stream__(
+        &mut self,
+        py: Python,
+        requested_schema: Option<PyObject>,
+    ) -> PyResult<PyObject> {
+        self.df.align_chunks();
+        let names = self.df.get_column_names();
+        let chunks = self
+            .df
+            .iter_chunks()
+            .map(|chunk| chunk.into_arrays())
+            .collect::<Vec<_>>();
+        let fields = match chunks.first() {
+            Some(arrays) => names
+                .iter()
+                .zip(arrays)
+                .map(|(name, arr)| ArrowField::new(name, arr.data_type().clone(), true))
+                .collect(),
+            None => self.df.schema().to_arrow().fields,
+        };
+        let dtype = ArrowDataType::Struct(fields);
+        let arrays = chunks
+            .into_iter()
+            .map(|arrays| StructArray::new(dtype.clone(), arrays, None).boxed())
+            .collect();
+        let field = ArrowField::new("", dtype, false);
+        arrow_interop::to_py::to_py_stream_capsule(arrays, field, py)
+    }
+
     pub fn to_pandas(&mut self) -> PyResult<Vec<PyObject>> {
         self.df.as_single_chunk_par();
         Python::with_gil(|py| {
@@ -981,6 +1059,10 @@ impl PyDataFrame {
         self.df.n_chunks()
     }
 
+    pub fn chunk_lengths(&self) -> Vec<Vec<usize>> {
+        self.df.chunk_lengths()
+    }
+
     pub fn shape(&self) -> (usize, usize) {
         self.df.shape()
     }
@@ -1200,18 +1282,31 @@ impl PyDataFrame {
         sort_columns: bool,
         aggregate_expr: Option<PyExpr>,
         separator: Option<&str>,
+        margins_name: Option<&str>,
     ) -> PyResult<Self> {
         let fun = if maintain_order { pivot_stable } else { pivot };
         let agg_expr = aggregate_expr.map(|expr| expr.inner);
-        let df = fun(
-            &self.df,
-            values,
-            index,
-            columns,
-            sort_columns,
-            agg_expr,
-            separator,
-        )
+        let df = match margins_name {
+            Some(margins_name) => pivot_with_margins(
+                &self.df,
+                values,
+                index,
+                columns,
+                sort_columns,
+                agg_expr,
+                separator,
+                margins_name,
+            ),
+            None => fun(
+                &self.df,
+                values,
+                index,
+                columns,
+                sort_columns,
+                agg_expr,
+                separator,
+            ),
+        }
         .map_err(PyPolarsErr::from)?;
         Ok(PyDataFrame::new(df))
     }
@@ -1367,6 +1462,11 @@ impl PyDataFrame {
         Ok(hash.into_series().into())
     }
 
+    pub fn hash_rows_stable(&self, seed: u64) -> PyResult<PySeries> {
+        let hash = self.df.hash_rows_stable(seed).map_err(PyPolarsErr::from)?;
+        Ok(hash.into_series().into())
+    }
+
     #[pyo3(signature = (keep_names_as, column_names))]
     pub fn transpose(&self, keep_names_as: Option<&str>, column_names: &PyAny) -> PyResult<Self> {
         let new_col_names = if let Ok(name) = column_names.extract::<Vec<String>>() {
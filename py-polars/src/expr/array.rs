@@ -15,6 +15,40 @@ impl PyExpr {
     fn array_sum(&self) -> Self {
         self.inner.clone().arr().sum().into()
     }
+
+    fn array_mean(&self) -> Self {
+        self.inner.clone().arr().mean().into()
+    }
+
+    fn array_std(&self, ddof: u8) -> Self {
+        self.inner.clone().arr().std(ddof).into()
+    }
+
+    fn array_any(&self) -> Self {
+        self.inner.clone().arr().any().into()
+    }
+
+    fn array_all(&self) -> Self {
+        self.inner.clone().arr().all().into()
+    }
+
+    fn array_arg_min(&self) -> Self {
+        self.inner.clone().arr().arg_min().into()
+    }
+
+    fn array_arg_max(&self) -> Self {
+        self.inner.clone().arr().arg_max().into()
+    }
+
+    #[cfg(feature = "is_in")]
+    fn array_contains(&self, other: PyExpr) -> Self {
+        self.inner.clone().arr().contains(other.inner).into()
+    }
+
+    fn array_to_list(&self) -> Self {
+        self.inner.clone().arr().to_list().into()
+    }
+
     fn array_unique(&self, maintain_order: bool) -> Self {
         if maintain_order {
             self.inner.clone().arr().unique_stable().into()
@@ -14,6 +14,10 @@ impl PyExpr {
         self.inner.clone().dt().offset_by(by.inner).into()
     }
 
+    fn dt_session_id(&self, gap: &str) -> Self {
+        self.inner.clone().dt().session_id(gap).into()
+    }
+
     fn dt_epoch_seconds(&self) -> Self {
         self.clone()
             .inner
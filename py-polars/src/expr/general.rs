@@ -124,6 +124,10 @@ impl PyExpr {
         self.clone().inner.is_not_nan().into()
     }
 
+    fn nan_to_null(&self) -> Self {
+        self.inner.clone().nan_to_null().into()
+    }
+
     fn min(&self) -> Self {
         self.clone().inner.min().into()
     }
@@ -231,6 +235,10 @@ impl PyExpr {
         self.clone().inner.rle().into()
     }
     #[cfg(feature = "rle")]
+    fn rle_decode(&self) -> Self {
+        self.clone().inner.rle_decode().into()
+    }
+    #[cfg(feature = "rle")]
     fn rle_id(&self) -> Self {
         self.clone().inner.rle_id().into()
     }
@@ -244,6 +252,9 @@ impl PyExpr {
     fn value_counts(&self, sort: bool, parallel: bool) -> Self {
         self.inner.clone().value_counts(sort, parallel).into()
     }
+    fn most_frequent(&self, k: usize, parallel: bool) -> Self {
+        self.inner.clone().most_frequent(k, parallel).into()
+    }
     fn unique_counts(&self) -> Self {
         self.inner.clone().unique_counts().into()
     }
@@ -311,6 +322,14 @@ impl PyExpr {
         self.clone().inner.arg_min().into()
     }
 
+    fn min_by(&self, by: Self) -> Self {
+        self.clone().inner.min_by(by.inner).into()
+    }
+
+    fn max_by(&self, by: Self) -> Self {
+        self.clone().inner.max_by(by.inner).into()
+    }
+
     #[cfg(feature = "search_sorted")]
     fn search_sorted(&self, element: Self, side: Wrap<SearchSortedSide>) -> Self {
         self.inner
@@ -751,8 +770,16 @@ impl PyExpr {
         self.inner.clone().reshape(&dims).into()
     }
 
-    fn cumcount(&self, reverse: bool) -> Self {
-        self.inner.clone().cumcount(reverse).into()
+    fn cumcount(&self, reverse: bool, ignore_nulls: bool) -> Self {
+        if ignore_nulls {
+            self.inner.clone().cumcount_ignore_nulls(reverse).into()
+        } else {
+            self.inner.clone().cumcount(reverse).into()
+        }
+    }
+
+    fn cum_n_unique(&self, reverse: bool) -> Self {
+        self.inner.clone().cum_n_unique(reverse).into()
     }
 
     fn to_physical(&self) -> Self {
@@ -2,7 +2,6 @@ use polars::lazy::dsl::lit;
 use polars::prelude::*;
 use polars::series::ops::NullBehavior;
 use pyo3::prelude::*;
-use smartstring::alias::String as SmartString;
 
 use crate::conversion::Wrap;
 use crate::PyExpr;
@@ -124,6 +123,10 @@ impl PyExpr {
             .into()
     }
 
+    fn list_to_array(&self, width: usize) -> Self {
+        self.inner.clone().list().to_array(width).into()
+    }
+
     #[pyo3(signature = (width_strat, name_gen, upper_bound))]
     fn list_to_struct(
         &self,
@@ -135,7 +138,7 @@ impl PyExpr {
             Arc::new(move |idx: usize| {
                 Python::with_gil(|py| {
                     let out = lambda.call1(py, (idx,)).unwrap();
-                    let out: SmartString = out.extract::<&str>(py).unwrap().into();
+                    let out: PlSmallStr = out.extract::<&str>(py).unwrap().into();
                     out
                 })
             }) as NameGenerator
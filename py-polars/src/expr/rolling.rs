@@ -11,13 +11,15 @@ use crate::{PyExpr, PySeries};
 
 #[pymethods]
 impl PyExpr {
-    #[pyo3(signature = (window_size, weights, min_periods, center, by, closed))]
+    #[pyo3(signature = (window_size, weights, min_periods, center, center_bias, by, closed))]
+    #[allow(clippy::too_many_arguments)]
     fn rolling_sum(
         &self,
         window_size: &str,
         weights: Option<Vec<f64>>,
         min_periods: usize,
         center: bool,
+        center_bias: Wrap<CenterBias>,
         by: Option<String>,
         closed: Option<Wrap<ClosedWindow>>,
     ) -> Self {
@@ -26,6 +28,7 @@ impl PyExpr {
             weights,
             min_periods,
             center,
+            center_bias: center_bias.0,
             by,
             closed_window: closed.map(|c| c.0),
             ..Default::default()
@@ -33,13 +36,15 @@ impl PyExpr {
         self.inner.clone().rolling_sum(options).into()
     }
 
-    #[pyo3(signature = (window_size, weights, min_periods, center, by, closed))]
+    #[pyo3(signature = (window_size, weights, min_periods, center, center_bias, by, closed))]
+    #[allow(clippy::too_many_arguments)]
     fn rolling_min(
         &self,
         window_size: &str,
         weights: Option<Vec<f64>>,
         min_periods: usize,
         center: bool,
+        center_bias: Wrap<CenterBias>,
         by: Option<String>,
         closed: Option<Wrap<ClosedWindow>>,
     ) -> Self {
@@ -48,6 +53,7 @@ impl PyExpr {
             weights,
             min_periods,
             center,
+            center_bias: center_bias.0,
             by,
             closed_window: closed.map(|c| c.0),
             ..Default::default()
@@ -55,13 +61,15 @@ impl PyExpr {
         self.inner.clone().rolling_min(options).into()
     }
 
-    #[pyo3(signature = (window_size, weights, min_periods, center, by, closed))]
+    #[pyo3(signature = (window_size, weights, min_periods, center, center_bias, by, closed))]
+    #[allow(clippy::too_many_arguments)]
     fn rolling_max(
         &self,
         window_size: &str,
         weights: Option<Vec<f64>>,
         min_periods: usize,
         center: bool,
+        center_bias: Wrap<CenterBias>,
         by: Option<String>,
         closed: Option<Wrap<ClosedWindow>>,
     ) -> Self {
@@ -70,6 +78,7 @@ impl PyExpr {
             weights,
             min_periods,
             center,
+            center_bias: center_bias.0,
             by,
             closed_window: closed.map(|c| c.0),
             ..Default::default()
@@ -77,13 +86,15 @@ impl PyExpr {
         self.inner.clone().rolling_max(options).into()
     }
 
-    #[pyo3(signature = (window_size, weights, min_periods, center, by, closed))]
+    #[pyo3(signature = (window_size, weights, min_periods, center, center_bias, by, closed))]
+    #[allow(clippy::too_many_arguments)]
     fn rolling_mean(
         &self,
         window_size: &str,
         weights: Option<Vec<f64>>,
         min_periods: usize,
         center: bool,
+        center_bias: Wrap<CenterBias>,
         by: Option<String>,
         closed: Option<Wrap<ClosedWindow>>,
     ) -> Self {
@@ -92,6 +103,7 @@ impl PyExpr {
             weights,
             min_periods,
             center,
+            center_bias: center_bias.0,
             by,
             closed_window: closed.map(|c| c.0),
             ..Default::default()
@@ -100,7 +112,7 @@ impl PyExpr {
         self.inner.clone().rolling_mean(options).into()
     }
 
-    #[pyo3(signature = (window_size, weights, min_periods, center, by, closed, ddof))]
+    #[pyo3(signature = (window_size, weights, min_periods, center, center_bias, by, closed, ddof))]
     #[allow(clippy::too_many_arguments)]
     fn rolling_std(
         &self,
@@ -108,6 +120,7 @@ impl PyExpr {
         weights: Option<Vec<f64>>,
         min_periods: usize,
         center: bool,
+        center_bias: Wrap<CenterBias>,
         by: Option<String>,
         closed: Option<Wrap<ClosedWindow>>,
         ddof: u8,
@@ -117,6 +130,7 @@ impl PyExpr {
             weights,
             min_periods,
             center,
+            center_bias: center_bias.0,
             by,
             closed_window: closed.map(|c| c.0),
             fn_params: Some(Arc::new(RollingVarParams { ddof }) as Arc<dyn Any + Send + Sync>),
@@ -125,7 +139,7 @@ impl PyExpr {
         self.inner.clone().rolling_std(options).into()
     }
 
-    #[pyo3(signature = (window_size, weights, min_periods, center, by, closed, ddof))]
+    #[pyo3(signature = (window_size, weights, min_periods, center, center_bias, by, closed, ddof))]
     #[allow(clippy::too_many_arguments)]
     fn rolling_var(
         &self,
@@ -133,6 +147,7 @@ impl PyExpr {
         weights: Option<Vec<f64>>,
         min_periods: usize,
         center: bool,
+        center_bias: Wrap<CenterBias>,
         by: Option<String>,
         closed: Option<Wrap<ClosedWindow>>,
         ddof: u8,
@@ -142,6 +157,7 @@ impl PyExpr {
             weights,
             min_periods,
             center,
+            center_bias: center_bias.0,
             by,
             closed_window: closed.map(|c| c.0),
             fn_params: Some(Arc::new(RollingVarParams { ddof }) as Arc<dyn Any + Send + Sync>),
@@ -150,13 +166,15 @@ impl PyExpr {
         self.inner.clone().rolling_var(options).into()
     }
 
-    #[pyo3(signature = (window_size, weights, min_periods, center, by, closed))]
+    #[pyo3(signature = (window_size, weights, min_periods, center, center_bias, by, closed))]
+    #[allow(clippy::too_many_arguments)]
     fn rolling_median(
         &self,
         window_size: &str,
         weights: Option<Vec<f64>>,
         min_periods: usize,
         center: bool,
+        center_bias: Wrap<CenterBias>,
         by: Option<String>,
         closed: Option<Wrap<ClosedWindow>>,
     ) -> Self {
@@ -165,6 +183,7 @@ impl PyExpr {
             weights,
             min_periods,
             center,
+            center_bias: center_bias.0,
             by,
             closed_window: closed.map(|c| c.0),
             fn_params: Some(Arc::new(RollingQuantileParams {
@@ -175,7 +194,7 @@ impl PyExpr {
         self.inner.clone().rolling_quantile(options).into()
     }
 
-    #[pyo3(signature = (quantile, interpolation, window_size, weights, min_periods, center, by, closed))]
+    #[pyo3(signature = (quantile, interpolation, window_size, weights, min_periods, center, center_bias, by, closed))]
     #[allow(clippy::too_many_arguments)]
     fn rolling_quantile(
         &self,
@@ -185,6 +204,7 @@ impl PyExpr {
         weights: Option<Vec<f64>>,
         min_periods: usize,
         center: bool,
+        center_bias: Wrap<CenterBias>,
         by: Option<String>,
         closed: Option<Wrap<ClosedWindow>>,
     ) -> Self {
@@ -193,6 +213,7 @@ impl PyExpr {
             weights,
             min_periods,
             center,
+            center_bias: center_bias.0,
             by,
             closed_window: closed.map(|c| c.0),
             fn_params: Some(Arc::new(RollingQuantileParams {
@@ -208,6 +229,37 @@ impl PyExpr {
         self.inner.clone().rolling_skew(window_size, bias).into()
     }
 
+    fn rolling_kurtosis(&self, window_size: usize, fisher: bool, bias: bool) -> Self {
+        self.inner
+            .clone()
+            .rolling_kurtosis(window_size, fisher, bias)
+            .into()
+    }
+
+    fn expanding_min(&self, min_periods: usize) -> Self {
+        self.inner.clone().expanding_min(min_periods).into()
+    }
+
+    fn expanding_max(&self, min_periods: usize) -> Self {
+        self.inner.clone().expanding_max(min_periods).into()
+    }
+
+    fn expanding_mean(&self, min_periods: usize) -> Self {
+        self.inner.clone().expanding_mean(min_periods).into()
+    }
+
+    fn expanding_sum(&self, min_periods: usize) -> Self {
+        self.inner.clone().expanding_sum(min_periods).into()
+    }
+
+    fn expanding_var(&self, min_periods: usize) -> Self {
+        self.inner.clone().expanding_var(min_periods).into()
+    }
+
+    fn expanding_std(&self, min_periods: usize) -> Self {
+        self.inner.clone().expanding_std(min_periods).into()
+    }
+
     #[pyo3(signature = (lambda, window_size, weights, min_periods, center))]
     fn rolling_map(
         &self,
@@ -7,8 +7,12 @@ use crate::PyExpr;
 
 #[pymethods]
 impl PyExpr {
-    fn str_concat(&self, delimiter: &str) -> Self {
-        self.inner.clone().str().concat(delimiter).into()
+    fn str_concat(&self, delimiter: &str, ignore_nulls: bool) -> Self {
+        self.inner
+            .clone()
+            .str()
+            .concat(delimiter, ignore_nulls)
+            .into()
     }
 
     #[pyo3(signature = (format, strict, exact, cache))]
@@ -18,11 +22,12 @@ impl PyExpr {
             strict,
             exact,
             cache,
+            ..Default::default()
         };
         self.inner.clone().str().to_date(options).into()
     }
 
-    #[pyo3(signature = (format, time_unit, time_zone, strict, exact, cache, ambiguous))]
+    #[pyo3(signature = (format, time_unit, time_zone, strict, exact, cache, infer, ambiguous))]
     #[allow(clippy::too_many_arguments)]
     fn str_to_datetime(
         &self,
@@ -32,6 +37,7 @@ impl PyExpr {
         strict: bool,
         exact: bool,
         cache: bool,
+        infer: bool,
         ambiguous: Self,
     ) -> Self {
         let options = StrptimeOptions {
@@ -39,6 +45,7 @@ impl PyExpr {
             strict,
             exact,
             cache,
+            infer,
         };
         self.inner
             .clone()
@@ -59,10 +66,29 @@ impl PyExpr {
             strict,
             cache,
             exact: true,
+            ..Default::default()
         };
         self.inner.clone().str().to_time(options).into()
     }
 
+    fn str_to_duration(
+        &self,
+        format: Option<String>,
+        time_unit: Wrap<TimeUnit>,
+        strict: bool,
+    ) -> Self {
+        let options = StrptimeOptions {
+            format,
+            strict,
+            ..Default::default()
+        };
+        self.inner
+            .clone()
+            .str()
+            .to_duration(time_unit.0, options)
+            .into()
+    }
+
     fn str_strip_chars(&self, matches: Self) -> Self {
         self.inner.clone().str().strip_chars(matches.inner).into()
     }
@@ -291,6 +317,20 @@ impl PyExpr {
         self.inner.clone().str().split_inclusive(by.inner).into()
     }
 
+    #[cfg(feature = "lazy_regex")]
+    fn str_split_regex(&self, by: Self) -> Self {
+        self.inner.clone().str().split_regex(by.inner).into()
+    }
+
+    #[cfg(feature = "lazy_regex")]
+    fn str_split_regex_inclusive(&self, by: Self) -> Self {
+        self.inner
+            .clone()
+            .str()
+            .split_regex_inclusive(by.inner)
+            .into()
+    }
+
     fn str_split_exact(&self, by: Self, n: usize) -> Self {
         self.inner.clone().str().split_exact(by.inner, n).into()
     }
@@ -307,6 +347,11 @@ impl PyExpr {
         self.inner.clone().str().splitn(by.inner, n).into()
     }
 
+    #[cfg(feature = "lazy_regex")]
+    fn str_splitn_regex(&self, by: Self, n: usize) -> Self {
+        self.inner.clone().str().splitn_regex(by.inner, n).into()
+    }
+
     fn str_to_decimal(&self, infer_len: usize) -> Self {
         self.inner.clone().str().to_decimal(infer_len).into()
     }
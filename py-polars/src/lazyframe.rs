@@ -141,7 +141,8 @@ impl PyLazyFrame {
     #[pyo3(signature = (path, separator, has_header, ignore_errors, skip_rows, n_rows, cache, overwrite_dtype,
         low_memory, comment_char, quote_char, null_values, missing_utf8_is_empty_string,
         infer_schema_length, with_schema_modify, rechunk, skip_rows_after_header,
-        encoding, row_count, try_parse_dates, eol_char, raise_if_empty, truncate_ragged_lines, schema
+        encoding, row_count, try_parse_dates, eol_char, raise_if_empty, truncate_ragged_lines, schema,
+        projection, cloud_options, retries
     )
     )]
     fn new_from_csv(
@@ -169,7 +170,22 @@ impl PyLazyFrame {
         raise_if_empty: bool,
         truncate_ragged_lines: bool,
         schema: Option<Wrap<Schema>>,
+        projection: Option<Vec<usize>>,
+        cloud_options: Option<Vec<(String, String)>>,
+        retries: usize,
     ) -> PyResult<Self> {
+        let mut cloud_options = cloud_options
+            .map(|kv| parse_cloud_options(&path, kv))
+            .transpose()?;
+        if retries > 0 {
+            cloud_options =
+                cloud_options
+                    .or_else(|| Some(CloudOptions::default()))
+                    .map(|mut options| {
+                        options.max_retries = retries;
+                        options
+                    });
+        }
         let null_values = null_values.map(|w| w.0);
         let comment_char = comment_char.map(|s| s.as_bytes()[0]);
         let quote_char = quote_char.map(|s| s.as_bytes()[0]);
@@ -205,7 +221,9 @@ impl PyLazyFrame {
             .with_null_values(null_values)
             .with_missing_is_null(!missing_utf8_is_empty_string)
             .truncate_ragged_lines(truncate_ragged_lines)
-            .raise_if_empty(raise_if_empty);
+            .raise_if_empty(raise_if_empty)
+            .with_projection(projection)
+            .with_cloud_options(cloud_options);
 
         if let Some(lambda) = with_schema_modify {
             let f = |schema: Schema| {
@@ -273,7 +291,9 @@ impl PyLazyFrame {
             low_memory,
             cloud_options,
             use_statistics,
+            use_bloom_filters: false,
             hive_partitioning,
+            ..Default::default()
         };
         let lf = LazyFrame::scan_parquet(path, args).map_err(PyPolarsErr::from)?;
         Ok(lf.into())
@@ -297,6 +317,7 @@ impl PyLazyFrame {
             rechunk,
             row_count,
             memmap: memory_map,
+            ..Default::default()
         };
         let lf = LazyFrame::scan_ipc(path, args).map_err(PyPolarsErr::from)?;
         Ok(lf.into())
@@ -370,6 +391,11 @@ impl PyLazyFrame {
         ldf.into()
     }
 
+    fn with_streaming_memory_limit(&self, memory_limit: Option<usize>) -> Self {
+        let ldf = self.ldf.clone();
+        ldf.with_streaming_memory_limit(memory_limit).into()
+    }
+
     fn sort(
         &self,
         by_column: &str,
@@ -655,6 +681,23 @@ impl PyLazyFrame {
         PyLazyGroupBy { lgb: Some(lazy_gb) }
     }
 
+    fn group_by_session(
+        &mut self,
+        index_column: PyExpr,
+        gap: &str,
+        by: Vec<PyExpr>,
+        session_column: &str,
+    ) -> PyLazyGroupBy {
+        let ldf = self.ldf.clone();
+        let by = by
+            .into_iter()
+            .map(|pyexpr| pyexpr.inner)
+            .collect::<Vec<_>>();
+        let lazy_gb = ldf.group_by_session(index_column.inner, by, gap, session_column);
+
+        PyLazyGroupBy { lgb: Some(lazy_gb) }
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn group_by_dynamic(
         &mut self,
@@ -4,7 +4,7 @@ use polars_core::utils::CustomIterTools;
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 
-use crate::arrow_interop::to_rust::array_to_rust;
+use crate::arrow_interop::to_rust::{array_to_rust, stream_to_rust};
 use crate::conversion::{slice_extract_wrapped, vec_extract_wrapped, Wrap};
 use crate::error::PyPolarsErr;
 use crate::prelude::ObjectValue;
@@ -257,6 +257,17 @@ impl PySeries {
         Ok(s.into())
     }
 
+    #[staticmethod]
+    fn from_arrow_c_stream(name: &str, obj: &PyAny) -> PyResult<Self> {
+        let (field, arrays) = stream_to_rust(obj)?;
+        let series = if arrays.is_empty() {
+            Series::new_empty(name, &(&field.data_type).into())
+        } else {
+            Series::try_from((name, arrays)).map_err(PyPolarsErr::from)?
+        };
+        Ok(series.into())
+    }
+
     #[staticmethod]
     fn from_arrow(name: &str, array: &PyAny) -> PyResult<Self> {
         let arr = array_to_rust(array)?;
@@ -19,6 +19,25 @@ impl PySeries {
         })
     }
 
+    /// Export the chunks of the Series as an Arrow C stream, see the Arrow PyCapsule
+    /// interface. `requested_schema` is ignored; the consumer casts if needed.
+    #[allow(unused_variables)]
+    #[pyo3(signature = (requested_schema=None))]
+    fn __arrow_c_stream__(
+        &self,
+        py: Python,
+        requested_schema: Option<PyObject>,
+    ) -> PyResult<PyObject> {
+        let s = &self.series;
+        let arrays = (0..s.n_chunks()).map(|i| s.to_arrow(i)).collect::<Vec<_>>();
+        let dtype = arrays
+            .first()
+            .map(|arr| arr.data_type().clone())
+            .unwrap_or_else(|| s.dtype().to_arrow());
+        let field = ArrowField::new(s.name(), dtype, true);
+        arrow_interop::to_py::to_py_stream_capsule(arrays, field, py)
+    }
+
     /// For numeric types, this should only be called for Series with null types.
     /// Non-nullable types are handled with `view()`.
     /// This will cast to floats so that `None = np.nan`.
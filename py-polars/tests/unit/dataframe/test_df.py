@@ -539,6 +539,13 @@ def test_multiple_columns_drop() -> None:
     assert out.columns == ["a"]
 
 
+def test_chunk_lengths() -> None:
+    df = pl.DataFrame({"a": [2, 1, 3], "b": [1, 2, 3], "c": [1, 2, 3]})
+    assert df.chunk_lengths() == [[3]] * 3
+    assert pl.concat([df, df], rechunk=False).chunk_lengths() == [[3, 3]] * 3
+    assert pl.concat([df, df]).chunk_lengths() == [[6]] * 3
+
+
 def test_concat() -> None:
     df1 = pl.DataFrame({"a": [2, 1, 3], "b": [1, 2, 3], "c": [1, 2, 3]})
     df2 = pl.concat([df1, df1])
@@ -1612,6 +1619,32 @@ def test_reproducible_hash_with_seeds() -> None:
         assert_series_equal(expected, result, check_names=False, check_exact=True)
 
 
+def test_hash_rows_stable() -> None:
+    df = pl.DataFrame(
+        {"a": [1, None, 3], "b": [0.0, -0.0, None], "c": ["x", "", None]}
+    )
+    result = df.hash_rows(42, stable=True)
+    assert result.dtype == pl.UInt64
+    assert result.null_count() == 0
+
+    # independent of the integer width and the chunk layout
+    df_wide = pl.concat([df.head(1), df.tail(2)], rechunk=False).with_columns(
+        pl.col("a").cast(pl.Int8)
+    )
+    assert_series_equal(df_wide.hash_rows(42, stable=True), result)
+    assert not df.hash_rows(0, stable=True).series_equal(result)
+
+
+def test_hash_rows_stable_golden() -> None:
+    # these values are part of the contract of the stable hash and must never change
+    df = pl.DataFrame({"a": [1, None, 3], "b": [0.5, -0.0, None], "c": ["x", "", "z"]})
+    expected = pl.Series(
+        [15874456706399031779, 5147062472345068972, 10162124208234798799],
+        dtype=pl.UInt64,
+    )
+    assert_series_equal(df.hash_rows(42, stable=True), expected, check_names=False)
+
+
 def test_create_df_from_object() -> None:
     class Foo:
         def __init__(self, value: int) -> None:
@@ -2085,6 +2118,46 @@ def test_n_unique_subsets() -> None:
     )
 
 
+def test_value_counts_subsets() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [1, 1, 2, 1, 3, 2],
+            "b": ["x", "x", "y", "y", "z", "y"],
+            "c": [0, 1, 2, 3, 4, 5],
+        }
+    )
+    result = df.value_counts(["a", "b"]).sort("a", "b")
+    expected = pl.DataFrame(
+        {"a": [1, 1, 2, 3], "b": ["x", "y", "y", "z"], "counts": [2, 1, 2, 1]},
+        schema_overrides={"counts": pl.UInt32},
+    )
+    assert_frame_equal(result, expected)
+
+    result = df.value_counts("a", sort=True)
+    assert result.to_dict(False) == {"a": [1, 2, 3], "counts": [3, 2, 1]}
+
+    # omitting 'subset' counts unique rows
+    assert df.value_counts()["counts"].to_list() == [1] * 6
+
+    result = df.value_counts(["a", "b"], as_struct="key").sort("key")
+    assert result.schema == {
+        "key": pl.Struct({"a": pl.Int64, "b": pl.Utf8}),
+        "counts": pl.UInt32,
+    }
+    assert_frame_equal(result["key"].struct.unnest(), expected.select("a", "b"))
+
+
+def test_value_counts_name() -> None:
+    df = pl.DataFrame({"a": [1, 1, 2], "counts": [0, 0, 1]})
+    result = df.value_counts(sort=True, name="n")
+    assert result.to_dict(False) == {"a": [1, 2], "counts": [0, 1], "n": [2, 1]}
+
+    with pytest.raises(pl.DuplicateError, match="'counts'"):
+        df.value_counts()
+    with pytest.raises(pl.DuplicateError, match="'key'"):
+        df.value_counts(as_struct="key", name="key")
+
+
 def test_shrink_to_fit() -> None:
     df = pl.DataFrame({"foo": [1, 2, 3], "bar": [6, 7, 8], "ham": ["a", "b", "c"]})
 
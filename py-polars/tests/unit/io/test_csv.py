@@ -20,7 +20,7 @@ from polars.utils.various import normalize_filepath
 if TYPE_CHECKING:
     from pathlib import Path
 
-    from polars.type_aliases import TimeUnit
+    from polars.type_aliases import CsvEncoding, TimeUnit
 
 
 @pytest.fixture()
@@ -420,6 +420,35 @@ def test_read_csv_encoding(tmp_path: Path) -> None:
             )
 
 
+@pytest.mark.write_disk()
+@pytest.mark.parametrize(
+    ("encoding", "python_encoding"),
+    [("latin1", "latin-1"), ("utf16-le", "utf-16-le"), ("utf16-be", "utf-16-be")],
+)
+def test_read_csv_native_encoding(
+    encoding: CsvEncoding, python_encoding: str, tmp_path: Path
+) -> None:
+    tmp_path.mkdir(exist_ok=True)
+
+    csv = "ville,température
+Zürich,-3
+São Paulo,25
+"
+    expected = pl.DataFrame(
+        {"ville": ["Zürich", "São Paulo"], "température": [-3, 25]}
+    )
+    bts = csv.encode(python_encoding)
+    assert_frame_equal(pl.read_csv(bts, encoding=encoding), expected)
+
+    file_path = tmp_path / "encoding.csv"
+    file_path.write_bytes(bts)
+    assert_frame_equal(pl.read_csv(file_path, encoding=encoding), expected)
+    assert_frame_equal(pl.scan_csv(file_path, encoding=encoding).collect(), expected)
+    assert_frame_equal(
+        pl.scan_csv(file_path, encoding=encoding).collect(streaming=True), expected
+    )
+
+
 def test_column_rename_and_dtype_overwrite() -> None:
     csv = textwrap.dedent(
         """\
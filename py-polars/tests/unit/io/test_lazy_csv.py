@@ -234,3 +234,43 @@ def test_scan_csv_schema_overwrite_not_projected_8483(foods_file_path: Path) ->
     )
     expected = pl.DataFrame({"count": 27}, schema={"count": pl.UInt32})
     assert_frame_equal(df, expected)
+
+
+def test_scan_csv_headerless_projection(tmp_path: Path) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    file_path = tmp_path / "headerless.csv"
+    file_path.write_text("1,a,x\n2,b,y\n3,c,z\n")
+
+    lf = pl.scan_csv(file_path, has_header=False, columns=[2, 0])
+    assert lf.columns == ["column_3", "column_1"]
+    assert lf.collect().to_dict(False) == {
+        "column_3": ["x", "y", "z"],
+        "column_1": [1, 2, 3],
+    }
+
+    df = pl.scan_csv(
+        file_path,
+        has_header=False,
+        new_columns=["idx", "letter", "other"],
+        columns=["letter", "idx"],
+        row_count_name="rc",
+    ).collect()
+    assert df.to_dict(False) == {
+        "rc": [0, 1, 2],
+        "letter": ["a", "b", "c"],
+        "idx": [1, 2, 3],
+    }
+
+    with pytest.raises(pl.OutOfBoundsError):
+        pl.scan_csv(file_path, has_header=False, columns=[3])
+
+
+def test_scan_csv_object_store(tmp_path: Path) -> None:
+    # `file://` urls are read through the object store, like cloud storage
+    file_path = tmp_path / "object_store.csv"
+    file_path.write_text("a,b\n1,x\n2,y\n")
+
+    lf = pl.scan_csv(f"file://{file_path}", storage_options={}, retries=1)
+    # the query reads the object that was downloaded to infer the schema
+    file_path.unlink()
+    assert lf.collect().to_dict(False) == {"a": [1, 2], "b": ["x", "y"]}
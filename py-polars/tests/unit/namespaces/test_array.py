@@ -1,7 +1,8 @@
 import numpy as np
+import pytest
 
 import polars as pl
-from polars.testing import assert_frame_equal
+from polars.testing import assert_frame_equal, assert_series_equal
 
 
 def test_arr_min_max() -> None:
@@ -15,6 +16,68 @@ def test_arr_sum() -> None:
     assert s.arr.sum().to_list() == [3, 7]
 
 
+def test_arr_mean_std() -> None:
+    s = pl.Series(
+        "a", [[1, 2], [4, 3], [1, None]], dtype=pl.Array(width=2, inner=pl.Int64)
+    )
+    assert s.arr.mean().to_list() == [1.5, 3.5, 1.0]
+    assert s.arr.std(ddof=0).to_list() == [0.5, 0.5, 0.0]
+
+
+def test_arr_any_all() -> None:
+    s = pl.Series(
+        "a",
+        [[True, True], [False, True], [False, False]],
+        dtype=pl.Array(width=2, inner=pl.Boolean),
+    )
+    assert s.arr.any().to_list() == [True, True, False]
+    assert s.arr.all().to_list() == [True, False, False]
+
+    with pytest.raises(pl.InvalidOperationError):
+        pl.Series([[1, 2]], dtype=pl.Array(width=2, inner=pl.Int64)).arr.any()
+
+
+def test_arr_arg_min_max() -> None:
+    s = pl.Series("a", [[1, 2], [4, 3]], dtype=pl.Array(width=2, inner=pl.Int64))
+    assert s.arr.arg_min().to_list() == [0, 1]
+    assert s.arr.arg_max().to_list() == [1, 0]
+
+
+def test_arr_contains() -> None:
+    df = pl.DataFrame(
+        {
+            "a": pl.Series(
+                [[1, 2], [4, 3], [5, 6]], dtype=pl.Array(width=2, inner=pl.Int64)
+            ),
+            "b": [1, 3, 1],
+        }
+    )
+    out = df.select(
+        pl.col("a").arr.contains(2).alias("lit"),
+        pl.col("a").arr.contains(pl.col("b")).alias("col"),
+    )
+    assert out.to_dict(False) == {
+        "lit": [True, False, False],
+        "col": [True, True, False],
+    }
+
+
+def test_arr_list_roundtrip() -> None:
+    s = pl.Series("a", [[1, 2], [4, 3]], dtype=pl.Array(width=2, inner=pl.Int64))
+    out = s.arr.to_list()
+    assert out.dtype == pl.List(pl.Int64)
+    assert out.to_list() == [[1, 2], [4, 3]]
+
+    back = out.list.to_array(2)
+    assert back.dtype == pl.Array(width=2, inner=pl.Int64)
+    assert_series_equal(back, s)
+
+    df = pl.DataFrame({"a": [[1, 2], [4, 3]]})
+    assert df.lazy().select(pl.col("a").list.to_array(2)).schema == {
+        "a": pl.Array(width=2, inner=pl.Int64)
+    }
+
+
 def test_arr_unique() -> None:
     df = pl.DataFrame(
         {"a": pl.Series("a", [[1, 1], [4, 3]], dtype=pl.Array(width=2, inner=pl.Int64))}
@@ -43,6 +43,17 @@ def test_dt_to_string(series_of_int_dates: pl.Series) -> None:
     assert_series_equal(series_of_int_dates.dt.strftime("%F"), expected_str_dates)
 
 
+def test_dt_to_string_duration() -> None:
+    s = pl.Series(
+        [timedelta(days=1, hours=2, minutes=3, seconds=4.5), timedelta(0), None]
+    )
+    assert s.dt.to_string("iso").to_list() == ["P1DT2H3M4.5S", "PT0S", None]
+    assert s.dt.to_string("polars").to_list() == ["1d 2h 3m 4s 500ms", "0µs", None]
+
+    with pytest.raises(ComputeError, match="invalid format string"):
+        pl.Series([date(2020, 1, 1)]).dt.to_string("%Q")
+
+
 @pytest.mark.parametrize(
     ("unit_attr", "expected"),
     [
@@ -690,6 +701,21 @@ def test_offset_by_truncate_sorted_flag() -> None:
     assert s2.flags["SORTED_ASC"]
 
 
+def test_truncate_offset_by_time() -> None:
+    s = pl.Series([time(10, 35), time(23, 50), None])
+    assert s.dt.truncate("1h").to_list() == [time(10), time(23), None]
+    assert s.dt.truncate("1d").to_list() == [time(0), time(0), None]
+    assert s.dt.offset_by("30m").to_list() == [time(11, 5), time(0, 20), None]
+    assert s.dt.offset_by("-11h").to_list() == [time(23, 35), time(12, 50), None]
+
+    # an offset wraps times around midnight, so the result is no longer sorted
+    s = pl.Series([time(10), time(23, 10)]).set_sorted()
+    out = s.dt.truncate("1h", offset="2h")
+    assert out.to_list() == [time(12), time(1)]
+    assert not out.flags["SORTED_ASC"]
+    assert s.dt.truncate("1h").flags["SORTED_ASC"]
+
+
 def test_offset_by_broadcasting() -> None:
     # test broadcast lhs
     df = pl.DataFrame(
@@ -850,6 +876,24 @@ def test_offset_by_saturating_8217_8474(
     assert result == expected
 
 
+@pytest.mark.parametrize(
+    ("duration", "input_date", "expected"),
+    [
+        ("1mo_eom", date(2022, 2, 28), date(2022, 3, 31)),
+        ("1mo_eom", date(2024, 1, 31), date(2024, 2, 29)),
+        ("1mo_eom", date(2022, 1, 30), date(2022, 2, 28)),
+        ("1mo_eom", date(2022, 2, 15), date(2022, 3, 15)),
+        ("-1y_eom", date(2024, 2, 29), date(2023, 2, 28)),
+        ("1q_eom", date(2022, 4, 30), date(2022, 7, 31)),
+    ],
+)
+def test_offset_by_end_of_month(
+    duration: str, input_date: date, expected: date
+) -> None:
+    result = pl.Series([input_date]).dt.offset_by(duration).item()
+    assert result == expected
+
+
 def test_year_empty_df() -> None:
     df = pl.DataFrame(pl.Series(name="date", dtype=pl.Date))
     assert df.select(pl.col("date").dt.year()).dtypes == [pl.Int32]
@@ -28,6 +28,14 @@ def test_str_concat2() -> None:
     assert cast(str, df.item()) == "1-null-2"
 
 
+def test_str_concat_ignore_nulls() -> None:
+    df = pl.DataFrame({"g": [1, 1, 1, 2, 3], "a": ["x", None, "y", None, "z"]})
+    out = df.group_by("g", maintain_order=True).agg(
+        pl.col("a").str.concat(", ", ignore_nulls=True)
+    )
+    assert out.to_dict(False) == {"g": [1, 2, 3], "a": ["x, y", "", "z"]}
+
+
 def test_str_concat_datetime() -> None:
     df = pl.DataFrame({"d": [datetime(2020, 1, 1), None, datetime(2022, 1, 1)]})
     df = df.select(pl.col("d").str.concat("|"))
@@ -1036,6 +1044,35 @@ def test_splitn_expr() -> None:
     assert_frame_equal(out, expected)
 
 
+def test_split_regex() -> None:
+    s = pl.Series("x", ["a1b22c", None, "d", "1e2"])
+
+    assert s.str.split(r"\d+", literal=False).to_list() == [
+        ["a", "b", "c"],
+        None,
+        ["d"],
+        ["", "e", ""],
+    ]
+    assert s.str.split(r"\d+", inclusive=True, literal=False).to_list() == [
+        ["a1", "b22", "c"],
+        None,
+        ["d"],
+        ["1", "e2"],
+    ]
+
+    out = s.str.splitn(r"\d+", 2, literal=False).struct.unnest()
+    expected = pl.DataFrame(
+        {"field_0": ["a", None, "d", ""], "field_1": ["b22c", None, None, "e2"]}
+    )
+    assert_frame_equal(out, expected)
+
+    df = pl.DataFrame({"x": ["a1b", "c-d_e", None], "by": [r"\d", "[-_]", "x"]})
+    out = df.select(pl.col("x").str.split(pl.col("by"), literal=False))
+    assert out.to_dict(False) == {"x": [["a", "b"], ["c", "d", "e"], None]}
+
+    with pytest.raises(pl.ComputeError):
+        s.str.split("(", literal=False)
+
 def test_titlecase() -> None:
     df = pl.DataFrame(
         {
@@ -675,3 +675,49 @@ def test_strptime_use_earliest(exact: bool) -> None:
             pl.Datetime("us", "Europe/London"),
             exact=exact,
         ).item()
+
+
+def test_to_datetime_infer_format() -> None:
+    s = pl.Series(["2021-01-01 10:00:00", None, "2021-01-02 11:30:00"])
+    result = s.str.to_datetime(infer=True)
+    expected = pl.Series([datetime(2021, 1, 1, 10), None, datetime(2021, 1, 2, 11, 30)])
+    assert_series_equal(result, expected)
+
+    # all values are parsed with the format that is inferred from the sample
+    s = pl.Series(
+        ["2021-01-01 10:00:00", "2021-01-01 11:00:00", "2021/01/02 11:30:00"]
+    )
+    result = s.str.to_datetime(infer=True, strict=False)
+    expected = pl.Series([datetime(2021, 1, 1, 10), datetime(2021, 1, 1, 11), None])
+    assert_series_equal(result, expected)
+    with pytest.raises(ComputeError):
+        s.str.to_datetime(infer=True)
+
+
+def test_to_duration() -> None:
+    s = pl.Series(["1h30m", "PT1H30M", "-1w2d", "P1DT0.5S", None])
+    expected = pl.Series(
+        [
+            timedelta(hours=1, minutes=30),
+            timedelta(hours=1, minutes=30),
+            timedelta(days=-9),
+            timedelta(days=1, milliseconds=500),
+            None,
+        ]
+    )
+    assert_series_equal(s.str.to_duration(), expected)
+    assert s.str.to_duration(time_unit="ms").dtype == pl.Duration("ms")
+
+    # the formats can be restricted and round-trip through dt.to_string
+    out = s.str.to_duration("iso", strict=False)
+    assert out.null_count() == 3
+    expected_iso = [None, "PT1H30M", None, "P1DT0.5S", None]
+    assert out.dt.to_string("iso").to_list() == expected_iso
+
+    # calendar units don't have a fixed length
+    with pytest.raises(ComputeError, match="strict duration"):
+        pl.Series(["1mo"]).str.to_duration()
+
+    # durations that overflow raise, even if not strict
+    with pytest.raises(ComputeError, match="out of the range"):
+        pl.Series(["106752d"]).str.to_duration(strict=False)
@@ -195,6 +195,23 @@ def test_rolling_skew() -> None:
     )
 
 
+@pytest.mark.parametrize("fisher", [True, False])
+@pytest.mark.parametrize("bias", [True, False])
+def test_rolling_kurtosis(fisher: bool, bias: bool) -> None:
+    s = pl.Series([1, 2, None, 3, 2, 10, 8, 1])
+    out = s.rolling_kurtosis(window_size=5, fisher=fisher, bias=bias)
+    # the nulls in a window are skipped
+    expected = [None] * 4 + [
+        s.slice(i - 4, 5).kurtosis(fisher=fisher, bias=bias) for i in range(4, 8)
+    ]
+    assert out.to_list() == pytest.approx(expected)
+    assert_series_equal(
+        pl.select(pl.lit(s).rolling_kurtosis(5, fisher=fisher, bias=bias)).to_series(),
+        out,
+        check_names=False,
+    )
+
+
 @pytest.mark.parametrize("time_zone", [None, "US/Central"])
 @pytest.mark.parametrize(
     ("rolling_fn", "expected_values"),
@@ -906,6 +923,22 @@ def test_rolling_weighted_quantile_10031() -> None:
     )
 
 
+def test_rolling_weighted_median_nulls() -> None:
+    s = pl.Series([1.0, None, 3.0, 4.0])
+    assert_series_equal(
+        s.rolling_median(2, weights=[1.0, 2.0], min_periods=1),
+        pl.Series([1.0, 1.0, 3.0, 3.5]),
+    )
+    assert_series_equal(
+        s.rolling_quantile(0.5, "linear", 2, weights=[1.0, 2.0]),
+        pl.Series([None, None, None, 3.5]),
+    )
+    # the weights are aligned with the full window at the edges
+    assert_series_equal(
+        s.rolling_median(3, weights=[0.0, 1.0, 0.0], min_periods=1, center=True),
+        s,
+    )
+
 def test_rolling_aggregations_unsorted_raise_10991() -> None:
     df = pl.DataFrame(
         {
@@ -1020,3 +1053,51 @@ def test_rolling_nanoseconds_11003() -> None:
     )
     expected = df.with_columns(val=pl.Series([1, 3, 6]))
     assert_frame_equal(result, expected)
+
+
+def test_expanding_aggregations() -> None:
+    df = pl.DataFrame({"g": [1, 1, 2, 1, 2], "a": [3, None, 1, 5, 2]})
+
+    out = df.select(
+        pl.col("a").expanding_min().alias("min"),
+        pl.col("a").expanding_max(min_periods=2).alias("max"),
+        pl.col("a").expanding_sum().alias("sum"),
+        pl.col("a").expanding_mean().over("g").alias("mean"),
+    )
+    assert out.to_dict(False) == {
+        "min": [3, 3, 1, 1, 1],
+        "max": [None, None, 3, 5, 5],
+        "sum": [3, 3, 4, 9, 11],
+        "mean": [3.0, 3.0, 1.0, 4.0, 1.5],
+    }
+
+    s = pl.Series("a", [1.0, 4.0, 2.0, 9.0])
+    assert_series_equal(
+        s.expanding_var(),
+        pl.Series("a", [None, 4.5, 7.0 / 3.0, 38.0 / 3.0]),
+    )
+    assert_series_equal(s.expanding_std(), s.expanding_var().sqrt())
+    assert s.expanding_mean(min_periods=5).null_count() == 4
+
+
+def test_rolling_center_bias() -> None:
+    s = pl.Series("a", [1, 2, 3, 4, 5])
+
+    left = s.rolling_mean(2, min_periods=1, center=True)
+    assert left.to_list() == [1.0, 1.5, 2.5, 3.5, 4.5]
+    right = s.rolling_mean(2, min_periods=1, center=True, center_bias="right")
+    assert right.to_list() == [1.5, 2.5, 3.5, 4.5, 5.0]
+    split = s.rolling_mean(2, min_periods=1, center=True, center_bias="split")
+    assert split.to_list() == [1.25, 2.0, 3.0, 4.0, 4.75]
+    right = s.rolling_max(2, min_periods=1, center=True, center_bias="right")
+    assert right.to_list() == [2, 3, 4, 5, 5]
+
+    # the bias doesn't apply to windows of an odd size
+    assert_series_equal(
+        s.rolling_min(3, min_periods=1, center=True, center_bias="split"),
+        s.rolling_min(3, min_periods=1, center=True),
+    )
+    with pytest.raises(pl.InvalidOperationError, match="split center bias"):
+        s.rolling_min(2, center=True, center_bias="split")
+    with pytest.raises(ValueError, match="center_bias"):
+        s.rolling_min(2, center=True, center_bias="middle")  # type: ignore[arg-type]
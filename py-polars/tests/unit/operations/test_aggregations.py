@@ -287,3 +287,65 @@ def test_horizontal_sum_null_to_identity() -> None:
     assert pl.DataFrame({"a": [1, 5], "b": [10, None]}).select(
         [pl.sum_horizontal(["a", "b"])]
     ).to_series().to_list() == [11, 5]
+
+
+def test_min_by_max_by() -> None:
+    df = pl.DataFrame(
+        {
+            "key": ["a", "b", "a", "b", "c"],
+            "price": [1.0, 2.0, 3.0, 4.0, 5.0],
+            "ts": [3, 1, 2, 4, None],
+        }
+    )
+    out = df.group_by("key", maintain_order=True).agg(
+        pl.col("price").min_by("ts").alias("first"),
+        pl.col("price").max_by("ts").alias("latest"),
+    )
+    expected = pl.DataFrame(
+        {
+            "key": ["a", "b", "c"],
+            "first": [3.0, 2.0, None],
+            "latest": [1.0, 4.0, None],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+    # the values are sorted within the groups, but `by` is not
+    out = df.group_by("key", maintain_order=True).agg(
+        pl.col("price").sort(descending=True).max_by("ts")
+    )
+    assert out["price"].to_list() == [3.0, 2.0, None]
+    out = df.select(pl.col("price").max_by("ts").over("key"))
+    assert out["price"].to_list() == [1.0, 4.0, 1.0, 4.0, None]
+
+    assert df.select(pl.col("key").max_by("price")).item() == "c"
+    assert df.select(pl.col("key").min_by(pl.col("ts") * -1)).item() == "b"
+
+
+def test_min_by_max_by_categorical() -> None:
+    # the categories are created in the order "b", "c", "a"
+    df = pl.DataFrame(
+        {
+            "key": [1, 1, 1, 2, 2],
+            "value": [1, 2, 3, 4, 5],
+            "cat": ["b", "c", "a", "c", "b"],
+        }
+    ).with_columns(pl.col("cat").cast(pl.Categorical))
+
+    lexical = df.with_columns(pl.col("cat").cat.set_ordering("lexical"))
+    out = lexical.group_by("key", maintain_order=True).agg(
+        pl.col("value").min_by("cat").alias("min"),
+        pl.col("value").max_by("cat").alias("max"),
+    )
+    assert out["min"].to_list() == [3, 5]
+    assert out["max"].to_list() == [2, 4]
+    assert lexical.select(pl.col("value").min_by("cat")).item() == 3
+
+    # the physical ordering follows the order in which the categories were created
+    out = df.group_by("key", maintain_order=True).agg(
+        pl.col("value").min_by("cat").alias("min"),
+        pl.col("value").max_by("cat").alias("max"),
+    )
+    assert out["min"].to_list() == [1, 5]
+    assert out["max"].to_list() == [3, 4]
+    assert df.select(pl.col("value").max_by("cat")).item() == 3
@@ -291,3 +291,53 @@ def test_group_by_rolling_duplicates_11281() -> None:
     result = df.group_by_rolling("ts", period="1d", closed="left").agg(pl.col("val"))
     expected = df.with_columns(val=pl.Series([[], [1], [1], [1], [2, 2, 2], [3]]))
     assert_frame_equal(result, expected)
+
+
+def test_group_by_session() -> None:
+    df = pl.DataFrame(
+        {
+            "user": ["a", "a", "b", "a", "b"],
+            "ts": [
+                datetime(2020, 1, 1, 10, 0),
+                datetime(2020, 1, 1, 10, 20),
+                datetime(2020, 1, 1, 10, 30),
+                datetime(2020, 1, 1, 11, 0),
+                datetime(2020, 1, 1, 12, 0),
+            ],
+            "val": [1, 2, 3, 4, 5],
+        }
+    )
+    assert df.select(pl.col("ts").dt.session_id("30m")).to_series().to_list() == [
+        0,
+        0,
+        0,
+        0,
+        1,
+    ]
+    assert df["ts"].dt.session_id("20m").to_list() == [0, 0, 0, 1, 2]
+
+    result = (
+        df.lazy()
+        .group_by_session("ts", gap="30m", by="user")
+        .agg(pl.col("val"))
+        .collect()
+    )
+    expected = pl.DataFrame(
+        {
+            "user": ["a", "b", "a", "b"],
+            "session_id": pl.Series([0, 0, 1, 1], dtype=pl.UInt32),
+            "val": [[1, 2], [3], [4], [5]],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+    result = (
+        df.lazy()
+        .group_by_session("ts", gap="30m", by="user", session_column="session")
+        .agg(pl.col("val"))
+        .collect()
+    )
+    assert_frame_equal(result, expected.rename({"session_id": "session"}))
+
+    with pytest.raises(pl.ComputeError, match="not sorted"):
+        df.select(pl.col("ts").reverse().dt.session_id("30m"))
@@ -352,3 +352,30 @@ def test_pivot_struct() -> None:
             {"num1": 4, "num2": 4},
         ],
     }
+
+
+def test_pivot_margins() -> None:
+    df = pl.DataFrame(
+        {
+            "foo": ["one", "one", "two", "two", "one", "two"],
+            "bar": ["y", "y", "y", "x", "x", "x"],
+            "baz": [1, 2, 3, 4, 5, 6],
+        }
+    )
+    out = df.pivot(
+        values="baz",
+        index="foo",
+        columns="bar",
+        aggregate_function="mean",
+        margins=True,
+        margins_name="Total",
+    )
+    assert out.to_dict(False) == {
+        "foo": ["one", "two", "Total"],
+        "y": [1.5, 3.0, 2.0],
+        "x": [5.0, 5.0, 5.0],
+        "Total": [8 / 3, 13 / 3, 3.5],
+    }
+
+    with pytest.raises(ComputeError, match="aggregation function"):
+        df.pivot(values="baz", index="foo", columns="bar", margins=True)
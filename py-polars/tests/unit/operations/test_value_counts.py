@@ -55,3 +55,30 @@ def test_value_counts_duplicate_name() -> None:
 
     with pytest.raises(pl.DuplicateError, match="counts"):
         s.value_counts()
+
+
+def test_most_frequent() -> None:
+    s = pl.Series("a", ["x", "y", "y", None, "z", "z", "x", "z", "w"])
+    expected = pl.DataFrame(
+        {"a": ["z", "x", "y"], "counts": [3, 2, 2]},
+        schema_overrides={"counts": pl.UInt32},
+    )
+    assert_frame_equal(s.most_frequent(3), expected)
+    assert_frame_equal(
+        s.cast(pl.Categorical).most_frequent(3),
+        expected.with_columns(pl.col("a").cast(pl.Categorical)),
+        categorical_as_str=True,
+    )
+    assert s.most_frequent(0).height == 0
+    assert s.most_frequent(100).height == 5
+
+
+def test_most_frequent_expr() -> None:
+    df = pl.DataFrame({"g": [1, 1, 1, 2, 2], "id": ["a", "b", "b", "c", "d"]})
+    out = df.group_by("g", maintain_order=True).agg(
+        pl.col("id").most_frequent(1).first()
+    )
+    assert out.to_dict(False) == {
+        "g": [1, 2],
+        "id": [{"id": "b", "counts": 2}, {"id": "c", "counts": 1}],
+    }
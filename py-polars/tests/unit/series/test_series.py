@@ -1040,6 +1040,16 @@ def test_fill_nan() -> None:
     assert_series_equal(a.fill_nan(0), pl.Series("a", [1.0, 0.0, 2.0, 0.0, 3.0]))
 
 
+def test_nan_to_null() -> None:
+    nan = float("nan")
+    a = pl.Series("a", [1.0, nan, None, nan], dtype=pl.Float32)
+    expected = pl.Series("a", [1.0, None, None, None], dtype=pl.Float32)
+    assert_series_equal(a.nan_to_null(), expected)
+    assert_series_equal(a.nan_to_null().sort(), expected.sort())
+    b = pl.Series("b", [1, None])
+    assert_series_equal(b.nan_to_null(), b)
+
+
 def test_map_elements() -> None:
     with pytest.warns(PolarsInefficientMapWarning):
         a = pl.Series("a", [1, 2, None])
@@ -2733,3 +2743,11 @@ def test_series_getitem_out_of_bounds_negative() -> None:
         IndexError, match="index -10 is out of bounds for sequence of length 2"
     ):
         s[-10]
+
+
+def test_rle_decode() -> None:
+    s = pl.Series("s", [1, 1, 2, None, None, 1])
+    assert_series_equal(s.rle().rle_decode(), s)
+
+    df = pl.DataFrame({"s": s})
+    assert_frame_equal(df.select(pl.col("s").rle().rle_decode()), df)
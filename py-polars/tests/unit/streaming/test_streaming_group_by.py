@@ -281,6 +281,31 @@ def test_streaming_group_by_ooc_q3(
     assert_frame_equal(result, expected)
 
 
+@pytest.mark.slow()
+@pytest.mark.write_disk()
+def test_streaming_group_by_memory_limit(monkeypatch: Any, capfd: Any) -> None:
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+    # enough distinct keys to overflow the hash table of every thread
+    n = 200_000 * pl.threadpool_size()
+    q = (
+        pl.LazyFrame({"a": np.arange(n)})
+        .group_by("a")
+        .agg(pl.count(), pl.col("a").first().alias("a_first"))
+        .sort("a")
+    )
+    expected = q.collect()
+
+    # the group_by stays in memory within the limit
+    result = q.collect(streaming=True, streaming_memory_limit=1 << 40)
+    assert_frame_equal(result, expected)
+    assert "OOC group_by started" not in capfd.readouterr().err
+
+    # and spills to disk once the data it holds exceeds the limit
+    result = q.collect(streaming=True, streaming_memory_limit=1024)
+    assert_frame_equal(result, expected)
+    assert "OOC group_by started" in capfd.readouterr().err
+
+
 def test_streaming_group_by_struct_key() -> None:
     df = pl.DataFrame(
         {"A": [1, 2, 3, 2], "B": ["google", "ms", "apple", "ms"], "C": [2, 3, 4, 3]}
@@ -72,6 +72,44 @@ def test_cumcount() -> None:
     assert out["foo"][1].to_list() == [0, 1]
 
 
+def test_cumcount_ignore_nulls() -> None:
+    df = pl.DataFrame({"g": [1, 1, 2, 1, 2], "a": [None, 1, 3, None, 4]})
+
+    out = df.select(
+        pl.col("a").cumcount(ignore_nulls=True).alias("count"),
+        pl.col("a").cumcount(reverse=True, ignore_nulls=True).alias("reverse"),
+        pl.col("a").cumcount(ignore_nulls=True).over("g").alias("per_group"),
+    )
+    assert out["count"].to_list() == [0, 0, 1, 2, 2]
+    assert out["reverse"].to_list() == [3, 2, 1, 1, 0]
+    assert out["per_group"].to_list() == [0, 0, 0, 1, 1]
+
+    # without nulls this is the same as the default count
+    df = pl.DataFrame({"g": [1, 1, 2, 1, 2], "a": [5, 1, 3, 2, 4]})
+    for reverse in [False, True]:
+        count = pl.col("a").cumcount(reverse=reverse)
+        count_ignore_nulls = pl.col("a").cumcount(reverse=reverse, ignore_nulls=True)
+        assert_frame_equal(df.select(count), df.select(count_ignore_nulls))
+        assert_frame_equal(
+            df.select(count.over("g")), df.select(count_ignore_nulls.over("g"))
+        )
+
+
+def test_cum_n_unique() -> None:
+    df = pl.DataFrame({"g": ["x", "y", "x", "x", "y"], "a": [1, 1, None, 1, 2]})
+
+    out = df.select(
+        pl.col("a").cum_n_unique().alias("n_unique"),
+        pl.col("a").cum_n_unique(reverse=True).alias("reverse"),
+    )
+    assert out["n_unique"].to_list() == [1, 1, 2, 2, 3]
+    assert out["reverse"].to_list() == [3, 3, 3, 2, 1]
+
+    out = df.group_by("g", maintain_order=True).agg(pl.col("a").cum_n_unique())
+    assert out["a"].to_list() == [[1, 2, 2], [1, 2]]
+    assert df["a"].cum_n_unique().to_list() == [1, 1, 2, 2, 3]
+
+
 def test_filter_where() -> None:
     df = pl.DataFrame({"a": [1, 2, 3, 1, 2, 3], "b": [4, 5, 6, 7, 8, 9]})
     result_where = df.group_by("a", maintain_order=True).agg(
@@ -6,6 +6,7 @@ from typing import Any, cast
 import numpy as np
 import pandas as pd
 import pyarrow as pa
+import pyarrow.compute as pc
 import pytest
 from numpy.testing import assert_array_equal
 
@@ -477,6 +478,24 @@ def test_from_arrow() -> None:
     assert df.schema == {"a": pl.UInt32, "b": pl.UInt64}  # type: ignore[union-attr]
 
 
+@pytest.mark.skipif(
+    int(pa.__version__.split(".")[0]) < 16,
+    reason="view types require pyarrow 16",
+)
+def test_from_arrow_view_and_run_end_encoded() -> None:
+    values = ["a", None, "a string longer than twelve bytes", ""]
+    s = pl.from_arrow(pa.array(values, type=pa.string_view()))
+    assert_series_equal(s, pl.Series(values))  # type: ignore[arg-type]
+
+    binary = pa.array([b"\x00", None, b"x" * 20], type=pa.binary_view())
+    s = pl.from_arrow(binary.slice(1))
+    assert s.to_list() == [None, b"x" * 20]  # type: ignore[union-attr]
+
+    ree = pc.run_end_encode(pa.array([1, 1, None, 3, 3, 3]))
+    s = pl.from_arrow(ree.slice(1, 4))
+    assert s.to_list() == [1, None, 3, 3]  # type: ignore[union-attr]
+
+
 def test_from_pandas_dataframe() -> None:
     pd_df = pd.DataFrame([[1, 2, 3], [4, 5, 6]], columns=["a", "b", "c"])
     df = pl.from_pandas(pd_df)
@@ -1121,3 +1140,45 @@ def test_from_arrow_invalid_time_zone() -> None:
     )
     with pytest.raises(ComputeError, match=r"unable to parse time zone: '\+01:00'"):
         pl.from_arrow(arr)
+
+
+class _ArrowCStreamWrapper:
+    """Exposes only the Arrow PyCapsule interface of the wrapped object."""
+
+    def __init__(self, obj: Any) -> None:
+        self.obj = obj
+
+    def __arrow_c_stream__(self, requested_schema: object | None = None) -> object:
+        return self.obj.__arrow_c_stream__(requested_schema)
+
+
+def test_arrow_c_stream_roundtrip() -> None:
+    df = pl.concat(
+        [
+            pl.DataFrame({"a": [1, 2], "b": ["x", None]}),
+            pl.DataFrame({"a": [3], "b": ["z"]}),
+        ],
+        rechunk=False,
+    )
+    assert df.n_chunks() == 2
+
+    result = pl.DataFrame(_ArrowCStreamWrapper(df))
+    assert_frame_equal(result, df)
+    result = pl.DataFrame(_ArrowCStreamWrapper(df), schema_overrides={"a": pl.Int32})
+    assert result.schema == {"a": pl.Int32, "b": pl.Utf8}
+
+    s = df.to_series(1)
+    assert_series_equal(pl.Series("b", _ArrowCStreamWrapper(s)), s)
+
+    empty = df.clear()
+    assert_frame_equal(pl.DataFrame(_ArrowCStreamWrapper(empty)), empty)
+
+
+def test_arrow_c_stream_to_pyarrow() -> None:
+    import_capsule = getattr(pa.RecordBatchReader, "_import_from_c_capsule", None)
+    if import_capsule is None:
+        pytest.skip("pyarrow does not support the Arrow PyCapsule interface")
+
+    df = pl.DataFrame({"a": [1, 2], "b": ["x", "y"]})
+    tbl = import_capsule(df.__arrow_c_stream__()).read_all()
+    assert tbl.to_pydict() == {"a": [1, 2], "b": ["x", "y"]}